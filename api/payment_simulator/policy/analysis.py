@@ -83,6 +83,7 @@ FIELD_CATEGORY_MAP: dict[str, str] = {
     "cost_deadline_penalty": "CostField",
     "cost_eod_penalty": "CostField",
     "cost_split_friction": "CostField",
+    "cost_split_friction_exponent": "CostField",
     "accumulated_delay_cost": "CostField",
     "accumulated_overdraft_cost": "CostField",
 
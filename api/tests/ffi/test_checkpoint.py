@@ -224,6 +224,44 @@ def test_determinism_after_restore(config_with_transactions):
         assert r1["num_lsm_releases"] == r2["num_lsm_releases"], f"Tick {i}: LSM releases differ"
 
 
+def test_load_state_from_config_json_continues_identically(config_with_transactions):
+    """FFI: Loading with a JSON config reproduces the uninterrupted next tick."""
+    uninterrupted = Orchestrator.new(config_with_transactions)
+    interrupted = Orchestrator.new(config_with_transactions)
+    for _ in range(10):
+        uninterrupted.tick()
+        interrupted.tick()
+
+    state_json = interrupted.save_state()
+    restored = Orchestrator.load_state(json.dumps(config_with_transactions), state_json)
+
+    expected = uninterrupted.tick()
+    actual = restored.tick()
+    expected.pop("timing")
+    actual.pop("timing")
+    assert actual == expected
+    for agent_id in ["BANK_A", "BANK_B"]:
+        assert restored.get_agent_balance(agent_id) == uninterrupted.get_agent_balance(agent_id)
+
+
+def test_load_state_errors_are_value_errors(simple_config):
+    """FFI: Config mismatch and bad JSON surface as ValueError."""
+    orch = Orchestrator.new(simple_config)
+    orch.tick()
+    state_json = orch.save_state()
+
+    different_config = simple_config.copy()
+    different_config["rng_seed"] = 99999
+    with pytest.raises(ValueError, match="Config mismatch"):
+        Orchestrator.load_state(json.dumps(different_config), state_json)
+
+    with pytest.raises(ValueError, match="Deserialization error"):
+        Orchestrator.load_state(simple_config, "{not valid json}")
+
+    with pytest.raises(ValueError, match="config JSON"):
+        Orchestrator.load_state("{not valid json}", state_json)
+
+
 def test_save_load_roundtrip_preserves_exact_state(simple_config):
     """FFI: Multiple save/load cycles should preserve state."""
     # Original
@@ -50,11 +50,11 @@ class TestPolicySchemaFFI:
         assert len(schema["computations"]) == 12
 
     def test_get_policy_schema_actions_count(self):
-        """Should have exactly 16 action types."""
+        """Should have exactly 18 action types."""
         from payment_simulator.backends import get_policy_schema
 
         schema = json.loads(get_policy_schema())
-        assert len(schema["actions"]) == 16
+        assert len(schema["actions"]) == 18
 
     def test_get_policy_schema_values_count(self):
         """Should have exactly 4 value types."""
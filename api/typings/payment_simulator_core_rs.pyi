@@ -132,6 +132,14 @@ class Orchestrator:
         """Get system-wide simulation metrics."""
         ...
 
+    def system_metrics(self) -> dict[str, Any]:
+        """Get system-wide simulation metrics (same dict as get_system_metrics)."""
+        ...
+
+    def daily_agent_metrics(self, day: int) -> list[dict[str, Any]]:
+        """Get agent metrics for a completed day, sorted by agent ID."""
+        ...
+
     def get_transaction_counts_debug(self) -> dict[str, Any]:
         """Get transaction count debug information."""
         ...
@@ -232,8 +240,12 @@ class Orchestrator:
         ...
 
     @staticmethod
-    def load_state(config: dict[str, Any], state_json: str) -> Orchestrator:
-        """Load orchestrator from saved state JSON."""
+    def load_state(config: dict[str, Any] | str, state_json: str) -> Orchestrator:
+        """Load orchestrator from saved state JSON.
+
+        `config` may be the config dict or the same dict encoded as JSON.
+        Raises ValueError on a config mismatch or unparseable JSON.
+        """
         ...
 
     # Properties
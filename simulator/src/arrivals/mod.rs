@@ -8,7 +8,8 @@
 //!
 //! 1. **Determinism**: Same seed + same config → same arrivals
 //! 2. **Per-Agent Configuration**: Each agent has its own arrival parameters
-//! 3. **Poisson Arrivals**: Transaction count per tick follows Poisson distribution
+//! 3. **Count Models**: Transaction count per tick follows a Poisson (default) or
+//!    overdispersed negative binomial distribution
 //! 4. **Flexible Amounts**: Support multiple amount distributions
 //!
 //! # Example
@@ -29,12 +30,13 @@
 //!     deadline_range: (5, 20),
 //!     priority_distribution: PriorityDistribution::Fixed { value: 5 },
 //!     divisible: false,
+//!     ..Default::default()
 //! };
 //! ```
 
 use crate::models::Transaction;
 use crate::rng::RngManager;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Priority distribution types for transaction generation.
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -71,7 +73,19 @@ pub struct ArrivalConfig {
     /// If empty, uniform selection across all agents
     pub counterparty_weights: HashMap<String, f64>,
 
+    /// Counterparty group selection weights (agent_group → weight)
+    ///
+    /// When set, a target group is drawn by weight (groups not listed weigh
+    /// 1.0) and the receiver is a uniform member of it. Every other agent
+    /// must then have an `agent_group` (checked at config validation).
+    /// Falls back to `counterparty_weights` when empty or no group has
+    /// positive weight.
+    #[serde(default)]
+    pub counterparty_group_weights: HashMap<String, f64>,
+
     /// Deadline range in ticks from arrival (min, max)
+    ///
+    /// Ignored when `deadline_mode` is `AbsoluteTick`.
     pub deadline_range: (usize, usize),
 
     /// Priority distribution for generated transactions
@@ -79,10 +93,154 @@ pub struct ArrivalConfig {
 
     /// Whether generated transactions are divisible
     pub divisible: bool,
+
+    /// Optional intraday rate profile (one multiplier per tick-within-day)
+    ///
+    /// When set, the Poisson λ for a tick is
+    /// `rate_per_tick * intraday_profile[tick % ticks_per_day]`, allowing
+    /// morning and end-of-day peaks. Length must equal `ticks_per_day`.
+    #[serde(default)]
+    pub intraday_profile: Option<Vec<f64>>,
+
+    /// Optional tags attached to every generated transaction
+    #[serde(default)]
+    pub tags: Option<Vec<String>>,
+
+    /// Distribution of the number of arrivals per tick (default: Poisson)
+    #[serde(default)]
+    pub arrival_count_model: ArrivalCountModel,
+
+    /// Optional amount granularity in cents
+    ///
+    /// When set, every sampled amount is rounded to the nearest multiple
+    /// (ties to even) and never falls below one unit. Must be positive.
+    #[serde(default)]
+    pub amount_granularity: Option<i64>,
+
+    /// How deadlines are drawn (default: offset from arrival via `deadline_range`)
+    #[serde(default)]
+    pub deadline_mode: DeadlineMode,
+}
+
+/// A config that generates nothing (zero rate) with every optional setting off
+///
+/// Meant for struct update syntax, so configs only spell out what they use:
+///
+/// ```
+/// use payment_simulator_core_rs::arrivals::{AmountDistribution, ArrivalConfig};
+///
+/// let config = ArrivalConfig {
+///     rate_per_tick: 0.5,
+///     amount_distribution: AmountDistribution::Uniform { min: 1_000, max: 10_000 },
+///     deadline_range: (5, 20),
+///     ..Default::default()
+/// };
+/// assert!(config.intraday_profile.is_none());
+/// ```
+impl Default for ArrivalConfig {
+    fn default() -> Self {
+        Self {
+            rate_per_tick: 0.0,
+            amount_distribution: AmountDistribution::Uniform { min: 0, max: 0 },
+            counterparty_weights: HashMap::new(),
+            counterparty_group_weights: HashMap::new(),
+            deadline_range: (0, 0),
+            priority_distribution: PriorityDistribution::default(),
+            divisible: false,
+            intraday_profile: None,
+            tags: None,
+            arrival_count_model: ArrivalCountModel::default(),
+            amount_granularity: None,
+            deadline_mode: DeadlineMode::default(),
+        }
+    }
+}
+
+impl ArrivalConfig {
+    /// Check the priority distribution is well-formed.
+    ///
+    /// Categorical `values` and `weights` must have equal length, weights
+    /// must be finite and non-negative with a positive sum, and fixed or
+    /// uniform priorities must lie in `0..=10` (uniform also needs
+    /// `min <= max`). Returns a description of the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        match &self.priority_distribution {
+            PriorityDistribution::Fixed { value } => {
+                if *value > 10 {
+                    return Err(format!("Fixed priority must be in 0..=10, got {}", value));
+                }
+            }
+            PriorityDistribution::Categorical { values, weights } => {
+                if values.len() != weights.len() {
+                    return Err(format!(
+                        "Categorical priority values ({}) and weights ({}) must have equal length",
+                        values.len(),
+                        weights.len()
+                    ));
+                }
+                if let Some(weight) = weights.iter().find(|w| !w.is_finite() || **w < 0.0) {
+                    return Err(format!(
+                        "Categorical priority weights must be finite and non-negative, got {}",
+                        weight
+                    ));
+                }
+                if weights.iter().sum::<f64>() <= 0.0 {
+                    return Err("Categorical priority weights must have a positive sum".to_string());
+                }
+            }
+            PriorityDistribution::Uniform { min, max } => {
+                if *max > 10 {
+                    return Err(format!(
+                        "Uniform priority range must be within 0..=10, got {}..={}",
+                        min, max
+                    ));
+                }
+                if min > max {
+                    return Err(format!(
+                        "Uniform priority min ({}) must be <= max ({})",
+                        min, max
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// How generated deadlines are expressed.
+///
+/// Either way the deadline is capped at episode end (and end of day when
+/// `deadline_cap_at_eod` is set) and is always at least `arrival + 1`.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum DeadlineMode {
+    /// Deadline is `arrival + offset`, offset drawn from `deadline_range`
+    #[default]
+    RelativeOffset,
+
+    /// Deadline is an absolute tick drawn uniformly from `[min, max]`
+    ///
+    /// Models a fixed cutoff such as a CLS deadline. Requires `min <= max`.
+    AbsoluteTick { min: usize, max: usize },
+}
+
+/// Distribution of the per-tick arrival count.
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+pub enum ArrivalCountModel {
+    /// Poisson with λ = `rate_per_tick` (variance equals mean)
+    #[default]
+    Poisson,
+
+    /// Negative binomial: failures before `r` successes with success probability `p`
+    ///
+    /// Mean is `r(1-p)/p` and variance `r(1-p)/p²`, so counts are
+    /// overdispersed. `rate_per_tick` is ignored; the intraday profile and
+    /// time scale still scale the mean. Requires `r > 0.0` and `0.0 < p < 1.0`.
+    NegativeBinomial { r: f64, p: f64 },
 }
 
 /// Amount distribution types for transaction generation.
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub enum AmountDistribution {
     /// Uniform distribution between min and max (inclusive)
     Uniform { min: i64, max: i64 },
@@ -95,6 +253,197 @@ pub enum AmountDistribution {
 
     /// Exponential distribution with rate parameter
     Exponential { rate: f64 },
+
+    /// Pareto distribution (power-law tail for the very largest payments)
+    ///
+    /// `scale` is the minimum amount in cents; smaller `shape` gives a
+    /// heavier tail. Requires `shape > 0.0`.
+    Pareto { scale: i64, shape: f64 },
+
+    /// Weibull distribution (amount in cents)
+    ///
+    /// `shape < 1` gives a heavy tail, `shape = 1` is exponential and larger
+    /// shapes concentrate around `scale`. Requires `shape > 0.0` and
+    /// `scale > 0.0`.
+    Weibull { shape: f64, scale: f64 },
+
+    /// Weighted mixture of other distributions (e.g. retail + wholesale)
+    ///
+    /// Each sample first picks a component with probability proportional to
+    /// its weight, then draws from that component. Weights must be finite
+    /// and non-negative with a positive sum.
+    Mixture {
+        components: Vec<(f64, Box<AmountDistribution>)>,
+    },
+}
+
+impl AmountDistribution {
+    /// Check the distribution parameters are well-formed.
+    ///
+    /// Pareto needs `shape > 0.0` and a positive `scale`, Weibull needs
+    /// positive `shape` and `scale`, and mixtures need valid weights and
+    /// valid components. Returns a description of the first problem found.
+    pub fn validate(&self) -> Result<(), String> {
+        match self {
+            AmountDistribution::Pareto { scale, shape } => {
+                if !shape.is_finite() || *shape <= 0.0 {
+                    return Err(format!("Pareto shape must be > 0.0, got {}", shape));
+                }
+                if *scale <= 0 {
+                    return Err(format!("Pareto scale must be positive, got {}", scale));
+                }
+            }
+            AmountDistribution::Weibull { shape, scale } => {
+                if !shape.is_finite() || *shape <= 0.0 {
+                    return Err(format!("Weibull shape must be > 0.0, got {}", shape));
+                }
+                if !scale.is_finite() || *scale <= 0.0 {
+                    return Err(format!("Weibull scale must be > 0.0, got {}", scale));
+                }
+            }
+            AmountDistribution::Mixture { components } => {
+                if let Some((weight, _)) =
+                    components.iter().find(|(w, _)| !w.is_finite() || *w < 0.0)
+                {
+                    return Err(format!(
+                        "Mixture weights must be finite and non-negative, got {}",
+                        weight
+                    ));
+                }
+                if components.iter().map(|(w, _)| w).sum::<f64>() <= 0.0 {
+                    return Err("Mixture weights must have a positive sum".to_string());
+                }
+                for (_, component) in components {
+                    component.validate()?;
+                }
+            }
+            _ => {}
+        }
+
+        Ok(())
+    }
+
+    /// Sample an amount (cents) from this distribution.
+    ///
+    /// Always returns a positive amount; granularity rounding is applied
+    /// separately by the arrival generator.
+    pub fn sample(&self, rng: &mut RngManager) -> i64 {
+        match self {
+            AmountDistribution::Uniform { min, max } => {
+                rng.range(*min, *max + 1) // +1 for inclusive range
+            }
+            AmountDistribution::Normal { mean, std_dev } => {
+                let z = rng.next_gaussian();
+                let raw = *mean as f64 + (*std_dev as f64) * z;
+                // CRITICAL: Guard against NaN/Inf before casting to i64
+                let amount = if raw.is_finite() && raw > 0.0 {
+                    raw.round().min(i64::MAX as f64) as i64
+                } else if raw.is_finite() {
+                    1 // Negative or zero → clamp to 1
+                } else {
+                    1 // NaN/Inf → default to 1
+                };
+                amount.max(1) // Ensure positive
+            }
+            AmountDistribution::LogNormal { mean, std_dev } => {
+                // CRITICAL FIX: Convert from moment parameters (mean, std_dev of AMOUNT)
+                // to log-space parameters (μ, σ of the underlying normal).
+                //
+                // If M = desired mean and S = desired stddev of the amount:
+                //   variance V = S²
+                //   σ² = ln(1 + V/M²)
+                //   μ = ln(M) - σ²/2
+                //
+                // Then: log_amount = μ + σ*z, amount = exp(log_amount)
+                let m = *mean;
+                let s = *std_dev;
+
+                // Guard against invalid parameters
+                if m <= 0.0 || s < 0.0 {
+                    return 1; // Invalid → default to 1
+                }
+
+                let variance = s * s;
+                let sigma_squared = (1.0 + variance / (m * m)).ln();
+                let sigma = sigma_squared.sqrt();
+                let mu = m.ln() - sigma_squared / 2.0;
+
+                let z = rng.next_gaussian();
+                let log_amount = mu + sigma * z;
+
+                // CRITICAL: Guard against NaN/Inf before casting to i64
+                let raw = log_amount.exp();
+                let amount = if raw.is_finite() && raw > 0.0 {
+                    raw.round().min(i64::MAX as f64) as i64
+                } else {
+                    m.round() as i64 // Fallback to mean if overflow
+                };
+                amount.max(1) // Ensure positive
+            }
+            AmountDistribution::Exponential { rate } => {
+                let u = rng.next_f64();
+                // Guard against u=0 which gives -Inf from ln
+                let u_safe = u.max(f64::MIN_POSITIVE);
+                let raw = -u_safe.ln() / rate;
+                // CRITICAL: Guard against NaN/Inf before casting to i64
+                let amount = if raw.is_finite() && raw > 0.0 {
+                    raw.round().min(i64::MAX as f64) as i64
+                } else {
+                    1 // Invalid → default to 1
+                };
+                amount.max(1) // Ensure positive
+            }
+            AmountDistribution::Pareto { scale, shape } => {
+                // Inverse transform: x = scale / u^(1/shape)
+                let u = rng.next_f64();
+                // Guard against u=0 which gives +Inf
+                let u_safe = u.max(f64::MIN_POSITIVE);
+                let raw = *scale as f64 / u_safe.powf(1.0 / shape);
+                // CRITICAL: Guard against NaN/Inf before casting to i64
+                let amount = if raw.is_finite() && raw > 0.0 {
+                    raw.floor().min(i64::MAX as f64) as i64
+                } else {
+                    *scale
+                };
+                amount.max(*scale).max(1) // Never below the scale
+            }
+            AmountDistribution::Weibull { shape, scale } => {
+                // Inverse transform: x = scale * (-ln(1-u))^(1/shape)
+                let u = rng.next_f64();
+                let raw = scale * (-(1.0 - u).ln()).powf(1.0 / shape);
+                // CRITICAL: Guard against NaN/Inf before casting to i64
+                let amount = if raw.is_finite() && raw > 0.0 {
+                    raw.floor().min(i64::MAX as f64) as i64
+                } else {
+                    1 // Invalid → default to 1
+                };
+                amount.max(1) // Ensure positive
+            }
+            AmountDistribution::Mixture { components } => {
+                match Self::select_mixture_component(components, rng) {
+                    Some(component) => component.sample(rng),
+                    None => 1, // Empty mixture → default to 1
+                }
+            }
+        }
+    }
+
+    /// Pick a mixture component with probability proportional to its weight.
+    ///
+    /// Consumes one draw via `RngManager::sample_weighted_index`; returns
+    /// None when no component has positive weight.
+    fn select_mixture_component<'a>(
+        components: &'a [(f64, Box<AmountDistribution>)],
+        rng: &mut RngManager,
+    ) -> Option<&'a AmountDistribution> {
+        let weights: Vec<f64> = components.iter().map(|(w, _)| w.max(0.0)).collect();
+        if weights.iter().sum::<f64>() <= 0.0 {
+            return None;
+        }
+
+        let idx = rng.sample_weighted_index(&weights);
+        Some(components[idx].1.as_ref())
+    }
 }
 
 // ============================================================================
@@ -127,6 +476,15 @@ pub struct ArrivalBandConfig {
     /// Whether transactions in this band are divisible
     #[serde(default)]
     pub divisible: bool,
+
+    /// Per-transaction deadline jitter (ticks)
+    ///
+    /// When set, a uniform perturbation in `[-jitter, +jitter]` is added to
+    /// each generated deadline before the episode/EOD caps, spreading out
+    /// deadlines that would otherwise cluster. The deadline still always
+    /// falls after the arrival tick. None (default) draws no jitter.
+    #[serde(default)]
+    pub deadline_jitter: Option<usize>,
 }
 
 /// Per-band arrival configuration with urgent, normal, and low priority bands.
@@ -189,6 +547,10 @@ pub struct ArrivalGenerator {
     band_configs: HashMap<String, ArrivalBandsConfig>,
 
     /// All agent IDs (for counterparty selection)
+    ///
+    /// Sorted, then shuffled with a stream derived from the simulation seed
+    /// (see `receiver_order`), so selection does not depend on the order the
+    /// IDs were passed in.
     all_agent_ids: Vec<String>,
 
     /// Next transaction ID counter
@@ -202,6 +564,42 @@ pub struct ArrivalGenerator {
 
     /// Whether to cap deadlines at end of current day
     deadline_cap_at_eod: bool,
+
+    /// Cap on arrivals per agent per tick (None = uncapped)
+    max_arrivals_per_tick: Option<usize>,
+
+    /// Group membership of agents (agent_id → agent_group)
+    agent_groups: HashMap<String, String>,
+
+    /// Multiplier applied to every arrival rate (1.0 = as configured)
+    time_scale: f64,
+
+    /// Truncations since the last `drain_clamps` call
+    clamps: Vec<ArrivalClamp>,
+}
+
+/// An agent's sampled arrival count that was truncated by the per-tick cap
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArrivalClamp {
+    pub agent_id: String,
+    pub tick: usize,
+    /// Count sampled before clamping (summed over bands)
+    pub sampled_count: usize,
+    /// Configured cap the count was clamped to
+    pub max_count: usize,
+}
+
+/// Stable, order-independent snapshot of potential counterparties
+///
+/// The IDs are sorted to remove any dependence on input order, then
+/// shuffled with the `receiver_order` sub-stream of `rng_seed` so
+/// index-based tie-breaking does not favour agents whose IDs sort first.
+fn receiver_order(mut agent_ids: Vec<String>, rng_seed: u64) -> Vec<String> {
+    agent_ids.sort();
+    RngManager::new(rng_seed)
+        .derive_stream("receiver_order")
+        .shuffle(&mut agent_ids);
+    agent_ids
 }
 
 impl ArrivalGenerator {
@@ -225,11 +623,15 @@ impl ArrivalGenerator {
             base_configs: configs.clone(), // Store original configs
             configs,
             band_configs: HashMap::new(), // No band configs in legacy mode
-            all_agent_ids,
+            all_agent_ids: receiver_order(all_agent_ids, 0),
             next_tx_id: 0,
             episode_end_tick,
             ticks_per_day,
             deadline_cap_at_eod,
+            max_arrivals_per_tick: None,
+            agent_groups: HashMap::new(),
+            time_scale: 1.0,
+            clamps: Vec::new(),
         }
     }
 
@@ -253,11 +655,15 @@ impl ArrivalGenerator {
             configs: HashMap::new(),
             base_configs: HashMap::new(),
             band_configs,
-            all_agent_ids,
+            all_agent_ids: receiver_order(all_agent_ids, 0),
             next_tx_id: 0,
             episode_end_tick,
             ticks_per_day,
             deadline_cap_at_eod,
+            max_arrivals_per_tick: None,
+            agent_groups: HashMap::new(),
+            time_scale: 1.0,
+            clamps: Vec::new(),
         }
     }
 
@@ -285,11 +691,75 @@ impl ArrivalGenerator {
             base_configs: legacy_configs.clone(),
             configs: legacy_configs,
             band_configs,
-            all_agent_ids,
+            all_agent_ids: receiver_order(all_agent_ids, 0),
             next_tx_id: 0,
             episode_end_tick,
             ticks_per_day,
             deadline_cap_at_eod,
+            max_arrivals_per_tick: None,
+            agent_groups: HashMap::new(),
+            time_scale: 1.0,
+            clamps: Vec::new(),
+        }
+    }
+
+    /// Cap the number of arrivals generated per agent per tick
+    ///
+    /// Bounds the work and allocation for a misconfigured high rate. Each
+    /// truncation is recorded and can be collected with `drain_clamps`.
+    pub fn with_max_arrivals_per_tick(mut self, max_arrivals: Option<usize>) -> Self {
+        self.max_arrivals_per_tick = max_arrivals;
+        self
+    }
+
+    /// Set agent group membership for group-weighted counterparty selection
+    ///
+    /// Agents absent from the map belong to no group.
+    pub fn with_agent_groups(mut self, agent_groups: HashMap<String, String>) -> Self {
+        self.agent_groups = agent_groups;
+        self
+    }
+
+    /// Shuffle the counterparty snapshot with a stream derived from `rng_seed`
+    ///
+    /// The orchestrator passes its `rng_seed`; generators built without
+    /// this use seed 0.
+    pub fn with_rng_seed(mut self, rng_seed: u64) -> Self {
+        self.all_agent_ids = receiver_order(self.all_agent_ids, rng_seed);
+        self
+    }
+
+    /// Scale every arrival rate by `time_scale`
+    ///
+    /// Applies to Poisson λ (legacy and per-band configs) and to the
+    /// negative binomial mixing rate, on top of any intraday profile.
+    pub fn with_time_scale(mut self, time_scale: f64) -> Self {
+        self.time_scale = time_scale;
+        self
+    }
+
+    /// Take the truncations recorded since the last call
+    pub fn drain_clamps(&mut self) -> Vec<ArrivalClamp> {
+        std::mem::take(&mut self.clamps)
+    }
+
+    /// Clamp `sampled` to the cap, less the `already_generated` arrivals
+    fn clamp_count(&self, sampled: usize, already_generated: usize) -> usize {
+        match self.max_arrivals_per_tick {
+            Some(cap) => sampled.min(cap.saturating_sub(already_generated)),
+            None => sampled,
+        }
+    }
+
+    /// Record a truncation if `sampled` exceeded the cap
+    fn record_clamp(&mut self, agent_id: &str, tick: usize, sampled: usize) {
+        if let Some(cap) = self.max_arrivals_per_tick.filter(|cap| sampled > *cap) {
+            self.clamps.push(ArrivalClamp {
+                agent_id: agent_id.to_string(),
+                tick,
+                sampled_count: sampled,
+                max_count: cap,
+            });
         }
     }
 
@@ -326,20 +796,43 @@ impl ArrivalGenerator {
             None => return Vec::new(), // No arrivals configured for this agent
         };
 
-        // Sample arrival count from Poisson distribution
-        let num_arrivals = rng.poisson(config.rate_per_tick);
+        // Sample arrival count from the configured model (scaled by intraday profile)
+        let sampled = match config.arrival_count_model {
+            ArrivalCountModel::Poisson => rng.poisson(self.effective_rate(config, tick)),
+            ArrivalCountModel::NegativeBinomial { r, p } => {
+                // Scaling the mean by the profile multiplier m keeps r and
+                // moves p to p / (p + m(1 - p)), so the variance scales too
+                let multiplier = self.rate_multiplier(config, tick);
+                let scaled_p = p / (p + multiplier * (1.0 - p));
+                rng.negative_binomial(r, scaled_p)
+            }
+        } as usize;
+        let num_arrivals = self.clamp_count(sampled, 0);
+        self.record_clamp(agent_id, tick, sampled);
 
-        let mut transactions = Vec::with_capacity(num_arrivals as usize);
+        let config = &self.configs[agent_id];
+        let mut transactions = Vec::with_capacity(num_arrivals);
 
         for _ in 0..num_arrivals {
             // Sample amount
-            let amount = self.sample_amount(&config.amount_distribution, rng);
+            let amount =
+                self.sample_amount(&config.amount_distribution, config.amount_granularity, rng);
 
             // Select receiver
-            let receiver = self.select_counterparty(agent_id, &config.counterparty_weights, rng);
+            let receiver = self
+                .select_group_counterparty(agent_id, &config.counterparty_group_weights, rng)
+                .unwrap_or_else(|| {
+                    self.select_counterparty(agent_id, &config.counterparty_weights, rng)
+                });
 
             // Generate deadline
-            let deadline = self.generate_deadline(tick, config.deadline_range, rng);
+            let deadline = self.generate_deadline(
+                tick,
+                config.deadline_range,
+                &config.deadline_mode,
+                None,
+                rng,
+            );
 
             // Sample priority from distribution
             let priority = self.sample_priority(&config.priority_distribution, rng);
@@ -348,7 +841,9 @@ impl ArrivalGenerator {
             let _tx_id = format!("tx_{:08}", self.next_tx_id);
             self.next_tx_id += 1;
 
-            let mut tx = Transaction::new(agent_id.to_string(), receiver, amount, tick, deadline);
+            let mut tx = Transaction::new(agent_id.to_string(), receiver, amount, tick, deadline)
+                .with_divisible(config.divisible)
+                .with_tags(config.tags.clone().unwrap_or_default());
 
             // Set priority
             if priority > 0 {
@@ -373,6 +868,7 @@ impl ArrivalGenerator {
         rng: &mut RngManager,
     ) -> Vec<Transaction> {
         let mut transactions = Vec::new();
+        let mut sampled = 0;
 
         // Generate from urgent band (priority 8-10)
         if let Some(ref band_config) = bands.urgent {
@@ -381,6 +877,8 @@ impl ArrivalGenerator {
                 tick,
                 band_config,
                 PriorityBand::Urgent,
+                transactions.len(),
+                &mut sampled,
                 rng,
             );
             transactions.extend(band_txs);
@@ -393,6 +891,8 @@ impl ArrivalGenerator {
                 tick,
                 band_config,
                 PriorityBand::Normal,
+                transactions.len(),
+                &mut sampled,
                 rng,
             );
             transactions.extend(band_txs);
@@ -405,40 +905,58 @@ impl ArrivalGenerator {
                 tick,
                 band_config,
                 PriorityBand::Low,
+                transactions.len(),
+                &mut sampled,
                 rng,
             );
             transactions.extend(band_txs);
         }
 
+        self.record_clamp(agent_id, tick, sampled);
         transactions
     }
 
     /// Generate arrivals from a single priority band.
+    ///
+    /// The per-tick cap is shared across bands: `already_generated` arrivals
+    /// from earlier bands count against it. The raw sampled count is added
+    /// to `sampled`.
+    #[allow(clippy::too_many_arguments)]
     fn generate_from_band(
         &mut self,
         agent_id: &str,
         tick: usize,
         band_config: &ArrivalBandConfig,
         band: PriorityBand,
+        already_generated: usize,
+        sampled: &mut usize,
         rng: &mut RngManager,
     ) -> Vec<Transaction> {
         // Sample arrival count from Poisson distribution
-        let num_arrivals = rng.poisson(band_config.rate_per_tick);
+        let band_sampled = rng.poisson(band_config.rate_per_tick * self.time_scale) as usize;
+        *sampled += band_sampled;
+        let num_arrivals = self.clamp_count(band_sampled, already_generated);
 
-        let mut transactions = Vec::with_capacity(num_arrivals as usize);
+        let mut transactions = Vec::with_capacity(num_arrivals);
 
         let (priority_min, priority_max) = band.priority_range();
 
         for _ in 0..num_arrivals {
             // Sample amount
-            let amount = self.sample_amount(&band_config.amount_distribution, rng);
+            let amount = self.sample_amount(&band_config.amount_distribution, None, rng);
 
             // Select receiver
             let receiver = self.select_counterparty(agent_id, &band_config.counterparty_weights, rng);
 
             // Generate deadline using band-specific offset range
             let deadline_range = (band_config.deadline_offset_min, band_config.deadline_offset_max);
-            let deadline = self.generate_deadline(tick, deadline_range, rng);
+            let deadline = self.generate_deadline(
+                tick,
+                deadline_range,
+                &DeadlineMode::RelativeOffset,
+                band_config.deadline_jitter,
+                rng,
+            );
 
             // Sample priority uniformly within band range
             let priority = if priority_min == priority_max {
@@ -451,12 +969,10 @@ impl ArrivalGenerator {
             // Create transaction
             self.next_tx_id += 1;
 
-            let mut tx = Transaction::new(agent_id.to_string(), receiver, amount, tick, deadline);
+            let mut tx = Transaction::new(agent_id.to_string(), receiver, amount, tick, deadline)
+                .with_divisible(band_config.divisible);
             tx = tx.with_priority(priority);
 
-            // Note: divisibility is tracked at the arrival config level but not on individual transactions
-            // The divisible flag in ArrivalBandConfig is reserved for future use
-
             transactions.push(tx);
         }
 
@@ -464,73 +980,54 @@ impl ArrivalGenerator {
     }
 
     /// Sample an amount from the configured distribution.
-    fn sample_amount(&self, distribution: &AmountDistribution, rng: &mut RngManager) -> i64 {
-        match distribution {
-            AmountDistribution::Uniform { min, max } => {
-                rng.range(*min, *max + 1) // +1 for inclusive range
-            }
-            AmountDistribution::Normal { mean, std_dev } => {
-                let z = self.sample_standard_normal(rng);
-                let raw = *mean as f64 + (*std_dev as f64) * z;
-                // CRITICAL: Guard against NaN/Inf before casting to i64
-                let amount = if raw.is_finite() && raw > 0.0 {
-                    raw.round().min(i64::MAX as f64) as i64
-                } else if raw.is_finite() {
-                    1 // Negative or zero → clamp to 1
-                } else {
-                    1 // NaN/Inf → default to 1
-                };
-                amount.max(1) // Ensure positive
-            }
-            AmountDistribution::LogNormal { mean, std_dev } => {
-                // CRITICAL FIX: Convert from moment parameters (mean, std_dev of AMOUNT)
-                // to log-space parameters (μ, σ of the underlying normal).
-                //
-                // If M = desired mean and S = desired stddev of the amount:
-                //   variance V = S²
-                //   σ² = ln(1 + V/M²)
-                //   μ = ln(M) - σ²/2
-                //
-                // Then: log_amount = μ + σ*z, amount = exp(log_amount)
-                let m = *mean;
-                let s = *std_dev;
-
-                // Guard against invalid parameters
-                if m <= 0.0 || s < 0.0 {
-                    return 1; // Invalid → default to 1
-                }
-
-                let variance = s * s;
-                let sigma_squared = (1.0 + variance / (m * m)).ln();
-                let sigma = sigma_squared.sqrt();
-                let mu = m.ln() - sigma_squared / 2.0;
+    fn sample_amount(
+        &self,
+        distribution: &AmountDistribution,
+        granularity: Option<i64>,
+        rng: &mut RngManager,
+    ) -> i64 {
+        let amount = distribution.sample(rng);
+        match granularity {
+            Some(unit) if unit > 1 => round_to_granularity(amount, unit),
+            _ => amount,
+        }
+    }
 
-                let z = self.sample_standard_normal(rng);
-                let log_amount = mu + sigma * z;
+    /// Select a counterparty by group: a group by weight, then a uniform member
+    ///
+    /// Returns None when no group weights are configured or no group has
+    /// positive weight, so the caller falls back to per-agent weights.
+    fn select_group_counterparty(
+        &self,
+        sender_id: &str,
+        group_weights: &HashMap<String, f64>,
+        rng: &mut RngManager,
+    ) -> Option<String> {
+        if group_weights.is_empty() {
+            return None;
+        }
 
-                // CRITICAL: Guard against NaN/Inf before casting to i64
-                let raw = log_amount.exp();
-                let amount = if raw.is_finite() && raw > 0.0 {
-                    raw.round().min(i64::MAX as f64) as i64
-                } else {
-                    m.round() as i64 // Fallback to mean if overflow
-                };
-                amount.max(1) // Ensure positive
-            }
-            AmountDistribution::Exponential { rate } => {
-                let u = rng.next_f64();
-                // Guard against u=0 which gives -Inf from ln
-                let u_safe = u.max(f64::MIN_POSITIVE);
-                let raw = -u_safe.ln() / rate;
-                // CRITICAL: Guard against NaN/Inf before casting to i64
-                let amount = if raw.is_finite() && raw > 0.0 {
-                    raw.round().min(i64::MAX as f64) as i64
-                } else {
-                    1 // Invalid → default to 1
-                };
-                amount.max(1) // Ensure positive
+        // Potential receivers grouped by membership (sender excluded)
+        let mut groups: BTreeMap<&str, Vec<&String>> = BTreeMap::new();
+        for id in self.all_agent_ids.iter().filter(|id| id.as_str() != sender_id) {
+            if let Some(group) = self.agent_groups.get(id) {
+                groups.entry(group.as_str()).or_default().push(id);
             }
         }
+
+        // Unlisted groups weigh 1.0
+        let weights: Vec<f64> = groups
+            .keys()
+            .map(|group| group_weights.get(*group).copied().unwrap_or(1.0).max(0.0))
+            .collect();
+        if weights.iter().sum::<f64>() <= 0.0 {
+            return None;
+        }
+
+        // Pick a group by weight, then a uniform member
+        let members = groups.values().nth(rng.sample_weighted_index(&weights))?;
+        let idx = rng.range(0, members.len() as i64) as usize;
+        Some(members[idx].clone())
     }
 
     /// Select a counterparty (receiver) based on weights.
@@ -557,24 +1054,14 @@ impl ArrivalGenerator {
             return potential_receivers[idx].clone();
         }
 
-        // Weighted selection
-        let total_weight: f64 = potential_receivers
+        // Weighted selection (unlisted receivers weigh 1.0)
+        let receiver_weights: Vec<f64> = potential_receivers
             .iter()
-            .map(|id| weights.get(id.as_str()).unwrap_or(&1.0))
-            .sum();
-
-        let mut target = rng.next_f64() * total_weight;
-
-        for receiver_id in &potential_receivers {
-            let weight = weights.get(receiver_id.as_str()).unwrap_or(&1.0);
-            target -= weight;
-            if target <= 0.0 {
-                return receiver_id.to_string();
-            }
-        }
+            .map(|id| weights.get(id.as_str()).copied().unwrap_or(1.0))
+            .collect();
 
-        // Fallback to last receiver (shouldn't reach here)
-        potential_receivers.last().unwrap().to_string()
+        let idx = rng.sample_weighted_index(&receiver_weights);
+        potential_receivers[idx].to_string()
     }
 
     /// Generate a deadline for the transaction.
@@ -585,15 +1072,38 @@ impl ArrivalGenerator {
     ///
     /// Finally, the deadline is guaranteed to be at least arrival_tick + 1
     /// to satisfy the Transaction invariant (deadline > arrival).
+    ///
+    /// `range` is an offset from arrival under `RelativeOffset`; under
+    /// `AbsoluteTick` the mode's own bounds are used instead.
+    ///
+    /// A non-zero `jitter` adds a uniform `[-jitter, +jitter]` perturbation
+    /// before capping (one extra draw); the arrival floor still applies.
     fn generate_deadline(
         &self,
         arrival_tick: usize,
         range: (usize, usize),
+        mode: &DeadlineMode,
+        jitter: Option<usize>,
         rng: &mut RngManager,
     ) -> usize {
-        let (min_offset, max_offset) = range;
-        let offset = rng.range(min_offset as i64, max_offset as i64 + 1) as usize;
-        let raw_deadline = arrival_tick + offset;
+        let raw_deadline = match mode {
+            DeadlineMode::RelativeOffset => {
+                let (min_offset, max_offset) = range;
+                let offset = rng.range(min_offset as i64, max_offset as i64 + 1) as usize;
+                arrival_tick + offset
+            }
+            DeadlineMode::AbsoluteTick { min, max } => {
+                rng.range(*min as i64, *max as i64 + 1) as usize
+            }
+        };
+
+        let raw_deadline = match jitter {
+            Some(jitter) if jitter > 0 => {
+                let delta = rng.range(-(jitter as i64), jitter as i64 + 1);
+                (raw_deadline as i64 + delta).max(0) as usize
+            }
+            _ => raw_deadline,
+        };
 
         // Cap deadline at episode end (Issue #6 fix)
         let episode_capped = raw_deadline.min(self.episode_end_tick);
@@ -613,11 +1123,24 @@ impl ArrivalGenerator {
         capped.max(arrival_tick + 1)
     }
 
-    /// Sample from standard normal distribution using Box-Muller transform.
-    fn sample_standard_normal(&self, rng: &mut RngManager) -> f64 {
-        let u1 = rng.next_f64();
-        let u2 = rng.next_f64();
-        (-2.0 * u1.ln()).sqrt() * (2.0 * std::f64::consts::PI * u2).cos()
+    /// Effective Poisson λ for a tick, applying the intraday profile if configured.
+    ///
+    /// Missing profile entries (length mismatch) fall back to a multiplier of 1.0.
+    fn effective_rate(&self, config: &ArrivalConfig, tick: usize) -> f64 {
+        config.rate_per_tick * self.rate_multiplier(config, tick)
+    }
+
+    /// Rate multiplier for a tick: time scale × intraday profile entry
+    /// (the profile contributes 1.0 when not configured).
+    fn rate_multiplier(&self, config: &ArrivalConfig, tick: usize) -> f64 {
+        let profile_multiplier = match &config.intraday_profile {
+            Some(profile) if self.ticks_per_day > 0 => profile
+                .get(tick % self.ticks_per_day)
+                .copied()
+                .unwrap_or(1.0),
+            _ => 1.0,
+        };
+        self.time_scale * profile_multiplier
     }
 
     /// Sample a priority from the configured distribution.
@@ -647,17 +1170,9 @@ impl ArrivalGenerator {
             return values[0]; // Fallback to first value
         }
 
-        let mut target = rng.next_f64() * total_weight;
-
-        for (i, weight) in weights.iter().enumerate() {
-            target -= weight;
-            if target <= 0.0 {
-                return values[i].min(10); // Cap at 10
-            }
-        }
-
-        // Fallback to last value
-        values.last().copied().unwrap_or(5).min(10)
+        let idx = rng.sample_weighted_index(weights);
+        // Fallback to last value if the weights outnumber the values
+        values.get(idx).or(values.last()).copied().unwrap_or(5).min(10) // Cap at 10
     }
 
     // ========================================================================
@@ -676,6 +1191,24 @@ impl ArrivalGenerator {
         })
     }
 
+    /// Get all effective counterparty weights for an agent
+    ///
+    /// Covers every potential receiver (all agents except the sender).
+    /// Receivers without an explicit weight report the 1.0 that weighted
+    /// selection uses for them. Returns None if the agent has no arrival config.
+    pub fn get_counterparty_weights(&self, agent_id: &str) -> Option<BTreeMap<String, f64>> {
+        self.configs.get(agent_id).map(|c| {
+            self.all_agent_ids
+                .iter()
+                .filter(|id| id.as_str() != agent_id)
+                .map(|id| {
+                    let weight = c.counterparty_weights.get(id).copied().unwrap_or(1.0);
+                    (id.clone(), weight)
+                })
+                .collect()
+        })
+    }
+
     /// Get deadline range for an agent
     pub fn get_deadline_range(&self, agent_id: &str) -> Option<(usize, usize)> {
         self.configs.get(agent_id).map(|c| c.deadline_range)
@@ -718,6 +1251,51 @@ impl ArrivalGenerator {
         }
     }
 
+    /// Set counterparty weight for an agent, rescaling the others
+    ///
+    /// The other counterparties' weights are scaled proportionally so the
+    /// agent's total weight is unchanged. If the others all weigh zero, the
+    /// remaining weight is split uniformly among them.
+    ///
+    /// The total is not preserved when the new weight exceeds the old total:
+    /// the others drop to zero and the total grows to the new weight. A
+    /// `counterparty` that is not a potential receiver (the agent itself or
+    /// an unknown ID) is ignored, leaving the weights unchanged.
+    pub fn set_counterparty_weight_balanced(
+        &mut self,
+        agent_id: &str,
+        counterparty: &str,
+        weight: f64,
+    ) {
+        let current = match self.get_counterparty_weights(agent_id) {
+            Some(weights) if weights.contains_key(counterparty) => weights,
+            _ => return,
+        };
+
+        let total: f64 = current.values().sum();
+        let others: Vec<(&String, f64)> = current
+            .iter()
+            .filter(|(id, _)| id.as_str() != counterparty)
+            .map(|(id, w)| (id, *w))
+            .collect();
+        let others_total: f64 = others.iter().map(|(_, w)| w).sum();
+        let remaining = (total - weight).max(0.0);
+
+        if let Some(config) = self.configs.get_mut(agent_id) {
+            for (id, old_weight) in &others {
+                let new_weight = if others_total > 0.0 {
+                    old_weight * remaining / others_total
+                } else {
+                    remaining / others.len() as f64
+                };
+                config.counterparty_weights.insert((*id).clone(), new_weight);
+            }
+            config
+                .counterparty_weights
+                .insert(counterparty.to_string(), weight);
+        }
+    }
+
     /// Set deadline range for an agent
     pub fn set_deadline_range(&mut self, agent_id: &str, range: (usize, usize)) {
         if let Some(config) = self.configs.get_mut(agent_id) {
@@ -726,6 +1304,20 @@ impl ArrivalGenerator {
     }
 }
 
+/// Round an amount to the nearest multiple of `unit`, ties to even.
+///
+/// The result is never below one `unit`.
+fn round_to_granularity(amount: i64, unit: i64) -> i64 {
+    let quotient = amount / unit;
+    let remainder = amount % unit;
+    let multiples = match (2 * remainder as i128).cmp(&(unit as i128)) {
+        std::cmp::Ordering::Greater => quotient + 1,
+        std::cmp::Ordering::Equal => quotient + (quotient & 1),
+        std::cmp::Ordering::Less => quotient,
+    };
+    multiples.max(1).saturating_mul(unit)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -742,6 +1334,7 @@ mod tests {
             deadline_range: (5, 15),
             priority_distribution: PriorityDistribution::Fixed { value: 5 },
             divisible: true,
+            ..Default::default()
         };
 
         assert_eq!(config.rate_per_tick, 2.0);
@@ -767,6 +1360,7 @@ mod tests {
                 deadline_range: (5, 15),
                 priority_distribution: PriorityDistribution::Fixed { value: 0 },
                 divisible: false,
+                ..Default::default()
             },
         );
 
@@ -791,6 +1385,7 @@ mod tests {
                 deadline_range: (5, 10),
                 priority_distribution: PriorityDistribution::Fixed { value: 0 },
                 divisible: false,
+                ..Default::default()
             },
         );
 
@@ -828,6 +1423,7 @@ mod tests {
             deadline_range: (5, 10),
             priority_distribution: PriorityDistribution::Fixed { value: 0 },
             divisible: false,
+            ..Default::default()
         };
 
         let all_agents = vec!["BANK_A".to_string(), "BANK_B".to_string()];
@@ -849,6 +1445,220 @@ mod tests {
         }
     }
 
+    fn generator_with(amount_distribution: AmountDistribution) -> ArrivalGenerator {
+        let config = ArrivalConfig {
+            rate_per_tick: 20.0,
+            amount_distribution,
+            counterparty_weights: HashMap::new(),
+            deadline_range: (5, 10),
+            priority_distribution: PriorityDistribution::Fixed { value: 0 },
+            divisible: false,
+            ..Default::default()
+        };
+
+        ArrivalGenerator::new(
+            vec![("BANK_A".to_string(), config)].into_iter().collect(),
+            vec!["BANK_A".to_string(), "BANK_B".to_string()],
+            1000, // Episode end tick
+            1000, // ticks_per_day
+            false, // deadline_cap_at_eod
+        )
+    }
+
+    #[test]
+    fn test_sampled_distributions_deterministic() {
+        let distributions = [
+            AmountDistribution::Pareto {
+                scale: 100_000,
+                shape: 1.5,
+            },
+            AmountDistribution::Weibull {
+                shape: 1.5,
+                scale: 50_000.0,
+            },
+            retail_wholesale_mixture(),
+        ];
+
+        for distribution in distributions {
+            let mut gen1 = generator_with(distribution.clone());
+            let mut gen2 = generator_with(distribution.clone());
+            let mut rng1 = RngManager::new(7);
+            let mut rng2 = RngManager::new(7);
+
+            for tick in 0..10 {
+                let a1: Vec<i64> = gen1
+                    .generate_for_agent("BANK_A", tick, &mut rng1)
+                    .iter()
+                    .map(|tx| tx.amount())
+                    .collect();
+                let a2: Vec<i64> = gen2
+                    .generate_for_agent("BANK_A", tick, &mut rng2)
+                    .iter()
+                    .map(|tx| tx.amount())
+                    .collect();
+                assert_eq!(a1, a2, "{:?} not deterministic", distribution);
+            }
+        }
+    }
+
+    #[test]
+    fn test_pareto_distribution_never_below_scale() {
+        let mut generator = generator_with(AmountDistribution::Pareto {
+            scale: 100_000,
+            shape: 1.2,
+        });
+        let mut rng = RngManager::new(42);
+
+        let amounts: Vec<i64> = (0..50)
+            .flat_map(|tick| generator.generate_for_agent("BANK_A", tick, &mut rng))
+            .map(|tx| tx.amount())
+            .collect();
+
+        assert!(!amounts.is_empty());
+        assert!(amounts.iter().all(|&a| a >= 100_000));
+        // Heavy tail: some draws land well above the scale
+        assert!(amounts.iter().any(|&a| a > 200_000));
+    }
+
+    #[test]
+    fn test_weibull_distribution_median() {
+        for (shape, scale) in [(0.7, 80_000.0), (2.0, 50_000.0)] {
+            let mut generator = generator_with(AmountDistribution::Weibull { shape, scale });
+            let mut rng = RngManager::new(42);
+
+            let mut amounts: Vec<i64> = (0..500)
+                .flat_map(|tick| generator.generate_for_agent("BANK_A", tick, &mut rng))
+                .map(|tx| tx.amount())
+                .collect();
+            amounts.sort_unstable();
+
+            assert!(amounts.len() > 5_000);
+            assert!(amounts.iter().all(|&a| a >= 1));
+
+            let median = amounts[amounts.len() / 2] as f64;
+            let expected = scale * std::f64::consts::LN_2.powf(1.0 / shape);
+            assert!(
+                (median - expected).abs() < 0.05 * expected,
+                "Weibull({}, {}) median {} too far from {}",
+                shape,
+                scale,
+                median,
+                expected
+            );
+        }
+    }
+
+    fn retail_wholesale_mixture() -> AmountDistribution {
+        AmountDistribution::Mixture {
+            components: vec![
+                (
+                    0.8,
+                    Box::new(AmountDistribution::Uniform {
+                        min: 1_000,
+                        max: 5_000,
+                    }),
+                ),
+                (
+                    0.2,
+                    Box::new(AmountDistribution::Uniform {
+                        min: 1_000_000,
+                        max: 5_000_000,
+                    }),
+                ),
+            ],
+        }
+    }
+
+    #[test]
+    fn test_mixture_distribution_spans_both_components() {
+        let mut generator = generator_with(retail_wholesale_mixture());
+        let mut rng = RngManager::new(42);
+
+        let amounts: Vec<i64> = (0..50)
+            .flat_map(|tick| generator.generate_for_agent("BANK_A", tick, &mut rng))
+            .map(|tx| tx.amount())
+            .collect();
+
+        let retail = amounts
+            .iter()
+            .filter(|&&a| (1_000..=5_000).contains(&a))
+            .count();
+        let wholesale = amounts
+            .iter()
+            .filter(|&&a| (1_000_000..=5_000_000).contains(&a))
+            .count();
+
+        // Every draw comes from one of the two components
+        assert_eq!(retail + wholesale, amounts.len());
+        assert!(retail > 0 && wholesale > 0);
+        // Roughly the configured 80/20 split
+        let wholesale_share = wholesale as f64 / amounts.len() as f64;
+        assert!(
+            (0.1..0.3).contains(&wholesale_share),
+            "wholesale share {} far from 0.2",
+            wholesale_share
+        );
+    }
+
+    #[test]
+    fn test_mixture_validation() {
+        assert!(retail_wholesale_mixture().validate().is_ok());
+
+        let negative = AmountDistribution::Mixture {
+            components: vec![(
+                -1.0,
+                Box::new(AmountDistribution::Exponential { rate: 0.1 }),
+            )],
+        };
+        assert!(negative.validate().unwrap_err().contains("non-negative"));
+
+        let zero_sum = AmountDistribution::Mixture {
+            components: vec![(0.0, Box::new(AmountDistribution::Exponential { rate: 0.1 }))],
+        };
+        assert!(zero_sum.validate().unwrap_err().contains("positive sum"));
+
+        let empty = AmountDistribution::Mixture { components: vec![] };
+        assert!(empty.validate().is_err());
+
+        // Invalid components are caught however deeply they are nested
+        let nested = AmountDistribution::Mixture {
+            components: vec![(
+                1.0,
+                Box::new(AmountDistribution::Mixture {
+                    components: vec![(
+                        1.0,
+                        Box::new(AmountDistribution::Pareto {
+                            scale: 1_000,
+                            shape: 0.0,
+                        }),
+                    )],
+                }),
+            )],
+        };
+        assert!(nested.validate().unwrap_err().contains("Pareto shape"));
+    }
+
+    #[test]
+    fn test_mixture_serde_round_trip() {
+        let json = serde_json::to_string(&retail_wholesale_mixture()).unwrap();
+        let parsed: AmountDistribution = serde_json::from_str(&json).unwrap();
+
+        match parsed {
+            AmountDistribution::Mixture { components } => {
+                assert_eq!(components.len(), 2);
+                assert_eq!(components[1].0, 0.2);
+                assert!(matches!(
+                    *components[1].1,
+                    AmountDistribution::Uniform {
+                        min: 1_000_000,
+                        max: 5_000_000
+                    }
+                ));
+            }
+            other => panic!("expected Mixture, got {:?}", other),
+        }
+    }
+
     #[test]
     fn test_no_self_transactions() {
         let config = ArrivalConfig {
@@ -861,6 +1671,7 @@ mod tests {
             deadline_range: (5, 10),
             priority_distribution: PriorityDistribution::Fixed { value: 0 },
             divisible: false,
+            ..Default::default()
         };
 
         let all_agents = vec![
@@ -898,6 +1709,7 @@ mod tests {
             deadline_range: (5, 15),
             priority_distribution: PriorityDistribution::Fixed { value: 0 },
             divisible: false,
+            ..Default::default()
         };
 
         let all_agents = vec!["BANK_A".to_string(), "BANK_B".to_string()];
@@ -936,6 +1748,7 @@ mod tests {
             deadline_range: (5, 10),
             priority_distribution: PriorityDistribution::Fixed { value: 0 },
             divisible: false,
+            ..Default::default()
         };
 
         let all_agents = vec![
@@ -971,4 +1784,185 @@ mod tests {
         assert!(bank_b_count > 0);
         assert!(bank_b_count > bank_c_count);
     }
+
+    fn profile_config(profile: Option<Vec<f64>>) -> ArrivalConfig {
+        ArrivalConfig {
+            rate_per_tick: 1.5,
+            amount_distribution: AmountDistribution::Uniform {
+                min: 1000,
+                max: 5000,
+            },
+            counterparty_weights: HashMap::new(),
+            deadline_range: (5, 10),
+            priority_distribution: PriorityDistribution::Fixed { value: 0 },
+            divisible: false,
+            intraday_profile: profile,
+            ..Default::default()
+        }
+    }
+
+    fn count_arrivals_per_tick(config: ArrivalConfig, ticks_per_day: usize, days: usize) -> Vec<usize> {
+        let all_agents = vec!["BANK_A".to_string(), "BANK_B".to_string()];
+        let mut generator = ArrivalGenerator::new(
+            vec![("BANK_A".to_string(), config)].into_iter().collect(),
+            all_agents,
+            ticks_per_day * days,
+            ticks_per_day,
+            false,
+        );
+        let mut rng = RngManager::new(7);
+
+        (0..ticks_per_day * days)
+            .map(|tick| generator.generate_for_agent("BANK_A", tick, &mut rng).len())
+            .collect()
+    }
+
+    #[test]
+    fn test_flat_intraday_profile_matches_constant_rate() {
+        let flat = count_arrivals_per_tick(profile_config(None), 10, 5);
+        let profiled = count_arrivals_per_tick(profile_config(Some(vec![1.0; 10])), 10, 5);
+
+        assert_eq!(flat, profiled);
+    }
+
+    #[test]
+    fn test_peaked_intraday_profile_concentrates_arrivals() {
+        // All activity in ticks 0-1 (morning) and 8-9 (end of day)
+        let profile = vec![4.0, 4.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 4.0, 4.0];
+        let counts = count_arrivals_per_tick(profile_config(Some(profile)), 10, 20);
+
+        let mut peak = 0;
+        let mut off_peak = 0;
+        for (tick, count) in counts.iter().enumerate() {
+            match tick % 10 {
+                0 | 1 | 8 | 9 => peak += count,
+                _ => off_peak += count,
+            }
+        }
+
+        assert_eq!(off_peak, 0, "zero-multiplier ticks must produce no arrivals");
+        assert!(peak > 0);
+    }
+
+    #[test]
+    fn test_round_to_granularity_ties_to_even() {
+        assert_eq!(round_to_granularity(1_249, 500), 1_000);
+        assert_eq!(round_to_granularity(1_251, 500), 1_500);
+        // Ties go to the even multiple
+        assert_eq!(round_to_granularity(1_250, 500), 1_000);
+        assert_eq!(round_to_granularity(1_750, 500), 2_000);
+        // Never rounds below one unit
+        assert_eq!(round_to_granularity(1, 500), 500);
+        assert_eq!(round_to_granularity(250, 500), 500);
+    }
+
+    #[test]
+    fn test_uniform_counterparty_selection_ignores_agent_order() {
+        let config = ArrivalConfig {
+            rate_per_tick: 5.0,
+            amount_distribution: AmountDistribution::Uniform {
+                min: 1000,
+                max: 10000,
+            },
+            counterparty_weights: HashMap::new(),
+            deadline_range: (5, 10),
+            priority_distribution: PriorityDistribution::Fixed { value: 0 },
+            divisible: false,
+            ..Default::default()
+        };
+
+        let receivers_for = |all_agents: Vec<&str>| {
+            let mut generator = ArrivalGenerator::new(
+                vec![("BANK_A".to_string(), config.clone())].into_iter().collect(),
+                all_agents.into_iter().map(String::from).collect(),
+                1000,
+                1000,
+                false,
+            );
+            let mut rng = RngManager::new(42);
+            let mut counts: std::collections::BTreeMap<String, usize> = Default::default();
+            let mut sequence = Vec::new();
+            for tick in 0..200 {
+                for tx in generator.generate_for_agent("BANK_A", tick, &mut rng) {
+                    *counts.entry(tx.receiver_id().to_string()).or_default() += 1;
+                    sequence.push(tx.receiver_id().to_string());
+                }
+            }
+            (counts, sequence)
+        };
+
+        let (counts, sequence) = receivers_for(vec!["BANK_A", "BANK_B", "BANK_C", "BANK_D"]);
+        let (reordered_counts, reordered_sequence) =
+            receivers_for(vec!["BANK_D", "BANK_C", "BANK_A", "BANK_B"]);
+
+        assert_eq!(counts, reordered_counts);
+        assert_eq!(sequence, reordered_sequence);
+        assert_eq!(counts.len(), 3);
+        assert!(!counts.contains_key("BANK_A"));
+    }
+
+    #[test]
+    fn test_receiver_order_derived_from_rng_seed() {
+        let agents: Vec<String> = (0..8).map(|i| format!("BANK_{}", i)).collect();
+        let generator = |rng_seed: u64| {
+            ArrivalGenerator::new(HashMap::new(), agents.clone(), 1000, 1000, false)
+                .with_rng_seed(rng_seed)
+        };
+
+        assert_eq!(generator(7).all_agent_ids, generator(7).all_agent_ids);
+        assert_ne!(generator(7).all_agent_ids, generator(8).all_agent_ids);
+
+        let mut sorted = generator(7).all_agent_ids;
+        sorted.sort();
+        assert_eq!(sorted, agents);
+    }
+
+    #[test]
+    fn test_weighted_selection_uses_sample_weighted_index_draw_order() {
+        // Mixture components and groups are picked with exactly the draws
+        // of `sample_weighted_index`, so seeded runs stay reproducible
+        let components = match retail_wholesale_mixture() {
+            AmountDistribution::Mixture { components } => components,
+            _ => unreachable!(),
+        };
+        let group_weights: HashMap<String, f64> =
+            [("core".to_string(), 3.0), ("periphery".to_string(), 1.0)].into();
+        let generator = ArrivalGenerator::new(
+            HashMap::new(),
+            ["A", "B", "C", "D"].iter().map(|id| id.to_string()).collect(),
+            1000,
+            1000,
+            false,
+        )
+        .with_agent_groups(
+            [("B", "core"), ("C", "core"), ("D", "periphery")]
+                .iter()
+                .map(|(id, group)| (id.to_string(), group.to_string()))
+                .collect(),
+        );
+
+        for seed in 0..50 {
+            let mut rng = RngManager::new(seed);
+            let mut expected = rng.clone();
+
+            let component = AmountDistribution::select_mixture_component(&components, &mut rng);
+            let idx = expected.sample_weighted_index(&[0.8, 0.2]);
+            assert!(std::ptr::eq(component.unwrap(), components[idx].1.as_ref()));
+
+            let receiver = generator
+                .select_group_counterparty("A", &group_weights, &mut rng)
+                .unwrap();
+            let group = ["core", "periphery"][expected.sample_weighted_index(&[3.0, 1.0])];
+            // Members in snapshot order
+            let members: Vec<&String> = generator
+                .all_agent_ids
+                .iter()
+                .filter(|id| generator.agent_groups.get(*id).map(String::as_str) == Some(group))
+                .collect();
+            let member = members[expected.range(0, members.len() as i64) as usize];
+            assert_eq!(&receiver, member);
+
+            assert_eq!(rng.next(), expected.next());
+        }
+    }
 }
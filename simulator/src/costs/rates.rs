@@ -138,6 +138,20 @@ impl PriorityDelayMultipliers {
     }
 }
 
+/// Basis on which collateral opportunity cost accrues
+///
+/// `Posted` charges the full pledged value; `PostHaircut` charges only the
+/// credit capacity the collateral actually provides after haircuts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CollateralCostBasis {
+    /// Raw posted collateral value (default)
+    #[default]
+    Posted,
+    /// Usable credit capacity after haircuts
+    PostHaircut,
+}
+
 /// Cost Rates Configuration
 ///
 /// Defines rates for various costs accrued during simulation.
@@ -168,15 +182,38 @@ pub struct CostRates {
     /// `Rate { bps_per_event }` (bps applied to transaction amount).
     pub deadline_penalty: PenaltyMode,
 
+    /// Additional penalty per tick of lateness, charged at settlement (cents)
+    ///
+    /// When a transaction fully settles after its deadline, the sender is
+    /// charged `deadline_penalty_per_tick_late × (settle_tick - deadline_tick)`
+    /// on top of the one-time `deadline_penalty`. 0 (default) keeps the
+    /// penalty flat. Split payments are charged per child, not again for the
+    /// parent.
+    ///
+    /// Example: with a 50,000 flat penalty and 1,000 per tick late, a
+    /// payment settling 4 ticks late costs 50,000 + 4 × 1,000 = 54,000 cents.
+    #[serde(default)]
+    pub deadline_penalty_per_tick_late: i64,
+
     /// Split friction cost per split (cents)
     ///
     /// When a transaction is split into N parts, the cost is:
-    /// split_friction_cost × (N-1)
+    /// split_friction_cost × (N-1)^split_friction_exponent
     ///
     /// This represents the operational overhead of creating and processing
     /// multiple payment instructions instead of a single instruction.
     pub split_friction_cost: i64,
 
+    /// Exponent applied to the number of splits (N-1) in the friction formula
+    ///
+    /// 1.0 (default) keeps friction linear in the number of splits; values
+    /// above 1.0 make many small pieces disproportionately expensive.
+    ///
+    /// Example: with split_friction_cost = 1,000 and exponent 2.0, splitting
+    /// into 4 parts costs 1,000 × 3² = 9,000 cents instead of 3,000.
+    #[serde(default = "default_split_friction_exponent")]
+    pub split_friction_exponent: f64,
+
     /// Multiplier for delay cost when transaction is overdue (default: 5.0)
     ///
     /// Overdue transactions incur escalating costs to represent urgency.
@@ -210,6 +247,60 @@ pub struct CostRates {
     /// (which is assumed to already be at the central bank).
     #[serde(default)]
     pub liquidity_cost_per_tick_bps: f64,
+
+    /// Tiered (progressive) overdraft rates
+    ///
+    /// Each entry is `(threshold_cents, bps_per_tick)`, sorted by ascending
+    /// threshold. The overdraft is decomposed across tiers: a tier's rate
+    /// applies to the slice between its threshold and the next tier's
+    /// threshold (the last tier is open-ended). Overdraft below the first
+    /// threshold is not charged, so the first tier normally starts at 0.
+    ///
+    /// Example: `[(0, 1.0), (1_000_000, 3.0)]` charges 1 bp/tick on the first
+    /// $10,000 of overdraft and 3 bp/tick on anything deeper.
+    ///
+    /// If None, `overdraft_bps_per_tick` applies flat to the whole overdraft.
+    #[serde(default)]
+    pub liquidity_cost_tiers: Option<Vec<(i64, f64)>>,
+
+    /// Overnight deposit rate in basis points, applied once per day boundary
+    ///
+    /// At end of day each agent's positive closing balance earns
+    /// `balance × overnight_deposit_rate / 10,000`. Earnings are recorded as a
+    /// negative `overnight_interest` cost, offsetting the agent's other costs.
+    ///
+    /// Example: 1.0 bp on a $1M closing balance = 100,000,000 × 1 / 10,000 = 10,000 cents
+    #[serde(default)]
+    pub overnight_deposit_rate: f64,
+
+    /// Overnight overdraft rate in basis points, applied once per day boundary
+    ///
+    /// At end of day each agent's negative closing balance is charged
+    /// `|balance| × overnight_overdraft_rate / 10,000`, recorded as a positive
+    /// `overnight_interest` cost.
+    #[serde(default)]
+    pub overnight_overdraft_rate: f64,
+
+    /// Value the collateral opportunity cost is charged on
+    ///
+    /// `Posted` (default) applies `collateral_cost_per_tick_bps` to the raw
+    /// posted amount; `PostHaircut` applies it to the haircut-adjusted
+    /// collateral capacity.
+    #[serde(default)]
+    pub collateral_cost_basis: CollateralCostBasis,
+
+    /// One-time fee per collateral post (cents)
+    ///
+    /// Operational cost of a posting, charged once each time a collateral
+    /// `Post` decision executes and recorded as `collateral_fee_cost`.
+    /// Separate from the per-tick opportunity cost; withdrawals are free.
+    #[serde(default)]
+    pub collateral_post_fee: i64,
+}
+
+/// Default split friction exponent (linear friction)
+fn default_split_friction_exponent() -> f64 {
+    1.0
 }
 
 impl Default for CostRates {
@@ -220,10 +311,177 @@ impl Default for CostRates {
             collateral_cost_per_tick_bps: 0.0002, // 2 bps annualized / 100 ticks
             eod_penalty: PenaltyMode::Fixed { amount: 10_000 },  // $100 per unsettled tx
             deadline_penalty: PenaltyMode::Fixed { amount: 50_000 },  // $500 per missed deadline
+            deadline_penalty_per_tick_late: 0,    // Flat deadline penalty by default
             split_friction_cost: 1000,            // $10 per split
+            split_friction_exponent: 1.0,         // Linear in number of splits
             overdue_delay_multiplier: 5.0,        // 5x multiplier for overdue
             priority_delay_multipliers: None,     // No priority differentiation by default
             liquidity_cost_per_tick_bps: 0.0,     // No liquidity opportunity cost by default
+            liquidity_cost_tiers: None,           // Flat overdraft rate by default
+            overnight_deposit_rate: 0.0,          // No overnight interest by default
+            overnight_overdraft_rate: 0.0,
+            collateral_cost_basis: CollateralCostBasis::Posted,
+            collateral_post_fee: 0,               // Posting is free by default
+        }
+    }
+}
+
+impl CostRates {
+    /// Rate fields that can be changed mid-simulation by name
+    pub const ADJUSTABLE_RATES: &'static [&'static str] = &[
+        "overdraft_bps_per_tick",
+        "delay_cost_per_tick_per_cent",
+        "collateral_cost_per_tick_bps",
+        "liquidity_cost_per_tick_bps",
+        "overdue_delay_multiplier",
+        "overnight_deposit_rate",
+        "overnight_overdraft_rate",
+    ];
+
+    /// Check that `field` is adjustable and `value` is a valid rate
+    pub fn validate_rate_change(field: &str, value: f64) -> Result<(), String> {
+        if !Self::ADJUSTABLE_RATES.contains(&field) {
+            return Err(format!(
+                "Unknown cost rate field '{}', expected one of: {}",
+                field,
+                Self::ADJUSTABLE_RATES.join(", ")
+            ));
+        }
+        if !value.is_finite() || value < 0.0 {
+            return Err(format!(
+                "Cost rate {} must be finite and non-negative, got {}",
+                field, value
+            ));
+        }
+        Ok(())
+    }
+
+    /// Set a rate field by name, returning its previous value
+    ///
+    /// Only the fields in `ADJUSTABLE_RATES` can be set; negative or
+    /// non-finite values are rejected.
+    pub fn set_rate(&mut self, field: &str, value: f64) -> Result<f64, String> {
+        Self::validate_rate_change(field, value)?;
+
+        let slot = match field {
+            "overdraft_bps_per_tick" => &mut self.overdraft_bps_per_tick,
+            "delay_cost_per_tick_per_cent" => &mut self.delay_cost_per_tick_per_cent,
+            "collateral_cost_per_tick_bps" => &mut self.collateral_cost_per_tick_bps,
+            "liquidity_cost_per_tick_bps" => &mut self.liquidity_cost_per_tick_bps,
+            "overdue_delay_multiplier" => &mut self.overdue_delay_multiplier,
+            "overnight_deposit_rate" => &mut self.overnight_deposit_rate,
+            "overnight_overdraft_rate" => &mut self.overnight_overdraft_rate,
+            _ => unreachable!("validated above"),
+        };
+        Ok(std::mem::replace(slot, value))
+    }
+
+    /// Split friction for splitting one transaction into `num_splits` parts
+    ///
+    /// `split_friction_cost × (N-1)^split_friction_exponent`, rounded to the
+    /// nearest cent. Zero when nothing is split (N ≤ 1). The default
+    /// exponent of 1.0 stays in integer arithmetic.
+    pub fn split_friction_for(&self, num_splits: usize) -> i64 {
+        if num_splits <= 1 {
+            return 0;
+        }
+        let extra_splits = num_splits - 1;
+        if self.split_friction_exponent == 1.0 {
+            return self.split_friction_cost * extra_splits as i64;
+        }
+        (self.split_friction_cost as f64 * (extra_splits as f64).powf(self.split_friction_exponent))
+            .round() as i64
+    }
+
+    /// Delay cost multiplier for a transaction's current priority
+    ///
+    /// Returns 1.0 when no priority multipliers are configured, or when the
+    /// configured multiplier is negative or non-finite.
+    pub fn delay_multiplier_for_priority(&self, priority: u8) -> f64 {
+        self.priority_delay_multipliers
+            .as_ref()
+            .map(|m| m.get_multiplier_for_priority(priority))
+            .filter(|mult| mult.is_finite() && *mult >= 0.0)
+            .unwrap_or(1.0)
+    }
+}
+
+/// Agent-specific cost rates, each falling back to the global rate
+///
+/// Only the fields set here differ from the global `CostRates`; the rest
+/// are taken from the global rates when the agent's rates are looked up,
+/// so mid-simulation `CostRateChange` events still reach fields the agent
+/// does not override.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CostRateOverrides {
+    pub overdraft_bps_per_tick: Option<f64>,
+    pub delay_cost_per_tick_per_cent: Option<f64>,
+    pub collateral_cost_per_tick_bps: Option<f64>,
+    pub eod_penalty: Option<PenaltyMode>,
+    pub deadline_penalty: Option<PenaltyMode>,
+    pub deadline_penalty_per_tick_late: Option<i64>,
+    pub split_friction_cost: Option<i64>,
+    pub split_friction_exponent: Option<f64>,
+    pub overdue_delay_multiplier: Option<f64>,
+    pub priority_delay_multipliers: Option<PriorityDelayMultipliers>,
+    pub liquidity_cost_per_tick_bps: Option<f64>,
+    pub liquidity_cost_tiers: Option<Vec<(i64, f64)>>,
+    pub overnight_deposit_rate: Option<f64>,
+    pub overnight_overdraft_rate: Option<f64>,
+    pub collateral_cost_basis: Option<CollateralCostBasis>,
+    pub collateral_post_fee: Option<i64>,
+}
+
+impl CostRateOverrides {
+    /// The global `base` rates with this agent's overrides applied
+    pub fn apply_to(&self, base: &CostRates) -> CostRates {
+        CostRates {
+            overdraft_bps_per_tick: self
+                .overdraft_bps_per_tick
+                .unwrap_or(base.overdraft_bps_per_tick),
+            delay_cost_per_tick_per_cent: self
+                .delay_cost_per_tick_per_cent
+                .unwrap_or(base.delay_cost_per_tick_per_cent),
+            collateral_cost_per_tick_bps: self
+                .collateral_cost_per_tick_bps
+                .unwrap_or(base.collateral_cost_per_tick_bps),
+            eod_penalty: self.eod_penalty.clone().unwrap_or_else(|| base.eod_penalty.clone()),
+            deadline_penalty: self
+                .deadline_penalty
+                .clone()
+                .unwrap_or_else(|| base.deadline_penalty.clone()),
+            deadline_penalty_per_tick_late: self
+                .deadline_penalty_per_tick_late
+                .unwrap_or(base.deadline_penalty_per_tick_late),
+            split_friction_cost: self.split_friction_cost.unwrap_or(base.split_friction_cost),
+            split_friction_exponent: self
+                .split_friction_exponent
+                .unwrap_or(base.split_friction_exponent),
+            overdue_delay_multiplier: self
+                .overdue_delay_multiplier
+                .unwrap_or(base.overdue_delay_multiplier),
+            priority_delay_multipliers: self
+                .priority_delay_multipliers
+                .clone()
+                .or_else(|| base.priority_delay_multipliers.clone()),
+            liquidity_cost_per_tick_bps: self
+                .liquidity_cost_per_tick_bps
+                .unwrap_or(base.liquidity_cost_per_tick_bps),
+            liquidity_cost_tiers: self
+                .liquidity_cost_tiers
+                .clone()
+                .or_else(|| base.liquidity_cost_tiers.clone()),
+            overnight_deposit_rate: self
+                .overnight_deposit_rate
+                .unwrap_or(base.overnight_deposit_rate),
+            overnight_overdraft_rate: self
+                .overnight_overdraft_rate
+                .unwrap_or(base.overnight_overdraft_rate),
+            collateral_cost_basis: self
+                .collateral_cost_basis
+                .unwrap_or(base.collateral_cost_basis),
+            collateral_post_fee: self.collateral_post_fee.unwrap_or(base.collateral_post_fee),
         }
     }
 }
@@ -244,6 +502,81 @@ mod tests {
         assert_eq!(rates.overdue_delay_multiplier, 5.0);
         assert!(rates.priority_delay_multipliers.is_none());
         assert_eq!(rates.liquidity_cost_per_tick_bps, 0.0);
+        assert!(rates.liquidity_cost_tiers.is_none());
+        assert_eq!(rates.overnight_deposit_rate, 0.0);
+        assert_eq!(rates.overnight_overdraft_rate, 0.0);
+        assert_eq!(rates.split_friction_exponent, 1.0);
+    }
+
+    #[test]
+    fn test_split_friction_for_exponent() {
+        let mut rates = CostRates {
+            split_friction_cost: 1_000,
+            ..CostRates::default()
+        };
+        assert_eq!(rates.split_friction_for(1), 0);
+        assert_eq!(rates.split_friction_for(2), 1_000);
+        assert_eq!(rates.split_friction_for(4), 3_000);
+
+        rates.split_friction_exponent = 2.0;
+        assert_eq!(rates.split_friction_for(2), 1_000);
+        assert_eq!(rates.split_friction_for(4), 9_000);
+
+        rates.split_friction_exponent = 1.5;
+        assert_eq!(rates.split_friction_for(3), 2_828); // 1000 × 2^1.5 = 2828.43
+    }
+
+    #[test]
+    fn test_cost_rate_overrides_fall_back_to_base() {
+        let overrides: CostRateOverrides =
+            serde_json::from_str(r#"{"overdraft_bps_per_tick": 0.005}"#).unwrap();
+        let base = CostRates {
+            delay_cost_per_tick_per_cent: 0.0003,
+            ..CostRates::default()
+        };
+
+        let rates = overrides.apply_to(&base);
+
+        assert_eq!(rates.overdraft_bps_per_tick, 0.005);
+        assert_eq!(rates.delay_cost_per_tick_per_cent, 0.0003);
+        assert_eq!(rates.eod_penalty, base.eod_penalty);
+        assert_eq!(rates.split_friction_cost, base.split_friction_cost);
+    }
+
+    #[test]
+    fn test_set_rate_returns_previous_value() {
+        let mut rates = CostRates::default();
+
+        let old = rates.set_rate("overdraft_bps_per_tick", 0.002).unwrap();
+
+        assert_eq!(old, 0.001);
+        assert_eq!(rates.overdraft_bps_per_tick, 0.002);
+    }
+
+    #[test]
+    fn test_set_rate_rejects_unknown_fields_and_negatives() {
+        let mut rates = CostRates::default();
+
+        assert!(rates.set_rate("split_friction_cost", 1.0).is_err());
+        assert!(rates.set_rate("no_such_rate", 1.0).is_err());
+        assert!(rates.set_rate("delay_cost_per_tick_per_cent", -0.1).is_err());
+        assert!(rates.set_rate("delay_cost_per_tick_per_cent", f64::NAN).is_err());
+        assert_eq!(rates.delay_cost_per_tick_per_cent, 0.0001);
+    }
+
+    #[test]
+    fn test_delay_multiplier_for_priority() {
+        let mut rates = CostRates::default();
+        assert_eq!(rates.delay_multiplier_for_priority(9), 1.0);
+
+        rates.priority_delay_multipliers = Some(PriorityDelayMultipliers {
+            urgent_multiplier: 3.0,
+            normal_multiplier: 1.0,
+            low_multiplier: f64::NAN,
+        });
+        assert_eq!(rates.delay_multiplier_for_priority(9), 3.0);
+        assert_eq!(rates.delay_multiplier_for_priority(5), 1.0);
+        assert_eq!(rates.delay_multiplier_for_priority(1), 1.0);
     }
 
     #[test]
@@ -231,7 +231,7 @@ impl CostSchemaDocumented for CostRates {
                 description: "Cost incurred when splitting a transaction into multiple parts. \
                     Represents operational overhead of processing multiple instructions.".to_string(),
                 incurred_at: "Once per split, when Split/StaggerSplit/PaceAndRelease action executes".to_string(),
-                formula: "split_friction_cost * (num_splits - 1)".to_string(),
+                formula: "split_friction_cost * (num_splits - 1)^split_friction_exponent".to_string(),
                 default_value: "1,000".to_string(),
                 unit: "cents per split".to_string(),
                 data_type: "i64".to_string(),
@@ -29,6 +29,26 @@ impl ScenarioEventHandler {
             .collect()
     }
 
+    /// Expand all schedules into `(tick, event)` pairs in `[from_tick, end_tick)`
+    ///
+    /// Sorted by tick; events at the same tick keep their configured
+    /// (execution) order.
+    pub fn pending_events(&self, from_tick: usize, end_tick: usize) -> Vec<(usize, &ScenarioEvent)> {
+        let mut pending: Vec<(usize, &ScenarioEvent)> = self
+            .events
+            .iter()
+            .flat_map(|scheduled| {
+                scheduled
+                    .schedule
+                    .ticks_in_range(from_tick, end_tick)
+                    .into_iter()
+                    .map(move |tick| (tick, &scheduled.event))
+            })
+            .collect();
+        pending.sort_by_key(|(tick, _)| *tick);
+        pending
+    }
+
     /// Execute all events scheduled for the given tick
     ///
     /// Returns Ok with number of events executed, or Err if any event fails
@@ -79,6 +99,35 @@ impl ScenarioEvent {
                 Err("ScheduledSettlement must be handled at Orchestrator level".to_string())
             }
 
+            // LiquidityInjection is handled at Orchestrator level (tracks injected total)
+            ScenarioEvent::LiquidityInjection { .. } => {
+                Err("LiquidityInjection must be handled at Orchestrator level".to_string())
+            }
+
+            // AgentSuspend/AgentResume are handled at Orchestrator level (emit typed events)
+            ScenarioEvent::AgentSuspend { .. } => {
+                Err("AgentSuspend must be handled at Orchestrator level".to_string())
+            }
+
+            ScenarioEvent::AgentResume { .. } => {
+                Err("AgentResume must be handled at Orchestrator level".to_string())
+            }
+
+            // CostRateChange is handled at Orchestrator level (mutates live cost rates)
+            ScenarioEvent::CostRateChange { .. } => {
+                Err("CostRateChange must be handled at Orchestrator level".to_string())
+            }
+
+            // ReallocateLiquidity is handled at Orchestrator level (needs the configured pool)
+            ScenarioEvent::ReallocateLiquidity { .. } => {
+                Err("ReallocateLiquidity must be handled at Orchestrator level".to_string())
+            }
+
+            // ArrivalBurst is handled at Orchestrator level (needs the RNG and arrival path)
+            ScenarioEvent::ArrivalBurst { .. } => {
+                Err("ArrivalBurst must be handled at Orchestrator level".to_string())
+            }
+
             // TODO: Implement these at Orchestrator level
             ScenarioEvent::GlobalArrivalRateChange { .. } => {
                 Err("GlobalArrivalRateChange not yet implemented".to_string())
@@ -209,4 +258,42 @@ mod tests {
         // Tick 11 should have no events
         assert_eq!(handler.get_events_for_tick(11).len(), 0);
     }
+
+    #[test]
+    fn test_pending_events_expands_and_sorts_schedules() {
+        let events = vec![
+            ScheduledEvent {
+                event: ScenarioEvent::AgentResume {
+                    agent: "A".to_string(),
+                },
+                schedule: EventSchedule::Repeating {
+                    start_tick: 0,
+                    interval: 4,
+                },
+            },
+            ScheduledEvent {
+                event: ScenarioEvent::LiquidityInjection {
+                    agent: "A".to_string(),
+                    amount: 100,
+                },
+                schedule: EventSchedule::OneTime { tick: 4 },
+            },
+        ];
+
+        let handler = ScenarioEventHandler::new(events);
+        let pending: Vec<(usize, &str)> = handler
+            .pending_events(1, 10)
+            .into_iter()
+            .map(|(tick, event)| (tick, event.type_name()))
+            .collect();
+
+        assert_eq!(
+            pending,
+            vec![
+                (4, "agent_resume"),
+                (4, "liquidity_injection"),
+                (8, "agent_resume"),
+            ]
+        );
+    }
 }
@@ -84,7 +84,11 @@ pub enum ScenarioEvent {
     /// Adjust counterparty weights for an agent
     ///
     /// Changes the probability of sending to a specific counterparty.
-    /// If auto_balance_others is true, other weights are adjusted proportionally.
+    /// If auto_balance_others is true, other weights are adjusted proportionally
+    /// so the agent's total weight is preserved, with two exceptions: a
+    /// `new_weight` above the old total drops the others to zero (the total
+    /// grows to `new_weight`), and a `counterparty` that is not a potential
+    /// receiver of `agent` leaves the weights unchanged.
     ///
     /// # Example
     /// Bank D increases transactions to Bank A from 20% to 50%
@@ -129,6 +133,99 @@ pub enum ScenarioEvent {
         to_agent: String,
         amount: i64, // Integer cents (INV-1)
     },
+
+    /// External liquidity injection (e.g., central bank emergency funding)
+    ///
+    /// Credits the agent's balance with no counterparty debit. This
+    /// intentionally breaks zero-sum balance conservation; the orchestrator
+    /// tracks a running injected total so checkpoints stay verifiable.
+    ///
+    /// # Example
+    /// Central bank injects $1M into Bank C at midday during a stress test
+    LiquidityInjection {
+        agent: String,
+        amount: i64, // Integer cents, must be non-negative
+    },
+
+    /// Suspend an agent (operational outage or default)
+    ///
+    /// While suspended the agent's policies are skipped and nothing is
+    /// released from its Queue 1. Payments already in Queue 2 stay there and
+    /// incoming payments still credit normally. If `resume_tick` is set the
+    /// agent resumes automatically at that tick.
+    ///
+    /// # Example
+    /// Bank B suffers an outage at tick 30 and recovers at tick 45
+    AgentSuspend {
+        agent: String,
+        resume_tick: Option<usize>,
+    },
+
+    /// Resume a suspended agent
+    ///
+    /// No-op if the agent is not suspended.
+    AgentResume { agent: String },
+
+    /// Change one of the live cost rates
+    ///
+    /// `field` names a rate in `CostRates::ADJUSTABLE_RATES` (overdraft,
+    /// delay, collateral, liquidity, overdue multiplier, overnight rates).
+    /// The new value applies from the tick the event fires onwards and must
+    /// be non-negative.
+    ///
+    /// # Example
+    /// Central bank doubles the overdraft rate at midday
+    CostRateChange { field: String, new_value: f64 },
+
+    /// Re-allocate an agent's liquidity pool at a new fraction
+    ///
+    /// Sets allocated liquidity to `floor(liquidity_pool × new_fraction)`
+    /// and credits (or debits) the balance by the change. Requires the agent
+    /// to have a `liquidity_pool`; `new_fraction` must be in `[0.0, 1.0]`.
+    /// The allocation is returned to the pool at end of day as usual.
+    ///
+    /// # Example
+    /// Bank A draws its whole pool into settlement at midday
+    ReallocateLiquidity { agent: String, new_fraction: f64 },
+
+    /// Inject a burst of `count` payments from one agent
+    ///
+    /// Amounts are drawn from `amount_distribution` and receivers uniformly
+    /// from the other agents, both via the simulation RNG, so a burst is
+    /// deterministic given the seed. Each payment goes through the normal
+    /// arrival path with deadline `tick + deadline_offset`.
+    ///
+    /// # Example
+    /// Stress test: Bank A receives 50 client payment orders at tick 20
+    ArrivalBurst {
+        agent: String,
+        count: usize,
+        amount_distribution: crate::arrivals::AmountDistribution,
+        deadline_offset: usize,
+        priority: u8,
+    },
+}
+
+impl ScenarioEvent {
+    /// Snake-case event type name, as used in configs and execution logs
+    pub fn type_name(&self) -> &'static str {
+        match self {
+            ScenarioEvent::DirectTransfer { .. } => "direct_transfer",
+            ScenarioEvent::CustomTransactionArrival { .. } => "custom_transaction_arrival",
+            ScenarioEvent::CollateralAdjustment { .. } => "collateral_adjustment",
+            ScenarioEvent::GlobalArrivalRateChange { .. } => "global_arrival_rate_change",
+            ScenarioEvent::AgentArrivalRateChange { .. } => "agent_arrival_rate_change",
+            ScenarioEvent::CounterpartyWeightChange { .. } => "counterparty_weight_change",
+            ScenarioEvent::DeadlineWindowChange { .. } => "deadline_window_change",
+            ScenarioEvent::ScheduledSettlement { .. } => "scheduled_settlement",
+            ScenarioEvent::LiquidityInjection { .. } => "liquidity_injection",
+            ScenarioEvent::AgentSuspend { .. } => "agent_suspend",
+            ScenarioEvent::AgentResume { .. } => "agent_resume",
+            ScenarioEvent::CostRateChange { .. } => "cost_rate_change",
+            ScenarioEvent::ReallocateLiquidity { .. } => "reallocate_liquidity",
+            ScenarioEvent::ArrivalBurst { .. } => "arrival_burst",
+        }
+    }
 }
 
 /// When to execute a scenario event
@@ -138,6 +235,18 @@ pub enum EventSchedule {
     /// Execute once at a specific tick
     OneTime { tick: usize },
 
+    /// Execute every `interval` ticks from `start_tick`, up to and including
+    /// `end_tick` when bounded
+    ///
+    /// `end_tick` must be present (possibly `null`) when deserializing, so
+    /// `{start_tick, interval}` still parses as `Repeating`.
+    Recurring {
+        start_tick: usize,
+        interval: usize,
+        #[serde(deserialize_with = "Option::deserialize")]
+        end_tick: Option<usize>,
+    },
+
     /// Execute at regular intervals starting from start_tick
     Repeating { start_tick: usize, interval: usize },
 }
@@ -150,6 +259,67 @@ impl EventSchedule {
             EventSchedule::Repeating { start_tick, interval } => {
                 tick >= *start_tick && (tick - start_tick) % interval == 0
             }
+            EventSchedule::Recurring {
+                start_tick,
+                interval,
+                end_tick,
+            } => {
+                end_tick.is_none_or(|end| tick <= end)
+                    && EventSchedule::Repeating {
+                        start_tick: *start_tick,
+                        interval: *interval,
+                    }
+                    .should_execute(tick)
+            }
+        }
+    }
+
+    /// Recurrence interval in ticks (`None` for one-time schedules)
+    pub fn interval(&self) -> Option<usize> {
+        match *self {
+            EventSchedule::OneTime { .. } => None,
+            EventSchedule::Repeating { interval, .. } | EventSchedule::Recurring { interval, .. } => {
+                Some(interval)
+            }
+        }
+    }
+
+    /// All ticks in `[from_tick, end_tick)` at which this schedule triggers
+    pub fn ticks_in_range(&self, from_tick: usize, end_tick: usize) -> Vec<usize> {
+        match *self {
+            EventSchedule::OneTime { tick } => {
+                if tick >= from_tick && tick < end_tick {
+                    vec![tick]
+                } else {
+                    Vec::new()
+                }
+            }
+            // Interval 0 is invalid; list the start tick once rather than panic
+            EventSchedule::Repeating { start_tick, interval: 0 } => {
+                EventSchedule::OneTime { tick: start_tick }.ticks_in_range(from_tick, end_tick)
+            }
+            EventSchedule::Repeating { start_tick, interval } => {
+                // First occurrence at or after from_tick
+                let first = if from_tick <= start_tick {
+                    start_tick
+                } else {
+                    start_tick + (from_tick - start_tick).div_ceil(interval) * interval
+                };
+                (first..end_tick).step_by(interval).collect()
+            }
+            EventSchedule::Recurring {
+                start_tick,
+                interval,
+                end_tick: last_tick,
+            } => {
+                // The schedule's end is inclusive, the range end exclusive
+                let end_tick = last_tick.map_or(end_tick, |last| end_tick.min(last + 1));
+                EventSchedule::Repeating {
+                    start_tick,
+                    interval,
+                }
+                .ticks_in_range(from_tick, end_tick)
+            }
         }
     }
 }
@@ -165,6 +335,80 @@ pub struct ScheduledEvent {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_ticks_in_range() {
+        let one_time = EventSchedule::OneTime { tick: 10 };
+        assert_eq!(one_time.ticks_in_range(0, 20), vec![10]);
+        assert!(one_time.ticks_in_range(11, 20).is_empty());
+        assert!(one_time.ticks_in_range(0, 10).is_empty());
+
+        let repeating = EventSchedule::Repeating { start_tick: 5, interval: 10 };
+        assert_eq!(repeating.ticks_in_range(0, 40), vec![5, 15, 25, 35]);
+        assert_eq!(repeating.ticks_in_range(15, 40), vec![15, 25, 35]);
+        assert_eq!(repeating.ticks_in_range(16, 35), vec![25]);
+    }
+
+    #[test]
+    fn test_recurring_schedule_stops_after_end_tick() {
+        let schedule = EventSchedule::Recurring {
+            start_tick: 10,
+            interval: 10,
+            end_tick: Some(30),
+        };
+
+        assert!(!schedule.should_execute(0));
+        assert!(schedule.should_execute(10));
+        assert!(!schedule.should_execute(15));
+        assert!(schedule.should_execute(30));
+        assert!(!schedule.should_execute(40));
+        assert_eq!(schedule.ticks_in_range(0, 100), vec![10, 20, 30]);
+    }
+
+    #[test]
+    fn test_unbounded_recurring_schedule() {
+        let schedule = EventSchedule::Recurring {
+            start_tick: 5,
+            interval: 10,
+            end_tick: None,
+        };
+
+        assert!(schedule.should_execute(995));
+        assert_eq!(schedule.ticks_in_range(0, 40), vec![5, 15, 25, 35]);
+    }
+
+    #[test]
+    fn test_schedule_deserialization_distinguishes_recurring() {
+        let repeating: EventSchedule =
+            serde_json::from_str(r#"{"start_tick": 0, "interval": 5}"#).unwrap();
+        assert_eq!(
+            repeating,
+            EventSchedule::Repeating {
+                start_tick: 0,
+                interval: 5
+            }
+        );
+
+        let recurring: EventSchedule =
+            serde_json::from_str(r#"{"start_tick": 0, "interval": 5, "end_tick": 20}"#).unwrap();
+        assert_eq!(
+            recurring,
+            EventSchedule::Recurring {
+                start_tick: 0,
+                interval: 5,
+                end_tick: Some(20)
+            }
+        );
+
+        // Unbounded recurring schedules round-trip via an explicit null
+        let unbounded = EventSchedule::Recurring {
+            start_tick: 0,
+            interval: 5,
+            end_tick: None,
+        };
+        let json = serde_json::to_string(&unbounded).unwrap();
+        assert_eq!(serde_json::from_str::<EventSchedule>(&json).unwrap(), unbounded);
+    }
+
     #[test]
     fn test_one_time_schedule() {
         let schedule = EventSchedule::OneTime { tick: 10 };
@@ -7,9 +7,10 @@ use pyo3::types::{PyDict, PyList};
 
 use super::types::{
     agent_metrics_to_py, collateral_event_to_py, parse_orchestrator_config, policy_config_to_py,
-    tick_result_to_py, transaction_to_py,
+    system_metrics_to_py, tick_result_to_py, tick_summary_to_py, transaction_to_py,
 };
 use crate::orchestrator::Orchestrator as RustOrchestrator;
+use crate::SimulationError;
 
 /// Python wrapper for Rust Orchestrator
 ///
@@ -83,6 +84,11 @@ fn event_to_py_dict<'py>(
             dict.set_item("priority", priority)?;
             dict.set_item("is_divisible", is_divisible)?;
         }
+        crate::models::event::Event::ArrivalsClamped { agent_id, sampled_count, max_count, .. } => {
+            dict.set_item("agent_id", agent_id)?;
+            dict.set_item("sampled_count", sampled_count)?;
+            dict.set_item("max_count", max_count)?;
+        }
         crate::models::event::Event::PolicySubmit { agent_id, tx_id, .. } => {
             dict.set_item("agent_id", agent_id)?;
             dict.set_item("tx_id", tx_id)?;
@@ -157,21 +163,23 @@ fn event_to_py_dict<'py>(
             dict.set_item("reason", reason)?;
             dict.set_item("decision_path", decision_path)?;
         }
-        crate::models::event::Event::RtgsImmediateSettlement { tx_id, sender, receiver, amount, sender_balance_before, sender_balance_after, .. } => {
+        crate::models::event::Event::RtgsImmediateSettlement { tx_id, sender, receiver, amount, sender_balance_before, sender_balance_after, settlement_mechanism, .. } => {
             dict.set_item("tx_id", tx_id)?;
             dict.set_item("sender", sender)?;
             dict.set_item("receiver", receiver)?;
             dict.set_item("amount", amount)?;
             dict.set_item("sender_balance_before", sender_balance_before)?;
             dict.set_item("sender_balance_after", sender_balance_after)?;
+            dict.set_item("settlement_mechanism", settlement_mechanism.to_string())?;
         }
-        crate::models::event::Event::Queue2LiquidityRelease { tx_id, sender, receiver, amount, queue_wait_ticks, release_reason, .. } => {
+        crate::models::event::Event::Queue2LiquidityRelease { tx_id, sender, receiver, amount, queue_wait_ticks, release_reason, settlement_mechanism, .. } => {
             dict.set_item("tx_id", tx_id)?;
             dict.set_item("sender", sender)?;
             dict.set_item("receiver", receiver)?;
             dict.set_item("amount", amount)?;
             dict.set_item("queue_wait_ticks", queue_wait_ticks)?;
             dict.set_item("release_reason", release_reason)?;
+            dict.set_item("settlement_mechanism", settlement_mechanism.to_string())?;
         }
         crate::models::event::Event::BilateralLimitExceeded { sender, receiver, tx_id, amount, current_bilateral_outflow, bilateral_limit, .. } => {
             dict.set_item("sender", sender)?;
@@ -207,6 +215,12 @@ fn event_to_py_dict<'py>(
             dict.set_item("tx_id", tx_id)?;
             dict.set_item("sender_id", sender_id)?;
         }
+        crate::models::event::Event::RtgsQueueFull { tx_id, sender_id, queue_size, capacity, .. } => {
+            dict.set_item("tx_id", tx_id)?;
+            dict.set_item("sender_id", sender_id)?;
+            dict.set_item("queue_size", queue_size)?;
+            dict.set_item("capacity", capacity)?;
+        }
         crate::models::event::Event::RtgsSubmission { tx_id, sender, receiver, amount, internal_priority, rtgs_priority, .. } => {
             dict.set_item("tx_id", tx_id)?;
             dict.set_item("sender", sender)?;
@@ -220,13 +234,27 @@ fn event_to_py_dict<'py>(
             dict.set_item("sender", sender)?;
             dict.set_item("original_rtgs_priority", original_rtgs_priority)?;
         }
+        crate::models::event::Event::TransactionCancelled { tx_id, sender_id, receiver_id, remaining_amount, queue, .. } => {
+            dict.set_item("tx_id", tx_id)?;
+            dict.set_item("sender_id", sender_id)?;
+            dict.set_item("receiver_id", receiver_id)?;
+            dict.set_item("remaining_amount", remaining_amount)?;
+            dict.set_item("queue", queue)?;
+        }
+        crate::models::event::Event::SettlementAbandoned { tx_id, sender_id, receiver_id, remaining_amount, retry_count, .. } => {
+            dict.set_item("tx_id", tx_id)?;
+            dict.set_item("sender_id", sender_id)?;
+            dict.set_item("receiver_id", receiver_id)?;
+            dict.set_item("remaining_amount", remaining_amount)?;
+            dict.set_item("retry_count", retry_count)?;
+        }
         crate::models::event::Event::RtgsResubmission { tx_id, sender, old_rtgs_priority, new_rtgs_priority, .. } => {
             dict.set_item("tx_id", tx_id)?;
             dict.set_item("sender", sender)?;
             dict.set_item("old_rtgs_priority", old_rtgs_priority)?;
             dict.set_item("new_rtgs_priority", new_rtgs_priority)?;
         }
-        crate::models::event::Event::LsmBilateralOffset { agent_a, agent_b, tx_ids, amount_a, amount_b, .. } => {
+        crate::models::event::Event::LsmBilateralOffset { agent_a, agent_b, tx_ids, amount_a, amount_b, settlement_mechanism, .. } => {
             dict.set_item("agent_a", agent_a)?;
             dict.set_item("agent_b", agent_b)?;
             dict.set_item("tx_ids", tx_ids)?;
@@ -234,8 +262,9 @@ fn event_to_py_dict<'py>(
             dict.set_item("amount_b", amount_b)?;
             // Also set "amount" for backward compatibility (sum of the two)
             dict.set_item("amount", amount_a + amount_b)?;
+            dict.set_item("settlement_mechanism", settlement_mechanism.to_string())?;
         }
-        crate::models::event::Event::LsmCycleSettlement { agents, tx_amounts, total_value, net_positions, max_net_outflow, max_net_outflow_agent, tx_ids, .. } => {
+        crate::models::event::Event::LsmCycleSettlement { agents, tx_amounts, total_value, net_positions, max_net_outflow, max_net_outflow_agent, tx_ids, settlement_mechanism, .. } => {
             dict.set_item("agents", agents)?;
             dict.set_item("tx_amounts", tx_amounts)?;
             dict.set_item("total_value", total_value)?;
@@ -245,6 +274,15 @@ fn event_to_py_dict<'py>(
             dict.set_item("tx_ids", tx_ids)?;
             // Backward compatibility: also set "cycle_value"
             dict.set_item("cycle_value", total_value)?;
+            dict.set_item("settlement_mechanism", settlement_mechanism.to_string())?;
+        }
+        crate::models::event::Event::LsmNettingSettlement { agents, removed_agents, total_value, net_positions, tx_ids, settlement_mechanism, .. } => {
+            dict.set_item("agents", agents)?;
+            dict.set_item("removed_agents", removed_agents)?;
+            dict.set_item("total_value", total_value)?;
+            dict.set_item("net_positions", net_positions)?;
+            dict.set_item("tx_ids", tx_ids)?;
+            dict.set_item("settlement_mechanism", settlement_mechanism.to_string())?;
         }
         crate::models::event::Event::CostAccrual { agent_id, costs, .. } => {
             dict.set_item("agent_id", agent_id)?;
@@ -255,6 +293,8 @@ fn event_to_py_dict<'py>(
             cost_dict.set_item("collateral_cost", costs.collateral_cost)?;
             cost_dict.set_item("penalty_cost", costs.penalty_cost)?;
             cost_dict.set_item("split_friction_cost", costs.split_friction_cost)?;
+            cost_dict.set_item("overnight_interest", costs.overnight_interest)?;
+            cost_dict.set_item("collateral_fee_cost", costs.collateral_fee_cost)?;
             cost_dict.set_item("total", costs.total())?;
             dict.set_item("costs", cost_dict)?;
         }
@@ -292,6 +332,19 @@ fn event_to_py_dict<'py>(
             dict.set_item("deadline_penalty_cost", deadline_penalty_cost)?;
             dict.set_item("estimated_delay_cost", estimated_delay_cost)?;
         }
+        crate::models::event::Event::ForcedSettlement {
+            tx_id, sender_id, receiver_id, amount, deadline_tick,
+            sender_balance_before, sender_balance_after, queue, ..
+        } => {
+            dict.set_item("tx_id", tx_id)?;
+            dict.set_item("sender_id", sender_id)?;
+            dict.set_item("receiver_id", receiver_id)?;
+            dict.set_item("amount", amount)?;
+            dict.set_item("deadline_tick", deadline_tick)?;
+            dict.set_item("sender_balance_before", sender_balance_before)?;
+            dict.set_item("sender_balance_after", sender_balance_after)?;
+            dict.set_item("queue", queue)?;
+        }
         crate::models::event::Event::RtgsQueue2Settle { tx_id, sender, receiver, amount, reason, .. } => {
             dict.set_item("tx_id", tx_id)?;
             dict.set_item("sender", sender)?;
@@ -324,6 +377,48 @@ fn event_to_py_dict<'py>(
             dict.set_item("amount", amount)?;
             dict.set_item("balance_after", balance_after)?;
         }
+        crate::models::event::Event::PartialSettlement { tx_id, sender, receiver, settled_amount, remaining_amount, .. } => {
+            dict.set_item("tx_id", tx_id)?;
+            dict.set_item("sender", sender)?;
+            dict.set_item("receiver", receiver)?;
+            dict.set_item("settled_amount", settled_amount)?;
+            dict.set_item("remaining_amount", remaining_amount)?;
+        }
+        crate::models::event::Event::LiquidityInjection { agent_id, amount, balance_after, .. } => {
+            dict.set_item("agent_id", agent_id)?;
+            dict.set_item("amount", amount)?;
+            dict.set_item("balance_after", balance_after)?;
+        }
+        crate::models::event::Event::ScheduledReleaseTriggered { agent_id, tx_id, amount, scheduled_tick, .. } => {
+            dict.set_item("agent_id", agent_id)?;
+            dict.set_item("tx_id", tx_id)?;
+            dict.set_item("amount", amount)?;
+            dict.set_item("scheduled_tick", scheduled_tick)?;
+        }
+        crate::models::event::Event::EodForceRelease { agent_id, tx_id, amount, .. } => {
+            dict.set_item("agent_id", agent_id)?;
+            dict.set_item("tx_id", tx_id)?;
+            dict.set_item("amount", amount)?;
+        }
+        crate::models::event::Event::AgentSuspended { agent_id, resume_tick, .. } => {
+            dict.set_item("agent_id", agent_id)?;
+            dict.set_item("resume_tick", resume_tick)?;
+        }
+        crate::models::event::Event::AgentResumed { agent_id, .. } => {
+            dict.set_item("agent_id", agent_id)?;
+        }
+        crate::models::event::Event::CostRateChanged { field, old_value, new_value, .. } => {
+            dict.set_item("field", field)?;
+            dict.set_item("old_value", old_value)?;
+            dict.set_item("new_value", new_value)?;
+        }
+        crate::models::event::Event::LiquidityReallocated { agent_id, new_fraction, old_allocation, new_allocation, balance_after, .. } => {
+            dict.set_item("agent_id", agent_id)?;
+            dict.set_item("new_fraction", new_fraction)?;
+            dict.set_item("old_allocation", old_allocation)?;
+            dict.set_item("new_allocation", new_allocation)?;
+            dict.set_item("balance_after", balance_after)?;
+        }
     }
 
     Ok(dict)
@@ -361,6 +456,31 @@ impl PyOrchestrator {
         Ok(PyOrchestrator { inner })
     }
 
+    /// Create a fresh orchestrator from this one's config with a new seed
+    ///
+    /// Only the RNG seed differs; the new instance starts at tick 0
+    /// regardless of how far this one has run.
+    ///
+    /// # Arguments
+    ///
+    /// * `seed` - RNG seed for the replication
+    ///
+    /// # Returns
+    ///
+    /// New Orchestrator instance
+    fn with_seed(&self, seed: u64) -> PyResult<PyOrchestrator> {
+        let rust_config = self.inner.config().clone().with_seed(seed);
+
+        let inner = RustOrchestrator::new(rust_config).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                "Failed to create orchestrator: {}",
+                e
+            ))
+        })?;
+
+        Ok(PyOrchestrator { inner })
+    }
+
     /// Execute one simulation tick
     ///
     /// Runs the complete 9-step tick loop:
@@ -398,6 +518,58 @@ impl PyOrchestrator {
         tick_result_to_py(py, &result)
     }
 
+    /// Execute N ticks in a single call
+    ///
+    /// Avoids per-tick FFI overhead for long runs. The tick loop runs with the
+    /// GIL released; execution stops at the first failing tick.
+    ///
+    /// # Arguments
+    ///
+    /// * `num_ticks` - Number of ticks to execute
+    ///
+    /// # Returns
+    ///
+    /// List of dicts, one per tick, with keys `tick`, `num_arrivals`,
+    /// `num_settlements`, `num_lsm_releases` and `total_cost` (no timing data)
+    fn run_ticks(&mut self, py: Python, num_ticks: usize) -> PyResult<Py<PyList>> {
+        let results = py
+            .allow_threads(|| self.inner.run_ticks(num_ticks))
+            .map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                    "Tick execution failed: {}",
+                    e
+                ))
+            })?;
+
+        let py_list = PyList::empty(py);
+        for result in &results {
+            py_list.append(tick_summary_to_py(py, result)?)?;
+        }
+
+        Ok(py_list.into())
+    }
+
+    /// Get the most recent tick results
+    ///
+    /// The orchestrator keeps a ring buffer of recent `TickResult`s so
+    /// dashboards can poll progress without re-querying full state.
+    ///
+    /// # Arguments
+    ///
+    /// * `n` - Maximum number of results to return
+    ///
+    /// # Returns
+    ///
+    /// List of tick result dicts (same format as `tick()`), oldest first
+    fn recent_tick_results(&self, py: Python, n: usize) -> PyResult<Py<PyList>> {
+        let py_list = PyList::empty(py);
+        for result in &self.inner.recent_tick_results(n) {
+            py_list.append(tick_result_to_py(py, result)?)?;
+        }
+
+        Ok(py_list.into())
+    }
+
     /// Run N ticks with GIL released and return one agent's total accumulated cost.
     ///
     /// This is the "bootstrap fast path" — releases the Python GIL during
@@ -528,6 +700,14 @@ impl PyOrchestrator {
                         "split_friction_cost".into(),
                         serde_json::Value::Number(costs.total_split_friction_cost.into()),
                     );
+                    entry.insert(
+                        "overnight_interest".into(),
+                        serde_json::Value::Number(costs.total_overnight_interest.into()),
+                    );
+                    entry.insert(
+                        "collateral_fee_cost".into(),
+                        serde_json::Value::Number(costs.total_collateral_fee_cost.into()),
+                    );
                     map.insert(aid.clone(), serde_json::Value::Object(entry));
                 }
             }
@@ -548,6 +728,26 @@ impl PyOrchestrator {
         self.inner.current_day()
     }
 
+    /// Get configured ticks per day
+    fn ticks_per_day(&self) -> usize {
+        self.inner.config().ticks_per_day
+    }
+
+    /// Get configured number of simulated days
+    fn num_days(&self) -> usize {
+        self.inner.config().num_days
+    }
+
+    /// Get the RNG seed the simulation was constructed with
+    fn rng_seed(&self) -> u64 {
+        self.inner.config().rng_seed
+    }
+
+    /// Get number of agents in the configuration
+    fn num_agents(&self) -> usize {
+        self.inner.config().agent_configs.len()
+    }
+
     // ========================================================================
     // State Query Methods (Phase 7)
     // ========================================================================
@@ -658,12 +858,15 @@ impl PyOrchestrator {
         let dict = PyDict::new(py);
         // Basic fields
         dict.set_item("balance", agent.balance())?;
+        dict.set_item("provisional_balance", agent.provisional_balance())?;
         dict.set_item("available_liquidity", agent.available_liquidity())?;
+        dict.set_item("reserve_requirement", agent.reserve_requirement())?;
         dict.set_item("queue1_size", agent.outgoing_queue().len())?;
 
         // Collateral fields
         dict.set_item("posted_collateral", agent.posted_collateral())?;
         dict.set_item("collateral_haircut", agent.collateral_haircut())?;
+        dict.set_item("collateral_classes", agent.collateral_classes().to_vec())?;
         dict.set_item("unsecured_cap", agent.unsecured_cap())?;
 
         // New T2/CLM-style collateral/headroom metrics
@@ -722,6 +925,65 @@ impl PyOrchestrator {
         Ok(dict.into())
     }
 
+    /// Get agent's limit utilization for the current day (Phase 1: TARGET2 LSM)
+    ///
+    /// Utilization is today's outflow divided by the limit (1.0 = exhausted).
+    ///
+    /// # Arguments
+    /// * `agent_id` - Agent identifier (e.g., "BANK_A")
+    ///
+    /// # Returns
+    /// Dictionary with:
+    /// - bilateral_utilization: Dict[str, float] (counterparty_id -> fraction used)
+    /// - multilateral_utilization: Optional[float] (None if no multilateral limit)
+    fn get_agent_limit_utilization(&self, py: Python, agent_id: &str) -> PyResult<Py<PyDict>> {
+        let utilization = self.inner.get_agent_limit_utilization(agent_id)
+            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                format!("Agent '{}' not found", agent_id)
+            ))?;
+
+        let dict = PyDict::new(py);
+
+        let bilateral_dict = PyDict::new(py);
+        for (counterparty, fraction) in &utilization.bilateral {
+            bilateral_dict.set_item(counterparty, *fraction)?;
+        }
+        dict.set_item("bilateral_utilization", bilateral_dict)?;
+        dict.set_item("multilateral_utilization", utilization.multilateral)?;
+
+        Ok(dict.into())
+    }
+
+    /// Get agent's policy state registers
+    ///
+    /// Returns the `bank_state_*` registers written by `SetState`/`AddState`
+    /// bank-tree actions. Registers reset at end of day.
+    ///
+    /// # Arguments
+    /// * `agent_id` - Agent identifier (e.g., "BANK_A")
+    ///
+    /// # Returns
+    /// Dict[str, float] (register key -> value), empty if none are set
+    ///
+    /// # Example (from Python)
+    /// ```python
+    /// registers = orch.agent_state_registers("BANK_A")
+    /// print(f"Stress: {registers.get('bank_state_stress', 0.0)}")
+    /// ```
+    fn agent_state_registers(&self, py: Python, agent_id: &str) -> PyResult<Py<PyDict>> {
+        let registers = self.inner.get_agent_state_registers(agent_id)
+            .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                format!("Agent '{}' not found", agent_id)
+            ))?;
+
+        let dict = PyDict::new(py);
+        for (key, value) in registers {
+            dict.set_item(key, value)?;
+        }
+
+        Ok(dict.into())
+    }
+
     /// Get agent's current outflow tracking (Phase 1: TARGET2 LSM)
     ///
     /// Returns current day's bilateral outflows and total outflow for limit enforcement.
@@ -981,6 +1243,30 @@ impl PyOrchestrator {
         self.inner.get_queue2_size()
     }
 
+    /// Count transactions by status at the current tick
+    ///
+    /// Includes split children alongside their parents.
+    ///
+    /// # Returns
+    ///
+    /// Dictionary mapping every status name ("Pending", "PartiallySettled",
+    /// "Settled", "Overdue", "Cancelled", "Returned") to its count; absent
+    /// statuses are 0
+    ///
+    /// # Example (from Python)
+    ///
+    /// ```python
+    /// counts = orch.status_counts()
+    /// print(f"{counts['Overdue']} overdue of {sum(counts.values())}")
+    /// ```
+    fn status_counts(&self, py: Python) -> PyResult<Py<PyDict>> {
+        let dict = PyDict::new(py);
+        for (status, count) in self.inner.status_counts() {
+            dict.set_item(status, count)?;
+        }
+        Ok(dict.into())
+    }
+
     /// Get contents of RTGS queue (Queue 2)
     ///
     /// Returns a list of transaction IDs currently in the central RTGS queue,
@@ -1004,6 +1290,76 @@ impl PyOrchestrator {
         self.inner.get_queue2_contents()
     }
 
+    /// Get the net bilateral obligation graph over Queue 2
+    ///
+    /// # Returns
+    ///
+    /// List of `(sender, receiver, net_amount)` tuples sorted by sender then
+    /// receiver. Opposite obligations between a pair are netted into one edge.
+    fn current_obligation_graph(&self) -> Vec<(String, String, i64)> {
+        self.inner.current_obligation_graph()
+    }
+
+    /// Get Queue 1 transactions waiting at least `threshold_ticks`
+    ///
+    /// Age counts from the tick a transaction first entered Queue 1.
+    ///
+    /// # Returns
+    ///
+    /// List of transaction IDs ordered by agent ID, then Queue 1 position.
+    fn starved_transactions(&self, threshold_ticks: usize) -> Vec<String> {
+        self.inner.starved_transactions(threshold_ticks)
+    }
+
+    /// Get queued transactions that are past their deadline
+    ///
+    /// Covers both Queue 1 and Queue 2.
+    ///
+    /// # Returns
+    ///
+    /// List of `(tx_id, ticks_overdue)` tuples, most overdue first.
+    fn overdue_transactions(&self) -> Vec<(String, usize)> {
+        self.inner.overdue_transactions()
+    }
+
+    /// Get an agent's end-of-tick balance series
+    ///
+    /// Requires `record_balance_series` in the config.
+    ///
+    /// # Returns
+    ///
+    /// List of balances (cents), one per executed tick, oldest first; None
+    /// if recording is off, no tick has run yet or the agent is unknown.
+    ///
+    /// # Example (from Python)
+    ///
+    /// ```python
+    /// series = orch.balance_series("BANK_A")
+    /// plt.plot(range(len(series)), series)
+    /// ```
+    fn balance_series(&self, agent_id: &str) -> Option<Vec<i64>> {
+        self.inner.balance_series(agent_id).map(|series| series.to_vec())
+    }
+
+    /// Get the network-wide settlement throughput series
+    ///
+    /// Requires `record_throughput_series` in the config.
+    ///
+    /// # Returns
+    ///
+    /// List of `(settled_value, settlement_count)` tuples, one per executed
+    /// tick, oldest first; empty if recording is off.
+    ///
+    /// # Example (from Python)
+    ///
+    /// ```python
+    /// values = [value for value, _ in orch.throughput_series()]
+    /// plt.plot(range(len(values)), values)
+    /// ```
+    fn throughput_series(&self) -> Vec<(i64, usize)> {
+        self.inner.throughput_series().to_vec()
+    }
+
     /// Get contents of agent's internal queue (Queue 1)
     ///
     /// Returns a list of transaction IDs currently in the agent's
@@ -1121,7 +1477,8 @@ impl PyOrchestrator {
     /// * `amount` - Transaction amount in cents (must be positive)
     /// * `deadline_tick` - Tick by which transaction must settle
     /// * `priority` - Priority level (0-10, higher = more urgent)
-    /// * `divisible` - Whether transaction can be split (currently unused, reserved for future use)
+    /// * `divisible` - Whether policies may split the transaction
+    /// * `tags` - Optional labels for downstream analysis (default: none)
     ///
     /// # Returns
     ///
@@ -1145,6 +1502,7 @@ impl PyOrchestrator {
     ///     deadline_tick=50,     # Must settle by tick 50
     ///     priority=5,           # Medium priority
     ///     divisible=False,      # Cannot be split
+    ///     tags=["CLS"],         # Optional analysis labels
     /// )
     /// print(f"Created transaction: {tx_id}")
     ///
@@ -1152,6 +1510,7 @@ impl PyOrchestrator {
     /// orch.tick()
     /// balance = orch.get_agent_balance("BANK_A")
     /// ```
+    #[pyo3(signature = (sender, receiver, amount, deadline_tick, priority, divisible, tags=None))]
     fn submit_transaction(
         &mut self,
         sender: &str,
@@ -1160,9 +1519,18 @@ impl PyOrchestrator {
         deadline_tick: usize,
         priority: u8,
         divisible: bool,
+        tags: Option<Vec<String>>,
     ) -> PyResult<String> {
         self.inner
-            .submit_transaction(sender, receiver, amount, deadline_tick, priority, divisible)
+            .submit_transaction_with_tags(
+                sender,
+                receiver,
+                amount,
+                deadline_tick,
+                priority,
+                divisible,
+                tags.unwrap_or_default(),
+            )
             .map_err(|e| {
                 PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
                     "Failed to submit transaction: {}",
@@ -1241,6 +1609,32 @@ impl PyOrchestrator {
             })
     }
 
+    /// Preview which transactions would settle immediately if released now
+    ///
+    /// Simulates sequential RTGS settlement in the given order without
+    /// changing any state. Returns a list of `(tx_id, would_settle)` tuples.
+    fn preview_settlement(&self, tx_ids: Vec<String>) -> Vec<(String, bool)> {
+        self.inner.preview_settlement(&tx_ids)
+    }
+
+    /// Cancel a transaction that has not yet settled
+    ///
+    /// Removes it from Queue 1 or Queue 2 and marks it cancelled; it will
+    /// never settle afterwards.
+    ///
+    /// # Errors
+    ///
+    /// Raises ValueError if the transaction is unknown, already settled,
+    /// already cancelled, or not in either queue.
+    fn cancel_transaction(&mut self, tx_id: &str) -> PyResult<()> {
+        self.inner.cancel_transaction(tx_id).map_err(|e| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                "Failed to cancel transaction: {}",
+                e
+            ))
+        })
+    }
+
     /// Withdraw a transaction from RTGS Queue 2 (Phase 0: Dual Priority System)
     ///
     /// Removes the transaction from Queue 2 and clears its RTGS priority.
@@ -1261,6 +1655,36 @@ impl PyOrchestrator {
         Ok(result.into())
     }
 
+    /// Run an LSM pass immediately, outside the tick loop
+    ///
+    /// Uses the current state and tick and logs the same events as the
+    /// in-loop pass.
+    ///
+    /// # Returns
+    ///
+    /// Dict with iterations_run, total_settled_value, final_queue_size,
+    /// bilateral_offsets, cycles_settled and netting_batches.
+    ///
+    /// # Example (from Python)
+    ///
+    /// ```python
+    /// orch.submit_transaction("BANK_A", "BANK_B", 100_000, 50, 5, False)
+    /// result = orch.run_lsm_now()
+    /// print(result["total_settled_value"])
+    /// ```
+    fn run_lsm_now(&mut self, py: Python) -> PyResult<Py<PyDict>> {
+        let lsm_result = self.inner.run_lsm_now();
+
+        let result = PyDict::new(py);
+        result.set_item("iterations_run", lsm_result.iterations_run)?;
+        result.set_item("total_settled_value", lsm_result.total_settled_value)?;
+        result.set_item("final_queue_size", lsm_result.final_queue_size)?;
+        result.set_item("bilateral_offsets", lsm_result.bilateral_offsets)?;
+        result.set_item("cycles_settled", lsm_result.cycles_settled)?;
+        result.set_item("netting_batches", lsm_result.netting_batches)?;
+        Ok(result.into())
+    }
+
     /// Resubmit a transaction to RTGS with a new priority (Phase 0: Dual Priority System)
     ///
     /// Sets a new RTGS priority for the transaction. The transaction will be
@@ -1550,7 +1974,8 @@ impl PyOrchestrator {
     ///
     /// # Arguments
     ///
-    /// * `config` - Configuration dictionary (must match original config)
+    /// * `config` - Configuration dictionary, or the same dictionary as a JSON
+    ///   string (must match original config)
     /// * `state_json` - JSON string from previous save_state() call
     ///
     /// # Returns
@@ -1559,10 +1984,11 @@ impl PyOrchestrator {
     ///
     /// # Errors
     ///
-    /// Raises RuntimeError if:
+    /// Raises ValueError if:
     /// - Config hash mismatch (config doesn't match checkpoint)
-    /// - JSON deserialization fails
-    /// - State validation fails (invariants violated)
+    /// - Config or checkpoint JSON cannot be parsed
+    ///
+    /// Raises RuntimeError if state validation fails (invariants violated)
     ///
     /// # Example (from Python)
     ///
@@ -1574,18 +2000,42 @@ impl PyOrchestrator {
     /// # Restore orchestrator
     /// orch = Orchestrator.load_state(config, state_json)
     ///
+    /// # Or with the config stored alongside the checkpoint as JSON
+    /// orch = Orchestrator.load_state(json.dumps(config), state_json)
+    ///
     /// # Continue simulation
     /// result = orch.tick()
     /// ```
     #[staticmethod]
-    fn load_state(config: &Bound<'_, PyDict>, state_json: &str) -> PyResult<Self> {
-        let rust_config = parse_orchestrator_config(config)?;
+    fn load_state(py: Python, config: &Bound<'_, PyAny>, state_json: &str) -> PyResult<Self> {
+        let config_dict: Bound<'_, PyDict> = if let Ok(config_json) = config.extract::<String>() {
+            py.import("json")?
+                .call_method1("loads", (config_json,))
+                .map_err(|e| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "Failed to parse config JSON: {}",
+                        e
+                    ))
+                })?
+                .downcast_into()
+                .map_err(|_| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "Config JSON must encode an object",
+                    )
+                })?
+        } else {
+            config.downcast::<PyDict>()?.clone()
+        };
+        let rust_config = parse_orchestrator_config(&config_dict)?;
 
         let inner = RustOrchestrator::load_state(rust_config, state_json).map_err(|e| {
-            PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
-                "Failed to load state: {}",
-                e
-            ))
+            let message = format!("Failed to load state: {}", e);
+            match e {
+                SimulationError::ConfigMismatch { .. } | SimulationError::DeserializationError(_) => {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>(message)
+                }
+                _ => PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(message),
+            }
         })?;
 
         Ok(PyOrchestrator { inner })
@@ -1723,6 +2173,8 @@ impl PyOrchestrator {
     /// - `delay_cost`: Queue delay cost (cents)
     /// - `split_friction_cost`: Transaction splitting cost (cents)
     /// - `deadline_penalty`: Deadline miss penalties (cents)
+    /// - `overnight_interest`: Net overnight interest (cents, negative if earned)
+    /// - `collateral_fee_cost`: One-time collateral posting fees (cents)
     /// - `total_cost`: Sum of all costs (cents)
     ///
     /// # Errors
@@ -1747,11 +2199,65 @@ impl PyOrchestrator {
         dict.set_item("delay_cost", costs.total_delay_cost)?;
         dict.set_item("split_friction_cost", costs.total_split_friction_cost)?;
         dict.set_item("deadline_penalty", costs.total_penalty_cost)?;
+        dict.set_item("overnight_interest", costs.total_overnight_interest)?;
+        dict.set_item("collateral_fee_cost", costs.total_collateral_fee_cost)?;
         dict.set_item("total_cost", costs.total())?;
 
         Ok(dict.into())
     }
 
+    /// Get an agent's running cost accumulator by category
+    ///
+    /// Unlike `get_daily_agent_metrics()`, which only reports costs once a
+    /// day has closed, this reads the live `CostAccumulator` and can be
+    /// called at any tick.
+    ///
+    /// # Arguments
+    ///
+    /// * `agent_id` - Agent identifier
+    ///
+    /// # Returns
+    ///
+    /// Dictionary mapping each cost category to its accumulated value
+    /// (integer cents):
+    /// - `liquidity`: Overdraft cost
+    /// - `delay`: Queue 1 delay cost
+    /// - `collateral`: Collateral opportunity cost
+    /// - `penalty`: Deadline and end-of-day penalties
+    /// - `split_friction`: Transaction splitting cost
+    /// - `liquidity_opportunity`: Opportunity cost of allocated liquidity
+    /// - `overnight_interest`: Net overnight interest (negative if earned)
+    /// - `collateral_fee`: One-time collateral posting fees
+    ///
+    /// # Errors
+    ///
+    /// Raises KeyError if agent_id not found
+    ///
+    /// # Example (from Python)
+    ///
+    /// ```python
+    /// orch.tick()
+    /// costs = orch.agent_costs("BANK_A")
+    /// print(f"Delay so far: ${costs['delay'] / 100:.2f}")
+    /// ```
+    fn agent_costs(&self, py: Python, agent_id: String) -> PyResult<Py<PyDict>> {
+        let costs = self.inner.get_costs(&agent_id).ok_or_else(|| {
+            pyo3::exceptions::PyKeyError::new_err(format!("Agent not found: {}", agent_id))
+        })?;
+
+        let dict = PyDict::new(py);
+        dict.set_item("liquidity", costs.total_liquidity_cost)?;
+        dict.set_item("delay", costs.total_delay_cost)?;
+        dict.set_item("collateral", costs.total_collateral_cost)?;
+        dict.set_item("penalty", costs.total_penalty_cost)?;
+        dict.set_item("split_friction", costs.total_split_friction_cost)?;
+        dict.set_item("liquidity_opportunity", costs.total_liquidity_opportunity_cost)?;
+        dict.set_item("overnight_interest", costs.total_overnight_interest)?;
+        dict.set_item("collateral_fee", costs.total_collateral_fee_cost)?;
+
+        Ok(dict.into())
+    }
+
     /// Get comprehensive system-wide metrics
     ///
     /// Returns snapshot of current simulation health including:
@@ -1769,8 +2275,10 @@ impl PyOrchestrator {
     /// - `max_delay_ticks`: Maximum delay observed
     /// - `queue1_total_size`: Total transactions in agent queues
     /// - `queue2_total_size`: Total transactions in RTGS queue
+    /// - `max_queue1_age`: Oldest Queue 1 age in ticks
     /// - `peak_overdraft`: Largest overdraft across all agents (cents)
     /// - `agents_in_overdraft`: Number of agents with negative balance
+    /// - `liquidity_usage_gini`: Gini coefficient of credit usage across agents (0.0-1.0)
     ///
     /// # Example (from Python)
     ///
@@ -1781,20 +2289,43 @@ impl PyOrchestrator {
     /// print(f"Agents in overdraft: {metrics['agents_in_overdraft']}")
     /// ```
     fn get_system_metrics(&self, py: Python) -> PyResult<Py<PyDict>> {
-        let metrics = self.inner.calculate_system_metrics();
+        system_metrics_to_py(py, &self.inner.calculate_system_metrics())
+    }
 
-        let dict = PyDict::new(py);
-        dict.set_item("total_arrivals", metrics.total_arrivals)?;
-        dict.set_item("total_settlements", metrics.total_settlements)?;
-        dict.set_item("settlement_rate", metrics.settlement_rate)?;
-        dict.set_item("avg_delay_ticks", metrics.avg_delay_ticks)?;
-        dict.set_item("max_delay_ticks", metrics.max_delay_ticks)?;
-        dict.set_item("queue1_total_size", metrics.queue1_total_size)?;
-        dict.set_item("queue2_total_size", metrics.queue2_total_size)?;
-        dict.set_item("peak_overdraft", metrics.peak_overdraft)?;
-        dict.set_item("agents_in_overdraft", metrics.agents_in_overdraft)?;
+    /// Get system-wide metrics
+    ///
+    /// Dict of every `SystemMetrics` field, with the same keys as
+    /// `get_system_metrics()`: counts and cents as Python ints, rates as
+    /// floats.
+    ///
+    /// # Example (from Python)
+    ///
+    /// ```python
+    /// metrics = orch.system_metrics()
+    /// print(f"Peak overdraft: {metrics['peak_overdraft']} cents")
+    /// ```
+    fn system_metrics(&self, py: Python) -> PyResult<Py<PyDict>> {
+        system_metrics_to_py(py, &self.inner.system_metrics())
+    }
 
-        Ok(dict.into())
+    /// Get daily metrics for every agent on a completed day
+    ///
+    /// List of dicts mirroring `DailyMetrics` (cents as ints, sorted by
+    /// agent ID). Empty if the day has not completed yet.
+    ///
+    /// # Example (from Python)
+    ///
+    /// ```python
+    /// for m in orch.daily_agent_metrics(0):
+    ///     print(m["agent_id"], m["closing_balance"])
+    /// ```
+    fn daily_agent_metrics(&self, py: Python, day: usize) -> PyResult<Py<PyList>> {
+        let simulation_id = self.inner.simulation_id();
+        let py_list = PyList::empty(py);
+        for m in self.inner.daily_agent_metrics(day) {
+            py_list.append(agent_metrics_to_py(py, &m, &simulation_id)?)?;
+        }
+        Ok(py_list.into())
     }
 
     /// Get detailed transaction counts for debugging settlement rate issues
@@ -1891,7 +2422,7 @@ impl PyOrchestrator {
     /// # Returns
     ///
     /// List of event dictionaries, each containing:
-    /// - `event_type`: Event type name (e.g., "Arrival", "Settlement")
+    /// - `event_type`: Event type name (e.g., "Arrival", "RtgsImmediateSettlement")
     /// - `tick`: Tick when event occurred
     /// - Additional fields specific to each event type
     ///
@@ -1915,6 +2446,70 @@ impl PyOrchestrator {
         Ok(py_list.into())
     }
 
+    /// Get a page of events, optionally filtered by tick range and type
+    ///
+    /// Avoids pulling the whole log across the FFI boundary on long runs.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - Number of matching events to skip (default 0)
+    /// * `limit` - Maximum number of events to return (default: all)
+    /// * `tick_from` - First tick to include (inclusive, default: open)
+    /// * `tick_to` - Tick to stop at (exclusive, default: open)
+    /// * `type_filter` - Only return events of this type (e.g. "RtgsImmediateSettlement")
+    ///
+    /// # Returns
+    ///
+    /// List of event dictionaries in log order, as in `get_all_events()`
+    ///
+    /// # Example (from Python)
+    ///
+    /// ```python
+    /// offset = 0
+    /// while page := orch.events(offset=offset, limit=500, type_filter="LsmCycleSettlement"):
+    ///     process(page)
+    ///     offset += len(page)
+    /// ```
+    #[pyo3(signature = (offset=0, limit=None, tick_from=None, tick_to=None, type_filter=None))]
+    fn events(
+        &self,
+        py: Python,
+        offset: usize,
+        limit: Option<usize>,
+        tick_from: Option<usize>,
+        tick_to: Option<usize>,
+        type_filter: Option<&str>,
+    ) -> PyResult<Py<PyList>> {
+        let events = self
+            .inner
+            .query_events(offset, limit, tick_from, tick_to, type_filter);
+
+        let py_list = PyList::empty(py);
+        for event in events {
+            let event_dict = event_to_py_dict(py, event)?;
+            py_list.append(&event_dict)?;
+        }
+
+        Ok(py_list.into())
+    }
+
+    /// Export the full event log as JSON Lines
+    ///
+    /// # Returns
+    ///
+    /// One JSON object per line, in log order. Each object has a `type`
+    /// field (the event type) and a `tick` field.
+    ///
+    /// # Example (from Python)
+    ///
+    /// ```python
+    /// with open("events.jsonl", "w") as f:
+    ///     f.write(orch.export_events_jsonl())
+    /// ```
+    fn export_events_jsonl(&self) -> String {
+        self.inner.export_events_jsonl()
+    }
+
     /// Get full details for a specific transaction
     ///
     /// # Arguments
@@ -1976,14 +2571,7 @@ impl PyOrchestrator {
                 dict.set_item("rtgs_submission_tick", py.None())?;
             }
 
-            // Convert status to string
-            let status_str = match tx.status() {
-                crate::models::transaction::TransactionStatus::Pending => "Pending",
-                crate::models::transaction::TransactionStatus::PartiallySettled { .. } => "PartiallySettled",
-                crate::models::transaction::TransactionStatus::Settled { .. } => "Settled",
-                crate::models::transaction::TransactionStatus::Overdue { .. } => "Overdue",
-            };
-            dict.set_item("status", status_str)?;
+            dict.set_item("status", tx.status().name())?;
 
             Ok(Some(dict.into()))
         } else {
@@ -2033,6 +2621,35 @@ impl PyOrchestrator {
         self.inner.get_agent_collateral_posted(&agent_id)
     }
 
+    /// Get agent's maximum collateral capacity
+    ///
+    /// Configured `max_collateral_capacity`, or 10× the unsecured cap when
+    /// not set.
+    ///
+    /// # Returns
+    ///
+    /// Maximum postable collateral in cents, or None if agent not found
+    fn agent_max_collateral_capacity(&self, agent_id: String) -> Option<i64> {
+        self.inner.get_agent_max_collateral_capacity(&agent_id)
+    }
+
+    /// Get how much more collateral an agent can post
+    ///
+    /// # Returns
+    ///
+    /// Remaining collateral capacity in cents, or None if agent not found
+    ///
+    /// # Example (from Python)
+    ///
+    /// ```python
+    /// headroom = orch.agent_remaining_collateral_capacity("BANK_A")
+    /// if headroom and headroom >= needed:
+    ///     print("Can post enough collateral")
+    /// ```
+    fn agent_remaining_collateral_capacity(&self, agent_id: String) -> Option<i64> {
+        self.inner.get_agent_remaining_collateral_capacity(&agent_id)
+    }
+
     /// Get agent's total allowed overdraft limit
     ///
     /// Returns the maximum negative balance an agent can have, calculated as:
@@ -6,8 +6,11 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList};
 use std::collections::HashMap;
 
-use crate::arrivals::{AmountDistribution, ArrivalBandConfig, ArrivalBandsConfig, ArrivalConfig, PriorityDistribution};
-use crate::costs::PenaltyMode;
+use crate::arrivals::{
+    AmountDistribution, ArrivalBandConfig, ArrivalBandsConfig, ArrivalConfig, ArrivalCountModel,
+    DeadlineMode, PriorityDistribution,
+};
+use crate::costs::{CollateralCostBasis, CostRateOverrides, PenaltyMode};
 use crate::events::{EventSchedule, ScenarioEvent, ScheduledEvent};
 
 /// Parse a PenaltyMode from a Python value.
@@ -56,7 +59,7 @@ fn parse_penalty_mode(value: Option<Bound<'_, pyo3::PyAny>>, default_amount: i64
         }
     }
 }
-use crate::orchestrator::{AgentConfig, AgentLimitsConfig, CostRates, OrchestratorConfig, PolicyConfig, PriorityDelayMultipliers, PriorityEscalationConfig, Queue1Ordering, TickResult};
+use crate::orchestrator::{AgentConfig, AgentLimitsConfig, CostRates, DeadlineEnforcement, OrchestratorConfig, PolicyConfig, PriorityDelayMultipliers, PriorityEscalationConfig, Queue1Ordering, Queue2Ordering, ScriptedArrival, SplitRemainderPolicy, SystemMetrics, TickResult};
 use crate::settlement::lsm::LsmConfig;
 
 // ========================================================================
@@ -228,6 +231,147 @@ pub fn parse_orchestrator_config(py_config: &Bound<'_, PyDict>) -> PyResult<Orch
             Queue1Ordering::Fifo
         };
 
+    // Parse split_remainder_policy (default: LastChild for backward compatibility)
+    let split_remainder_policy: SplitRemainderPolicy =
+        if let Some(policy_str) = py_config.get_item("split_remainder_policy")? {
+            let policy: String = policy_str.extract()?;
+            match policy.as_str() {
+                "last_child" | "LastChild" => SplitRemainderPolicy::LastChild,
+                "distributed" | "Distributed" => SplitRemainderPolicy::Distributed,
+                _ => {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "Invalid split_remainder_policy: '{}'. Must be 'last_child' or 'distributed'",
+                        policy
+                    )));
+                }
+            }
+        } else {
+            SplitRemainderPolicy::default()
+        };
+
+    // Parse deadline_enforcement (default: SoftDelay for backward compatibility)
+    let deadline_enforcement: DeadlineEnforcement =
+        if let Some(mode_str) = py_config.get_item("deadline_enforcement")? {
+            let mode: String = mode_str.extract()?;
+            match mode.as_str() {
+                "soft_delay" | "SoftDelay" => DeadlineEnforcement::SoftDelay,
+                "penalize_and_force_settle" | "PenalizeAndForceSettle" => {
+                    DeadlineEnforcement::PenalizeAndForceSettle
+                }
+                _ => {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "Invalid deadline_enforcement: '{}'. Must be 'soft_delay' or 'penalize_and_force_settle'",
+                        mode
+                    )));
+                }
+            }
+        } else {
+            DeadlineEnforcement::default()
+        };
+
+    // Parse queue2_ordering (default: Fifo for backward compatibility)
+    let queue2_ordering: Queue2Ordering =
+        if let Some(ordering_str) = py_config.get_item("queue2_ordering")? {
+            let ordering: String = ordering_str.extract()?;
+            match ordering.as_str() {
+                "fifo" | "Fifo" | "FIFO" => Queue2Ordering::Fifo,
+                "priority_bands" | "PriorityBands" => Queue2Ordering::PriorityBands,
+                "largest_first" | "LargestFirst" => Queue2Ordering::LargestFirst,
+                "smallest_first" | "SmallestFirst" => Queue2Ordering::SmallestFirst,
+                "soonest_deadline" | "SoonestDeadline" => Queue2Ordering::SoonestDeadline,
+                _ => {
+                    return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+                        "Invalid queue2_ordering: '{}'. Must be 'fifo', 'priority_bands', \
+                         'largest_first', 'smallest_first' or 'soonest_deadline'",
+                        ordering
+                    )));
+                }
+            }
+        } else {
+            Queue2Ordering::Fifo
+        };
+
+    // Parse entry_offset_min_amount (default: 0 for backward compatibility)
+    let entry_offset_min_amount: i64 = py_config
+        .get_item("entry_offset_min_amount")?
+        .map(|item| item.extract())
+        .transpose()?
+        .unwrap_or(0);
+
+    // Parse record_decision_paths (default: false, opt-in debugging aid)
+    let record_decision_paths: bool = py_config
+        .get_item("record_decision_paths")?
+        .map(|item| item.extract())
+        .transpose()?
+        .unwrap_or(false);
+
+    // Parse debug_invariant_checks (default: false, opt-in debugging aid)
+    let debug_invariant_checks: bool = py_config
+        .get_item("debug_invariant_checks")?
+        .map(|item| item.extract())
+        .transpose()?
+        .unwrap_or(false);
+
+    // Parse rtgs_queue_capacity (default: None = unbounded Queue 2)
+    let rtgs_queue_capacity: Option<usize> = py_config
+        .get_item("rtgs_queue_capacity")?
+        .map(|item| item.extract())
+        .transpose()?;
+
+    // Parse force_release_at_eod (default: false, policies decide releases)
+    let force_release_at_eod: bool = py_config
+        .get_item("force_release_at_eod")?
+        .map(|item| item.extract())
+        .transpose()?
+        .unwrap_or(false);
+
+    // Parse record_balance_series (default: false, opt-in to bound memory)
+    let record_balance_series: bool = py_config
+        .get_item("record_balance_series")?
+        .map(|item| item.extract())
+        .transpose()?
+        .unwrap_or(false);
+
+    // Parse record_throughput_series (default: false, opt-in to bound memory)
+    let record_throughput_series: bool = py_config
+        .get_item("record_throughput_series")?
+        .map(|item| item.extract())
+        .transpose()?
+        .unwrap_or(false);
+
+    // Parse warmup_ticks (default: 0, no warm-up exclusion)
+    let warmup_ticks: usize = py_config
+        .get_item("warmup_ticks")?
+        .map(|item| item.extract())
+        .transpose()?
+        .unwrap_or(0);
+
+    // Parse time_scale (default: 1.0, rates as configured)
+    let time_scale: f64 = py_config
+        .get_item("time_scale")?
+        .map(|item| item.extract())
+        .transpose()?
+        .unwrap_or(1.0);
+
+    // Parse max_settlement_retries (default: None = retry forever)
+    let max_settlement_retries: Option<usize> = py_config
+        .get_item("max_settlement_retries")?
+        .map(|item| item.extract())
+        .transpose()?;
+
+    // Parse max_arrivals_per_agent_per_tick (default: None = uncapped)
+    let max_arrivals_per_agent_per_tick: Option<usize> = py_config
+        .get_item("max_arrivals_per_agent_per_tick")?
+        .map(|item| item.extract())
+        .transpose()?;
+
+    // Parse flow_window_ticks (default: 10-tick trailing flow rate window)
+    let flow_window_ticks: usize = py_config
+        .get_item("flow_window_ticks")?
+        .map(|item| item.extract())
+        .transpose()?
+        .unwrap_or(10);
+
     // Parse priority_mode (default: false for backward compatibility)
     let priority_mode: bool = py_config
         .get_item("priority_mode")?
@@ -328,6 +472,21 @@ pub fn parse_orchestrator_config(py_config: &Bound<'_, PyDict>) -> PyResult<Orch
         deferred_crediting,
         deadline_cap_at_eod,
         daily_liquidity_reallocation,
+        queue2_ordering,
+        entry_offset_min_amount,
+        record_decision_paths,
+        rtgs_queue_capacity,
+        flow_window_ticks,
+        split_remainder_policy,
+        debug_invariant_checks,
+        deadline_enforcement,
+        max_arrivals_per_agent_per_tick,
+        force_release_at_eod,
+        max_settlement_retries,
+        record_balance_series,
+        record_throughput_series,
+        warmup_ticks,
+        time_scale,
     })
 }
 
@@ -372,6 +531,10 @@ fn parse_agent_config(py_agent: &Bound<'_, PyDict>) -> PyResult<AgentConfig> {
     // Parse optional max_collateral_capacity using helper
     let max_collateral_capacity: Option<i64> = extract_optional(py_agent, "max_collateral_capacity")?;
 
+    // Parse optional collateral_classes (list of (class, amount, haircut) tuples)
+    let collateral_classes: Option<Vec<(String, i64, f64)>> =
+        extract_optional(py_agent, "collateral_classes")?;
+
     // Parse optional limits (bilateral/multilateral) using helper
     let limits: Option<AgentLimitsConfig> = if let Ok(Some(py_limits)) = py_agent.get_item("limits") {
         let limits_dict: Bound<'_, PyDict> = py_limits.downcast_into()?;
@@ -384,6 +547,45 @@ fn parse_agent_config(py_agent: &Bound<'_, PyDict>) -> PyResult<AgentConfig> {
     let liquidity_pool: Option<i64> = extract_optional(py_agent, "liquidity_pool")?;
     let liquidity_allocation_fraction: Option<f64> = extract_optional(py_agent, "liquidity_allocation_fraction")?;
 
+    // Parse optional agent_group (for group-weighted counterparty selection)
+    let agent_group: Option<String> = extract_optional(py_agent, "agent_group")?;
+
+    // Parse optional reserve requirement (default: none)
+    let reserve_requirement: i64 = extract_optional(py_agent, "reserve_requirement")?.unwrap_or(0);
+
+    // Parse optional deferred crediting override (default: use global flag)
+    let deferred_crediting: Option<bool> = extract_optional(py_agent, "deferred_crediting")?;
+
+    // Parse optional opening balance distribution (overrides opening_balance)
+    let opening_balance_distribution = if let Some(py_dist) = py_agent.get_item("opening_balance_distribution")? {
+        let dist_dict: Bound<'_, PyDict> = py_dist.downcast_into()?;
+        Some(parse_amount_distribution(&dist_dict)?)
+    } else {
+        None
+    };
+
+    // Parse optional agent-specific cost rates (any subset of the cost_rates keys)
+    let cost_rate_overrides = if let Some(py_costs) = py_agent.get_item("cost_rate_overrides")? {
+        let costs_dict: Bound<'_, PyDict> = py_costs.downcast_into()?;
+        Some(parse_cost_rate_overrides(&costs_dict)?)
+    } else {
+        None
+    };
+
+    // Parse optional scripted arrivals (list of dicts)
+    let scripted_arrivals: Option<Vec<ScriptedArrival>> = match py_agent.get_item("scripted_arrivals")? {
+        Some(py_list) if !py_list.is_none() => {
+            let list: Bound<'_, PyList> = py_list.downcast_into()?;
+            let mut arrivals = Vec::new();
+            for py_arrival in list.iter() {
+                let arrival_dict: Bound<'_, PyDict> = py_arrival.downcast_into()?;
+                arrivals.push(parse_scripted_arrival(&arrival_dict)?);
+            }
+            Some(arrivals)
+        }
+        _ => None,
+    };
+
     Ok(AgentConfig {
         id,
         opening_balance,
@@ -397,6 +599,24 @@ fn parse_agent_config(py_agent: &Bound<'_, PyDict>) -> PyResult<AgentConfig> {
         limits,
         liquidity_pool,
         liquidity_allocation_fraction,
+        collateral_classes,
+        agent_group,
+        reserve_requirement,
+        deferred_crediting,
+        scripted_arrivals,
+        opening_balance_distribution,
+        cost_rate_overrides,
+    })
+}
+
+/// Convert Python dict to ScriptedArrival
+fn parse_scripted_arrival(py_arrival: &Bound<'_, PyDict>) -> PyResult<ScriptedArrival> {
+    Ok(ScriptedArrival {
+        tick: extract_required(py_arrival, "tick")?,
+        receiver: extract_required(py_arrival, "receiver")?,
+        amount: extract_required(py_arrival, "amount")?,
+        deadline_offset: extract_required(py_arrival, "deadline_offset")?,
+        priority: extract_required(py_arrival, "priority")?,
     })
 }
 
@@ -454,6 +674,15 @@ fn parse_policy_config(py_policy: &Bound<'_, PyDict>) -> PyResult<PolicyConfig>
                 min_split_amount,
             })
         }
+        "ValueThreshold" => {
+            let threshold: i64 = extract_required(py_policy, "threshold")?;
+            let max_hold_ticks: usize = extract_required(py_policy, "max_hold_ticks")?;
+
+            Ok(PolicyConfig::ValueThreshold {
+                threshold,
+                max_hold_ticks,
+            })
+        }
         "MockSplitting" => {
             let num_splits: usize = extract_required(py_policy, "num_splits")?;
             Ok(PolicyConfig::MockSplitting { num_splits })
@@ -514,6 +743,14 @@ pub fn policy_config_to_py(py: Python, policy: &PolicyConfig) -> PyResult<Py<PyD
             dict.set_item("max_splits", max_splits)?;
             dict.set_item("min_split_amount", min_split_amount)?;
         }
+        PolicyConfig::ValueThreshold {
+            threshold,
+            max_hold_ticks,
+        } => {
+            dict.set_item("type", "ValueThreshold")?;
+            dict.set_item("threshold", threshold)?;
+            dict.set_item("max_hold_ticks", max_hold_ticks)?;
+        }
         PolicyConfig::MockSplitting { num_splits } => {
             dict.set_item("type", "MockSplitting")?;
             dict.set_item("num_splits", num_splits)?;
@@ -570,6 +807,10 @@ fn parse_arrival_config(py_arrivals: &Bound<'_, PyDict>) -> PyResult<ArrivalConf
         counterparty_weights.insert(agent_id, weight);
     }
 
+    // Parse optional counterparty group weights (default empty)
+    let counterparty_group_weights: HashMap<String, f64> =
+        extract_optional(py_arrivals, "counterparty_group_weights")?.unwrap_or_default();
+
     // Parse deadline_range (tuple or list of 2 elements)
     let deadline_range: (usize, usize) =
         if let Some(range_item) = py_arrivals.get_item("deadline_range")? {
@@ -605,16 +846,125 @@ fn parse_arrival_config(py_arrivals: &Bound<'_, PyDict>) -> PyResult<ArrivalConf
         .transpose()?
         .unwrap_or(false);
 
+    // Parse intraday_profile (optional list of per-tick rate multipliers)
+    let intraday_profile: Option<Vec<f64>> = extract_optional(py_arrivals, "intraday_profile")?;
+
+    // Parse tags (optional list of strings attached to generated transactions)
+    let tags: Option<Vec<String>> = extract_optional(py_arrivals, "tags")?;
+
+    // Parse arrival_count_model (optional, default Poisson)
+    let arrival_count_model = match py_arrivals.get_item("arrival_count_model")? {
+        Some(py_model) => {
+            let model_dict: Bound<'_, PyDict> = py_model.downcast_into()?;
+            parse_arrival_count_model(&model_dict)?
+        }
+        None => ArrivalCountModel::default(),
+    };
+
+    // Parse amount_granularity (optional rounding unit for sampled amounts)
+    let amount_granularity: Option<i64> = extract_optional(py_arrivals, "amount_granularity")?;
+
+    // Parse deadline_mode (optional, default RelativeOffset)
+    let deadline_mode = match py_arrivals.get_item("deadline_mode")? {
+        Some(py_mode) => {
+            let mode_dict: Bound<'_, PyDict> = py_mode.downcast_into()?;
+            parse_deadline_mode(&mode_dict)?
+        }
+        None => DeadlineMode::RelativeOffset,
+    };
+
     Ok(ArrivalConfig {
         rate_per_tick,
         amount_distribution,
         counterparty_weights,
+        counterparty_group_weights,
         deadline_range,
         priority_distribution,
         divisible,
+        intraday_profile,
+        tags,
+        arrival_count_model,
+        amount_granularity,
+        deadline_mode,
     })
 }
 
+/// Convert Python dict to ArrivalCountModel
+///
+/// Expected format: `{"type": "Poisson"}` or
+/// `{"type": "NegativeBinomial", "r": 2.0, "p": 0.3}`
+fn parse_arrival_count_model(py_model: &Bound<'_, PyDict>) -> PyResult<ArrivalCountModel> {
+    let model_type: String = py_model
+        .get_item("type")?
+        .ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>("Missing arrival_count_model 'type'")
+        })?
+        .extract()?;
+
+    match model_type.as_str() {
+        "Poisson" => Ok(ArrivalCountModel::Poisson),
+        "NegativeBinomial" => {
+            let r: f64 = py_model
+                .get_item("r")?
+                .ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>("NegativeBinomial requires 'r'")
+                })?
+                .extract()?;
+
+            let p: f64 = py_model
+                .get_item("p")?
+                .ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>("NegativeBinomial requires 'p'")
+                })?
+                .extract()?;
+
+            Ok(ArrivalCountModel::NegativeBinomial { r, p })
+        }
+        _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Unknown arrival count model: {}",
+            model_type
+        ))),
+    }
+}
+
+/// Convert Python dict to DeadlineMode
+///
+/// Expected format: `{"type": "RelativeOffset"}` or
+/// `{"type": "AbsoluteTick", "min": 70, "max": 70}`
+fn parse_deadline_mode(py_mode: &Bound<'_, PyDict>) -> PyResult<DeadlineMode> {
+    let mode_type: String = py_mode
+        .get_item("type")?
+        .ok_or_else(|| {
+            PyErr::new::<pyo3::exceptions::PyValueError, _>("Missing deadline_mode 'type'")
+        })?
+        .extract()?;
+
+    match mode_type.as_str() {
+        "RelativeOffset" => Ok(DeadlineMode::RelativeOffset),
+        "AbsoluteTick" => {
+            let min: usize = py_mode
+                .get_item("min")?
+                .ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>("AbsoluteTick requires 'min'")
+                })?
+                .extract()?;
+
+            let max: usize = py_mode
+                .get_item("max")?
+                .ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>("AbsoluteTick requires 'max'")
+                })?
+                .extract()?;
+
+            Ok(DeadlineMode::AbsoluteTick { min, max })
+        }
+        _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Unknown deadline mode: {}",
+            mode_type
+        ))),
+    }
+}
+
 /// Convert Python dict to ArrivalBandsConfig (Enhancement 11.3)
 ///
 /// Expected format:
@@ -663,7 +1013,8 @@ fn parse_arrival_bands_config(py_bands: &Bound<'_, PyDict>) -> PyResult<ArrivalB
 ///     "deadline_offset_min": 5,
 ///     "deadline_offset_max": 20,
 ///     "counterparty_weights": { "BANK_B": 0.5, "BANK_C": 0.5 },  # optional
-///     "divisible": false  # optional
+///     "divisible": false,  # optional
+///     "deadline_jitter": 3  # optional
 /// }
 /// ```
 fn parse_arrival_band_config(py_band: &Bound<'_, PyDict>) -> PyResult<ArrivalBandConfig> {
@@ -702,6 +1053,9 @@ fn parse_arrival_band_config(py_band: &Bound<'_, PyDict>) -> PyResult<ArrivalBan
     // Optional: divisible (default false)
     let divisible: bool = extract_with_default(py_band, "divisible", false)?;
 
+    // Optional: deadline_jitter (default none)
+    let deadline_jitter: Option<usize> = extract_optional(py_band, "deadline_jitter")?;
+
     Ok(ArrivalBandConfig {
         rate_per_tick,
         amount_distribution,
@@ -709,6 +1063,7 @@ fn parse_arrival_band_config(py_band: &Bound<'_, PyDict>) -> PyResult<ArrivalBan
         deadline_offset_max,
         counterparty_weights,
         divisible,
+        deadline_jitter,
     })
 }
 
@@ -850,6 +1205,75 @@ fn parse_amount_distribution(py_dist: &Bound<'_, PyDict>) -> PyResult<AmountDist
 
             Ok(AmountDistribution::Exponential { rate })
         }
+        "Pareto" => {
+            let scale: i64 = py_dist
+                .get_item("scale")?
+                .ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>("Pareto requires 'scale'")
+                })?
+                .extract()?;
+
+            let shape: f64 = py_dist
+                .get_item("shape")?
+                .ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>("Pareto requires 'shape'")
+                })?
+                .extract()?;
+
+            Ok(AmountDistribution::Pareto { scale, shape })
+        }
+        "Weibull" => {
+            let shape: f64 = py_dist
+                .get_item("shape")?
+                .ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>("Weibull requires 'shape'")
+                })?
+                .extract()?;
+
+            let scale: f64 = py_dist
+                .get_item("scale")?
+                .ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>("Weibull requires 'scale'")
+                })?
+                .extract()?;
+
+            Ok(AmountDistribution::Weibull { shape, scale })
+        }
+        "Mixture" => {
+            let py_components: Bound<'_, PyList> = py_dist
+                .get_item("components")?
+                .ok_or_else(|| {
+                    PyErr::new::<pyo3::exceptions::PyValueError, _>("Mixture requires 'components'")
+                })?
+                .downcast_into()?;
+
+            let mut components = Vec::new();
+            for py_component in py_components.iter() {
+                let component_dict: Bound<'_, PyDict> = py_component.downcast_into()?;
+
+                let weight: f64 = component_dict
+                    .get_item("weight")?
+                    .ok_or_else(|| {
+                        PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                            "Mixture component requires 'weight'",
+                        )
+                    })?
+                    .extract()?;
+
+                let dist_dict: Bound<'_, PyDict> = component_dict
+                    .get_item("distribution")?
+                    .ok_or_else(|| {
+                        PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                            "Mixture component requires 'distribution'",
+                        )
+                    })?
+                    .downcast_into()?;
+
+                components.push((weight, Box::new(parse_amount_distribution(&dist_dict)?)));
+            }
+
+            Ok(AmountDistribution::Mixture { components })
+        }
         _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
             "Unknown distribution type: {}",
             dist_type
@@ -889,12 +1313,26 @@ fn parse_cost_rates(py_costs: &Bound<'_, PyDict>) -> PyResult<CostRates> {
             50_000,
         )?,
 
+        // Per-tick lateness penalty charged at settlement (default: none)
+        deadline_penalty_per_tick_late: py_costs
+            .get_item("deadline_penalty_per_tick_late")?
+            .map(|v| v.extract())
+            .transpose()?
+            .unwrap_or(0),
+
         split_friction_cost: py_costs
             .get_item("split_friction_cost")?
             .map(|v| v.extract())
             .transpose()?
             .unwrap_or(1000),
 
+        // Exponent on the number of splits in the friction formula (default: linear)
+        split_friction_exponent: py_costs
+            .get_item("split_friction_exponent")?
+            .map(|v| v.extract())
+            .transpose()?
+            .unwrap_or(1.0),
+
         // Phase 3: Overdue delay cost multiplier
         overdue_delay_multiplier: py_costs
             .get_item("overdue_delay_multiplier")?
@@ -918,9 +1356,93 @@ fn parse_cost_rates(py_costs: &Bound<'_, PyDict>) -> PyResult<CostRates> {
             .map(|v| v.extract())
             .transpose()?
             .unwrap_or(0.0), // Default: no liquidity opportunity cost
+
+        // Tiered overdraft rates: list of (threshold_cents, bps_per_tick)
+        liquidity_cost_tiers: extract_optional(py_costs, "liquidity_cost_tiers")?,
+
+        // Overnight interest on closing balances (bps per day boundary)
+        overnight_deposit_rate: py_costs
+            .get_item("overnight_deposit_rate")?
+            .map(|v| v.extract())
+            .transpose()?
+            .unwrap_or(0.0),
+        overnight_overdraft_rate: py_costs
+            .get_item("overnight_overdraft_rate")?
+            .map(|v| v.extract())
+            .transpose()?
+            .unwrap_or(0.0),
+
+        // Collateral cost basis: "posted" (default) or "post_haircut"
+        collateral_cost_basis: parse_collateral_cost_basis(py_costs)?
+            .unwrap_or(CollateralCostBasis::Posted),
+
+        // One-time fee per collateral post (default: free)
+        collateral_post_fee: py_costs
+            .get_item("collateral_post_fee")?
+            .map(|v| v.extract())
+            .transpose()?
+            .unwrap_or(0),
+    })
+}
+
+/// Convert Python dict to CostRateOverrides (keys as in `parse_cost_rates`)
+///
+/// Missing keys stay `None` so the agent follows the global rate.
+fn parse_cost_rate_overrides(py_costs: &Bound<'_, PyDict>) -> PyResult<CostRateOverrides> {
+    let eod_penalty = py_costs
+        .get_item("eod_penalty")?
+        .or(py_costs.get_item("eod_penalty_per_transaction")?);
+    let deadline_penalty = py_costs.get_item("deadline_penalty")?;
+    let priority_delay_multipliers = match py_costs.get_item("priority_delay_multipliers")? {
+        Some(py_priority) => {
+            let priority_dict: Bound<'_, PyDict> = py_priority.downcast_into()?;
+            Some(parse_priority_delay_multipliers(&priority_dict)?)
+        }
+        None => None,
+    };
+
+    Ok(CostRateOverrides {
+        overdraft_bps_per_tick: extract_optional(py_costs, "overdraft_bps_per_tick")?,
+        delay_cost_per_tick_per_cent: extract_optional(py_costs, "delay_cost_per_tick_per_cent")?,
+        collateral_cost_per_tick_bps: extract_optional(py_costs, "collateral_cost_per_tick_bps")?,
+        eod_penalty: match eod_penalty {
+            Some(v) => Some(parse_penalty_mode(Some(v), 0)?),
+            None => None,
+        },
+        deadline_penalty: match deadline_penalty {
+            Some(v) => Some(parse_penalty_mode(Some(v), 0)?),
+            None => None,
+        },
+        deadline_penalty_per_tick_late: extract_optional(py_costs, "deadline_penalty_per_tick_late")?,
+        split_friction_cost: extract_optional(py_costs, "split_friction_cost")?,
+        split_friction_exponent: extract_optional(py_costs, "split_friction_exponent")?,
+        overdue_delay_multiplier: extract_optional(py_costs, "overdue_delay_multiplier")?,
+        priority_delay_multipliers,
+        liquidity_cost_per_tick_bps: extract_optional(py_costs, "liquidity_cost_per_tick_bps")?,
+        liquidity_cost_tiers: extract_optional(py_costs, "liquidity_cost_tiers")?,
+        overnight_deposit_rate: extract_optional(py_costs, "overnight_deposit_rate")?,
+        overnight_overdraft_rate: extract_optional(py_costs, "overnight_overdraft_rate")?,
+        collateral_cost_basis: parse_collateral_cost_basis(py_costs)?,
+        collateral_post_fee: extract_optional(py_costs, "collateral_post_fee")?,
     })
 }
 
+/// Parse the optional `collateral_cost_basis` key: "posted" or "post_haircut"
+fn parse_collateral_cost_basis(
+    py_costs: &Bound<'_, PyDict>,
+) -> PyResult<Option<CollateralCostBasis>> {
+    let basis: Option<String> = extract_optional(py_costs, "collateral_cost_basis")?;
+    match basis.as_deref() {
+        None => Ok(None),
+        Some("posted" | "Posted") => Ok(Some(CollateralCostBasis::Posted)),
+        Some("post_haircut" | "PostHaircut") => Ok(Some(CollateralCostBasis::PostHaircut)),
+        Some(other) => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
+            "Invalid collateral_cost_basis: '{}'. Must be 'posted' or 'post_haircut'",
+            other
+        ))),
+    }
+}
+
 /// Convert Python dict to PriorityDelayMultipliers (Enhancement 11.1)
 fn parse_priority_delay_multipliers(
     py_priority: &Bound<'_, PyDict>,
@@ -961,20 +1483,54 @@ fn parse_lsm_config(py_lsm: &Bound<'_, PyDict>) -> PyResult<LsmConfig> {
             .transpose()?
             .unwrap_or(true),
 
-        max_cycle_length: py_lsm
-            .get_item("max_cycle_length")?
-            .map(|v| v.extract())
-            .transpose()?
-            .unwrap_or(4),
+        // Missing keeps the default of 4; an explicit None means unbounded
+        max_cycle_length: extract_optional::<Option<usize>>(py_lsm, "max_cycle_length")?
+            .unwrap_or(Some(4)),
 
         max_cycles_per_tick: py_lsm
             .get_item("max_cycles_per_tick")?
             .map(|v| v.extract())
             .transpose()?
             .unwrap_or(10),
+
+        partial_settlement: py_lsm
+            .get_item("partial_settlement")?
+            .map(|v| v.extract())
+            .transpose()?
+            .unwrap_or(false),
+
+        lsm_interval_ticks: py_lsm
+            .get_item("lsm_interval_ticks")?
+            .map(|v| v.extract())
+            .transpose()?
+            .unwrap_or(1),
+
+        enable_multilateral_netting: py_lsm
+            .get_item("enable_multilateral_netting")?
+            .map(|v| v.extract())
+            .transpose()?
+            .unwrap_or(false),
     })
 }
 
+/// Convert SystemMetrics to Python dict
+pub fn system_metrics_to_py(py: Python, metrics: &SystemMetrics) -> PyResult<Py<PyDict>> {
+    let dict = PyDict::new(py);
+    dict.set_item("total_arrivals", metrics.total_arrivals)?;
+    dict.set_item("total_settlements", metrics.total_settlements)?;
+    dict.set_item("settlement_rate", metrics.settlement_rate)?;
+    dict.set_item("avg_delay_ticks", metrics.avg_delay_ticks)?;
+    dict.set_item("max_delay_ticks", metrics.max_delay_ticks)?;
+    dict.set_item("queue1_total_size", metrics.queue1_total_size)?;
+    dict.set_item("queue2_total_size", metrics.queue2_total_size)?;
+    dict.set_item("max_queue1_age", metrics.max_queue1_age)?;
+    dict.set_item("peak_overdraft", metrics.peak_overdraft)?;
+    dict.set_item("agents_in_overdraft", metrics.agents_in_overdraft)?;
+    dict.set_item("liquidity_usage_gini", metrics.liquidity_usage_gini)?;
+
+    Ok(dict.into())
+}
+
 /// Convert TickResult to Python dict
 pub fn tick_result_to_py(py: Python, result: &TickResult) -> PyResult<Py<PyDict>> {
     let dict = PyDict::new(py);
@@ -999,6 +1555,19 @@ pub fn tick_result_to_py(py: Python, result: &TickResult) -> PyResult<Py<PyDict>
     Ok(dict.into())
 }
 
+/// Convert TickResult to a lightweight Python dict (counts and cost only, no timing)
+pub fn tick_summary_to_py(py: Python, result: &TickResult) -> PyResult<Py<PyDict>> {
+    let dict = PyDict::new(py);
+
+    dict.set_item("tick", result.tick)?;
+    dict.set_item("num_arrivals", result.num_arrivals)?;
+    dict.set_item("num_settlements", result.num_settlements)?;
+    dict.set_item("num_lsm_releases", result.num_lsm_releases)?;
+    dict.set_item("total_cost", result.total_cost)?;
+
+    Ok(dict.into())
+}
+
 /// Convert Transaction to Python dict
 ///
 /// Converts a Rust Transaction to a Python dict matching the TransactionRecord Pydantic model.
@@ -1059,6 +1628,8 @@ pub fn transaction_to_py(
         crate::models::TransactionStatus::PartiallySettled { .. } => "settled", // Map partially settled to settled
         crate::models::TransactionStatus::Settled { .. } => "settled",
         crate::models::TransactionStatus::Overdue { .. } => "overdue", // Phase 5: New overdue status
+        crate::models::TransactionStatus::Cancelled { .. } => "cancelled",
+        crate::models::TransactionStatus::Returned { .. } => "returned",
     };
     dict.set_item("status", status_str)?;
 
@@ -1093,6 +1664,8 @@ pub fn transaction_to_py(
         dict.set_item("parent_tx_id", py.None())?;
     }
     dict.set_item("split_index", py.None())?; // TODO: Track split index in Transaction
+    dict.set_item("is_divisible", tx.is_divisible())?;
+    dict.set_item("tags", tx.tags().to_vec())?;
 
     // RTGS Priority (Phase 0: Dual Priority System)
     // rtgs_priority is None until transaction is submitted to RTGS Queue 2
@@ -1156,6 +1729,10 @@ pub fn agent_metrics_to_py(
     // Credit usage
     dict.set_item("unsecured_cap", metrics.unsecured_cap)?;
     dict.set_item("peak_overdraft", metrics.peak_overdraft)?;
+    dict.set_item(
+        "peak_liquidity_requirement",
+        metrics.peak_liquidity_requirement,
+    )?;
 
     // Collateral management (Phase 8)
     dict.set_item(
@@ -1185,12 +1762,20 @@ pub fn agent_metrics_to_py(
     dict.set_item("queue1_peak_size", metrics.queue1_peak_size)?;
     dict.set_item("queue1_eod_size", metrics.queue1_eod_size)?;
 
+    // Liquidity efficiency
+    dict.set_item("settled_value", metrics.settled_value)?;
+    dict.set_item("liquidity_saved", metrics.liquidity_saved)?;
+    dict.set_item("turnover_ratio", metrics.turnover_ratio)?;
+    dict.set_item("in_warmup", metrics.in_warmup)?;
+
     // Costs
     dict.set_item("liquidity_cost", metrics.liquidity_cost)?;
     dict.set_item("delay_cost", metrics.delay_cost)?;
     dict.set_item("collateral_cost", metrics.collateral_cost)?;
     dict.set_item("split_friction_cost", metrics.split_friction_cost)?;
     dict.set_item("deadline_penalty_cost", metrics.deadline_penalty_cost)?;
+    dict.set_item("overnight_interest", metrics.overnight_interest)?;
+    dict.set_item("collateral_fee_cost", metrics.collateral_fee_cost)?;
     dict.set_item("total_cost", metrics.total_cost)?;
 
     Ok(dict.into())
@@ -1301,9 +1886,29 @@ fn parse_scenario_events(py_events: &Bound<'_, PyList>) -> PyResult<Vec<Schedule
                     .extract()?;
                 EventSchedule::Repeating { start_tick, interval }
             }
+            "Recurring" => {
+                let start_tick: usize = event_dict
+                    .get_item("start_tick")?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "Recurring schedule requires 'start_tick'"
+                    ))?
+                    .extract()?;
+                let interval: usize = event_dict
+                    .get_item("interval")?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "Recurring schedule requires 'interval'"
+                    ))?
+                    .extract()?;
+                let end_tick: Option<usize> = event_dict
+                    .get_item("end_tick")?
+                    .map(|v| v.extract::<Option<usize>>())
+                    .transpose()?
+                    .flatten();
+                EventSchedule::Recurring { start_tick, interval, end_tick }
+            }
             _ => {
                 return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
-                    "Invalid schedule type: {}. Must be 'OneTime' or 'Repeating'",
+                    "Invalid schedule type: {}. Must be 'OneTime', 'Repeating' or 'Recurring'",
                     schedule_type
                 )));
             }
@@ -1495,6 +2100,115 @@ fn parse_scenario_events(py_events: &Bound<'_, PyList>) -> PyResult<Vec<Schedule
                     amount,
                 }
             }
+            "LiquidityInjection" => {
+                let agent: String = event_dict
+                    .get_item("agent")?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "LiquidityInjection requires 'agent'"
+                    ))?
+                    .extract()?;
+                let amount: i64 = event_dict
+                    .get_item("amount")?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "LiquidityInjection requires 'amount'"
+                    ))?
+                    .extract()?;
+
+                ScenarioEvent::LiquidityInjection { agent, amount }
+            }
+            "AgentSuspend" => {
+                let agent: String = event_dict
+                    .get_item("agent")?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "AgentSuspend requires 'agent'"
+                    ))?
+                    .extract()?;
+                let resume_tick: Option<usize> = extract_optional(&event_dict, "resume_tick")?;
+
+                ScenarioEvent::AgentSuspend { agent, resume_tick }
+            }
+            "AgentResume" => {
+                let agent: String = event_dict
+                    .get_item("agent")?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "AgentResume requires 'agent'"
+                    ))?
+                    .extract()?;
+
+                ScenarioEvent::AgentResume { agent }
+            }
+            "CostRateChange" => {
+                let field: String = event_dict
+                    .get_item("field")?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "CostRateChange requires 'field'"
+                    ))?
+                    .extract()?;
+                let new_value: f64 = event_dict
+                    .get_item("new_value")?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "CostRateChange requires 'new_value'"
+                    ))?
+                    .extract()?;
+
+                ScenarioEvent::CostRateChange { field, new_value }
+            }
+            "ReallocateLiquidity" => {
+                let agent: String = event_dict
+                    .get_item("agent")?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "ReallocateLiquidity requires 'agent'"
+                    ))?
+                    .extract()?;
+                let new_fraction: f64 = event_dict
+                    .get_item("new_fraction")?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "ReallocateLiquidity requires 'new_fraction'"
+                    ))?
+                    .extract()?;
+
+                ScenarioEvent::ReallocateLiquidity { agent, new_fraction }
+            }
+            "ArrivalBurst" => {
+                let agent: String = event_dict
+                    .get_item("agent")?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "ArrivalBurst requires 'agent'"
+                    ))?
+                    .extract()?;
+                let count: usize = event_dict
+                    .get_item("count")?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "ArrivalBurst requires 'count'"
+                    ))?
+                    .extract()?;
+                let py_dist: Bound<'_, PyDict> = event_dict
+                    .get_item("amount_distribution")?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "ArrivalBurst requires 'amount_distribution'"
+                    ))?
+                    .downcast_into()?;
+                let deadline_offset: usize = event_dict
+                    .get_item("deadline_offset")?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "ArrivalBurst requires 'deadline_offset'"
+                    ))?
+                    .extract()?;
+                let priority: u8 = event_dict
+                    .get_item("priority")?
+                    .ok_or_else(|| PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                        "ArrivalBurst requires 'priority'"
+                    ))?
+                    .extract()?;
+
+                ScenarioEvent::ArrivalBurst {
+                    agent,
+                    count,
+                    amount_distribution: parse_amount_distribution(&py_dist)?,
+                    deadline_offset,
+                    priority,
+                }
+            }
             _ => {
                 return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
                     "Invalid event type: {}",
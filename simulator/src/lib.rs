@@ -29,15 +29,17 @@ pub mod rng;
 pub mod settlement;
 
 // Re-exports for convenience
-pub use arrivals::{AmountDistribution, ArrivalConfig};
+pub use arrivals::{AmountDistribution, ArrivalConfig, ArrivalCountModel, DeadlineMode};
 pub use core::time::TimeManager;
 pub use models::{
     agent::{Agent, AgentError, WithdrawError},
     event::{Event, EventLog},
-    state::SimulationState,
-    transaction::{RtgsPriority, Transaction, TransactionError, TransactionStatus},
+    state::{QueueLocation, SimulationState, StateDiff},
+    transaction::{
+        RtgsPriority, SettlementMechanism, Transaction, TransactionError, TransactionStatus,
+    },
 };
-pub use costs::{get_priority_band, CostRates, PenaltyMode, PriorityBand, PriorityDelayMultipliers};
+pub use costs::{get_priority_band, CollateralCostBasis, CostRateOverrides, CostRates, PenaltyMode, PriorityBand, PriorityDelayMultipliers};
 pub use orchestrator::{
     AgentConfig, CostAccumulator, CostBreakdown, Orchestrator, OrchestratorConfig, PolicyConfig,
     SimulationError, TickResult,
@@ -79,6 +81,45 @@ fn py_get_cost_schema() -> PyResult<String> {
     Ok(costs::schema_docs::get_cost_schema())
 }
 
+/// Run a config once per seed and return the final system metrics of each run.
+///
+/// Runs are executed serially, in `seeds` order, regardless of the crate's
+/// `parallel` feature.
+///
+/// # Arguments
+///
+/// * `config` - Orchestrator config dict (its `rng_seed` is replaced per run)
+/// * `seeds` - RNG seeds to run
+/// * `ticks` - Number of ticks to advance each run
+///
+/// # Returns
+///
+/// List of system metrics dicts (same keys as `Orchestrator.get_system_metrics()`)
+#[cfg(feature = "pyo3")]
+#[pyfunction]
+#[pyo3(name = "run_seed_sweep")]
+fn py_run_seed_sweep(
+    py: Python,
+    config: &Bound<'_, pyo3::types::PyDict>,
+    seeds: Vec<u64>,
+    ticks: usize,
+) -> PyResult<Vec<Py<pyo3::types::PyDict>>> {
+    let base_config = ffi::types::parse_orchestrator_config(config)?;
+
+    seeds
+        .iter()
+        .map(|&seed| {
+            let metrics = orchestrator::engine::run_seed(&base_config, seed, ticks).map_err(|e| {
+                PyErr::new::<pyo3::exceptions::PyRuntimeError, _>(format!(
+                    "Seed {} failed: {}",
+                    seed, e
+                ))
+            })?;
+            ffi::types::system_metrics_to_py(py, &metrics)
+        })
+        .collect()
+}
+
 /// Validate a policy tree JSON string.
 ///
 /// This function performs comprehensive validation of a policy JSON file:
@@ -213,5 +254,6 @@ fn payment_simulator_core_rs(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(py_get_policy_schema, m)?)?;
     m.add_function(wrap_pyfunction!(py_get_cost_schema, m)?)?;
     m.add_function(wrap_pyfunction!(py_validate_policy, m)?)?;
+    m.add_function(wrap_pyfunction!(py_run_seed_sweep, m)?)?;
     Ok(())
 }
@@ -77,6 +77,11 @@ pub enum WithdrawError {
 ///     total_outflow: 0,
 ///     allocated_liquidity: None,
 ///     max_collateral_capacity: None,
+///     suspended: false,
+///     suspension_resume_tick: None,
+///     collateral_classes: vec![],
+///     state_registers: HashMap::new(),
+///     reserve_requirement: 0,
 /// };
 ///
 /// let agent = Agent::restore(data);
@@ -97,7 +102,7 @@ pub struct AgentRestoreData {
     pub last_decision_tick: Option<usize>,
     /// Target minimum balance to maintain
     pub liquidity_buffer: i64,
-    /// Posted collateral amount (i64 cents)
+    /// Posted collateral amount (i64 cents), including collateral classes
     pub posted_collateral: i64,
     /// Collateral haircut (discount rate)
     pub collateral_haircut: f64,
@@ -115,6 +120,16 @@ pub struct AgentRestoreData {
     pub allocated_liquidity: Option<i64>,
     /// Maximum collateral capacity override (optional for backwards compat)
     pub max_collateral_capacity: Option<i64>,
+    /// Whether the agent is suspended (operational outage)
+    pub suspended: bool,
+    /// Tick at which a suspended agent automatically resumes
+    pub suspension_resume_tick: Option<usize>,
+    /// Collateral pledged by asset class: (class name, amount, haircut)
+    pub collateral_classes: Vec<(String, i64, f64)>,
+    /// Policy state registers (bank_state_* keys)
+    pub state_registers: std::collections::HashMap<String, f64>,
+    /// Minimum reserve the agent must hold (i64 cents)
+    pub reserve_requirement: i64,
 }
 
 /// Represents a bank (agent) in the payment system
@@ -286,6 +301,43 @@ pub struct Agent {
     ///
     /// When None, falls back to the heuristic calculation.
     max_collateral_capacity: Option<i64>,
+
+    /// Whether the agent is suspended (operational outage)
+    ///
+    /// While suspended the agent's policies are skipped and nothing is
+    /// released from Queue 1. Incoming payments still credit normally.
+    #[serde(default)]
+    suspended: bool,
+
+    /// Tick at which a suspended agent automatically resumes
+    ///
+    /// None means the suspension lasts until an explicit resume.
+    #[serde(default)]
+    suspension_resume_tick: Option<usize>,
+
+    /// Collateral pledged by asset class: (class name, amount in cents, haircut)
+    ///
+    /// Each class is discounted by its own haircut. Class collateral is a
+    /// fixed pledge on top of `posted_collateral`, which remains the pool that
+    /// policies post to and withdraw from (discounted by `collateral_haircut`).
+    /// Empty means the scalar path only.
+    #[serde(default)]
+    collateral_classes: Vec<(String, i64, f64)>,
+
+    /// Minimum reserve the agent must hold (i64 cents) - defaults to 0
+    ///
+    /// Regulatory floor on the balance: the reserve is excluded from the
+    /// freely usable balance in `available_liquidity()`, so a payment that
+    /// would dip below it settles only by drawing on overdraft capacity.
+    #[serde(default)]
+    reserve_requirement: i64,
+
+    /// Deferred credits received this tick but not yet applied (i64 cents)
+    ///
+    /// Raised as deferred-crediting settlements occur and promoted into
+    /// `balance` at end of tick; always 0 between ticks.
+    #[serde(default)]
+    provisional_credit: i64,
 }
 
 impl Agent {
@@ -334,6 +386,14 @@ impl Agent {
             allocated_liquidity: 0, // Default: no allocated liquidity
             // Max collateral capacity: None = use heuristic (10 × unsecured_cap)
             max_collateral_capacity: None,
+            // Not suspended by default
+            suspended: false,
+            suspension_resume_tick: None,
+            // No collateral classes by default (scalar haircut path)
+            collateral_classes: Vec::new(),
+            // No reserve requirement by default
+            reserve_requirement: 0,
+            provisional_credit: 0,
         }
     }
 
@@ -387,6 +447,14 @@ impl Agent {
             allocated_liquidity: 0, // Default: no allocated liquidity
             // Max collateral capacity: None = use heuristic (10 × unsecured_cap)
             max_collateral_capacity: None,
+            // Not suspended by default
+            suspended: false,
+            suspension_resume_tick: None,
+            // No collateral classes by default (scalar haircut path)
+            collateral_classes: Vec::new(),
+            // No reserve requirement by default
+            reserve_requirement: 0,
+            provisional_credit: 0,
         }
     }
 
@@ -421,12 +489,19 @@ impl Agent {
     ///     total_outflow: 0,
     ///     allocated_liquidity: None,
     ///     max_collateral_capacity: None,
+    ///     suspended: false,
+    ///     suspension_resume_tick: None,
+    ///     collateral_classes: vec![],
+    ///     state_registers: HashMap::new(),
+    ///     reserve_requirement: 0,
     /// };
     ///
     /// let agent = Agent::restore(data);
     /// assert_eq!(agent.id(), "BANK_A");
     /// ```
     pub fn restore(data: AgentRestoreData) -> Self {
+        // Snapshot totals include class collateral; the pool holds the rest
+        let class_total: i64 = data.collateral_classes.iter().map(|(_, amount, _)| amount).sum();
         Self {
             id: data.id,
             balance: data.balance,
@@ -434,7 +509,7 @@ impl Agent {
             incoming_expected: data.incoming_expected,
             last_decision_tick: data.last_decision_tick,
             liquidity_buffer: data.liquidity_buffer,
-            posted_collateral: data.posted_collateral,
+            posted_collateral: data.posted_collateral - class_total,
             collateral_haircut: data.collateral_haircut,
             unsecured_cap: data.unsecured_cap,
             collateral_posted_at_tick: data.collateral_posted_at_tick,
@@ -446,8 +521,8 @@ impl Agent {
             release_budget_per_counterparty_usage: std::collections::HashMap::new(),
             // Phase 3.4: Collateral timers (none by default - not checkpointed)
             collateral_withdrawal_timers: std::collections::HashMap::new(),
-            // Phase 4.5: State registers (none by default - not checkpointed)
-            state_registers: std::collections::HashMap::new(),
+            // Phase 4.5: State registers (restored from snapshot)
+            state_registers: data.state_registers,
             // Phase 1 (TARGET2 LSM): Limits (restored from snapshot)
             bilateral_limits: data.bilateral_limits,
             multilateral_limit: data.multilateral_limit,
@@ -457,6 +532,13 @@ impl Agent {
             allocated_liquidity: data.allocated_liquidity.unwrap_or(0),
             // Max collateral capacity (restored if set, otherwise None = heuristic)
             max_collateral_capacity: data.max_collateral_capacity,
+            // Suspension state (restored from snapshot)
+            suspended: data.suspended,
+            suspension_resume_tick: data.suspension_resume_tick,
+            // Collateral classes (restored from snapshot)
+            collateral_classes: data.collateral_classes,
+            reserve_requirement: data.reserve_requirement,
+            provisional_credit: 0,
         }
     }
 
@@ -500,6 +582,11 @@ impl Agent {
             total_outflow,
             allocated_liquidity: None, // Not tracked in legacy snapshots
             max_collateral_capacity: None, // Not tracked in legacy snapshots
+            suspended: false,              // Not tracked in legacy snapshots
+            suspension_resume_tick: None,
+            collateral_classes: Vec::new(),
+            state_registers: std::collections::HashMap::new(),
+            reserve_requirement: 0,
         })
     }
 
@@ -521,6 +608,44 @@ impl Agent {
         self.balance
     }
 
+    /// Get provisional balance (final balance plus deferred credits pending this tick)
+    ///
+    /// Equals `balance()` except mid-tick under deferred crediting, where
+    /// settled incoming payments are visible here before they are promoted.
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::Agent;
+    ///
+    /// let mut agent = Agent::new("BANK_A".to_string(), 100_000);
+    /// agent.add_provisional_credit(25_000);
+    /// assert_eq!(agent.balance(), 100_000);
+    /// assert_eq!(agent.provisional_balance(), 125_000);
+    ///
+    /// agent.promote_provisional_credit(25_000);
+    /// assert_eq!(agent.balance(), 125_000);
+    /// assert_eq!(agent.provisional_balance(), 125_000);
+    /// ```
+    pub fn provisional_balance(&self) -> i64 {
+        self.balance.saturating_add(self.provisional_credit)
+    }
+
+    /// Record a deferred credit that will be promoted to the balance at end of tick
+    ///
+    /// Does not change `balance()`, so the funds cannot be spent this tick.
+    pub fn add_provisional_credit(&mut self, amount: i64) {
+        assert!(amount >= 0, "amount must be positive");
+        self.provisional_credit = self.provisional_credit.saturating_add(amount);
+    }
+
+    /// Promote a deferred credit into the final balance
+    ///
+    /// Credits `amount` and releases it from the provisional portion.
+    pub fn promote_provisional_credit(&mut self, amount: i64) {
+        self.credit(amount);
+        self.provisional_credit = (self.provisional_credit - amount).max(0);
+    }
+
     /// Get amount of credit currently in use
     ///
     /// Returns the absolute value of negative balance (amount below zero).
@@ -553,6 +678,9 @@ impl Agent {
     /// allowed_overdraft_limit = floor(posted_collateral × (1 - haircut)) + unsecured_cap
     /// ```
     ///
+    /// With collateral classes, each class contributes `amount × (1 - class haircut)`
+    /// to the collateralized part (see [`Agent::collateral_capacity`]).
+    ///
     /// # Example
     /// ```
     /// use payment_simulator_core_rs::Agent;
@@ -567,9 +695,33 @@ impl Agent {
     /// assert_eq!(agent.allowed_overdraft_limit(), 110_000_00);
     /// ```
     pub fn allowed_overdraft_limit(&self) -> i64 {
-        let one_minus_haircut = (1.0 - self.collateral_haircut).max(0.0);
-        let collateral_capacity = (self.posted_collateral as f64 * one_minus_haircut).floor() as i64;
-        collateral_capacity + self.unsecured_cap
+        self.collateral_capacity() + self.unsecured_cap
+    }
+
+    /// Credit capacity provided by posted collateral after haircuts
+    ///
+    /// Formula:
+    /// ```text
+    /// collateral_capacity = floor(pool × (1 - haircut) + Σ class_amount × (1 - class_haircut))
+    /// ```
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::Agent;
+    ///
+    /// let mut agent = Agent::new("BANK_A".to_string(), 0);
+    /// agent.set_collateral_classes(vec![
+    ///     ("govt_bonds".to_string(), 100_000_00, 0.0),
+    ///     ("covered_bonds".to_string(), 50_000_00, 0.10),
+    /// ]);
+    ///
+    /// // 100k × 1.0 + 50k × 0.9 = 145k
+    /// assert_eq!(agent.collateral_capacity(), 145_000_00);
+    /// assert_eq!(agent.posted_collateral(), 150_000_00);
+    /// ```
+    pub fn collateral_capacity(&self) -> i64 {
+        let pool_value = self.posted_collateral as f64 * (1.0 - self.collateral_haircut).max(0.0);
+        (pool_value + self.collateral_class_value()).floor() as i64
     }
 
     /// Calculate current overdraft headroom
@@ -607,6 +759,9 @@ impl Agent {
     /// 1. Maintaining allowed_overdraft_limit ≥ credit_used + buffer
     /// 2. Not going negative on posted collateral
     ///
+    /// Only the collateral pool is withdrawable; collateral classes count
+    /// towards capacity but stay pledged.
+    ///
     /// Formula:
     /// ```text
     /// required_collateral = ceil((credit_used + buffer - unsecured_cap) / (1 - haircut))
@@ -643,11 +798,12 @@ impl Agent {
         }
 
         // Calculate required collateral to maintain: allowed_limit ≥ credit_used + buffer
-        // Need: C × (1 - h) + unsecured_cap ≥ credit_used + buffer
-        // Therefore: C ≥ (credit_used + buffer - unsecured_cap) / (1 - h)
+        // Need: C × (1 - h) + unsecured_cap + class_capacity ≥ credit_used + buffer
+        // Therefore: C ≥ (credit_used + buffer - unsecured_cap - class_capacity) / (1 - h)
         let credit_used = self.credit_used();
         let target_limit = credit_used + buffer;
-        let unsecured_contribution = self.unsecured_cap.min(target_limit); // Can't use more than needed
+        let fixed_capacity = self.unsecured_cap + self.collateral_class_value().floor() as i64;
+        let unsecured_contribution = fixed_capacity.min(target_limit); // Can't use more than needed
 
         let required_from_collateral = target_limit.saturating_sub(unsecured_contribution);
         let required_collateral_f = required_from_collateral as f64 / one_minus_haircut;
@@ -661,7 +817,7 @@ impl Agent {
     ///
     /// Formula:
     /// ```text
-    /// available_liquidity = max(0, balance) + max(0, headroom - credit_used)
+    /// available_liquidity = max(0, balance - reserve_requirement) + max(0, headroom - credit_used)
     ///
     /// where:
     ///   headroom = credit_limit + floor(posted_collateral × (1 - haircut)) + unsecured_cap
@@ -673,6 +829,8 @@ impl Agent {
     /// - Negative balance means credit is in use, reducing available headroom
     /// - Posted collateral adds to headroom, discounted by haircut (e.g., 2% haircut → 98% value)
     /// - Unsecured cap provides additional overdraft capacity
+    /// - The reserve requirement is held back from the balance, but not from
+    ///   overdraft capacity, so only credit can take the balance below it
     ///
     /// # Example
     /// ```
@@ -695,16 +853,15 @@ impl Agent {
     /// assert_eq!(agent2.available_liquidity(), 108000);
     /// ```
     pub fn available_liquidity(&self) -> i64 {
-        // Calculate usable funds from positive balance
-        let balance_liquidity = self.balance.max(0);
+        // Calculate usable funds from positive balance above the reserve
+        let balance_liquidity = (self.balance - self.reserve_requirement).max(0);
 
         // Calculate credit in use (negative balance means using credit)
         let credit_used = (-self.balance).max(0);
 
         // Calculate total headroom with collateral (discounted by haircut)
         // haircut is now the discount rate, so use (1 - haircut)
-        let one_minus_haircut = (1.0 - self.collateral_haircut).max(0.0);
-        let collateral_headroom = (self.posted_collateral as f64 * one_minus_haircut).floor() as i64;
+        let collateral_headroom = self.collateral_capacity();
 
         // Total overdraft headroom = unsecured + collateralized
         let unsecured_headroom = self.unsecured_cap;
@@ -983,18 +1140,88 @@ impl Agent {
         self.liquidity_buffer = buffer;
     }
 
+    /// Get reserve requirement (minimum balance held back from payments)
+    pub fn reserve_requirement(&self) -> i64 {
+        self.reserve_requirement
+    }
+
+    /// Set reserve requirement
+    ///
+    /// Unlike the liquidity buffer, which policies may consult, the reserve
+    /// is enforced at settlement through `available_liquidity()`.
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::Agent;
+    ///
+    /// let mut agent = Agent::new("BANK_A".to_string(), 1_000_000);
+    /// agent.set_reserve_requirement(400_000);
+    /// assert_eq!(agent.available_liquidity(), 600_000);
+    /// assert!(!agent.can_pay(700_000));
+    /// ```
+    pub fn set_reserve_requirement(&mut self, reserve: i64) {
+        assert!(reserve >= 0, "reserve_requirement must be non-negative");
+        self.reserve_requirement = reserve;
+    }
+
     /// Get posted collateral amount (Phase 8)
     ///
-    /// Returns the amount of collateral posted to secure intraday credit.
-    /// Accrues opportunity cost per tick.
+    /// Returns the amount of collateral posted to secure intraday credit,
+    /// including any collateral classes. Accrues opportunity cost per tick.
     pub fn posted_collateral(&self) -> i64 {
+        self.posted_collateral + self.collateral_class_total()
+    }
+
+    /// Collateral in the withdrawable pool (posted collateral excluding classes)
+    pub fn pool_collateral(&self) -> i64 {
         self.posted_collateral
     }
 
+    /// Get collateral classes: (class name, amount in cents, haircut)
+    pub fn collateral_classes(&self) -> &[(String, i64, f64)] {
+        &self.collateral_classes
+    }
+
+    /// Set collateral pledged by asset class
+    ///
+    /// Replaces any existing classes. The withdrawable pool is unchanged, so
+    /// `posted_collateral()` grows by the class total.
+    ///
+    /// # Panics
+    /// Panics if an amount is negative or a haircut is not in range [0.0, 1.0]
+    pub fn set_collateral_classes(&mut self, classes: Vec<(String, i64, f64)>) {
+        for (name, amount, haircut) in &classes {
+            assert!(*amount >= 0, "collateral class {} amount must be non-negative", name);
+            assert!(
+                (0.0..=1.0).contains(haircut),
+                "collateral class {} haircut must be between 0.0 and 1.0",
+                name
+            );
+        }
+        self.collateral_classes = classes;
+    }
+
+    /// Total collateral pledged across classes (before haircuts)
+    fn collateral_class_total(&self) -> i64 {
+        self.collateral_classes.iter().map(|(_, amount, _)| amount).sum()
+    }
+
+    /// Haircut-adjusted value of the collateral classes (unrounded)
+    fn collateral_class_value(&self) -> f64 {
+        self.collateral_classes
+            .iter()
+            .map(|(_, amount, haircut)| *amount as f64 * (1.0 - haircut).max(0.0))
+            .sum()
+    }
+
     /// Set posted collateral amount (Phase 8)
     ///
+    /// Sets the total reported by `posted_collateral()`; with collateral
+    /// classes the withdrawable pool becomes `collateral - class total`.
+    ///
     /// # Arguments
-    /// * `collateral` - Collateral amount in cents (must be non-negative)
+    /// * `collateral` - Collateral amount in cents (must be non-negative and
+    ///   not below the collateral class total)
     ///
     /// # Example
     /// ```
@@ -1007,7 +1234,12 @@ impl Agent {
     /// ```
     pub fn set_posted_collateral(&mut self, collateral: i64) {
         assert!(collateral >= 0, "posted_collateral must be non-negative");
-        self.posted_collateral = collateral;
+        let class_total = self.collateral_class_total();
+        assert!(
+            collateral >= class_total,
+            "posted_collateral cannot fall below the collateral class total"
+        );
+        self.posted_collateral = collateral - class_total;
     }
 
     /// Set collateral haircut (discount rate)
@@ -1173,12 +1405,12 @@ impl Agent {
         // Clamp to safe amount (partial withdrawal allowed)
         let actual = requested.min(max_safe).min(self.posted_collateral);
 
-        // Apply withdrawal
-        let new_total = self.posted_collateral - actual;
-        self.set_posted_collateral(new_total);
+        // Apply withdrawal (pool only; collateral classes stay pledged)
+        let new_pool = self.posted_collateral - actual;
+        self.posted_collateral = new_pool;
 
         // Clear posted_at_tick if all collateral withdrawn
-        if new_total == 0 {
+        if new_pool == 0 {
             self.collateral_posted_at_tick = None;
         }
 
@@ -1244,7 +1476,7 @@ impl Agent {
     /// assert!((agent.liquidity_pressure() - 0.33).abs() < 0.01); // ~33% into available liquidity
     /// ```
     pub fn liquidity_pressure(&self) -> f64 {
-        let total_liquidity = (self.balance + self.unsecured_cap + self.posted_collateral) as f64;
+        let total_liquidity = (self.balance + self.unsecured_cap + self.posted_collateral()) as f64;
         if total_liquidity == 0.0 {
             return 1.0; // Maximum stress if no liquidity
         }
@@ -1322,6 +1554,44 @@ impl Agent {
         self.max_collateral_capacity
     }
 
+    /// Check whether the agent is suspended (operational outage)
+    pub fn is_suspended(&self) -> bool {
+        self.suspended
+    }
+
+    /// Tick at which a suspended agent automatically resumes
+    ///
+    /// Returns `None` when not suspended or when the suspension is open-ended.
+    pub fn suspension_resume_tick(&self) -> Option<usize> {
+        self.suspension_resume_tick
+    }
+
+    /// Suspend the agent, optionally until `resume_tick`
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::Agent;
+    ///
+    /// let mut agent = Agent::new("BANK_A".to_string(), 1000000);
+    /// agent.suspend(Some(20));
+    /// assert!(agent.is_suspended());
+    /// assert_eq!(agent.suspension_resume_tick(), Some(20));
+    ///
+    /// agent.resume();
+    /// assert!(!agent.is_suspended());
+    /// assert_eq!(agent.suspension_resume_tick(), None);
+    /// ```
+    pub fn suspend(&mut self, resume_tick: Option<usize>) {
+        self.suspended = true;
+        self.suspension_resume_tick = resume_tick;
+    }
+
+    /// Lift a suspension
+    pub fn resume(&mut self) {
+        self.suspended = false;
+        self.suspension_resume_tick = None;
+    }
+
     /// Calculate remaining collateral capacity
     ///
     /// Returns how much additional collateral the agent can post.
@@ -1341,7 +1611,7 @@ impl Agent {
     /// assert_eq!(agent.remaining_collateral_capacity(), 4_000_000); // 4M remaining
     /// ```
     pub fn remaining_collateral_capacity(&self) -> i64 {
-        self.max_collateral_capacity() - self.posted_collateral
+        self.max_collateral_capacity() - self.posted_collateral()
     }
 
     /// Calculate Queue 1 liquidity gap
@@ -1753,6 +2023,26 @@ impl Agent {
         self.total_outflow
     }
 
+    /// Fraction of each bilateral limit used today (counterparty_id -> outflow / limit)
+    ///
+    /// Only counterparties with a configured limit are included. A
+    /// non-positive limit has no headroom and reports 1.0.
+    pub fn bilateral_utilization(&self) -> std::collections::HashMap<String, f64> {
+        self.bilateral_limits
+            .iter()
+            .map(|(counterparty, limit)| {
+                let outflow = self.bilateral_outflows.get(counterparty).copied().unwrap_or(0);
+                (counterparty.clone(), limit_utilization(outflow, *limit))
+            })
+            .collect()
+    }
+
+    /// Fraction of the multilateral limit used today (None if no limit configured)
+    pub fn multilateral_utilization(&self) -> Option<f64> {
+        self.multilateral_limit
+            .map(|limit| limit_utilization(self.total_outflow, limit))
+    }
+
     /// Record a payment outflow (called when settlement occurs)
     /// Returns true if within limits, false if exceeds limits
     pub fn record_outflow(&mut self, receiver_id: &str, amount: i64) {
@@ -1792,6 +2082,15 @@ impl Agent {
     }
 }
 
+/// Outflow as a fraction of `limit`; a non-positive limit counts as fully used
+fn limit_utilization(outflow: i64, limit: i64) -> f64 {
+    if limit <= 0 {
+        1.0
+    } else {
+        outflow as f64 / limit as f64
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1954,6 +2253,59 @@ mod tests {
         assert_eq!(gap, 0);
     }
 
+    #[test]
+    fn test_collateral_classes_blend_haircuts() {
+        let mut agent = Agent::new("BANK_A".to_string(), 0);
+        agent.set_unsecured_cap(50_000);
+        agent.set_collateral_classes(vec![
+            ("govt_bonds".to_string(), 1_000_000, 0.0),
+            ("corporate_bonds".to_string(), 500_000, 0.10),
+        ]);
+
+        // 1M × 1.0 + 500k × 0.9 = 1.45M collateralized, plus 50k unsecured
+        assert_eq!(agent.posted_collateral(), 1_500_000);
+        assert_eq!(agent.collateral_capacity(), 1_450_000);
+        assert_eq!(agent.allowed_overdraft_limit(), 1_500_000);
+        assert_eq!(agent.available_liquidity(), 1_500_000);
+        assert!(agent.can_pay(1_500_000));
+        assert!(!agent.can_pay(1_500_001));
+    }
+
+    #[test]
+    fn test_collateral_classes_combine_with_pool() {
+        let mut agent = Agent::new("BANK_A".to_string(), -1_000_000);
+        agent.set_collateral_haircut(0.02);
+        agent.set_posted_collateral(200_000);
+        agent.set_collateral_classes(vec![
+            ("govt_bonds".to_string(), 600_000, 0.0),
+            ("corporate_bonds".to_string(), 400_000, 0.10),
+        ]);
+
+        // Pool 200k × 0.98 + 600k + 400k × 0.9 = 1.156M
+        assert_eq!(agent.posted_collateral(), 1_200_000);
+        assert_eq!(agent.pool_collateral(), 200_000);
+        assert_eq!(agent.allowed_overdraft_limit(), 1_156_000);
+
+        // Classes cover 960k of the 1M used; the pool must keep ceil(40k / 0.98)
+        assert_eq!(agent.max_withdrawable_collateral(0), 200_000 - 40_817);
+
+        // Posting more grows the pool only
+        agent.set_posted_collateral(agent.posted_collateral() + 100_000);
+        assert_eq!(agent.pool_collateral(), 300_000);
+        assert_eq!(agent.collateral_classes().len(), 2);
+    }
+
+    #[test]
+    fn test_collateral_classes_absent_keeps_scalar_path() {
+        let mut agent = Agent::new("BANK_A".to_string(), 0);
+        agent.set_posted_collateral(1_000_000);
+        agent.set_collateral_haircut(0.10);
+
+        assert!(agent.collateral_classes().is_empty());
+        assert_eq!(agent.posted_collateral(), agent.pool_collateral());
+        assert_eq!(agent.allowed_overdraft_limit(), 900_000);
+    }
+
     #[test]
     fn test_liquidity_pressure_with_collateral() {
         let mut agent = Agent::new("BANK_A".to_string(), 1_000_000);
@@ -2007,4 +2359,30 @@ mod tests {
         assert_eq!(agent.posted_collateral(), 0);
         assert_eq!(agent.available_liquidity(), 1_500_000);
     }
+
+    #[test]
+    fn test_limit_utilization() {
+        let mut agent = Agent::new("BANK_A".to_string(), 1_000_000);
+        agent.set_bilateral_limits(
+            [("BANK_B".to_string(), 200_000), ("BANK_C".to_string(), 0)]
+                .into_iter()
+                .collect(),
+        );
+        assert_eq!(agent.multilateral_utilization(), None);
+        agent.set_multilateral_limit(Some(400_000));
+
+        agent.record_outflow("BANK_B", 50_000);
+        agent.record_outflow("BANK_D", 50_000);
+
+        let bilateral = agent.bilateral_utilization();
+        assert_eq!(bilateral.len(), 2);
+        assert_eq!(bilateral["BANK_B"], 0.25);
+        // A zero limit leaves no headroom
+        assert_eq!(bilateral["BANK_C"], 1.0);
+        assert_eq!(agent.multilateral_utilization(), Some(0.25));
+
+        agent.reset_daily_outflows();
+        assert_eq!(agent.bilateral_utilization()["BANK_B"], 0.0);
+        assert_eq!(agent.multilateral_utilization(), Some(0.0));
+    }
 }
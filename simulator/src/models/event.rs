@@ -36,13 +36,16 @@
 //! println!("Event at tick {}: {:?}", event.tick(), event);
 //! ```
 
+use crate::models::transaction::SettlementMechanism;
 use crate::orchestrator::CostBreakdown;
+use serde::{Deserialize, Serialize};
 
 /// Simulation event capturing a state change.
 ///
 /// All events include a tick number for temporal ordering.
 /// Events are logged in the order they occur within a tick.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type")]
 pub enum Event {
     /// New transaction arrived (generated or injected)
     Arrival {
@@ -56,6 +59,16 @@ pub enum Event {
         is_divisible: bool,
     },
 
+    /// Sampled arrival count truncated by `max_arrivals_per_agent_per_tick`
+    ///
+    /// Only `max_count` transactions were generated for the agent this tick.
+    ArrivalsClamped {
+        tick: usize,
+        agent_id: String,
+        sampled_count: usize,   // Count drawn before clamping (all bands)
+        max_count: usize,       // Configured cap
+    },
+
     /// Policy decided to submit transaction from Queue 1 to settlement
     PolicySubmit {
         tick: usize,
@@ -202,6 +215,7 @@ pub enum Event {
         amount: i64,
         sender_balance_before: i64,  // For audit trail
         sender_balance_after: i64,   // For audit trail
+        settlement_mechanism: SettlementMechanism,  // Always RtgsImmediate
     },
 
     /// Transaction queued to RTGS queue (insufficient liquidity)
@@ -211,6 +225,18 @@ pub enum Event {
         sender_id: String,
     },
 
+    /// Transaction rejected from a full RTGS queue back to the sender's Queue 1
+    ///
+    /// Emitted instead of `QueuedRtgs` when `rtgs_queue_capacity` is set and
+    /// Queue 2 already holds that many transactions.
+    RtgsQueueFull {
+        tick: usize,
+        tx_id: String,
+        sender_id: String,
+        queue_size: usize,
+        capacity: usize,
+    },
+
     /// Transaction submitted to RTGS Queue 2 (Phase 0: Dual Priority System)
     ///
     /// Emitted when a transaction is released from Queue 1 (internal bank queue)
@@ -237,6 +263,33 @@ pub enum Event {
         original_rtgs_priority: String,  // Priority it had before withdrawal
     },
 
+    /// Transaction cancelled before settlement via `Orchestrator::cancel_transaction`
+    ///
+    /// `queue` names where it was removed from ("queue1" or "queue2");
+    /// `remaining_amount` is the value that will now never settle.
+    TransactionCancelled {
+        tick: usize,
+        tx_id: String,
+        sender_id: String,
+        receiver_id: String,
+        remaining_amount: i64,
+        queue: String,
+    },
+
+    /// Transaction returned unsettled after `max_settlement_retries` failed
+    /// Queue 2 retries
+    ///
+    /// The transaction has been removed from Queue 2 and marked
+    /// `TransactionStatus::Returned`; `remaining_amount` will never settle.
+    SettlementAbandoned {
+        tick: usize,
+        tx_id: String,
+        sender_id: String,
+        receiver_id: String,
+        remaining_amount: i64,
+        retry_count: usize,
+    },
+
     /// Transaction resubmitted to RTGS Queue 2 with new priority (Phase 0: Dual Priority System)
     ///
     /// Emitted when a previously withdrawn transaction is resubmitted to Queue 2.
@@ -257,6 +310,7 @@ pub enum Event {
         amount_a: i64,
         amount_b: i64,
         tx_ids: Vec<String>,  // Changed from tx_id_a, tx_id_b to match test expectations
+        settlement_mechanism: SettlementMechanism,  // Always BilateralOffset
     },
 
     /// Transaction settled via LSM cycle detection
@@ -269,6 +323,21 @@ pub enum Event {
         max_net_outflow: i64,          // NEW: Maximum net outflow in cycle
         max_net_outflow_agent: String, // NEW: Agent with max net outflow
         tx_ids: Vec<String>,
+        settlement_mechanism: SettlementMechanism,  // Always MultilateralCycle
+    },
+
+    /// Queue settled via LSM multilateral netting (Algorithm 4)
+    ///
+    /// `agents` and `net_positions` are aligned and sorted by agent ID.
+    /// `removed_agents` were excluded as infeasible; their payments stay queued.
+    LsmNettingSettlement {
+        tick: usize,
+        agents: Vec<String>,
+        removed_agents: Vec<String>,
+        total_value: i64,
+        net_positions: Vec<i64>,
+        tx_ids: Vec<String>,
+        settlement_mechanism: SettlementMechanism,  // Always MultilateralNetting
     },
 
     /// Costs accrued for an agent this tick
@@ -311,6 +380,92 @@ pub enum Event {
         balance_after: i64,    // Agent balance after allocation
     },
 
+    /// External liquidity injected into an agent (scenario event)
+    ///
+    /// Emitted when a `LiquidityInjection` scenario event credits an agent
+    /// with no counterparty debit. Total system balance grows by `amount`.
+    LiquidityInjection {
+        tick: usize,
+        agent_id: String,
+        amount: i64,           // Amount injected
+        balance_after: i64,    // Agent balance after injection
+    },
+
+    /// Scheduled release fired for a transaction held in Queue 1
+    ///
+    /// Emitted when a policy submitted a transaction with a future
+    /// `target_tick` and that tick has arrived. The transaction moves from
+    /// Queue 1 to settlement without further policy evaluation.
+    ScheduledReleaseTriggered {
+        tick: usize,
+        agent_id: String,
+        tx_id: String,
+        amount: i64,
+        scheduled_tick: usize,  // Target tick chosen by the policy
+    },
+
+    /// Transaction force-released from Queue 1 at end of day
+    ///
+    /// Emitted on the last tick of a day when `force_release_at_eod` is set,
+    /// after the normal policy pass. The transaction is submitted to RTGS
+    /// regardless of the policy's decision.
+    EodForceRelease {
+        tick: usize,
+        agent_id: String,
+        tx_id: String,
+        amount: i64,
+    },
+
+    /// Agent suspended by an `AgentSuspend` scenario event
+    ///
+    /// While suspended the agent's policies are skipped and nothing leaves
+    /// its Queue 1. Incoming payments still settle normally.
+    AgentSuspended {
+        tick: usize,
+        agent_id: String,
+        resume_tick: Option<usize>,  // Automatic resume tick (None = open-ended)
+    },
+
+    /// Suspended agent resumed (explicit `AgentResume` or scheduled resume tick)
+    AgentResumed {
+        tick: usize,
+        agent_id: String,
+    },
+
+    /// Live cost rate changed by a `CostRateChange` scenario event
+    CostRateChanged {
+        tick: usize,
+        field: String,
+        old_value: f64,
+        new_value: f64,
+    },
+
+    /// Agent's pool allocation changed by a `ReallocateLiquidity` scenario event
+    ///
+    /// The balance moved by `new_allocation - old_allocation`.
+    LiquidityReallocated {
+        tick: usize,
+        agent_id: String,
+        new_fraction: f64,
+        old_allocation: i64,
+        new_allocation: i64,
+        balance_after: i64,
+    },
+
+    /// Queued transaction partially settled from Queue 2
+    ///
+    /// Emitted when `partial_settlement` is enabled and the sender could afford
+    /// only part of a queued payment. The settled chunk moves atomically
+    /// (debit + credit); the remainder stays in Queue 2.
+    PartialSettlement {
+        tick: usize,
+        tx_id: String,
+        sender: String,
+        receiver: String,
+        settled_amount: i64,     // Amount settled this time
+        remaining_amount: i64,   // Amount still queued
+    },
+
     /// Transaction crossed its deadline and became overdue
     ///
     /// Emitted when a transaction first becomes overdue. The one-time deadline penalty
@@ -346,6 +501,23 @@ pub enum Event {
         estimated_delay_cost: i64,      // Accumulated delay costs while overdue
     },
 
+    /// Overdue transaction force-settled by the deadline hard cutoff
+    ///
+    /// Emitted under `DeadlineEnforcement::PenalizeAndForceSettle`. The
+    /// transaction bypasses its policy, Queue 2 ordering and counterparty
+    /// limits, and may leave the sender in overdraft (within its allowed limit).
+    ForcedSettlement {
+        tick: usize,
+        tx_id: String,
+        sender_id: String,
+        receiver_id: String,
+        amount: i64,                    // Amount settled (remaining at cutoff)
+        deadline_tick: usize,           // Original deadline
+        sender_balance_before: i64,
+        sender_balance_after: i64,
+        queue: String,                  // "queue1" or "queue2"
+    },
+
     /// Scenario event executed
     ///
     /// Emitted when a configured scenario event executes (direct transfer,
@@ -369,6 +541,7 @@ pub enum Event {
         amount: i64,
         queue_wait_ticks: i64,       // How long it waited in queue
         release_reason: String,       // "NewLiquidity", "IncomingPayment", "CollateralPosted", etc.
+        settlement_mechanism: SettlementMechanism,  // Always RtgsQueue
     },
 
     /// Bilateral limit exceeded - payment blocked due to counterparty-specific limit
@@ -400,11 +573,11 @@ pub enum Event {
 
     /// Algorithm execution event - records which settlement algorithm ran
     ///
-    /// Emitted when a settlement algorithm (1-FIFO, 2-Bilateral, 3-Multilateral)
-    /// completes execution. Part of TARGET2 LSM Phase 2 (Algorithm Sequencing).
+    /// Emitted when a settlement algorithm (1-FIFO, 2-Bilateral, 3-Multilateral,
+    /// 4-Netting when enabled) completes execution. Part of TARGET2 LSM Phase 2 (Algorithm Sequencing).
     AlgorithmExecution {
         tick: usize,
-        algorithm: u8,           // 1=FIFO, 2=Bilateral, 3=Multilateral
+        algorithm: u8,           // 1=FIFO, 2=Bilateral, 3=Multilateral, 4=Netting
         result: String,          // "Success", "NoProgress", "Failure"
         settlements: usize,      // Number of transactions settled
         settled_value: i64,      // Total value settled in cents
@@ -459,6 +632,7 @@ impl Event {
     pub fn tick(&self) -> usize {
         match self {
             Event::Arrival { tick, .. } => *tick,
+            Event::ArrivalsClamped { tick, .. } => *tick,
             Event::PolicySubmit { tick, .. } => *tick,
             Event::PolicyHold { tick, .. } => *tick,
             Event::PolicyDrop { tick, .. } => *tick,
@@ -473,15 +647,20 @@ impl Event {
             Event::BankBudgetSet { tick, .. } => *tick,
             Event::RtgsImmediateSettlement { tick, .. } => *tick,
             Event::QueuedRtgs { tick, .. } => *tick,
+            Event::RtgsQueueFull { tick, .. } => *tick,
             Event::RtgsSubmission { tick, .. } => *tick,
             Event::RtgsWithdrawal { tick, .. } => *tick,
+            Event::TransactionCancelled { tick, .. } => *tick,
+            Event::SettlementAbandoned { tick, .. } => *tick,
             Event::RtgsResubmission { tick, .. } => *tick,
             Event::LsmBilateralOffset { tick, .. } => *tick,
             Event::LsmCycleSettlement { tick, .. } => *tick,
+            Event::LsmNettingSettlement { tick, .. } => *tick,
             Event::CostAccrual { tick, .. } => *tick,
             Event::EndOfDay { tick, .. } => *tick,
             Event::TransactionWentOverdue { tick, .. } => *tick,
             Event::OverdueTransactionSettled { tick, .. } => *tick,
+            Event::ForcedSettlement { tick, .. } => *tick,
             Event::ScenarioEventExecuted { tick, .. } => *tick,
             Event::Queue2LiquidityRelease { tick, .. } => *tick,
             Event::BilateralLimitExceeded { tick, .. } => *tick,
@@ -493,6 +672,14 @@ impl Event {
             Event::DeferredCreditApplied { tick, .. } => *tick,
             Event::LiquidityReturn { tick, .. } => *tick,
             Event::LiquidityAllocation { tick, .. } => *tick,
+            Event::PartialSettlement { tick, .. } => *tick,
+            Event::LiquidityInjection { tick, .. } => *tick,
+            Event::ScheduledReleaseTriggered { tick, .. } => *tick,
+            Event::EodForceRelease { tick, .. } => *tick,
+            Event::AgentSuspended { tick, .. } => *tick,
+            Event::AgentResumed { tick, .. } => *tick,
+            Event::CostRateChanged { tick, .. } => *tick,
+            Event::LiquidityReallocated { tick, .. } => *tick,
         }
     }
 
@@ -500,6 +687,7 @@ impl Event {
     pub fn event_type(&self) -> &'static str {
         match self {
             Event::Arrival { .. } => "Arrival",
+            Event::ArrivalsClamped { .. } => "ArrivalsClamped",
             Event::PolicySubmit { .. } => "PolicySubmit",
             Event::PolicyHold { .. } => "PolicyHold",
             Event::PolicyDrop { .. } => "PolicyDrop",
@@ -514,15 +702,20 @@ impl Event {
             Event::BankBudgetSet { .. } => "BankBudgetSet",
             Event::RtgsImmediateSettlement { .. } => "RtgsImmediateSettlement",
             Event::QueuedRtgs { .. } => "QueuedRtgs",
+            Event::RtgsQueueFull { .. } => "RtgsQueueFull",
             Event::RtgsSubmission { .. } => "RtgsSubmission",
             Event::RtgsWithdrawal { .. } => "RtgsWithdrawal",
+            Event::TransactionCancelled { .. } => "TransactionCancelled",
+            Event::SettlementAbandoned { .. } => "SettlementAbandoned",
             Event::RtgsResubmission { .. } => "RtgsResubmission",
             Event::LsmBilateralOffset { .. } => "LsmBilateralOffset",
             Event::LsmCycleSettlement { .. } => "LsmCycleSettlement",
+            Event::LsmNettingSettlement { .. } => "LsmNettingSettlement",
             Event::CostAccrual { .. } => "CostAccrual",
             Event::EndOfDay { .. } => "EndOfDay",
             Event::TransactionWentOverdue { .. } => "TransactionWentOverdue",
             Event::OverdueTransactionSettled { .. } => "OverdueTransactionSettled",
+            Event::ForcedSettlement { .. } => "ForcedSettlement",
             Event::ScenarioEventExecuted { .. } => "ScenarioEventExecuted",
             Event::Queue2LiquidityRelease { .. } => "Queue2LiquidityRelease",
             Event::BilateralLimitExceeded { .. } => "BilateralLimitExceeded",
@@ -534,6 +727,14 @@ impl Event {
             Event::DeferredCreditApplied { .. } => "DeferredCreditApplied",
             Event::LiquidityReturn { .. } => "LiquidityReturn",
             Event::LiquidityAllocation { .. } => "LiquidityAllocation",
+            Event::PartialSettlement { .. } => "PartialSettlement",
+            Event::LiquidityInjection { .. } => "LiquidityInjection",
+            Event::ScheduledReleaseTriggered { .. } => "ScheduledReleaseTriggered",
+            Event::EodForceRelease { .. } => "EodForceRelease",
+            Event::AgentSuspended { .. } => "AgentSuspended",
+            Event::AgentResumed { .. } => "AgentResumed",
+            Event::CostRateChanged { .. } => "CostRateChanged",
+            Event::LiquidityReallocated { .. } => "LiquidityReallocated",
         }
     }
 
@@ -549,13 +750,20 @@ impl Event {
             Event::PriorityEscalated { tx_id, .. } => Some(tx_id),
             Event::RtgsImmediateSettlement { tx_id, .. } => Some(tx_id),
             Event::QueuedRtgs { tx_id, .. } => Some(tx_id),
+            Event::RtgsQueueFull { tx_id, .. } => Some(tx_id),
+            Event::TransactionCancelled { tx_id, .. } => Some(tx_id),
+            Event::SettlementAbandoned { tx_id, .. } => Some(tx_id),
             Event::TransactionWentOverdue { tx_id, .. } => Some(tx_id),
             Event::OverdueTransactionSettled { tx_id, .. } => Some(tx_id),
+            Event::ForcedSettlement { tx_id, .. } => Some(tx_id),
             Event::Queue2LiquidityRelease { tx_id, .. } => Some(tx_id),
             Event::BilateralLimitExceeded { tx_id, .. } => Some(tx_id),
             Event::MultilateralLimitExceeded { tx_id, .. } => Some(tx_id),
             #[allow(deprecated)]
             Event::RtgsQueue2Settle { tx_id, .. } => Some(tx_id),
+            Event::PartialSettlement { tx_id, .. } => Some(tx_id),
+            Event::ScheduledReleaseTriggered { tx_id, .. } => Some(tx_id),
+            Event::EodForceRelease { tx_id, .. } => Some(tx_id),
             _ => None,
         }
     }
@@ -564,6 +772,7 @@ impl Event {
     pub fn agent_id(&self) -> Option<&str> {
         match self {
             Event::Arrival { sender_id, .. } => Some(sender_id),
+            Event::ArrivalsClamped { agent_id, .. } => Some(agent_id),
             Event::PolicySubmit { agent_id, .. } => Some(agent_id),
             Event::PolicyHold { agent_id, .. } => Some(agent_id),
             Event::PolicyDrop { agent_id, .. } => Some(agent_id),
@@ -574,15 +783,26 @@ impl Event {
             Event::CollateralWithdraw { agent_id, .. } => Some(agent_id),
             Event::RtgsImmediateSettlement { sender, .. } => Some(sender),
             Event::QueuedRtgs { sender_id, .. } => Some(sender_id),
+            Event::RtgsQueueFull { sender_id, .. } => Some(sender_id),
+            Event::TransactionCancelled { sender_id, .. } => Some(sender_id),
+            Event::SettlementAbandoned { sender_id, .. } => Some(sender_id),
             Event::CostAccrual { agent_id, .. } => Some(agent_id),
             Event::TransactionWentOverdue { sender_id, .. } => Some(sender_id),
             Event::OverdueTransactionSettled { sender_id, .. } => Some(sender_id),
+            Event::ForcedSettlement { sender_id, .. } => Some(sender_id),
             Event::Queue2LiquidityRelease { sender, .. } => Some(sender),
             Event::BilateralLimitExceeded { sender, .. } => Some(sender),
             Event::MultilateralLimitExceeded { sender, .. } => Some(sender),
             #[allow(deprecated)]
             Event::RtgsQueue2Settle { sender, .. } => Some(sender),
             Event::DeferredCreditApplied { agent_id, .. } => Some(agent_id),
+            Event::PartialSettlement { sender, .. } => Some(sender),
+            Event::LiquidityInjection { agent_id, .. } => Some(agent_id),
+            Event::ScheduledReleaseTriggered { agent_id, .. } => Some(agent_id),
+            Event::EodForceRelease { agent_id, .. } => Some(agent_id),
+            Event::AgentSuspended { agent_id, .. } => Some(agent_id),
+            Event::AgentResumed { agent_id, .. } => Some(agent_id),
+            Event::LiquidityReallocated { agent_id, .. } => Some(agent_id),
             _ => None,
         }
     }
@@ -627,6 +847,14 @@ impl EventLog {
         self.events.iter().filter(|e| e.tick() == tick).collect()
     }
 
+    /// Get events with `start_tick <= tick < end_tick` (inclusive-exclusive)
+    pub fn events_in_range(&self, start_tick: usize, end_tick: usize) -> Vec<&Event> {
+        self.events
+            .iter()
+            .filter(|e| (start_tick..end_tick).contains(&e.tick()))
+            .collect()
+    }
+
     /// Get events of a specific type
     pub fn events_of_type(&self, event_type: &str) -> Vec<&Event> {
         self.events
@@ -655,12 +883,49 @@ impl EventLog {
     pub fn clear(&mut self) {
         self.events.clear();
     }
+
+    /// Serialize the log as JSON Lines, one event per line in log order
+    ///
+    /// Each line is an object with a `type` tag (the variant name) plus the
+    /// variant's fields, so `tick` is present on every line. Lines parse
+    /// back into `Event` with `serde_json::from_str`.
+    pub fn to_jsonl(&self) -> String {
+        let mut out = String::new();
+        for event in &self.events {
+            out.push_str(&serde_json::to_string(event).expect("events serialize to JSON"));
+            out.push('\n');
+        }
+        out
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_to_jsonl_one_line_per_event() {
+        let mut log = EventLog::new();
+        assert_eq!(log.to_jsonl(), "");
+
+        log.log(Event::AgentResumed {
+            tick: 3,
+            agent_id: "BANK_A".to_string(),
+        });
+        log.log(Event::AgentResumed {
+            tick: 4,
+            agent_id: "BANK_B".to_string(),
+        });
+
+        let jsonl = log.to_jsonl();
+        let lines: Vec<&str> = jsonl.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(
+            lines[0],
+            r#"{"type":"AgentResumed","tick":3,"agent_id":"BANK_A"}"#
+        );
+    }
+
     #[test]
     fn test_event_tick() {
         let event = Event::Arrival {
@@ -687,6 +952,7 @@ mod tests {
             amount: 100_000,
             sender_balance_before: 500_000,
             sender_balance_after: 400_000,
+            settlement_mechanism: SettlementMechanism::RtgsImmediate,
         };
 
         assert_eq!(event.event_type(), "RtgsImmediateSettlement");
@@ -760,6 +1026,7 @@ mod tests {
             amount: 100_000,
             sender_balance_before: 500_000,
             sender_balance_after: 400_000,
+            settlement_mechanism: SettlementMechanism::RtgsImmediate,
         });
 
         log.log(Event::Arrival {
@@ -803,6 +1070,7 @@ mod tests {
             amount: 100_000,
             sender_balance_before: 500_000,
             sender_balance_after: 400_000,
+            settlement_mechanism: SettlementMechanism::RtgsImmediate,
         });
 
         let arrivals = log.events_of_type("Arrival");
@@ -841,6 +1109,7 @@ mod tests {
             amount: 100_000,
             sender_balance_before: 500_000,
             sender_balance_after: 400_000,
+            settlement_mechanism: SettlementMechanism::RtgsImmediate,
         });
 
         let tx_events = log.events_for_tx("tx_001");
@@ -1004,4 +1273,28 @@ mod tests {
         let tick15_events = log.events_at_tick(15);
         assert_eq!(tick15_events.len(), 1);
     }
+
+    #[test]
+    fn test_events_in_range_is_inclusive_exclusive() {
+        let mut log = EventLog::new();
+        for tick in 0..5 {
+            log.log(Event::Arrival {
+                tick,
+                tx_id: format!("tx_{}", tick),
+                sender_id: "BANK_A".to_string(),
+                receiver_id: "BANK_B".to_string(),
+                amount: 1_000,
+                deadline: 10,
+                priority: 5,
+                is_divisible: false,
+            });
+        }
+
+        let ticks: Vec<usize> = log.events_in_range(1, 3).iter().map(|e| e.tick()).collect();
+        assert_eq!(ticks, vec![1, 2]);
+
+        assert!(log.events_in_range(2, 2).is_empty());
+        assert_eq!(log.events_in_range(0, 5).len(), 5);
+        assert_eq!(log.events_in_range(4, 100).len(), 1);
+    }
 }
@@ -0,0 +1,156 @@
+//! Flow Window - trailing settled flow rates per agent
+//!
+//! Tracks how much value each agent has sent and received through
+//! settlement over the last `window_ticks` completed ticks. Policies read
+//! the resulting rates via `recent_inflow_rate` / `recent_outflow_rate`.
+//!
+//! # Usage
+//!
+//! Settlement paths call [`FlowWindow::record`] as value moves. At the end of
+//! each tick the orchestrator calls [`FlowWindow::advance`], which closes the
+//! current tick's bucket and drops buckets older than the window.
+//!
+//! ```rust
+//! use payment_simulator_core_rs::models::flow_window::FlowWindow;
+//!
+//! let mut window = FlowWindow::new(2);
+//! window.record("BANK_A", "BANK_B", 1_000);
+//! window.advance();
+//! assert_eq!(window.inflow_rate("BANK_B"), 500.0);
+//! assert_eq!(window.outflow_rate("BANK_A"), 500.0);
+//!
+//! window.advance();
+//! window.advance();
+//! assert_eq!(window.inflow_rate("BANK_B"), 0.0);
+//! ```
+
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, VecDeque};
+
+/// Settled value moved in one tick, per agent: `(inflow, outflow)` in cents
+type TickFlows = BTreeMap<String, (i64, i64)>;
+
+/// Rolling per-agent settled inflow/outflow over a trailing tick window
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FlowWindow {
+    /// Number of completed ticks the rates are averaged over
+    window_ticks: usize,
+
+    /// Flows settled so far in the tick that is still in progress
+    current: TickFlows,
+
+    /// Closed tick buckets, oldest first (at most `window_ticks` entries)
+    history: VecDeque<TickFlows>,
+
+    /// Running sums over `history`, so rate lookups are O(log N)
+    totals: TickFlows,
+}
+
+impl FlowWindow {
+    /// Create an empty window averaging over `window_ticks` ticks
+    ///
+    /// # Panics
+    ///
+    /// Panics if `window_ticks` is zero (rejected earlier by config validation).
+    pub fn new(window_ticks: usize) -> Self {
+        assert!(window_ticks > 0, "flow window must span at least one tick");
+        Self {
+            window_ticks,
+            current: BTreeMap::new(),
+            history: VecDeque::with_capacity(window_ticks),
+            totals: BTreeMap::new(),
+        }
+    }
+
+    /// Number of ticks the rates are averaged over
+    pub fn window_ticks(&self) -> usize {
+        self.window_ticks
+    }
+
+    /// Record `amount` settled from `sender` to `receiver` in the current tick
+    pub fn record(&mut self, sender: &str, receiver: &str, amount: i64) {
+        self.current.entry(sender.to_string()).or_default().1 += amount;
+        self.current.entry(receiver.to_string()).or_default().0 += amount;
+    }
+
+    /// Close the current tick and slide the window forward by one tick
+    pub fn advance(&mut self) {
+        let closed = std::mem::take(&mut self.current);
+        for (agent_id, (inflow, outflow)) in &closed {
+            let total = self.totals.entry(agent_id.clone()).or_default();
+            total.0 += inflow;
+            total.1 += outflow;
+        }
+        self.history.push_back(closed);
+
+        if self.history.len() > self.window_ticks {
+            if let Some(expired) = self.history.pop_front() {
+                for (agent_id, (inflow, outflow)) in expired {
+                    if let Some(total) = self.totals.get_mut(&agent_id) {
+                        total.0 -= inflow;
+                        total.1 -= outflow;
+                        if *total == (0, 0) {
+                            self.totals.remove(&agent_id);
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// Average settled inflow per tick over the window (cents/tick)
+    ///
+    /// Always divides by the full window length, so rates ramp up over the
+    /// first `window_ticks` ticks of a run.
+    pub fn inflow_rate(&self, agent_id: &str) -> f64 {
+        self.totals
+            .get(agent_id)
+            .map_or(0.0, |(inflow, _)| *inflow as f64 / self.window_ticks as f64)
+    }
+
+    /// Average settled outflow per tick over the window (cents/tick)
+    pub fn outflow_rate(&self, agent_id: &str) -> f64 {
+        self.totals
+            .get(agent_id)
+            .map_or(0.0, |(_, outflow)| *outflow as f64 / self.window_ticks as f64)
+    }
+}
+
+impl Default for FlowWindow {
+    fn default() -> Self {
+        Self::new(10)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flows_outside_window_expire() {
+        let mut window = FlowWindow::new(3);
+        window.record("A", "B", 300);
+        window.advance();
+        assert_eq!(window.inflow_rate("B"), 100.0);
+        assert_eq!(window.outflow_rate("A"), 100.0);
+        assert_eq!(window.inflow_rate("A"), 0.0);
+
+        for _ in 0..2 {
+            window.advance();
+        }
+        assert_eq!(window.inflow_rate("B"), 100.0);
+
+        window.advance();
+        assert_eq!(window.inflow_rate("B"), 0.0);
+        assert_eq!(window.outflow_rate("A"), 0.0);
+    }
+
+    #[test]
+    fn test_current_tick_not_counted_until_advanced() {
+        let mut window = FlowWindow::new(2);
+        window.record("A", "B", 100);
+        assert_eq!(window.inflow_rate("B"), 0.0);
+        window.advance();
+        assert_eq!(window.inflow_rate("B"), 50.0);
+    }
+}
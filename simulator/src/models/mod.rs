@@ -3,6 +3,7 @@
 pub mod agent;
 pub mod collateral_event;
 pub mod event;
+pub mod flow_window;
 pub mod queue_index;
 pub mod state;
 pub mod transaction;
@@ -12,4 +13,6 @@ pub use agent::{Agent, AgentError};
 pub use collateral_event::{CollateralAction, CollateralEvent, CollateralLayer};
 pub use event::{Event, EventLog};
 pub use queue_index::{AgentQueue2Metrics, AgentQueueIndex};
-pub use transaction::{RtgsPriority, Transaction, TransactionError, TransactionStatus};
+pub use transaction::{
+    RtgsPriority, SettlementMechanism, Transaction, TransactionError, TransactionStatus,
+};
@@ -21,10 +21,11 @@
 use crate::models::agent::Agent;
 use crate::models::collateral_event::CollateralEvent;
 use crate::models::event::{Event, EventLog};
+use crate::models::flow_window::FlowWindow;
 use crate::models::queue_index::AgentQueueIndex;
 use crate::models::transaction::Transaction;
 use crate::settlement::lsm::LsmCycleEvent;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Complete simulation state
 ///
@@ -71,6 +72,12 @@ pub struct SimulationState {
     /// See `/docs/queue_architecture.md` for complete two-queue model explanation.
     rtgs_queue: Vec<String>,
 
+    /// Maximum number of transactions Queue 2 may hold (None = unbounded)
+    ///
+    /// Submissions that would queue beyond this are rejected back to the
+    /// sender's Queue 1. Set from `OrchestratorConfig::rtgs_queue_capacity`.
+    rtgs_queue_capacity: Option<usize>,
+
     /// Event log for replay and auditing
     ///
     /// Records all significant state changes during simulation.
@@ -96,6 +103,18 @@ pub struct SimulationState {
     ///
     /// Must be rebuilt after any modification to rtgs_queue via `rebuild_queue2_index()`.
     queue2_index: AgentQueueIndex,
+
+    /// Trailing per-agent settled inflow/outflow (feeds `recent_*_rate` policy fields)
+    ///
+    /// Window length is set from `OrchestratorConfig::flow_window_ticks`.
+    flow_window: FlowWindow,
+
+    /// Settled `(value, count)` across all agents in the tick in progress
+    tick_throughput: (i64, usize),
+
+    /// `(sender, ticks late)` for transactions that settled after their
+    /// deadline and have not been charged yet
+    late_settlements: Vec<(String, usize)>,
 }
 
 impl SimulationState {
@@ -128,10 +147,14 @@ impl SimulationState {
             agents: agents_map,
             transactions: BTreeMap::new(),
             rtgs_queue: Vec::new(),
+            rtgs_queue_capacity: None,
             event_log: EventLog::new(),
             collateral_events: Vec::new(),
             lsm_cycle_events: Vec::new(),
             queue2_index: AgentQueueIndex::new(),
+            flow_window: FlowWindow::default(),
+            tick_throughput: (0, 0),
+            late_settlements: Vec::new(),
         }
     }
 
@@ -183,10 +206,14 @@ impl SimulationState {
             agents,
             transactions,
             rtgs_queue,
+            rtgs_queue_capacity: None,
             event_log: EventLog::new(),
             collateral_events: Vec::new(),
             lsm_cycle_events: Vec::new(),
             queue2_index: AgentQueueIndex::new(),
+            flow_window: FlowWindow::default(),
+            tick_throughput: (0, 0),
+            late_settlements: Vec::new(),
         })
     }
 
@@ -272,6 +299,75 @@ impl SimulationState {
         &self.rtgs_queue
     }
 
+    /// Maximum Queue 2 size (None = unbounded)
+    pub fn rtgs_queue_capacity(&self) -> Option<usize> {
+        self.rtgs_queue_capacity
+    }
+
+    /// Set the maximum Queue 2 size (None = unbounded)
+    ///
+    /// Only gates new submissions; transactions already queued stay queued.
+    pub fn set_rtgs_queue_capacity(&mut self, capacity: Option<usize>) {
+        self.rtgs_queue_capacity = capacity;
+    }
+
+    /// Trailing settled flow window
+    pub fn flow_window(&self) -> &FlowWindow {
+        &self.flow_window
+    }
+
+    /// Replace the flow window (config setup and checkpoint restore)
+    pub fn set_flow_window(&mut self, flow_window: FlowWindow) {
+        self.flow_window = flow_window;
+    }
+
+    /// Record value settled from `sender_id` to `receiver_id` this tick
+    ///
+    /// Called by every settlement path alongside `Agent::record_outflow`.
+    pub fn record_settled_flow(&mut self, sender_id: &str, receiver_id: &str, amount: i64) {
+        self.flow_window.record(sender_id, receiver_id, amount);
+        self.tick_throughput.0 = self.tick_throughput.0.saturating_add(amount);
+        self.tick_throughput.1 += 1;
+    }
+
+    /// Take the `(value, count)` settled since the last call and reset it
+    ///
+    /// The orchestrator calls this once at the end of every tick.
+    pub fn take_tick_throughput(&mut self) -> (i64, usize) {
+        std::mem::take(&mut self.tick_throughput)
+    }
+
+    /// Record that `tx_id` has just fully settled at `tick`
+    ///
+    /// Called by every settlement path right after `Transaction::settle`
+    /// (split parents are never settled directly, so only the payment that
+    /// actually moved is recorded). Settlements after the deadline are kept
+    /// for the orchestrator to charge the per-tick lateness penalty.
+    pub fn record_settlement(&mut self, tx_id: &str, tick: usize) {
+        if let Some(tx) = self.transactions.get(tx_id) {
+            if tick > tx.deadline_tick() {
+                self.late_settlements
+                    .push((tx.sender_id().to_string(), tick - tx.deadline_tick()));
+            }
+        }
+    }
+
+    /// Take the late settlements recorded since the last call
+    pub fn take_late_settlements(&mut self) -> Vec<(String, usize)> {
+        std::mem::take(&mut self.late_settlements)
+    }
+
+    /// Close the current tick in the flow window
+    pub fn advance_flow_window(&mut self) {
+        self.flow_window.advance();
+    }
+
+    /// Check whether Queue 2 has reached its capacity
+    pub fn is_rtgs_queue_full(&self) -> bool {
+        self.rtgs_queue_capacity
+            .is_some_and(|capacity| self.rtgs_queue.len() >= capacity)
+    }
+
     /// Get mutable reference to RTGS queue
     pub fn rtgs_queue_mut(&mut self) -> &mut Vec<String> {
         &mut self.rtgs_queue
@@ -331,6 +427,45 @@ impl SimulationState {
             .fold(0i64, |acc, x| acc.saturating_add(x))
     }
 
+    /// Get Queue 2 transactions where this agent is the receiver
+    ///
+    /// These are the agent's expected inflows: payments already submitted
+    /// to RTGS by counterparties but not yet settled. Returned in queue order.
+    pub fn incoming_queued_for(&self, agent_id: &str) -> Vec<&Transaction> {
+        self.rtgs_queue
+            .iter()
+            .filter_map(|tx_id| self.transactions.get(tx_id))
+            .filter(|tx| tx.receiver_id() == agent_id)
+            .collect()
+    }
+
+    /// Net queued obligation of agent `a` towards agent `b`
+    ///
+    /// Sums remaining amounts of unsettled transactions in both Queue 1
+    /// (the senders' internal queues) and Queue 2. Positive means `a` owes
+    /// `b` on net, negative means `b` owes `a`; swapping the arguments
+    /// negates the result.
+    pub fn net_position_between(&self, a: &str, b: &str) -> i64 {
+        self.queued_outflow_between(a, b) - self.queued_outflow_between(b, a)
+    }
+
+    /// Remaining value queued from `sender` to `receiver` in Queue 1 and Queue 2
+    fn queued_outflow_between(&self, sender: &str, receiver: &str) -> i64 {
+        let queue1 = self
+            .agents
+            .get(sender)
+            .map(|agent| agent.outgoing_queue())
+            .unwrap_or_default();
+
+        queue1
+            .iter()
+            .chain(self.rtgs_queue.iter())
+            .filter_map(|tx_id| self.transactions.get(tx_id))
+            .filter(|tx| tx.sender_id() == sender && tx.receiver_id() == receiver)
+            .map(|tx| tx.remaining_amount())
+            .fold(0i64, |acc, x| acc.saturating_add(x))
+    }
+
     // =========================================================================
     // Queue 1 (Internal Bank Queues) Accessor Methods - Phase 4
     // =========================================================================
@@ -601,6 +736,201 @@ impl SimulationState {
     pub fn queue2_index(&self) -> &AgentQueueIndex {
         &self.queue2_index
     }
+
+    /// Check the state's structural invariants
+    ///
+    /// Intended for fuzzing and post-mutation validation. Checks that:
+    /// - no transaction has a negative amount or remaining amount, and the
+    ///   settled amount never exceeds the total
+    /// - every Queue 1 and Queue 2 entry exists in the transaction map
+    /// - no transaction ID appears more than once across all queues
+    /// - split children's remaining amounts sum to their parent's remaining
+    ///   amount (child settlements reduce the parent in step)
+    ///
+    /// # Returns
+    ///
+    /// - `Ok(())` if all invariants hold
+    /// - `Err(message)` describing the first violation found
+    pub fn validate_invariants(&self) -> Result<(), String> {
+        // 1. Amounts
+        for (tx_id, tx) in &self.transactions {
+            if tx.amount() < 0 || tx.remaining_amount() < 0 {
+                return Err(format!(
+                    "Transaction {} has negative amount (amount {}, remaining {})",
+                    tx_id,
+                    tx.amount(),
+                    tx.remaining_amount()
+                ));
+            }
+            if tx.settled_amount() > tx.amount() || tx.remaining_amount() > tx.amount() {
+                return Err(format!(
+                    "Transaction {} settled amount out of range (amount {}, settled {})",
+                    tx_id,
+                    tx.amount(),
+                    tx.settled_amount()
+                ));
+            }
+        }
+
+        // 2. Queue membership and uniqueness (Queue 1 and Queue 2 together)
+        let mut seen: BTreeMap<&str, String> = BTreeMap::new();
+        let queue1_entries = self.agents.values().flat_map(|agent| {
+            agent
+                .outgoing_queue()
+                .iter()
+                .map(move |tx_id| (tx_id, format!("agent {} queue", agent.id())))
+        });
+        let queue2_entries = self
+            .rtgs_queue
+            .iter()
+            .map(|tx_id| (tx_id, "RTGS queue".to_string()));
+
+        for (tx_id, location) in queue1_entries.chain(queue2_entries) {
+            if !self.transactions.contains_key(tx_id) {
+                return Err(format!("Orphaned transaction in {}: {}", location, tx_id));
+            }
+            if let Some(prev_location) = seen.insert(tx_id, location.clone()) {
+                return Err(format!(
+                    "Duplicate transaction {} in queues: {} and {}",
+                    tx_id, prev_location, location
+                ));
+            }
+        }
+
+        // 3. Split children account for their parent's outstanding amount
+        let mut child_sums: BTreeMap<&str, i64> = BTreeMap::new();
+        for tx in self.transactions.values() {
+            if let Some(parent_id) = tx.parent_id() {
+                *child_sums.entry(parent_id).or_insert(0) += tx.remaining_amount();
+            }
+        }
+        for (parent_id, child_sum) in child_sums {
+            let parent = self
+                .transactions
+                .get(parent_id)
+                .ok_or_else(|| format!("Split parent {} not found", parent_id))?;
+            if child_sum != parent.remaining_amount() {
+                return Err(format!(
+                    "Split children of {} have {} remaining, parent has {}",
+                    parent_id,
+                    child_sum,
+                    parent.remaining_amount()
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Structured difference from this state to `other`
+    ///
+    /// Intended for debugging and tests: take a snapshot (`clone()`), run
+    /// some steps, then diff the snapshot against the live state. Every list
+    /// in the result is sorted, so equal inputs always give equal output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use payment_simulator_core_rs::{Agent, SimulationState};
+    ///
+    /// let state = SimulationState::new(vec![Agent::new("A".to_string(), 1_000)]);
+    /// let mut later = state.clone();
+    /// later.get_agent_mut("A").unwrap().adjust_balance(-400);
+    ///
+    /// let diff = state.diff(&later);
+    /// assert_eq!(diff.balance_changes, vec![("A".to_string(), -400)]);
+    /// ```
+    pub fn diff(&self, other: &SimulationState) -> StateDiff {
+        let balance_changes = self
+            .agents
+            .iter()
+            .filter_map(|(id, agent)| {
+                let after = other.agents.get(id)?.balance();
+                let delta = after - agent.balance();
+                (delta != 0).then(|| (id.clone(), delta))
+            })
+            .collect();
+
+        let added_transactions = other
+            .transactions
+            .keys()
+            .filter(|id| !self.transactions.contains_key(*id))
+            .cloned()
+            .collect();
+        let removed_transactions = self
+            .transactions
+            .keys()
+            .filter(|id| !other.transactions.contains_key(*id))
+            .cloned()
+            .collect();
+
+        let before = self.queue_memberships();
+        let after = other.queue_memberships();
+
+        StateDiff {
+            balance_changes,
+            added_transactions,
+            removed_transactions,
+            queue_entered: after.difference(&before).cloned().collect(),
+            queue_left: before.difference(&after).cloned().collect(),
+        }
+    }
+
+    /// Every (queue, transaction ID) pair across Queue 1 and Queue 2
+    fn queue_memberships(&self) -> BTreeSet<(QueueLocation, String)> {
+        let queue1 = self.agents.values().flat_map(|agent| {
+            agent
+                .outgoing_queue()
+                .iter()
+                .map(move |tx_id| {
+                    (QueueLocation::Queue1(agent.id().to_string()), tx_id.clone())
+                })
+        });
+        let queue2 = self
+            .rtgs_queue
+            .iter()
+            .map(|tx_id| (QueueLocation::Queue2, tx_id.clone()));
+        queue1.chain(queue2).collect()
+    }
+}
+
+/// Queue a transaction can sit in
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum QueueLocation {
+    /// An agent's internal queue (Queue 1), keyed by agent ID
+    Queue1(String),
+    /// The central RTGS queue (Queue 2)
+    Queue2,
+}
+
+/// Result of [`SimulationState::diff`]
+///
+/// All lists are sorted (by agent ID, transaction ID, or queue then
+/// transaction ID).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StateDiff {
+    /// Balance change per agent present in both states (`after - before`);
+    /// agents whose balance did not move are omitted
+    pub balance_changes: Vec<(String, i64)>,
+    /// Transaction IDs present only in the later state
+    pub added_transactions: Vec<String>,
+    /// Transaction IDs present only in the earlier state
+    pub removed_transactions: Vec<String>,
+    /// Transactions that joined a queue
+    pub queue_entered: Vec<(QueueLocation, String)>,
+    /// Transactions that left a queue
+    pub queue_left: Vec<(QueueLocation, String)>,
+}
+
+impl StateDiff {
+    /// True if the two states matched on everything the diff tracks
+    pub fn is_empty(&self) -> bool {
+        self.balance_changes.is_empty()
+            && self.added_transactions.is_empty()
+            && self.removed_transactions.is_empty()
+            && self.queue_entered.is_empty()
+            && self.queue_left.is_empty()
+    }
 }
 
 #[cfg(test)]
@@ -683,4 +1013,166 @@ mod tests {
 
         assert_eq!(state.queue_value(), 3_000_000);
     }
+
+    #[test]
+    fn test_incoming_queued_for() {
+        let agents = vec![
+            Agent::new("A".to_string(), 0),
+            Agent::new("B".to_string(), 0),
+            Agent::new("C".to_string(), 0),
+        ];
+        let mut state = SimulationState::new(agents);
+
+        let to_b = Transaction::new("A".to_string(), "B".to_string(), 1_000_000, 0, 100);
+        let to_c = Transaction::new("A".to_string(), "C".to_string(), 2_000_000, 0, 100);
+        let from_c = Transaction::new("C".to_string(), "B".to_string(), 500_000, 0, 100);
+        let not_queued = Transaction::new("A".to_string(), "B".to_string(), 700_000, 0, 100);
+
+        let ids: Vec<String> = [&to_b, &to_c, &from_c]
+            .iter()
+            .map(|tx| tx.id().to_string())
+            .collect();
+        for tx in [to_b, to_c, from_c, not_queued] {
+            state.add_transaction(tx);
+        }
+        for id in &ids {
+            state.queue_transaction(id.clone());
+        }
+
+        let incoming: Vec<&str> = state.incoming_queued_for("B").iter().map(|tx| tx.id()).collect();
+        assert_eq!(incoming, vec![ids[0].as_str(), ids[2].as_str()]);
+        assert!(state.incoming_queued_for("A").is_empty());
+    }
+
+    #[test]
+    fn test_net_position_between() {
+        let agents = vec![
+            Agent::new("A".to_string(), 0),
+            Agent::new("B".to_string(), 0),
+            Agent::new("C".to_string(), 0),
+        ];
+        let mut state = SimulationState::new(agents);
+
+        // A→B: 300k in Queue 1 + 500k in Queue 2; B→A: 600k in Queue 2
+        let a_to_b_q1 = Transaction::new("A".to_string(), "B".to_string(), 300_000, 0, 100);
+        let a_to_b_q2 = Transaction::new("A".to_string(), "B".to_string(), 500_000, 0, 100);
+        let b_to_a_q2 = Transaction::new("B".to_string(), "A".to_string(), 600_000, 0, 100);
+        // Other pairs and unqueued transactions don't count
+        let a_to_c = Transaction::new("A".to_string(), "C".to_string(), 900_000, 0, 100);
+        let not_queued = Transaction::new("B".to_string(), "A".to_string(), 700_000, 0, 100);
+
+        let q1_id = a_to_b_q1.id().to_string();
+        let q2_ids: Vec<String> = [&a_to_b_q2, &b_to_a_q2, &a_to_c]
+            .iter()
+            .map(|tx| tx.id().to_string())
+            .collect();
+        for tx in [a_to_b_q1, a_to_b_q2, b_to_a_q2, a_to_c, not_queued] {
+            state.add_transaction(tx);
+        }
+        state.get_agent_mut("A").unwrap().queue_outgoing(q1_id);
+        for id in &q2_ids {
+            state.queue_transaction(id.clone());
+        }
+
+        assert_eq!(state.net_position_between("A", "B"), 200_000);
+        assert_eq!(state.net_position_between("B", "A"), -200_000);
+        assert_eq!(state.net_position_between("A", "C"), 900_000);
+        assert_eq!(state.net_position_between("B", "C"), 0);
+
+        // Partial settlement leaves only the remaining amount outstanding
+        state
+            .get_transaction_mut(&q2_ids[1])
+            .unwrap()
+            .settle_partial(400_000, 1)
+            .unwrap();
+        assert_eq!(state.net_position_between("A", "B"), 600_000);
+        assert_eq!(state.net_position_between("B", "A"), -600_000);
+    }
+
+    #[test]
+    fn test_diff_of_identical_states_is_empty() {
+        let mut state = SimulationState::new(vec![Agent::new("A".to_string(), 1_000_000)]);
+        let tx = Transaction::new("A".to_string(), "B".to_string(), 500_000, 0, 100);
+        let tx_id = tx.id().to_string();
+        state.add_transaction(tx);
+        state.queue_transaction(tx_id);
+
+        assert!(state.diff(&state.clone()).is_empty());
+    }
+
+    #[test]
+    fn test_diff_reports_single_settlement() {
+        let agents = vec![
+            Agent::new("A".to_string(), 1_000_000),
+            Agent::new("B".to_string(), 0),
+            Agent::new("C".to_string(), 0),
+        ];
+        let mut state = SimulationState::new(agents);
+        let tx = Transaction::new("A".to_string(), "B".to_string(), 300_000, 0, 100);
+        let tx_id = tx.id().to_string();
+        state.add_transaction(tx);
+        state.queue_transaction(tx_id.clone());
+
+        let before = state.clone();
+        let result = crate::settlement::rtgs::process_queue(&mut state, 1);
+        assert_eq!(result.settled_count, 1);
+
+        let diff = before.diff(&state);
+        assert_eq!(
+            diff.balance_changes,
+            vec![("A".to_string(), -300_000), ("B".to_string(), 300_000)]
+        );
+        assert_eq!(diff.queue_left, vec![(QueueLocation::Queue2, tx_id)]);
+        assert!(diff.queue_entered.is_empty());
+        assert!(diff.added_transactions.is_empty());
+        assert!(diff.removed_transactions.is_empty());
+    }
+
+    #[test]
+    fn test_diff_tracks_new_transactions_and_queue1_moves_in_sorted_order() {
+        let agents = vec![
+            Agent::new("A".to_string(), 1_000_000),
+            Agent::new("B".to_string(), 1_000_000),
+        ];
+        let mut before = SimulationState::new(agents);
+        let moved = Transaction::new("A".to_string(), "B".to_string(), 100_000, 0, 100);
+        let moved_id = moved.id().to_string();
+        before.add_transaction(moved);
+        before.get_agent_mut("A").unwrap().queue_outgoing(moved_id.clone());
+
+        // Released from Queue 1 to Queue 2; two new payments queued by B
+        let mut after = before.clone();
+        after.get_agent_mut("A").unwrap().remove_from_queue(&moved_id);
+        after.queue_transaction(moved_id.clone());
+        let mut new_ids = Vec::new();
+        for _ in 0..2 {
+            let tx = Transaction::new("B".to_string(), "A".to_string(), 50_000, 0, 100);
+            new_ids.push(tx.id().to_string());
+            after
+                .get_agent_mut("B")
+                .unwrap()
+                .queue_outgoing(tx.id().to_string());
+            after.add_transaction(tx);
+        }
+        new_ids.sort();
+
+        let diff = before.diff(&after);
+        assert!(diff.balance_changes.is_empty());
+        assert_eq!(diff.added_transactions, new_ids);
+        assert_eq!(
+            diff.queue_left,
+            vec![(QueueLocation::Queue1("A".to_string()), moved_id.clone())]
+        );
+        let mut expected_entered: Vec<_> = new_ids
+            .iter()
+            .map(|id| (QueueLocation::Queue1("B".to_string()), id.clone()))
+            .collect();
+        expected_entered.push((QueueLocation::Queue2, moved_id));
+        assert_eq!(diff.queue_entered, expected_entered);
+
+        // The reverse diff swaps additions and removals
+        let reverse = after.diff(&before);
+        assert_eq!(reverse.removed_transactions, new_ids);
+        assert_eq!(reverse.queue_left, diff.queue_entered);
+    }
 }
@@ -95,6 +95,44 @@ impl FromStr for RtgsPriority {
     }
 }
 
+/// How a transaction was settled
+///
+/// Recorded at the point of settlement so research output can distinguish
+/// plain RTGS flow from liquidity-saving mechanisms.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum SettlementMechanism {
+    /// Settled on submission - sender had sufficient liquidity
+    RtgsImmediate,
+
+    /// Settled from Queue 2 once liquidity became available
+    RtgsQueue,
+
+    /// Settled by LSM bilateral offset (A↔B netting)
+    BilateralOffset,
+
+    /// Settled as part of an LSM multilateral cycle
+    MultilateralCycle,
+
+    /// Settled in an LSM multilateral netting batch
+    MultilateralNetting,
+
+    /// Force-settled after missing its deadline (deadline hard cutoff)
+    Forced,
+}
+
+impl fmt::Display for SettlementMechanism {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SettlementMechanism::RtgsImmediate => write!(f, "RtgsImmediate"),
+            SettlementMechanism::RtgsQueue => write!(f, "RtgsQueue"),
+            SettlementMechanism::BilateralOffset => write!(f, "BilateralOffset"),
+            SettlementMechanism::MultilateralCycle => write!(f, "MultilateralCycle"),
+            SettlementMechanism::MultilateralNetting => write!(f, "MultilateralNetting"),
+            SettlementMechanism::Forced => write!(f, "Forced"),
+        }
+    }
+}
+
 /// Transaction status
 ///
 /// Tracks the lifecycle of a payment through the system.
@@ -124,6 +162,49 @@ pub enum TransactionStatus {
         /// Tick when transaction first missed its deadline
         missed_deadline_tick: usize,
     },
+
+    /// Transaction withdrawn before settlement (e.g., a revoked instruction)
+    ///
+    /// Terminal: a cancelled transaction is out of both queues and can never
+    /// settle. Any amount settled before cancellation stays settled.
+    Cancelled {
+        /// Tick when the transaction was cancelled
+        tick: usize,
+    },
+
+    /// Transaction given up on after too many failed Queue 2 retries
+    ///
+    /// Terminal: set when `max_settlement_retries` is reached. The
+    /// transaction is out of Queue 2 and will never settle; any amount
+    /// settled beforehand stays settled.
+    Returned {
+        /// Tick when the transaction was returned
+        tick: usize,
+    },
+}
+
+impl TransactionStatus {
+    /// Names of all status variants, in declaration order
+    pub const NAMES: [&'static str; 6] = [
+        "Pending",
+        "PartiallySettled",
+        "Settled",
+        "Overdue",
+        "Cancelled",
+        "Returned",
+    ];
+
+    /// Variant name without its fields (e.g. "Settled")
+    pub fn name(&self) -> &'static str {
+        match self {
+            TransactionStatus::Pending => "Pending",
+            TransactionStatus::PartiallySettled { .. } => "PartiallySettled",
+            TransactionStatus::Settled { .. } => "Settled",
+            TransactionStatus::Overdue { .. } => "Overdue",
+            TransactionStatus::Cancelled { .. } => "Cancelled",
+            TransactionStatus::Returned { .. } => "Returned",
+        }
+    }
 }
 
 /// Errors that can occur during transaction operations
@@ -141,6 +222,12 @@ pub enum TransactionError {
     #[error("Cannot settle dropped transaction")]
     TransactionDropped,
 
+    #[error("Transaction has been cancelled")]
+    TransactionCancelled,
+
+    #[error("Transaction has been returned unsettled")]
+    TransactionReturned,
+
     #[error("Settlement amount must be positive")]
     InvalidAmount,
 }
@@ -236,6 +323,45 @@ pub struct Transaction {
     /// - `None`: Use default Normal priority when submitted to RTGS
     /// - `Some(priority)`: Use this priority when submitted to RTGS
     declared_rtgs_priority: Option<RtgsPriority>,
+
+    /// Mechanism that fully settled this transaction
+    ///
+    /// - `None`: Not yet settled (or settled via its split children)
+    /// - `Some(mechanism)`: Set by the RTGS/LSM code at the point of settlement
+    #[serde(default)]
+    settlement_mechanism: Option<SettlementMechanism>,
+
+    /// Whether policies may split this transaction into child payments
+    ///
+    /// Set from the arrival configuration or the submission call. Split
+    /// children are never divisible (a split cannot be split again).
+    #[serde(default)]
+    divisible: bool,
+
+    /// Free-form labels for downstream analysis (e.g. "CLS", "customer")
+    ///
+    /// Set from the arrival configuration or the submission call, and
+    /// inherited by split children. Has no effect on settlement.
+    #[serde(default)]
+    tags: Vec<String>,
+
+    /// Tick when the transaction first entered the sender's Queue 1
+    ///
+    /// - `None`: Never queued in Queue 1 (e.g. a split child released straight to RTGS)
+    /// - `Some(tick)`: First Queue 1 entry; later re-entries keep the original tick
+    #[serde(default)]
+    entered_queue1_tick: Option<usize>,
+
+    /// Queue 2 processing passes that failed to settle this transaction
+    #[serde(default)]
+    retry_count: usize,
+
+    /// Fine-grained ordering key within the same priority (lower = earlier)
+    ///
+    /// Only breaks ties between equal priorities under `PriorityDeadline`
+    /// Queue 1 ordering; the 0-10 `priority` still decides the band.
+    #[serde(default)]
+    order_hint: i64,
 }
 
 impl Transaction {
@@ -291,6 +417,12 @@ impl Transaction {
             rtgs_priority: None, // Set when submitted to RTGS
             rtgs_submission_tick: None,
             declared_rtgs_priority: None, // Set via submit_transaction_with_rtgs_priority
+            settlement_mechanism: None,
+            divisible: false,
+            tags: Vec::new(),
+            entered_queue1_tick: None,
+            retry_count: 0,
+            order_hint: 0,
         }
     }
 
@@ -308,6 +440,8 @@ impl Transaction {
     /// * `deadline_tick` - Deadline tick (same as parent)
     /// * `parent_id` - Parent transaction ID
     ///
+    /// Children are never divisible, regardless of the parent's flag.
+    ///
     /// # Panics
     /// Panics if amount <= 0 or deadline <= arrival
     ///
@@ -365,6 +499,12 @@ impl Transaction {
             rtgs_priority: None, // Set when submitted to RTGS
             rtgs_submission_tick: None,
             declared_rtgs_priority: None, // Children inherit parent's declared priority
+            settlement_mechanism: None,
+            divisible: false,
+            tags: Vec::new(),
+            entered_queue1_tick: None,
+            retry_count: 0,
+            order_hint: 0,
         }
     }
 
@@ -431,6 +571,12 @@ impl Transaction {
             rtgs_priority: None, // Not set for legacy snapshots
             rtgs_submission_tick: None,
             declared_rtgs_priority: None, // Not set for legacy snapshots
+            settlement_mechanism: None,
+            divisible: false,
+            tags: Vec::new(),
+            entered_queue1_tick: None,
+            retry_count: 0,
+            order_hint: 0,
         }
     }
 
@@ -469,6 +615,12 @@ impl Transaction {
             rtgs_priority,
             rtgs_submission_tick,
             declared_rtgs_priority,
+            settlement_mechanism: None,
+            divisible: false,
+            tags: Vec::new(),
+            entered_queue1_tick: None,
+            retry_count: 0,
+            order_hint: 0,
         }
     }
 
@@ -493,6 +645,75 @@ impl Transaction {
         self
     }
 
+    /// Set whether the transaction may be split (builder pattern)
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::Transaction;
+    ///
+    /// let tx = Transaction::new(
+    ///     "BANK_A".to_string(),
+    ///     "BANK_B".to_string(),
+    ///     100000,
+    ///     10,
+    ///     50,
+    /// ).with_divisible(true);
+    ///
+    /// assert!(tx.is_divisible());
+    /// ```
+    pub fn with_divisible(mut self, divisible: bool) -> Self {
+        self.divisible = divisible;
+        self
+    }
+
+    /// Set the transaction's tags (builder pattern)
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::Transaction;
+    ///
+    /// let tx = Transaction::new(
+    ///     "BANK_A".to_string(),
+    ///     "BANK_B".to_string(),
+    ///     100000,
+    ///     10,
+    ///     50,
+    /// ).with_tags(vec!["CLS".to_string()]);
+    ///
+    /// assert!(tx.has_tag("CLS"));
+    /// ```
+    pub fn with_tags(mut self, tags: Vec<String>) -> Self {
+        self.tags = tags;
+        self
+    }
+
+    /// Set the Queue 1 ordering hint (builder pattern)
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::Transaction;
+    ///
+    /// let tx = Transaction::new(
+    ///     "BANK_A".to_string(),
+    ///     "BANK_B".to_string(),
+    ///     100000,
+    ///     10,
+    ///     50,
+    /// ).with_order_hint(-3);
+    ///
+    /// assert_eq!(tx.order_hint(), -3);
+    /// ```
+    pub fn with_order_hint(mut self, order_hint: i64) -> Self {
+        self.order_hint = order_hint;
+        self
+    }
+
+    /// Set the failed Queue 2 retry count (checkpoint restoration)
+    pub fn with_retry_count(mut self, retry_count: usize) -> Self {
+        self.retry_count = retry_count;
+        self
+    }
+
     /// Get transaction ID
     pub fn id(&self) -> &str {
         &self.id
@@ -657,6 +878,75 @@ impl Transaction {
         self.declared_rtgs_priority = Some(priority);
     }
 
+    /// Check if policies may split this transaction
+    pub fn is_divisible(&self) -> bool {
+        self.divisible
+    }
+
+    /// Get the transaction's tags
+    pub fn tags(&self) -> &[String] {
+        &self.tags
+    }
+
+    /// Check if the transaction carries `tag`
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
+    /// Get the tick when the transaction first entered Queue 1
+    pub fn entered_queue1_tick(&self) -> Option<usize> {
+        self.entered_queue1_tick
+    }
+
+    /// Record that the transaction entered Queue 1 at `tick`
+    ///
+    /// Only the first entry is kept, so a transaction handed back from Queue 2
+    /// (withdrawal, full queue) keeps the age it has already accumulated.
+    pub fn mark_entered_queue1(&mut self, tick: usize) {
+        self.entered_queue1_tick.get_or_insert(tick);
+    }
+
+    /// Ticks elapsed since the transaction first entered Queue 1
+    ///
+    /// Returns 0 for transactions that were never queued in Queue 1.
+    pub fn ticks_in_queue(&self, current_tick: usize) -> usize {
+        self.entered_queue1_tick
+            .map_or(0, |tick| current_tick.saturating_sub(tick))
+    }
+
+    /// Get the Queue 1 ordering hint (tie-breaker within equal priority)
+    pub fn order_hint(&self) -> i64 {
+        self.order_hint
+    }
+
+    /// Set the Queue 1 ordering hint
+    pub fn set_order_hint(&mut self, order_hint: i64) {
+        self.order_hint = order_hint;
+    }
+
+    /// Get the number of failed Queue 2 settlement retries
+    pub fn retry_count(&self) -> usize {
+        self.retry_count
+    }
+
+    /// Record one failed Queue 2 settlement retry, returning the new count
+    pub fn record_retry(&mut self) -> usize {
+        self.retry_count += 1;
+        self.retry_count
+    }
+
+    /// Get the mechanism that settled this transaction (None if unsettled)
+    pub fn settlement_mechanism(&self) -> Option<SettlementMechanism> {
+        self.settlement_mechanism
+    }
+
+    /// Record the mechanism that settled this transaction
+    ///
+    /// Called by the settlement engines (RTGS, LSM) alongside `settle()`.
+    pub fn set_settlement_mechanism(&mut self, mechanism: SettlementMechanism) {
+        self.settlement_mechanism = Some(mechanism);
+    }
+
     /// Check if transaction is pending
     pub fn is_pending(&self) -> bool {
         matches!(self.status, TransactionStatus::Pending)
@@ -732,6 +1022,14 @@ impl Transaction {
             return Err(TransactionError::AlreadySettled);
         }
 
+        if self.is_cancelled() {
+            return Err(TransactionError::TransactionCancelled);
+        }
+
+        if self.is_returned() {
+            return Err(TransactionError::TransactionReturned);
+        }
+
         // NOTE: Removed check for Dropped status - overdue transactions can still settle
         // In real payment systems, all obligations must eventually be settled
 
@@ -750,6 +1048,62 @@ impl Transaction {
         Ok(())
     }
 
+    /// Settle part of the remaining amount, leaving the rest outstanding
+    ///
+    /// Used by the RTGS queue when partial settlement is enabled and the
+    /// sender can afford only a portion of the payment. The status moves to
+    /// `PartiallySettled` (overdue transactions stay `Overdue`).
+    ///
+    /// # Arguments
+    /// * `amount` - Amount to settle (i64 cents, must be > 0 and < remaining)
+    /// * `tick` - Tick when settlement occurs
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::Transaction;
+    ///
+    /// let mut tx = Transaction::new("BANK_A".to_string(), "BANK_B".to_string(), 100000, 0, 50);
+    ///
+    /// tx.settle_partial(60000, 5).unwrap();
+    /// assert_eq!(tx.settled_amount(), 60000);
+    /// assert_eq!(tx.remaining_amount(), 40000);
+    /// assert!(!tx.is_fully_settled());
+    /// ```
+    pub fn settle_partial(&mut self, amount: i64, tick: usize) -> Result<(), TransactionError> {
+        if amount <= 0 {
+            return Err(TransactionError::InvalidAmount);
+        }
+
+        if self.remaining_amount == 0 {
+            return Err(TransactionError::AlreadySettled);
+        }
+
+        if self.is_cancelled() {
+            return Err(TransactionError::TransactionCancelled);
+        }
+
+        if self.is_returned() {
+            return Err(TransactionError::TransactionReturned);
+        }
+
+        // A chunk equal to the remainder is a full settlement - use settle()
+        if amount >= self.remaining_amount {
+            return Err(TransactionError::AmountExceedsRemaining {
+                amount,
+                remaining: self.remaining_amount,
+            });
+        }
+
+        self.remaining_amount -= amount;
+        if let TransactionStatus::Pending = self.status {
+            self.status = TransactionStatus::PartiallySettled {
+                first_settlement_tick: tick,
+            };
+        }
+
+        Ok(())
+    }
+
     /// Reduce remaining amount when a child transaction settles
     ///
     /// This method is used internally by the settlement engine when a child
@@ -863,6 +1217,8 @@ impl Transaction {
                 Ok(())
             }
             TransactionStatus::Settled { .. } => Err(TransactionError::AlreadySettled),
+            TransactionStatus::Cancelled { .. } => Err(TransactionError::TransactionCancelled),
+            TransactionStatus::Returned { .. } => Err(TransactionError::TransactionReturned),
         }
     }
 
@@ -874,6 +1230,72 @@ impl Transaction {
         matches!(self.status, TransactionStatus::Overdue { .. })
     }
 
+    /// Cancel the transaction so it can never settle
+    ///
+    /// Only the status changes; removing the transaction from Queue 1/2 is the
+    /// caller's job (see `Orchestrator::cancel_transaction`).
+    ///
+    /// # Returns
+    /// - Ok(()) if cancelled
+    /// - Err(TransactionError::AlreadySettled) if fully settled
+    /// - Err(TransactionError::TransactionCancelled) if already cancelled
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::Transaction;
+    ///
+    /// let mut tx = Transaction::new("A".to_string(), "B".to_string(), 100_000, 0, 50);
+    /// tx.cancel(5).unwrap();
+    ///
+    /// assert!(tx.is_cancelled());
+    /// assert!(tx.settle(100_000, 6).is_err());
+    /// ```
+    pub fn cancel(&mut self, tick: usize) -> Result<(), TransactionError> {
+        match self.status {
+            TransactionStatus::Settled { .. } => Err(TransactionError::AlreadySettled),
+            TransactionStatus::Cancelled { .. } => Err(TransactionError::TransactionCancelled),
+            TransactionStatus::Returned { .. } => Err(TransactionError::TransactionReturned),
+            _ if self.remaining_amount == 0 => Err(TransactionError::AlreadySettled),
+            _ => {
+                self.status = TransactionStatus::Cancelled { tick };
+                Ok(())
+            }
+        }
+    }
+
+    /// Check if transaction has been cancelled
+    pub fn is_cancelled(&self) -> bool {
+        matches!(self.status, TransactionStatus::Cancelled { .. })
+    }
+
+    /// Return the transaction unsettled after exhausting its settlement retries
+    ///
+    /// Only the status changes; removing the transaction from Queue 2 is the
+    /// caller's job.
+    ///
+    /// # Returns
+    /// - Ok(()) if returned
+    /// - Err(TransactionError::AlreadySettled) if fully settled
+    /// - Err(TransactionError::TransactionCancelled) if cancelled
+    /// - Err(TransactionError::TransactionReturned) if already returned
+    pub fn mark_returned(&mut self, tick: usize) -> Result<(), TransactionError> {
+        match self.status {
+            TransactionStatus::Settled { .. } => Err(TransactionError::AlreadySettled),
+            TransactionStatus::Cancelled { .. } => Err(TransactionError::TransactionCancelled),
+            TransactionStatus::Returned { .. } => Err(TransactionError::TransactionReturned),
+            _ if self.remaining_amount == 0 => Err(TransactionError::AlreadySettled),
+            _ => {
+                self.status = TransactionStatus::Returned { tick };
+                Ok(())
+            }
+        }
+    }
+
+    /// Check if transaction has been returned unsettled
+    pub fn is_returned(&self) -> bool {
+        matches!(self.status, TransactionStatus::Returned { .. })
+    }
+
     /// Get tick when transaction became overdue
     ///
     /// Returns Some(tick) if overdue, None otherwise
@@ -990,6 +1412,28 @@ mod tests {
         assert!(result.is_ok());
     }
 
+    #[test]
+    fn test_cancelled_transaction_cannot_settle() {
+        let mut tx = Transaction::new("A".to_string(), "B".to_string(), 100_000, 0, 50);
+        tx.cancel(3).unwrap();
+
+        assert_eq!(tx.status(), &TransactionStatus::Cancelled { tick: 3 });
+        assert_eq!(tx.settle(100_000, 4), Err(TransactionError::TransactionCancelled));
+        assert_eq!(tx.settle_partial(10_000, 4), Err(TransactionError::TransactionCancelled));
+        assert_eq!(tx.mark_overdue(51), Err(TransactionError::TransactionCancelled));
+        assert_eq!(tx.cancel(5), Err(TransactionError::TransactionCancelled));
+        assert_eq!(tx.remaining_amount(), 100_000);
+    }
+
+    #[test]
+    fn test_settled_transaction_cannot_be_cancelled() {
+        let mut tx = Transaction::new("A".to_string(), "B".to_string(), 100_000, 0, 50);
+        tx.settle(100_000, 2).unwrap();
+
+        assert_eq!(tx.cancel(3), Err(TransactionError::AlreadySettled));
+        assert!(tx.is_fully_settled());
+    }
+
     #[test]
     fn test_set_priority() {
         let mut tx = Transaction::new("A".to_string(), "B".to_string(), 100_000, 0, 50);
@@ -1012,4 +1456,58 @@ mod tests {
         tx.set_priority(255);
         assert_eq!(tx.priority(), 10); // Capped
     }
+
+    #[test]
+    fn test_settle_partial() {
+        let mut tx = Transaction::new("A".to_string(), "B".to_string(), 100_000, 0, 50);
+
+        tx.settle_partial(60_000, 5).unwrap();
+        assert_eq!(tx.remaining_amount(), 40_000);
+        assert_eq!(
+            tx.status(),
+            &TransactionStatus::PartiallySettled {
+                first_settlement_tick: 5
+            }
+        );
+
+        // Full remainder must go through settle()
+        assert!(tx.settle_partial(40_000, 6).is_err());
+        assert!(tx.settle_partial(0, 6).is_err());
+        tx.settle(40_000, 6).unwrap();
+        assert!(tx.is_fully_settled());
+    }
+
+    #[test]
+    fn test_ticks_in_queue_counts_from_first_entry() {
+        let mut tx = Transaction::new("A".to_string(), "B".to_string(), 100_000, 0, 50);
+        assert_eq!(tx.ticks_in_queue(10), 0);
+
+        tx.mark_entered_queue1(3);
+        assert_eq!(tx.ticks_in_queue(3), 0);
+        assert_eq!(tx.ticks_in_queue(10), 7);
+
+        // Re-entering Queue 1 keeps the original entry tick
+        tx.mark_entered_queue1(8);
+        assert_eq!(tx.entered_queue1_tick(), Some(3));
+        assert_eq!(tx.ticks_in_queue(10), 7);
+    }
+
+    #[test]
+    fn test_returned_transaction_cannot_settle() {
+        let mut tx = Transaction::new("A".to_string(), "B".to_string(), 100_000, 0, 50);
+        assert_eq!(tx.record_retry(), 1);
+        assert_eq!(tx.record_retry(), 2);
+        assert_eq!(tx.retry_count(), 2);
+
+        tx.mark_returned(4).unwrap();
+
+        assert!(tx.is_returned());
+        assert_eq!(tx.status(), &TransactionStatus::Returned { tick: 4 });
+        assert_eq!(tx.status().name(), "Returned");
+        assert_eq!(tx.settle(100_000, 5), Err(TransactionError::TransactionReturned));
+        assert_eq!(tx.settle_partial(10_000, 5), Err(TransactionError::TransactionReturned));
+        assert_eq!(tx.mark_overdue(51), Err(TransactionError::TransactionReturned));
+        assert_eq!(tx.cancel(5), Err(TransactionError::TransactionReturned));
+        assert_eq!(tx.mark_returned(5), Err(TransactionError::TransactionReturned));
+    }
 }
@@ -10,12 +10,15 @@
 //! - **Queue Integrity**: No orphaned or duplicate transactions
 //! - **Config Matching**: State can only be loaded with matching config
 
+use crate::costs::CostRates;
 use crate::models::agent::Agent;
-use crate::models::transaction::{Transaction, TransactionStatus};
+use crate::models::flow_window::FlowWindow;
+use crate::models::transaction::{SettlementMechanism, Transaction, TransactionStatus};
 use crate::orchestrator::SimulationError;
+use crate::rng::RngManager;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 // ============================================================================
 // Snapshot Structures
@@ -36,6 +39,10 @@ pub struct StateSnapshot {
     /// RNG seed at time of snapshot (CRITICAL for determinism)
     pub rng_seed: u64,
 
+    /// Pending second Box-Muller value held by the RNG (optional for backwards compat)
+    #[serde(default)]
+    pub rng_cached_gaussian: Option<f64>,
+
     /// All agent states
     pub agents: Vec<AgentSnapshot>,
 
@@ -47,6 +54,31 @@ pub struct StateSnapshot {
 
     /// SHA256 hash of original config (for validation)
     pub config_hash: String,
+
+    /// Running total of externally injected liquidity (optional for backwards compat)
+    #[serde(default)]
+    pub injected_liquidity: i64,
+
+    /// Per-agent arrival RNG streams, keyed by agent ID (optional for backwards compat)
+    ///
+    /// Empty in legacy snapshots; the streams are then re-derived from the
+    /// config seed.
+    #[serde(default)]
+    pub arrival_rngs: BTreeMap<String, RngManager>,
+
+    /// Scheduled releases keyed by release tick (optional for backwards compat)
+    #[serde(default)]
+    pub scheduled_releases: BTreeMap<usize, Vec<String>>,
+
+    /// Live cost rates, which `CostRateChange` events may have moved away
+    /// from the config (optional for backwards compat; `None` uses the config)
+    #[serde(default)]
+    pub cost_rates: Option<CostRates>,
+
+    /// Trailing settled flow window (optional for backwards compat; `None`
+    /// starts an empty window)
+    #[serde(default)]
+    pub flow_window: Option<FlowWindow>,
 }
 
 /// Agent state snapshot
@@ -73,6 +105,20 @@ pub struct AgentSnapshot {
     // Maximum collateral capacity (optional for backwards compat)
     #[serde(default)]
     pub max_collateral_capacity: Option<i64>,
+    // Suspension state (optional for backwards compat)
+    #[serde(default)]
+    pub suspended: bool,
+    #[serde(default)]
+    pub suspension_resume_tick: Option<usize>,
+    // Collateral classes (optional for backwards compat)
+    #[serde(default)]
+    pub collateral_classes: Vec<(String, i64, f64)>,
+    // Policy state registers (optional for backwards compat)
+    #[serde(default)]
+    pub state_registers: BTreeMap<String, f64>,
+    // Reserve requirement (optional for backwards compat)
+    #[serde(default)]
+    pub reserve_requirement: i64,
 }
 
 impl From<&Agent> for AgentSnapshot {
@@ -97,6 +143,16 @@ impl From<&Agent> for AgentSnapshot {
             allocated_liquidity: Some(agent.allocated_liquidity()),
             // Maximum collateral capacity (explicit setting, not heuristic)
             max_collateral_capacity: agent.max_collateral_capacity_setting(),
+            // Suspension state
+            suspended: agent.is_suspended(),
+            suspension_resume_tick: agent.suspension_resume_tick(),
+            collateral_classes: agent.collateral_classes().to_vec(),
+            state_registers: agent
+                .state_registers()
+                .iter()
+                .map(|(key, value)| (key.clone(), *value))
+                .collect(),
+            reserve_requirement: agent.reserve_requirement(),
         }
     }
 }
@@ -122,6 +178,11 @@ impl From<AgentSnapshot> for Agent {
             total_outflow: snapshot.total_outflow,
             allocated_liquidity: snapshot.allocated_liquidity, // Enhancement 11.2
             max_collateral_capacity: snapshot.max_collateral_capacity,
+            suspended: snapshot.suspended,
+            suspension_resume_tick: snapshot.suspension_resume_tick,
+            collateral_classes: snapshot.collateral_classes,
+            state_registers: snapshot.state_registers.into_iter().collect(),
+            reserve_requirement: snapshot.reserve_requirement,
         })
     }
 }
@@ -139,6 +200,18 @@ pub struct TransactionSnapshot {
     pub priority: u8,
     pub status: TransactionStatus,
     pub parent_id: Option<String>,
+    #[serde(default)]
+    pub settlement_mechanism: Option<SettlementMechanism>,
+    #[serde(default)]
+    pub divisible: bool,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    #[serde(default)]
+    pub entered_queue1_tick: Option<usize>,
+    #[serde(default)]
+    pub order_hint: i64,
+    #[serde(default)]
+    pub retry_count: usize,
 }
 
 impl From<&Transaction> for TransactionSnapshot {
@@ -154,13 +227,19 @@ impl From<&Transaction> for TransactionSnapshot {
             priority: tx.priority(),
             status: tx.status().clone(),
             parent_id: tx.parent_id().map(|s| s.to_string()),
+            settlement_mechanism: tx.settlement_mechanism(),
+            divisible: tx.is_divisible(),
+            tags: tx.tags().to_vec(),
+            entered_queue1_tick: tx.entered_queue1_tick(),
+            order_hint: tx.order_hint(),
+            retry_count: tx.retry_count(),
         }
     }
 }
 
 impl From<TransactionSnapshot> for Transaction {
     fn from(snapshot: TransactionSnapshot) -> Self {
-        Transaction::from_snapshot(
+        let mut tx = Transaction::from_snapshot(
             snapshot.id,
             snapshot.sender_id,
             snapshot.receiver_id,
@@ -172,6 +251,17 @@ impl From<TransactionSnapshot> for Transaction {
             snapshot.status,
             snapshot.parent_id,
         )
+        .with_divisible(snapshot.divisible)
+        .with_tags(snapshot.tags)
+        .with_order_hint(snapshot.order_hint)
+        .with_retry_count(snapshot.retry_count);
+        if let Some(mechanism) = snapshot.settlement_mechanism {
+            tx.set_settlement_mechanism(mechanism);
+        }
+        if let Some(tick) = snapshot.entered_queue1_tick {
+            tx.mark_entered_queue1(tick);
+        }
+        tx
     }
 }
 
@@ -231,7 +321,7 @@ pub fn compute_config_hash<T: Serialize>(config: &T) -> Result<String, Simulatio
 /// Validate state snapshot integrity
 ///
 /// Checks critical invariants:
-/// - Balance conservation
+/// - Balance conservation (net of `injected_liquidity`, which is not zero-sum)
 /// - Transaction referential integrity
 /// - Queue uniqueness (no duplicates)
 pub fn validate_snapshot(
@@ -239,11 +329,20 @@ pub fn validate_snapshot(
     expected_total_balance: i64,
 ) -> Result<(), SimulationError> {
     // 1. Balance conservation
+    // External injections add money to the system, so compare the conserved
+    // balance (total minus the injected running total) against the expectation
+    if snapshot.injected_liquidity < 0 {
+        return Err(SimulationError::StateValidationError(format!(
+            "Injected liquidity cannot be negative: {}",
+            snapshot.injected_liquidity
+        )));
+    }
     let total_balance: i64 = snapshot.agents.iter().map(|a| a.balance).sum();
-    if total_balance != expected_total_balance {
+    let conserved_balance = total_balance - snapshot.injected_liquidity;
+    if conserved_balance != expected_total_balance {
         return Err(SimulationError::StateValidationError(format!(
-            "Balance conservation violated: expected {}, got {}",
-            expected_total_balance, total_balance
+            "Balance conservation violated: expected {}, got {} (total {} minus injected {})",
+            expected_total_balance, conserved_balance, total_balance, snapshot.injected_liquidity
         )));
     }
 
@@ -41,6 +41,7 @@
 //!             credit_limit: 500_000,
 //!             policy: PolicyConfig::Fifo,
 //!             arrival_config: None,
+//!             ..Default::default()
 //!         },
 //!         AgentConfig {
 //!             id: "BANK_B".to_string(),
@@ -51,9 +52,11 @@
 //!                 urgency_threshold: 5,
 //!             },
 //!             arrival_config: None,
+//!             ..Default::default()
 //!         },
 //!     ],
 //!     cost_rates: Default::default(),
+//!     ..Default::default()
 //! };
 //!
 //! let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -66,17 +69,24 @@
 //! }
 //! ```
 
-use crate::arrivals::{ArrivalBandsConfig, ArrivalConfig, ArrivalGenerator};
+use crate::arrivals::{
+    AmountDistribution, ArrivalBandsConfig, ArrivalConfig, ArrivalCountModel, ArrivalGenerator,
+    DeadlineMode,
+};
 use crate::core::time::TimeManager;
-use crate::costs::{get_priority_band, CostRates, PenaltyMode, PriorityBand, PriorityDelayMultipliers};
+use crate::costs::{get_priority_band, CollateralCostBasis, CostRateOverrides, CostRates, PenaltyMode, PriorityBand, PriorityDelayMultipliers};
 use crate::models::agent::Agent;
 use crate::models::event::{Event, EventLog};
+use crate::models::flow_window::FlowWindow;
 use crate::models::state::SimulationState;
-use crate::models::transaction::{RtgsPriority, Transaction};
+use crate::models::transaction::{RtgsPriority, SettlementMechanism, Transaction};
+use crate::policy::tree::DecisionPath;
 use crate::policy::CashManagerPolicy;
 use crate::rng::RngManager;
 use crate::settlement::lsm::LsmConfig;
-use std::collections::HashMap;
+use crate::settlement::Queue2Ordering;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet, VecDeque};
 
 // ============================================================================
 // Configuration Types
@@ -87,6 +97,11 @@ fn default_eod_rush_threshold() -> f64 {
     0.8
 }
 
+/// Default trailing window for `recent_inflow_rate` / `recent_outflow_rate`
+fn default_flow_window_ticks() -> usize {
+    10
+}
+
 /// Complete orchestrator configuration
 ///
 /// This struct contains all parameters needed to initialize a simulation.
@@ -101,6 +116,8 @@ fn default_eod_rush_threshold() -> f64 {
 /// * `cost_rates` - Rates for calculating liquidity, delay, and penalty costs
 fn default_true() -> bool { true }
 
+fn default_time_scale() -> f64 { 1.0 }
+
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct OrchestratorConfig {
     /// Number of ticks per business day (e.g., 100 ticks = 1 tick per ~5 minutes)
@@ -148,6 +165,15 @@ pub struct OrchestratorConfig {
     #[serde(default)]
     pub priority_mode: bool,
 
+    /// Queue 2 processing order (default: FIFO)
+    /// When left at "fifo", `priority_mode` still selects priority bands.
+    /// - "fifo": Insertion order (default, backward compatible)
+    /// - "priority_bands": Same as `priority_mode`
+    /// - "largest_first" / "smallest_first": By remaining amount
+    /// - "soonest_deadline": By deadline tick (ascending)
+    #[serde(default)]
+    pub queue2_ordering: Queue2Ordering,
+
     /// Dynamic priority escalation configuration (default: disabled)
     /// When enabled, transaction priorities are boosted as deadlines approach.
     #[serde(default)]
@@ -169,6 +195,13 @@ pub struct OrchestratorConfig {
     #[serde(default)]
     pub entry_disposition_offsetting: bool,
 
+    /// Minimum leg size for entry disposition offsetting (default: 0)
+    /// Entry-time offsetting only triggers when both directions of a bilateral
+    /// pair total more than this amount (cents). Avoids churning the queue with
+    /// tiny offsets. 0 offsets every pair (backward compatible).
+    #[serde(default)]
+    pub entry_offset_min_amount: i64,
+
     /// Deferred crediting mode (default: false)
     /// When enabled, credits from settlements are accumulated during the tick
     /// and applied at the end of the tick. This prevents "within-tick recycling"
@@ -177,6 +210,8 @@ pub struct OrchestratorConfig {
     ///
     /// When false (default): Immediate crediting - receivers can use funds immediately
     /// When true: Deferred crediting - receivers can only use funds in the next tick
+    ///
+    /// Agents may override this via `AgentConfig::deferred_crediting`.
     #[serde(default)]
     pub deferred_crediting: bool,
 
@@ -203,6 +238,539 @@ pub struct OrchestratorConfig {
     /// When false: allocation happens once at init, balances carry forward as-is.
     #[serde(default = "default_true")]
     pub daily_liquidity_reallocation: bool,
+
+    /// Record payment tree decision paths (default: false)
+    ///
+    /// When enabled, the path of node IDs traversed for every payment
+    /// decision is kept and can be queried with `get_decision_path`.
+    /// Off by default because the store grows with every decision.
+    #[serde(default)]
+    pub record_decision_paths: bool,
+
+    /// Maximum Queue 2 (RTGS queue) size (default: None = unbounded)
+    ///
+    /// Models a bounded central queue. A submission that cannot settle while
+    /// Queue 2 is full is rejected back to the sender's Queue 1 (emitting
+    /// `RtgsQueueFull`) and is retried when the policy next releases it.
+    #[serde(default)]
+    pub rtgs_queue_capacity: Option<usize>,
+
+    /// Trailing window, in ticks, for settled flow rates (default: 10)
+    ///
+    /// Policies see `recent_inflow_rate` / `recent_outflow_rate` as the
+    /// average value settled per tick over this many completed ticks.
+    #[serde(default = "default_flow_window_ticks")]
+    pub flow_window_ticks: usize,
+
+    /// Where `SubmitPartial` / `StaggerSplit` put the split remainder
+    /// (default: last child)
+    #[serde(default)]
+    pub split_remainder_policy: SplitRemainderPolicy,
+
+    /// Validate state invariants after every tick (default: false)
+    ///
+    /// When enabled, `tick()` runs `SimulationState::validate_invariants`
+    /// once the tick completes and fails with `StateValidationError` on the
+    /// first violation. Meant for fuzzing and debugging; it scans every
+    /// transaction each tick.
+    #[serde(default)]
+    pub debug_invariant_checks: bool,
+
+    /// Treatment of transactions that miss their deadline (default: SoftDelay)
+    ///
+    /// `PenalizeAndForceSettle` charges the usual one-time deadline penalty
+    /// and then settles the overdue transaction in the same tick, bypassing
+    /// its policy, Queue 2 ordering and counterparty limits. Settlement still
+    /// respects the sender's allowed overdraft; a transaction that cannot be
+    /// covered even with full overdraft stays queued and is retried each tick.
+    #[serde(default)]
+    pub deadline_enforcement: DeadlineEnforcement,
+
+    /// Cap on generated arrivals per agent per tick (default: None = uncapped)
+    ///
+    /// Guards against a misconfigured high `rate_per_tick`. When the sampled
+    /// count exceeds the cap only the cap is generated and
+    /// `ArrivalsClamped` is logged. Band configs share one cap per agent.
+    #[serde(default)]
+    pub max_arrivals_per_agent_per_tick: Option<usize>,
+
+    /// Force-release all of Queue 1 on the last tick of each day (default: false)
+    ///
+    /// After the normal policy passes on the final tick of a day, every
+    /// transaction still in a non-suspended agent's Queue 1 is submitted to
+    /// RTGS regardless of policy or release budget, logging `EodForceRelease`.
+    #[serde(default)]
+    pub force_release_at_eod: bool,
+
+    /// Failed Queue 2 retries before a transaction is returned (default: None = retry forever)
+    ///
+    /// Each Queue 2 processing pass that fails to settle a transaction counts
+    /// one retry. On reaching the limit the transaction is removed from
+    /// Queue 2, marked `TransactionStatus::Returned` and `SettlementAbandoned`
+    /// is logged.
+    #[serde(default)]
+    pub max_settlement_retries: Option<usize>,
+
+    /// Record every agent's end-of-tick balance (default: false)
+    ///
+    /// When enabled, each agent's balance is appended to a per-agent series
+    /// after every tick, queryable with `balance_series`. Off by default
+    /// because the series grow by one entry per agent per tick.
+    #[serde(default)]
+    pub record_balance_series: bool,
+
+    /// Record network-wide settled value and count per tick (default: false)
+    ///
+    /// When enabled, each tick's `(settled_value, settlement_count)` is
+    /// appended to a series queryable with `throughput_series`.
+    #[serde(default)]
+    pub record_throughput_series: bool,
+
+    /// Number of initial ticks excluded from delay metrics (default: 0)
+    ///
+    /// Transactions arriving before this tick are left out of
+    /// `calculate_system_metrics`, `settlement_delay_histogram` and
+    /// `delay_percentiles`, so start-up queue build-up does not skew them.
+    /// `DailyMetrics` for days overlapping the warm-up are flagged `in_warmup`.
+    #[serde(default)]
+    pub warmup_ticks: usize,
+
+    /// Multiplier on every agent's arrival rate (default: 1.0)
+    ///
+    /// Lets a config calibrated at one temporal resolution run at another:
+    /// with ticks twice as long, `time_scale: 2.0` doubles each Poisson λ
+    /// (and the negative binomial mixing rate). Must be finite and > 0.
+    #[serde(default = "default_time_scale")]
+    pub time_scale: f64,
+}
+
+/// A config with no agents and every optional setting at its serde default
+///
+/// `ticks_per_day`, `num_days` and `rng_seed` have no meaningful default and
+/// are left at zero, so a usable config must set them (use
+/// [`OrchestratorConfig::builder`] to have them checked). Meant for struct
+/// update syntax, so configs only spell out what they use:
+///
+/// ```
+/// use payment_simulator_core_rs::orchestrator::OrchestratorConfig;
+///
+/// let config = OrchestratorConfig {
+///     ticks_per_day: 100,
+///     num_days: 1,
+///     rng_seed: 42,
+///     ..Default::default()
+/// };
+/// assert_eq!(config.flow_window_ticks, 10);
+/// ```
+impl Default for OrchestratorConfig {
+    fn default() -> Self {
+        Self {
+            ticks_per_day: 0,
+            eod_rush_threshold: default_eod_rush_threshold(),
+            num_days: 0,
+            rng_seed: 0,
+            agent_configs: Vec::new(),
+            cost_rates: CostRates::default(),
+            lsm_config: LsmConfig::default(),
+            scenario_events: None,
+            queue1_ordering: Queue1Ordering::default(),
+            priority_mode: false,
+            queue2_ordering: Queue2Ordering::default(),
+            priority_escalation: PriorityEscalationConfig::default(),
+            algorithm_sequencing: false,
+            entry_disposition_offsetting: false,
+            entry_offset_min_amount: 0,
+            deferred_crediting: false,
+            deadline_cap_at_eod: false,
+            daily_liquidity_reallocation: default_true(),
+            record_decision_paths: false,
+            rtgs_queue_capacity: None,
+            flow_window_ticks: default_flow_window_ticks(),
+            split_remainder_policy: SplitRemainderPolicy::default(),
+            debug_invariant_checks: false,
+            deadline_enforcement: DeadlineEnforcement::default(),
+            max_arrivals_per_agent_per_tick: None,
+            force_release_at_eod: false,
+            max_settlement_retries: None,
+            record_balance_series: false,
+            record_throughput_series: false,
+            warmup_ticks: 0,
+            time_scale: default_time_scale(),
+        }
+    }
+}
+
+impl OrchestratorConfig {
+    /// Start building a config with defaults for every optional field
+    ///
+    /// See [`OrchestratorConfigBuilder`].
+    pub fn builder() -> OrchestratorConfigBuilder {
+        OrchestratorConfigBuilder::default()
+    }
+
+    /// Same config with a different RNG seed
+    ///
+    /// Only `rng_seed` changes, so replications of a scenario differ in
+    /// their random draws (and checkpoint config hash) and nothing else.
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.rng_seed = seed;
+        self
+    }
+}
+
+/// Builder for [`OrchestratorConfig`]
+///
+/// `ticks_per_day`, `num_days`, `rng_seed` and at least one agent are
+/// required; every other field starts at the same default a config file
+/// gets when the key is omitted. `build()` runs the same validation as
+/// `Orchestrator::new`, so a built config is known to be usable.
+///
+/// # Example
+///
+/// ```
+/// use payment_simulator_core_rs::orchestrator::{AgentConfig, OrchestratorConfig, PolicyConfig};
+///
+/// let agent = |id: &str| AgentConfig {
+///     id: id.to_string(),
+///     opening_balance: 1_000_000,
+///     unsecured_cap: 0,
+///     policy: PolicyConfig::Fifo,
+///     arrival_config: None,
+///     arrival_bands: None,
+///     posted_collateral: None,
+///     collateral_haircut: None,
+///     max_collateral_capacity: None,
+///     limits: None,
+///     liquidity_pool: None,
+///     liquidity_allocation_fraction: None,
+///     ..Default::default()
+/// };
+///
+/// let config = OrchestratorConfig::builder()
+///     .ticks_per_day(100)
+///     .num_days(1)
+///     .rng_seed(42)
+///     .agent(agent("BANK_A"))
+///     .agent(agent("BANK_B"))
+///     .deferred_crediting(true)
+///     .build()
+///     .unwrap();
+/// assert_eq!(config.agent_configs.len(), 2);
+/// ```
+#[derive(Debug, Clone)]
+pub struct OrchestratorConfigBuilder {
+    ticks_per_day: Option<usize>,
+    num_days: Option<usize>,
+    rng_seed: Option<u64>,
+    agent_configs: Vec<AgentConfig>,
+    eod_rush_threshold: f64,
+    cost_rates: CostRates,
+    lsm_config: LsmConfig,
+    scenario_events: Option<Vec<crate::events::ScheduledEvent>>,
+    queue1_ordering: Queue1Ordering,
+    priority_mode: bool,
+    queue2_ordering: Queue2Ordering,
+    priority_escalation: PriorityEscalationConfig,
+    algorithm_sequencing: bool,
+    entry_disposition_offsetting: bool,
+    entry_offset_min_amount: i64,
+    deferred_crediting: bool,
+    deadline_cap_at_eod: bool,
+    daily_liquidity_reallocation: bool,
+    record_decision_paths: bool,
+    rtgs_queue_capacity: Option<usize>,
+    flow_window_ticks: usize,
+    split_remainder_policy: SplitRemainderPolicy,
+    debug_invariant_checks: bool,
+    deadline_enforcement: DeadlineEnforcement,
+    max_arrivals_per_agent_per_tick: Option<usize>,
+    force_release_at_eod: bool,
+    max_settlement_retries: Option<usize>,
+    record_balance_series: bool,
+    record_throughput_series: bool,
+    warmup_ticks: usize,
+    time_scale: f64,
+}
+
+impl Default for OrchestratorConfigBuilder {
+    fn default() -> Self {
+        Self {
+            ticks_per_day: None,
+            num_days: None,
+            rng_seed: None,
+            agent_configs: Vec::new(),
+            eod_rush_threshold: default_eod_rush_threshold(),
+            cost_rates: CostRates::default(),
+            lsm_config: LsmConfig::default(),
+            scenario_events: None,
+            queue1_ordering: Queue1Ordering::default(),
+            priority_mode: false,
+            queue2_ordering: Queue2Ordering::default(),
+            priority_escalation: PriorityEscalationConfig::default(),
+            algorithm_sequencing: false,
+            entry_disposition_offsetting: false,
+            entry_offset_min_amount: 0,
+            deferred_crediting: false,
+            deadline_cap_at_eod: false,
+            daily_liquidity_reallocation: default_true(),
+            record_decision_paths: false,
+            rtgs_queue_capacity: None,
+            flow_window_ticks: default_flow_window_ticks(),
+            split_remainder_policy: SplitRemainderPolicy::default(),
+            debug_invariant_checks: false,
+            deadline_enforcement: DeadlineEnforcement::default(),
+            max_arrivals_per_agent_per_tick: None,
+            force_release_at_eod: false,
+            max_settlement_retries: None,
+            record_balance_series: false,
+            record_throughput_series: false,
+            warmup_ticks: 0,
+            time_scale: 1.0,
+        }
+    }
+}
+
+impl OrchestratorConfigBuilder {
+    /// Number of ticks per business day (required)
+    pub fn ticks_per_day(mut self, ticks_per_day: usize) -> Self {
+        self.ticks_per_day = Some(ticks_per_day);
+        self
+    }
+
+    /// Number of business days to simulate (required)
+    pub fn num_days(mut self, num_days: usize) -> Self {
+        self.num_days = Some(num_days);
+        self
+    }
+
+    /// RNG seed (required)
+    pub fn rng_seed(mut self, rng_seed: u64) -> Self {
+        self.rng_seed = Some(rng_seed);
+        self
+    }
+
+    /// Replace all agent configurations
+    pub fn agent_configs(mut self, agent_configs: Vec<AgentConfig>) -> Self {
+        self.agent_configs = agent_configs;
+        self
+    }
+
+    /// Add one agent configuration
+    pub fn agent(mut self, agent_config: AgentConfig) -> Self {
+        self.agent_configs.push(agent_config);
+        self
+    }
+
+    /// End-of-day rush threshold (default: 0.8)
+    pub fn eod_rush_threshold(mut self, eod_rush_threshold: f64) -> Self {
+        self.eod_rush_threshold = eod_rush_threshold;
+        self
+    }
+
+    /// Cost rates (default: `CostRates::default()`)
+    pub fn cost_rates(mut self, cost_rates: CostRates) -> Self {
+        self.cost_rates = cost_rates;
+        self
+    }
+
+    /// LSM configuration (default: `LsmConfig::default()`)
+    pub fn lsm_config(mut self, lsm_config: LsmConfig) -> Self {
+        self.lsm_config = lsm_config;
+        self
+    }
+
+    /// Scheduled scenario events (default: none)
+    pub fn scenario_events(mut self, events: Vec<crate::events::ScheduledEvent>) -> Self {
+        self.scenario_events = Some(events);
+        self
+    }
+
+    /// Queue 1 ordering (default: FIFO)
+    pub fn queue1_ordering(mut self, ordering: Queue1Ordering) -> Self {
+        self.queue1_ordering = ordering;
+        self
+    }
+
+    /// T2-style Queue 2 priority mode (default: false)
+    pub fn priority_mode(mut self, enabled: bool) -> Self {
+        self.priority_mode = enabled;
+        self
+    }
+
+    /// Queue 2 ordering (default: FIFO)
+    pub fn queue2_ordering(mut self, ordering: Queue2Ordering) -> Self {
+        self.queue2_ordering = ordering;
+        self
+    }
+
+    /// Priority escalation (default: disabled)
+    pub fn priority_escalation(mut self, escalation: PriorityEscalationConfig) -> Self {
+        self.priority_escalation = escalation;
+        self
+    }
+
+    /// Emit AlgorithmExecution events (default: false)
+    pub fn algorithm_sequencing(mut self, enabled: bool) -> Self {
+        self.algorithm_sequencing = enabled;
+        self
+    }
+
+    /// Entry disposition offsetting (default: false)
+    pub fn entry_disposition_offsetting(mut self, enabled: bool) -> Self {
+        self.entry_disposition_offsetting = enabled;
+        self
+    }
+
+    /// Minimum leg size for entry disposition offsetting (default: 0)
+    pub fn entry_offset_min_amount(mut self, amount: i64) -> Self {
+        self.entry_offset_min_amount = amount;
+        self
+    }
+
+    /// Deferred crediting (default: false)
+    pub fn deferred_crediting(mut self, enabled: bool) -> Self {
+        self.deferred_crediting = enabled;
+        self
+    }
+
+    /// Cap generated deadlines at end of day (default: false)
+    pub fn deadline_cap_at_eod(mut self, enabled: bool) -> Self {
+        self.deadline_cap_at_eod = enabled;
+        self
+    }
+
+    /// Daily liquidity reallocation (default: true, as for config files)
+    pub fn daily_liquidity_reallocation(mut self, enabled: bool) -> Self {
+        self.daily_liquidity_reallocation = enabled;
+        self
+    }
+
+    /// Record payment tree decision paths (default: false)
+    pub fn record_decision_paths(mut self, enabled: bool) -> Self {
+        self.record_decision_paths = enabled;
+        self
+    }
+
+    /// Maximum Queue 2 size (default: unbounded)
+    pub fn rtgs_queue_capacity(mut self, capacity: usize) -> Self {
+        self.rtgs_queue_capacity = Some(capacity);
+        self
+    }
+
+    /// Trailing window for settled flow rates (default: 10 ticks)
+    pub fn flow_window_ticks(mut self, ticks: usize) -> Self {
+        self.flow_window_ticks = ticks;
+        self
+    }
+
+    /// Split remainder placement (default: last child)
+    pub fn split_remainder_policy(mut self, policy: SplitRemainderPolicy) -> Self {
+        self.split_remainder_policy = policy;
+        self
+    }
+
+    /// Validate state invariants after every tick (default: false)
+    pub fn debug_invariant_checks(mut self, enabled: bool) -> Self {
+        self.debug_invariant_checks = enabled;
+        self
+    }
+
+    /// Treatment of transactions that miss their deadline (default: soft delay)
+    pub fn deadline_enforcement(mut self, mode: DeadlineEnforcement) -> Self {
+        self.deadline_enforcement = mode;
+        self
+    }
+
+    /// Cap on generated arrivals per agent per tick (default: uncapped)
+    pub fn max_arrivals_per_agent_per_tick(mut self, max_arrivals: usize) -> Self {
+        self.max_arrivals_per_agent_per_tick = Some(max_arrivals);
+        self
+    }
+
+    /// Force-release all of Queue 1 on the last tick of each day (default: false)
+    pub fn force_release_at_eod(mut self, enabled: bool) -> Self {
+        self.force_release_at_eod = enabled;
+        self
+    }
+
+    /// Return transactions after this many failed Queue 2 retries (default: retry forever)
+    pub fn max_settlement_retries(mut self, max_retries: usize) -> Self {
+        self.max_settlement_retries = Some(max_retries);
+        self
+    }
+
+    /// Record every agent's end-of-tick balance series (default: false)
+    pub fn record_balance_series(mut self, enabled: bool) -> Self {
+        self.record_balance_series = enabled;
+        self
+    }
+
+    /// Record the network-wide settlement throughput series (default: false)
+    pub fn record_throughput_series(mut self, enabled: bool) -> Self {
+        self.record_throughput_series = enabled;
+        self
+    }
+
+    /// Exclude transactions arriving in the first `ticks` from delay metrics (default: 0)
+    pub fn warmup_ticks(mut self, ticks: usize) -> Self {
+        self.warmup_ticks = ticks;
+        self
+    }
+
+    /// Multiplier on every agent's arrival rate (default: 1.0)
+    pub fn time_scale(mut self, time_scale: f64) -> Self {
+        self.time_scale = time_scale;
+        self
+    }
+
+    /// Build and validate the config
+    ///
+    /// # Errors
+    ///
+    /// `SimulationError::InvalidConfig` if a required field was not set or
+    /// the resulting config fails the checks `Orchestrator::new` applies.
+    pub fn build(self) -> Result<OrchestratorConfig, SimulationError> {
+        let required = |name: &str| {
+            SimulationError::InvalidConfig(format!("{} is required", name))
+        };
+
+        let config = OrchestratorConfig {
+            ticks_per_day: self.ticks_per_day.ok_or_else(|| required("ticks_per_day"))?,
+            eod_rush_threshold: self.eod_rush_threshold,
+            num_days: self.num_days.ok_or_else(|| required("num_days"))?,
+            rng_seed: self.rng_seed.ok_or_else(|| required("rng_seed"))?,
+            agent_configs: self.agent_configs,
+            cost_rates: self.cost_rates,
+            lsm_config: self.lsm_config,
+            scenario_events: self.scenario_events,
+            queue1_ordering: self.queue1_ordering,
+            priority_mode: self.priority_mode,
+            queue2_ordering: self.queue2_ordering,
+            priority_escalation: self.priority_escalation,
+            algorithm_sequencing: self.algorithm_sequencing,
+            entry_disposition_offsetting: self.entry_disposition_offsetting,
+            entry_offset_min_amount: self.entry_offset_min_amount,
+            deferred_crediting: self.deferred_crediting,
+            deadline_cap_at_eod: self.deadline_cap_at_eod,
+            daily_liquidity_reallocation: self.daily_liquidity_reallocation,
+            record_decision_paths: self.record_decision_paths,
+            rtgs_queue_capacity: self.rtgs_queue_capacity,
+            flow_window_ticks: self.flow_window_ticks,
+            split_remainder_policy: self.split_remainder_policy,
+            debug_invariant_checks: self.debug_invariant_checks,
+            deadline_enforcement: self.deadline_enforcement,
+            max_arrivals_per_agent_per_tick: self.max_arrivals_per_agent_per_tick,
+            force_release_at_eod: self.force_release_at_eod,
+            max_settlement_retries: self.max_settlement_retries,
+            record_balance_series: self.record_balance_series,
+            record_throughput_series: self.record_throughput_series,
+            warmup_ticks: self.warmup_ticks,
+            time_scale: self.time_scale,
+        };
+
+        Orchestrator::validate_config(&config)?;
+        Ok(config)
+    }
 }
 
 /// Priority escalation configuration
@@ -211,13 +779,23 @@ pub struct OrchestratorConfig {
 /// This prevents low-priority transactions from being starved when they
 /// become urgent due to time pressure.
 ///
-/// # Escalation Formula (linear)
+/// # Escalation Formula
 ///
 /// ```text
-/// boost = max_boost * (1 - ticks_remaining / start_escalating_at_ticks)
+/// progress = 1 - ticks_remaining / start_escalating_at_ticks
+/// boost = max_boost * shape(progress)
 /// ```
 ///
-/// Example with start_escalating_at_ticks=20, max_boost=3:
+/// where `shape` depends on `curve`:
+/// - `"linear"`: `progress`
+/// - `"quadratic"`: `progress²`
+/// - `"exponential"`: `(e^(3·progress) - 1) / (e^3 - 1)`
+///
+/// All shapes go from 0 at the start of the window to 1 at the deadline, so
+/// `max_boost` is always reached; the non-linear ones stay gentle early and
+/// escalate sharply near the deadline.
+///
+/// Example with start_escalating_at_ticks=20, max_boost=3 (linear):
 /// - 20 ticks remaining: +0 boost
 /// - 10 ticks remaining: +1.5 boost
 /// - 5 ticks remaining: +2.25 boost
@@ -228,7 +806,7 @@ pub struct PriorityEscalationConfig {
     #[serde(default)]
     pub enabled: bool,
 
-    /// Escalation curve type (currently only "linear" supported)
+    /// Escalation curve type: "linear", "quadratic" or "exponential"
     #[serde(default = "default_escalation_curve")]
     pub curve: String,
 
@@ -252,6 +830,42 @@ impl Default for PriorityEscalationConfig {
     }
 }
 
+impl PriorityEscalationConfig {
+    /// Supported `curve` names
+    pub const CURVES: &'static [&'static str] = &["linear", "quadratic", "exponential"];
+
+    /// Steepness of the "exponential" curve (higher = later, sharper rise)
+    const EXPONENTIAL_STEEPNESS: f64 = 3.0;
+
+    /// Priority boost for a transaction `ticks_remaining` ticks from its deadline
+    ///
+    /// Returns 0 outside the escalation window and `max_boost` at (or past)
+    /// the deadline. Unknown curve names are rejected by config validation.
+    pub fn boost(&self, ticks_remaining: usize) -> u8 {
+        if ticks_remaining > self.start_escalating_at_ticks {
+            return 0;
+        }
+
+        // Progress through escalation window (0.0 to 1.0)
+        let progress = if self.start_escalating_at_ticks > 0 {
+            1.0 - (ticks_remaining as f64 / self.start_escalating_at_ticks as f64)
+        } else {
+            1.0
+        };
+
+        let shaped = match self.curve.as_str() {
+            "quadratic" => progress * progress,
+            "exponential" => {
+                let k = Self::EXPONENTIAL_STEEPNESS;
+                ((k * progress).exp() - 1.0) / (k.exp() - 1.0)
+            }
+            _ => progress,
+        };
+
+        (self.max_boost as f64 * shaped).round() as u8
+    }
+}
+
 fn default_escalation_curve() -> String {
     "linear".to_string()
 }
@@ -271,10 +885,50 @@ pub enum Queue1Ordering {
     /// First-In-First-Out ordering (default)
     #[default]
     Fifo,
-    /// Priority-deadline ordering: high priority first, then soonest deadline
+    /// Priority-deadline ordering: high priority first, then soonest deadline,
+    /// then earliest arrival; remaining ties keep their queue order
     PriorityDeadline,
 }
 
+/// Where the indivisible remainder goes when a payment is split
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SplitRemainderPolicy {
+    /// Last child carries the whole remainder (default)
+    #[default]
+    LastChild,
+    /// First `remainder` children carry one extra cent each
+    Distributed,
+}
+
+impl SplitRemainderPolicy {
+    /// Amount of child `index` (0-based) when splitting `total` into `num_splits`
+    ///
+    /// Children always sum to `total` exactly.
+    pub fn child_amount(&self, total: i64, num_splits: usize, index: usize) -> i64 {
+        let base = total / num_splits as i64;
+        let remainder = total % num_splits as i64;
+        match self {
+            SplitRemainderPolicy::LastChild if index == num_splits - 1 => base + remainder,
+            SplitRemainderPolicy::LastChild => base,
+            SplitRemainderPolicy::Distributed if (index as i64) < remainder => base + 1,
+            SplitRemainderPolicy::Distributed => base,
+        }
+    }
+}
+
+/// What happens to a transaction once it misses its deadline
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DeadlineEnforcement {
+    /// Stays queued as overdue, accruing multiplied delay cost (default)
+    #[default]
+    SoftDelay,
+    /// One-time deadline penalty, then settled at once even into overdraft
+    /// (within the sender's allowed limit)
+    PenalizeAndForceSettle,
+}
+
 /// Per-agent configuration
 ///
 /// Specifies initial state and behavior for a single agent (bank).
@@ -337,6 +991,16 @@ pub struct AgentConfig {
     #[serde(default)]
     pub max_collateral_capacity: Option<i64>,
 
+    /// Collateral pledged by asset class: (class name, amount in cents, haircut)
+    ///
+    /// Each class contributes `amount × (1 - haircut)` to credit capacity, on
+    /// top of `posted_collateral` (which keeps using `collateral_haircut`).
+    /// Class collateral counts towards `posted_collateral()` but cannot be
+    /// withdrawn by collateral policies.
+    /// Example: [("govt_bonds", 10_000_000, 0.0), ("corporate", 5_000_000, 0.10)]
+    #[serde(default)]
+    pub collateral_classes: Option<Vec<(String, i64, f64)>>,
+
     /// Payment limits configuration (Phase 1: TARGET2 LSM alignment)
     /// Controls bilateral (per-counterparty) and multilateral (total) outflow limits
     #[serde(default)]
@@ -363,6 +1027,120 @@ pub struct AgentConfig {
     /// Example: pool = 1_000_000, fraction = 0.5 → allocate 500,000 cents
     #[serde(default)]
     pub liquidity_allocation_fraction: Option<f64>,
+
+    /// Group membership for group-weighted counterparty selection
+    ///
+    /// Arrival configs with `counterparty_group_weights` pick a target
+    /// group first, then a uniform member (e.g. "core" / "periphery").
+    #[serde(default)]
+    pub agent_group: Option<String>,
+
+    /// Minimum reserve the agent must hold (cents, default 0)
+    ///
+    /// Held back from the freely usable balance, so RTGS and LSM settlement
+    /// only take the balance below it by drawing on overdraft capacity.
+    #[serde(default)]
+    pub reserve_requirement: i64,
+
+    /// Per-agent deferred crediting override (default: None)
+    ///
+    /// Decides whether settlement credits *to this agent* are applied at end
+    /// of tick. `None` falls back to the global `deferred_crediting` flag.
+    #[serde(default)]
+    pub deferred_crediting: Option<bool>,
+
+    /// Exact arrivals to inject, e.g. replayed from a historical payment file
+    ///
+    /// When set, these replace stochastic generation for this agent: any
+    /// `arrival_config` / `arrival_bands` is ignored.
+    #[serde(default)]
+    pub scripted_arrivals: Option<Vec<ScriptedArrival>>,
+
+    /// Distribution to draw the opening balance from (default: None)
+    ///
+    /// When set, replaces `opening_balance` with a sample from the shared
+    /// simulation RNG at construction. Randomized agents are sampled in
+    /// sorted ID order before any other draw, so balances are reproducible
+    /// for a given seed; note that this shifts every later shared-RNG draw.
+    #[serde(default)]
+    pub opening_balance_distribution: Option<AmountDistribution>,
+
+    /// Agent-specific cost rates (default: None = global `cost_rates`)
+    ///
+    /// Each field set here replaces the global rate in every cost charged
+    /// to this agent, e.g. to price overdrafts differently by participant
+    /// tier. Unset fields follow the global rates, including changes made
+    /// by scenario `CostRateChange` events.
+    ///
+    /// This is a partial `CostRateOverrides` rather than a full `CostRates`:
+    /// a full struct would fill every omitted field from `CostRates::default()`
+    /// instead of the scenario's global rates, and would freeze the agent's
+    /// rates against later `CostRateChange` events.
+    #[serde(default)]
+    pub cost_rate_overrides: Option<CostRateOverrides>,
+}
+
+/// An agent with no ID, zero balance and credit, a FIFO policy and every
+/// optional setting off
+///
+/// Meant for struct update syntax, so configs only spell out what they use:
+///
+/// ```
+/// use payment_simulator_core_rs::orchestrator::AgentConfig;
+///
+/// let agent = AgentConfig {
+///     id: "BANK_A".to_string(),
+///     opening_balance: 1_000_000,
+///     ..Default::default()
+/// };
+/// assert!(agent.arrival_config.is_none());
+/// ```
+impl Default for AgentConfig {
+    fn default() -> Self {
+        Self {
+            id: String::new(),
+            opening_balance: 0,
+            unsecured_cap: 0,
+            policy: PolicyConfig::Fifo,
+            arrival_config: None,
+            arrival_bands: None,
+            posted_collateral: None,
+            collateral_haircut: None,
+            max_collateral_capacity: None,
+            limits: None,
+            liquidity_pool: None,
+            liquidity_allocation_fraction: None,
+            collateral_classes: None,
+            agent_group: None,
+            reserve_requirement: 0,
+            deferred_crediting: None,
+            scripted_arrivals: None,
+            opening_balance_distribution: None,
+            cost_rate_overrides: None,
+        }
+    }
+}
+
+/// A single scripted arrival sent by the owning agent
+///
+/// Injected at `tick` through `Orchestrator::submit_transaction`, so it is
+/// logged as a regular `Arrival` and subject to the same deadline capping.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct ScriptedArrival {
+    /// Absolute tick at which the payment arrives
+    pub tick: usize,
+
+    /// Receiving agent ID
+    pub receiver: String,
+
+    /// Payment amount (cents)
+    pub amount: i64,
+
+    /// Ticks from arrival to deadline (must be positive)
+    pub deadline_offset: usize,
+
+    /// Priority level (0-10, higher = more urgent)
+    pub priority: u8,
 }
 
 /// Bilateral and multilateral limits configuration for an agent
@@ -416,6 +1194,18 @@ pub enum PolicyConfig {
         min_split_amount: i64,
     },
 
+    /// Value-threshold batching policy
+    ///
+    /// Holds transactions in Queue 1 until the agent's total queued outgoing
+    /// value reaches `threshold`, then releases the whole batch. Any
+    /// transaction held for `max_hold_ticks` or longer is force-released.
+    ValueThreshold {
+        /// Minimum total Queue 1 value (cents) before the batch is released
+        threshold: i64,
+        /// Maximum ticks a transaction may wait in Queue 1
+        max_hold_ticks: usize,
+    },
+
     /// Mock splitting policy for testing (Phase 5)
     ///
     /// Always splits transactions into fixed number of parts.
@@ -465,7 +1255,7 @@ pub enum PolicyConfig {
 // are now imported from crate::costs module
 
 /// Cost breakdown for a single tick or agent
-#[derive(Debug, Clone, Default, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct CostBreakdown {
     /// Overdraft cost accrued this tick (cents)
     pub liquidity_cost: i64,
@@ -482,8 +1272,9 @@ pub struct CostBreakdown {
     /// Transaction splitting friction cost (cents)
     ///
     /// When a policy decides to split a transaction into N parts,
-    /// a friction cost is charged using the formula: f_s × (N-1)
-    /// where f_s is the per-split friction rate (split_friction_cost).
+    /// a friction cost is charged using the formula: f_s × (N-1)^e
+    /// where f_s is the per-split friction rate (split_friction_cost)
+    /// and e is split_friction_exponent (1.0 by default).
     ///
     /// This represents the operational overhead of creating and
     /// processing multiple smaller payments instead of one large payment.
@@ -494,6 +1285,21 @@ pub struct CostBreakdown {
     /// Applied to allocated liquidity (from liquidity_pool × allocation_fraction)
     /// to represent the opportunity cost of holding funds in settlement.
     pub liquidity_opportunity_cost: i64,
+
+    /// Overnight interest on the closing balance (cents, signed)
+    ///
+    /// Only non-zero on the end-of-day accrual. Positive when an overdrafted
+    /// balance is charged, negative when a positive balance earns deposit
+    /// interest (earnings reduce the agent's total cost).
+    #[serde(default)]
+    pub overnight_interest: i64,
+
+    /// One-time collateral posting fees charged this tick (cents)
+    ///
+    /// `collateral_post_fee` per executed post; distinct from the per-tick
+    /// opportunity cost in `collateral_cost`.
+    #[serde(default)]
+    pub collateral_fee_cost: i64,
 }
 
 impl CostBreakdown {
@@ -505,6 +1311,8 @@ impl CostBreakdown {
             + self.penalty_cost
             + self.split_friction_cost
             + self.liquidity_opportunity_cost
+            + self.overnight_interest
+            + self.collateral_fee_cost
     }
 }
 
@@ -534,6 +1342,13 @@ pub struct CostAccumulator {
     /// Accumulated opportunity cost from allocated liquidity sitting in
     /// the settlement system rather than earning interest elsewhere.
     pub total_liquidity_opportunity_cost: i64,
+
+    /// Total overnight interest (signed: negative when deposit interest
+    /// earned exceeds overdraft interest charged)
+    pub total_overnight_interest: i64,
+
+    /// Total one-time collateral posting fees
+    pub total_collateral_fee_cost: i64,
 }
 
 impl CostAccumulator {
@@ -550,6 +1365,8 @@ impl CostAccumulator {
         self.total_penalty_cost += costs.penalty_cost;
         self.total_split_friction_cost += costs.split_friction_cost;
         self.total_liquidity_opportunity_cost += costs.liquidity_opportunity_cost;
+        self.total_overnight_interest += costs.overnight_interest;
+        self.total_collateral_fee_cost += costs.collateral_fee_cost;
     }
 
     /// Update peak net debit if current balance is more negative
@@ -567,6 +1384,8 @@ impl CostAccumulator {
             + self.total_penalty_cost
             + self.total_split_friction_cost
             + self.total_liquidity_opportunity_cost
+            + self.total_overnight_interest
+            + self.total_collateral_fee_cost
     }
 }
 
@@ -578,7 +1397,7 @@ impl CostAccumulator {
 ///
 /// Provides comprehensive view of simulation health and efficiency.
 /// Used for Phase 8 API endpoints and monitoring dashboards.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct SystemMetrics {
     /// Total transactions that have arrived in the system
     pub total_arrivals: usize,
@@ -601,11 +1420,104 @@ pub struct SystemMetrics {
     /// Total number of transactions in RTGS queue (Queue 2)
     pub queue2_total_size: usize,
 
+    /// Oldest Queue 1 age in ticks (see `Transaction::ticks_in_queue`)
+    pub max_queue1_age: usize,
+
     /// Peak overdraft observed across all agents (absolute value)
     pub peak_overdraft: i64,
 
     /// Number of agents currently in overdraft
     pub agents_in_overdraft: usize,
+
+    /// Gini coefficient of current credit usage across agents
+    /// 0.0 = all agents use equal liquidity (or none), (n-1)/n = one agent uses it all
+    pub liquidity_usage_gini: f64,
+}
+
+/// How much of an agent's payment limits it has used today
+///
+/// Utilization is today's settled outflow divided by the limit, so 1.0 means
+/// the limit is exhausted. Resets with the daily outflow tracking.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LimitUtilization {
+    /// Per-counterparty utilization (only counterparties with a bilateral limit)
+    pub bilateral: BTreeMap<String, f64>,
+
+    /// Multilateral utilization (None if no multilateral limit configured)
+    pub multilateral: Option<f64>,
+}
+
+/// Gini coefficient of non-negative values (sorted-cumulative formula)
+///
+/// `G = 2·Σ(i·x_i) / (n·Σx) − (n+1)/n` over values sorted ascending, i = 1..n.
+/// Returns 0.0 for fewer than two values or an all-zero distribution.
+fn gini_coefficient(values: &[i64]) -> f64 {
+    let n = values.len();
+    let total: i128 = values.iter().map(|&v| v as i128).sum();
+    if n < 2 || total <= 0 {
+        return 0.0;
+    }
+
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
+
+    let weighted: i128 = sorted
+        .iter()
+        .enumerate()
+        .map(|(i, &v)| (i as i128 + 1) * v as i128)
+        .sum();
+
+    let n = n as f64;
+    2.0 * weighted as f64 / (n * total as f64) - (n + 1.0) / n
+}
+
+/// Run a clone of `base_config` with `rng_seed` replaced for `ticks` ticks
+///
+/// Returns the system metrics at the end of the run.
+pub fn run_seed(
+    base_config: &OrchestratorConfig,
+    seed: u64,
+    ticks: usize,
+) -> Result<SystemMetrics, SimulationError> {
+    let mut config = base_config.clone();
+    config.rng_seed = seed;
+    let mut orchestrator = Orchestrator::new(config)?;
+    for _ in 0..ticks {
+        orchestrator.tick()?;
+    }
+    Ok(orchestrator.calculate_system_metrics())
+}
+
+/// Run `base_config` once per seed and collect the final system metrics
+///
+/// Returns one `SystemMetrics` per seed, in `seeds` order. Runs are
+/// independent, so results do not depend on whether the `parallel` feature
+/// spreads them across threads.
+///
+/// # Errors
+///
+/// The first `SimulationError` raised while building or ticking any run.
+pub fn run_seed_sweep(
+    base_config: &OrchestratorConfig,
+    seeds: &[u64],
+    ticks: usize,
+) -> Result<Vec<SystemMetrics>, SimulationError> {
+    #[cfg(feature = "parallel")]
+    {
+        use rayon::prelude::*;
+        seeds
+            .par_iter()
+            .map(|&seed| run_seed(base_config, seed, ticks))
+            .collect()
+    }
+
+    #[cfg(not(feature = "parallel"))]
+    {
+        seeds
+            .iter()
+            .map(|&seed| run_seed(base_config, seed, ticks))
+            .collect()
+    }
 }
 
 // ============================================================================
@@ -634,6 +1546,11 @@ pub struct DailyMetrics {
     // Credit usage
     pub unsecured_cap: i64,
     pub peak_overdraft: i64,
+    /// Largest shortfall of balance against queued outbound commitments
+    /// (Queue 1 + Queue 2) seen at any tick, as a positive amount (0 if the
+    /// balance always covered them). Unlike `peak_overdraft` this counts
+    /// payments still waiting for liquidity.
+    pub peak_liquidity_requirement: i64,
 
     // Collateral management (Phase 8)
     pub opening_posted_collateral: i64,
@@ -654,18 +1571,31 @@ pub struct DailyMetrics {
     pub queue1_peak_size: usize,
     pub queue1_eod_size: usize,
 
+    // Liquidity efficiency (computed at EOD)
+    /// Gross value of the agent's outgoing payments settled today
+    pub settled_value: i64,
+    /// Settled value minus peak net liquidity usage (opening - min balance)
+    pub liquidity_saved: i64,
+    /// Settled value divided by opening balance (0.0 if opening balance <= 0)
+    pub turnover_ratio: f64,
+
     // Costs (captured from CostAccumulator at EOD)
     pub liquidity_cost: i64,
     pub delay_cost: i64,
     pub collateral_cost: i64,
     pub split_friction_cost: i64,
     pub deadline_penalty_cost: i64,
+    pub overnight_interest: i64,
+    pub collateral_fee_cost: i64,
     pub total_cost: i64,
+
+    /// Whether the day overlaps the configured warm-up period
+    pub in_warmup: bool,
 }
 
 impl DailyMetrics {
     /// Create new daily metrics for an agent at start of day
-    fn new(agent_id: String, day: usize, agent: &Agent) -> Self {
+    fn new(agent_id: String, day: usize, agent: &Agent, in_warmup: bool) -> Self {
         let unsecured_cap = agent.unsecured_cap();
         let opening_balance = agent.balance();
         let opening_posted_collateral = agent.posted_collateral();
@@ -679,6 +1609,7 @@ impl DailyMetrics {
             max_balance: opening_balance,
             unsecured_cap,
             peak_overdraft: 0,
+            peak_liquidity_requirement: 0,
             opening_posted_collateral,
             closing_posted_collateral: opening_posted_collateral,
             peak_posted_collateral: opening_posted_collateral,
@@ -692,12 +1623,18 @@ impl DailyMetrics {
             num_dropped: 0,
             queue1_peak_size: 0,
             queue1_eod_size: 0,
+            settled_value: 0,
+            liquidity_saved: 0,
+            turnover_ratio: 0.0,
             liquidity_cost: 0,
             delay_cost: 0,
             collateral_cost: 0,
             split_friction_cost: 0,
             deadline_penalty_cost: 0,
+            overnight_interest: 0,
+            collateral_fee_cost: 0,
             total_cost: 0,
+            in_warmup,
         }
     }
 
@@ -712,6 +1649,15 @@ impl DailyMetrics {
         }
     }
 
+    /// Update liquidity requirement tracking (called each tick)
+    ///
+    /// `position` is balance minus queued outbound commitments.
+    fn update_liquidity_requirement(&mut self, position: i64) {
+        if position < 0 {
+            self.peak_liquidity_requirement = self.peak_liquidity_requirement.max(-position);
+        }
+    }
+
     /// Update collateral tracking (called after collateral changes)
     fn update_collateral(&mut self, new_collateral: i64) {
         self.peak_posted_collateral = self.peak_posted_collateral.max(new_collateral);
@@ -722,26 +1668,69 @@ impl DailyMetrics {
         self.queue1_peak_size = self.queue1_peak_size.max(current_size);
     }
 
+    /// Capture the day's settled outflows (called at EOD, before they reset)
+    ///
+    /// Also records the final tick's balance, which the per-tick update
+    /// has not seen yet on the EOD tick.
+    fn capture_settlement_activity(&mut self, agent: &Agent) {
+        self.update_balance(agent.balance());
+        self.settled_value = agent.total_outflow();
+    }
+
     /// Finalize metrics at end of day
     fn finalize(&mut self, agent: &Agent, costs: &CostAccumulator) {
         self.closing_balance = agent.balance();
         self.closing_posted_collateral = agent.posted_collateral();
         self.queue1_eod_size = agent.outgoing_queue_size();
 
+        // Liquidity efficiency: gross value sent vs. liquidity actually drawn down
+        let peak_liquidity_usage = (self.opening_balance - self.min_balance).max(0);
+        self.liquidity_saved = (self.settled_value - peak_liquidity_usage).max(0);
+        self.turnover_ratio = if self.opening_balance > 0 {
+            self.settled_value as f64 / self.opening_balance as f64
+        } else {
+            0.0
+        };
+
         // Capture costs from accumulator
         self.liquidity_cost = costs.total_liquidity_cost;
         self.delay_cost = costs.total_delay_cost;
         self.collateral_cost = costs.total_collateral_cost;
         self.split_friction_cost = costs.total_split_friction_cost;
         self.deadline_penalty_cost = costs.total_penalty_cost;
+        self.overnight_interest = costs.total_overnight_interest;
+        self.collateral_fee_cost = costs.total_collateral_fee_cost;
         self.total_cost = costs.total();
     }
 }
 
+/// Derive the arrival RNG stream for one agent from the base seed
+fn arrival_rng_for(rng_seed: u64, agent_id: &str) -> RngManager {
+    RngManager::new(rng_seed).derive_stream(&format!("arrivals:{}", agent_id))
+}
+
+/// Derive arrival RNG streams for every configured agent
+fn derive_arrival_rngs(config: &OrchestratorConfig) -> HashMap<String, RngManager> {
+    config
+        .agent_configs
+        .iter()
+        .map(|ac| (ac.id.clone(), arrival_rng_for(config.rng_seed, &ac.id)))
+        .collect()
+}
+
 // ============================================================================
 // Orchestrator
 // ============================================================================
 
+/// Callback invoked at the end of every tick
+///
+/// Receives the tick's result and a read-only view of the simulation state.
+/// `Send + Sync` so the orchestrator can still cross thread boundaries (FFI).
+pub type TickObserver = Box<dyn FnMut(&TickResult, &SimulationState) + Send + Sync>;
+
+/// Number of recent `TickResult`s retained by the orchestrator
+pub const RECENT_TICK_RESULTS_CAPACITY: usize = 256;
+
 /// Main orchestrator managing simulation state and tick loop
 ///
 /// The Orchestrator owns all simulation state and coordinates:
@@ -768,6 +1757,13 @@ pub struct Orchestrator {
     /// Deterministic RNG
     rng_manager: RngManager,
 
+    /// Per-agent arrival RNG streams
+    ///
+    /// Derived from the base seed and the agent ID (see
+    /// `RngManager::derive_stream`), so an agent's arrival sequence does not
+    /// depend on which other agents exist.
+    arrival_rngs: HashMap<String, RngManager>,
+
     /// Per-agent policy executors
     policies: HashMap<String, Box<dyn CashManagerPolicy>>,
 
@@ -777,6 +1773,17 @@ pub struct Orchestrator {
     /// Cost calculation rates
     cost_rates: CostRates,
 
+    /// Per-agent cost rates from `AgentConfig::cost_rate_overrides`
+    ///
+    /// Merged onto `cost_rates` at lookup; agents without an entry use
+    /// `cost_rates` unchanged.
+    agent_cost_rates: HashMap<String, CostRateOverrides>,
+
+    /// Pending scripted arrivals by tick, as (sender, arrival)
+    ///
+    /// Bucketed at construction so each tick takes only its own entries.
+    scripted_arrivals: BTreeMap<usize, Vec<(String, ScriptedArrival)>>,
+
     /// LSM configuration
     lsm_config: LsmConfig,
 
@@ -789,6 +1796,12 @@ pub struct Orchestrator {
     /// Transaction IDs to attempt settlement this tick
     pending_settlements: Vec<String>,
 
+    /// Transactions a policy released with a future `target_tick`
+    /// Key: release tick, value: transaction IDs in scheduling order.
+    /// Scheduled transactions stay in Queue 1 but are withheld from policy
+    /// re-evaluation until their release tick.
+    scheduled_releases: BTreeMap<usize, Vec<String>>,
+
     /// Counter for generating unique transaction IDs
     #[allow(dead_code)] // Reserved for future use
     next_tx_id: usize,
@@ -803,6 +1816,36 @@ pub struct Orchestrator {
 
     /// Scenario event handler for scheduled events
     scenario_event_handler: Option<crate::events::ScenarioEventHandler>,
+
+    /// Running total of external liquidity injected by scenario events (cents)
+    ///
+    /// Injections break zero-sum conservation, so checkpoint validation
+    /// subtracts this from the system balance before checking it.
+    injected_liquidity: i64,
+
+    /// Optional per-tick observer (read-only, not part of checkpoints)
+    on_tick: Option<TickObserver>,
+
+    /// Ring buffer of the most recent tick results (oldest first)
+    recent_tick_results: VecDeque<TickResult>,
+
+    /// Recorded payment decision paths keyed by (tick, tx_id)
+    ///
+    /// Only populated when `record_decision_paths` is enabled. Not part of
+    /// checkpoints.
+    decision_paths: HashMap<(usize, String), DecisionPath>,
+
+    /// End-of-tick balance of each agent, one entry per executed tick
+    ///
+    /// Only populated when `record_balance_series` is enabled. Not part of
+    /// checkpoints.
+    balance_series: HashMap<String, Vec<i64>>,
+
+    /// Settled `(value, count)` across all agents, one entry per executed tick
+    ///
+    /// Only populated when `record_throughput_series` is enabled. Not part
+    /// of checkpoints.
+    throughput_series: Vec<(i64, usize)>,
 }
 
 /// Performance timing data for a single tick
@@ -918,6 +1961,11 @@ enum SettlementOutcome {
     Settled,
     /// Transaction queued (insufficient liquidity)
     Queued,
+    /// Transaction returned to Queue 1 (insufficient liquidity, Queue 2 full)
+    Rejected {
+        queue_size: usize,
+        capacity: usize,
+    },
 }
 
 impl Orchestrator {
@@ -959,6 +2007,7 @@ impl Orchestrator {
     ///             limits: None,
     ///             liquidity_pool: None,
     ///             liquidity_allocation_fraction: None,
+    ///             ..Default::default()
     ///         },
     ///     ],
     ///     cost_rates: Default::default(),
@@ -971,6 +2020,7 @@ impl Orchestrator {
     ///     entry_disposition_offsetting: false,
     ///     deferred_crediting: false,
     ///     deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+    ///     ..Default::default()
     /// };
     ///
     /// let orchestrator = Orchestrator::new(config).unwrap();
@@ -979,6 +2029,26 @@ impl Orchestrator {
         // Validate configuration
         Self::validate_config(&config)?;
 
+        // Initialize RNG
+        let mut rng_manager = RngManager::new(config.rng_seed);
+        let arrival_rngs = derive_arrival_rngs(&config);
+
+        // Draw randomized opening balances first, in sorted agent ID order
+        let mut randomized: Vec<(&str, &AmountDistribution)> = config
+            .agent_configs
+            .iter()
+            .filter_map(|ac| {
+                ac.opening_balance_distribution
+                    .as_ref()
+                    .map(|distribution| (ac.id.as_str(), distribution))
+            })
+            .collect();
+        randomized.sort_by_key(|(id, _)| *id);
+        let sampled_balances: HashMap<&str, i64> = randomized
+            .into_iter()
+            .map(|(id, distribution)| (id, distribution.sample(&mut rng_manager)))
+            .collect();
+
         // Initialize agents
         let agents: Vec<Agent> = config
             .agent_configs
@@ -993,7 +2063,11 @@ impl Orchestrator {
                 } else {
                     0
                 };
-                let initial_balance = ac.opening_balance + allocated_liquidity;
+                let opening_balance = sampled_balances
+                    .get(ac.id.as_str())
+                    .copied()
+                    .unwrap_or(ac.opening_balance);
+                let initial_balance = opening_balance + allocated_liquidity;
 
                 let mut agent = Agent::new(ac.id.clone(), initial_balance);
 
@@ -1014,6 +2088,11 @@ impl Orchestrator {
                 if let Some(max_cap) = ac.max_collateral_capacity {
                     agent.set_max_collateral_capacity(max_cap);
                 }
+                // Set collateral classes if specified (per-class haircuts)
+                if let Some(classes) = &ac.collateral_classes {
+                    agent.set_collateral_classes(classes.clone());
+                }
+                agent.set_reserve_requirement(ac.reserve_requirement);
                 // Set payment limits if specified (Phase 1: TARGET2 LSM)
                 if let Some(limits) = &ac.limits {
                     agent.set_bilateral_limits(limits.bilateral_limits.clone());
@@ -1023,32 +2102,36 @@ impl Orchestrator {
             })
             .collect();
 
-        let state = SimulationState::new(agents);
+        let mut state = SimulationState::new(agents);
+        state.set_rtgs_queue_capacity(config.rtgs_queue_capacity);
+        state.set_flow_window(FlowWindow::new(config.flow_window_ticks));
 
         // Initialize time manager
         let time_manager = TimeManager::new(config.ticks_per_day);
 
-        // Initialize RNG
-        let rng_manager = RngManager::new(config.rng_seed);
-
         // Initialize policies
         // All policies now use JSON-based TreePolicy loaded via factory
         let mut policies: HashMap<String, Box<dyn CashManagerPolicy>> = HashMap::new();
         for agent_config in &config.agent_configs {
-            let tree_policy =
+            let mut tree_policy =
                 crate::policy::tree::create_policy(&agent_config.policy).map_err(|e| {
                     SimulationError::InvalidConfig(format!(
                         "Failed to create JSON policy for agent {}: {}",
                         agent_config.id, e
                     ))
                 })?;
+            tree_policy.set_record_decision_paths(config.record_decision_paths);
             policies.insert(agent_config.id.clone(), Box::new(tree_policy));
         }
 
         // Initialize arrival generator (if any agents have arrival configs or band configs)
         let mut arrival_configs_map = HashMap::new();
         let mut band_configs_map = HashMap::new();
-        for agent_config in &config.agent_configs {
+        for agent_config in config
+            .agent_configs
+            .iter()
+            .filter(|ac| ac.scripted_arrivals.is_none())
+        {
             if let Some(arrival_cfg) = &agent_config.arrival_config {
                 arrival_configs_map.insert(agent_config.id.clone(), arrival_cfg.clone());
             }
@@ -1075,7 +2158,11 @@ impl Orchestrator {
                 episode_end_tick,
                 config.ticks_per_day,
                 config.deadline_cap_at_eod,
-            ))
+            )
+            .with_max_arrivals_per_tick(config.max_arrivals_per_agent_per_tick)
+            .with_agent_groups(Self::agent_groups(&config))
+            .with_time_scale(config.time_scale)
+            .with_rng_seed(config.rng_seed))
         } else {
             None
         };
@@ -1090,7 +2177,8 @@ impl Orchestrator {
         let mut current_day_metrics = HashMap::new();
         for agent_config in &config.agent_configs {
             let agent = state.get_agent(&agent_config.id).unwrap();
-            let metrics = DailyMetrics::new(agent_config.id.clone(), 0, agent);
+            let in_warmup = config.warmup_ticks > 0;
+            let metrics = DailyMetrics::new(agent_config.id.clone(), 0, agent, in_warmup);
             current_day_metrics.insert(agent_config.id.clone(), metrics);
         }
 
@@ -1102,6 +2190,8 @@ impl Orchestrator {
 
         // Clone values we need before moving config
         let cost_rates = config.cost_rates.clone();
+        let agent_cost_rates = Self::agent_cost_rates(&config);
+        let scripted_arrivals = Self::scripted_arrivals(&config);
         let lsm_config = config.lsm_config.clone();
 
         Ok(Self {
@@ -1109,20 +2199,122 @@ impl Orchestrator {
             state,
             time_manager,
             rng_manager,
+            arrival_rngs,
             policies,
             arrival_generator,
             cost_rates,
+            agent_cost_rates,
+            scripted_arrivals,
             lsm_config,
             accumulated_costs,
             event_log: EventLog::new(),
             pending_settlements: Vec::new(),
+            scheduled_releases: BTreeMap::new(),
             next_tx_id: 1,
             current_day_metrics,
             historical_metrics: HashMap::new(),
             scenario_event_handler,
+            injected_liquidity: 0,
+            on_tick: None,
+            recent_tick_results: VecDeque::with_capacity(RECENT_TICK_RESULTS_CAPACITY),
+            decision_paths: HashMap::new(),
+            balance_series: HashMap::new(),
+            throughput_series: Vec::new(),
         })
     }
 
+    /// Group membership of agents that declare an `agent_group`
+    fn agent_groups(config: &OrchestratorConfig) -> HashMap<String, String> {
+        config
+            .agent_configs
+            .iter()
+            .filter_map(|ac| ac.agent_group.clone().map(|group| (ac.id.clone(), group)))
+            .collect()
+    }
+
+    /// Per-agent cost rate overrides, keyed by agent ID
+    fn agent_cost_rates(config: &OrchestratorConfig) -> HashMap<String, CostRateOverrides> {
+        config
+            .agent_configs
+            .iter()
+            .filter_map(|ac| ac.cost_rate_overrides.clone().map(|rates| (ac.id.clone(), rates)))
+            .collect()
+    }
+
+    /// Scripted arrivals bucketed by tick (agents in config order, then
+    /// script order within each tick)
+    fn scripted_arrivals(
+        config: &OrchestratorConfig,
+    ) -> BTreeMap<usize, Vec<(String, ScriptedArrival)>> {
+        let mut by_tick: BTreeMap<usize, Vec<(String, ScriptedArrival)>> = BTreeMap::new();
+        for ac in &config.agent_configs {
+            for arrival in ac.scripted_arrivals.iter().flatten() {
+                by_tick
+                    .entry(arrival.tick)
+                    .or_default()
+                    .push((ac.id.clone(), arrival.clone()));
+            }
+        }
+        by_tick
+    }
+
+    /// Validate cost rate parameters (global rates or an agent's overrides)
+    fn validate_cost_rates(rates: &CostRates) -> Result<(), SimulationError> {
+        let exponent = rates.split_friction_exponent;
+        if !exponent.is_finite() || exponent < 0.0 {
+            return Err(SimulationError::InvalidConfig(format!(
+                "split_friction_exponent must be finite and non-negative, got {}",
+                exponent
+            )));
+        }
+
+        if rates.deadline_penalty_per_tick_late < 0 {
+            return Err(SimulationError::InvalidConfig(format!(
+                "deadline_penalty_per_tick_late must be non-negative, got {}",
+                rates.deadline_penalty_per_tick_late
+            )));
+        }
+
+        if rates.collateral_post_fee < 0 {
+            return Err(SimulationError::InvalidConfig(format!(
+                "collateral_post_fee must be non-negative, got {}",
+                rates.collateral_post_fee
+            )));
+        }
+
+        // Validate liquidity_cost_tiers: ascending thresholds, sane rates
+        if let Some(tiers) = &rates.liquidity_cost_tiers {
+            if tiers.is_empty() {
+                return Err(SimulationError::InvalidConfig(
+                    "liquidity_cost_tiers must contain at least one tier".to_string(),
+                ));
+            }
+            for (i, (threshold, rate)) in tiers.iter().enumerate() {
+                if *threshold < 0 {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "liquidity_cost_tiers[{}]: threshold must be non-negative, got {}",
+                        i, threshold
+                    )));
+                }
+                if !rate.is_finite() || *rate < 0.0 {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "liquidity_cost_tiers[{}]: rate must be finite and non-negative, got {}",
+                        i, rate
+                    )));
+                }
+                if i > 0 && *threshold <= tiers[i - 1].0 {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "liquidity_cost_tiers thresholds must be strictly increasing ({} follows {})",
+                        threshold,
+                        tiers[i - 1].0
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     /// Validate configuration
     fn validate_config(config: &OrchestratorConfig) -> Result<(), SimulationError> {
         if config.ticks_per_day == 0 {
@@ -1143,6 +2335,123 @@ impl Orchestrator {
             ));
         }
 
+        if config.entry_offset_min_amount < 0 {
+            return Err(SimulationError::InvalidConfig(format!(
+                "entry_offset_min_amount must be non-negative, got {}",
+                config.entry_offset_min_amount
+            )));
+        }
+
+        if !PriorityEscalationConfig::CURVES.contains(&config.priority_escalation.curve.as_str()) {
+            return Err(SimulationError::InvalidConfig(format!(
+                "Unknown priority_escalation curve '{}'. Must be one of: {}",
+                config.priority_escalation.curve,
+                PriorityEscalationConfig::CURVES.join(", ")
+            )));
+        }
+
+        if config.rtgs_queue_capacity == Some(0) {
+            return Err(SimulationError::InvalidConfig(
+                "rtgs_queue_capacity must be > 0 (omit it for an unbounded queue)".to_string(),
+            ));
+        }
+
+        if config.max_arrivals_per_agent_per_tick == Some(0) {
+            return Err(SimulationError::InvalidConfig(
+                "max_arrivals_per_agent_per_tick must be > 0 (omit it for no cap)".to_string(),
+            ));
+        }
+
+        if !config.time_scale.is_finite() || config.time_scale <= 0.0 {
+            return Err(SimulationError::InvalidConfig(format!(
+                "time_scale must be finite and > 0, got {}",
+                config.time_scale
+            )));
+        }
+
+        if config.max_settlement_retries == Some(0) {
+            return Err(SimulationError::InvalidConfig(
+                "max_settlement_retries must be > 0 (omit it to retry forever)".to_string(),
+            ));
+        }
+
+        if config.flow_window_ticks == 0 {
+            return Err(SimulationError::InvalidConfig(
+                "flow_window_ticks must be > 0".to_string(),
+            ));
+        }
+
+        if config.lsm_config.lsm_interval_ticks == 0 {
+            return Err(SimulationError::InvalidConfig(
+                "lsm_interval_ticks must be > 0".to_string(),
+            ));
+        }
+
+        Self::validate_cost_rates(&config.cost_rates)?;
+
+        // Validate scenario events that carry amounts
+        for scheduled in config.scenario_events.iter().flatten() {
+            if scheduled.schedule.interval() == Some(0) {
+                return Err(SimulationError::InvalidConfig(format!(
+                    "Scenario event {}: schedule interval must be positive",
+                    scheduled.event.type_name()
+                )));
+            }
+            if let crate::events::ScenarioEvent::LiquidityInjection { agent, amount } =
+                &scheduled.event
+            {
+                if *amount < 0 {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "LiquidityInjection for {}: amount must be non-negative, got {}",
+                        agent, amount
+                    )));
+                }
+            }
+            if let (
+                crate::events::ScenarioEvent::AgentSuspend {
+                    agent,
+                    resume_tick: Some(resume_tick),
+                },
+                crate::events::EventSchedule::OneTime { tick },
+            ) = (&scheduled.event, &scheduled.schedule)
+            {
+                if resume_tick <= tick {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "AgentSuspend for {}: resume_tick {} must be after tick {}",
+                        agent, resume_tick, tick
+                    )));
+                }
+            }
+            if let crate::events::ScenarioEvent::CostRateChange { field, new_value } =
+                &scheduled.event
+            {
+                CostRates::validate_rate_change(field, *new_value)
+                    .map_err(|e| SimulationError::InvalidConfig(format!("CostRateChange: {}", e)))?;
+            }
+            if let crate::events::ScenarioEvent::ReallocateLiquidity { agent, new_fraction } =
+                &scheduled.event
+            {
+                Self::reallocation_pool(config, agent, *new_fraction)?;
+            }
+            if let crate::events::ScenarioEvent::ArrivalBurst {
+                agent,
+                amount_distribution,
+                ..
+            } = &scheduled.event
+            {
+                amount_distribution.validate().map_err(|e| {
+                    SimulationError::InvalidConfig(format!("ArrivalBurst for {}: {}", agent, e))
+                })?;
+            }
+        }
+
+        // All configured agent IDs (scripted arrivals may target any of them)
+        let agent_ids: std::collections::HashSet<&str> = config
+            .agent_configs
+            .iter()
+            .map(|ac| ac.id.as_str())
+            .collect();
+
         // Check for duplicate agent IDs
         let mut ids = std::collections::HashSet::new();
         for agent_config in &config.agent_configs {
@@ -1180,48 +2489,248 @@ impl Orchestrator {
                     agent_config.id
                 )));
             }
-        }
-
-        Ok(())
-    }
 
-    /// Cap a deadline according to config settings.
-    ///
-    /// This applies the same capping logic used by ArrivalGenerator:
-    /// 1. Cap at episode end (prevent impossible deadlines)
-    /// 2. If deadline_cap_at_eod enabled, also cap at current day's end
-    ///
-    /// # Arguments
-    /// * `arrival_tick` - The tick when the transaction arrives
-    /// * `raw_deadline` - The uncapped deadline tick
-    ///
-    /// # Returns
-    /// The capped deadline tick
-    fn cap_deadline(&self, arrival_tick: usize, raw_deadline: usize) -> usize {
-        // Cap at episode end (first tick after simulation ends)
-        let episode_end_tick = self.config.num_days * self.config.ticks_per_day;
-        let episode_capped = raw_deadline.min(episode_end_tick);
+            // Validate cost rate overrides
+            if let Some(overrides) = &agent_config.cost_rate_overrides {
+                Self::validate_cost_rates(&overrides.apply_to(&config.cost_rates)).map_err(|e| match e {
+                    SimulationError::InvalidConfig(msg) => SimulationError::InvalidConfig(format!(
+                        "Agent {}: cost_rate_overrides: {}",
+                        agent_config.id, msg
+                    )),
+                    other => other,
+                })?;
+            }
 
-        // If deadline_cap_at_eod enabled, also cap at current day's end
-        // For ticks_per_day=10: day 0 has ticks 0-9, so last tick of day 0 is 9
-        let capped = if self.config.deadline_cap_at_eod {
-            let current_day = arrival_tick / self.config.ticks_per_day;
-            // day_end_tick is the last tick OF the current day (not first tick of next day)
-            let day_end_tick = (current_day + 1) * self.config.ticks_per_day - 1;
-            episode_capped.min(day_end_tick)
-        } else {
-            episode_capped
-        };
+            // Validate scripted arrivals
+            for arrival in agent_config.scripted_arrivals.iter().flatten() {
+                if !agent_ids.contains(arrival.receiver.as_str()) {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "Agent {}: scripted arrival receiver {} does not exist",
+                        agent_config.id, arrival.receiver
+                    )));
+                }
+                if arrival.receiver == agent_config.id {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "Agent {}: scripted arrival cannot be sent to itself",
+                        agent_config.id
+                    )));
+                }
+                if arrival.amount <= 0 {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "Agent {}: scripted arrival amount must be positive, got {}",
+                        agent_config.id, arrival.amount
+                    )));
+                }
+                if arrival.deadline_offset == 0 {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "Agent {}: scripted arrival deadline_offset must be positive",
+                        agent_config.id
+                    )));
+                }
+                if arrival.priority > 10 {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "Agent {}: scripted arrival priority must be in 0..=10, got {}",
+                        agent_config.id, arrival.priority
+                    )));
+                }
+                if arrival.tick >= config.num_days * config.ticks_per_day {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "Agent {}: scripted arrival tick {} is beyond the episode ({} ticks)",
+                        agent_config.id,
+                        arrival.tick,
+                        config.num_days * config.ticks_per_day
+                    )));
+                }
+            }
 
-        // Ensure deadline is always at least one tick after arrival
-        // (required by Transaction invariant: deadline > arrival)
-        // This handles edge cases like transactions arriving at the last tick of a day
-        capped.max(arrival_tick + 1)
-    }
+            // Validate arrival priority distribution
+            if let Some(arrival_config) = &agent_config.arrival_config {
+                arrival_config.validate().map_err(|msg| {
+                    SimulationError::InvalidConfig(format!("Agent {}: {}", agent_config.id, msg))
+                })?;
+            }
 
-    // ========================================================================
-    // Accessors
-    // ========================================================================
+            // Validate amount distribution parameters
+            let band_distributions = agent_config.arrival_bands.iter().flat_map(|bands| {
+                [&bands.urgent, &bands.normal, &bands.low]
+                    .into_iter()
+                    .flatten()
+                    .map(|band| &band.amount_distribution)
+            });
+            for distribution in agent_config
+                .arrival_config
+                .iter()
+                .map(|ac| &ac.amount_distribution)
+                .chain(band_distributions)
+                .chain(agent_config.opening_balance_distribution.iter())
+            {
+                distribution.validate().map_err(|msg| {
+                    SimulationError::InvalidConfig(format!("Agent {}: {}", agent_config.id, msg))
+                })?;
+            }
+
+            // Validate negative binomial count model parameters
+            if let Some(ArrivalCountModel::NegativeBinomial { r, p }) = agent_config
+                .arrival_config
+                .as_ref()
+                .map(|ac| &ac.arrival_count_model)
+            {
+                if !r.is_finite() || *r <= 0.0 {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "Agent {}: NegativeBinomial r must be > 0.0, got {}",
+                        agent_config.id, r
+                    )));
+                }
+                if !(*p > 0.0 && *p < 1.0) {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "Agent {}: NegativeBinomial p must be in (0.0, 1.0), got {}",
+                        agent_config.id, p
+                    )));
+                }
+            }
+
+            // Validate amount granularity
+            if let Some(granularity) = agent_config
+                .arrival_config
+                .as_ref()
+                .and_then(|ac| ac.amount_granularity)
+            {
+                if granularity <= 0 {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "Agent {}: amount_granularity must be positive, got {}",
+                        agent_config.id, granularity
+                    )));
+                }
+            }
+
+            // Validate absolute deadline bounds
+            if let Some(DeadlineMode::AbsoluteTick { min, max }) = agent_config
+                .arrival_config
+                .as_ref()
+                .map(|ac| &ac.deadline_mode)
+            {
+                if min > max {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "Agent {}: AbsoluteTick deadline min ({}) must be <= max ({})",
+                        agent_config.id, min, max
+                    )));
+                }
+            }
+
+            // Validate counterparty group weights
+            if let Some(arrival_config) = &agent_config.arrival_config {
+                if arrival_config
+                    .counterparty_group_weights
+                    .values()
+                    .any(|w| !w.is_finite() || *w < 0.0)
+                {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "Agent {}: counterparty_group_weights must be finite and non-negative",
+                        agent_config.id
+                    )));
+                }
+
+                // Ungrouped agents could never be drawn as receivers
+                if !arrival_config.counterparty_group_weights.is_empty() {
+                    if let Some(ungrouped) = config
+                        .agent_configs
+                        .iter()
+                        .find(|ac| ac.id != agent_config.id && ac.agent_group.is_none())
+                    {
+                        return Err(SimulationError::InvalidConfig(format!(
+                            "Agent {}: counterparty_group_weights requires every agent to have an agent_group, but {} has none",
+                            agent_config.id, ungrouped.id
+                        )));
+                    }
+                }
+            }
+
+            if agent_config.reserve_requirement < 0 {
+                return Err(SimulationError::InvalidConfig(format!(
+                    "Agent {}: reserve_requirement must be non-negative, got {}",
+                    agent_config.id, agent_config.reserve_requirement
+                )));
+            }
+
+            // Validate collateral classes
+            for (name, amount, haircut) in agent_config.collateral_classes.iter().flatten() {
+                if *amount < 0 {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "Agent {}: collateral class {} amount must be non-negative, got {}",
+                        agent_config.id, name, amount
+                    )));
+                }
+                if !(0.0..=1.0).contains(haircut) {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "Agent {}: collateral class {} haircut must be in [0.0, 1.0], got {}",
+                        agent_config.id, name, haircut
+                    )));
+                }
+            }
+
+            // Validate intraday_profile matches the day length
+            if let Some(profile) = agent_config
+                .arrival_config
+                .as_ref()
+                .and_then(|ac| ac.intraday_profile.as_ref())
+            {
+                if profile.len() != config.ticks_per_day {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "Agent {}: intraday_profile length ({}) must equal ticks_per_day ({})",
+                        agent_config.id,
+                        profile.len(),
+                        config.ticks_per_day
+                    )));
+                }
+                if profile.iter().any(|m| !m.is_finite() || *m < 0.0) {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "Agent {}: intraday_profile multipliers must be finite and non-negative",
+                        agent_config.id
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Cap a deadline according to config settings.
+    ///
+    /// This applies the same capping logic used by ArrivalGenerator:
+    /// 1. Cap at episode end (prevent impossible deadlines)
+    /// 2. If deadline_cap_at_eod enabled, also cap at current day's end
+    ///
+    /// # Arguments
+    /// * `arrival_tick` - The tick when the transaction arrives
+    /// * `raw_deadline` - The uncapped deadline tick
+    ///
+    /// # Returns
+    /// The capped deadline tick
+    fn cap_deadline(&self, arrival_tick: usize, raw_deadline: usize) -> usize {
+        // Cap at episode end (first tick after simulation ends)
+        let episode_end_tick = self.config.num_days * self.config.ticks_per_day;
+        let episode_capped = raw_deadline.min(episode_end_tick);
+
+        // If deadline_cap_at_eod enabled, also cap at current day's end
+        // For ticks_per_day=10: day 0 has ticks 0-9, so last tick of day 0 is 9
+        let capped = if self.config.deadline_cap_at_eod {
+            let current_day = arrival_tick / self.config.ticks_per_day;
+            // day_end_tick is the last tick OF the current day (not first tick of next day)
+            let day_end_tick = (current_day + 1) * self.config.ticks_per_day - 1;
+            episode_capped.min(day_end_tick)
+        } else {
+            episode_capped
+        };
+
+        // Ensure deadline is always at least one tick after arrival
+        // (required by Transaction invariant: deadline > arrival)
+        // This handles edge cases like transactions arriving at the last tick of a day
+        capped.max(arrival_tick + 1)
+    }
+
+    // ========================================================================
+    // Accessors
+    // ========================================================================
 
     /// Get current tick number
     pub fn current_tick(&self) -> usize {
@@ -1248,11 +2757,58 @@ impl Orchestrator {
         &mut self.state
     }
 
+    /// Get reference to the RNG manager
+    ///
+    /// Read-only: cloning it lets callers preview upcoming draws without
+    /// perturbing the simulation's random sequence.
+    pub fn rng_manager(&self) -> &RngManager {
+        &self.rng_manager
+    }
+
+    /// Get reference to an agent's arrival RNG stream
+    ///
+    /// Arrivals draw from these per-agent streams rather than from
+    /// `rng_manager()`. Returns None for unknown agents.
+    pub fn arrival_rng(&self, agent_id: &str) -> Option<&RngManager> {
+        self.arrival_rngs.get(agent_id)
+    }
+
+    /// Get the tick at which a transaction is scheduled for release
+    ///
+    /// Returns None if the transaction has no pending scheduled release.
+    pub fn scheduled_release_tick(&self, tx_id: &str) -> Option<usize> {
+        self.scheduled_releases
+            .iter()
+            .find(|(_, tx_ids)| tx_ids.iter().any(|id| id == tx_id))
+            .map(|(tick, _)| *tick)
+    }
+
     /// Get reference to cost rates configuration
     pub fn cost_rates(&self) -> &CostRates {
         &self.cost_rates
     }
 
+    /// Get the cost rates accrued for an agent
+    ///
+    /// The global rates with the agent's `cost_rate_overrides` applied;
+    /// borrowed unchanged for agents without overrides (and unknown agents).
+    pub fn cost_rates_for_agent(&self, agent_id: &str) -> Cow<'_, CostRates> {
+        Self::merged_cost_rates(&self.cost_rates, &self.agent_cost_rates, agent_id)
+    }
+
+    /// `cost_rates_for_agent` over borrowed fields, for use while a policy
+    /// is mutably borrowed
+    fn merged_cost_rates<'a>(
+        cost_rates: &'a CostRates,
+        agent_cost_rates: &HashMap<String, CostRateOverrides>,
+        agent_id: &str,
+    ) -> Cow<'a, CostRates> {
+        match agent_cost_rates.get(agent_id) {
+            Some(overrides) => Cow::Owned(overrides.apply_to(cost_rates)),
+            None => Cow::Borrowed(cost_rates),
+        }
+    }
+
     /// Get total events logged
     pub fn event_count(&self) -> usize {
         self.event_log.len()
@@ -1293,6 +2849,13 @@ impl Orchestrator {
             .and_then(|gen| gen.get_counterparty_weight(agent_id, counterparty))
     }
 
+    /// Get all effective counterparty weights for an agent
+    pub fn get_counterparty_weights(&self, agent_id: &str) -> Option<BTreeMap<String, f64>> {
+        self.arrival_generator
+            .as_ref()
+            .and_then(|gen| gen.get_counterparty_weights(agent_id))
+    }
+
     /// Get deadline range for an agent
     pub fn get_deadline_range(&self, agent_id: &str) -> Option<(usize, usize)> {
         self.arrival_generator
@@ -1463,10 +3026,11 @@ impl Orchestrator {
                 }
 
                 if let Some(generator) = &mut self.arrival_generator {
-                    generator.set_counterparty_weight(agent, counterparty, *new_weight);
-
-                    // TODO: Implement auto_balance_others logic
-                    // For now, just set the weight directly
+                    if *auto_balance_others {
+                        generator.set_counterparty_weight_balanced(agent, counterparty, *new_weight);
+                    } else {
+                        generator.set_counterparty_weight(agent, counterparty, *new_weight);
+                    }
 
                     // Log event
                     self.log_event(crate::models::Event::ScenarioEventExecuted {
@@ -1598,6 +3162,7 @@ impl Orchestrator {
                         amount: *amount,
                         sender_balance_before,
                         sender_balance_after,
+                        settlement_mechanism: SettlementMechanism::RtgsImmediate,
                     });
 
                     // Also log as ScenarioEventExecuted for replay identity
@@ -1628,6 +3193,211 @@ impl Orchestrator {
                     });
                 }
             }
+
+            // LiquidityInjection: credit agent from outside the system (no counterparty)
+            ScenarioEvent::LiquidityInjection { agent, amount } => {
+                if *amount < 0 {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "LiquidityInjection amount must be non-negative, got {}",
+                        amount
+                    )));
+                }
+
+                let target = self
+                    .state
+                    .get_agent_mut(agent)
+                    .ok_or_else(|| SimulationError::AgentNotFound(agent.clone()))?;
+                target.adjust_balance(*amount);
+                let balance_after = target.balance();
+
+                self.injected_liquidity += amount;
+
+                self.log_event(crate::models::Event::LiquidityInjection {
+                    tick,
+                    agent_id: agent.clone(),
+                    amount: *amount,
+                    balance_after,
+                });
+
+                // Also log as ScenarioEventExecuted for replay identity
+                self.log_event(crate::models::Event::ScenarioEventExecuted {
+                    tick,
+                    event_type: "liquidity_injection".to_string(),
+                    details: json!({
+                        "agent": agent,
+                        "amount": amount,
+                    }),
+                });
+            }
+
+            // AgentSuspend: skip the agent's policies until resumed
+            ScenarioEvent::AgentSuspend { agent, resume_tick } => {
+                if let Some(resume) = resume_tick {
+                    if *resume <= tick {
+                        return Err(SimulationError::InvalidConfig(format!(
+                            "AgentSuspend for {}: resume_tick {} must be after tick {}",
+                            agent, resume, tick
+                        )));
+                    }
+                }
+
+                self.state
+                    .get_agent_mut(agent)
+                    .ok_or_else(|| SimulationError::AgentNotFound(agent.clone()))?
+                    .suspend(*resume_tick);
+
+                self.log_event(crate::models::Event::AgentSuspended {
+                    tick,
+                    agent_id: agent.clone(),
+                    resume_tick: *resume_tick,
+                });
+
+                // Also log as ScenarioEventExecuted for replay identity
+                self.log_event(crate::models::Event::ScenarioEventExecuted {
+                    tick,
+                    event_type: "agent_suspend".to_string(),
+                    details: json!({
+                        "agent": agent,
+                        "resume_tick": resume_tick,
+                    }),
+                });
+            }
+
+            // AgentResume: lift a suspension (no-op if not suspended)
+            ScenarioEvent::AgentResume { agent } => {
+                let target = self
+                    .state
+                    .get_agent_mut(agent)
+                    .ok_or_else(|| SimulationError::AgentNotFound(agent.clone()))?;
+
+                if target.is_suspended() {
+                    target.resume();
+                    self.log_event(crate::models::Event::AgentResumed {
+                        tick,
+                        agent_id: agent.clone(),
+                    });
+                }
+
+                // Also log as ScenarioEventExecuted for replay identity
+                self.log_event(crate::models::Event::ScenarioEventExecuted {
+                    tick,
+                    event_type: "agent_resume".to_string(),
+                    details: json!({
+                        "agent": agent,
+                    }),
+                });
+            }
+
+            // ReallocateLiquidity: move the agent's pool allocation to a new fraction
+            ScenarioEvent::ReallocateLiquidity { agent, new_fraction } => {
+                let pool = Self::reallocation_pool(&self.config, agent, *new_fraction)?;
+                let new_allocation = (pool as f64 * new_fraction).floor() as i64;
+
+                let target = self
+                    .state
+                    .get_agent_mut(agent)
+                    .ok_or_else(|| SimulationError::AgentNotFound(agent.clone()))?;
+                let old_allocation = target.allocated_liquidity();
+                target.adjust_balance(new_allocation - old_allocation);
+                target.set_allocated_liquidity(new_allocation);
+                let balance_after = target.balance();
+
+                self.log_event(crate::models::Event::LiquidityReallocated {
+                    tick,
+                    agent_id: agent.clone(),
+                    new_fraction: *new_fraction,
+                    old_allocation,
+                    new_allocation,
+                    balance_after,
+                });
+
+                // Also log as ScenarioEventExecuted for replay identity
+                self.log_event(crate::models::Event::ScenarioEventExecuted {
+                    tick,
+                    event_type: "reallocate_liquidity".to_string(),
+                    details: json!({
+                        "agent": agent,
+                        "new_fraction": new_fraction,
+                    }),
+                });
+            }
+
+            // ArrivalBurst: sample `count` payments and submit each through the
+            // normal arrival path
+            ScenarioEvent::ArrivalBurst {
+                agent,
+                count,
+                amount_distribution,
+                deadline_offset,
+                priority,
+            } => {
+                let receivers: Vec<String> = self
+                    .state
+                    .agents()
+                    .keys()
+                    .filter(|id| id.as_str() != agent)
+                    .cloned()
+                    .collect();
+                if receivers.is_empty() {
+                    return Err(SimulationError::InvalidConfig(format!(
+                        "ArrivalBurst for {}: no other agents to pay",
+                        agent
+                    )));
+                }
+
+                let deadline_tick = tick + deadline_offset;
+                let mut tx_ids = Vec::with_capacity(*count);
+                for _ in 0..*count {
+                    let idx = self.rng_manager.range(0, receivers.len() as i64) as usize;
+                    let amount = amount_distribution.sample(&mut self.rng_manager);
+                    tx_ids.push(self.submit_transaction(
+                        agent,
+                        &receivers[idx],
+                        amount,
+                        deadline_tick,
+                        *priority,
+                        false,
+                    )?);
+                }
+
+                // Also log as scenario event for replay identity
+                self.log_event(crate::models::Event::ScenarioEventExecuted {
+                    tick,
+                    event_type: "arrival_burst".to_string(),
+                    details: json!({
+                        "agent": agent,
+                        "count": count,
+                        "deadline": deadline_tick,
+                        "priority": priority,
+                        "tx_ids": tx_ids,
+                    }),
+                });
+            }
+
+            // CostRateChange: external, trusted update of the live cost rates
+            ScenarioEvent::CostRateChange { field, new_value } => {
+                let old_value = self
+                    .cost_rates
+                    .set_rate(field, *new_value)
+                    .map_err(|e| SimulationError::InvalidConfig(format!("CostRateChange: {}", e)))?;
+
+                self.log_event(crate::models::Event::CostRateChanged {
+                    tick,
+                    field: field.clone(),
+                    old_value,
+                    new_value: *new_value,
+                });
+
+                // Also log as ScenarioEventExecuted for replay identity
+                self.log_event(crate::models::Event::ScenarioEventExecuted {
+                    tick,
+                    event_type: "cost_rate_change".to_string(),
+                    details: json!({
+                        "field": field,
+                        "new_value": new_value,
+                    }),
+                });
+            }
         }
 
         Ok(())
@@ -1690,15 +3460,7 @@ impl Orchestrator {
     /// Used by Phase 8 REST API endpoints for monitoring.
     pub fn calculate_system_metrics(&self) -> SystemMetrics {
         // Step 1: Build parent → children mapping
-        let mut children_map: HashMap<String, Vec<String>> = HashMap::new();
-        for tx in self.state.transactions().values() {
-            if let Some(parent_id) = tx.parent_id() {
-                children_map
-                    .entry(parent_id.to_string())
-                    .or_insert_with(Vec::new)
-                    .push(tx.id().to_string());
-            }
-        }
+        let children_map = self.build_children_map();
 
         // Step 2: Count only original arrivals and check effective settlement
         let mut total_arrivals = 0;
@@ -1706,8 +3468,8 @@ impl Orchestrator {
         let mut delays = Vec::new();
 
         for tx in self.state.transactions().values() {
-            // Only count original transactions (not splits)
-            if tx.parent_id().is_none() {
+            // Only count original transactions (not splits) that arrived after warm-up
+            if tx.parent_id().is_none() && tx.arrival_tick() >= self.config.warmup_ticks {
                 total_arrivals += 1;
 
                 // Check if effectively settled (recursively for splits)
@@ -1751,6 +3513,13 @@ impl Orchestrator {
 
         let queue2_total_size = self.state.rtgs_queue().len();
 
+        // Oldest transaction still waiting in Queue 1
+        let max_queue1_age = self
+            .queue1_ages()
+            .map(|(_, age)| age)
+            .max()
+            .unwrap_or(0);
+
         // Find peak overdraft (most negative balance)
         let peak_overdraft = self
             .state
@@ -1768,6 +3537,15 @@ impl Orchestrator {
             .filter(|agent| agent.balance() < 0)
             .count();
 
+        // Concentration of liquidity usage (credit used per agent)
+        let credit_usage: Vec<i64> = self
+            .state
+            .agents()
+            .values()
+            .map(|agent| agent.credit_used())
+            .collect();
+        let liquidity_usage_gini = gini_coefficient(&credit_usage);
+
         SystemMetrics {
             total_arrivals,
             total_settlements,
@@ -1776,29 +3554,159 @@ impl Orchestrator {
             max_delay_ticks,
             queue1_total_size,
             queue2_total_size,
+            max_queue1_age,
             peak_overdraft,
             agents_in_overdraft,
+            liquidity_usage_gini,
         }
     }
 
-    /// Get detailed transaction counts for debugging
+    /// Histogram of settlement delays for settled original transactions
     ///
-    /// Returns a breakdown of transaction counts to help diagnose
-    /// settlement rate calculation issues.
+    /// Uses the same effective-settlement rule as `calculate_system_metrics`:
+    /// split parents count once, settled when all their children are. Delay
+    /// is the final settlement tick minus the arrival tick (for split parents,
+    /// the last child's settlement tick).
+    ///
+    /// # Arguments
+    ///
+    /// * `bucket_width` - Width of each bucket in ticks
     ///
     /// # Returns
     ///
-    /// Tuple of (total_txs, arrivals, children, settled_arrivals, settled_children)
-    pub fn get_transaction_counts_debug(&self) -> (usize, usize, usize, usize, usize) {
-        let total_txs = self.state.transactions().len();
-        let mut arrivals = 0;
-        let mut children = 0;
-        let mut settled_arrivals = 0;
-        let mut settled_children = 0;
+    /// Contiguous `(bucket_start_ticks, count)` pairs from 0 up to the bucket
+    /// holding the maximum observed delay. Empty if nothing has settled or
+    /// `bucket_width` is 0.
+    pub fn settlement_delay_histogram(&self, bucket_width: usize) -> Vec<(usize, usize)> {
+        if bucket_width == 0 {
+            return Vec::new();
+        }
 
-        for tx in self.state.transactions().values() {
-            if tx.parent_id().is_none() {
-                arrivals += 1;
+        let delays = self.settled_delays();
+
+        let max_delay = match delays.iter().max() {
+            Some(max) => *max,
+            None => return Vec::new(),
+        };
+
+        let mut counts = vec![0usize; max_delay / bucket_width + 1];
+        for delay in delays {
+            counts[delay / bucket_width] += 1;
+        }
+
+        counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| (i * bucket_width, count))
+            .collect()
+    }
+
+    /// Percentiles of settlement delay over settled original transactions
+    ///
+    /// Delays are computed as in `settlement_delay_histogram` and ranked with
+    /// the nearest-rank method: the p-th percentile of n sorted delays is the
+    /// value at rank `ceil(p * n)` (at least 1).
+    ///
+    /// # Arguments
+    ///
+    /// * `ps` - Requested percentiles as fractions; values outside [0, 1]
+    ///   are clamped
+    ///
+    /// # Returns
+    ///
+    /// One `(percentile, delay_ticks)` pair per requested percentile, in
+    /// request order, with the percentile after clamping. Delays are 0 if
+    /// nothing has settled yet.
+    pub fn delay_percentiles(&self, ps: &[f64]) -> Vec<(f64, usize)> {
+        let mut delays = self.settled_delays();
+        delays.sort_unstable();
+
+        ps.iter()
+            .map(|&p| {
+                let p = if p.is_nan() { 0.0 } else { p.clamp(0.0, 1.0) };
+                if delays.is_empty() {
+                    return (p, 0);
+                }
+                let rank = ((p * delays.len() as f64).ceil() as usize).max(1);
+                (p, delays[rank - 1])
+            })
+            .collect()
+    }
+
+    /// Arrival-to-settlement delays of effectively settled original transactions
+    ///
+    /// Transactions arriving during the warm-up period are excluded.
+    fn settled_delays(&self) -> Vec<usize> {
+        let children_map = self.build_children_map();
+        let transactions = self.state.transactions();
+
+        transactions
+            .values()
+            .filter(|tx| tx.parent_id().is_none())
+            .filter(|tx| tx.arrival_tick() >= self.config.warmup_ticks)
+            .filter(|tx| Self::is_effectively_settled(tx.id(), transactions, &children_map))
+            .filter_map(|tx| {
+                Self::effective_settlement_tick(tx.id(), transactions, &children_map)
+                    .map(|tick| tick.saturating_sub(tx.arrival_tick()))
+            })
+            .collect()
+    }
+
+    /// Build parent → children mapping for split transactions
+    fn build_children_map(&self) -> HashMap<String, Vec<String>> {
+        let mut children_map: HashMap<String, Vec<String>> = HashMap::new();
+        for tx in self.state.transactions().values() {
+            if let Some(parent_id) = tx.parent_id() {
+                children_map
+                    .entry(parent_id.to_string())
+                    .or_default()
+                    .push(tx.id().to_string());
+            }
+        }
+        children_map
+    }
+
+    /// Tick at which a transaction became effectively settled
+    ///
+    /// For split parents, the latest settlement tick among all descendants.
+    /// Returns None if the transaction (or any descendant) is not settled.
+    fn effective_settlement_tick(
+        tx_id: &str,
+        transactions: &std::collections::BTreeMap<String, Transaction>,
+        children_map: &HashMap<String, Vec<String>>,
+    ) -> Option<usize> {
+        if let Some(child_ids) = children_map.get(tx_id) {
+            return child_ids
+                .iter()
+                .map(|child_id| Self::effective_settlement_tick(child_id, transactions, children_map))
+                .collect::<Option<Vec<usize>>>()
+                .and_then(|ticks| ticks.into_iter().max());
+        }
+
+        match transactions.get(tx_id)?.status() {
+            crate::models::transaction::TransactionStatus::Settled { tick } => Some(*tick),
+            _ => None,
+        }
+    }
+
+    /// Get detailed transaction counts for debugging
+    ///
+    /// Returns a breakdown of transaction counts to help diagnose
+    /// settlement rate calculation issues.
+    ///
+    /// # Returns
+    ///
+    /// Tuple of (total_txs, arrivals, children, settled_arrivals, settled_children)
+    pub fn get_transaction_counts_debug(&self) -> (usize, usize, usize, usize, usize) {
+        let total_txs = self.state.transactions().len();
+        let mut arrivals = 0;
+        let mut children = 0;
+        let mut settled_arrivals = 0;
+        let mut settled_children = 0;
+
+        for tx in self.state.transactions().values() {
+            if tx.parent_id().is_none() {
+                arrivals += 1;
                 if tx.is_fully_settled() {
                     settled_arrivals += 1;
                 }
@@ -1873,6 +3781,101 @@ impl Orchestrator {
         self.state.rtgs_queue().clone()
     }
 
+    /// Get the net bilateral obligation graph over Queue 2
+    ///
+    /// Read-only snapshot of what the LSM would see if it ran now, for
+    /// visualizing gridlock. See `lsm::build_obligation_graph`.
+    ///
+    /// # Returns
+    ///
+    /// `(sender, receiver, net_amount)` edges sorted by sender then receiver.
+    pub fn current_obligation_graph(&self) -> Vec<(String, String, i64)> {
+        crate::settlement::lsm::build_obligation_graph(&self.state)
+    }
+
+    /// Get Queue 1 transactions that have waited at least `threshold_ticks`
+    ///
+    /// Flags payments starved by higher-priority traffic or held by policy.
+    /// Age is measured from first Queue 1 entry (`Transaction::ticks_in_queue`).
+    ///
+    /// # Returns
+    ///
+    /// Transaction IDs ordered by agent ID, then Queue 1 position.
+    pub fn starved_transactions(&self, threshold_ticks: usize) -> Vec<String> {
+        self.queue1_ages()
+            .filter(|(_, age)| *age >= threshold_ticks)
+            .map(|(tx_id, _)| tx_id.to_string())
+            .collect()
+    }
+
+    /// Get queued transactions that are past their deadline
+    ///
+    /// Covers both Queue 1 and Queue 2. A transaction is overdue once
+    /// `deadline_tick < current_tick`; lateness is `current_tick - deadline_tick`.
+    ///
+    /// # Returns
+    ///
+    /// `(tx_id, ticks_overdue)` pairs, most overdue first. Equal lateness keeps
+    /// queue order: Queue 1 by agent ID and position, then Queue 2.
+    pub fn overdue_transactions(&self) -> Vec<(String, usize)> {
+        let current_tick = self.current_tick();
+        let queued = self
+            .state
+            .agents()
+            .values()
+            .flat_map(|agent| agent.outgoing_queue())
+            .chain(self.state.rtgs_queue());
+
+        let mut overdue: Vec<(String, usize)> = queued
+            .filter_map(|tx_id| self.state.get_transaction(tx_id))
+            .filter(|tx| !tx.is_fully_settled() && tx.deadline_tick() < current_tick)
+            .map(|tx| (tx.id().to_string(), current_tick - tx.deadline_tick()))
+            .collect();
+
+        // Stable sort, so equal lateness keeps queue order
+        overdue.sort_by_key(|&(_, ticks_overdue)| std::cmp::Reverse(ticks_overdue));
+        overdue
+    }
+
+    /// Get scheduled scenario events at or after `from_tick`
+    ///
+    /// Repeating schedules are expanded up to the end of the simulation
+    /// (`num_days * ticks_per_day`). Agent resumes scheduled via
+    /// `AgentSuspend::resume_tick` are not scenario events and are not listed.
+    ///
+    /// # Returns
+    ///
+    /// `(tick, event_type)` pairs sorted by tick, using the snake-case type
+    /// names from the config (e.g. `"direct_transfer"`). Events at the same
+    /// tick are listed in execution order.
+    pub fn pending_scenario_events(&self, from_tick: usize) -> Vec<(usize, String)> {
+        let end_tick = self.config.num_days * self.config.ticks_per_day;
+        self.scenario_event_handler
+            .as_ref()
+            .map(|handler| {
+                handler
+                    .pending_events(from_tick, end_tick)
+                    .into_iter()
+                    .map(|(tick, event)| (tick, event.type_name().to_string()))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    /// Ages of all transactions currently in any agent's Queue 1
+    fn queue1_ages(&self) -> impl Iterator<Item = (&str, usize)> + '_ {
+        let current_tick = self.current_tick();
+        self.state
+            .agents()
+            .values()
+            .flat_map(|agent| agent.outgoing_queue())
+            .filter_map(move |tx_id| {
+                self.state
+                    .get_transaction(tx_id)
+                    .map(|tx| (tx_id.as_str(), tx.ticks_in_queue(current_tick)))
+            })
+    }
+
     /// Get contents of agent's internal queue (Queue 1)
     ///
     /// Returns a vector of transaction IDs currently in the agent's
@@ -1966,6 +3969,58 @@ impl Orchestrator {
         self.event_log.events_at_tick(tick)
     }
 
+    /// Get all events with `start_tick <= tick < end_tick`
+    pub fn get_events_in_range(&self, start_tick: usize, end_tick: usize) -> Vec<&Event> {
+        self.event_log.events_in_range(start_tick, end_tick)
+    }
+
+    /// Get all events of one type (e.g. "RtgsImmediateSettlement")
+    pub fn get_events_of_type(&self, event_type: &str) -> Vec<&Event> {
+        self.event_log.events_of_type(event_type)
+    }
+
+    /// Get one page of the event log, optionally filtered
+    ///
+    /// Keeps events with `tick_from <= tick < tick_to` (a missing bound is
+    /// open) whose type equals `event_type`, if given. Skips the first
+    /// `offset` matches and returns at most `limit` (all if `None`), in log
+    /// order.
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// // Second page of 100 settlements from day 0
+    /// let page = orch.query_events(100, Some(100), Some(0), Some(100), Some("RtgsImmediateSettlement"));
+    /// ```
+    pub fn query_events(
+        &self,
+        offset: usize,
+        limit: Option<usize>,
+        tick_from: Option<usize>,
+        tick_to: Option<usize>,
+        event_type: Option<&str>,
+    ) -> Vec<&Event> {
+        let start = tick_from.unwrap_or(0);
+        let end = tick_to.unwrap_or(usize::MAX);
+
+        self.event_log
+            .events()
+            .iter()
+            .filter(|e| (start..end).contains(&e.tick()))
+            .filter(|e| event_type.is_none_or(|t| e.event_type() == t))
+            .skip(offset)
+            .take(limit.unwrap_or(usize::MAX))
+            .collect()
+    }
+
+    /// Export the full event log as JSON Lines
+    ///
+    /// One JSON object per event, in log order, each carrying a `type` tag
+    /// and a `tick` field. See [`EventLog::to_jsonl`].
+    pub fn export_events_jsonl(&self) -> String {
+        self.event_log.to_jsonl()
+    }
+
     /// Get transaction details by ID
     ///
     /// Returns a reference to a transaction if it exists in the system.
@@ -1991,6 +4046,38 @@ impl Orchestrator {
         self.state.get_transaction(tx_id)
     }
 
+    /// Get all transactions carrying `tag`, ordered by transaction ID
+    ///
+    /// Includes split children, which inherit their parent's tags.
+    pub fn get_transactions_with_tag(&self, tag: &str) -> Vec<&Transaction> {
+        self.state
+            .transactions()
+            .values()
+            .filter(|tx| tx.has_tag(tag))
+            .collect()
+    }
+
+    /// Get the mechanism that settled a transaction
+    ///
+    /// Distinguishes plain RTGS settlement (immediate or from Queue 2) from
+    /// LSM bilateral offsets and multilateral cycles.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(mechanism)` - Transaction is fully settled
+    /// * `None` - Transaction not found, not yet settled, or settled via split children
+    ///
+    /// # Example
+    ///
+    /// ```ignore
+    /// if let Some(mechanism) = orch.get_settlement_mechanism("tx_12345") {
+    ///     println!("Settled via {}", mechanism);
+    /// }
+    /// ```
+    pub fn get_settlement_mechanism(&self, tx_id: &str) -> Option<SettlementMechanism> {
+        self.state.get_transaction(tx_id)?.settlement_mechanism()
+    }
+
     /// Get contents of RTGS queue (Queue 2)
     ///
     /// Returns transaction IDs currently waiting in the central RTGS queue
@@ -2067,6 +4154,63 @@ impl Orchestrator {
         self.state.get_agent(agent_id).map(|a| a.posted_collateral())
     }
 
+    /// Get agent's maximum collateral capacity
+    ///
+    /// The configured `max_collateral_capacity`, or the 10× unsecured cap
+    /// heuristic when none was set.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(capacity)` - Maximum postable collateral in cents
+    /// * `None` - Agent not found
+    pub fn get_agent_max_collateral_capacity(&self, agent_id: &str) -> Option<i64> {
+        self.state.get_agent(agent_id).map(|a| a.max_collateral_capacity())
+    }
+
+    /// Get how much more collateral an agent can post
+    ///
+    /// Maximum collateral capacity less currently posted collateral.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(headroom)` - Remaining collateral capacity in cents
+    /// * `None` - Agent not found
+    pub fn get_agent_remaining_collateral_capacity(&self, agent_id: &str) -> Option<i64> {
+        self.state
+            .get_agent(agent_id)
+            .map(|a| a.remaining_collateral_capacity())
+    }
+
+    /// Get agent's policy state registers (set via `SetState`/`AddState`)
+    ///
+    /// Registers are daily-scoped: they reset to empty at end of day.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(registers)` - Register key → value, sorted by key
+    /// * `None` - Agent not found
+    pub fn get_agent_state_registers(&self, agent_id: &str) -> Option<BTreeMap<String, f64>> {
+        self.state.get_agent(agent_id).map(|a| {
+            a.state_registers()
+                .iter()
+                .map(|(key, value)| (key.clone(), *value))
+                .collect()
+        })
+    }
+
+    /// Get agent's bilateral/multilateral limit utilization for the current day
+    ///
+    /// # Returns
+    ///
+    /// * `Some(utilization)` - See `LimitUtilization`
+    /// * `None` - Agent not found
+    pub fn get_agent_limit_utilization(&self, agent_id: &str) -> Option<LimitUtilization> {
+        self.state.get_agent(agent_id).map(|a| LimitUtilization {
+            bilateral: a.bilateral_utilization().into_iter().collect(),
+            multilateral: a.multilateral_utilization(),
+        })
+    }
+
     /// Get agent's total allowed overdraft limit (credit + collateral backing)
     ///
     /// Returns the maximum negative balance an agent can have, calculated as:
@@ -2153,6 +4297,32 @@ impl Orchestrator {
         deadline_tick: usize,
         priority: u8,
         divisible: bool,
+    ) -> Result<String, SimulationError> {
+        self.submit_transaction_with_tags(
+            sender_id,
+            receiver_id,
+            amount,
+            deadline_tick,
+            priority,
+            divisible,
+            Vec::new(),
+        )
+    }
+
+    /// Submit a transaction carrying analysis tags
+    ///
+    /// Same as `submit_transaction`, with `tags` attached to the created
+    /// transaction (see `get_transactions_with_tag`).
+    #[allow(clippy::too_many_arguments)]
+    pub fn submit_transaction_with_tags(
+        &mut self,
+        sender_id: &str,
+        receiver_id: &str,
+        amount: i64,
+        deadline_tick: usize,
+        priority: u8,
+        divisible: bool,
+        tags: Vec<String>,
     ) -> Result<String, SimulationError> {
         // Validate sender exists
         if !self.state.agents().contains_key(sender_id) {
@@ -2193,15 +4363,12 @@ impl Orchestrator {
             capped_deadline,
         );
 
-        // Set priority
-        tx = tx.with_priority(priority);
-
-        // TODO(Phase 7): Store divisibility flag on Transaction
-        // Currently, the Transaction struct doesn't have a divisible field.
-        // Policies check divisibility from ArrivalConfig, but externally-submitted
-        // transactions don't have a way to store this flag yet.
-        // For now, we accept the parameter but don't use it.
-        let _ = divisible; // Suppress unused warning
+        // Set priority, divisibility and tags
+        tx = tx
+            .with_priority(priority)
+            .with_divisible(divisible)
+            .with_tags(tags);
+        tx.mark_entered_queue1(current_tick);
 
         // Add transaction to state
         let tx_id_clone = tx.id().to_string();
@@ -2294,89 +4461,498 @@ impl Orchestrator {
             capped_deadline,
         );
 
-        // Set priority
-        tx = tx.with_priority(priority);
+        // Set priority and divisibility
+        tx = tx.with_priority(priority).with_divisible(divisible);
+
+        // Set declared RTGS priority (Phase 0: Dual Priority System)
+        tx.set_declared_rtgs_priority(rtgs_priority);
+        tx.mark_entered_queue1(current_tick);
+
+        // Add transaction to state
+        let tx_id_clone = tx.id().to_string();
+        self.state.add_transaction(tx);
+
+        // Queue in sender's outgoing queue (Queue 1)
+        if let Some(agent) = self.state.get_agent_mut(sender_id) {
+            agent.queue_outgoing(tx_id_clone.clone());
+        }
+
+        // Log submission event
+        self.log_event(Event::Arrival {
+            tick: current_tick,
+            tx_id: tx_id_clone.clone(),
+            sender_id: sender_id.to_string(),
+            receiver_id: receiver_id.to_string(),
+            amount,
+            deadline: capped_deadline,
+            priority,
+            is_divisible: divisible,
+        });
+
+        Ok(tx_id_clone)
+    }
+
+    /// Preview which transactions would settle immediately if released now
+    ///
+    /// Simulates sequential RTGS settlement of `tx_ids` in the given order on
+    /// copies of the involved agents: each success debits the sender (and,
+    /// unless the receiver defers its credits, credits the receiver) before the
+    /// next transaction is checked. Applies the same liquidity and
+    /// bilateral/multilateral limit checks as real settlement. No events are
+    /// logged and no queue or balance is touched.
+    ///
+    /// Unknown, settled and cancelled transactions report `false`.
+    pub fn preview_settlement(&self, tx_ids: &[String]) -> Vec<(String, bool)> {
+        let mut agents: BTreeMap<String, Agent> = BTreeMap::new();
+
+        tx_ids
+            .iter()
+            .map(|tx_id| {
+                let settles = self.preview_settle_one(&mut agents, tx_id).is_some();
+                (tx_id.clone(), settles)
+            })
+            .collect()
+    }
+
+    /// Apply one previewed settlement to the agent copies (None = would not settle)
+    fn preview_settle_one(&self, agents: &mut BTreeMap<String, Agent>, tx_id: &str) -> Option<()> {
+        let tx = self.state.get_transaction(tx_id)?;
+        if tx.is_fully_settled() || tx.is_cancelled() || tx.is_returned() {
+            return None;
+        }
+        let (sender_id, receiver_id) = (tx.sender_id(), tx.receiver_id());
+        let amount = tx.remaining_amount();
+
+        for agent_id in [sender_id, receiver_id] {
+            if !agents.contains_key(agent_id) {
+                agents.insert(agent_id.to_string(), self.state.get_agent(agent_id)?.clone());
+            }
+        }
+
+        let sender = agents.get_mut(sender_id)?;
+        let (bilateral_ok, _, _) = sender.check_bilateral_limit(receiver_id, amount);
+        let (multilateral_ok, _, _) = sender.check_multilateral_limit(amount);
+        if !(sender.can_pay(amount) && bilateral_ok && multilateral_ok) {
+            return None;
+        }
+        sender.debit(amount).ok()?;
+        sender.record_outflow(receiver_id, amount);
+
+        if !self.agent_defers_credits(receiver_id) {
+            agents.get_mut(receiver_id)?.credit(amount);
+        }
+        Some(())
+    }
+
+    /// Whether settlement credits to `agent_id` are deferred to end of tick
+    ///
+    /// The agent's `deferred_crediting` override wins; otherwise the global
+    /// `deferred_crediting` flag applies.
+    fn agent_defers_credits(&self, agent_id: &str) -> bool {
+        self.config
+            .agent_configs
+            .iter()
+            .find(|ac| ac.id == agent_id)
+            .and_then(|ac| ac.deferred_crediting)
+            .unwrap_or(self.config.deferred_crediting)
+    }
+
+    /// Withdraw a transaction from RTGS Queue 2 (Phase 0: Dual Priority System)
+    ///
+    /// Removes the transaction from Queue 2 and clears its RTGS priority/submission tick.
+    /// The transaction can then be resubmitted with a different priority.
+    ///
+    /// # Arguments
+    ///
+    /// * `tx_id` - Transaction ID to withdraw
+    ///
+    /// # Returns
+    ///
+    /// Result with success status and optional error message.
+    pub fn withdraw_from_rtgs(&mut self, tx_id: &str) -> Result<(), SimulationError> {
+        let current_tick = self.current_tick();
+
+        // Get transaction details before modification
+        let tx = self.state.get_transaction(tx_id).ok_or_else(|| {
+            SimulationError::SettlementError(format!("Transaction {} not found", tx_id))
+        })?;
+
+        // Get the original RTGS priority for the event
+        let original_priority = tx
+            .rtgs_priority()
+            .map(|p| p.to_string())
+            .unwrap_or_else(|| "None".to_string());
+        let sender = tx.sender_id().to_string();
+
+        // Remove from RTGS queue
+        let queue = self.state.rtgs_queue_mut();
+        let initial_len = queue.len();
+        queue.retain(|id| id != tx_id);
+
+        if queue.len() == initial_len {
+            return Err(SimulationError::SettlementError(format!(
+                "Transaction {} not found in RTGS Queue 2",
+                tx_id
+            )));
+        }
+
+        // Clear RTGS priority on the transaction
+        if let Some(tx) = self.state.get_transaction_mut(tx_id) {
+            tx.clear_rtgs_priority();
+        }
+
+        // Emit withdrawal event
+        self.log_event(Event::RtgsWithdrawal {
+            tick: current_tick,
+            tx_id: tx_id.to_string(),
+            sender,
+            original_rtgs_priority: original_priority,
+        });
+
+        Ok(())
+    }
+
+    /// Run an LSM pass immediately, outside the tick loop
+    ///
+    /// Runs against the current state at the current tick, regardless of
+    /// `lsm_interval_ticks`, and logs the same events as the in-loop pass
+    /// (e.g. right after injecting an arrival burst). No tick is in progress,
+    /// so credits are applied immediately even under deferred crediting.
+    ///
+    /// # Returns
+    ///
+    /// The pass summary: settled value, offsets, cycles and final queue size.
+    pub fn run_lsm_now(&mut self) -> crate::settlement::lsm::LsmPassResult {
+        let current_tick = self.current_tick();
+        let lsm_result = crate::settlement::lsm::run_lsm_pass_with_deferred(
+            &mut self.state,
+            &self.lsm_config,
+            current_tick,
+            self.time_manager.ticks_per_day(),
+            self.config.entry_disposition_offsetting,
+            self.config.entry_offset_min_amount,
+            None,
+        );
+        self.state.rebuild_queue2_index();
+        self.record_lsm_pass(&lsm_result, current_tick, true);
+        lsm_result
+    }
+
+    /// Persist cycle events and log replay events for an LSM pass
+    ///
+    /// `lsm_ran` is false when the tick skipped LSM (`lsm_interval_ticks`),
+    /// which suppresses the algorithm sequencing events.
+    fn record_lsm_pass(
+        &mut self,
+        lsm_result: &crate::settlement::lsm::LsmPassResult,
+        current_tick: usize,
+        lsm_ran: bool,
+    ) {
+        // Store LSM cycle events for persistence (Phase 4.2)
+        self.state
+            .lsm_cycle_events
+            .extend(lsm_result.cycle_events.clone());
+
+        // Log enriched LSM replay events (already contain all fields for display)
+        // These events are created in lsm.rs with complete data and don't need reconstruction
+        let lsm_debug = std::env::var("LSM_DEBUG").is_ok();
+        if lsm_debug {
+            eprintln!("[LSM DEBUG] Logging {} LSM replay events", lsm_result.replay_events.len());
+        }
+        for event in &lsm_result.replay_events {
+            if lsm_debug {
+                eprintln!("[LSM DEBUG] Logging enriched event: {:?}", event.event_type());
+            }
+            self.log_event(event.clone());
+        }
+
+        // Emit Algorithm 2 (Bilateral) and Algorithm 3 (Multilateral) events if algorithm_sequencing is enabled
+        if self.config.algorithm_sequencing && lsm_ran {
+            // Algorithm 2: Bilateral offsetting
+            let alg2_settlements = lsm_result.bilateral_offsets;
+            // Count bilateral events in replay events to get settled value
+            // Each bilateral offset has amount_a and amount_b - we sum the smaller (net settlement)
+            let alg2_value: i64 = lsm_result.replay_events.iter()
+                .filter_map(|e| match e {
+                    Event::LsmBilateralOffset { amount_a, amount_b, .. } => Some(std::cmp::min(*amount_a, *amount_b)),
+                    _ => None,
+                })
+                .sum();
+            let alg2_result = if alg2_settlements > 0 { "Success" } else { "NoProgress" };
+            self.log_event(Event::AlgorithmExecution {
+                tick: current_tick,
+                algorithm: 2,
+                result: alg2_result.to_string(),
+                settlements: alg2_settlements,
+                settled_value: alg2_value,
+            });
+
+            // Algorithm 3: Multilateral cycle settlement
+            let alg3_settlements = lsm_result.cycles_settled;
+            // Count cycle events in replay events to get settled value
+            let alg3_value: i64 = lsm_result.replay_events.iter()
+                .filter_map(|e| match e {
+                    Event::LsmCycleSettlement { total_value, .. } => Some(*total_value),
+                    _ => None,
+                })
+                .sum();
+            let alg3_result = if alg3_settlements > 0 { "Success" } else { "NoProgress" };
+            self.log_event(Event::AlgorithmExecution {
+                tick: current_tick,
+                algorithm: 3,
+                result: alg3_result.to_string(),
+                settlements: alg3_settlements,
+                settled_value: alg3_value,
+            });
+
+            // Algorithm 4: Multilateral netting (only when enabled)
+            if self.lsm_config.enable_multilateral_netting {
+                let alg4_settlements = lsm_result.netting_batches;
+                let alg4_value: i64 = lsm_result.replay_events.iter()
+                    .filter_map(|e| match e {
+                        Event::LsmNettingSettlement { total_value, .. } => Some(*total_value),
+                        _ => None,
+                    })
+                    .sum();
+                let alg4_result = if alg4_settlements > 0 { "Success" } else { "NoProgress" };
+                self.log_event(Event::AlgorithmExecution {
+                    tick: current_tick,
+                    algorithm: 4,
+                    result: alg4_result.to_string(),
+                    settlements: alg4_settlements,
+                    settled_value: alg4_value,
+                });
+            }
+        }
+    }
+
+    /// Force-settle overdue transactions (deadline hard cutoff)
+    ///
+    /// Settles every overdue transaction still in Queue 2 (in queue order)
+    /// or a Queue 1 (agents in ID order), ignoring policy decisions, queue
+    /// ordering and bilateral/multilateral limits. The sender may go into
+    /// overdraft but not beyond its allowed limit; transactions it cannot
+    /// cover stay queued. Credits the receiver immediately, or through
+    /// `deferred_credits` when the receiver is on deferred crediting.
+    ///
+    /// # Returns
+    ///
+    /// Number of transactions force-settled.
+    fn force_settle_overdue(
+        &mut self,
+        tick: usize,
+        mut deferred_credits: Option<&mut crate::settlement::DeferredCredits>,
+    ) -> Result<usize, SimulationError> {
+        let is_overdue = |tx_id: &String| {
+            self.state
+                .get_transaction(tx_id)
+                .is_some_and(|tx| tx.is_overdue() && !tx.is_fully_settled())
+        };
+        let mut candidates: Vec<(String, &'static str)> = self
+            .state
+            .rtgs_queue()
+            .iter()
+            .filter(|tx_id| is_overdue(tx_id))
+            .map(|tx_id| (tx_id.clone(), "queue2"))
+            .collect();
+        for agent in self.state.agents().values() {
+            candidates.extend(
+                agent
+                    .outgoing_queue()
+                    .iter()
+                    .filter(|tx_id| is_overdue(tx_id))
+                    .map(|tx_id| (tx_id.clone(), "queue1")),
+            );
+        }
+
+        let mut settled = 0;
+        let mut queue2_changed = false;
+        for (tx_id, queue) in candidates {
+            let (sender_id, receiver_id, amount, deadline_tick, parent_id) = {
+                let tx = self.state.get_transaction(&tx_id).unwrap();
+                (
+                    tx.sender_id().to_string(),
+                    tx.receiver_id().to_string(),
+                    tx.remaining_amount(),
+                    tx.deadline_tick(),
+                    tx.parent_id().map(|s| s.to_string()),
+                )
+            };
+
+            let sender = self
+                .state
+                .get_agent_mut(&sender_id)
+                .ok_or_else(|| SimulationError::AgentNotFound(sender_id.clone()))?;
+            if !sender.can_pay(amount) {
+                continue; // Beyond the allowed overdraft; retry next tick
+            }
+            let sender_balance_before = sender.balance();
+            sender.debit(amount).map_err(|e| {
+                SimulationError::SettlementError(format!("Debit failed: {}", e))
+            })?;
+            sender.record_outflow(&receiver_id, amount);
+            if queue == "queue1" {
+                sender.remove_from_queue(&tx_id);
+            }
+            let sender_balance_after = sender.balance();
+
+            if queue == "queue2" {
+                self.state.rtgs_queue_mut().retain(|id| id != &tx_id);
+                queue2_changed = true;
+            }
+            self.state.record_settled_flow(&sender_id, &receiver_id, amount);
+            match deferred_credits.as_deref_mut() {
+                Some(dc) if dc.defers(&receiver_id) => {
+                    dc.defer(&mut self.state, &receiver_id, amount, &tx_id);
+                }
+                _ => {
+                    self.state
+                        .get_agent_mut(&receiver_id)
+                        .ok_or_else(|| SimulationError::AgentNotFound(receiver_id.clone()))?
+                        .credit(amount);
+                }
+            }
 
-        // Set declared RTGS priority (Phase 0: Dual Priority System)
-        tx.set_declared_rtgs_priority(rtgs_priority);
+            {
+                let tx = self.state.get_transaction_mut(&tx_id).unwrap();
+                tx.settle(amount, tick).map_err(|e| {
+                    SimulationError::SettlementError(format!("Settle failed: {}", e))
+                })?;
+                tx.set_settlement_mechanism(SettlementMechanism::Forced);
+            }
+            self.state.record_settlement(&tx_id, tick);
 
-        // TODO(Phase 7): Store divisibility flag on Transaction
-        let _ = divisible;
+            // If this is a child transaction, update parent's remaining_amount
+            if let Some(parent_id) = parent_id {
+                let parent = self.state.get_transaction_mut(&parent_id).unwrap();
+                parent.reduce_remaining_for_child(amount).map_err(|e| {
+                    SimulationError::SettlementError(format!("Parent update failed: {}", e))
+                })?;
+                if parent.remaining_amount() == 0 {
+                    parent.mark_fully_settled(tick).map_err(|e| {
+                        SimulationError::SettlementError(format!("Parent mark settled failed: {}", e))
+                    })?;
+                }
+            }
 
-        // Add transaction to state
-        let tx_id_clone = tx.id().to_string();
-        self.state.add_transaction(tx);
+            self.log_event(Event::ForcedSettlement {
+                tick,
+                tx_id,
+                sender_id,
+                receiver_id,
+                amount,
+                deadline_tick,
+                sender_balance_before,
+                sender_balance_after,
+                queue: queue.to_string(),
+            });
+            settled += 1;
+        }
 
-        // Queue in sender's outgoing queue (Queue 1)
-        if let Some(agent) = self.state.get_agent_mut(sender_id) {
-            agent.queue_outgoing(tx_id_clone.clone());
+        if queue2_changed {
+            self.state.rebuild_queue2_index();
         }
 
-        // Log submission event
-        self.log_event(Event::Arrival {
-            tick: current_tick,
-            tx_id: tx_id_clone.clone(),
-            sender_id: sender_id.to_string(),
-            receiver_id: receiver_id.to_string(),
-            amount,
-            deadline: capped_deadline,
-            priority,
-            is_divisible: divisible,
-        });
+        Ok(settled)
+    }
 
-        Ok(tx_id_clone)
+    /// Apply pending deferred credits and log `DeferredCreditApplied` events
+    fn apply_deferred_credits(
+        &mut self,
+        deferred_credits: Option<&mut crate::settlement::DeferredCredits>,
+        tick: usize,
+    ) {
+        if let Some(dc) = deferred_credits {
+            if !dc.is_empty() {
+                for event in dc.apply_all(&mut self.state, tick) {
+                    self.log_event(event);
+                }
+            }
+        }
     }
 
-    /// Withdraw a transaction from RTGS Queue 2 (Phase 0: Dual Priority System)
-    ///
-    /// Removes the transaction from Queue 2 and clears its RTGS priority/submission tick.
-    /// The transaction can then be resubmitted with a different priority.
-    ///
-    /// # Arguments
+    /// Cancel a transaction before it settles
     ///
-    /// * `tx_id` - Transaction ID to withdraw
+    /// Removes the transaction from whichever queue holds it (the sender's
+    /// Queue 1 or RTGS Queue 2), marks it `TransactionStatus::Cancelled` and
+    /// logs `Event::TransactionCancelled`. Any pending scheduled release is
+    /// dropped with it. Cancelled transactions never settle.
     ///
-    /// # Returns
+    /// # Errors
     ///
-    /// Result with success status and optional error message.
-    pub fn withdraw_from_rtgs(&mut self, tx_id: &str) -> Result<(), SimulationError> {
+    /// - `TransactionNotFound`: unknown transaction ID
+    /// - `SettlementError`: already settled, already cancelled, or not in
+    ///   either queue (e.g. a split parent)
+    pub fn cancel_transaction(&mut self, tx_id: &str) -> Result<(), SimulationError> {
         let current_tick = self.current_tick();
 
-        // Get transaction details before modification
-        let tx = self.state.get_transaction(tx_id).ok_or_else(|| {
-            SimulationError::SettlementError(format!("Transaction {} not found", tx_id))
-        })?;
-
-        // Get the original RTGS priority for the event
-        let original_priority = tx
-            .rtgs_priority()
-            .map(|p| p.to_string())
-            .unwrap_or_else(|| "None".to_string());
-        let sender = tx.sender_id().to_string();
-
-        // Remove from RTGS queue
-        let queue = self.state.rtgs_queue_mut();
-        let initial_len = queue.len();
-        queue.retain(|id| id != tx_id);
-
-        if queue.len() == initial_len {
+        let tx = self
+            .state
+            .get_transaction(tx_id)
+            .ok_or_else(|| SimulationError::TransactionNotFound(tx_id.to_string()))?;
+        if tx.is_fully_settled() {
             return Err(SimulationError::SettlementError(format!(
-                "Transaction {} not found in RTGS Queue 2",
+                "Transaction {} is already settled",
+                tx_id
+            )));
+        }
+        if tx.is_cancelled() {
+            return Err(SimulationError::SettlementError(format!(
+                "Transaction {} is already cancelled",
+                tx_id
+            )));
+        }
+        if tx.is_returned() {
+            return Err(SimulationError::SettlementError(format!(
+                "Transaction {} has been returned unsettled",
                 tx_id
             )));
         }
+        let sender_id = tx.sender_id().to_string();
+        let receiver_id = tx.receiver_id().to_string();
+        let remaining_amount = tx.remaining_amount();
 
-        // Clear RTGS priority on the transaction
+        let removed_from_queue1 = self
+            .state
+            .get_agent_mut(&sender_id)
+            .map(|agent| agent.remove_from_queue(tx_id))
+            .unwrap_or(false);
+        let queue = if removed_from_queue1 {
+            "queue1"
+        } else {
+            let rtgs_queue = self.state.rtgs_queue_mut();
+            let initial_len = rtgs_queue.len();
+            rtgs_queue.retain(|id| id != tx_id);
+            if rtgs_queue.len() == initial_len {
+                return Err(SimulationError::SettlementError(format!(
+                    "Transaction {} is not in Queue 1 or Queue 2",
+                    tx_id
+                )));
+            }
+            self.state.rebuild_queue2_index();
+            "queue2"
+        };
+
+        for scheduled in self.scheduled_releases.values_mut() {
+            scheduled.retain(|id| id != tx_id);
+        }
+        self.scheduled_releases.retain(|_, scheduled| !scheduled.is_empty());
+        if let Some(receiver) = self.state.get_agent_mut(&receiver_id) {
+            receiver.remove_expected_inflow(tx_id);
+        }
         if let Some(tx) = self.state.get_transaction_mut(tx_id) {
-            tx.clear_rtgs_priority();
+            tx.cancel(current_tick).map_err(|e| {
+                SimulationError::SettlementError(format!("Cancel failed: {}", e))
+            })?;
         }
 
-        // Emit withdrawal event
-        self.log_event(Event::RtgsWithdrawal {
+        self.log_event(Event::TransactionCancelled {
             tick: current_tick,
             tx_id: tx_id.to_string(),
-            sender,
-            original_rtgs_priority: original_priority,
+            sender_id,
+            receiver_id,
+            remaining_amount,
+            queue: queue.to_string(),
         });
 
         Ok(())
@@ -2425,6 +5001,7 @@ impl Orchestrator {
         // Set new declared RTGS priority
         if let Some(tx) = self.state.get_transaction_mut(tx_id) {
             tx.set_declared_rtgs_priority(rtgs_priority);
+            tx.mark_entered_queue1(current_tick);
         }
 
         // Add back to sender's Queue 1 so it can be released again
@@ -2488,6 +5065,23 @@ impl Orchestrator {
             .collect()
     }
 
+    /// Count transactions by status at the current tick
+    ///
+    /// Keyed by `TransactionStatus` variant name; every variant is present,
+    /// with 0 if no transaction has that status. Split children are counted
+    /// alongside their parents, so the counts sum to the total number of
+    /// transactions in state.
+    pub fn status_counts(&self) -> HashMap<String, usize> {
+        let mut counts: HashMap<String, usize> = crate::models::TransactionStatus::NAMES
+            .iter()
+            .map(|name| (name.to_string(), 0))
+            .collect();
+        for tx in self.state.transactions().values() {
+            *counts.entry(tx.status().name().to_string()).or_insert(0) += 1;
+        }
+        counts
+    }
+
     /// Get simulation ID
     ///
     /// Returns a unique identifier for this simulation run.
@@ -2561,6 +5155,26 @@ impl Orchestrator {
         metrics
     }
 
+    /// Owned copy of the daily metrics for every agent on `day`
+    ///
+    /// Same records as `get_daily_agent_metrics`, sorted by agent ID, for
+    /// callers (e.g. the FFI layer) that outlive the borrow. Empty until the
+    /// day has completed.
+    pub fn daily_agent_metrics(&self, day: usize) -> Vec<DailyMetrics> {
+        self.get_daily_agent_metrics(day)
+            .into_iter()
+            .cloned()
+            .collect()
+    }
+
+    /// Current system-wide metrics
+    ///
+    /// Equivalent to `calculate_system_metrics`; pairs with
+    /// `daily_agent_metrics` as the metrics entry points used by the FFI.
+    pub fn system_metrics(&self) -> SystemMetrics {
+        self.calculate_system_metrics()
+    }
+
     /// Get agent policy configurations
     ///
     /// Returns the PolicyConfig for each agent as specified in the original
@@ -2698,14 +5312,25 @@ impl Orchestrator {
             current_tick: self.time_manager.current_tick(),
             current_day: self.time_manager.current_day(),
             rng_seed: self.rng_manager.get_state(), // CRITICAL: Current RNG state
+            rng_cached_gaussian: self.rng_manager.cached_gaussian(),
             agents,
             transactions,
             rtgs_queue: self.state.get_rtgs_queue().clone(),
             config_hash,
+            injected_liquidity: self.injected_liquidity,
+            arrival_rngs: self
+                .arrival_rngs
+                .iter()
+                .map(|(id, rng)| (id.clone(), rng.clone()))
+                .collect(),
+            scheduled_releases: self.scheduled_releases.clone(),
+            cost_rates: Some(self.cost_rates.clone()),
+            flow_window: Some(self.state.flow_window().clone()),
         };
 
-        // Validate invariants before serializing
-        let expected_balance = self.get_all_agent_balances().values().sum();
+        // Validate invariants before serializing (injections are not zero-sum)
+        let expected_balance =
+            self.get_all_agent_balances().values().sum::<i64>() - self.injected_liquidity;
         validate_snapshot(&snapshot, expected_balance)?;
 
         // Serialize to JSON
@@ -2764,9 +5389,13 @@ impl Orchestrator {
             });
         }
 
-        // Validate state integrity
-        let expected_balance: i64 = snapshot.agents.iter().map(|a| a.balance).sum();
+        // Validate state integrity (injected liquidity is outside the conserved total)
+        let expected_balance: i64 = snapshot.agents.iter().map(|a| a.balance).sum::<i64>()
+            - snapshot.injected_liquidity;
         validate_snapshot(&snapshot, expected_balance)?;
+        let snapshot_injected_liquidity = snapshot.injected_liquidity;
+        let snapshot_scheduled_releases = snapshot.scheduled_releases.clone();
+        let snapshot_cost_rates = snapshot.cost_rates.clone();
 
         // Reconstruct state
         let agents: std::collections::BTreeMap<_, _> = snapshot
@@ -2787,12 +5416,18 @@ impl Orchestrator {
             })
             .collect();
 
-        let state = crate::models::state::SimulationState::from_parts(
+        let mut state = crate::models::state::SimulationState::from_parts(
             agents,
             transactions,
             snapshot.rtgs_queue,
         )
         .map_err(|e| SimulationError::StateValidationError(e))?;
+        state.set_rtgs_queue_capacity(config.rtgs_queue_capacity);
+        state.set_flow_window(
+            snapshot
+                .flow_window
+                .unwrap_or_else(|| FlowWindow::new(config.flow_window_ticks)),
+        );
 
         // Reconstruct time manager
         let time_manager = crate::core::time::TimeManager::from_state(
@@ -2802,27 +5437,38 @@ impl Orchestrator {
             snapshot.current_day,
         );
 
-        // Reconstruct RNG manager with saved seed
-        let rng_manager = crate::rng::RngManager::new(snapshot.rng_seed);
+        // Reconstruct RNG manager from saved state (not re-seeded)
+        let rng_manager = crate::rng::RngManager::from_state(snapshot.rng_seed)
+            .with_cached_gaussian(snapshot.rng_cached_gaussian);
+        let arrival_rngs: HashMap<String, RngManager> = if snapshot.arrival_rngs.is_empty() {
+            derive_arrival_rngs(&config) // Legacy snapshot
+        } else {
+            snapshot.arrival_rngs.into_iter().collect()
+        };
 
         // Reconstruct policies
         // All policies now use JSON-based TreePolicy loaded via factory
         let mut policies: HashMap<String, Box<dyn crate::policy::CashManagerPolicy>> =
             HashMap::new();
         for agent_config in &config.agent_configs {
-            let tree_policy =
+            let mut tree_policy =
                 crate::policy::tree::create_policy(&agent_config.policy).map_err(|e| {
                     SimulationError::InvalidConfig(format!(
                         "Failed to create JSON policy for agent {}: {}",
                         agent_config.id, e
                     ))
                 })?;
+            tree_policy.set_record_decision_paths(config.record_decision_paths);
             policies.insert(agent_config.id.clone(), Box::new(tree_policy));
         }
 
         // Reconstruct arrival generator if configured
         let mut arrival_configs_map = HashMap::new();
-        for agent_config in &config.agent_configs {
+        for agent_config in config
+            .agent_configs
+            .iter()
+            .filter(|ac| ac.scripted_arrivals.is_none())
+        {
             if let Some(arrival_cfg) = &agent_config.arrival_config {
                 arrival_configs_map.insert(agent_config.id.clone(), arrival_cfg.clone());
             }
@@ -2842,7 +5488,11 @@ impl Orchestrator {
                 episode_end_tick,
                 config.ticks_per_day,
                 config.deadline_cap_at_eod,
-            ))
+            )
+            .with_max_arrivals_per_tick(config.max_arrivals_per_agent_per_tick)
+            .with_agent_groups(Self::agent_groups(&config))
+            .with_time_scale(config.time_scale)
+            .with_rng_seed(config.rng_seed))
         } else {
             None
         };
@@ -2864,7 +5514,9 @@ impl Orchestrator {
             .map(|events| crate::events::ScenarioEventHandler::new(events.clone()));
 
         // Clone values we need before moving config
-        let cost_rates = config.cost_rates.clone();
+        let cost_rates = snapshot_cost_rates.unwrap_or_else(|| config.cost_rates.clone());
+        let agent_cost_rates = Self::agent_cost_rates(&config);
+        let scripted_arrivals = Self::scripted_arrivals(&config);
         let lsm_config = config.lsm_config.clone();
 
         Ok(Self {
@@ -2872,17 +5524,27 @@ impl Orchestrator {
             state,
             time_manager,
             rng_manager,
+            arrival_rngs,
             policies,
             arrival_generator,
             cost_rates,
+            agent_cost_rates,
+            scripted_arrivals,
             lsm_config,
             accumulated_costs,
             event_log: crate::models::event::EventLog::new(),
             pending_settlements: Vec::new(),
+            scheduled_releases: snapshot_scheduled_releases,
             next_tx_id: 0, // Will be updated on next transaction
             current_day_metrics,
             historical_metrics,
             scenario_event_handler,
+            injected_liquidity: snapshot_injected_liquidity,
+            on_tick: None,
+            recent_tick_results: VecDeque::with_capacity(RECENT_TICK_RESULTS_CAPACITY),
+            decision_paths: HashMap::new(),
+            balance_series: HashMap::new(),
+            throughput_series: Vec::new(),
         })
     }
 
@@ -2894,6 +5556,15 @@ impl Orchestrator {
         self.config.clone()
     }
 
+    /// Get reference to the orchestrator configuration
+    ///
+    /// Borrowing counterpart of the internal clone used for checkpoint
+    /// hashing. Handy after `load_state`, where the caller may not have kept
+    /// its own copy of the config.
+    pub fn config(&self) -> &OrchestratorConfig {
+        &self.config
+    }
+
     /// Get all agent balances
     ///
     /// Returns a map of agent ID to current balance.
@@ -2909,6 +5580,13 @@ impl Orchestrator {
             .collect()
     }
 
+    /// Get total external liquidity injected by scenario events (cents)
+    ///
+    /// System balance = conserved balance + injected liquidity.
+    pub fn injected_liquidity(&self) -> i64 {
+        self.injected_liquidity
+    }
+
     // ========================================================================
     // Event Logging
     // ========================================================================
@@ -2960,11 +5638,18 @@ impl Orchestrator {
         let current_tick = self.current_tick();
         let mut num_settlements = 0;
 
-        // Initialize deferred credits accumulator if deferred crediting mode is enabled
-        let mut deferred_credits = if self.config.deferred_crediting {
-            Some(DeferredCredits::new())
-        } else {
+        // Initialize deferred credits accumulator if any agent defers its credits
+        let deferred_agents: Vec<String> = self
+            .config
+            .agent_configs
+            .iter()
+            .filter(|ac| self.agent_defers_credits(&ac.id))
+            .map(|ac| ac.id.clone())
+            .collect();
+        let mut deferred_credits = if deferred_agents.is_empty() {
             None
+        } else {
+            Some(DeferredCredits::for_agents(deferred_agents))
         };
 
         // Clear pending settlements from previous tick
@@ -2992,6 +5677,9 @@ impl Orchestrator {
         }
 
         // STEP 0.5: EXECUTE SCENARIO EVENTS
+        // Scheduled resumes fire first, so a suspension at this tick still applies
+        self.resume_due_agents(current_tick);
+
         // Execute scheduled scenario events before arrivals (they may modify rates, etc.)
         if let Some(handler) = &self.scenario_event_handler {
             // Collect events first to avoid borrow checker conflicts
@@ -3015,13 +5703,18 @@ impl Orchestrator {
             let agent_ids: Vec<String> = self.state.get_all_agent_ids();
 
             for agent_id in agent_ids {
-                // Generate arrivals for this agent
-                let new_transactions =
-                    generator.generate_for_agent(&agent_id, current_tick, &mut self.rng_manager);
+                // Generate arrivals for this agent from its own RNG stream
+                let rng_seed = self.config.rng_seed;
+                let rng = self
+                    .arrival_rngs
+                    .entry(agent_id.clone())
+                    .or_insert_with(|| arrival_rng_for(rng_seed, &agent_id));
+                let new_transactions = generator.generate_for_agent(&agent_id, current_tick, rng);
                 num_arrivals += new_transactions.len();
 
                 // Add transactions to state and queue them
-                for tx in new_transactions {
+                for mut tx in new_transactions {
+                    tx.mark_entered_queue1(current_tick);
                     let tx_id = tx.id().to_string();
 
                     // Collect arrival event for logging (after generator is done)
@@ -3033,7 +5726,7 @@ impl Orchestrator {
                         amount: tx.amount(),
                         deadline: tx.deadline_tick(),
                         priority: tx.priority(),
-                        is_divisible: false, // TODO: Add is_divisible to Transaction struct
+                        is_divisible: tx.is_divisible(),
                     });
 
                     self.state.add_transaction(tx);
@@ -3044,6 +5737,15 @@ impl Orchestrator {
                     }
                 }
             }
+
+            for clamp in generator.drain_clamps() {
+                arrival_events.push(Event::ArrivalsClamped {
+                    tick: clamp.tick,
+                    agent_id: clamp.agent_id,
+                    sampled_count: clamp.sampled_count,
+                    max_count: clamp.max_count,
+                });
+            }
         }
 
         // Log arrival events (after generator is done to avoid borrow checker issues)
@@ -3051,6 +5753,20 @@ impl Orchestrator {
             self.log_event(event);
         }
 
+        // Scripted arrivals (agents in config order, then script order)
+        let scripted = self.scripted_arrivals.remove(&current_tick).unwrap_or_default();
+        for (sender_id, arrival) in scripted {
+            self.submit_transaction(
+                &sender_id,
+                &arrival.receiver,
+                arrival.amount,
+                current_tick + arrival.deadline_offset,
+                arrival.priority,
+                false,
+            )?;
+            num_arrivals += 1;
+        }
+
         // Capture timing for arrivals phase
         let arrivals_start = tick_start;
         timing.arrivals_micros = arrivals_start.elapsed().as_micros() as u64;
@@ -3062,6 +5778,11 @@ impl Orchestrator {
         let all_agent_ids: Vec<String> = self.state.agents().keys().cloned().collect();
 
         for agent_id in all_agent_ids.clone() {
+            // Suspended agents make no policy decisions
+            if self.is_agent_suspended(&agent_id) {
+                continue;
+            }
+
             let agent = self
                 .state
                 .get_agent(&agent_id)
@@ -3082,8 +5803,10 @@ impl Orchestrator {
                     ))
                 })?;
 
+            let cost_rates =
+                Self::merged_cost_rates(&self.cost_rates, &self.agent_cost_rates, &agent_id);
             let decision = tree_policy
-                .evaluate_strategic_collateral(agent, &self.state, current_tick, &self.cost_rates, self.config.ticks_per_day, self.config.eod_rush_threshold)
+                .evaluate_strategic_collateral(agent, &self.state, current_tick, &cost_rates, self.config.ticks_per_day, self.config.eod_rush_threshold)
                 .map_err(|e| {
                     SimulationError::InvalidConfig(format!(
                         "Failed to evaluate strategic collateral for {}: {}",
@@ -3144,6 +5867,8 @@ impl Orchestrator {
                         reason: format!("{:?}", reason),
                         new_total: new_collateral,
                     });
+
+                    self.charge_collateral_post_fee(&agent_id, current_tick);
                 }
                 CollateralDecision::Withdraw { amount, reason } => {
                     if amount <= 0 {
@@ -3154,7 +5879,8 @@ impl Orchestrator {
                     }
 
                     let agent = self.state.get_agent(&agent_id).unwrap();
-                    let posted = agent.posted_collateral();
+                    // Collateral classes stay pledged; only the pool is withdrawable
+                    let posted = agent.pool_collateral();
 
                     // Clamp withdrawal amount to what's actually available
                     // This handles race conditions where collateral changes between
@@ -3196,6 +5922,10 @@ impl Orchestrator {
         // Evaluate bank_tree once per agent to set release budgets for this tick
         // This runs after collateral decisions but before payment decisions
         for agent_id in all_agent_ids.clone() {
+            if self.is_agent_suspended(&agent_id) {
+                continue;
+            }
+
             let agent = self
                 .state
                 .get_agent(&agent_id)
@@ -3217,8 +5947,10 @@ impl Orchestrator {
                     ))
                 })?;
 
+            let cost_rates =
+                Self::merged_cost_rates(&self.cost_rates, &self.agent_cost_rates, &agent_id);
             let decision = tree_policy
-                .evaluate_bank_tree(agent, &self.state, current_tick, &self.cost_rates, self.config.ticks_per_day, self.config.eod_rush_threshold)
+                .evaluate_bank_tree(agent, &self.state, current_tick, &cost_rates, self.config.ticks_per_day, self.config.eod_rush_threshold)
                 .map_err(|e| {
                     SimulationError::InvalidConfig(format!(
                         "Failed to evaluate bank_tree for {}: {}",
@@ -3389,13 +6121,19 @@ impl Orchestrator {
             }
         }
 
+        // STEP 1.9: SCHEDULED RELEASES
+        // Transactions whose target_tick has arrived bypass policy evaluation
+        self.trigger_scheduled_releases(current_tick);
+
         // STEP 2: POLICY EVALUATION
         // Get agents with queued transactions (Queue 1)
         let policy_eval_start = Instant::now();
+        // Suspended agents keep their Queue 1 untouched until they resume
         let agents_with_queues: Vec<String> = self
             .state
             .agents_with_queued_transactions()
             .into_iter()
+            .filter(|agent_id| !self.is_agent_suspended(agent_id))
             .collect();
 
         // Apply priority escalation before queue sorting (Phase 5)
@@ -3408,6 +6146,11 @@ impl Orchestrator {
             self.sort_agent_queue(agent_id);
         }
 
+        // Transactions awaiting a scheduled release are withheld from the
+        // policy so no EvalContext is built for them
+        let scheduled: HashSet<String> =
+            self.scheduled_releases.values().flatten().cloned().collect();
+
         for agent_id in agents_with_queues {
             // Get agent and policy
             let agent = self
@@ -3415,6 +6158,23 @@ impl Orchestrator {
                 .get_agent(&agent_id)
                 .ok_or_else(|| SimulationError::AgentNotFound(agent_id.clone()))?;
 
+            let unscheduled_view;
+            let agent = if agent.outgoing_queue().iter().any(|id| scheduled.contains(id)) {
+                let mut view = agent.clone();
+                view.replace_outgoing_queue(
+                    agent
+                        .outgoing_queue()
+                        .iter()
+                        .filter(|id| !scheduled.contains(*id))
+                        .cloned()
+                        .collect(),
+                );
+                unscheduled_view = view;
+                &unscheduled_view
+            } else {
+                agent
+            };
+
             let policy = self
                 .policies
                 .get_mut(&agent_id)
@@ -3422,13 +6182,18 @@ impl Orchestrator {
 
             // Evaluate policy for all transactions in Queue 1
             // Pass cost_rates for policy decision-making (read-only, external)
+            let cost_rates =
+                Self::merged_cost_rates(&self.cost_rates, &self.agent_cost_rates, &agent_id);
             let decisions =
-                policy.evaluate_queue(agent, &self.state, current_tick, &self.cost_rates, self.config.ticks_per_day, self.config.eod_rush_threshold);
+                policy.evaluate_queue(agent, &self.state, current_tick, &cost_rates, self.config.ticks_per_day, self.config.eod_rush_threshold);
 
             // Process decisions
             for decision in decisions {
                 use crate::policy::ReleaseDecision;
 
+                // Split decisions only apply to divisible transactions
+                let decision = self.gate_split_on_divisibility(decision);
+
                 match decision {
                     ReleaseDecision::SubmitFull {
                         tx_id,
@@ -3444,8 +6209,7 @@ impl Orchestrator {
                             }
                         }
 
-                        // Handle target tick (simplified for now - just check if immediate)
-                        // TODO: Add proper scheduled release tracking for future ticks
+                        // Handle target tick: future targets are scheduled below
                         let should_release_now = match target_tick {
                             None => true,                            // No target = immediate
                             Some(target) => target <= current_tick, // Past or current = immediate
@@ -3519,23 +6283,146 @@ impl Orchestrator {
                                     reason: "BudgetExhausted".to_string(),
                                 });
                             }
-                        } else {
-                            // Future target tick - leave in Queue 1 for now
-                            // Transaction will be reconsidered by policy next tick
-                            // TODO: Add scheduled release infrastructure
+                        } else if let Some(target) = target_tick {
+                            // Future target tick - stays in Queue 1, withheld from
+                            // policy until released by trigger_scheduled_releases
+                            self.scheduled_releases.entry(target).or_default().push(tx_id);
                         }
                     }
                     ReleaseDecision::SubmitPartial { tx_id, num_splits } => {
                         // Phase 5: Transaction splitting implementation
 
-                        // Validate num_splits
-                        if num_splits < 2 {
-                            return Err(SimulationError::SettlementError(format!(
-                                "num_splits must be >= 2, got {}",
-                                num_splits
-                            )));
+                        // Validate num_splits
+                        if num_splits < 2 {
+                            return Err(SimulationError::SettlementError(format!(
+                                "num_splits must be >= 2, got {}",
+                                num_splits
+                            )));
+                        }
+
+                        // Get parent transaction
+                        let parent_tx = self
+                            .state
+                            .get_transaction(&tx_id)
+                            .ok_or_else(|| {
+                                SimulationError::SettlementError(format!(
+                                    "Transaction {} not found for splitting",
+                                    tx_id
+                                ))
+                            })?
+                            .clone();
+
+                        // Remove parent from Queue 1 (will be replaced by children)
+                        if let Some(agent) = self.state.get_agent_mut(&agent_id) {
+                            agent.remove_from_queue(&tx_id);
+                        }
+
+                        // Calculate child amounts (equal splits, remainder per config)
+                        let total_amount = parent_tx.amount();
+                        let remainder_policy = self.config.split_remainder_policy;
+
+                        // Create child transactions
+                        let mut child_ids = Vec::new();
+                        for i in 0..num_splits {
+                            let child_amount =
+                                remainder_policy.child_amount(total_amount, num_splits, i);
+
+                            // Create child transaction
+                            let mut child = crate::models::Transaction::new_split(
+                                parent_tx.sender_id().to_string(),
+                                parent_tx.receiver_id().to_string(),
+                                child_amount,
+                                parent_tx.arrival_tick(),
+                                parent_tx.deadline_tick(),
+                                tx_id.clone(),
+                            );
+
+                            // Preserve parent's priority and tags
+                            child = child
+                                .with_priority(parent_tx.priority())
+                                .with_tags(parent_tx.tags().to_vec());
+
+                            let child_id = child.id().to_string();
+                            child_ids.push(child_id.clone());
+
+                            // Emit Arrival event for child transaction (Issue #3 fix)
+                            // This ensures replay can reconstruct split children from events
+                            // Child transactions are not divisible (can't split a split)
+                            self.log_event(Event::Arrival {
+                                tick: current_tick,
+                                tx_id: child_id.clone(),
+                                sender_id: child.sender_id().to_string(),
+                                receiver_id: child.receiver_id().to_string(),
+                                amount: child_amount,
+                                deadline: child.deadline_tick(),
+                                priority: child.priority(),
+                                is_divisible: false, // Child transactions are not divisible
+                            });
+
+                            // Add child to state and pending settlements
+                            let sender = child.sender_id().to_string();
+                            let receiver = child.receiver_id().to_string();
+                            let child_priority = child.priority();
+                            self.state.add_transaction(child);
+
+                            // Set RTGS priority for child (Phase 0: Dual Priority System)
+                            // Children inherit parent's declared priority, or default to Normal
+                            let rtgs_priority = parent_tx.declared_rtgs_priority().unwrap_or(RtgsPriority::Normal);
+                            if let Some(tx) = self.state.get_transaction_mut(&child_id) {
+                                tx.set_rtgs_priority(rtgs_priority, current_tick);
+                            }
+
+                            // Log RTGS submission event for child (Phase 0: Dual Priority System)
+                            self.log_event(Event::RtgsSubmission {
+                                tick: current_tick,
+                                tx_id: child_id.clone(),
+                                sender: sender.clone(),
+                                receiver: receiver.clone(),
+                                amount: child_amount,
+                                internal_priority: child_priority,
+                                rtgs_priority: rtgs_priority.to_string(),
+                            });
+
+                            self.pending_settlements.push(child_id);
+                        }
+
+                        // Calculate and charge split friction cost
+                        let friction_cost = self.cost_rates_for_agent(&agent_id).split_friction_for(num_splits);
+
+                        if friction_cost > 0 {
+                            if let Some(accumulator) = self.accumulated_costs.get_mut(&agent_id) {
+                                accumulator.total_split_friction_cost += friction_cost;
+                            }
+
+                            // Log friction cost event
+                            self.log_event(Event::CostAccrual {
+                                tick: current_tick,
+                                agent_id: agent_id.clone(),
+                                costs: CostBreakdown {
+                                    liquidity_cost: 0,
+                                    delay_cost: 0,
+                                    collateral_cost: 0,
+                                    penalty_cost: 0,
+                                    split_friction_cost: friction_cost,
+                                    liquidity_opportunity_cost: 0,
+                                    overnight_interest: 0,
+                                    collateral_fee_cost: 0,
+                                },
+                            });
                         }
 
+                        // Log policy split event
+                        self.log_event(Event::PolicySplit {
+                            tick: current_tick,
+                            agent_id: agent_id.clone(),
+                            tx_id,
+                            num_splits,
+                            child_ids,
+                        });
+                    }
+                    ReleaseDecision::SubmitAmount { tx_id, amount } => {
+                        // Explicit partial release: `amount` now, remainder retained in Queue 1
+
                         // Get parent transaction
                         let parent_tx = self
                             .state
@@ -3548,44 +6435,41 @@ impl Orchestrator {
                             })?
                             .clone();
 
-                        // Remove parent from Queue 1 (will be replaced by children)
+                        // Validate amount
+                        let remaining = parent_tx.remaining_amount();
+                        if amount <= 0 || amount >= remaining {
+                            return Err(SimulationError::SettlementError(format!(
+                                "SubmitAmount amount must be > 0 and < remaining amount {}, got {}",
+                                remaining, amount
+                            )));
+                        }
+
+                        // Remove parent from Queue 1 (replaced by the two children)
                         if let Some(agent) = self.state.get_agent_mut(&agent_id) {
                             agent.remove_from_queue(&tx_id);
                         }
 
-                        // Calculate child amounts (equal splits with remainder in last)
-                        let total_amount = parent_tx.amount();
-                        let base_amount = total_amount / num_splits as i64;
-                        let remainder = total_amount % num_splits as i64;
-
-                        // Create child transactions
+                        let rtgs_priority =
+                            parent_tx.declared_rtgs_priority().unwrap_or(RtgsPriority::Normal);
                         let mut child_ids = Vec::new();
-                        for i in 0..num_splits {
-                            let child_amount = if i == num_splits - 1 {
-                                base_amount + remainder // Last child gets remainder
-                            } else {
-                                base_amount
-                            };
 
-                            // Create child transaction
-                            let mut child = crate::models::Transaction::new_split(
+                        // Released child first, retained child second
+                        for (child_amount, release_now) in [(amount, true), (remaining - amount, false)] {
+                            let child = crate::models::Transaction::new_split(
                                 parent_tx.sender_id().to_string(),
                                 parent_tx.receiver_id().to_string(),
                                 child_amount,
                                 parent_tx.arrival_tick(),
                                 parent_tx.deadline_tick(),
                                 tx_id.clone(),
-                            );
-
-                            // Preserve parent's priority
-                            child = child.with_priority(parent_tx.priority());
+                            )
+                            .with_priority(parent_tx.priority())
+                            .with_tags(parent_tx.tags().to_vec());
 
                             let child_id = child.id().to_string();
                             child_ids.push(child_id.clone());
 
-                            // Emit Arrival event for child transaction (Issue #3 fix)
-                            // This ensures replay can reconstruct split children from events
-                            // Child transactions are not divisible (can't split a split)
+                            // Emit Arrival event for child (for replay)
                             self.log_event(Event::Arrival {
                                 tick: current_tick,
                                 tx_id: child_id.clone(),
@@ -3594,46 +6478,48 @@ impl Orchestrator {
                                 amount: child_amount,
                                 deadline: child.deadline_tick(),
                                 priority: child.priority(),
-                                is_divisible: false, // Child transactions are not divisible
+                                is_divisible: false, // Children are not divisible
                             });
 
-                            // Add child to state and pending settlements
                             let sender = child.sender_id().to_string();
                             let receiver = child.receiver_id().to_string();
                             let child_priority = child.priority();
                             self.state.add_transaction(child);
 
-                            // Set RTGS priority for child (Phase 0: Dual Priority System)
-                            // Children inherit parent's declared priority, or default to Normal
-                            let rtgs_priority = parent_tx.declared_rtgs_priority().unwrap_or(RtgsPriority::Normal);
-                            if let Some(tx) = self.state.get_transaction_mut(&child_id) {
-                                tx.set_rtgs_priority(rtgs_priority, current_tick);
-                            }
+                            if release_now {
+                                if let Some(tx) = self.state.get_transaction_mut(&child_id) {
+                                    tx.set_rtgs_priority(rtgs_priority, current_tick);
+                                }
 
-                            // Log RTGS submission event for child (Phase 0: Dual Priority System)
-                            self.log_event(Event::RtgsSubmission {
-                                tick: current_tick,
-                                tx_id: child_id.clone(),
-                                sender: sender.clone(),
-                                receiver: receiver.clone(),
-                                amount: child_amount,
-                                internal_priority: child_priority,
-                                rtgs_priority: rtgs_priority.to_string(),
-                            });
+                                self.log_event(Event::RtgsSubmission {
+                                    tick: current_tick,
+                                    tx_id: child_id.clone(),
+                                    sender,
+                                    receiver,
+                                    amount: child_amount,
+                                    internal_priority: child_priority,
+                                    rtgs_priority: rtgs_priority.to_string(),
+                                });
 
-                            self.pending_settlements.push(child_id);
+                                self.pending_settlements.push(child_id);
+                            } else {
+                                if let Some(tx) = self.state.get_transaction_mut(&child_id) {
+                                    tx.mark_entered_queue1(current_tick);
+                                }
+                                if let Some(agent) = self.state.get_agent_mut(&agent_id) {
+                                    agent.queue_outgoing(child_id);
+                                }
+                            }
                         }
 
-                        // Calculate and charge split friction cost
-                        let friction_cost =
-                            self.cost_rates.split_friction_cost * (num_splits as i64 - 1);
+                        // One split (two children): friction charged once
+                        let friction_cost = self.cost_rates_for_agent(&agent_id).split_friction_for(2);
 
                         if friction_cost > 0 {
                             if let Some(accumulator) = self.accumulated_costs.get_mut(&agent_id) {
                                 accumulator.total_split_friction_cost += friction_cost;
                             }
 
-                            // Log friction cost event
                             self.log_event(Event::CostAccrual {
                                 tick: current_tick,
                                 agent_id: agent_id.clone(),
@@ -3644,16 +6530,17 @@ impl Orchestrator {
                                     penalty_cost: 0,
                                     split_friction_cost: friction_cost,
                                     liquidity_opportunity_cost: 0,
+                                    overnight_interest: 0,
+                                    collateral_fee_cost: 0,
                                 },
                             });
                         }
 
-                        // Log policy split event
                         self.log_event(Event::PolicySplit {
                             tick: current_tick,
                             agent_id: agent_id.clone(),
                             tx_id,
-                            num_splits,
+                            num_splits: 2,
                             child_ids,
                         });
                     }
@@ -3684,6 +6571,12 @@ impl Orchestrator {
                             });
                         }
                     }
+                    ReleaseDecision::SetOrderHint { tx_id, order_hint } => {
+                        // Only the tie-breaking key changes; transaction stays in Queue 1
+                        if let Some(tx) = self.state.get_transaction_mut(&tx_id) {
+                            tx.set_order_hint(order_hint);
+                        }
+                    }
                     ReleaseDecision::Drop { tx_id } => {
                         // Remove from Queue 1, mark as overdue (temporary - policies should handle this differently)
                         if let Some(agent) = self.state.get_agent_mut(&agent_id) {
@@ -3745,8 +6638,7 @@ impl Orchestrator {
 
                         // Calculate child amounts
                         let total_amount = parent_tx.amount();
-                        let base_amount = total_amount / num_splits as i64;
-                        let remainder = total_amount % num_splits as i64;
+                        let remainder_policy = self.config.split_remainder_policy;
 
                         // Apply priority boost (capped at 10)
                         let boosted_priority = (parent_tx.priority() + priority_boost_children).min(10);
@@ -3757,11 +6649,8 @@ impl Orchestrator {
                         let mut scheduled_children = Vec::new();
 
                         for i in 0..num_splits {
-                            let child_amount = if i == num_splits - 1 {
-                                base_amount + remainder
-                            } else {
-                                base_amount
-                            };
+                            let child_amount =
+                                remainder_policy.child_amount(total_amount, num_splits, i);
 
                             // Create child transaction
                             let mut child = crate::models::Transaction::new_split(
@@ -3773,8 +6662,10 @@ impl Orchestrator {
                                 tx_id.clone(),
                             );
 
-                            // Apply boosted priority
-                            child = child.with_priority(boosted_priority);
+                            // Apply boosted priority, keep parent's tags
+                            child = child
+                                .with_priority(boosted_priority)
+                                .with_tags(parent_tx.tags().to_vec());
 
                             let child_id = child.id().to_string();
                             child_ids.push(child_id.clone());
@@ -3811,14 +6702,16 @@ impl Orchestrator {
                         // TODO: Add field to Orchestrator struct to track scheduled releases
                         // For now, we'll add them to agent's Queue 1 (suboptimal but functional)
                         for (child_id, _release_tick) in &scheduled_children {
+                            if let Some(tx) = self.state.get_transaction_mut(child_id) {
+                                tx.mark_entered_queue1(current_tick);
+                            }
                             if let Some(agent) = self.state.get_agent_mut(&agent_id) {
                                 agent.queue_outgoing(child_id.clone());
                             }
                         }
 
                         // Calculate and charge split friction cost (same as SubmitPartial)
-                        let friction_cost =
-                            self.cost_rates.split_friction_cost * (num_splits as i64 - 1);
+                        let friction_cost = self.cost_rates_for_agent(&agent_id).split_friction_for(num_splits);
 
                         if friction_cost > 0 {
                             if let Some(accumulator) = self.accumulated_costs.get_mut(&agent_id) {
@@ -3835,6 +6728,8 @@ impl Orchestrator {
                                     penalty_cost: 0,
                                     split_friction_cost: friction_cost,
                                     liquidity_opportunity_cost: 0,
+                                    overnight_interest: 0,
+                                    collateral_fee_cost: 0,
                                 },
                             });
                         }
@@ -3930,8 +6825,8 @@ impl Orchestrator {
                 }
 
                 let agent = match self.state.get_agent(&agent_id) {
-                    Some(a) => a.clone(),
-                    None => continue,
+                    Some(a) if !a.is_suspended() => a.clone(),
+                    _ => continue,
                 };
 
                 let policy = match self.policies.get_mut(&agent_id) {
@@ -3939,6 +6834,9 @@ impl Orchestrator {
                     None => continue,
                 };
 
+                let cost_rates =
+                    Self::merged_cost_rates(&self.cost_rates, &self.agent_cost_rates, &agent_id);
+
                 // Evaluate policy for each Queue 2 transaction
                 for tx_id in queue2_tx_ids {
                     let tx = match self.state.get_transaction(&tx_id) {
@@ -3957,7 +6855,7 @@ impl Orchestrator {
                         &agent,
                         &self.state,
                         current_tick,
-                        &self.cost_rates,
+                        &cost_rates,
                         self.config.ticks_per_day,
                         self.config.eod_rush_threshold,
                     );
@@ -3968,6 +6866,11 @@ impl Orchestrator {
             decisions
         };
 
+        // Store decision paths from STEP 2 and STEP 2b (opt-in debugging aid)
+        if self.config.record_decision_paths {
+            self.collect_decision_paths(current_tick);
+        }
+
         // Process Queue 2 decisions
         for (agent_id, decision) in queue2_decisions {
             use crate::policy::ReleaseDecision;
@@ -4037,6 +6940,12 @@ impl Orchestrator {
             }
         }
 
+        // STEP 2c: END-OF-DAY FORCE RELEASE
+        // Runs after both policy passes so policies still see the final tick
+        if self.config.force_release_at_eod && self.time_manager.is_end_of_day() {
+            self.force_release_queue1(current_tick);
+        }
+
         // Capture timing for policy evaluation phase
         timing.policy_eval_micros = policy_eval_start.elapsed().as_micros() as u64;
 
@@ -4060,16 +6969,18 @@ impl Orchestrator {
 
                 // Collect overdue data if transaction is overdue
                 let overdue_data = if was_overdue {
+                    let rates = self.cost_rates_for_agent(&sender_id);
                     Some((
                         tx.amount(),                          // total amount
                         tx.deadline_tick(),                   // deadline_tick
                         tx.overdue_since_tick().unwrap(),     // overdue_since_tick
                         current_tick - tx.overdue_since_tick().unwrap(), // total_ticks_overdue
-                        self.cost_rates.deadline_penalty.resolve(tx.amount()),     // deadline_penalty_cost
+                        rates.deadline_penalty.resolve(tx.amount()),     // deadline_penalty_cost
                         // Estimate accumulated delay cost
                         (tx.remaining_amount() as f64
-                            * self.cost_rates.delay_cost_per_tick_per_cent
-                            * self.cost_rates.overdue_delay_multiplier
+                            * rates.delay_cost_per_tick_per_cent
+                            * rates.overdue_delay_multiplier
+                            * rates.delay_multiplier_for_priority(tx.priority())
                             * (current_tick - tx.overdue_since_tick().unwrap()) as f64)
                             .round() as i64,
                     ))
@@ -4136,6 +7047,7 @@ impl Orchestrator {
                         amount,
                         sender_balance_before,
                         sender_balance_after,
+                        settlement_mechanism: SettlementMechanism::RtgsImmediate,
                     });
                 }
                 SettlementOutcome::Queued => {
@@ -4147,6 +7059,19 @@ impl Orchestrator {
                         sender_id,
                     });
                 }
+                SettlementOutcome::Rejected {
+                    queue_size,
+                    capacity,
+                } => {
+                    // Queue 2 at capacity, transaction is back in Queue 1
+                    self.log_event(Event::RtgsQueueFull {
+                        tick: current_tick,
+                        tx_id: tx_id.clone(),
+                        sender_id,
+                        queue_size,
+                        capacity,
+                    });
+                }
             }
         }
 
@@ -4156,14 +7081,15 @@ impl Orchestrator {
         // STEP 4: PROCESS RTGS QUEUE (Queue 2)
         // Retry queued transactions
 
-        // Sort Queue 2 by priority bands if priority_mode is enabled
-        self.sort_queue2_by_priority_bands();
-
+        // Queue 2 is sorted by the configured ordering before retrying
+        let queue2_ordering = self.queue2_ordering();
         let rtgs_queue_start = Instant::now();
-        let queue_result = rtgs::process_queue_with_deferred(
+        let queue_result = rtgs::process_queue_ordered(
             &mut self.state,
             current_tick,
             deferred_credits.as_mut(),
+            self.lsm_config.partial_settlement,
+            queue2_ordering,
         );
         num_settlements += queue_result.settled_count;
 
@@ -4188,9 +7114,27 @@ impl Orchestrator {
                 amount: settled_tx.amount,
                 queue_wait_ticks,
                 release_reason: "liquidity_available".to_string(),
+                settlement_mechanism: SettlementMechanism::RtgsQueue,
             });
         }
 
+        // Emit PartialSettlement events (remainders stay in Queue 2)
+        for partial in &queue_result.partial_settlements {
+            self.log_event(Event::PartialSettlement {
+                tick: current_tick,
+                tx_id: partial.tx_id.clone(),
+                sender: partial.sender_id.clone(),
+                receiver: partial.receiver_id.clone(),
+                settled_amount: partial.amount,
+                remaining_amount: partial.remaining_amount,
+            });
+        }
+
+        // STEP 4b: RETURN TRANSACTIONS THAT EXHAUSTED THEIR RETRIES
+        if let Some(max_retries) = self.config.max_settlement_retries {
+            self.abandon_exhausted_retries(current_tick, max_retries);
+        }
+
         // Capture timing for RTGS queue processing phase
         timing.rtgs_queue_micros = rtgs_queue_start.elapsed().as_micros() as u64;
 
@@ -4209,15 +7153,17 @@ impl Orchestrator {
         }
 
         // STEP 5: LSM COORDINATOR
-        // Find and release offsetting transactions
+        // Find and release offsetting transactions. Runs only every
+        // `lsm_interval_ticks` ticks; in between, Queue 2 simply waits.
         let lsm_start = Instant::now();
+        let lsm_due = current_tick.is_multiple_of(self.lsm_config.lsm_interval_ticks);
 
         // DIAGNOSTIC LOGGING (Test 4 from lsm-splitting-investigation-plan.md)
         // Enable by setting environment variable: LSM_DEBUG=1
         if std::env::var("LSM_DEBUG").is_ok() {
             eprintln!("[LSM DEBUG] Tick {}: Queue 2 (RTGS): {}, Queue 1 (Internal): {}",
                 current_tick, self.state.queue_size(), self.state.total_internal_queue_size());
-            eprintln!("[LSM DEBUG] Tick {}: LSM config: bilateral={}, cycles={}, max_cycle_length={}",
+            eprintln!("[LSM DEBUG] Tick {}: LSM config: bilateral={}, cycles={}, max_cycle_length={:?}",
                 current_tick,
                 self.lsm_config.enable_bilateral,
                 self.lsm_config.enable_cycles,
@@ -4225,15 +7171,24 @@ impl Orchestrator {
             );
         }
 
-        let lsm_result = lsm::run_lsm_pass_with_deferred(
-            &mut self.state,
-            &self.lsm_config,
-            current_tick,
-            self.time_manager.ticks_per_day(),
-            self.config.entry_disposition_offsetting,
-            deferred_credits.as_mut(),
-        );
-        let num_lsm_releases = lsm_result.bilateral_offsets + lsm_result.cycles_settled;
+        let lsm_result = if lsm_due {
+            lsm::run_lsm_pass_with_deferred(
+                &mut self.state,
+                &self.lsm_config,
+                current_tick,
+                self.time_manager.ticks_per_day(),
+                self.config.entry_disposition_offsetting,
+                self.config.entry_offset_min_amount,
+                deferred_credits.as_mut(),
+            )
+        } else {
+            lsm::LsmPassResult {
+                final_queue_size: self.state.queue_size(),
+                ..Default::default()
+            }
+        };
+        let num_lsm_releases =
+            lsm_result.bilateral_offsets + lsm_result.cycles_settled + lsm_result.netting_batches;
         num_settlements += num_lsm_releases;
 
         // STEP 5.5: REBUILD QUEUE 2 INDEX
@@ -4260,63 +7215,7 @@ impl Orchestrator {
             }
         }
 
-        // Store LSM cycle events for persistence (Phase 4.2)
-        self.state
-            .lsm_cycle_events
-            .extend(lsm_result.cycle_events.clone());
-
-        // Log enriched LSM replay events (already contain all fields for display)
-        // These events are created in lsm.rs with complete data and don't need reconstruction
-        let lsm_debug = std::env::var("LSM_DEBUG").is_ok();
-        if lsm_debug {
-            eprintln!("[LSM DEBUG] Logging {} LSM replay events", lsm_result.replay_events.len());
-        }
-        for event in &lsm_result.replay_events {
-            if lsm_debug {
-                eprintln!("[LSM DEBUG] Logging enriched event: {:?}", event.event_type());
-            }
-            self.log_event(event.clone());
-        }
-
-        // Emit Algorithm 2 (Bilateral) and Algorithm 3 (Multilateral) events if algorithm_sequencing is enabled
-        if self.config.algorithm_sequencing {
-            // Algorithm 2: Bilateral offsetting
-            let alg2_settlements = lsm_result.bilateral_offsets;
-            // Count bilateral events in replay events to get settled value
-            // Each bilateral offset has amount_a and amount_b - we sum the smaller (net settlement)
-            let alg2_value: i64 = lsm_result.replay_events.iter()
-                .filter_map(|e| match e {
-                    Event::LsmBilateralOffset { amount_a, amount_b, .. } => Some(std::cmp::min(*amount_a, *amount_b)),
-                    _ => None,
-                })
-                .sum();
-            let alg2_result = if alg2_settlements > 0 { "Success" } else { "NoProgress" };
-            self.log_event(Event::AlgorithmExecution {
-                tick: current_tick,
-                algorithm: 2,
-                result: alg2_result.to_string(),
-                settlements: alg2_settlements,
-                settled_value: alg2_value,
-            });
-
-            // Algorithm 3: Multilateral cycle settlement
-            let alg3_settlements = lsm_result.cycles_settled;
-            // Count cycle events in replay events to get settled value
-            let alg3_value: i64 = lsm_result.replay_events.iter()
-                .filter_map(|e| match e {
-                    Event::LsmCycleSettlement { total_value, .. } => Some(*total_value),
-                    _ => None,
-                })
-                .sum();
-            let alg3_result = if alg3_settlements > 0 { "Success" } else { "NoProgress" };
-            self.log_event(Event::AlgorithmExecution {
-                tick: current_tick,
-                algorithm: 3,
-                result: alg3_result.to_string(),
-                settlements: alg3_settlements,
-                settled_value: alg3_value,
-            });
-        }
+        self.record_lsm_pass(&lsm_result, current_tick, lsm_due);
 
         // STEP 5.5: END-OF-TICK COLLATERAL MANAGEMENT (Layer 2)
         // Evaluate end-of-tick collateral decisions for each agent AFTER settlements complete
@@ -4324,6 +7223,10 @@ impl Orchestrator {
         let all_agent_ids: Vec<String> = self.state.agents().keys().cloned().collect();
 
         for agent_id in all_agent_ids {
+            if self.is_agent_suspended(&agent_id) {
+                continue;
+            }
+
             // Get agent
             let agent = self
                 .state
@@ -4348,8 +7251,10 @@ impl Orchestrator {
                 })?;
 
             // Evaluate END-OF-TICK collateral decision (Layer 2)
+            let cost_rates =
+                Self::merged_cost_rates(&self.cost_rates, &self.agent_cost_rates, &agent_id);
             let decision = tree_policy
-                .evaluate_end_of_tick_collateral(agent, &self.state, current_tick, &self.cost_rates, self.config.ticks_per_day, self.config.eod_rush_threshold)
+                .evaluate_end_of_tick_collateral(agent, &self.state, current_tick, &cost_rates, self.config.ticks_per_day, self.config.eod_rush_threshold)
                 .map_err(|e| {
                     SimulationError::InvalidConfig(format!(
                         "Failed to evaluate end-of-tick collateral for {}: {}",
@@ -4415,6 +7320,8 @@ impl Orchestrator {
                         reason: format!("{:?}", reason),
                         new_total: new_collateral,
                     });
+
+                    self.charge_collateral_post_fee(&agent_id, current_tick);
                 }
                 CollateralDecision::Withdraw { amount, reason } => {
                     // Validate amount is positive
@@ -4427,7 +7334,8 @@ impl Orchestrator {
 
                     // Get agent to check available collateral
                     let agent = self.state.get_agent(&agent_id).unwrap();
-                    let posted = agent.posted_collateral();
+                    // Collateral classes stay pledged; only the pool is withdrawable
+                    let posted = agent.pool_collateral();
 
                     // Clamp withdrawal amount to what's actually available
                     // This handles race conditions where collateral changes between
@@ -4475,17 +7383,7 @@ impl Orchestrator {
         // STEP 5.7: APPLY DEFERRED CREDITS (deferred crediting mode)
         // If deferred crediting is enabled, accumulated credits are applied at end of tick
         // This prevents "within-tick recycling" where incoming payments fund outgoing payments
-        if let Some(ref mut dc) = deferred_credits {
-            if !dc.is_empty() {
-                // Apply all accumulated credits and get the events
-                let credit_events = dc.apply_all(&mut self.state, current_tick);
-
-                // Log each deferred credit event
-                for event in credit_events {
-                    self.log_event(event);
-                }
-            }
-        }
+        self.apply_deferred_credits(deferred_credits.as_mut(), current_tick);
 
         // STEP 6: COST ACCRUAL (Phase 4b.3 - minimal for now)
         let cost_accrual_start = Instant::now();
@@ -4494,8 +7392,17 @@ impl Orchestrator {
         // Capture timing for cost accrual phase
         timing.cost_accrual_micros = cost_accrual_start.elapsed().as_micros() as u64;
 
-        // STEP 7: DEADLINE ENFORCEMENT (handled by policies in STEP 2)
-        // Policies drop expired transactions via ReleaseDecision::Drop
+        // STEP 7: DEADLINE ENFORCEMENT
+        // Policies drop expired transactions via ReleaseDecision::Drop (STEP 2).
+        // In hard cutoff mode, overdue transactions (penalized above when they
+        // went overdue) are force-settled now.
+        if self.config.deadline_enforcement == DeadlineEnforcement::PenalizeAndForceSettle {
+            num_settlements += self.force_settle_overdue(current_tick, deferred_credits.as_mut())?;
+            // Forced credits to deferred receivers land at end of tick as well
+            self.apply_deferred_credits(deferred_credits.as_mut(), current_tick);
+            // Forced settlements happen after cost accrual; charge their lateness now
+            total_cost += self.charge_late_settlement_penalties(current_tick);
+        }
 
         // STEP 8: END-OF-DAY HANDLING (before advancing time)
         // Check if current tick is the last tick of the day
@@ -4507,21 +7414,168 @@ impl Orchestrator {
         // STEP 9: ADVANCE TIME
         self.time_manager.advance_tick();
 
-        // STEP 9.5: UPDATE DAILY METRICS (Phase 3: Agent Metrics Collection)
-        // Track balance changes, queue sizes, and collateral for all agents
-        self.update_tick_metrics();
+        // Close this tick's settled flows into the trailing rate window
+        self.state.advance_flow_window();
+        let throughput = self.state.take_tick_throughput();
+        if self.config.record_throughput_series {
+            self.throughput_series.push(throughput);
+        }
+
+        // STEP 9.5: UPDATE DAILY METRICS (Phase 3: Agent Metrics Collection)
+        // Track balance changes, queue sizes, and collateral for all agents
+        self.update_tick_metrics();
+
+        if self.config.record_balance_series {
+            for (agent_id, agent) in self.state.agents() {
+                self.balance_series
+                    .entry(agent_id.clone())
+                    .or_default()
+                    .push(agent.balance());
+            }
+        }
+
+        // Optional post-tick self-check (fuzzing / debugging aid)
+        if self.config.debug_invariant_checks {
+            self.state
+                .validate_invariants()
+                .map_err(|e| SimulationError::StateValidationError(format!("after tick {}: {}", current_tick, e)))?;
+        }
+
+        // Calculate total timing
+        timing.total_micros = tick_start.elapsed().as_micros() as u64;
+
+        let result = TickResult {
+            tick: current_tick,
+            num_arrivals,
+            num_settlements,
+            num_lsm_releases,
+            total_cost,
+            timing,
+        };
+
+        // STEP 10: RECORD RESULT AND NOTIFY OBSERVER
+        // Both are read-only with respect to simulation logic
+        if self.recent_tick_results.len() == RECENT_TICK_RESULTS_CAPACITY {
+            self.recent_tick_results.pop_front();
+        }
+        self.recent_tick_results.push_back(result.clone());
+
+        if let Some(observer) = self.on_tick.as_mut() {
+            observer(&result, &self.state);
+        }
+
+        Ok(result)
+    }
+
+    /// Register a callback invoked at the end of every tick
+    ///
+    /// Replaces any previously registered observer. The observer only gets
+    /// shared references, so it cannot affect simulation results.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// orchestrator.set_tick_observer(Box::new(|result, state| {
+    ///     println!("tick {}: queue2={}", result.tick, state.queue_size());
+    /// }));
+    /// ```
+    pub fn set_tick_observer(&mut self, observer: TickObserver) {
+        self.on_tick = Some(observer);
+    }
+
+    /// Remove the registered tick observer, if any
+    pub fn clear_tick_observer(&mut self) {
+        self.on_tick = None;
+    }
+
+    /// Get up to `n` of the most recent tick results, oldest first
+    ///
+    /// At most `RECENT_TICK_RESULTS_CAPACITY` results are retained.
+    pub fn recent_tick_results(&self, n: usize) -> Vec<TickResult> {
+        let skip = self.recent_tick_results.len().saturating_sub(n);
+        self.recent_tick_results.iter().skip(skip).cloned().collect()
+    }
+
+    /// Get the payment tree path behind a transaction's decision at `tick`
+    ///
+    /// Returns `None` unless `record_decision_paths` is enabled and the
+    /// transaction's policy was evaluated at that tick.
+    pub fn get_decision_path(&self, tick: usize, tx_id: &str) -> Option<DecisionPath> {
+        self.decision_paths.get(&(tick, tx_id.to_string())).cloned()
+    }
+
+    /// Get an agent's end-of-tick balance series, oldest first
+    ///
+    /// One entry per tick executed since construction (or checkpoint
+    /// restore). Returns `None` unless `record_balance_series` is enabled
+    /// and at least one tick has run, or for unknown agents.
+    pub fn balance_series(&self, agent_id: &str) -> Option<&[i64]> {
+        self.balance_series.get(agent_id).map(|series| series.as_slice())
+    }
+
+    /// Get the network-wide `(settled_value, settlement_count)` series, oldest first
+    ///
+    /// One entry per tick executed since construction (or checkpoint
+    /// restore). Value is what actually moved, so partial settlements and
+    /// split children contribute their settled amounts, and each settlement
+    /// posting (including every partial chunk) counts once. Empty unless
+    /// `record_throughput_series` is enabled.
+    pub fn throughput_series(&self) -> &[(i64, usize)] {
+        &self.throughput_series
+    }
+
+    /// Move decision paths buffered by the agents' policies into the store
+    fn collect_decision_paths(&mut self, tick: usize) {
+        for policy in self.policies.values_mut() {
+            if let Some(tree_policy) = policy
+                .as_any_mut()
+                .downcast_mut::<crate::policy::tree::TreePolicy>()
+            {
+                for (tx_id, path) in tree_policy.take_decision_paths() {
+                    self.decision_paths.insert((tick, tx_id), path);
+                }
+            }
+        }
+    }
+
+    /// Execute up to `n` ticks and return their results in order
+    ///
+    /// Runs the tick loop internally, avoiding per-tick call overhead for
+    /// callers (e.g. the FFI layer) that only need the aggregated results.
+    /// Stops at the first failing tick and propagates its error; ticks that
+    /// completed before the failure remain applied to the simulation state.
+    ///
+    /// # Example
+    ///
+    /// ```rust,ignore
+    /// let results = orchestrator.run_ticks(100)?;
+    /// let settled: usize = results.iter().map(|r| r.num_settlements).sum();
+    /// ```
+    pub fn run_ticks(&mut self, n: usize) -> Result<Vec<TickResult>, SimulationError> {
+        let mut results = Vec::with_capacity(n);
+        for _ in 0..n {
+            results.push(self.tick()?);
+        }
+        Ok(results)
+    }
+
+    /// Execute all remaining ticks up to the end of the simulation
+    ///
+    /// The terminal tick is `num_days * ticks_per_day`. Returns an empty
+    /// vector (and does nothing) if the simulation is already complete.
+    pub fn run_to_end(&mut self) -> Result<Vec<TickResult>, SimulationError> {
+        let remaining = self.total_ticks().saturating_sub(self.current_tick());
+        self.run_ticks(remaining)
+    }
 
-        // Calculate total timing
-        timing.total_micros = tick_start.elapsed().as_micros() as u64;
+    /// Total number of ticks in the simulation (`num_days * ticks_per_day`)
+    pub fn total_ticks(&self) -> usize {
+        self.config.num_days * self.config.ticks_per_day
+    }
 
-        Ok(TickResult {
-            tick: current_tick,
-            num_arrivals,
-            num_settlements,
-            num_lsm_releases,
-            total_cost,
-            timing,
-        })
+    /// Check whether the simulation has reached its terminal tick
+    pub fn is_complete(&self) -> bool {
+        self.current_tick() >= self.total_ticks()
     }
 
     /// Accrue costs for this tick
@@ -4538,6 +7592,8 @@ impl Orchestrator {
         // Collect agent IDs first to avoid borrow checker issues
         let agent_ids: Vec<String> = self.state.agents().keys().cloned().collect();
 
+        let late_settlement_penalties = self.take_late_settlement_penalties();
+
         for agent_id in agent_ids {
             // First pass: collect data and identify newly overdue transactions
             // Check both Queue 1 (agent's outgoing queue) and Queue 2 (RTGS queue)
@@ -4566,10 +7622,16 @@ impl Orchestrator {
                     }
                 }
 
-                (agent.balance(), agent.posted_collateral(), agent.allocated_liquidity(), overdue)
+                let collateral = match self.cost_rates_for_agent(&agent_id).collateral_cost_basis {
+                    CollateralCostBasis::Posted => agent.posted_collateral(),
+                    CollateralCostBasis::PostHaircut => agent.collateral_capacity(),
+                };
+
+                (agent.balance(), collateral, agent.allocated_liquidity(), overdue)
             };
 
             // Mark transactions as overdue and emit events (mutable borrow, agent borrow released)
+            let deadline_penalty = self.cost_rates_for_agent(&agent_id).deadline_penalty.clone();
             for tx_id in &newly_overdue_txs {
                 if let Some(tx_mut) = self.state.get_transaction_mut(tx_id) {
                     // Collect transaction data before marking overdue
@@ -4583,7 +7645,7 @@ impl Orchestrator {
                     tx_mut.mark_overdue(tick).ok();
 
                     // Resolve deadline penalty for this transaction
-                    let resolved_penalty = self.cost_rates.deadline_penalty.resolve(amount);
+                    let resolved_penalty = deadline_penalty.resolve(amount);
 
                     // Emit event
                     self.log_event(Event::TransactionWentOverdue {
@@ -4605,20 +7667,20 @@ impl Orchestrator {
                 let mut total = 0i64;
                 for tx_id in &newly_overdue_txs {
                     if let Some(tx) = self.state.get_transaction(tx_id) {
-                        total += self.cost_rates.deadline_penalty.resolve(tx.amount());
+                        total += deadline_penalty.resolve(tx.amount());
                     }
                 }
                 total
-            };
+            } + late_settlement_penalties.get(&agent_id).copied().unwrap_or(0);
 
             // Calculate overdraft cost (liquidity cost)
-            let liquidity_cost = self.calculate_overdraft_cost(balance);
+            let liquidity_cost = self.calculate_overdraft_cost(&agent_id, balance);
 
             // Calculate delay cost for queued transactions
             let delay_cost = self.calculate_delay_cost(&agent_id);
 
             // Calculate collateral opportunity cost (Phase 8)
-            let collateral_cost = self.calculate_collateral_cost(collateral);
+            let collateral_cost = self.calculate_collateral_cost(&agent_id, collateral);
 
             // Split friction cost handled at decision time
             let split_friction_cost = 0;
@@ -4626,7 +7688,8 @@ impl Orchestrator {
             // Calculate liquidity opportunity cost (Enhancement 11.2)
             // This is the opportunity cost of allocated liquidity sitting in settlement
             // Formula: allocated_liquidity × (liquidity_cost_per_tick_bps / 10,000)
-            let liquidity_opportunity_cost = self.calculate_liquidity_opportunity_cost(allocated_liquidity);
+            let liquidity_opportunity_cost =
+                self.calculate_liquidity_opportunity_cost(&agent_id, allocated_liquidity);
 
             let costs = CostBreakdown {
                 liquidity_cost,
@@ -4635,6 +7698,8 @@ impl Orchestrator {
                 penalty_cost,
                 split_friction_cost,
                 liquidity_opportunity_cost,
+                overnight_interest: 0,
+                collateral_fee_cost: 0,
             };
 
             // Accumulate costs
@@ -4658,10 +7723,64 @@ impl Orchestrator {
         total_cost
     }
 
+    /// Per-tick lateness penalties for settlements recorded since the last call
+    ///
+    /// Every settlement path reports transactions that settle after their
+    /// deadline (`SimulationState::record_settlement`); each charges its
+    /// sender `deadline_penalty_per_tick_late × (settle_tick - deadline_tick)`
+    /// using the sender's rates (see `cost_rates_for_agent`). The one-time
+    /// `deadline_penalty` is charged separately when the transaction goes
+    /// overdue. Split children are charged, never their parent.
+    fn take_late_settlement_penalties(&mut self) -> HashMap<String, i64> {
+        let mut penalties = HashMap::new();
+        for (sender_id, ticks_late) in self.state.take_late_settlements() {
+            let per_tick_late = self
+                .cost_rates_for_agent(&sender_id)
+                .deadline_penalty_per_tick_late;
+            if per_tick_late == 0 {
+                continue;
+            }
+            *penalties.entry(sender_id).or_insert(0) +=
+                per_tick_late.saturating_mul(ticks_late as i64);
+        }
+        penalties
+    }
+
+    /// Charge lateness penalties for settlements made after cost accrual
+    ///
+    /// Used after forced settlement (STEP 7). Returns the total charged.
+    fn charge_late_settlement_penalties(&mut self, tick: usize) -> i64 {
+        let mut penalties: Vec<(String, i64)> =
+            self.take_late_settlement_penalties().into_iter().collect();
+        penalties.sort();
+
+        let mut total = 0;
+        for (agent_id, penalty_cost) in penalties {
+            let costs = CostBreakdown {
+                penalty_cost,
+                ..CostBreakdown::default()
+            };
+            if let Some(accumulator) = self.accumulated_costs.get_mut(&agent_id) {
+                accumulator.add(&costs);
+            }
+            total += penalty_cost;
+            self.log_event(Event::CostAccrual {
+                tick,
+                agent_id,
+                costs,
+            });
+        }
+        total
+    }
+
     /// Calculate overdraft cost for a given balance using integer-only arithmetic
     ///
     /// Overdraft cost = max(0, -balance) * overdraft_bps_per_tick / 10,000
     ///
+    /// When `liquidity_cost_tiers` is configured, the overdraft is decomposed
+    /// across the tiers instead: each tier's rate applies only to the slice of
+    /// overdraft between its threshold and the next tier's threshold.
+    ///
     /// CRITICAL INVARIANT: Money is always i64. This function uses integer-only
     /// arithmetic to avoid NaN/Inf/precision issues from f64→i64 casts.
     ///
@@ -4670,10 +7789,11 @@ impl Orchestrator {
     ///
     /// Example: -$500,000 balance at 1 bps/tick = 500,000 * 1 / 10,000 = 50 cents
     /// Example: -$117,679.26 at 0.8 bps/tick = 11,767,926 * 0.8 / 10,000 = 941 cents
-    fn calculate_overdraft_cost(&self, balance: i64) -> i64 {
+    fn calculate_overdraft_cost(&self, agent_id: &str, balance: i64) -> i64 {
         if balance >= 0 {
             return 0;
         }
+        let rates = self.cost_rates_for_agent(agent_id);
 
         let overdraft_amount = (-balance) as u128;
 
@@ -4684,25 +7804,46 @@ impl Orchestrator {
         // CRITICAL: Guard against NaN/Inf before casting to integer
         // NaN/Inf comparisons are false, so .max(0.0) doesn't help
         // f64 as u128 for NaN/Inf is undefined behavior
-        let rate = self.cost_rates.overdraft_bps_per_tick;
-        let bps_rate_scaled = if rate.is_finite() && rate >= 0.0 {
-            (rate * BPS_SCALE as f64).round() as u128
-        } else {
-            0
+        let scale_rate = |rate: f64| -> u128 {
+            if rate.is_finite() && rate >= 0.0 {
+                (rate * BPS_SCALE as f64).round() as u128
+            } else {
+                0
+            }
         };
 
-        if bps_rate_scaled == 0 {
+        // Sum of (overdraft slice × scaled rate), divided once below
+        let numerator = match &rates.liquidity_cost_tiers {
+            Some(tiers) => {
+                let mut total: u128 = 0;
+                for (i, (threshold, rate)) in tiers.iter().enumerate() {
+                    let lower = (*threshold).max(0) as u128;
+                    let upper = tiers
+                        .get(i + 1)
+                        .map(|(next, _)| (*next).max(0) as u128)
+                        .unwrap_or(u128::MAX);
+                    if overdraft_amount <= lower {
+                        break;
+                    }
+                    let slice = overdraft_amount.min(upper) - lower;
+                    total = total.saturating_add(slice * scale_rate(*rate));
+                }
+                total
+            }
+            None => overdraft_amount * scale_rate(rates.overdraft_bps_per_tick),
+        };
+
+        if numerator == 0 {
             return 0;
         }
 
-        // Integer-only calculation: amount * bps_rate_scaled / (10,000 * BPS_SCALE)
+        // Integer-only calculation: numerator / (10,000 * BPS_SCALE)
         // Combined divisor: 10,000 * 1,000 = 10,000,000
         const COMBINED_DIVISOR: u128 = 10_000 * BPS_SCALE;
 
         // Compute with rounding: add half the divisor before dividing (round half up)
-        let numerator = overdraft_amount * bps_rate_scaled;
         let half_divisor = COMBINED_DIVISOR / 2;
-        let result = (numerator + half_divisor) / COMBINED_DIVISOR;
+        let result = numerator.saturating_add(half_divisor) / COMBINED_DIVISOR;
 
         // Clamp to i64 range
         result.min(i64::MAX as u128) as i64
@@ -4739,7 +7880,8 @@ impl Orchestrator {
         const RATE_SCALE: u128 = 1_000_000;
 
         // CRITICAL: Guard against NaN/Inf before casting to integer
-        let delay_rate = self.cost_rates.delay_cost_per_tick_per_cent;
+        let rates = self.cost_rates_for_agent(agent_id);
+        let delay_rate = rates.delay_cost_per_tick_per_cent;
         let rate_scaled = if delay_rate.is_finite() && delay_rate >= 0.0 {
             (delay_rate * RATE_SCALE as f64).round() as u128
         } else {
@@ -4752,7 +7894,7 @@ impl Orchestrator {
 
         // Pre-compute overdue multiplier (scaled)
         // CRITICAL: Guard against NaN/Inf
-        let overdue_rate = self.cost_rates.overdue_delay_multiplier;
+        let overdue_rate = rates.overdue_delay_multiplier;
         let overdue_mult_scaled = if overdue_rate.is_finite() && overdue_rate >= 0.0 {
             (overdue_rate * MULT_SCALE as f64).round() as u128
         } else {
@@ -4774,19 +7916,13 @@ impl Orchestrator {
                 normal_mult
             };
 
-            // Apply priority-based multiplier if configured (Enhancement 11.1)
-            // CRITICAL: Guard against NaN/Inf
-            let priority_mult_scaled = self.cost_rates.priority_delay_multipliers
-                .as_ref()
-                .map(|m| {
-                    let mult = m.get_multiplier_for_priority(tx.priority());
-                    if mult.is_finite() && mult >= 0.0 {
-                        (mult * MULT_SCALE as f64).round() as u128
-                    } else {
-                        MULT_SCALE // Default to 1.0 if invalid
-                    }
-                })
-                .unwrap_or(MULT_SCALE);
+            // Apply priority-based multiplier for the transaction's current
+            // (possibly escalated) priority band (Enhancement 11.1).
+            // The helper falls back to 1.0 for NaN/Inf/negative multipliers.
+            let priority_mult_scaled = (rates
+                .delay_multiplier_for_priority(tx.priority())
+                * MULT_SCALE as f64)
+                .round() as u128;
 
             // Combine multipliers: (overdue * priority) / MULT_SCALE
             // This keeps one MULT_SCALE factor
@@ -4827,6 +7963,9 @@ impl Orchestrator {
     ///
     /// Collateral cost = posted_collateral * collateral_cost_per_tick_bps / 10,000
     ///
+    /// `posted_collateral` is the raw pledged value or the post-haircut
+    /// capacity, depending on `CostRates::collateral_cost_basis`.
+    ///
     /// CRITICAL INVARIANT: Money is always i64. This function uses integer-only
     /// arithmetic to avoid NaN/Inf/precision issues from f64→i64 casts.
     ///
@@ -4837,7 +7976,7 @@ impl Orchestrator {
     /// rather than deployed in other earning activities.
     ///
     /// Example: $1,000,000 collateral at 2 bps/tick = $1M × 2 / 10,000 = $200 = 20,000 cents
-    fn calculate_collateral_cost(&self, posted_collateral: i64) -> i64 {
+    fn calculate_collateral_cost(&self, agent_id: &str, posted_collateral: i64) -> i64 {
         if posted_collateral <= 0 {
             return 0;
         }
@@ -4847,7 +7986,7 @@ impl Orchestrator {
         const BPS_SCALE: u128 = 1_000_000;
 
         // CRITICAL: Guard against NaN/Inf before casting to integer
-        let rate = self.cost_rates.collateral_cost_per_tick_bps;
+        let rate = self.cost_rates_for_agent(agent_id).collateral_cost_per_tick_bps;
         let bps_rate_scaled = if rate.is_finite() && rate >= 0.0 {
             (rate * BPS_SCALE as f64).round() as u128
         } else {
@@ -4891,7 +8030,7 @@ impl Orchestrator {
     ///
     /// Note: This only applies to liquidity allocated from liquidity_pool,
     /// not to opening_balance (which is assumed to already be at the central bank).
-    fn calculate_liquidity_opportunity_cost(&self, allocated_liquidity: i64) -> i64 {
+    fn calculate_liquidity_opportunity_cost(&self, agent_id: &str, allocated_liquidity: i64) -> i64 {
         if allocated_liquidity <= 0 {
             return 0;
         }
@@ -4900,7 +8039,7 @@ impl Orchestrator {
         const BPS_SCALE: u128 = 1_000_000;
 
         // CRITICAL: Guard against NaN/Inf before casting to integer
-        let rate = self.cost_rates.liquidity_cost_per_tick_bps;
+        let rate = self.cost_rates_for_agent(agent_id).liquidity_cost_per_tick_bps;
         let bps_rate_scaled = if rate.is_finite() && rate >= 0.0 {
             (rate * BPS_SCALE as f64).round() as u128
         } else {
@@ -4930,7 +8069,13 @@ impl Orchestrator {
     ///
     /// When queue1_ordering is PriorityDeadline:
     /// - Higher priority transactions come first (descending)
-    /// - For same priority, earlier deadline comes first (ascending)
+    /// - For same priority, lower `order_hint` comes first (ascending)
+    /// - For same hint, earlier deadline comes first (ascending)
+    /// - For same deadline, earlier arrival comes first (ascending)
+    /// - Remaining ties keep their current queue order (stable sort)
+    ///
+    /// Transaction IDs are random UUIDs, so they are deliberately not used
+    /// as a tie-breaker: queue order is reproducible for a given seed.
     ///
     /// When queue1_ordering is Fifo (default):
     /// - No sorting - maintains insertion order
@@ -4952,30 +8097,37 @@ impl Orchestrator {
             return; // Nothing to sort
         }
 
-        // Collect (tx_id, priority, deadline) for sorting
-        let mut tx_info: Vec<(String, u8, usize)> = queue
+        // Collect (tx_id, priority, order_hint, deadline, arrival) for sorting
+        let mut tx_info: Vec<(String, u8, i64, usize, usize)> = queue
             .iter()
             .filter_map(|tx_id| {
                 self.state.get_transaction(tx_id).map(|tx| {
-                    (tx_id.clone(), tx.priority(), tx.deadline_tick())
+                    (
+                        tx_id.clone(),
+                        tx.priority(),
+                        tx.order_hint(),
+                        tx.deadline_tick(),
+                        tx.arrival_tick(),
+                    )
                 })
             })
             .collect();
 
-        // Sort by priority (descending), then deadline (ascending)
+        // Sort by priority (descending), then order hint, deadline and
+        // arrival (ascending). sort_by is stable, so full ties keep queue order.
         tx_info.sort_by(|a, b| {
             // Higher priority first
-            match b.1.cmp(&a.1) {
-                std::cmp::Ordering::Equal => {
-                    // Same priority: earlier deadline first
-                    a.2.cmp(&b.2)
-                }
-                other => other,
-            }
+            b.1.cmp(&a.1)
+                // Same priority: lower order hint first
+                .then(a.2.cmp(&b.2))
+                // Same hint: earlier deadline first
+                .then(a.3.cmp(&b.3))
+                // Same deadline: earlier arrival first
+                .then(a.4.cmp(&b.4))
         });
 
         // Extract sorted tx_ids
-        let sorted_ids: Vec<String> = tx_info.into_iter().map(|(id, _, _)| id).collect();
+        let sorted_ids: Vec<String> = tx_info.into_iter().map(|(id, ..)| id).collect();
 
         // Replace agent's queue with sorted version
         if let Some(agent) = self.state.get_agent_mut(agent_id) {
@@ -4989,13 +8141,12 @@ impl Orchestrator {
     /// priorities as their deadlines approach. This prevents low-priority transactions
     /// from being starved when they become urgent due to time pressure.
     ///
-    /// # Escalation Formula (linear)
+    /// # Escalation Formula
     ///
     /// ```text
     /// ticks_remaining = deadline - current_tick
     /// if ticks_remaining <= start_escalating_at_ticks:
-    ///     progress = 1 - (ticks_remaining / start_escalating_at_ticks)
-    ///     boost = max_boost * progress
+    ///     boost = PriorityEscalationConfig::boost(ticks_remaining)
     ///     new_priority = min(10, original_priority + boost)
     /// ```
     fn apply_priority_escalation(&mut self, current_tick: usize) {
@@ -5004,12 +8155,11 @@ impl Orchestrator {
         }
 
         let start_at = self.config.priority_escalation.start_escalating_at_ticks;
-        let max_boost = self.config.priority_escalation.max_boost;
 
         // Collect all transaction IDs that need escalation, along with their original priority
         let tx_data: Vec<(String, String, u8, usize)> = self.state.transactions()
             .iter()
-            .filter(|(_, tx)| !tx.is_fully_settled())
+            .filter(|(_, tx)| !tx.is_fully_settled() && !tx.is_cancelled() && !tx.is_returned())
             .map(|(id, tx)| (id.clone(), tx.sender_id().to_string(), tx.original_priority(), tx.deadline_tick()))
             .collect();
 
@@ -5022,15 +8172,8 @@ impl Orchestrator {
 
             // Only escalate if within the escalation window
             if ticks_remaining <= start_at {
-                // Calculate progress through escalation window (0.0 to 1.0)
-                let progress = if start_at > 0 {
-                    1.0 - (ticks_remaining as f64 / start_at as f64)
-                } else {
-                    1.0
-                };
-
-                // Calculate boost (linear curve)
-                let boost = (max_boost as f64 * progress).round() as u8;
+                // Calculate boost along the configured curve
+                let boost = self.config.priority_escalation.boost(ticks_remaining);
 
                 // Apply boost to original priority, capped at 10
                 let escalated_priority = std::cmp::min(10, original_priority.saturating_add(boost));
@@ -5066,68 +8209,261 @@ impl Orchestrator {
         }
     }
 
-    /// Sort Queue 2 (RTGS queue) by T2-style priority bands
-    ///
-    /// When `priority_mode` is enabled, Queue 2 is sorted by priority bands:
-    /// - Urgent (8-10): Processed first
-    /// - Normal (4-7): Processed second
-    /// - Low (0-3): Processed last
-    ///
-    /// Within each band, FIFO ordering is preserved (original insertion order).
+    /// Release transactions whose scheduled target tick has arrived
+    ///
+    /// Moves each due transaction from Queue 1 to pending settlements (like a
+    /// policy `SubmitFull`) and logs a `ScheduledReleaseTriggered` event.
+    /// Scheduled releases are commitments made on an earlier tick, so they
+    /// are not re-checked against the bank's release budget. Transactions no
+    /// longer in Queue 1 (settled or dropped in the meantime) are skipped.
+    /// Releases of suspended senders stay scheduled until the sender resumes.
+    fn trigger_scheduled_releases(&mut self, current_tick: usize) {
+        let due_ticks: Vec<usize> = self
+            .scheduled_releases
+            .range(..=current_tick)
+            .map(|(tick, _)| *tick)
+            .collect();
+
+        for scheduled_tick in due_ticks {
+            let tx_ids = self.scheduled_releases.remove(&scheduled_tick).unwrap_or_default();
+            let mut held = Vec::new();
+
+            for tx_id in tx_ids {
+                let (sender_id, receiver_id, amount) = match self.state.get_transaction(&tx_id) {
+                    Some(tx) => (
+                        tx.sender_id().to_string(),
+                        tx.receiver_id().to_string(),
+                        tx.remaining_amount(),
+                    ),
+                    None => continue,
+                };
+
+                let still_queued = self
+                    .state
+                    .get_agent(&sender_id)
+                    .map(|agent| agent.outgoing_queue().contains(&tx_id))
+                    .unwrap_or(false);
+                if !still_queued {
+                    continue;
+                }
+
+                if self.is_agent_suspended(&sender_id) {
+                    held.push(tx_id);
+                    continue;
+                }
+
+                if let Some(agent) = self.state.get_agent_mut(&sender_id) {
+                    agent.remove_from_queue(&tx_id);
+                    agent.track_release(&receiver_id, amount);
+                }
+
+                let (rtgs_priority, internal_priority) = match self.state.get_transaction_mut(&tx_id) {
+                    Some(tx) => {
+                        let priority = tx.declared_rtgs_priority().unwrap_or(RtgsPriority::Normal);
+                        tx.set_rtgs_priority(priority, current_tick);
+                        (priority.to_string(), tx.priority())
+                    }
+                    None => continue,
+                };
+
+                self.pending_settlements.push(tx_id.clone());
+
+                self.log_event(Event::ScheduledReleaseTriggered {
+                    tick: current_tick,
+                    agent_id: sender_id.clone(),
+                    tx_id: tx_id.clone(),
+                    amount,
+                    scheduled_tick,
+                });
+
+                self.log_event(Event::RtgsSubmission {
+                    tick: current_tick,
+                    tx_id,
+                    sender: sender_id,
+                    receiver: receiver_id,
+                    amount,
+                    internal_priority,
+                    rtgs_priority,
+                });
+            }
+
+            if !held.is_empty() {
+                self.scheduled_releases.insert(scheduled_tick, held);
+            }
+        }
+    }
+
+    /// Submit every transaction left in Queue 1 to RTGS (end-of-day finality)
     ///
-    /// # Priority Bands (T2-style)
+    /// Bypasses policy and release budgets. Suspended agents keep their
+    /// Queue 1, and pending scheduled releases are superseded. Agents are
+    /// processed in ID order, transactions in Queue 1 order.
+    fn force_release_queue1(&mut self, current_tick: usize) {
+        let agent_ids: Vec<String> = self
+            .state
+            .agents_with_queued_transactions()
+            .into_iter()
+            .filter(|agent_id| !self.is_agent_suspended(agent_id))
+            .collect();
+
+        for agent_id in agent_ids {
+            let tx_ids = match self.state.get_agent(&agent_id) {
+                Some(agent) => agent.outgoing_queue().to_vec(),
+                None => continue,
+            };
+
+            for tx_id in tx_ids {
+                let (receiver_id, amount) = match self.state.get_transaction(&tx_id) {
+                    Some(tx) => (tx.receiver_id().to_string(), tx.remaining_amount()),
+                    None => continue,
+                };
+
+                for scheduled in self.scheduled_releases.values_mut() {
+                    scheduled.retain(|id| id != &tx_id);
+                }
+
+                if let Some(agent) = self.state.get_agent_mut(&agent_id) {
+                    agent.remove_from_queue(&tx_id);
+                    agent.track_release(&receiver_id, amount);
+                }
+
+                let (rtgs_priority, internal_priority) = match self.state.get_transaction_mut(&tx_id) {
+                    Some(tx) => {
+                        let priority = tx.declared_rtgs_priority().unwrap_or(RtgsPriority::Normal);
+                        tx.set_rtgs_priority(priority, current_tick);
+                        (priority.to_string(), tx.priority())
+                    }
+                    None => continue,
+                };
+
+                self.pending_settlements.push(tx_id.clone());
+
+                self.log_event(Event::EodForceRelease {
+                    tick: current_tick,
+                    agent_id: agent_id.clone(),
+                    tx_id: tx_id.clone(),
+                    amount,
+                });
+
+                self.log_event(Event::RtgsSubmission {
+                    tick: current_tick,
+                    tx_id,
+                    sender: agent_id.clone(),
+                    receiver: receiver_id,
+                    amount,
+                    internal_priority,
+                    rtgs_priority,
+                });
+            }
+        }
+
+        self.scheduled_releases.retain(|_, tx_ids| !tx_ids.is_empty());
+    }
+
+    /// Remove Queue 2 transactions with `max_retries` failed retries and return them
     ///
-    /// | Band   | Priority | Description |
-    /// |--------|----------|-------------|
-    /// | Urgent | 8-10     | Time-critical payments, securities settlement |
-    /// | Normal | 4-7      | Standard interbank payments |
-    /// | Low    | 0-3      | Discretionary payments |
-    fn sort_queue2_by_priority_bands(&mut self) {
-        // Only sort if priority_mode is enabled
-        if !self.config.priority_mode {
+    /// Each is marked `TransactionStatus::Returned` and logged as
+    /// `SettlementAbandoned`, in Queue 2 order.
+    fn abandon_exhausted_retries(&mut self, current_tick: usize, max_retries: usize) {
+        let exhausted: Vec<String> = self
+            .state
+            .rtgs_queue()
+            .iter()
+            .filter(|tx_id| {
+                self.state
+                    .get_transaction(tx_id)
+                    .is_some_and(|tx| tx.retry_count() >= max_retries)
+            })
+            .cloned()
+            .collect();
+        if exhausted.is_empty() {
             return;
         }
 
-        let queue = self.state.rtgs_queue().clone();
-        if queue.len() <= 1 {
-            return; // Nothing to sort
+        self.state
+            .rtgs_queue_mut()
+            .retain(|tx_id| !exhausted.contains(tx_id));
+        self.state.rebuild_queue2_index();
+
+        for tx_id in exhausted {
+            let tx = match self.state.get_transaction_mut(&tx_id) {
+                Some(tx) => tx,
+                None => continue,
+            };
+            if tx.mark_returned(current_tick).is_err() {
+                continue;
+            }
+            let sender_id = tx.sender_id().to_string();
+            let receiver_id = tx.receiver_id().to_string();
+            let remaining_amount = tx.remaining_amount();
+            let retry_count = tx.retry_count();
+
+            if let Some(receiver) = self.state.get_agent_mut(&receiver_id) {
+                receiver.remove_expected_inflow(&tx_id);
+            }
+
+            self.log_event(Event::SettlementAbandoned {
+                tick: current_tick,
+                tx_id,
+                sender_id,
+                receiver_id,
+                remaining_amount,
+                retry_count,
+            });
         }
+    }
 
-        // Collect (tx_id, rtgs_priority_order, rtgs_submission_tick) for stable sorting
-        // Phase 0: Dual Priority System - Sort by RTGS priority, not internal priority
-        let mut tx_info: Vec<(String, u8, usize)> = queue
+    /// Check whether an agent is suspended (unknown agents are not)
+    fn is_agent_suspended(&self, agent_id: &str) -> bool {
+        self.state
+            .get_agent(agent_id)
+            .map(|agent| agent.is_suspended())
+            .unwrap_or(false)
+    }
+
+    /// Resume suspended agents whose scheduled resume tick has arrived
+    ///
+    /// Logs an `AgentResumed` event per agent, in agent ID order.
+    fn resume_due_agents(&mut self, current_tick: usize) {
+        let mut due: Vec<String> = self
+            .state
+            .agents()
             .iter()
-            .filter_map(|tx_id| {
-                self.state.get_transaction(tx_id).map(|tx| {
-                    // RtgsPriority enum order: HighlyUrgent=0, Urgent=1, Normal=2
-                    // Lower value = higher priority
-                    let rtgs_priority_order = tx.rtgs_priority()
-                        .map(|p| p as u8)
-                        .unwrap_or(2); // Default to Normal (lowest)
-                    let submission_tick = tx.rtgs_submission_tick().unwrap_or(usize::MAX);
-                    (tx_id.clone(), rtgs_priority_order, submission_tick)
-                })
+            .filter(|(_, agent)| {
+                agent.is_suspended()
+                    && agent
+                        .suspension_resume_tick()
+                        .is_some_and(|resume_tick| resume_tick <= current_tick)
             })
+            .map(|(id, _)| id.clone())
             .collect();
+        due.sort();
 
-        // Sort by RTGS priority (ascending - lower value = higher priority),
-        // then by submission_tick (ascending, FIFO)
-        tx_info.sort_by(|a, b| {
-            // Lower rtgs_priority_order = higher priority (HighlyUrgent=0 before Urgent=1 before Normal=2)
-            match a.1.cmp(&b.1) {
-                std::cmp::Ordering::Equal => {
-                    // Same RTGS priority: FIFO by submission tick
-                    a.2.cmp(&b.2)
-                }
-                other => other,
+        for agent_id in due {
+            if let Some(agent) = self.state.get_agent_mut(&agent_id) {
+                agent.resume();
             }
-        });
-
-        // Extract sorted tx_ids
-        let sorted_ids: Vec<String> = tx_info.into_iter().map(|(id, _, _)| id).collect();
+            self.log_event(Event::AgentResumed {
+                tick: current_tick,
+                agent_id,
+            });
+        }
+    }
 
-        // Replace RTGS queue with sorted version
-        *self.state.rtgs_queue_mut() = sorted_ids;
+    /// Effective Queue 2 processing order
+    ///
+    /// An explicit `queue2_ordering` wins. With the default `Fifo`, the legacy
+    /// `priority_mode` flag still selects T2-style priority bands:
+    /// - HighlyUrgent: Processed first
+    /// - Urgent: Processed second
+    /// - Normal: Processed last
+    ///
+    /// Within each band, FIFO ordering is preserved (RTGS submission tick).
+    fn queue2_ordering(&self) -> Queue2Ordering {
+        match self.config.queue2_ordering {
+            Queue2Ordering::Fifo if self.config.priority_mode => Queue2Ordering::PriorityBands,
+            ordering => ordering,
+        }
     }
 
     /// Handle end-of-day processing
@@ -5157,6 +8493,35 @@ impl Orchestrator {
         }
     }
 
+    /// Look up the liquidity pool a `ReallocateLiquidity` event draws on
+    ///
+    /// Errors if the fraction is outside `[0.0, 1.0]` or the agent has no
+    /// configured `liquidity_pool`.
+    fn reallocation_pool(
+        config: &OrchestratorConfig,
+        agent_id: &str,
+        new_fraction: f64,
+    ) -> Result<i64, SimulationError> {
+        if !(0.0..=1.0).contains(&new_fraction) {
+            return Err(SimulationError::InvalidConfig(format!(
+                "ReallocateLiquidity for {}: new_fraction must be between 0.0 and 1.0, got {}",
+                agent_id, new_fraction
+            )));
+        }
+
+        config
+            .agent_configs
+            .iter()
+            .find(|ac| ac.id == agent_id)
+            .and_then(|ac| ac.liquidity_pool)
+            .ok_or_else(|| {
+                SimulationError::InvalidConfig(format!(
+                    "ReallocateLiquidity for {}: agent has no liquidity_pool",
+                    agent_id
+                ))
+            })
+    }
+
     /// Return allocated liquidity to pool at end of day.
     ///
     /// For each agent with a liquidity pool, withdraws the allocated amount
@@ -5296,12 +8661,14 @@ impl Orchestrator {
                 let penalty = {
                     let mut total = 0i64;
 
+                    let eod_penalty = self.cost_rates_for_agent(&agent_id).eod_penalty.clone();
+
                     // Re-scan Q1 for overdue remaining amounts
                     let agent = self.state.get_agent(&agent_id).unwrap();
                     for tx_id in agent.outgoing_queue() {
                         if let Some(tx) = self.state.get_transaction(tx_id) {
                             if tx.deadline_tick() < current_tick {
-                                total += self.cost_rates.eod_penalty.resolve(tx.remaining_amount());
+                                total += eod_penalty.resolve(tx.remaining_amount());
                             }
                         }
                     }
@@ -5310,7 +8677,7 @@ impl Orchestrator {
                     for tx_id in self.state.rtgs_queue() {
                         if let Some(tx) = self.state.get_transaction(tx_id) {
                             if tx.sender_id() == agent_id && tx.deadline_tick() < current_tick {
-                                total += self.cost_rates.eod_penalty.resolve(tx.remaining_amount());
+                                total += eod_penalty.resolve(tx.remaining_amount());
                             }
                         }
                     }
@@ -5335,11 +8702,16 @@ impl Orchestrator {
                         penalty_cost: penalty,
                         split_friction_cost: 0,
                         liquidity_opportunity_cost: 0,
+                        overnight_interest: 0,
+                        collateral_fee_cost: 0,
                     },
                 });
             }
         }
 
+        // Overnight interest on closing balances (once per day boundary)
+        self.accrue_overnight_interest(current_tick);
+
         // Phase 4.5: Reset state registers at end of day
         // All state registers reset to 0.0 for next day (daily scope only)
         for agent_id in self.state.agents().keys().cloned().collect::<Vec<_>>() {
@@ -5364,6 +8736,11 @@ impl Orchestrator {
 
         // Phase 1 (TARGET2 LSM): Reset daily outflows for all agents
         for agent_id in self.state.agents().keys().cloned().collect::<Vec<_>>() {
+            // Daily metrics need the day's outflows before they are reset
+            if let Some(metrics) = self.current_day_metrics.get_mut(&agent_id) {
+                metrics.capture_settlement_activity(self.state.get_agent(&agent_id).unwrap());
+            }
+
             let agent_mut = self.state.get_agent_mut(&agent_id).unwrap();
             agent_mut.reset_daily_outflows();
         }
@@ -5390,6 +8767,93 @@ impl Orchestrator {
         Ok(total_penalties)
     }
 
+    /// Charge the one-time `collateral_post_fee` for an executed collateral post
+    fn charge_collateral_post_fee(&mut self, agent_id: &str, tick: usize) {
+        let fee = self.cost_rates_for_agent(agent_id).collateral_post_fee;
+        if fee == 0 {
+            return;
+        }
+
+        let costs = CostBreakdown {
+            collateral_fee_cost: fee,
+            ..Default::default()
+        };
+        if let Some(accumulator) = self.accumulated_costs.get_mut(agent_id) {
+            accumulator.add(&costs);
+        }
+        self.log_event(Event::CostAccrual {
+            tick,
+            agent_id: agent_id.to_string(),
+            costs,
+        });
+    }
+
+    /// Accrue overnight interest on each agent's closing balance
+    ///
+    /// Called exactly once per day boundary from `handle_end_of_day`. Interest
+    /// is a cost entry only: balances are not adjusted, so settlement-money
+    /// conservation is unaffected. Agents are processed in sorted order for
+    /// deterministic event ordering.
+    fn accrue_overnight_interest(&mut self, tick: usize) {
+        for agent_id in self.state.get_all_agent_ids() {
+            let balance = self.state.get_agent(&agent_id).unwrap().balance();
+            let interest = self.calculate_overnight_interest(&agent_id, balance);
+            if interest == 0 {
+                continue;
+            }
+
+            let costs = CostBreakdown {
+                overnight_interest: interest,
+                ..Default::default()
+            };
+            if let Some(accumulator) = self.accumulated_costs.get_mut(&agent_id) {
+                accumulator.add(&costs);
+            }
+            self.log_event(Event::CostAccrual {
+                tick,
+                agent_id,
+                costs,
+            });
+        }
+    }
+
+    /// Calculate an agent's overnight interest on its closing balance using
+    /// integer-only arithmetic
+    ///
+    /// Returns a signed cost: `|balance| × overnight_overdraft_rate / 10,000`
+    /// for negative balances, `-(balance × overnight_deposit_rate / 10,000)` for
+    /// positive balances. The magnitude is rounded half up, so earning and
+    /// charging round symmetrically.
+    fn calculate_overnight_interest(&self, agent_id: &str, balance: i64) -> i64 {
+        let rates = self.cost_rates_for_agent(agent_id);
+        let rate = match balance.cmp(&0) {
+            std::cmp::Ordering::Greater => rates.overnight_deposit_rate,
+            std::cmp::Ordering::Less => rates.overnight_overdraft_rate,
+            std::cmp::Ordering::Equal => return 0,
+        };
+
+        // Same fixed-point scheme as calculate_overdraft_cost (milli-bps)
+        const BPS_SCALE: u128 = 1000;
+        const COMBINED_DIVISOR: u128 = 10_000 * BPS_SCALE;
+
+        // CRITICAL: Guard against NaN/Inf before casting to integer
+        if !rate.is_finite() || rate <= 0.0 {
+            return 0;
+        }
+        let scaled_rate = (rate * BPS_SCALE as f64).round() as u128;
+
+        let numerator = (balance.unsigned_abs() as u128).saturating_mul(scaled_rate);
+        let magnitude =
+            (numerator.saturating_add(COMBINED_DIVISOR / 2) / COMBINED_DIVISOR).min(i64::MAX as u128)
+                as i64;
+
+        if balance > 0 {
+            -magnitude
+        } else {
+            magnitude
+        }
+    }
+
     /// Finalize daily metrics at end of day (Phase 3: Agent Metrics Collection)
     ///
     /// 1. Finalize current day metrics (capture closing balance, costs, etc.)
@@ -5414,7 +8878,9 @@ impl Orchestrator {
 
             // Initialize metrics for next day
             let agent = self.state.get_agent(agent_id).unwrap();
-            let next_day_metrics = DailyMetrics::new(agent_id.clone(), current_day + 1, agent);
+            let in_warmup = (current_day + 1) * self.config.ticks_per_day < self.config.warmup_ticks;
+            let next_day_metrics =
+                DailyMetrics::new(agent_id.clone(), current_day + 1, agent, in_warmup);
             self.current_day_metrics
                 .insert(agent_id.clone(), next_day_metrics);
 
@@ -5427,12 +8893,25 @@ impl Orchestrator {
 
     /// Update metrics at end of tick (Phase 3: Agent Metrics Collection)
     ///
-    /// Tracks balance changes and queue sizes for all agents.
+    /// Tracks balance changes, queue sizes and liquidity requirement for all agents.
     fn update_tick_metrics(&mut self) {
         let agent_ids: Vec<String> = self.state.get_all_agent_ids();
 
+        // Queue 2 value per sender, collected in one pass over the queue
+        let mut rtgs_queued_by_sender: HashMap<&str, i64> = HashMap::new();
+        for tx in self
+            .state
+            .rtgs_queue()
+            .iter()
+            .filter_map(|tx_id| self.state.get_transaction(tx_id))
+        {
+            *rtgs_queued_by_sender.entry(tx.sender_id()).or_insert(0) += tx.remaining_amount();
+        }
+
         for agent_id in &agent_ids {
             let agent = self.state.get_agent(agent_id).unwrap();
+            let queued_outbound = self.state.agent_queue_value(agent_id)
+                + rtgs_queued_by_sender.get(agent_id.as_str()).copied().unwrap_or(0);
 
             if let Some(metrics) = self.current_day_metrics.get_mut(agent_id) {
                 // Update balance tracking
@@ -5443,10 +8922,41 @@ impl Orchestrator {
 
                 // Update queue size tracking
                 metrics.update_queue_size(agent.outgoing_queue_size());
+
+                // Update liquidity requirement (balance vs. queued commitments)
+                metrics.update_liquidity_requirement(agent.balance() - queued_outbound);
             }
         }
     }
 
+    /// Downgrade a split decision on a non-divisible transaction to a full release
+    ///
+    /// Policies may request `SubmitPartial`, `SubmitAmount` or `StaggerSplit` for any queued
+    /// transaction; the transaction's `divisible` flag has the final say. A
+    /// non-divisible transaction is released whole, with no split friction.
+    fn gate_split_on_divisibility(
+        &self,
+        decision: crate::policy::ReleaseDecision,
+    ) -> crate::policy::ReleaseDecision {
+        use crate::policy::ReleaseDecision;
+
+        let tx_id = match &decision {
+            ReleaseDecision::SubmitPartial { tx_id, .. }
+            | ReleaseDecision::SubmitAmount { tx_id, .. }
+            | ReleaseDecision::StaggerSplit { tx_id, .. } => tx_id,
+            _ => return decision,
+        };
+
+        match self.state.get_transaction(tx_id) {
+            Some(tx) if !tx.is_divisible() => ReleaseDecision::SubmitFull {
+                tx_id: tx_id.clone(),
+                priority_override: None,
+                target_tick: None,
+            },
+            _ => decision,
+        }
+    }
+
     /// Try to settle a transaction that's already in the state
     ///
     /// If settlement fails due to insufficient liquidity, queue the transaction.
@@ -5542,14 +9052,15 @@ impl Orchestrator {
                 // Record outflow for bilateral/multilateral limit tracking (Phase 1 TARGET2 LSM)
                 sender.record_outflow(&receiver_id, amount);
             }
+            self.state.record_settled_flow(&sender_id, &receiver_id, amount);
 
-            // Handle credit: either defer or apply immediately
+            // Handle credit: either defer or apply immediately (per receiver)
             match deferred_credits {
-                Some(dc) => {
-                    // Deferred crediting mode: accumulate credit for end of tick
-                    dc.accumulate(&receiver_id, amount, tx_id);
+                Some(dc) if dc.defers(&receiver_id) => {
+                    // Deferred receiver: accumulate credit for end of tick
+                    dc.defer(&mut self.state, &receiver_id, amount, tx_id);
                 }
-                None => {
+                _ => {
                     // Immediate crediting mode: apply credit now
                     let receiver = self.state.get_agent_mut(&receiver_id).unwrap();
                     receiver.credit(amount);
@@ -5567,7 +9078,9 @@ impl Orchestrator {
                 tx.settle(amount, tick).map_err(|e| {
                     SimulationError::SettlementError(format!("Settle failed: {}", e))
                 })?;
+                tx.set_settlement_mechanism(SettlementMechanism::RtgsImmediate);
             }
+            self.state.record_settlement(tx_id, tick);
 
             // If this is a child transaction, update parent's remaining_amount
             if let Some(parent_id) = parent_id {
@@ -5585,6 +9098,23 @@ impl Orchestrator {
             }
 
             Ok(SettlementOutcome::Settled)
+        } else if let Some(capacity) = self
+            .state
+            .rtgs_queue_capacity()
+            .filter(|_| self.state.is_rtgs_queue_full())
+        {
+            // Queue 2 is full: hand the transaction back to the sender's Queue 1
+            if let Some(tx) = self.state.get_transaction_mut(tx_id) {
+                tx.mark_entered_queue1(tick);
+            }
+            self.state
+                .get_agent_mut(&sender_id)
+                .ok_or_else(|| SimulationError::AgentNotFound(sender_id.clone()))?
+                .queue_outgoing(tx_id.to_string());
+            Ok(SettlementOutcome::Rejected {
+                queue_size: self.state.queue_size(),
+                capacity,
+            })
         } else {
             // Queue the transaction in RTGS queue (Queue 2)
             self.state.queue_transaction(tx_id.to_string());
@@ -5749,6 +9279,7 @@ mod tests {
                     posted_collateral: None,
                     collateral_haircut: None,
                     limits: None,
+                    ..Default::default()
                 },
                 AgentConfig {
                     id: "BANK_B".to_string(),
@@ -5762,6 +9293,7 @@ mod tests {
                     posted_collateral: None,
                     collateral_haircut: None,
                     limits: None,
+                    ..Default::default()
                 },
             ],
             cost_rates: CostRates::default(),
@@ -5772,6 +9304,7 @@ mod tests {
             priority_escalation: Default::default(),
             algorithm_sequencing: false,
             entry_disposition_offsetting: false,
+            ..Default::default()
         }
     }
 
@@ -5826,6 +9359,7 @@ mod tests {
             priority_escalation: Default::default(),
             algorithm_sequencing: false,
             entry_disposition_offsetting: false,
+            ..Default::default()
         };
 
         let result = Orchestrator::new(config);
@@ -5862,6 +9396,7 @@ mod tests {
                     posted_collateral: None,
                     collateral_haircut: None,
                     limits: None,
+                    ..Default::default()
                 },
                 AgentConfig {
                     id: "BANK_A".to_string(), // Duplicate!
@@ -5872,6 +9407,7 @@ mod tests {
                     posted_collateral: None,
                     collateral_haircut: None,
                     limits: None,
+                    ..Default::default()
                 },
             ],
             cost_rates: CostRates::default(),
@@ -5882,6 +9418,7 @@ mod tests {
             priority_escalation: Default::default(),
             algorithm_sequencing: false,
             entry_disposition_offsetting: false,
+            ..Default::default()
         };
 
         let result = Orchestrator::new(config);
@@ -5898,6 +9435,7 @@ mod tests {
             collateral_cost: 0,
             penalty_cost: 0,
             split_friction_cost: 0,
+            overnight_interest: 0,
         };
 
         acc.add(&cost1);
@@ -5911,6 +9449,7 @@ mod tests {
             collateral_cost: 0,
             penalty_cost: 500,
             split_friction_cost: 0,
+            overnight_interest: 0,
         };
 
         acc.add(&cost2);
@@ -5945,6 +9484,7 @@ mod tests {
             collateral_cost: 0,
             penalty_cost: 2000,
             split_friction_cost: 250,
+            overnight_interest: 0,
         };
 
         assert_eq!(cost.total(), 3750); // 1000 + 500 + 2000 + 250
@@ -12,11 +12,14 @@ mod tests;
 
 // Re-export main types for convenience
 pub use engine::{
-    AgentConfig, AgentLimitsConfig, CostAccumulator, CostBreakdown, DailyMetrics, Orchestrator,
-    OrchestratorConfig, PolicyConfig, PriorityEscalationConfig, Queue1Ordering, SimulationError, TickResult,
+    AgentConfig, AgentLimitsConfig, CostAccumulator, CostBreakdown, DailyMetrics, LimitUtilization, Orchestrator,
+    OrchestratorConfig, OrchestratorConfigBuilder, PolicyConfig, PriorityEscalationConfig, Queue1Ordering, ScriptedArrival, SimulationError, SplitRemainderPolicy, DeadlineEnforcement, TickObserver,
+    TickResult, RECENT_TICK_RESULTS_CAPACITY,
+    run_seed, run_seed_sweep, SystemMetrics,
 };
 // BIS model support - CostRates and priority types are now in costs module
-pub use crate::costs::{get_priority_band, CostRates, PenaltyMode, PriorityBand, PriorityDelayMultipliers};
+pub use crate::settlement::Queue2Ordering;
+pub use crate::costs::{get_priority_band, CollateralCostBasis, CostRateOverrides, CostRates, PenaltyMode, PriorityBand, PriorityDelayMultipliers};
 
 // Re-export checkpoint types
 pub use checkpoint::{AgentSnapshot, StateSnapshot, TransactionSnapshot};
@@ -29,6 +29,7 @@ fn test_eod_penalty_only_applies_to_overdue_transactions() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "RECEIVER".to_string(),
@@ -43,6 +44,7 @@ fn test_eod_penalty_only_applies_to_overdue_transactions() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates {
@@ -59,6 +61,7 @@ fn test_eod_penalty_only_applies_to_overdue_transactions() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut engine = Orchestrator::new(config).unwrap();
@@ -150,6 +153,7 @@ fn test_eod_penalty_applies_to_all_overdue_transactions() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "RECEIVER".to_string(),
@@ -164,6 +168,7 @@ fn test_eod_penalty_applies_to_all_overdue_transactions() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates {
@@ -180,6 +185,7 @@ fn test_eod_penalty_applies_to_all_overdue_transactions() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut engine = Orchestrator::new(config).unwrap();
@@ -238,6 +244,7 @@ fn test_no_eod_penalty_when_all_transactions_settle_before_deadline() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "RECEIVER".to_string(),
@@ -252,6 +259,7 @@ fn test_no_eod_penalty_when_all_transactions_settle_before_deadline() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates {
@@ -268,6 +276,7 @@ fn test_no_eod_penalty_when_all_transactions_settle_before_deadline() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut engine = Orchestrator::new(config).unwrap();
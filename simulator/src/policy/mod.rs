@@ -134,7 +134,8 @@ pub enum ReleaseDecision {
     /// The last child gets any remainder to ensure exact sum.
     ///
     /// All child transactions are immediately submitted to RTGS.
-    /// A split friction cost is charged: `split_friction_cost × (num_splits - 1)`
+    /// A split friction cost is charged:
+    /// `split_friction_cost × (num_splits - 1)^split_friction_exponent`
     ///
     /// # Phase 5 Implementation
     /// This enables policies to "pace" large payments by voluntarily splitting
@@ -144,6 +145,16 @@ pub enum ReleaseDecision {
         num_splits: usize, // Number of equal-sized children to create
     },
 
+    /// Release an exact amount now and keep the rest in Queue 1
+    ///
+    /// Splits the transaction into two children linked to the parent: a
+    /// released child of `amount`, submitted to RTGS immediately, and a
+    /// retained child of the remainder, which stays in Queue 1 for later
+    /// decisions. Requires `0 < amount < remaining_amount`.
+    ///
+    /// A single split friction cost is charged (`split_friction_cost × 1`).
+    SubmitAmount { tx_id: String, amount: i64 },
+
     /// Hold transaction in Queue 1 for later
     ///
     /// Transaction remains in internal queue. Will be re-evaluated next tick.
@@ -193,6 +204,13 @@ pub enum ReleaseDecision {
         new_priority: u8,
     },
 
+    /// Set the transaction's Queue 1 ordering hint
+    ///
+    /// The hint only breaks ties between transactions of equal priority
+    /// under `PriorityDeadline` ordering (lower hint first). Like
+    /// `Reprioritize`, the transaction stays in Queue 1.
+    SetOrderHint { tx_id: String, order_hint: i64 },
+
     /// Split transaction and release children with staggered timing (Phase 3.1)
     ///
     /// Creates `num_splits` child transactions from the parent, but unlike
@@ -220,7 +238,7 @@ pub enum ReleaseDecision {
     /// # Cost
     ///
     /// Split friction cost is charged once when split occurs:
-    /// `split_friction_cost × (num_splits - 1)`
+    /// `split_friction_cost × (num_splits - 1)^split_friction_exponent`
     ///
     /// Staggering timing is free (no additional cost beyond the split itself).
     ///
@@ -294,6 +312,24 @@ pub enum ReleaseDecision {
     },
 }
 
+impl ReleaseDecision {
+    /// Transaction this decision applies to
+    pub fn tx_id(&self) -> &str {
+        match self {
+            ReleaseDecision::SubmitFull { tx_id, .. }
+            | ReleaseDecision::SubmitPartial { tx_id, .. }
+            | ReleaseDecision::SubmitAmount { tx_id, .. }
+            | ReleaseDecision::Hold { tx_id, .. }
+            | ReleaseDecision::Drop { tx_id }
+            | ReleaseDecision::Reprioritize { tx_id, .. }
+            | ReleaseDecision::SetOrderHint { tx_id, .. }
+            | ReleaseDecision::StaggerSplit { tx_id, .. }
+            | ReleaseDecision::WithdrawFromRtgs { tx_id }
+            | ReleaseDecision::ResubmitToRtgs { tx_id, .. } => tx_id,
+        }
+    }
+}
+
 /// Reason for holding a transaction in Queue 1
 #[derive(Debug, Clone, PartialEq)]
 pub enum HoldReason {
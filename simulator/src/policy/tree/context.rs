@@ -32,18 +32,29 @@ pub enum ContextError {
 ///
 /// **Agent Fields**:
 /// - balance, credit_limit, available_liquidity, credit_used (i64 → f64)
+/// - provisional_balance: balance plus deferred credits received this tick
+///   and not yet applied; equals balance outside deferred crediting (i64 → f64)
 /// - effective_liquidity: balance + unused_credit_capacity (i64 → f64) - Phase 11 fix
 /// - liquidity_buffer, outgoing_queue_size, incoming_expected_count (i64/usize → f64)
+/// - incoming_expected_value: Queue 2 value where agent is receiver (i64 → f64)
+/// - recent_inflow_rate, recent_outflow_rate: settled value per tick over the
+///   trailing `flow_window_ticks` window (f64)
 /// - is_using_credit (bool → 0.0/1.0)
-/// - liquidity_pressure (f64)
+/// - liquidity_pressure: Share of the allowed overdraft limit not covered by
+///   available liquidity, `(allowed_overdraft_limit - available_liquidity) /
+///   allowed_overdraft_limit` clamped to [0, 1] (f64)
 ///
 /// **Derived Fields**:
 /// - ticks_to_deadline (i64, can be negative)
 /// - queue_age (usize)
+/// - ticks_since_arrival: alias of queue_age (usize)
+/// - ticks_until_deadline: ticks left before the deadline, 0 once overdue (usize)
 ///
 /// **System Fields**:
 /// - current_tick (usize → f64)
 /// - rtgs_queue_size, rtgs_queue_value, total_agents (usize/i64 → f64)
+/// - queue2_total_size: transactions in Queue 2 across all agents (usize → f64)
+/// - system_agents_in_overdraft: agents with a negative balance (usize → f64)
 ///
 /// **Collateral Fields** (Phase 8.2):
 /// - posted_collateral: Amount of collateral currently posted (i64 → f64)
@@ -72,6 +83,7 @@ pub enum ContextError {
 /// - cost_delay_per_tick_per_cent: Delay cost per tick per cent (f64)
 /// - cost_collateral_bps_per_tick: Collateral opportunity cost in bps per tick (f64)
 /// - cost_split_friction: Cost per split operation (f64)
+/// - cost_split_friction_exponent: Exponent on (num_splits - 1) in split friction (f64)
 /// - cost_deadline_penalty: Penalty for missing deadline (f64)
 /// - cost_eod_penalty: End-of-day penalty per unsettled transaction (f64)
 /// - cost_delay_this_tx_one_tick: Delay cost for THIS transaction for one tick (f64)
@@ -201,6 +213,10 @@ impl EvalContext {
 
         // Agent fields
         fields.insert("balance".to_string(), agent.balance() as f64);
+        fields.insert(
+            "provisional_balance".to_string(),
+            agent.provisional_balance() as f64,
+        );
         fields.insert("credit_limit".to_string(), agent.unsecured_cap() as f64);
         fields.insert(
             "available_liquidity".to_string(),
@@ -241,7 +257,19 @@ impl EvalContext {
             "incoming_expected_count".to_string(),
             agent.incoming_expected().len() as f64,
         );
-        fields.insert("liquidity_pressure".to_string(), agent.liquidity_pressure());
+        fields.insert(
+            "incoming_expected_value".to_string(),
+            calculate_incoming_expected_value(state, agent.id()) as f64,
+        );
+        fields.insert(
+            "recent_inflow_rate".to_string(),
+            state.flow_window().inflow_rate(agent.id()),
+        );
+        fields.insert(
+            "recent_outflow_rate".to_string(),
+            state.flow_window().outflow_rate(agent.id()),
+        );
+        fields.insert("liquidity_pressure".to_string(), liquidity_pressure(agent));
 
         // Phase 1.1: Overdraft Regime Fields (Policy Enhancements V2)
         // credit_headroom = remaining capacity before hitting credit limit
@@ -397,11 +425,25 @@ impl EvalContext {
         let queue_age = tick.saturating_sub(tx.arrival_tick());
         fields.insert("queue_age".to_string(), queue_age as f64);
 
+        // Alias of queue_age under a self-describing name
+        fields.insert("ticks_since_arrival".to_string(), queue_age as f64);
+
+        let ticks_until_deadline = tx.deadline_tick().saturating_sub(tick);
+        fields.insert(
+            "ticks_until_deadline".to_string(),
+            ticks_until_deadline as f64,
+        );
+
         // System fields
         fields.insert("current_tick".to_string(), tick as f64);
         fields.insert("rtgs_queue_size".to_string(), state.queue_size() as f64);
         fields.insert("rtgs_queue_value".to_string(), state.queue_value() as f64);
         fields.insert("total_agents".to_string(), state.num_agents() as f64);
+        fields.insert("queue2_total_size".to_string(), state.queue_size() as f64);
+        fields.insert(
+            "system_agents_in_overdraft".to_string(),
+            count_agents_in_overdraft(state) as f64,
+        );
 
         // Phase 8.2: Collateral Management Fields
 
@@ -544,6 +586,10 @@ impl EvalContext {
             "cost_split_friction".to_string(),
             cost_rates.split_friction_cost as f64,
         );
+        fields.insert(
+            "cost_split_friction_exponent".to_string(),
+            cost_rates.split_friction_exponent,
+        );
         fields.insert(
             "cost_deadline_penalty".to_string(),
             cost_rates.deadline_penalty.resolve(tx.amount()) as f64,
@@ -656,6 +702,10 @@ impl EvalContext {
 
         // Agent fields (same as transaction context)
         fields.insert("balance".to_string(), agent.balance() as f64);
+        fields.insert(
+            "provisional_balance".to_string(),
+            agent.provisional_balance() as f64,
+        );
         fields.insert("credit_limit".to_string(), agent.unsecured_cap() as f64);
         fields.insert(
             "available_liquidity".to_string(),
@@ -685,7 +735,19 @@ impl EvalContext {
             "incoming_expected_count".to_string(),
             agent.incoming_expected().len() as f64,
         );
-        fields.insert("liquidity_pressure".to_string(), agent.liquidity_pressure());
+        fields.insert(
+            "incoming_expected_value".to_string(),
+            calculate_incoming_expected_value(state, agent.id()) as f64,
+        );
+        fields.insert(
+            "recent_inflow_rate".to_string(),
+            state.flow_window().inflow_rate(agent.id()),
+        );
+        fields.insert(
+            "recent_outflow_rate".to_string(),
+            state.flow_window().outflow_rate(agent.id()),
+        );
+        fields.insert("liquidity_pressure".to_string(), liquidity_pressure(agent));
         fields.insert("is_overdraft_capped".to_string(), 1.0);
 
         // Queue 1 metrics
@@ -710,6 +772,11 @@ impl EvalContext {
         fields.insert("rtgs_queue_size".to_string(), state.queue_size() as f64);
         fields.insert("rtgs_queue_value".to_string(), state.queue_value() as f64);
         fields.insert("total_agents".to_string(), state.num_agents() as f64);
+        fields.insert("queue2_total_size".to_string(), state.queue_size() as f64);
+        fields.insert(
+            "system_agents_in_overdraft".to_string(),
+            count_agents_in_overdraft(state) as f64,
+        );
 
         // Collateral fields
         fields.insert(
@@ -813,6 +880,10 @@ impl EvalContext {
             "cost_split_friction".to_string(),
             cost_rates.split_friction_cost as f64,
         );
+        fields.insert(
+            "cost_split_friction_exponent".to_string(),
+            cost_rates.split_friction_exponent,
+        );
         // Bank-level context: no specific transaction, so resolve with 0
         // (Fixed mode returns the fixed amount; Rate mode returns 0)
         fields.insert(
@@ -971,8 +1042,27 @@ mod tests {
         assert_eq!(context.get_field("outgoing_queue_size").unwrap(), 2.0);
         assert_eq!(context.get_field("incoming_expected_count").unwrap(), 1.0);
 
-        // Liquidity pressure should be > 0
-        assert!(context.get_field("liquidity_pressure").unwrap() > 0.0);
+        // Positive balance covers the whole overdraft limit: no pressure
+        assert_eq!(context.get_field("liquidity_pressure").unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_liquidity_pressure_tracks_overdraft_usage() {
+        // Limit 200k, 150k of it drawn: available 50k → (200k - 50k) / 200k
+        let mut agent = Agent::new("BANK_A".to_string(), -150_000);
+        agent.set_unsecured_cap(200_000);
+        assert_eq!(liquidity_pressure(&agent), 0.75);
+
+        // Beyond the limit clamps to 1.0
+        let mut agent = Agent::new("BANK_A".to_string(), -250_000);
+        agent.set_unsecured_cap(200_000);
+        assert_eq!(liquidity_pressure(&agent), 1.0);
+    }
+
+    #[test]
+    fn test_liquidity_pressure_with_zero_limit() {
+        assert_eq!(liquidity_pressure(&Agent::new("BANK_A".to_string(), 10_000)), 0.0);
+        assert_eq!(liquidity_pressure(&Agent::new("BANK_A".to_string(), 0)), 1.0);
     }
 
     #[test]
@@ -1013,6 +1103,8 @@ mod tests {
         assert_eq!(context.get_field("rtgs_queue_size").unwrap(), 0.0); // Empty queue
         assert_eq!(context.get_field("rtgs_queue_value").unwrap(), 0.0);
         assert_eq!(context.get_field("total_agents").unwrap(), 3.0);
+        assert_eq!(context.get_field("queue2_total_size").unwrap(), 0.0);
+        assert_eq!(context.get_field("system_agents_in_overdraft").unwrap(), 0.0);
     }
 
     #[test]
@@ -1061,6 +1153,25 @@ mod tests {
         assert_eq!(context.get_field("is_past_deadline").unwrap(), 1.0);
     }
 
+    #[test]
+    fn test_transaction_age_fields() {
+        let (tx, agent, state, tick) = create_test_context();
+        let context = EvalContext::build(&tx, &agent, &state, tick, &create_cost_rates(), 100, 0.8);
+
+        // arrival 10, deadline 50, tick 30
+        assert_eq!(context.get_field("ticks_since_arrival").unwrap(), 20.0);
+        assert_eq!(
+            context.get_field("ticks_since_arrival").unwrap(),
+            context.get_field("queue_age").unwrap()
+        );
+        assert_eq!(context.get_field("ticks_until_deadline").unwrap(), 20.0);
+
+        // Overdue: ticks_until_deadline saturates at 0, age keeps growing
+        let context = EvalContext::build(&tx, &agent, &state, 60, &create_cost_rates(), 100, 0.8);
+        assert_eq!(context.get_field("ticks_since_arrival").unwrap(), 50.0);
+        assert_eq!(context.get_field("ticks_until_deadline").unwrap(), 0.0);
+    }
+
     #[test]
     fn test_boolean_fields_as_floats() {
         // Create a transaction that uses credit
@@ -1245,6 +1356,39 @@ fn calculate_q2_bilateral_values(
     (my_q2_out, my_q2_in)
 }
 
+/// Normalized overdraft pressure, clamped to [0, 1]
+///
+/// `(allowed_overdraft_limit - available_liquidity) / allowed_overdraft_limit`:
+/// 0.0 while the agent can cover its whole limit, 1.0 once the limit is used
+/// up. With no overdraft limit the ratio is undefined; pressure is then 1.0
+/// when no liquidity is left and 0.0 otherwise.
+fn liquidity_pressure(agent: &Agent) -> f64 {
+    let limit = agent.allowed_overdraft_limit();
+    let available = agent.available_liquidity();
+    if limit <= 0 {
+        return if available > 0 { 0.0 } else { 1.0 };
+    }
+    ((limit - available) as f64 / limit as f64).clamp(0.0, 1.0)
+}
+
+/// Count agents whose balance is negative (using intraday credit)
+fn count_agents_in_overdraft(state: &SimulationState) -> usize {
+    state
+        .agents()
+        .values()
+        .filter(|agent| agent.balance() < 0)
+        .count()
+}
+
+/// Calculate total Queue 2 value where the agent is the receiver
+fn calculate_incoming_expected_value(state: &SimulationState, agent_id: &str) -> i64 {
+    state
+        .incoming_queued_for(agent_id)
+        .iter()
+        .map(|tx| tx.remaining_amount())
+        .fold(0i64, |acc, x| acc.saturating_add(x))
+}
+
 /// Calculate top N counterparties by Queue 2 outflow value
 fn calculate_top_counterparties_by_q2_outflow(
     state: &SimulationState,
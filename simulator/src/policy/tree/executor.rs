@@ -5,8 +5,8 @@
 
 use crate::orchestrator::{CostRates, PenaltyMode};
 use crate::policy::tree::{
-    build_decision, traverse_tree, validate_tree, DecisionTreeDef, EvalContext, EvalError,
-    ValidationError,
+    build_decision, traverse_tree, traverse_tree_with_path, validate_tree, DecisionPath,
+    DecisionTreeDef, EvalContext, EvalError, TreeNode, ValidationError,
 };
 use crate::policy::{CashManagerPolicy, ReleaseDecision};
 use crate::{Agent, SimulationState};
@@ -64,6 +64,12 @@ pub struct TreePolicy {
 
     /// Whether tree has been validated
     validated: bool,
+
+    /// Whether payment tree decision paths are recorded (off by default)
+    record_decision_paths: bool,
+
+    /// Decision paths recorded since the last `take_decision_paths`, by tx ID
+    recorded_paths: Vec<(String, DecisionPath)>,
 }
 
 impl TreePolicy {
@@ -102,6 +108,8 @@ impl TreePolicy {
         Self {
             tree,
             validated: false,
+            record_decision_paths: false,
+            recorded_paths: Vec::new(),
         }
     }
 
@@ -427,6 +435,39 @@ impl TreePolicy {
             self.tree.parameters.insert(key, value);
         }
     }
+
+    /// Enable or disable recording of payment tree decision paths
+    ///
+    /// When enabled, every payment decision also stores the path of node IDs
+    /// traversed. Paths accumulate until drained with `take_decision_paths`.
+    pub fn set_record_decision_paths(&mut self, enabled: bool) {
+        self.record_decision_paths = enabled;
+        if !enabled {
+            self.recorded_paths.clear();
+        }
+    }
+
+    /// Drain decision paths recorded since the last call, as (tx_id, path)
+    pub fn take_decision_paths(&mut self) -> Vec<(String, DecisionPath)> {
+        std::mem::take(&mut self.recorded_paths)
+    }
+}
+
+/// Traverse the payment tree, pushing the path onto `recorded` when given
+fn traverse_payment_tree<'a>(
+    tree: &'a DecisionTreeDef,
+    context: &EvalContext,
+    tx_id: &str,
+    recorded: Option<&mut Vec<(String, DecisionPath)>>,
+) -> Result<&'a TreeNode, EvalError> {
+    match recorded {
+        Some(recorded) => {
+            let (node, path) = traverse_tree_with_path(tree, context)?;
+            recorded.push((tx_id.to_string(), path));
+            Ok(node)
+        }
+        None => traverse_tree(tree, context),
+    }
 }
 
 impl CashManagerPolicy for TreePolicy {
@@ -484,7 +525,8 @@ impl CashManagerPolicy for TreePolicy {
             }
 
             // Traverse tree to find action
-            let action_node = match traverse_tree(&self.tree, &context) {
+            let recorded = self.record_decision_paths.then_some(&mut self.recorded_paths);
+            let action_node = match traverse_payment_tree(&self.tree, &context, tx_id, recorded) {
                 Ok(node) => node,
                 Err(e) => {
                     panic!("Tree traversal failed for tx {}: {:?}", tx_id, e);
@@ -549,7 +591,8 @@ impl CashManagerPolicy for TreePolicy {
         }
 
         // Traverse tree to find action
-        let action_node = match traverse_tree(&self.tree, &context) {
+        let recorded = self.record_decision_paths.then_some(&mut self.recorded_paths);
+        let action_node = match traverse_payment_tree(&self.tree, &context, tx.id(), recorded) {
             Ok(node) => node,
             Err(e) => {
                 // Return Hold on error (safe default)
@@ -86,6 +86,62 @@ pub fn create_policy(config: &PolicyConfig) -> Result<TreePolicy, TreePolicyErro
             Ok(policy)
         }
 
+        PolicyConfig::ValueThreshold {
+            threshold,
+            max_hold_ticks,
+        } => {
+            // Generated tree: release once the queue is worth batching, or
+            // once a transaction has waited too long
+            let json = r#"{
+                "version": "1.0",
+                "policy_id": "value_threshold_policy",
+                "description": "Batch releases until queued value meets threshold, with a maximum hold time",
+                "payment_tree": {
+                    "type": "condition",
+                    "node_id": "N1_ThresholdOrTimeout",
+                    "description": "Queue value meets threshold or transaction held too long",
+                    "condition": {
+                        "op": "or",
+                        "conditions": [
+                            {
+                                "op": ">=",
+                                "left": {"field": "queue1_total_value"},
+                                "right": {"param": "threshold"}
+                            },
+                            {
+                                "op": ">=",
+                                "left": {"field": "queue_age"},
+                                "right": {"param": "max_hold_ticks"}
+                            }
+                        ]
+                    },
+                    "on_true": {
+                        "type": "action",
+                        "node_id": "A1_Release",
+                        "action": "Release",
+                        "parameters": {}
+                    },
+                    "on_false": {
+                        "type": "action",
+                        "node_id": "A2_Hold",
+                        "action": "Hold",
+                        "parameters": {
+                            "reason": {"value": "BelowValueThreshold"}
+                        }
+                    }
+                },
+                "parameters": {}
+            }"#;
+            let mut policy = TreePolicy::from_json(json)?;
+
+            let mut params = HashMap::new();
+            params.insert("threshold".to_string(), *threshold as f64);
+            params.insert("max_hold_ticks".to_string(), *max_hold_ticks as f64);
+            policy.with_parameters(params);
+
+            Ok(policy)
+        }
+
         PolicyConfig::MockSplitting { num_splits } => {
             // Load MockSplitting policy and inject num_splits
             let path = policies_dir().join("mock_splitting.json");
@@ -222,6 +278,21 @@ mod tests {
         assert_eq!(tree.parameters.get("urgency_threshold"), Some(&5.0));
     }
 
+    #[test]
+    fn test_create_value_threshold_policy() {
+        let config = PolicyConfig::ValueThreshold {
+            threshold: 500_000,
+            max_hold_ticks: 8,
+        };
+        let policy = create_policy(&config).expect("Failed to create ValueThreshold policy");
+        assert_eq!(policy.policy_id(), "value_threshold_policy");
+
+        // Verify both parameters were injected into the generated tree
+        let tree = policy.tree();
+        assert_eq!(tree.parameters.get("threshold"), Some(&500_000.0));
+        assert_eq!(tree.parameters.get("max_hold_ticks"), Some(&8.0));
+    }
+
     #[test]
     fn test_create_liquidity_aware_with_custom_params() {
         let config = PolicyConfig::LiquidityAware {
@@ -439,6 +439,23 @@ pub fn traverse_tree<'a>(
     traverse_node(root, context, &tree.parameters, 0)
 }
 
+/// Traverse the payment tree with path tracking
+///
+/// Returns both the terminal action node and the decision path taken.
+/// Returns error if payment_tree is not defined.
+pub fn traverse_tree_with_path<'a>(
+    tree: &'a DecisionTreeDef,
+    context: &EvalContext,
+) -> Result<(&'a TreeNode, crate::policy::tree::DecisionPath), EvalError> {
+    let root = tree
+        .payment_tree
+        .as_ref()
+        .ok_or_else(|| EvalError::InvalidTree("payment_tree is not defined".to_string()))?;
+    let mut path = crate::policy::tree::DecisionPath::new();
+    let node = traverse_node_with_path(root, context, &tree.parameters, 0, &mut path)?;
+    Ok((node, path))
+}
+
 /// Traverse the strategic collateral tree to reach an action node.
 ///
 /// Returns the terminal action node reached.
@@ -758,6 +775,25 @@ pub fn build_decision(
             })
         }
 
+        ActionType::ReleaseAmount => {
+            // Explicit partial release: amount now, remainder stays in Queue 1
+            let amount = evaluate_action_parameter(action_params, "amount", context, params)?;
+            let amount_i64 = amount.round() as i64;
+            let remaining = context.get_field("remaining_amount")? as i64;
+
+            if amount_i64 <= 0 || amount_i64 >= remaining {
+                return Err(EvalError::InvalidActionParameter(format!(
+                    "amount must be > 0 and < remaining_amount ({}), got {}",
+                    remaining, amount_i64
+                )));
+            }
+
+            Ok(ReleaseDecision::SubmitAmount {
+                tx_id,
+                amount: amount_i64,
+            })
+        }
+
         ActionType::StaggerSplit => {
             // Phase 3.1: Split with staggered timing
             let num_splits =
@@ -867,6 +903,16 @@ pub fn build_decision(
             })
         }
 
+        ActionType::SetOrderHint => {
+            let order_hint =
+                evaluate_action_parameter(action_params, "order_hint", context, params)?;
+
+            Ok(ReleaseDecision::SetOrderHint {
+                tx_id,
+                order_hint: order_hint as i64,
+            })
+        }
+
         // Phase 3.3/4.5: Bank-level actions are not valid in payment decision context
         ActionType::SetReleaseBudget
         | ActionType::SetState
@@ -1029,9 +1075,11 @@ pub fn build_collateral_decision(
         | ActionType::PaceAndRelease
         | ActionType::Split
         | ActionType::StaggerSplit
+        | ActionType::ReleaseAmount
         | ActionType::Hold
         | ActionType::Drop
         | ActionType::Reprioritize
+        | ActionType::SetOrderHint
         | ActionType::SetReleaseBudget
         | ActionType::SetState
         | ActionType::AddState
@@ -1638,6 +1686,71 @@ mod tests {
         assert_eq!(result, 10.0);
     }
 
+    #[test]
+    fn test_eval_clamp_computation() {
+        let (context, params) = create_test_context();
+
+        // clamp(balance, 0, threshold) → balance exceeds the upper bound
+        let computation = Computation::Clamp {
+            value: Value::Field {
+                field: "balance".to_string(),
+            },
+            min: Value::Literal { value: json!(0) },
+            max: Value::Param {
+                param: "threshold".to_string(),
+            },
+        };
+
+        let result = evaluate_computation(&computation, &context, &params).unwrap();
+        assert_eq!(result, 100_000.0);
+    }
+
+    #[test]
+    fn test_eval_nested_min_max_clamp() {
+        let (context, params) = create_test_context();
+
+        // clamp(min(available_liquidity, amount * multiplier), threshold, max(balance, unsecured_cap))
+        let computation = Computation::Clamp {
+            value: Value::Compute {
+                compute: Box::new(Computation::Min {
+                    values: vec![
+                        Value::Field {
+                            field: "available_liquidity".to_string(),
+                        },
+                        Value::Compute {
+                            compute: Box::new(Computation::Multiply {
+                                left: Value::Field {
+                                    field: "amount".to_string(),
+                                },
+                                right: Value::Param {
+                                    param: "multiplier".to_string(),
+                                },
+                            }),
+                        },
+                    ],
+                }),
+            },
+            min: Value::Param {
+                param: "threshold".to_string(),
+            },
+            max: Value::Compute {
+                compute: Box::new(Computation::Max {
+                    values: vec![
+                        Value::Field {
+                            field: "balance".to_string(),
+                        },
+                        Value::Field {
+                            field: "unsecured_cap".to_string(),
+                        },
+                    ],
+                }),
+            },
+        };
+
+        let result = evaluate_computation(&computation, &context, &params).unwrap();
+        assert_eq!(result, 150_000.0); // min(700000, 150000) within [100000, 500000]
+    }
+
     #[test]
     fn test_eval_empty_max_error() {
         let (context, params) = create_test_context();
@@ -2523,6 +2636,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_build_decision_release_amount() {
+        let (context, params) = create_test_context();
+
+        let release_amount = |amount: i64| {
+            let mut action_params = HashMap::new();
+            action_params.insert(
+                "amount".to_string(),
+                ValueOrCompute::Direct { value: json!(amount) },
+            );
+            let action_node = TreeNode::Action {
+                node_id: "A1".to_string(),
+                action: ActionType::ReleaseAmount,
+                parameters: action_params,
+            };
+            build_decision(&action_node, "tx_001".to_string(), &context, &params)
+        };
+
+        match release_amount(30_000).unwrap() {
+            ReleaseDecision::SubmitAmount { tx_id, amount } => {
+                assert_eq!(tx_id, "tx_001");
+                assert_eq!(amount, 30_000);
+            }
+            _ => panic!("Expected SubmitAmount decision"),
+        }
+
+        // Must leave a positive remainder of the 100,000 transaction
+        for invalid in [0, 100_000, 150_000] {
+            assert!(matches!(
+                release_amount(invalid),
+                Err(EvalError::InvalidActionParameter(_))
+            ));
+        }
+    }
+
     // ============================================================================
     // PHASE 8.2: Collateral Decision Building (TDD Cycle 3)
     // ============================================================================
@@ -2900,4 +3048,32 @@ mod tests {
             _ => panic!("Expected MissingActionParameter error"),
         }
     }
+
+    #[test]
+    fn test_build_set_order_hint_decision() {
+        let (context, params) = create_test_context();
+
+        let mut action_params = HashMap::new();
+        action_params.insert(
+            "order_hint".to_string(),
+            ValueOrCompute::Direct { value: json!(-42) },
+        );
+
+        let action_node = TreeNode::Action {
+            node_id: "H1".to_string(),
+            action: ActionType::SetOrderHint,
+            parameters: action_params,
+        };
+
+        let decision =
+            build_decision(&action_node, "tx_001".to_string(), &context, &params).unwrap();
+
+        match decision {
+            ReleaseDecision::SetOrderHint { tx_id, order_hint } => {
+                assert_eq!(tx_id, "tx_001");
+                assert_eq!(order_hint, -42);
+            }
+            _ => panic!("Expected SetOrderHint decision"),
+        }
+    }
 }
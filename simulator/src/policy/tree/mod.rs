@@ -33,7 +33,7 @@ pub use interpreter::{
     build_bank_decision_with_path, build_collateral_decision, build_decision,
     evaluate_computation, evaluate_expression, evaluate_value, traverse_bank_tree_with_path,
     traverse_end_of_tick_collateral_tree, traverse_strategic_collateral_tree, traverse_tree,
-    EvalError,
+    traverse_tree_with_path, EvalError,
 };
 pub use types::{
     ActionType, Computation, DecisionPath, DecisionPathNode, DecisionTreeDef, Expression,
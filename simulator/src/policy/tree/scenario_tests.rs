@@ -405,8 +405,10 @@ mod tests {
                 | ReleaseDecision::Hold { tx_id, .. }
                 | ReleaseDecision::Drop { tx_id }
                 | ReleaseDecision::SubmitPartial { tx_id, .. }
+                | ReleaseDecision::SubmitAmount { tx_id, .. }
                 | ReleaseDecision::StaggerSplit { tx_id, .. }
                 | ReleaseDecision::Reprioritize { tx_id, .. }
+                | ReleaseDecision::SetOrderHint { tx_id, .. }
                 | ReleaseDecision::WithdrawFromRtgs { tx_id }
                 | ReleaseDecision::ResubmitToRtgs { tx_id, .. } => tx_id == &urgent_id,
             })
@@ -419,8 +421,10 @@ mod tests {
                 | ReleaseDecision::Hold { tx_id, .. }
                 | ReleaseDecision::Drop { tx_id }
                 | ReleaseDecision::SubmitPartial { tx_id, .. }
+                | ReleaseDecision::SubmitAmount { tx_id, .. }
                 | ReleaseDecision::StaggerSplit { tx_id, .. }
                 | ReleaseDecision::Reprioritize { tx_id, .. }
+                | ReleaseDecision::SetOrderHint { tx_id, .. }
                 | ReleaseDecision::WithdrawFromRtgs { tx_id }
                 | ReleaseDecision::ResubmitToRtgs { tx_id, .. } => tx_id == &normal_id,
             })
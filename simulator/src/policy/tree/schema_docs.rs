@@ -710,6 +710,30 @@ impl SchemaDocumented for ActionType {
                 unit: None,
                 added_in: Some("1.0".to_string()),
             },
+            SchemaElement {
+                name: "ReleaseAmount".to_string(),
+                json_key: "ReleaseAmount".to_string(),
+                category: SchemaCategory::PaymentAction,
+                description: "Release an exact amount now and keep the remainder queued".to_string(),
+                semantics: Some("Splits transaction into a released child of `amount` and a retained child of the remainder, which stays in Queue 1".to_string()),
+                parameters: vec![
+                    ParameterDoc {
+                        name: "amount".to_string(),
+                        param_type: "number".to_string(),
+                        required: true,
+                        description: "Amount to release now (cents); must be > 0 and < remaining_amount".to_string(),
+                        example: Some(serde_json::json!(250000)),
+                        valid_values: None,
+                    },
+                ],
+                valid_in_trees: payment_tree.clone(),
+                example_json: Some(serde_json::json!({"type": "action", "node_id": "A6", "action": "ReleaseAmount", "parameters": {"amount": {"field": "available_liquidity"}}})),
+                source_location: "simulator/src/policy/tree/types.rs:294".to_string(),
+                see_also: vec!["Split".to_string(), "Release".to_string()],
+                data_type: None,
+                unit: None,
+                added_in: Some("1.0".to_string()),
+            },
             SchemaElement {
                 name: "Hold".to_string(),
                 json_key: "Hold".to_string(),
@@ -764,6 +788,30 @@ impl SchemaDocumented for ActionType {
                 unit: None,
                 added_in: Some("1.0".to_string()),
             },
+            SchemaElement {
+                name: "SetOrderHint".to_string(),
+                json_key: "SetOrderHint".to_string(),
+                category: SchemaCategory::PaymentAction,
+                description: "Set the tie-breaking order hint without releasing".to_string(),
+                semantics: Some("Orders equal-priority transactions in Queue 1 (lower hint first) under priority_deadline ordering".to_string()),
+                parameters: vec![
+                    ParameterDoc {
+                        name: "order_hint".to_string(),
+                        param_type: "number".to_string(),
+                        required: true,
+                        description: "Ordering key within the same priority (lower = earlier)".to_string(),
+                        example: Some(serde_json::json!(-100)),
+                        valid_values: None,
+                    },
+                ],
+                valid_in_trees: payment_tree.clone(),
+                example_json: Some(serde_json::json!({"type": "action", "node_id": "A9", "action": "SetOrderHint", "parameters": {"order_hint": {"value": -100}}})),
+                source_location: "simulator/src/policy/tree/types.rs:306".to_string(),
+                see_also: vec!["Reprioritize".to_string()],
+                data_type: None,
+                unit: None,
+                added_in: Some("1.0".to_string()),
+            },
             // Bank actions (valid in bank_tree)
             SchemaElement {
                 name: "SetReleaseBudget".to_string(),
@@ -961,6 +1009,139 @@ impl SchemaDocumented for ActionType {
     }
 }
 
+/// Documentation for evaluation context fields
+///
+/// Context fields are being documented incrementally; fields not listed here
+/// are described in the `EvalContext` docs (context.rs).
+pub fn field_schema_docs() -> Vec<SchemaElement> {
+    vec![
+        SchemaElement {
+            name: "recent_inflow_rate".to_string(),
+            json_key: "recent_inflow_rate".to_string(),
+            category: SchemaCategory::AgentField,
+            description: "Average value settled to the agent per tick over the trailing flow window".to_string(),
+            semantics: Some("Sum of settled incoming value over the last flow_window_ticks completed ticks, divided by flow_window_ticks. Payments settling in the current tick are not counted until it ends.".to_string()),
+            parameters: vec![],
+            valid_in_trees: all_trees(),
+            example_json: Some(serde_json::json!({"field": "recent_inflow_rate"})),
+            source_location: "simulator/src/policy/tree/context.rs".to_string(),
+            see_also: vec!["recent_outflow_rate".to_string(), "incoming_expected_value".to_string()],
+            data_type: Some("f64".to_string()),
+            unit: Some("cents/tick".to_string()),
+            added_in: Some("1.0".to_string()),
+        },
+        SchemaElement {
+            name: "recent_outflow_rate".to_string(),
+            json_key: "recent_outflow_rate".to_string(),
+            category: SchemaCategory::AgentField,
+            description: "Average value settled from the agent per tick over the trailing flow window".to_string(),
+            semantics: Some("Sum of settled outgoing value over the last flow_window_ticks completed ticks, divided by flow_window_ticks. Payments settling in the current tick are not counted until it ends.".to_string()),
+            parameters: vec![],
+            valid_in_trees: all_trees(),
+            example_json: Some(serde_json::json!({"field": "recent_outflow_rate"})),
+            source_location: "simulator/src/policy/tree/context.rs".to_string(),
+            see_also: vec!["recent_inflow_rate".to_string()],
+            data_type: Some("f64".to_string()),
+            unit: Some("cents/tick".to_string()),
+            added_in: Some("1.0".to_string()),
+        },
+        SchemaElement {
+            name: "provisional_balance".to_string(),
+            json_key: "provisional_balance".to_string(),
+            category: SchemaCategory::AgentField,
+            description: "Balance including deferred credits received this tick but not yet applied".to_string(),
+            semantics: Some("Under deferred crediting, incoming payments settled during the tick raise provisional_balance immediately but only reach balance at end of tick, so these funds cannot be spent yet. Only the end-of-tick collateral tree runs between settlement and that promotion; the other trees run before settlement and would always see balance. Equals balance when deferred crediting is off for the agent.".to_string()),
+            parameters: vec![],
+            valid_in_trees: vec!["end_of_tick_collateral_tree".to_string()],
+            example_json: Some(serde_json::json!({"field": "provisional_balance"})),
+            source_location: "simulator/src/policy/tree/context.rs".to_string(),
+            see_also: vec!["balance".to_string()],
+            data_type: Some("i64".to_string()),
+            unit: Some("cents".to_string()),
+            added_in: Some("1.0".to_string()),
+        },
+        SchemaElement {
+            name: "liquidity_pressure".to_string(),
+            json_key: "liquidity_pressure".to_string(),
+            category: SchemaCategory::AgentField,
+            description: "Share of the allowed overdraft limit not covered by available liquidity".to_string(),
+            semantics: Some("(allowed_overdraft_limit - available_liquidity) / allowed_overdraft_limit, clamped to [0, 1]: 0.0 while the balance covers the whole limit, 1.0 once the limit is used up. With a zero limit it is 1.0 when no liquidity is left and 0.0 otherwise.".to_string()),
+            parameters: vec![],
+            valid_in_trees: all_trees(),
+            example_json: Some(serde_json::json!({"field": "liquidity_pressure"})),
+            source_location: "simulator/src/policy/tree/context.rs".to_string(),
+            see_also: vec!["available_liquidity".to_string(), "allowed_overdraft_limit".to_string()],
+            data_type: Some("f64".to_string()),
+            unit: Some("ratio".to_string()),
+            added_in: Some("1.0".to_string()),
+        },
+        SchemaElement {
+            name: "ticks_since_arrival".to_string(),
+            json_key: "ticks_since_arrival".to_string(),
+            category: SchemaCategory::DerivedField,
+            description: "Ticks elapsed since the transaction arrived (alias of queue_age)".to_string(),
+            semantics: Some("current_tick - arrival_tick, always equal to queue_age. Zero in the arrival tick; keeps growing while the transaction is held, including after it becomes overdue.".to_string()),
+            parameters: vec![],
+            valid_in_trees: vec!["payment_tree".to_string()],
+            example_json: Some(serde_json::json!({
+                "op": ">",
+                "left": {"field": "ticks_since_arrival"},
+                "right": {"param": "max_wait_ticks"}
+            })),
+            source_location: "simulator/src/policy/tree/context.rs".to_string(),
+            see_also: vec!["ticks_until_deadline".to_string(), "queue_age".to_string()],
+            data_type: Some("usize".to_string()),
+            unit: Some("ticks".to_string()),
+            added_in: Some("1.0".to_string()),
+        },
+        SchemaElement {
+            name: "ticks_until_deadline".to_string(),
+            json_key: "ticks_until_deadline".to_string(),
+            category: SchemaCategory::DerivedField,
+            description: "Ticks remaining before the transaction's deadline".to_string(),
+            semantics: Some("deadline_tick - current_tick, saturating at 0: an overdue transaction reports 0 rather than a negative value. Use ticks_to_deadline when the overrun matters.".to_string()),
+            parameters: vec![],
+            valid_in_trees: vec!["payment_tree".to_string()],
+            example_json: Some(serde_json::json!({"field": "ticks_until_deadline"})),
+            source_location: "simulator/src/policy/tree/context.rs".to_string(),
+            see_also: vec!["ticks_to_deadline".to_string(), "ticks_since_arrival".to_string()],
+            data_type: Some("usize".to_string()),
+            unit: Some("ticks".to_string()),
+            added_in: Some("1.0".to_string()),
+        },
+        SchemaElement {
+            name: "queue2_total_size".to_string(),
+            json_key: "queue2_total_size".to_string(),
+            category: SchemaCategory::SystemField,
+            description: "Number of transactions waiting in the RTGS central queue (Queue 2) across all agents".to_string(),
+            semantics: Some("Read from the simulation state when the context is built, so it reflects submissions made earlier in the same tick. Useful for holding payments while the system is congested.".to_string()),
+            parameters: vec![],
+            valid_in_trees: all_trees(),
+            example_json: Some(serde_json::json!({"field": "queue2_total_size"})),
+            source_location: "simulator/src/policy/tree/context.rs".to_string(),
+            see_also: vec!["rtgs_queue_value".to_string(), "system_queue2_pressure_index".to_string()],
+            data_type: Some("usize".to_string()),
+            unit: Some("transactions".to_string()),
+            added_in: Some("1.0".to_string()),
+        },
+        SchemaElement {
+            name: "system_agents_in_overdraft".to_string(),
+            json_key: "system_agents_in_overdraft".to_string(),
+            category: SchemaCategory::SystemField,
+            description: "Number of agents whose settlement balance is negative".to_string(),
+            semantics: Some("Counts every agent in the simulation, including the evaluating agent. An agent at exactly zero balance is not in overdraft.".to_string()),
+            parameters: vec![],
+            valid_in_trees: all_trees(),
+            example_json: Some(serde_json::json!({"field": "system_agents_in_overdraft"})),
+            source_location: "simulator/src/policy/tree/context.rs".to_string(),
+            see_also: vec!["total_agents".to_string(), "is_using_credit".to_string()],
+            data_type: Some("usize".to_string()),
+            unit: Some("agents".to_string()),
+            added_in: Some("1.0".to_string()),
+        },
+    ]
+}
+
 /// Generate complete policy schema documentation as JSON string
 pub fn get_policy_schema() -> String {
     let schema = PolicySchemaDoc {
@@ -972,7 +1153,7 @@ pub fn get_policy_schema() -> String {
         values: Value::schema_docs(),
         computations: Computation::schema_docs(),
         actions: ActionType::schema_docs(),
-        fields: vec![],  // TODO: Add field docs
+        fields: field_schema_docs(),
     };
 
     serde_json::to_string_pretty(&schema).expect("Schema serialization should not fail")
@@ -1205,8 +1386,8 @@ mod tests {
 
         let docs = ActionType::schema_docs();
 
-        // 8 payment + 3 bank + 3 collateral + 2 RTGS = 16 actions
-        assert_eq!(docs.len(), 16, "Expected 16 action types, got {}", docs.len());
+        // 10 payment + 3 bank + 3 collateral + 2 RTGS = 18 actions
+        assert_eq!(docs.len(), 18, "Expected 18 action types, got {}", docs.len());
 
         let names: Vec<&str> = docs.iter().map(|d| d.name.as_str()).collect();
 
@@ -1216,6 +1397,8 @@ mod tests {
         assert!(names.contains(&"Drop"), "Missing Drop");
         assert!(names.contains(&"Split"), "Missing Split");
         assert!(names.contains(&"StaggerSplit"), "Missing StaggerSplit");
+        assert!(names.contains(&"SetOrderHint"), "Missing SetOrderHint");
+        assert!(names.contains(&"ReleaseAmount"), "Missing ReleaseAmount");
 
         // Bank actions
         assert!(names.contains(&"SetReleaseBudget"), "Missing SetReleaseBudget");
@@ -1306,7 +1489,7 @@ mod tests {
         let schema = super::get_policy_schema();
         let parsed: PolicySchemaDoc = serde_json::from_str(&schema).unwrap();
 
-        assert_eq!(parsed.actions.len(), 16);
+        assert_eq!(parsed.actions.len(), 18);
     }
 
     #[test]
@@ -1316,4 +1499,77 @@ mod tests {
 
         assert_eq!(parsed.values.len(), 4);
     }
+
+    #[test]
+    fn test_get_policy_schema_documents_flow_rate_fields() {
+        let schema = super::get_policy_schema();
+        let parsed: PolicySchemaDoc = serde_json::from_str(&schema).unwrap();
+
+        let names: Vec<&str> = parsed.fields.iter().map(|f| f.name.as_str()).collect();
+        assert!(names.contains(&"recent_inflow_rate"));
+        assert!(names.contains(&"recent_outflow_rate"));
+        assert!(parsed
+            .fields
+            .iter()
+            .filter(|f| f.name.starts_with("recent_"))
+            .all(|f| f.category == SchemaCategory::AgentField));
+    }
+
+    #[test]
+    fn test_get_policy_schema_documents_liquidity_pressure() {
+        let schema = super::get_policy_schema();
+        let parsed: PolicySchemaDoc = serde_json::from_str(&schema).unwrap();
+
+        let field = parsed
+            .fields
+            .iter()
+            .find(|f| f.name == "liquidity_pressure")
+            .expect("liquidity_pressure should be documented");
+        assert_eq!(field.category, SchemaCategory::AgentField);
+    }
+
+    #[test]
+    fn test_get_policy_schema_documents_provisional_balance() {
+        let schema = super::get_policy_schema();
+        let parsed: PolicySchemaDoc = serde_json::from_str(&schema).unwrap();
+
+        let field = parsed
+            .fields
+            .iter()
+            .find(|f| f.name == "provisional_balance")
+            .expect("provisional_balance should be documented");
+        assert_eq!(field.category, SchemaCategory::AgentField);
+        assert_eq!(field.valid_in_trees, vec!["end_of_tick_collateral_tree".to_string()]);
+    }
+
+    #[test]
+    fn test_get_policy_schema_documents_transaction_age_fields() {
+        let schema = super::get_policy_schema();
+        let parsed: PolicySchemaDoc = serde_json::from_str(&schema).unwrap();
+
+        for name in ["ticks_since_arrival", "ticks_until_deadline"] {
+            let field = parsed
+                .fields
+                .iter()
+                .find(|f| f.name == name)
+                .unwrap_or_else(|| panic!("{} should be documented", name));
+            assert_eq!(field.category, SchemaCategory::DerivedField);
+            assert_eq!(field.valid_in_trees, vec!["payment_tree".to_string()]);
+        }
+    }
+
+    #[test]
+    fn test_get_policy_schema_documents_system_pressure_fields() {
+        let schema = super::get_policy_schema();
+        let parsed: PolicySchemaDoc = serde_json::from_str(&schema).unwrap();
+
+        for name in ["queue2_total_size", "system_agents_in_overdraft"] {
+            let field = parsed
+                .fields
+                .iter()
+                .find(|f| f.name == name)
+                .unwrap_or_else(|| panic!("{} should be documented", name));
+            assert_eq!(field.category, SchemaCategory::SystemField);
+        }
+    }
 }
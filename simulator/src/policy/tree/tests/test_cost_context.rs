@@ -238,6 +238,8 @@ mod test_cost_context {
         assert!(context.has_field("cost_split_friction"));
         let split_friction = context.get_field("cost_split_friction").unwrap();
         assert_eq!(split_friction, 1000.0);
+        // Default friction is linear in the number of extra splits
+        assert_eq!(context.get_field("cost_split_friction_exponent").unwrap(), 1.0);
     }
 
     // ========================================================================
@@ -399,6 +401,7 @@ mod test_cost_context {
         assert!(field_names.contains(&"cost_delay_per_tick_per_cent"));
         assert!(field_names.contains(&"cost_collateral_bps_per_tick"));
         assert!(field_names.contains(&"cost_split_friction"));
+        assert!(field_names.contains(&"cost_split_friction_exponent"));
         assert!(field_names.contains(&"cost_deadline_penalty"));
         assert!(field_names.contains(&"cost_eod_penalty"));
         assert!(field_names.contains(&"cost_delay_this_tx_one_tick"));
@@ -577,4 +580,24 @@ mod test_cost_context {
             10_000.0,
         );
     }
+
+    #[test]
+    fn test_split_friction_exponent_in_both_contexts() {
+        // Policies need the exponent to reproduce the engine's split charge
+        let agent = Agent::new("BANK_A".to_string(), 1_000_000);
+        let tx = Transaction::new("BANK_A".to_string(), "BANK_B".to_string(), 100_000, 0, 100);
+        let state = SimulationState::new(vec![agent.clone()]);
+
+        let cost_rates = CostRates {
+            split_friction_cost: 1000,
+            split_friction_exponent: 1.5,
+            ..Default::default()
+        };
+
+        let tx_context = EvalContext::build(&tx, &agent, &state, 50, &cost_rates, 100, 0.8);
+        let bank_context = EvalContext::bank_level(&agent, &state, 50, &cost_rates, 100, 0.8);
+
+        assert_eq!(tx_context.get_field("cost_split_friction_exponent").unwrap(), 1.5);
+        assert_eq!(bank_context.get_field("cost_split_friction_exponent").unwrap(), 1.5);
+    }
 }
@@ -290,6 +290,9 @@ pub enum ActionType {
     /// Split transaction with staggered release timing (Phase 3.1)
     StaggerSplit,
 
+    /// Release an exact `amount` now, keeping the remainder in Queue 1
+    ReleaseAmount,
+
     /// Hold transaction in Queue 1 for later
     Hold,
 
@@ -300,6 +303,10 @@ pub enum ActionType {
     /// Change transaction priority without moving from Queue 1
     Reprioritize,
 
+    /// Set the transaction's Queue 1 ordering hint
+    /// Tie-breaker within the same priority; lower hints are processed first
+    SetOrderHint,
+
     // Phase 3.3: Bank-Level Budget Actions (Policy Enhancements V2)
     /// Set release budget for this tick (bank-level action)
     /// Evaluated once per tick before processing transactions.
@@ -340,6 +340,8 @@ fn is_transaction_only_field(field: &str) -> bool {
             | "overdue_duration"
             | "ticks_to_deadline"
             | "queue_age"
+            | "ticks_since_arrival"
+            | "ticks_until_deadline"
             | "time_in_queue"
             | "cost_delay_so_far"
             | "cost_if_settled_now"
@@ -359,6 +361,7 @@ fn is_bank_level_field(field: &str) -> bool {
         field,
         // Agent fields
         "balance"
+            | "provisional_balance"
             | "credit_limit"
             | "available_liquidity"
             | "credit_used"
@@ -368,6 +371,9 @@ fn is_bank_level_field(field: &str) -> bool {
             | "liquidity_buffer"
             | "outgoing_queue_size"
             | "incoming_expected_count"
+            | "incoming_expected_value"
+            | "recent_inflow_rate"
+            | "recent_outflow_rate"
             | "liquidity_pressure"
             | "is_overdraft_capped"
             // Queue 1 metrics
@@ -379,6 +385,8 @@ fn is_bank_level_field(field: &str) -> bool {
             | "rtgs_queue_size"
             | "rtgs_queue_value"
             | "total_agents"
+            | "queue2_total_size"
+            | "system_agents_in_overdraft"
             // Collateral fields
             | "posted_collateral"
             | "max_collateral_capacity"
@@ -401,6 +409,7 @@ fn is_bank_level_field(field: &str) -> bool {
             | "cost_delay_per_tick_per_cent"
             | "cost_collateral_bps_per_tick"
             | "cost_split_friction"
+            | "cost_split_friction_exponent"
             | "cost_deadline_penalty"
             | "cost_eod_penalty"
             // Time/day fields
@@ -1246,6 +1255,67 @@ mod tests {
             .any(|e| matches!(e, ValidationError::InvalidParameterReference(_))));
     }
 
+    #[test]
+    fn test_reject_invalid_references_nested_in_clamp() {
+        let context = create_sample_context();
+
+        // clamp(min(amount, bogus_field), 0, missing_param)
+        let tree = DecisionTreeDef {
+            version: "1.0".to_string(),
+            policy_id: "test".to_string(),
+            description: None,
+            bank_tree: None,
+            payment_tree: Some(TreeNode::Condition {
+                node_id: "N1".to_string(),
+                description: String::new(),
+                condition: Expression::GreaterThan {
+                    left: Value::Compute {
+                        compute: Box::new(Computation::Clamp {
+                            value: Value::Compute {
+                                compute: Box::new(Computation::Min {
+                                    values: vec![
+                                        Value::Field {
+                                            field: "amount".to_string(),
+                                        },
+                                        Value::Field {
+                                            field: "bogus_field".to_string(), // INVALID
+                                        },
+                                    ],
+                                }),
+                            },
+                            min: Value::Literal { value: json!(0) },
+                            max: Value::Param {
+                                param: "missing_param".to_string(), // INVALID
+                            },
+                        }),
+                    },
+                    right: Value::Literal { value: json!(0) },
+                },
+                on_true: Box::new(TreeNode::Action {
+                    node_id: "A1".to_string(),
+                    action: ActionType::Release,
+                    parameters: HashMap::new(),
+                }),
+                on_false: Box::new(TreeNode::Action {
+                    node_id: "A2".to_string(),
+                    action: ActionType::Hold,
+                    parameters: HashMap::new(),
+                }),
+            }),
+            strategic_collateral_tree: None,
+            end_of_tick_collateral_tree: None,
+            parameters: HashMap::new(),
+        };
+
+        let errors = validate_tree(&tree, &context).unwrap_err();
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, ValidationError::InvalidFieldReference(_))));
+        assert!(errors
+            .iter()
+            .any(|e| matches!(e, ValidationError::InvalidParameterReference(_))));
+    }
+
     // ========================================================================
     // Phase 6.13: Division Safety Tests
     // ========================================================================
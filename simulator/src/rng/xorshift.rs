@@ -31,6 +31,13 @@ use serde::{Deserialize, Serialize};
 pub struct RngManager {
     /// Internal state (64-bit)
     state: u64,
+
+    /// Second Box-Muller value held back by `next_gaussian()`
+    ///
+    /// Part of the RNG state: cloning or serializing the manager carries it,
+    /// so a restored RNG continues the exact same Gaussian sequence.
+    #[serde(default)]
+    cached_gaussian: Option<f64>,
 }
 
 impl RngManager {
@@ -48,7 +55,52 @@ impl RngManager {
     pub fn new(seed: u64) -> Self {
         // Ensure seed is never zero (xorshift requirement)
         let state = if seed == 0 { 1 } else { seed };
-        Self { state }
+        Self {
+            state,
+            cached_gaussian: None,
+        }
+    }
+
+    /// Restore an RNG from a previously captured state
+    ///
+    /// Unlike `new()`, this is meant for values obtained from `get_state()`
+    /// (e.g. a checkpoint), and sets the xorshift state directly. The Gaussian
+    /// cache starts empty; use `with_cached_gaussian()` to restore it too.
+    ///
+    /// # Arguments
+    /// * `state` - Internal state returned by `get_state()`
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::RngManager;
+    ///
+    /// let mut rng = RngManager::new(12345);
+    /// rng.next();
+    ///
+    /// let mut restored = RngManager::from_state(rng.get_state());
+    /// assert_eq!(rng.next(), restored.next());
+    /// ```
+    pub fn from_state(state: u64) -> Self {
+        // A zero state would lock xorshift at zero forever
+        let state = if state == 0 { 1 } else { state };
+        Self {
+            state,
+            cached_gaussian: None,
+        }
+    }
+
+    /// Set the pending Gaussian value (builder pattern)
+    ///
+    /// Pairs with `cached_gaussian()` so a checkpoint can restore the
+    /// complete RNG state, not just the xorshift word.
+    pub fn with_cached_gaussian(mut self, cached: Option<f64>) -> Self {
+        self.cached_gaussian = cached;
+        self
+    }
+
+    /// Get the pending second Box-Muller value, if any
+    pub fn cached_gaussian(&self) -> Option<f64> {
+        self.cached_gaussian
     }
 
     /// Generate next random u64 value
@@ -64,12 +116,8 @@ impl RngManager {
     /// ```
     pub fn next(&mut self) -> u64 {
         // xorshift64* algorithm
-        let mut x = self.state;
-        x ^= x >> 12;
-        x ^= x << 25;
-        x ^= x >> 27;
-        self.state = x;
-        x.wrapping_mul(0x2545F4914F6CDD1D)
+        self.state = xorshift_step(self.state);
+        self.state.wrapping_mul(0x2545F4914F6CDD1D)
     }
 
     /// Generate random value in range [min, max)
@@ -96,6 +144,28 @@ impl RngManager {
         min + (value % range_size) as i64
     }
 
+    /// Shuffle a slice in place (Fisher-Yates)
+    ///
+    /// Draws one value per element after the first, so the permutation is
+    /// fully determined by the current RNG state.
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::RngManager;
+    ///
+    /// let mut a = [1, 2, 3, 4, 5];
+    /// let mut b = [1, 2, 3, 4, 5];
+    /// RngManager::new(12345).shuffle(&mut a);
+    /// RngManager::new(12345).shuffle(&mut b);
+    /// assert_eq!(a, b);
+    /// ```
+    pub fn shuffle<T>(&mut self, slice: &mut [T]) {
+        for i in (1..slice.len()).rev() {
+            let j = self.range(0, i as i64 + 1) as usize;
+            slice.swap(i, j);
+        }
+    }
+
     /// Get current RNG state (for checkpointing/replay)
     ///
     /// # Example
@@ -106,7 +176,7 @@ impl RngManager {
     /// let state = rng.get_state();
     ///
     /// // Later, can recreate RNG from this state
-    /// let rng2 = RngManager::new(state);
+    /// let rng2 = RngManager::from_state(state);
     /// ```
     pub fn get_state(&self) -> u64 {
         self.state
@@ -130,6 +200,40 @@ impl RngManager {
         (value >> 11) as f64 * (1.0 / ((1u64 << 53) as f64))
     }
 
+    /// Generate a standard normal sample (mean 0, std dev 1)
+    ///
+    /// Uses the Box-Muller transform, which turns two uniform draws into two
+    /// independent normals. The first call of each pair returns the cosine
+    /// branch and caches the sine branch; the next call returns the cached
+    /// value without advancing the xorshift state.
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::RngManager;
+    ///
+    /// let mut rng = RngManager::new(12345);
+    /// let z = rng.next_gaussian();
+    /// assert!(z.is_finite());
+    /// ```
+    pub fn next_gaussian(&mut self) -> f64 {
+        if let Some(cached) = self.cached_gaussian.take() {
+            return cached;
+        }
+
+        // ln(0) is -inf, so redraw in the (astronomically rare) zero case
+        let mut u1 = self.next_f64();
+        while u1 == 0.0 {
+            u1 = self.next_f64();
+        }
+        let u2 = self.next_f64();
+
+        let radius = (-2.0 * u1.ln()).sqrt();
+        let theta = 2.0 * std::f64::consts::PI * u2;
+
+        self.cached_gaussian = Some(radius * theta.sin());
+        radius * theta.cos()
+    }
+
     /// Sample from Poisson distribution with given rate (lambda)
     ///
     /// Uses the Knuth algorithm for Poisson sampling.
@@ -167,6 +271,243 @@ impl RngManager {
 
         k - 1
     }
+
+    /// Sample from a Gamma distribution with given shape and scale
+    ///
+    /// Uses the Marsaglia-Tsang squeeze method for `shape >= 1`; smaller
+    /// shapes are boosted to `shape + 1` and scaled by `u^(1/shape)`.
+    /// Non-positive parameters return 0.0.
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::RngManager;
+    ///
+    /// let mut rng = RngManager::new(12345);
+    /// let x = rng.gamma(2.0, 1.5); // Mean 3.0
+    /// assert!(x >= 0.0);
+    /// ```
+    pub fn gamma(&mut self, shape: f64, scale: f64) -> f64 {
+        if shape <= 0.0 || scale <= 0.0 {
+            return 0.0;
+        }
+
+        if shape < 1.0 {
+            let boost = self.next_f64().powf(1.0 / shape);
+            return self.gamma(shape + 1.0, scale) * boost;
+        }
+
+        let d = shape - 1.0 / 3.0;
+        let c = 1.0 / (9.0 * d).sqrt();
+
+        loop {
+            let z = self.next_gaussian();
+            let v = 1.0 + c * z;
+            if v <= 0.0 {
+                continue;
+            }
+            let v = v * v * v;
+            let u = self.next_f64();
+
+            if u < 1.0 - 0.0331 * z.powi(4) || u.ln() < 0.5 * z * z + d * (1.0 - v + v.ln()) {
+                return d * v * scale;
+            }
+        }
+    }
+
+    /// Sample from a negative binomial distribution
+    ///
+    /// Counts failures before `r` successes with success probability `p`, so
+    /// the mean is `r(1-p)/p` and the variance `r(1-p)/p²`. Sampled as a
+    /// gamma-Poisson mixture, which also supports non-integer `r`.
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::RngManager;
+    ///
+    /// let mut rng = RngManager::new(12345);
+    /// let count = rng.negative_binomial(2.0, 0.5); // Mean 2.0, variance 4.0
+    /// ```
+    pub fn negative_binomial(&mut self, r: f64, p: f64) -> u32 {
+        if r <= 0.0 || p <= 0.0 || p >= 1.0 {
+            return 0;
+        }
+
+        let lambda = self.gamma(r, (1.0 - p) / p);
+        self.poisson(lambda)
+    }
+
+    /// Draw an index with probability proportional to its weight
+    ///
+    /// Scales one uniform draw by the total weight and walks the cumulative
+    /// weights, returning the first index where the running total reaches
+    /// the target. Always consumes exactly one draw, even for an all-zero
+    /// vector (which returns index 0). Falls back to the last index if
+    /// rounding leaves the target unreached.
+    ///
+    /// # Panics
+    /// Panics if `weights` is empty.
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::RngManager;
+    ///
+    /// let mut rng = RngManager::new(12345);
+    /// let idx = rng.sample_weighted_index(&[0.0, 3.0, 1.0]);
+    /// assert!(idx == 1 || idx == 2);
+    /// ```
+    pub fn sample_weighted_index(&mut self, weights: &[f64]) -> usize {
+        assert!(!weights.is_empty(), "weights must not be empty");
+
+        let total_weight: f64 = weights.iter().sum();
+        let mut target = self.next_f64() * total_weight;
+
+        for (i, weight) in weights.iter().enumerate() {
+            target -= weight;
+            if target <= 0.0 {
+                return i;
+            }
+        }
+
+        weights.len() - 1
+    }
+
+    /// Derive an independent sub-stream identified by `label`
+    ///
+    /// The child seed mixes this RNG's current state with an FNV-1a hash of
+    /// the label through splitmix64 finalizers, so distinct labels give
+    /// uncorrelated streams and the same (state, label) pair always gives the
+    /// same stream. Does not advance this RNG.
+    ///
+    /// Deriving from a freshly seeded RNG makes each sub-stream depend only
+    /// on the base seed and the label, so adding a new label (e.g. a new
+    /// agent) leaves the other streams untouched.
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::RngManager;
+    ///
+    /// let base = RngManager::new(12345);
+    /// let mut a1 = base.derive_stream("BANK_A");
+    /// let mut a2 = base.derive_stream("BANK_A");
+    /// let mut b = base.derive_stream("BANK_B");
+    ///
+    /// let first = a1.next();
+    /// assert_eq!(first, a2.next());
+    /// assert_ne!(first, b.next());
+    /// ```
+    pub fn derive_stream(&self, label: &str) -> RngManager {
+        // FNV-1a: stable across platforms and Rust versions (unlike std hashers)
+        let mut label_hash: u64 = 0xcbf29ce484222325;
+        for byte in label.as_bytes() {
+            label_hash ^= *byte as u64;
+            label_hash = label_hash.wrapping_mul(0x100000001b3);
+        }
+
+        RngManager::new(splitmix64(self.state ^ splitmix64(label_hash)))
+    }
+
+    /// Advance the state as if `next()` had been called `steps` times
+    ///
+    /// The xorshift transition is linear over GF(2), so this raises its
+    /// 64×64 bit matrix to the `steps` power by repeated squaring: O(log steps)
+    /// matrix products regardless of jump size. The Gaussian cache is left
+    /// untouched, exactly as `next()` leaves it.
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::RngManager;
+    ///
+    /// let mut jumped = RngManager::new(12345);
+    /// jumped.jump(1_000);
+    ///
+    /// let mut stepped = RngManager::new(12345);
+    /// for _ in 0..1_000 {
+    ///     stepped.next();
+    /// }
+    /// assert_eq!(jumped.next(), stepped.next());
+    /// ```
+    pub fn jump(&mut self, steps: u64) {
+        self.state = gf2_apply(&gf2_power(&xorshift_matrix(), steps), self.state);
+    }
+
+    /// Create the RNG for Monte Carlo replica `index`
+    ///
+    /// Replica `i` starts `i * 2^48` draws ahead of this RNG's current state,
+    /// so replicas are reproducible from one master seed and, for indices
+    /// below 2^16, run over non-overlapping blocks of the xorshift period.
+    /// Replica 0 continues this RNG's sequence. Does not advance this RNG.
+    ///
+    /// # Example
+    /// ```
+    /// use payment_simulator_core_rs::RngManager;
+    ///
+    /// let master = RngManager::new(12345);
+    /// let mut first = master.spawn_replica(1);
+    /// let mut again = master.spawn_replica(1);
+    /// let mut second = master.spawn_replica(2);
+    ///
+    /// let value = first.next();
+    /// assert_eq!(value, again.next());
+    /// assert_ne!(value, second.next());
+    /// ```
+    pub fn spawn_replica(&self, index: u64) -> RngManager {
+        let stride = gf2_power(&xorshift_matrix(), 1 << REPLICA_STRIDE_LOG2);
+        RngManager::from_state(gf2_apply(&gf2_power(&stride, index), self.state))
+    }
+}
+
+/// Replicas are spaced 2^48 draws apart (room for 2^16 disjoint replicas)
+const REPLICA_STRIDE_LOG2: u32 = 48;
+
+/// One xorshift64 state transition (without the output multiplier)
+fn xorshift_step(mut x: u64) -> u64 {
+    x ^= x >> 12;
+    x ^= x << 25;
+    x ^= x >> 27;
+    x
+}
+
+/// 64×64 matrix over GF(2), stored as the images of the 64 unit vectors
+type Gf2Matrix = [u64; 64];
+
+/// The xorshift transition as a GF(2) matrix
+fn xorshift_matrix() -> Gf2Matrix {
+    std::array::from_fn(|bit| xorshift_step(1 << bit))
+}
+
+/// Multiply matrix `m` by bit vector `v`
+fn gf2_apply(m: &Gf2Matrix, v: u64) -> u64 {
+    let mut result = 0;
+    let mut bits = v;
+    while bits != 0 {
+        result ^= m[bits.trailing_zeros() as usize];
+        bits &= bits - 1;
+    }
+    result
+}
+
+/// Raise `m` to the power `exp` by repeated squaring
+fn gf2_power(m: &Gf2Matrix, mut exp: u64) -> Gf2Matrix {
+    let mut result: Gf2Matrix = std::array::from_fn(|bit| 1 << bit);
+    let mut base = *m;
+    while exp != 0 {
+        if exp & 1 == 1 {
+            result = std::array::from_fn(|bit| gf2_apply(&base, result[bit]));
+        }
+        exp >>= 1;
+        if exp != 0 {
+            base = std::array::from_fn(|bit| gf2_apply(&base, base[bit]));
+        }
+    }
+    result
+}
+
+/// splitmix64 finalizer: bijective 64-bit mixing function
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9E3779B97F4A7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+    z ^ (z >> 31)
 }
 
 #[cfg(test)]
@@ -186,6 +527,29 @@ mod tests {
         rng.range(100, 50); // min > max should panic
     }
 
+    #[test]
+    fn test_shuffle_is_deterministic_permutation() {
+        let original: Vec<u32> = (0..20).collect();
+
+        let mut a = original.clone();
+        let mut b = original.clone();
+        RngManager::new(7).shuffle(&mut a);
+        RngManager::new(7).shuffle(&mut b);
+        assert_eq!(a, b);
+        assert_ne!(a, original);
+
+        let mut sorted = a.clone();
+        sorted.sort();
+        assert_eq!(sorted, original);
+
+        // Empty and single-element slices are left untouched
+        let mut empty: [u32; 0] = [];
+        RngManager::new(7).shuffle(&mut empty);
+        let mut single = [42];
+        RngManager::new(7).shuffle(&mut single);
+        assert_eq!(single, [42]);
+    }
+
     #[test]
     fn test_next_f64_in_range() {
         let mut rng = RngManager::new(12345);
@@ -211,4 +575,253 @@ mod tests {
             assert_eq!(val1, val2, "next_f64() not deterministic");
         }
     }
+
+    #[test]
+    fn test_next_gaussian_deterministic() {
+        let mut rng1 = RngManager::new(424242);
+        let mut rng2 = RngManager::new(424242);
+
+        for _ in 0..101 {
+            let z1 = rng1.next_gaussian();
+            let z2 = rng2.next_gaussian();
+            assert_eq!(z1.to_bits(), z2.to_bits(), "next_gaussian() not deterministic");
+        }
+    }
+
+    #[test]
+    fn test_next_gaussian_uses_cached_pair() {
+        let mut rng = RngManager::new(12345);
+        let mut reference = RngManager::new(12345);
+
+        // Fresh draw: consumes two uniforms, returns the cosine branch
+        let first = rng.next_gaussian();
+        let u1 = reference.next_f64();
+        let u2 = reference.next_f64();
+        let radius = (-2.0 * u1.ln()).sqrt();
+        let theta = 2.0 * std::f64::consts::PI * u2;
+        assert_eq!(first.to_bits(), (radius * theta.cos()).to_bits());
+        assert_eq!(rng.get_state(), reference.get_state());
+
+        // Cached draw: returns the sine branch without advancing state
+        let second = rng.next_gaussian();
+        assert_eq!(second.to_bits(), (radius * theta.sin()).to_bits());
+        assert_eq!(rng.get_state(), reference.get_state());
+
+        // Third call starts a new pair
+        rng.next_gaussian();
+        assert_ne!(rng.get_state(), reference.get_state());
+    }
+
+    #[test]
+    fn test_next_gaussian_cache_survives_serde_round_trip() {
+        let mut rng = RngManager::new(777);
+        rng.next_gaussian(); // leaves a cached value behind
+
+        let json = serde_json::to_string(&rng).unwrap();
+        let mut restored: RngManager = serde_json::from_str(&json).unwrap();
+
+        for _ in 0..10 {
+            assert_eq!(rng.next_gaussian().to_bits(), restored.next_gaussian().to_bits());
+        }
+    }
+
+    #[test]
+    fn test_from_state_resumes_sequence() {
+        let mut rng = RngManager::new(31337);
+        for _ in 0..17 {
+            rng.next();
+        }
+        rng.next_gaussian();
+
+        let mut restored =
+            RngManager::from_state(rng.get_state()).with_cached_gaussian(rng.cached_gaussian());
+
+        assert_eq!(rng.next_gaussian().to_bits(), restored.next_gaussian().to_bits());
+        for _ in 0..100 {
+            assert_eq!(rng.next(), restored.next());
+        }
+    }
+
+    #[test]
+    fn test_derive_stream_is_deterministic_and_label_specific() {
+        let base = RngManager::new(42);
+        let mut a1 = base.derive_stream("BANK_A");
+        let mut a2 = RngManager::new(42).derive_stream("BANK_A");
+        let mut b = base.derive_stream("BANK_B");
+
+        let seq_a1: Vec<u64> = (0..10).map(|_| a1.next()).collect();
+        let seq_a2: Vec<u64> = (0..10).map(|_| a2.next()).collect();
+        let seq_b: Vec<u64> = (0..10).map(|_| b.next()).collect();
+
+        assert_eq!(seq_a1, seq_a2);
+        assert_ne!(seq_a1, seq_b);
+        // Deriving does not advance the parent
+        assert_eq!(base.get_state(), 42);
+    }
+
+    #[test]
+    fn test_next_gaussian_moments() {
+        let mut rng = RngManager::new(2024);
+        let n = 20_000;
+        let samples: Vec<f64> = (0..n).map(|_| rng.next_gaussian()).collect();
+
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let var = samples.iter().map(|z| (z - mean).powi(2)).sum::<f64>() / n as f64;
+
+        assert!(mean.abs() < 0.05, "mean {} too far from 0", mean);
+        assert!((var - 1.0).abs() < 0.05, "variance {} too far from 1", var);
+    }
+
+    #[test]
+    fn test_gamma_moments() {
+        let mut rng = RngManager::new(7);
+        let n = 20_000;
+
+        for (shape, scale) in [(0.5, 2.0), (3.0, 1.5)] {
+            let samples: Vec<f64> = (0..n).map(|_| rng.gamma(shape, scale)).collect();
+            let mean = samples.iter().sum::<f64>() / n as f64;
+            let var = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n as f64;
+
+            let expected_mean = shape * scale;
+            let expected_var = shape * scale * scale;
+            assert!(samples.iter().all(|x| *x >= 0.0));
+            assert!(
+                (mean - expected_mean).abs() < 0.05 * expected_mean,
+                "gamma({}, {}) mean {} too far from {}",
+                shape,
+                scale,
+                mean,
+                expected_mean
+            );
+            assert!(
+                (var - expected_var).abs() < 0.1 * expected_var,
+                "gamma({}, {}) variance {} too far from {}",
+                shape,
+                scale,
+                var,
+                expected_var
+            );
+        }
+    }
+
+    #[test]
+    fn test_negative_binomial_deterministic_and_degenerate() {
+        let mut rng1 = RngManager::new(555);
+        let mut rng2 = RngManager::new(555);
+        for _ in 0..100 {
+            assert_eq!(rng1.negative_binomial(2.0, 0.3), rng2.negative_binomial(2.0, 0.3));
+        }
+
+        let mut rng = RngManager::new(1);
+        assert_eq!(rng.negative_binomial(0.0, 0.5), 0);
+        assert_eq!(rng.negative_binomial(2.0, 1.0), 0);
+        assert_eq!(rng.get_state(), RngManager::new(1).get_state());
+    }
+
+    /// The cumulative draw as it was inlined at the arrival call sites
+    fn inline_categorical_draw(rng: &mut RngManager, weights: &[f64]) -> usize {
+        let total_weight: f64 = weights.iter().sum();
+        let mut target = rng.next_f64() * total_weight;
+        for (i, weight) in weights.iter().enumerate() {
+            target -= weight;
+            if target <= 0.0 {
+                return i;
+            }
+        }
+        weights.len() - 1
+    }
+
+    #[test]
+    fn test_sample_weighted_index_matches_inline_draw() {
+        let weight_sets: [&[f64]; 4] = [
+            &[1.0, 1.0, 1.0],
+            &[0.1, 5.0, 0.0, 2.5],
+            &[0.0, 0.0],
+            &[3.0],
+        ];
+        for weights in weight_sets {
+            let mut rng = RngManager::new(4242);
+            let mut reference = RngManager::new(4242);
+            for _ in 0..1_000 {
+                assert_eq!(
+                    rng.sample_weighted_index(weights),
+                    inline_categorical_draw(&mut reference, weights)
+                );
+            }
+            assert_eq!(rng.get_state(), reference.get_state());
+        }
+    }
+
+    #[test]
+    fn test_sample_weighted_index_frequencies() {
+        let weights = [0.05, 0.15, 0.0, 0.8];
+        let n = 50_000;
+        let mut counts = [0usize; 4];
+        let mut rng = RngManager::new(99);
+        for _ in 0..n {
+            counts[rng.sample_weighted_index(&weights)] += 1;
+        }
+
+        assert_eq!(counts[2], 0, "zero-weight index must never be drawn");
+        for (i, weight) in weights.iter().enumerate() {
+            let freq = counts[i] as f64 / n as f64;
+            assert!(
+                (freq - weight).abs() < 0.01,
+                "index {} frequency {} too far from {}",
+                i,
+                freq,
+                weight
+            );
+        }
+    }
+
+    #[test]
+    fn test_jump_matches_repeated_next() {
+        for steps in [0, 1, 2, 63, 64, 1_000, 12_345] {
+            let mut jumped = RngManager::new(987654321);
+            jumped.jump(steps);
+
+            let mut stepped = RngManager::new(987654321);
+            for _ in 0..steps {
+                stepped.next();
+            }
+
+            assert_eq!(jumped.get_state(), stepped.get_state(), "steps = {}", steps);
+            assert_eq!(jumped.next(), stepped.next());
+        }
+    }
+
+    #[test]
+    fn test_jumps_compose() {
+        let mut split = RngManager::new(5);
+        split.jump(1 << 40);
+        split.jump(12_345);
+
+        let mut whole = RngManager::new(5);
+        whole.jump((1 << 40) + 12_345);
+
+        assert_eq!(split.get_state(), whole.get_state());
+    }
+
+    #[test]
+    fn test_spawn_replica_is_reproducible_and_disjoint() {
+        let master = RngManager::new(42);
+
+        let mut zero = master.spawn_replica(0);
+        assert_eq!(zero.next(), master.clone().next());
+
+        let draws = |index: u64| -> Vec<u64> {
+            let mut rng = master.spawn_replica(index);
+            (0..1_000).map(|_| rng.next()).collect()
+        };
+        let replica_1 = draws(1);
+        let replica_2 = draws(2);
+
+        assert_eq!(replica_1, draws(1));
+        let seen: std::collections::HashSet<u64> = replica_1.iter().copied().collect();
+        assert!(replica_2.iter().all(|value| !seen.contains(value)));
+
+        // Spawning does not advance the master
+        assert_eq!(master.get_state(), 42);
+    }
 }
@@ -32,7 +32,7 @@
 
 use crate::models::event::Event;
 use crate::models::state::SimulationState;
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, BTreeSet};
 
 /// Accumulator for deferred credits during a tick.
 ///
@@ -45,16 +45,40 @@ pub struct DeferredCredits {
     ///
     /// Uses BTreeMap for deterministic iteration order (sorted by agent_id)
     pending: BTreeMap<String, (i64, Vec<String>)>,
+
+    /// Receivers whose credits are deferred (`None` = every agent)
+    ///
+    /// Credits to any other receiver are applied immediately by the caller.
+    deferred_agents: Option<BTreeSet<String>>,
 }
 
 impl DeferredCredits {
-    /// Create a new empty accumulator.
+    /// Create a new empty accumulator that defers credits to every agent.
     pub fn new() -> Self {
         Self {
             pending: BTreeMap::new(),
+            deferred_agents: None,
+        }
+    }
+
+    /// Create a new empty accumulator that defers credits only to `agent_ids`.
+    ///
+    /// Used when deferred crediting is configured per agent: receivers not in
+    /// the set keep immediate crediting (and within-tick recycling).
+    pub fn for_agents(agent_ids: impl IntoIterator<Item = String>) -> Self {
+        Self {
+            pending: BTreeMap::new(),
+            deferred_agents: Some(agent_ids.into_iter().collect()),
         }
     }
 
+    /// Check whether credits to `agent_id` should be deferred.
+    pub fn defers(&self, agent_id: &str) -> bool {
+        self.deferred_agents
+            .as_ref()
+            .is_none_or(|agents| agents.contains(agent_id))
+    }
+
     /// Accumulate a credit for an agent.
     ///
     /// # Arguments
@@ -72,6 +96,18 @@ impl DeferredCredits {
         entry.1.push(tx_id.to_string());
     }
 
+    /// Defer a credit for an agent and raise its provisional balance.
+    ///
+    /// Like [`accumulate`](Self::accumulate), but also records the amount on
+    /// the receiving agent so `Agent::provisional_balance()` reflects it until
+    /// [`apply_all`](Self::apply_all) promotes it at end of tick.
+    pub fn defer(&mut self, state: &mut SimulationState, agent_id: &str, amount: i64, tx_id: &str) {
+        self.accumulate(agent_id, amount, tx_id);
+        if let Some(agent) = state.get_agent_mut(agent_id) {
+            agent.add_provisional_credit(amount);
+        }
+    }
+
     /// Check if the accumulator is empty.
     pub fn is_empty(&self) -> bool {
         self.pending.is_empty()
@@ -107,8 +143,8 @@ impl DeferredCredits {
         for agent_id in agent_ids {
             if let Some((amount, tx_ids)) = self.pending.remove(&agent_id) {
                 if let Some(agent) = state.get_agent_mut(&agent_id) {
-                    // Apply the credit
-                    agent.credit(amount);
+                    // Apply the credit (promoting any provisional portion)
+                    agent.promote_provisional_credit(amount);
 
                     // Create event for this credit application
                     events.push(Event::DeferredCreditApplied {
@@ -182,6 +218,39 @@ mod tests {
         assert_eq!(dc.total_for_agent("UNKNOWN"), 0);
     }
 
+    #[test]
+    fn test_new_defers_every_agent() {
+        let dc = DeferredCredits::new();
+        assert!(dc.defers("BANK_A"));
+        assert!(dc.defers("BANK_B"));
+    }
+
+    #[test]
+    fn test_for_agents_defers_only_listed_agents() {
+        let dc = DeferredCredits::for_agents(vec!["BANK_A".to_string()]);
+        assert!(dc.defers("BANK_A"));
+        assert!(!dc.defers("BANK_B"));
+    }
+
+    #[test]
+    fn test_defer_raises_provisional_balance_until_applied() {
+        use crate::models::agent::Agent;
+
+        let mut state = SimulationState::new(vec![Agent::new("BANK_A".to_string(), 100_000)]);
+        let mut dc = DeferredCredits::new();
+        dc.defer(&mut state, "BANK_A", 40_000, "tx_001");
+
+        let agent = state.get_agent("BANK_A").unwrap();
+        assert_eq!(agent.balance(), 100_000);
+        assert_eq!(agent.provisional_balance(), 140_000);
+
+        dc.apply_all(&mut state, 0);
+
+        let agent = state.get_agent("BANK_A").unwrap();
+        assert_eq!(agent.balance(), 140_000);
+        assert_eq!(agent.provisional_balance(), 140_000);
+    }
+
     #[test]
     fn test_clear() {
         let mut dc = DeferredCredits::new();
@@ -3,6 +3,7 @@
 //! Implements T2 RTGS-compliant liquidity optimization through:
 //! - Bilateral offsetting (A↔B payment netting with unequal amounts)
 //! - Multilateral cycle settlement (A→B→C→A with unequal payment values)
+//! - Optional multilateral netting of the whole queue (Algorithm 4 style)
 //! - Two-phase atomic settlement ensuring all-or-nothing execution
 //!
 //! # Overview
@@ -64,9 +65,10 @@
 //!    - If any step fails, entire cycle fails (rollback not needed due to Phase 1 check)
 
 use crate::models::event::Event;
+use crate::models::transaction::SettlementMechanism;
 use crate::models::state::SimulationState;
 use crate::settlement::rtgs::{process_queue, SettlementError};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap};
 
 // ============================================================================
 // Submodules
@@ -88,11 +90,50 @@ pub struct LsmConfig {
     /// Enable cycle detection and settlement
     pub enable_cycles: bool,
 
-    /// Maximum cycle length to detect (3-5 typical)
-    pub max_cycle_length: usize,
+    /// Maximum cycle length to detect (3-5 typical, default 4)
+    ///
+    /// Bounds the DFS depth used for 4+ cycles. Enumerating cycles is
+    /// exponential in the worst case, so on dense payment graphs an unbounded
+    /// search can dominate tick time; long cycles are also rare in practice.
+    /// Cycles longer than the limit are never returned or settled. Only an
+    /// explicit `None` (null) removes the bound; a missing value keeps 4.
+    #[serde(default = "default_max_cycle_length")]
+    pub max_cycle_length: Option<usize>,
 
     /// Maximum cycles to settle per tick (performance limit)
     pub max_cycles_per_tick: usize,
+
+    /// Allow partial settlement of queued transactions in Queue 2
+    ///
+    /// When enabled, a queued payment whose sender has some (but not enough)
+    /// available liquidity settles the affordable portion, leaving the
+    /// remainder queued. Reduces gridlock on large payments.
+    #[serde(default)]
+    pub partial_settlement: bool,
+
+    /// Run the LSM pass only on ticks divisible by this interval (default 1)
+    ///
+    /// LSM dominates tick time on large networks while each extra pass adds
+    /// little. Between passes, queued transactions simply wait in Queue 2
+    /// (RTGS retries still run every tick). Must be > 0.
+    #[serde(default = "default_lsm_interval_ticks")]
+    pub lsm_interval_ticks: usize,
+
+    /// Enable multilateral net settlement of the whole queue (Algorithm 4)
+    ///
+    /// Runs after cycle settlement. Settles every queued payment among the
+    /// largest liquidity-feasible set of participants simultaneously, which
+    /// can break gridlocks that no single bilateral pair or cycle can.
+    #[serde(default)]
+    pub enable_multilateral_netting: bool,
+}
+
+fn default_max_cycle_length() -> Option<usize> {
+    Some(4)
+}
+
+fn default_lsm_interval_ticks() -> usize {
+    1
 }
 
 impl Default for LsmConfig {
@@ -100,8 +141,11 @@ impl Default for LsmConfig {
         Self {
             enable_bilateral: true,
             enable_cycles: true,
-            max_cycle_length: 4,
+            max_cycle_length: default_max_cycle_length(),
             max_cycles_per_tick: 10,
+            partial_settlement: false,
+            lsm_interval_ticks: 1,
+            enable_multilateral_netting: false,
         }
     }
 }
@@ -170,6 +214,33 @@ pub struct CycleSettlementResult {
     pub net_positions: BTreeMap<String, i64>,
 }
 
+/// Result of a multilateral netting run
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct NettingResult {
+    /// Participants whose payments settled, sorted by agent ID
+    pub participants: Vec<String>,
+
+    /// Participants removed as liquidity-infeasible, in removal order
+    pub removed_participants: Vec<String>,
+
+    /// Transaction IDs settled, in Queue 2 order
+    pub transactions: Vec<String>,
+
+    /// Gross value settled (sum of all transaction amounts)
+    pub settled_value: i64,
+
+    /// Net position of each surviving participant
+    /// Positive = net inflow, Negative = net outflow
+    pub net_positions: BTreeMap<String, i64>,
+}
+
+impl NettingResult {
+    /// Whether any transactions settled
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+}
+
 /// LSM cycle event for persistence (Phase 4)
 #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
 pub struct LsmCycleEvent {
@@ -216,7 +287,7 @@ pub struct LsmCycleEvent {
 }
 
 /// Result of complete LSM pass
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, Default, PartialEq)]
 pub struct LsmPassResult {
     /// Number of iterations run
     pub iterations_run: usize,
@@ -233,6 +304,9 @@ pub struct LsmPassResult {
     /// Number of cycles settled
     pub cycles_settled: usize,
 
+    /// Number of multilateral netting batches settled
+    pub netting_batches: usize,
+
     /// Detailed cycle events for persistence (Phase 4)
     pub cycle_events: Vec<LsmCycleEvent>,
 
@@ -282,9 +356,23 @@ pub fn bilateral_offset(state: &mut SimulationState, tick: usize) -> BilateralOf
 /// Same as `bilateral_offset`, but accepts an optional `DeferredCredits` accumulator.
 /// When provided, credits are accumulated instead of applied immediately.
 pub fn bilateral_offset_with_deferred(
+    state: &mut SimulationState,
+    tick: usize,
+    deferred_credits: Option<&mut super::deferred::DeferredCredits>,
+) -> BilateralOffsetResult {
+    bilateral_offset_with_min_leg(state, tick, deferred_credits, 0)
+}
+
+/// Find and settle bilateral offsetting opportunities above a minimum leg size
+///
+/// Same as `bilateral_offset_with_deferred`, but skips any pair whose total
+/// in either direction does not exceed `min_leg_amount`. Skipped pairs stay
+/// queued. A `min_leg_amount` of 0 offsets every pair.
+pub fn bilateral_offset_with_min_leg(
     state: &mut SimulationState,
     tick: usize,
     mut deferred_credits: Option<&mut super::deferred::DeferredCredits>,
+    min_leg_amount: i64,
 ) -> BilateralOffsetResult {
     let mut pairs_found = 0;
     let mut offset_value = 0i64;
@@ -307,10 +395,26 @@ pub fn bilateral_offset_with_deferred(
     // Phase 2: Pop ready pairs in deterministic priority order
     // Priority: highest liquidity release first, tie-break by agent IDs
     while let Some(key) = pair_index.pop_ready() {
-        pairs_found += 1;
-
         let agent_a = key.agent_a();
         let agent_b = key.agent_b();
+
+        // Both legs must exceed the minimum size to be worth offsetting
+        if min_leg_amount > 0
+            && pair_index
+                .flow_sum(agent_a, agent_b)
+                .min(pair_index.flow_sum(agent_b, agent_a))
+                <= min_leg_amount
+        {
+            if lsm_debug {
+                eprintln!(
+                    "[LSM DEBUG] Skipping bilateral pair {} ⇄ {}: leg not above minimum {}",
+                    agent_a, agent_b, min_leg_amount
+                );
+            }
+            continue;
+        }
+
+        pairs_found += 1;
         let liquidity_release = key.liquidity_release();
 
         if lsm_debug {
@@ -432,12 +536,10 @@ fn settle_bilateral_pair(
 
     // Check if net sender can handle the net negative balance (within credit limits)
     if let Some(sender) = state.get_agent(&net_sender) {
-        let projected_balance = sender.balance() - net_amount;
-        // CRITICAL: Use allowed_overdraft_limit() which includes collateral backing,
-        // not just the legacy credit_limit field
-        let max_negative = sender.allowed_overdraft_limit();
-        if projected_balance < -(max_negative as i64) {
-            // Would exceed total allowed overdraft (collateral + unsecured + credit_limit)
+        // CRITICAL: Use available_liquidity(), as RTGS does, so collateral backing
+        // counts and the reserve requirement is held back
+        if net_amount > sender.available_liquidity() {
+            // Would exceed total allowed overdraft or dip into the reserve
             return 0;
         }
     }
@@ -486,24 +588,29 @@ fn settle_bilateral_pair(
                 // Record outflow for bilateral/multilateral limit tracking
                 sender.record_outflow(&receiver_id, amount);
             }
+            state.record_settled_flow(&sender_id, &receiver_id, amount);
 
-            // Credit handling: immediate or deferred based on mode
-            if let Some(ref mut dc) = deferred_credits {
-                // Deferred mode: accumulate credit
-                dc.accumulate(&receiver_id, amount, tx_id);
-            } else {
-                // Immediate mode: credit directly
-                state
-                    .get_agent_mut(&receiver_id)
-                    .unwrap()
-                    .adjust_balance(amount as i64);
+            // Credit handling: immediate or deferred based on the receiver
+            match deferred_credits {
+                Some(ref mut dc) if dc.defers(&receiver_id) => {
+                    // Deferred receiver: accumulate credit
+                    dc.defer(state, &receiver_id, amount, tx_id);
+                }
+                _ => {
+                    // Immediate receiver: credit directly
+                    state
+                        .get_agent_mut(&receiver_id)
+                        .unwrap()
+                        .adjust_balance(amount as i64);
+                }
             }
 
-            state
-                .get_transaction_mut(tx_id)
-                .unwrap()
-                .settle(amount, tick)
-                .ok();
+            let tx = state.get_transaction_mut(tx_id).unwrap();
+            if tx.settle(amount, tick).is_ok() {
+                tx.set_settlement_mechanism(SettlementMechanism::BilateralOffset);
+                state.record_settlement(tx_id, tick);
+                reduce_split_parent(state, tx_id, amount, tick);
+            }
 
             // Mark for removal (deferred until batch compaction)
             let lsm_debug = std::env::var("LSM_DEBUG").is_ok();
@@ -529,24 +636,29 @@ fn settle_bilateral_pair(
                 // Record outflow for bilateral/multilateral limit tracking
                 sender.record_outflow(&receiver_id, amount);
             }
+            state.record_settled_flow(&sender_id, &receiver_id, amount);
 
-            // Credit handling: immediate or deferred based on mode
-            if let Some(ref mut dc) = deferred_credits {
-                // Deferred mode: accumulate credit
-                dc.accumulate(&receiver_id, amount, tx_id);
-            } else {
-                // Immediate mode: credit directly
-                state
-                    .get_agent_mut(&receiver_id)
-                    .unwrap()
-                    .adjust_balance(amount as i64);
+            // Credit handling: immediate or deferred based on the receiver
+            match deferred_credits {
+                Some(ref mut dc) if dc.defers(&receiver_id) => {
+                    // Deferred receiver: accumulate credit
+                    dc.defer(state, &receiver_id, amount, tx_id);
+                }
+                _ => {
+                    // Immediate receiver: credit directly
+                    state
+                        .get_agent_mut(&receiver_id)
+                        .unwrap()
+                        .adjust_balance(amount as i64);
+                }
             }
 
-            state
-                .get_transaction_mut(tx_id)
-                .unwrap()
-                .settle(amount, tick)
-                .ok();
+            let tx = state.get_transaction_mut(tx_id).unwrap();
+            if tx.settle(amount, tick).is_ok() {
+                tx.set_settlement_mechanism(SettlementMechanism::BilateralOffset);
+                state.record_settlement(tx_id, tick);
+                reduce_split_parent(state, tx_id, amount, tick);
+            }
 
             // Mark for removal (deferred until batch compaction)
             let lsm_debug = std::env::var("LSM_DEBUG").is_ok();
@@ -562,6 +674,25 @@ fn settle_bilateral_pair(
     settlements
 }
 
+/// Reduce a settled split child's parent by the settled amount
+///
+/// Mirrors `rtgs::try_settle`: the parent is marked settled once its last
+/// child settles, keeping children and parent remaining amounts in step.
+fn reduce_split_parent(state: &mut SimulationState, tx_id: &str, amount: i64, tick: usize) {
+    let parent_id = state
+        .get_transaction(tx_id)
+        .and_then(|tx| tx.parent_id().map(|s| s.to_string()));
+
+    if let Some(parent_id) = parent_id {
+        if let Some(parent) = state.get_transaction_mut(&parent_id) {
+            parent.reduce_remaining_for_child(amount).ok(); // Defensive - ignore errors
+            if parent.remaining_amount() == 0 {
+                parent.mark_fully_settled(tick).ok(); // Defensive - ignore errors
+            }
+        }
+    }
+}
+
 // ============================================================================
 // T2-Compliant LSM Helpers (Phase 1 & 2)
 // ============================================================================
@@ -683,6 +814,112 @@ fn check_cycle_feasibility(
     Ok(())
 }
 
+// ============================================================================
+// Obligation Graph Snapshot
+// ============================================================================
+
+/// Build the net bilateral obligation graph over Queue 2
+///
+/// Sums the remaining amounts of all queued transactions per direction and
+/// nets each pair of agents against each other, giving the gridlock picture
+/// the LSM works from before a pass. Read-only; intended for visualization.
+///
+/// # Returns
+///
+/// `(sender, receiver, net_amount)` edges sorted by sender then receiver.
+/// Each pair appears at most once, pointing from the net payer to the net
+/// payee; pairs whose obligations cancel exactly are omitted.
+///
+/// # Example
+///
+/// ```rust
+/// // Queue contains: A→B (500k), B→A (200k), B→C (100k)
+/// // build_obligation_graph returns: [(A, B, 300k), (B, C, 100k)]
+/// ```
+pub fn build_obligation_graph(state: &SimulationState) -> Vec<(String, String, i64)> {
+    // Gross flows per direction
+    let mut gross: BTreeMap<(&str, &str), i64> = BTreeMap::new();
+    for tx_id in state.rtgs_queue() {
+        if let Some(tx) = state.get_transaction(tx_id) {
+            if tx.sender_id() == tx.receiver_id() {
+                continue;
+            }
+            let flow = gross.entry((tx.sender_id(), tx.receiver_id())).or_insert(0);
+            *flow = flow.saturating_add(tx.remaining_amount());
+        }
+    }
+
+    // Net each unordered pair once (visit it from the smaller agent ID)
+    let mut edges = Vec::new();
+    for (&(sender, receiver), &amount) in &gross {
+        let reverse = gross.get(&(receiver, sender)).copied().unwrap_or(0);
+        if reverse != 0 && sender > receiver {
+            continue; // Already netted from the other direction
+        }
+        let net = amount.saturating_sub(reverse);
+        if net > 0 {
+            edges.push((sender.to_string(), receiver.to_string(), net));
+        } else if net < 0 {
+            edges.push((receiver.to_string(), sender.to_string(), -net));
+        }
+    }
+
+    edges.sort();
+    edges
+}
+
+/// Lower bound on the liquidity each agent needs to settle Queue 2
+///
+/// This is the classic netting lower bound: the liquidity an agent needs
+/// under perfect multilateral netting of all current obligations.
+///
+/// # Algorithm
+///
+/// Whatever order the queued payments settle in, once all of them have
+/// settled an agent's cumulative position equals its multilateral net
+/// position (inflows minus outflows). Its maximum cumulative net debit over
+/// the ordering is therefore at least its final net debit. Settling every
+/// payment simultaneously attains exactly that, so it is the optimal
+/// ordering and the bound is `max(0, outflow - inflow)` per agent.
+///
+/// Runs in O(T + A) for T queued transactions and A agents. Self-payments
+/// are ignored; remaining amounts are used for partially settled payments.
+///
+/// # Returns
+///
+/// Map of agent ID → minimum liquidity in cents (0 for net receivers and
+/// agents with nothing queued). Every agent in the state has an entry.
+///
+/// # Example
+///
+/// ```rust
+/// // Queue contains: A→B (500k), B→C (500k), C→D (500k)
+/// // Gross outflow is 1.5M; minimum_liquidity_bound returns
+/// // {A: 500k, B: 0, C: 0, D: 0}
+/// ```
+pub fn minimum_liquidity_bound(state: &SimulationState) -> HashMap<String, i64> {
+    let mut net_positions: HashMap<String, i64> =
+        state.agents().keys().map(|id| (id.clone(), 0)).collect();
+
+    for tx_id in state.rtgs_queue() {
+        if let Some(tx) = state.get_transaction(tx_id) {
+            if tx.sender_id() == tx.receiver_id() {
+                continue;
+            }
+            let amount = tx.remaining_amount();
+            let sender = net_positions.entry(tx.sender_id().to_string()).or_insert(0);
+            *sender = sender.saturating_sub(amount);
+            let receiver = net_positions.entry(tx.receiver_id().to_string()).or_insert(0);
+            *receiver = receiver.saturating_add(amount);
+        }
+    }
+
+    net_positions
+        .into_iter()
+        .map(|(id, position)| (id, position.min(0).saturating_neg()))
+        .collect()
+}
+
 // ============================================================================
 // Cycle Detection
 // ============================================================================
@@ -695,7 +932,8 @@ fn check_cycle_feasibility(
 /// # Algorithm
 ///
 /// Uses DFS with path tracking to find cycles. Limits cycle length to prevent
-/// exponential explosion.
+/// exponential explosion; pass `usize::MAX` for an unbounded search. Cycles
+/// longer than `max_cycle_length` are never returned.
 ///
 /// # Example
 ///
@@ -724,7 +962,7 @@ pub fn detect_cycles(state: &SimulationState, max_cycle_length: usize) -> Vec<Cy
 
         // Step 3: Find all triangles (3-cycles are the most common multilateral cycles)
         // In practice, triangles satisfy 80%+ of multilateral settlements
-        if !large_sccs.is_empty() {
+        if !large_sccs.is_empty() && max_cycle_length >= 3 {
             let candidates = graph::TriangleFinder::find_triangles(&agg_graph);
             let triangles: Vec<Cycle> = candidates.iter().map(|c| c.to_cycle()).collect();
             all_cycles.extend(triangles);
@@ -983,17 +1221,16 @@ pub fn settle_cycle_with_deferred(
     for (agent_id, &net_position) in &net_positions {
         if net_position < 0 {
             if let Some(agent) = state.get_agent(agent_id) {
-                let current_balance = agent.balance();
-                let projected_balance = current_balance + net_position; // net_position is negative
-                let allowed_overdraft = agent.allowed_overdraft_limit();
+                // Same reserve-adjusted headroom as RTGS (net_position is negative)
+                let available = agent.available_liquidity();
 
-                if projected_balance < -(allowed_overdraft as i64) {
-                    // This cycle would push the agent beyond their credit limit
+                if -net_position > available {
+                    // This cycle would push the agent beyond their credit limit or into its reserve
                     // This can happen when previous cycles in this tick already reduced the balance
                     return Err(SettlementError::AgentError(
                         crate::models::agent::AgentError::InsufficientLiquidity {
-                            required: projected_balance.abs() as i64,
-                            available: allowed_overdraft,
+                            required: -net_position,
+                            available,
                         },
                     ));
                 }
@@ -1056,23 +1293,28 @@ pub fn settle_cycle_with_deferred(
             // Record outflow for bilateral/multilateral limit tracking
             sender.record_outflow(&receiver_id, amount);
         }
+        state.record_settled_flow(&sender_id, &receiver_id, amount);
 
-        // Credit handling: immediate or deferred based on mode
-        if let Some(ref mut dc) = deferred_credits {
-            // Deferred mode: accumulate credit
-            dc.accumulate(&receiver_id, amount, tx_id);
-        } else {
-            // Immediate mode: credit directly
-            state
-                .get_agent_mut(&receiver_id)
-                .unwrap()
-                .adjust_balance(amount as i64);
+        // Credit handling: immediate or deferred based on the receiver
+        match deferred_credits {
+            Some(ref mut dc) if dc.defers(&receiver_id) => {
+                // Deferred receiver: accumulate credit
+                dc.defer(state, &receiver_id, amount, tx_id);
+            }
+            _ => {
+                // Immediate receiver: credit directly
+                state
+                    .get_agent_mut(&receiver_id)
+                    .unwrap()
+                    .adjust_balance(amount as i64);
+            }
         }
 
-        state
-            .get_transaction_mut(tx_id)
-            .unwrap()
-            .settle(amount, tick)?;
+        let tx = state.get_transaction_mut(tx_id).unwrap();
+        tx.settle(amount, tick)?;
+        tx.set_settlement_mechanism(SettlementMechanism::MultilateralCycle);
+        state.record_settlement(tx_id, tick);
+        reduce_split_parent(state, tx_id, amount, tick);
 
         // Mark for removal (deferred until batch compaction)
         to_remove.insert(tx_id.clone(), ());
@@ -1089,6 +1331,208 @@ pub fn settle_cycle_with_deferred(
     })
 }
 
+// ============================================================================
+// Multilateral Netting
+// ============================================================================
+
+/// Settle the whole queue on a multilateral net basis (TARGET2 Algorithm 4 style)
+///
+/// Builds every participant's net position across all payments in Queue 2,
+/// then repeatedly removes the most infeasible participant (largest
+/// liquidity shortfall against its net outflow), together with all of its
+/// incoming and outgoing payments, until each remaining net payer can cover
+/// its position. The surviving payments settle simultaneously at full value.
+///
+/// A participant whose batch outflows would breach a bilateral or
+/// multilateral limit is treated as infeasible and removed first. Net
+/// payers are checked against `available_liquidity()`, so the reserve
+/// requirement is held back as in RTGS and the other LSM paths.
+///
+/// Returns an empty result if `enable_multilateral_netting` is off or no
+/// feasible payment remains. Settled transactions are removed from Queue 2.
+///
+/// # What the Feasibility Check Excludes
+///
+/// - **Pending deferred credits**: feasibility uses the settled `balance()`,
+///   not `provisional_balance()`. Credits deferred earlier in the tick only
+///   become usable at t+1, so counting them would recycle liquidity that
+///   deferred crediting withholds. Bilateral offsetting and cycle settlement
+///   apply the same rule.
+/// - **Split parents**: a parent leaves Queue 1 when it splits and is never
+///   queued in Queue 2, so only its children enter the batch. Counting the
+///   parent as well would count the same obligation twice. Each settled child
+///   reduces its parent through `reduce_split_parent`, as in the other LSM
+///   paths.
+///
+/// # Example
+///
+/// ```rust
+/// // A→B 100k, A→C 100k, B→D 100k, C→D 100k, D→A 200k; all balances zero
+/// // No bilateral pairs, and every cycle leaves D 100k short
+/// // Net positions: A 0, B 0, C 0, D 0 → the whole batch settles
+/// ```
+pub fn run_multilateral_netting(
+    state: &mut SimulationState,
+    config: &LsmConfig,
+    tick: usize,
+) -> NettingResult {
+    run_multilateral_netting_with_deferred(state, config, tick, None)
+}
+
+/// Run multilateral netting with optional deferred crediting
+///
+/// Same as `run_multilateral_netting`, but accepts an optional `DeferredCredits`
+/// accumulator. When provided, credits are accumulated instead of applied immediately.
+pub fn run_multilateral_netting_with_deferred(
+    state: &mut SimulationState,
+    config: &LsmConfig,
+    tick: usize,
+    mut deferred_credits: Option<&mut super::deferred::DeferredCredits>,
+) -> NettingResult {
+    if !config.enable_multilateral_netting {
+        return NettingResult::default();
+    }
+
+    // Candidate payments: (tx_id, sender, receiver, remaining amount) in queue order
+    let queued: Vec<(String, String, String, i64)> = state
+        .rtgs_queue()
+        .iter()
+        .filter_map(|tx_id| state.get_transaction(tx_id))
+        .filter(|tx| !tx.is_fully_settled())
+        .map(|tx| {
+            (
+                tx.id().to_string(),
+                tx.sender_id().to_string(),
+                tx.receiver_id().to_string(),
+                tx.remaining_amount(),
+            )
+        })
+        .collect();
+
+    let mut removed_participants: Vec<String> = Vec::new();
+
+    // ========== PHASE 1: SHRINK TO A FEASIBLE SET (No State Changes) ==========
+    let (batch, net_positions) = loop {
+        let batch: Vec<&(String, String, String, i64)> = queued
+            .iter()
+            .filter(|(_, sender, receiver, _)| {
+                !removed_participants.contains(sender) && !removed_participants.contains(receiver)
+            })
+            .collect();
+
+        if batch.is_empty() {
+            return NettingResult {
+                removed_participants,
+                ..Default::default()
+            };
+        }
+
+        let mut net_positions: BTreeMap<String, i64> = BTreeMap::new();
+        for (_, sender, receiver, amount) in &batch {
+            *net_positions.entry(sender.clone()).or_insert(0) -= amount;
+            *net_positions.entry(receiver.clone()).or_insert(0) += amount;
+        }
+
+        match most_infeasible_participant(state, &batch, &net_positions) {
+            Some(agent_id) => removed_participants.push(agent_id),
+            None => break (batch, net_positions),
+        }
+    };
+
+    // ========== PHASE 2: ATOMIC SETTLEMENT (All or Nothing) ==========
+    let mut to_remove: BTreeMap<String, ()> = BTreeMap::new();
+    let mut transactions = Vec::with_capacity(batch.len());
+    let mut settled_value = 0i64;
+
+    for (tx_id, sender_id, receiver_id, amount) in batch {
+        // Use adjust_balance to bypass liquidity checks (net positions already verified)
+        {
+            let sender = state.get_agent_mut(sender_id).unwrap();
+            sender.adjust_balance(-amount);
+            // Record outflow for bilateral/multilateral limit tracking
+            sender.record_outflow(receiver_id, *amount);
+        }
+        state.record_settled_flow(sender_id, receiver_id, *amount);
+
+        // Credit handling: immediate or deferred based on the receiver
+        match deferred_credits {
+            Some(ref mut dc) if dc.defers(receiver_id) => {
+                dc.defer(state, receiver_id, *amount, tx_id);
+            }
+            _ => state.get_agent_mut(receiver_id).unwrap().adjust_balance(*amount),
+        }
+
+        let tx = state.get_transaction_mut(tx_id).unwrap();
+        if tx.settle(*amount, tick).is_ok() {
+            tx.set_settlement_mechanism(SettlementMechanism::MultilateralNetting);
+            state.record_settlement(tx_id, tick);
+            reduce_split_parent(state, tx_id, *amount, tick);
+        }
+
+        to_remove.insert(tx_id.clone(), ());
+        transactions.push(tx_id.clone());
+        settled_value = settled_value.saturating_add(*amount);
+    }
+
+    state.rtgs_queue_mut().retain(|id| !to_remove.contains_key(id));
+
+    NettingResult {
+        participants: net_positions.keys().cloned().collect(),
+        removed_participants,
+        transactions,
+        settled_value,
+        net_positions,
+    }
+}
+
+/// Find the participant that most blocks the netting batch, if any
+///
+/// Limit breaches rank above any liquidity shortfall. Ties go to the lowest
+/// agent ID so removal order is deterministic. Returns `None` when every
+/// participant can cover its net position.
+fn most_infeasible_participant(
+    state: &SimulationState,
+    batch: &[&(String, String, String, i64)],
+    net_positions: &BTreeMap<String, i64>,
+) -> Option<String> {
+    let mut worst: Option<(&String, i64)> = None;
+
+    for (agent_id, &net_position) in net_positions {
+        let shortfall = match state.get_agent(agent_id) {
+            None => i64::MAX,
+            Some(agent) => {
+                // Gross batch outflows, per receiver and in total
+                let mut outflows: BTreeMap<&str, i64> = BTreeMap::new();
+                for (_, sender, receiver, amount) in batch {
+                    if sender == agent_id {
+                        *outflows.entry(receiver.as_str()).or_insert(0) += amount;
+                    }
+                }
+                let total_outflow: i64 = outflows.values().sum();
+                let within_limits = agent.check_multilateral_limit(total_outflow).0
+                    && outflows
+                        .iter()
+                        .all(|(receiver, amount)| agent.check_bilateral_limit(receiver, *amount).0);
+
+                if !within_limits {
+                    i64::MAX
+                } else {
+                    // Shortfall if the net outflow exceeds the same reserve-adjusted
+                    // headroom RTGS uses. Settled balance only: pending deferred
+                    // credits are not liquidity yet
+                    -net_position - agent.available_liquidity()
+                }
+            }
+        };
+
+        if shortfall > 0 && worst.is_none_or(|(_, worst_shortfall)| shortfall > worst_shortfall) {
+            worst = Some((agent_id, shortfall));
+        }
+    }
+
+    worst.map(|(agent_id, _)| agent_id.clone())
+}
+
 // ============================================================================
 // LSM Coordinator
 // ============================================================================
@@ -1104,7 +1548,8 @@ pub fn settle_cycle_with_deferred(
 /// 2. Retry basic queue processing (recycling may enable settlements)
 /// 3. Run cycle detection and settlement
 /// 4. Retry basic queue processing again
-/// 5. Repeat until no progress or max iterations
+/// 5. Run multilateral netting, if enabled, and retry queue processing
+/// 6. Repeat until no progress or max iterations
 ///
 /// # Example
 ///
@@ -1126,25 +1571,37 @@ pub fn run_lsm_pass(
     ticks_per_day: usize,
     entry_disposition_offsetting: bool,
 ) -> LsmPassResult {
-    run_lsm_pass_with_deferred(state, config, tick, ticks_per_day, entry_disposition_offsetting, None)
+    run_lsm_pass_with_deferred(state, config, tick, ticks_per_day, entry_disposition_offsetting, 0, None)
 }
 
 /// Run LSM pass with optional deferred crediting
 ///
 /// Same as `run_lsm_pass`, but accepts an optional `DeferredCredits` accumulator.
 /// When provided, credits are accumulated instead of applied immediately.
+///
+/// When `entry_disposition_offsetting` is enabled, bilateral pairs with a leg
+/// at or below `entry_offset_min_amount` are not offset (0 = no minimum).
 pub fn run_lsm_pass_with_deferred(
     state: &mut SimulationState,
     config: &LsmConfig,
     tick: usize,
     ticks_per_day: usize,
     entry_disposition_offsetting: bool,
+    entry_offset_min_amount: i64,
     mut deferred_credits: Option<&mut super::deferred::DeferredCredits>,
 ) -> LsmPassResult {
+    // Minimum leg size only applies to entry-time offsetting
+    let min_leg_amount = if entry_disposition_offsetting {
+        entry_offset_min_amount
+    } else {
+        0
+    };
+
     let mut total_settled_value = 0i64;
     let mut iterations = 0;
     let mut bilateral_offsets = 0;
     let mut cycles_settled = 0;
+    let mut netting_batches = 0;
     let mut cycle_events = Vec::new();
     let mut replay_events = Vec::new(); // Events to be logged by orchestrator
     const MAX_ITERATIONS: usize = 3;
@@ -1162,7 +1619,8 @@ pub fn run_lsm_pass_with_deferred(
 
         // 1. Bilateral offsetting
         if config.enable_bilateral {
-            let bilateral_result = bilateral_offset_with_deferred(state, tick, deferred_credits.as_deref_mut());
+            let bilateral_result =
+                bilateral_offset_with_min_leg(state, tick, deferred_credits.as_deref_mut(), min_leg_amount);
             bilateral_offsets += bilateral_result.pairs_found;
             total_settled_value += bilateral_result.offset_value;
 
@@ -1264,6 +1722,7 @@ pub fn run_lsm_pass_with_deferred(
                         amount_a: pair.amount_a_to_b,
                         amount_b: pair.amount_b_to_a,
                         tx_ids: transactions.clone(),
+                        settlement_mechanism: SettlementMechanism::BilateralOffset,
                     });
                 }
 
@@ -1295,7 +1754,7 @@ pub fn run_lsm_pass_with_deferred(
             // Collect transaction IDs to remove (batch removal pattern for performance)
             let mut cycle_to_remove: BTreeMap<String, ()> = BTreeMap::new();
 
-            let cycles = detect_cycles(state, config.max_cycle_length);
+            let cycles = detect_cycles(state, config.max_cycle_length.unwrap_or(usize::MAX));
 
             if lsm_debug && !cycles.is_empty() {
                 eprintln!("[LSM DEBUG] Tick {}: Detected {} cycles (before filtering)", tick, cycles.len());
@@ -1371,6 +1830,7 @@ pub fn run_lsm_pass_with_deferred(
                         max_net_outflow,
                         max_net_outflow_agent: max_net_outflow_agent.clone(),
                         tx_ids: cycle.transactions.clone(),
+                        settlement_mechanism: SettlementMechanism::MultilateralCycle,
                     });
 
                     let event = LsmCycleEvent {
@@ -1412,7 +1872,41 @@ pub fn run_lsm_pass_with_deferred(
             }
         }
 
-        // 3. Check for progress
+        // 3. Multilateral netting of the remaining queue (Algorithm 4)
+        if config.enable_multilateral_netting && !state.rtgs_queue().is_empty() {
+            let netting = run_multilateral_netting_with_deferred(
+                state,
+                config,
+                tick,
+                deferred_credits.as_deref_mut(),
+            );
+
+            if !netting.is_empty() {
+                total_settled_value += netting.settled_value;
+                netting_batches += 1;
+
+                if lsm_debug {
+                    eprintln!("[LSM DEBUG] Tick {}: Netting settled {} transactions among {} agents ({} removed)",
+                        tick, netting.transactions.len(), netting.participants.len(), netting.removed_participants.len());
+                }
+
+                replay_events.push(Event::LsmNettingSettlement {
+                    tick,
+                    agents: netting.participants.clone(),
+                    removed_agents: netting.removed_participants.clone(),
+                    total_value: netting.settled_value,
+                    net_positions: netting.net_positions.values().copied().collect(),
+                    tx_ids: netting.transactions.clone(),
+                    settlement_mechanism: SettlementMechanism::MultilateralNetting,
+                });
+
+                // Retry queue processing after netting settlement
+                let queue_result = process_queue(state, tick);
+                total_settled_value += queue_result.settled_value;
+            }
+        }
+
+        // 4. Check for progress
         if total_settled_value == settled_this_iteration {
             break; // No progress, stop iterating
         }
@@ -1433,11 +1927,17 @@ pub fn run_lsm_pass_with_deferred(
     replay_events.sort_by(|a, b| {
         use Event::*;
         let tick_a = match a {
-            LsmBilateralOffset { tick, .. } | LsmCycleSettlement { tick, .. } | EntryDispositionOffset { tick, .. } => tick,
+            LsmBilateralOffset { tick, .. }
+            | LsmCycleSettlement { tick, .. }
+            | LsmNettingSettlement { tick, .. }
+            | EntryDispositionOffset { tick, .. } => tick,
             _ => &0,
         };
         let tick_b = match b {
-            LsmBilateralOffset { tick, .. } | LsmCycleSettlement { tick, .. } | EntryDispositionOffset { tick, .. } => tick,
+            LsmBilateralOffset { tick, .. }
+            | LsmCycleSettlement { tick, .. }
+            | LsmNettingSettlement { tick, .. }
+            | EntryDispositionOffset { tick, .. } => tick,
             _ => &0,
         };
         tick_a.cmp(tick_b)
@@ -1449,6 +1949,7 @@ pub fn run_lsm_pass_with_deferred(
         final_queue_size: state.queue_size(),
         bilateral_offsets,
         cycles_settled,
+        netting_batches,
         cycle_events,
         replay_events,
     }
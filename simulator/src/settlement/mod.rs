@@ -67,12 +67,13 @@ pub mod rtgs;
 
 // Re-export public API
 pub use rtgs::{
-    process_queue, process_queue_with_deferred, submit_transaction, try_settle,
+    process_queue, process_queue_ordered, process_queue_with_deferred, process_queue_with_options,
+    sort_queue, submit_transaction, try_settle, PartialSettlementDetail, Queue2Ordering,
     QueueProcessingResult, SettlementError, SubmissionResult,
 };
 
 pub use lsm::{
-    bilateral_offset, bilateral_offset_with_deferred, detect_cycles, run_lsm_pass,
+    bilateral_offset, bilateral_offset_with_deferred, bilateral_offset_with_min_leg, build_obligation_graph, detect_cycles, minimum_liquidity_bound, run_lsm_pass,
     run_lsm_pass_with_deferred, settle_cycle, settle_cycle_with_deferred, BilateralOffsetResult,
     Cycle, CycleSettlementResult, LsmConfig, LsmPassResult,
 };
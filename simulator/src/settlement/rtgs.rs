@@ -35,7 +35,7 @@
 
 use crate::models::agent::{Agent, AgentError};
 use crate::models::state::SimulationState;
-use crate::models::transaction::{Transaction, TransactionError};
+use crate::models::transaction::{SettlementMechanism, Transaction, TransactionError};
 use thiserror::Error;
 
 /// Errors that can occur during RTGS settlement
@@ -130,6 +130,7 @@ pub fn try_settle(
     sender.debit(amount)?;
     receiver.credit(amount);
     transaction.settle(amount, tick)?;
+    transaction.set_settlement_mechanism(SettlementMechanism::RtgsImmediate);
 
     Ok(())
 }
@@ -145,6 +146,14 @@ pub enum SubmissionResult {
         /// Position in queue (1-indexed)
         position: usize,
     },
+
+    /// Transaction could not settle and Queue 2 is at capacity
+    ///
+    /// The transaction is returned to the sender's Queue 1 for a later retry.
+    Rejected {
+        /// Configured Queue 2 capacity
+        capacity: usize,
+    },
 }
 
 /// Details of a transaction that settled from Queue 2 (RTGS queue)
@@ -156,6 +165,42 @@ pub struct SettledTransactionDetail {
     pub amount: i64,
 }
 
+/// Details of a queued transaction that partially settled from Queue 2
+///
+/// Only produced when partial settlement is enabled. The transaction stays
+/// in the queue with `remaining_amount` outstanding.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartialSettlementDetail {
+    pub tx_id: String,
+    pub sender_id: String,
+    pub receiver_id: String,
+    /// Amount settled this tick (cents)
+    pub amount: i64,
+    /// Amount still queued after this settlement (cents)
+    pub remaining_amount: i64,
+}
+
+/// Queue 2 processing order
+///
+/// Controls the order in which queued transactions are retried during
+/// `process_queue`. All orderings are stable: transactions that compare
+/// equal keep their existing queue order.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Queue2Ordering {
+    /// Insertion order (default)
+    #[default]
+    Fifo,
+    /// T2-style RTGS priority bands (HighlyUrgent, Urgent, Normal), then submission tick
+    PriorityBands,
+    /// Largest remaining amount first
+    LargestFirst,
+    /// Smallest remaining amount first
+    SmallestFirst,
+    /// Earliest deadline tick first
+    SoonestDeadline,
+}
+
 /// Statistics from processing the RTGS queue
 #[derive(Debug, Clone, PartialEq)]
 pub struct QueueProcessingResult {
@@ -179,6 +224,10 @@ pub struct QueueProcessingResult {
     /// Details of transactions that settled from Queue 2 this tick
     /// Added to support event emission for Queue 2 settlements (Issue #2)
     pub settled_transactions: Vec<SettledTransactionDetail>,
+
+    /// Details of transactions that partially settled from Queue 2 this tick
+    /// (empty unless partial settlement is enabled)
+    pub partial_settlements: Vec<PartialSettlementDetail>,
 }
 
 /// Submit a transaction to RTGS for settlement
@@ -196,6 +245,8 @@ pub struct QueueProcessingResult {
 ///
 /// - `Ok(SubmissionResult::SettledImmediately)` if settled immediately
 /// - `Ok(SubmissionResult::Queued)` if queued due to insufficient liquidity
+/// - `Ok(SubmissionResult::Rejected)` if it would queue but Queue 2 is at
+///   capacity; the transaction is put back in the sender's Queue 1
 /// - `Err(SettlementError)` for other errors (invalid agents, etc.)
 ///
 /// # Example
@@ -274,6 +325,7 @@ pub fn submit_transaction(
             // Record outflow for bilateral/multilateral limit tracking
             sender.record_outflow(&receiver_id, amount);
         }
+        state.record_settled_flow(&sender_id, &receiver_id, amount);
         {
             let receiver = state.get_agent_mut(&receiver_id).unwrap();
             receiver.credit(amount);
@@ -288,7 +340,9 @@ pub fn submit_transaction(
         {
             let transaction = state.get_transaction_mut(&tx_id).unwrap();
             transaction.settle(amount, tick)?;
+            transaction.set_settlement_mechanism(SettlementMechanism::RtgsImmediate);
         }
+        state.record_settlement(&tx_id, tick);
 
         // If this is a child transaction, update parent's remaining_amount
         if let Some(parent_id) = parent_id {
@@ -302,6 +356,13 @@ pub fn submit_transaction(
         }
 
         Ok(SubmissionResult::SettledImmediately { tick })
+    } else if let Some(capacity) = state.rtgs_queue_capacity().filter(|_| state.is_rtgs_queue_full()) {
+        // Queue 2 is full: hand the transaction back to the sender's Queue 1
+        if let Some(tx) = state.get_transaction_mut(&tx_id) {
+            tx.mark_entered_queue1(tick);
+        }
+        state.get_agent_mut(&sender_id).unwrap().queue_outgoing(tx_id);
+        Ok(SubmissionResult::Rejected { capacity })
     } else {
         // Queue the transaction
         state.queue_transaction(tx_id);
@@ -365,6 +426,45 @@ pub fn process_queue(state: &mut SimulationState, tick: usize) -> QueueProcessin
     process_queue_with_deferred(state, tick, None)
 }
 
+/// Sort Queue 2 (RTGS queue) in place according to `ordering`.
+///
+/// `Fifo` leaves the queue untouched. Every other ordering uses a stable
+/// sort, so ties keep their original insertion order.
+pub fn sort_queue(state: &mut SimulationState, ordering: Queue2Ordering) {
+    if ordering == Queue2Ordering::Fifo || state.rtgs_queue().len() <= 1 {
+        return;
+    }
+
+    // (tx_id, primary key, secondary key); lower keys are processed first
+    let mut keyed: Vec<(String, i64, usize)> = state
+        .rtgs_queue()
+        .iter()
+        .filter_map(|tx_id| {
+            state.get_transaction(tx_id).map(|tx| {
+                let (primary, secondary) = match ordering {
+                    Queue2Ordering::Fifo => (0, 0),
+                    Queue2Ordering::PriorityBands => {
+                        // RtgsPriority enum order: HighlyUrgent=0, Urgent=1, Normal=2
+                        let band = tx.rtgs_priority().map(|p| p as u8).unwrap_or(2);
+                        (
+                            band as i64,
+                            tx.rtgs_submission_tick().unwrap_or(usize::MAX),
+                        )
+                    }
+                    Queue2Ordering::LargestFirst => (-tx.remaining_amount(), 0),
+                    Queue2Ordering::SmallestFirst => (tx.remaining_amount(), 0),
+                    Queue2Ordering::SoonestDeadline => (tx.deadline_tick() as i64, 0),
+                };
+                (tx_id.clone(), primary, secondary)
+            })
+        })
+        .collect();
+
+    keyed.sort_by_key(|&(_, primary, secondary)| (primary, secondary));
+
+    *state.rtgs_queue_mut() = keyed.into_iter().map(|(id, _, _)| id).collect();
+}
+
 /// Process Queue 2 with optional deferred crediting.
 ///
 /// Same as `process_queue`, but accepts an optional `DeferredCredits` accumulator.
@@ -377,15 +477,68 @@ pub fn process_queue(state: &mut SimulationState, tick: usize) -> QueueProcessin
 /// * `tick` - Current tick number
 /// * `deferred_credits` - Optional accumulator for deferred credits
 pub fn process_queue_with_deferred(
+    state: &mut SimulationState,
+    tick: usize,
+    deferred_credits: Option<&mut super::deferred::DeferredCredits>,
+) -> QueueProcessingResult {
+    process_queue_with_options(state, tick, deferred_credits, false)
+}
+
+/// Process Queue 2 with optional deferred crediting and partial settlement.
+///
+/// When `partial_settlement` is true and a queued transaction's sender has
+/// positive but insufficient available liquidity, the affordable portion
+/// settles (debit + credit together) and the remainder stays queued.
+///
+/// # Arguments
+///
+/// * `state` - The simulation state
+/// * `tick` - Current tick number
+/// * `deferred_credits` - Optional accumulator for deferred credits
+/// * `partial_settlement` - Settle the affordable portion of unaffordable payments
+pub fn process_queue_with_options(
+    state: &mut SimulationState,
+    tick: usize,
+    deferred_credits: Option<&mut super::deferred::DeferredCredits>,
+    partial_settlement: bool,
+) -> QueueProcessingResult {
+    process_queue_ordered(
+        state,
+        tick,
+        deferred_credits,
+        partial_settlement,
+        Queue2Ordering::Fifo,
+    )
+}
+
+/// Process Queue 2 in the given order.
+///
+/// Sorts the queue according to `ordering` before the liquidity-checking
+/// pass, then behaves exactly like `process_queue_with_options`. Transactions
+/// that remain unsettled stay in the sorted order.
+///
+/// # Arguments
+///
+/// * `state` - The simulation state
+/// * `tick` - Current tick number
+/// * `deferred_credits` - Optional accumulator for deferred credits
+/// * `partial_settlement` - Settle the affordable portion of unaffordable payments
+/// * `ordering` - Queue 2 processing order
+pub fn process_queue_ordered(
     state: &mut SimulationState,
     tick: usize,
     mut deferred_credits: Option<&mut super::deferred::DeferredCredits>,
+    partial_settlement: bool,
+    ordering: Queue2Ordering,
 ) -> QueueProcessingResult {
+    sort_queue(state, ordering);
+
     let mut settled_count = 0;
     let mut settled_value = 0i64;
     let mut overdue_count = 0; // NEW: Count newly overdue transactions
     let mut still_pending = Vec::new();
     let mut settled_transactions = Vec::new(); // Track settled tx details for event emission
+    let mut partial_settlements = Vec::new();
 
     // Drain queue and process each transaction
     let queue = state.rtgs_queue_mut();
@@ -434,15 +587,16 @@ pub fn process_queue_with_deferred(
                 // Record outflow for bilateral/multilateral limit tracking
                 sender.record_outflow(&receiver_id, amount);
             }
+            state.record_settled_flow(&sender_id, &receiver_id, amount);
 
-            // Credit handling: immediate or deferred based on mode
+            // Credit handling: immediate or deferred based on the receiver
             match deferred_credits {
-                Some(ref mut dc) => {
-                    // Deferred mode: accumulate credit
-                    dc.accumulate(&receiver_id, amount, &tx_id);
+                Some(ref mut dc) if dc.defers(&receiver_id) => {
+                    // Deferred receiver: accumulate credit
+                    dc.defer(state, &receiver_id, amount, &tx_id);
                 }
-                None => {
-                    // Immediate mode: credit directly
+                _ => {
+                    // Immediate receiver: credit directly
                     let receiver = state.get_agent_mut(&receiver_id).unwrap();
                     receiver.credit(amount);
                 }
@@ -457,7 +611,9 @@ pub fn process_queue_with_deferred(
             {
                 let transaction = state.get_transaction_mut(&tx_id).unwrap();
                 transaction.settle(amount, tick).unwrap();
+                transaction.set_settlement_mechanism(SettlementMechanism::RtgsQueue);
             }
+            state.record_settlement(&tx_id, tick);
 
             // If this is a child transaction, update parent's remaining_amount
             if let Some(parent_id) = parent_id {
@@ -481,7 +637,24 @@ pub fn process_queue_with_deferred(
                 amount,
             });
         } else {
-            // Still can't settle, re-queue (even if overdue)
+            if partial_settlement && !can_pay {
+                if let Some(detail) = try_settle_partial(
+                    state,
+                    &tx_id,
+                    &sender_id,
+                    &receiver_id,
+                    tick,
+                    deferred_credits.as_deref_mut(),
+                ) {
+                    settled_value += detail.amount;
+                    partial_settlements.push(detail);
+                }
+            }
+
+            // Still can't settle in full, re-queue (even if overdue)
+            if let Some(transaction) = state.get_transaction_mut(&tx_id) {
+                transaction.record_retry();
+            }
             still_pending.push(tx_id.clone());
         }
     }
@@ -497,9 +670,71 @@ pub fn process_queue_with_deferred(
         dropped_count: 0, // Deprecated - always 0
         overdue_count,
         settled_transactions, // Issue #2 fix: Return details for event emission
+        partial_settlements,
     }
 }
 
+/// Settle the affordable portion of a queued transaction
+///
+/// Settles `min(available_liquidity, remaining)` if positive and within the
+/// sender's bilateral/multilateral limits. Debit and credit are applied
+/// together for the chunk. Returns `None` (no state change) otherwise.
+fn try_settle_partial(
+    state: &mut SimulationState,
+    tx_id: &str,
+    sender_id: &str,
+    receiver_id: &str,
+    tick: usize,
+    deferred_credits: Option<&mut super::deferred::DeferredCredits>,
+) -> Option<PartialSettlementDetail> {
+    let remaining = state.get_transaction(tx_id)?.remaining_amount();
+
+    let chunk = {
+        let sender = state.get_agent(sender_id)?;
+        let chunk = sender.available_liquidity().min(remaining);
+        let (bilateral_ok, _, _) = sender.check_bilateral_limit(receiver_id, chunk);
+        let (multilateral_ok, _, _) = sender.check_multilateral_limit(chunk);
+        if chunk <= 0 || chunk >= remaining || !bilateral_ok || !multilateral_ok {
+            return None;
+        }
+        chunk
+    };
+
+    // Update transaction first so an error leaves balances untouched
+    let parent_id = {
+        let transaction = state.get_transaction_mut(tx_id)?;
+        transaction.settle_partial(chunk, tick).ok()?;
+        transaction.parent_id().map(|s| s.to_string())
+    };
+
+    {
+        let sender = state.get_agent_mut(sender_id).unwrap();
+        sender.debit(chunk).unwrap();
+        sender.record_outflow(receiver_id, chunk);
+    }
+    state.record_settled_flow(sender_id, receiver_id, chunk);
+
+    match deferred_credits {
+        Some(dc) if dc.defers(receiver_id) => dc.defer(state, receiver_id, chunk, tx_id),
+        _ => state.get_agent_mut(receiver_id).unwrap().credit(chunk),
+    }
+
+    // Child transactions also reduce the parent's outstanding amount
+    if let Some(parent_id) = parent_id {
+        if let Some(parent) = state.get_transaction_mut(&parent_id) {
+            parent.reduce_remaining_for_child(chunk).ok(); // Defensive - ignore errors
+        }
+    }
+
+    Some(PartialSettlementDetail {
+        tx_id: tx_id.to_string(),
+        sender_id: sender_id.to_string(),
+        receiver_id: receiver_id.to_string(),
+        amount: chunk,
+        remaining_amount: remaining - chunk,
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -671,4 +906,34 @@ mod tests {
         assert!(tx.is_overdue());
         assert_eq!(tx.overdue_since_tick(), Some(51));
     }
+
+    #[test]
+    fn test_settlement_mechanism_recorded() {
+        let agents = vec![
+            create_agent("BANK_A", 100_000, 0),
+            create_agent("BANK_B", 0, 0),
+        ];
+        let mut state = SimulationState::new(agents);
+
+        let immediate = create_transaction("BANK_A", "BANK_B", 100_000, 0, 50);
+        let immediate_id = immediate.id().to_string();
+        submit_transaction(&mut state, immediate, 1).unwrap();
+
+        let queued = create_transaction("BANK_A", "BANK_B", 50_000, 0, 50);
+        let queued_id = queued.id().to_string();
+        submit_transaction(&mut state, queued, 1).unwrap();
+        assert_eq!(state.get_transaction(&queued_id).unwrap().settlement_mechanism(), None);
+
+        state.get_agent_mut("BANK_A").unwrap().credit(50_000);
+        process_queue(&mut state, 2);
+
+        assert_eq!(
+            state.get_transaction(&immediate_id).unwrap().settlement_mechanism(),
+            Some(SettlementMechanism::RtgsImmediate)
+        );
+        assert_eq!(
+            state.get_transaction(&queued_id).unwrap().settlement_mechanism(),
+            Some(SettlementMechanism::RtgsQueue)
+        );
+    }
 }
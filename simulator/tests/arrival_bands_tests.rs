@@ -27,6 +27,7 @@ fn test_arrival_band_config_struct_exists() {
         deadline_offset_max: 15,
         counterparty_weights: HashMap::new(),
         divisible: false,
+        deadline_jitter: None,
     };
 
     assert_eq!(band.rate_per_tick, 0.5);
@@ -51,6 +52,7 @@ fn test_arrival_bands_config_with_all_bands() {
             deadline_offset_max: 15,
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
         normal: Some(ArrivalBandConfig {
             rate_per_tick: 3.0,
@@ -62,6 +64,7 @@ fn test_arrival_bands_config_with_all_bands() {
             deadline_offset_max: 50,
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
         low: Some(ArrivalBandConfig {
             rate_per_tick: 5.0,
@@ -73,6 +76,7 @@ fn test_arrival_bands_config_with_all_bands() {
             deadline_offset_max: 80,
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
     };
 
@@ -99,6 +103,7 @@ fn test_arrival_bands_config_partial_bands() {
             deadline_offset_max: 10,
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
         normal: None,
         low: None,
@@ -126,6 +131,7 @@ fn test_arrival_generator_with_bands_config() {
             deadline_offset_max: 15,
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
         normal: Some(ArrivalBandConfig {
             rate_per_tick: 2.0,
@@ -137,6 +143,7 @@ fn test_arrival_generator_with_bands_config() {
             deadline_offset_max: 40,
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
         low: None,
     };
@@ -167,6 +174,7 @@ fn test_per_band_arrivals_have_correct_priority_ranges() {
             deadline_offset_max: 10,
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
         normal: Some(ArrivalBandConfig {
             rate_per_tick: 5.0,
@@ -178,6 +186,7 @@ fn test_per_band_arrivals_have_correct_priority_ranges() {
             deadline_offset_max: 30,
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
         low: Some(ArrivalBandConfig {
             rate_per_tick: 5.0,
@@ -189,6 +198,7 @@ fn test_per_band_arrivals_have_correct_priority_ranges() {
             deadline_offset_max: 60,
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
     };
 
@@ -260,6 +270,7 @@ fn test_urgent_band_has_tight_deadlines() {
             deadline_offset_max: 15, // Tight deadlines
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
         normal: None,
         low: None,
@@ -306,6 +317,7 @@ fn test_low_band_has_relaxed_deadlines() {
             deadline_offset_max: 80, // Relaxed deadlines
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
     };
 
@@ -348,6 +360,7 @@ fn test_per_band_arrivals_deterministic() {
             deadline_offset_max: 15,
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
         normal: Some(ArrivalBandConfig {
             rate_per_tick: 3.0,
@@ -359,6 +372,7 @@ fn test_per_band_arrivals_deterministic() {
             deadline_offset_max: 40,
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
         low: None,
     };
@@ -412,6 +426,7 @@ fn test_mixed_arrival_modes() {
             deadline_offset_max: 15,
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
         normal: None,
         low: None,
@@ -428,6 +443,7 @@ fn test_mixed_arrival_modes() {
         deadline_range: (10, 30),
         priority_distribution: PriorityDistribution::Fixed { value: 5 },
         divisible: false,
+        ..Default::default()
     };
 
     let mut band_configs = HashMap::new();
@@ -482,6 +498,7 @@ fn test_per_band_counterparty_weights() {
             deadline_offset_max: 15,
             counterparty_weights: urgent_weights,
             divisible: false,
+            deadline_jitter: None,
         }),
         normal: None,
         low: None,
@@ -546,6 +563,7 @@ fn test_orchestrator_with_arrival_bands() {
                         deadline_offset_max: 20,
                         counterparty_weights: HashMap::new(),
                         divisible: false,
+                        deadline_jitter: None,
                     }),
                     normal: Some(ArrivalBandConfig {
                         rate_per_tick: 2.0,
@@ -557,6 +575,7 @@ fn test_orchestrator_with_arrival_bands() {
                         deadline_offset_max: 50,
                         counterparty_weights: HashMap::new(),
                         divisible: false,
+                        deadline_jitter: None,
                     }),
                     low: None,
                 }),
@@ -566,6 +585,7 @@ fn test_orchestrator_with_arrival_bands() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -580,6 +600,7 @@ fn test_orchestrator_with_arrival_bands() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: Default::default(),
@@ -592,6 +613,7 @@ fn test_orchestrator_with_arrival_bands() {
         entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).expect("Should create orchestrator");
@@ -647,6 +669,7 @@ fn test_arrival_config_and_bands_mutually_exclusive() {
                 deadline_range: (10, 30),
                 priority_distribution: PriorityDistribution::Fixed { value: 5 },
                 divisible: false,
+                ..Default::default()
             }),
             arrival_bands: Some(ArrivalBandsConfig {
                 urgent: Some(ArrivalBandConfig {
@@ -659,6 +682,7 @@ fn test_arrival_config_and_bands_mutually_exclusive() {
                     deadline_offset_max: 20,
                     counterparty_weights: HashMap::new(),
                     divisible: false,
+                    deadline_jitter: None,
                 }),
                 normal: None,
                 low: None,
@@ -669,6 +693,7 @@ fn test_arrival_config_and_bands_mutually_exclusive() {
             limits: None,
             liquidity_pool: None,
             liquidity_allocation_fraction: None,
+            ..Default::default()
         }],
         cost_rates: Default::default(),
         lsm_config: Default::default(),
@@ -680,6 +705,7 @@ fn test_arrival_config_and_bands_mutually_exclusive() {
         entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let result = Orchestrator::new(config);
@@ -738,6 +764,7 @@ fn test_band_arrivals_deadline_capped_at_episode_end() {
             deadline_offset_max: 80, // Large offset
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
     };
 
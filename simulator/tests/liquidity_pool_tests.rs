@@ -30,6 +30,7 @@ fn create_base_config() -> OrchestratorConfig {
         entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     }
 }
 
@@ -53,6 +54,7 @@ fn test_agent_config_with_liquidity_pool_basic() {
         limits: None,
         liquidity_pool: Some(2_000_000), // New field
         liquidity_allocation_fraction: None, // Defaults to 1.0
+        ..Default::default()
     };
 
     assert_eq!(agent_config.liquidity_pool, Some(2_000_000));
@@ -74,6 +76,7 @@ fn test_agent_config_with_allocation_fraction() {
         limits: None,
         liquidity_pool: Some(2_000_000),
         liquidity_allocation_fraction: Some(0.5), // 50% of pool
+        ..Default::default()
     };
 
     assert_eq!(agent_config.liquidity_allocation_fraction, Some(0.5));
@@ -95,6 +98,7 @@ fn test_agent_config_backwards_compatible() {
         limits: None,
         liquidity_pool: None, // Not specified
         liquidity_allocation_fraction: None,
+        ..Default::default()
     };
 
     assert_eq!(agent_config.liquidity_pool, None);
@@ -122,6 +126,7 @@ fn test_full_pool_allocation_default() {
         limits: None,
         liquidity_pool: Some(2_000_000),
         liquidity_allocation_fraction: None, // Should default to 1.0
+        ..Default::default()
     });
 
     let orchestrator = Orchestrator::new(config).unwrap();
@@ -148,6 +153,7 @@ fn test_half_pool_allocation() {
         limits: None,
         liquidity_pool: Some(2_000_000),
         liquidity_allocation_fraction: Some(0.5),
+        ..Default::default()
     });
 
     let orchestrator = Orchestrator::new(config).unwrap();
@@ -173,6 +179,7 @@ fn test_zero_allocation() {
         limits: None,
         liquidity_pool: Some(2_000_000),
         liquidity_allocation_fraction: Some(0.0),
+        ..Default::default()
     });
 
     let orchestrator = Orchestrator::new(config).unwrap();
@@ -198,6 +205,7 @@ fn test_opening_balance_plus_allocated_liquidity() {
         limits: None,
         liquidity_pool: Some(1_000_000), // Additional pool
         liquidity_allocation_fraction: Some(0.5), // Allocate 50%
+        ..Default::default()
     });
 
     let orchestrator = Orchestrator::new(config).unwrap();
@@ -230,6 +238,7 @@ fn test_reject_allocation_fraction_above_one() {
         limits: None,
         liquidity_pool: Some(2_000_000),
         liquidity_allocation_fraction: Some(1.5), // Invalid: > 1.0
+        ..Default::default()
     });
 
     let _ = Orchestrator::new(config).unwrap(); // Should panic
@@ -253,6 +262,7 @@ fn test_reject_allocation_fraction_below_zero() {
         limits: None,
         liquidity_pool: Some(2_000_000),
         liquidity_allocation_fraction: Some(-0.1), // Invalid: < 0
+        ..Default::default()
     });
 
     let _ = Orchestrator::new(config).unwrap(); // Should panic
@@ -276,6 +286,7 @@ fn test_reject_negative_liquidity_pool() {
         limits: None,
         liquidity_pool: Some(-1_000_000), // Invalid: negative
         liquidity_allocation_fraction: None,
+        ..Default::default()
     });
 
     let _ = Orchestrator::new(config).unwrap(); // Should panic
@@ -302,6 +313,7 @@ fn test_fractional_allocation_rounds_down() {
         limits: None,
         liquidity_pool: Some(1_000_001), // Odd number
         liquidity_allocation_fraction: Some(0.5),
+        ..Default::default()
     });
 
     let orchestrator = Orchestrator::new(config).unwrap();
@@ -328,6 +340,7 @@ fn test_zero_pool_is_valid() {
         limits: None,
         liquidity_pool: Some(0),
         liquidity_allocation_fraction: Some(0.5),
+        ..Default::default()
     });
 
     let orchestrator = Orchestrator::new(config).unwrap();
@@ -359,6 +372,7 @@ fn test_allocated_liquidity_has_opportunity_cost() {
         limits: None,
         liquidity_pool: Some(1_000_000),
         liquidity_allocation_fraction: Some(1.0),
+        ..Default::default()
     });
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -391,6 +405,7 @@ fn test_opening_balance_no_liquidity_cost() {
         limits: None,
         liquidity_pool: None, // No liquidity pool
         liquidity_allocation_fraction: None,
+        ..Default::default()
     });
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -196,6 +196,7 @@ fn create_delay_cost_test_config() -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -210,6 +211,7 @@ fn create_delay_cost_test_config() -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -222,6 +224,7 @@ fn create_delay_cost_test_config() -> OrchestratorConfig {
         entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     }
 }
 
@@ -484,3 +487,45 @@ fn test_priority_multiplier_combines_with_overdue_multiplier() {
         costs.total_delay_cost
     );
 }
+
+#[test]
+fn test_urgent_and_low_accrue_delay_cost_in_configured_ratio() {
+    // Two identical holding banks, one with an urgent and one with a low
+    // priority payment, queued for the same number of ticks
+    let mut config = create_delay_cost_test_config();
+    let mut bank_c = config.agent_configs[0].clone();
+    bank_c.id = "BANK_C".to_string();
+    config.agent_configs.push(bank_c);
+    config.cost_rates.delay_cost_per_tick_per_cent = 0.0001;
+    config.cost_rates.priority_delay_multipliers = Some(PriorityDelayMultipliers {
+        urgent_multiplier: 3.0,
+        normal_multiplier: 1.0,
+        low_multiplier: 1.0,
+    });
+
+    let mut orchestrator = Orchestrator::new(config).unwrap();
+
+    for (sender, priority) in [("BANK_A", 9), ("BANK_C", 1)] {
+        let tx = Transaction::new(sender.to_string(), "BANK_B".to_string(), 500_000, 0, 50)
+            .with_priority(priority);
+        orchestrator.state_mut().add_transaction(tx.clone());
+        orchestrator
+            .state_mut()
+            .get_agent_mut(sender)
+            .unwrap()
+            .queue_outgoing(tx.id().to_string());
+    }
+
+    for _ in 0..5 {
+        let result = orchestrator.tick().unwrap();
+        assert_eq!(result.num_settlements, 0, "Both transactions should be held");
+    }
+
+    // Urgent: 500_000 * 0.0001 * 3.0 * 5 ticks = 750 cents
+    // Low:    500_000 * 0.0001 * 1.0 * 5 ticks = 250 cents
+    let urgent_cost = orchestrator.get_costs("BANK_A").unwrap().total_delay_cost;
+    let low_cost = orchestrator.get_costs("BANK_C").unwrap().total_delay_cost;
+    assert_eq!(urgent_cost, 750);
+    assert_eq!(low_cost, 250);
+    assert_eq!(urgent_cost, 3 * low_cost);
+}
@@ -0,0 +1,174 @@
+//! Tests for absolute-tick arrival deadlines (`DeadlineMode::AbsoluteTick`)
+//!
+//! Under `AbsoluteTick { min, max }` each generated deadline is an absolute
+//! tick drawn from `[min, max]`, still subject to the episode-end and EOD
+//! caps and the `deadline > arrival` invariant.
+
+use payment_simulator_core_rs::arrivals::{
+    AmountDistribution, ArrivalConfig, ArrivalGenerator, DeadlineMode, PriorityDistribution,
+};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::{RngManager, SimulationError};
+use std::collections::HashMap;
+
+fn arrival_config(deadline_mode: DeadlineMode) -> ArrivalConfig {
+    ArrivalConfig {
+        rate_per_tick: 3.0,
+        amount_distribution: AmountDistribution::Uniform {
+            min: 1_000,
+            max: 10_000,
+        },
+        counterparty_weights: HashMap::new(),
+        deadline_range: (5, 10),
+        priority_distribution: PriorityDistribution::Fixed { value: 5 },
+        divisible: false,
+        deadline_mode,
+        ..Default::default()
+    }
+}
+
+/// (arrival_tick, deadline_tick) for every arrival over `ticks`
+fn generate(
+    deadline_mode: DeadlineMode,
+    ticks: usize,
+    ticks_per_day: usize,
+    deadline_cap_at_eod: bool,
+) -> Vec<(usize, usize)> {
+    let mut generator = ArrivalGenerator::new(
+        vec![("BANK_A".to_string(), arrival_config(deadline_mode))]
+            .into_iter()
+            .collect(),
+        vec!["BANK_A".to_string(), "BANK_B".to_string()],
+        ticks,
+        ticks_per_day,
+        deadline_cap_at_eod,
+    );
+    let mut rng = RngManager::new(42);
+
+    (0..ticks)
+        .flat_map(|tick| generator.generate_for_agent("BANK_A", tick, &mut rng))
+        .map(|tx| (tx.arrival_tick(), tx.deadline_tick()))
+        .collect()
+}
+
+#[test]
+fn test_fixed_absolute_deadline() {
+    let arrivals = generate(
+        DeadlineMode::AbsoluteTick { min: 70, max: 70 },
+        100,
+        100,
+        false,
+    );
+    assert!(arrivals.iter().any(|(arrival, _)| *arrival >= 70));
+
+    for (arrival, deadline) in arrivals {
+        if arrival < 70 {
+            assert_eq!(deadline, 70);
+        } else {
+            assert_eq!(deadline, arrival + 1);
+        }
+    }
+}
+
+#[test]
+fn test_absolute_deadline_range() {
+    let arrivals = generate(
+        DeadlineMode::AbsoluteTick { min: 60, max: 80 },
+        100,
+        100,
+        false,
+    );
+    let deadlines: Vec<usize> = arrivals
+        .iter()
+        .filter(|(arrival, _)| *arrival < 60)
+        .map(|(_, deadline)| *deadline)
+        .collect();
+
+    assert!(deadlines.iter().all(|d| (60..=80).contains(d)));
+    assert!(deadlines.iter().any(|d| *d != deadlines[0]));
+}
+
+#[test]
+fn test_absolute_deadline_respects_caps() {
+    // Episode ends at tick 50
+    for (arrival, deadline) in generate(
+        DeadlineMode::AbsoluteTick { min: 70, max: 70 },
+        50,
+        100,
+        false,
+    ) {
+        assert_eq!(deadline, 50.max(arrival + 1));
+    }
+
+    // Day ends every 25 ticks when capping at EOD
+    for (arrival, deadline) in generate(
+        DeadlineMode::AbsoluteTick { min: 70, max: 70 },
+        100,
+        25,
+        true,
+    ) {
+        let day_end = (arrival / 25 + 1) * 25;
+        assert_eq!(deadline, day_end.min(70).max(arrival + 1));
+    }
+}
+
+#[test]
+fn test_relative_offset_is_default() {
+    assert_eq!(DeadlineMode::default(), DeadlineMode::RelativeOffset);
+    for (arrival, deadline) in generate(DeadlineMode::RelativeOffset, 50, 100, false) {
+        let offset = deadline - arrival;
+        assert!((5..=10).contains(&offset) || deadline == 50);
+    }
+}
+
+#[test]
+fn test_inverted_absolute_bounds_rejected() {
+    let make_agent = |id: &str, arrival_config: Option<ArrivalConfig>| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    let result = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent(
+            "BANK_A",
+            Some(arrival_config(DeadlineMode::AbsoluteTick {
+                min: 80,
+                max: 70,
+            })),
+        ))
+        .agent(make_agent("BANK_B", None))
+        .build();
+    assert!(matches!(result, Err(SimulationError::InvalidConfig(_))));
+
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent(
+            "BANK_A",
+            Some(arrival_config(DeadlineMode::AbsoluteTick {
+                min: 70,
+                max: 80,
+            })),
+        ))
+        .agent(make_agent("BANK_B", None))
+        .build()
+        .unwrap();
+    assert!(Orchestrator::new(config).is_ok());
+}
@@ -0,0 +1,151 @@
+//! Tests for per-agent arrival RNG streams
+//!
+//! Each agent's arrivals draw from a stream derived from the base seed and
+//! the agent ID, so adding an agent to a scenario leaves the arrival
+//! sequences of the existing agents unchanged.
+
+use payment_simulator_core_rs::arrivals::{AmountDistribution, ArrivalConfig, PriorityDistribution};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use payment_simulator_core_rs::Event;
+use std::collections::HashMap;
+
+fn make_agent(id: &str, counterparty: &str) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance: 100_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: Some(ArrivalConfig {
+            rate_per_tick: 1.5,
+            amount_distribution: AmountDistribution::LogNormal {
+                mean: 50_000.0,
+                std_dev: 20_000.0,
+            },
+            // Pin the counterparty so a new agent is never chosen as receiver
+            counterparty_weights: {
+                let mut weights = HashMap::new();
+                weights.insert(counterparty.to_string(), 1.0);
+                weights.insert("BANK_C".to_string(), 0.0);
+                weights
+            },
+            deadline_range: (5, 20),
+            priority_distribution: PriorityDistribution::Uniform { min: 0, max: 10 },
+            divisible: false,
+            ..Default::default()
+        }),
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+fn create_config(agent_configs: Vec<AgentConfig>) -> OrchestratorConfig {
+    OrchestratorConfig {
+        ticks_per_day: 50,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 777,
+        agent_configs,
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+/// (tick, receiver, amount, deadline, priority) of every arrival sent by `sender`
+fn arrivals_for(orchestrator: &Orchestrator, sender: &str) -> Vec<(usize, String, i64, usize, u8)> {
+    orchestrator
+        .event_log()
+        .events()
+        .iter()
+        .filter_map(|e| match e {
+            Event::Arrival {
+                tick,
+                sender_id,
+                receiver_id,
+                amount,
+                deadline,
+                priority,
+                ..
+            } if sender_id == sender => {
+                Some((*tick, receiver_id.clone(), *amount, *deadline, *priority))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+fn run(config: OrchestratorConfig) -> Orchestrator {
+    let mut orchestrator = Orchestrator::new(config).unwrap();
+    for _ in 0..50 {
+        orchestrator.tick().unwrap();
+    }
+    orchestrator
+}
+
+#[test]
+fn test_adding_agent_preserves_existing_arrival_sequences() {
+    let two_agents = run(create_config(vec![
+        make_agent("BANK_A", "BANK_B"),
+        make_agent("BANK_B", "BANK_A"),
+    ]));
+    let three_agents = run(create_config(vec![
+        make_agent("BANK_A", "BANK_B"),
+        make_agent("BANK_B", "BANK_A"),
+        make_agent("BANK_C", "BANK_A"),
+    ]));
+
+    for agent_id in ["BANK_A", "BANK_B"] {
+        let before = arrivals_for(&two_agents, agent_id);
+        assert!(!before.is_empty(), "{} should generate arrivals", agent_id);
+        assert_eq!(
+            before,
+            arrivals_for(&three_agents, agent_id),
+            "{} arrival sequence changed when BANK_C was added",
+            agent_id
+        );
+    }
+    assert!(!arrivals_for(&three_agents, "BANK_C").is_empty());
+}
+
+#[test]
+fn test_agent_streams_differ_and_follow_the_seed() {
+    let config = create_config(vec![
+        make_agent("BANK_A", "BANK_B"),
+        make_agent("BANK_B", "BANK_A"),
+    ]);
+    let first = run(config.clone());
+    let second = run(config.clone());
+
+    // Same seed reproduces every agent's sequence
+    assert_eq!(arrivals_for(&first, "BANK_A"), arrivals_for(&second, "BANK_A"));
+
+    // Different seed changes it
+    let mut reseeded = config;
+    reseeded.rng_seed = 778;
+    let third = run(reseeded);
+    assert_ne!(arrivals_for(&first, "BANK_A"), arrivals_for(&third, "BANK_A"));
+
+    // Streams are independent per agent
+    assert_ne!(
+        first.arrival_rng("BANK_A").unwrap().get_state(),
+        first.arrival_rng("BANK_B").unwrap().get_state()
+    );
+}
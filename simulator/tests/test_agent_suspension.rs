@@ -0,0 +1,194 @@
+//! Tests for agent suspension scenario events
+//!
+//! `ScenarioEvent::AgentSuspend` models an operational outage: the agent's
+//! policies are skipped and its Queue 1 does not drain, while incoming
+//! payments still credit normally. `AgentResume` (or the optional
+//! `resume_tick`) lifts the suspension.
+
+use payment_simulator_core_rs::events::{EventSchedule, ScenarioEvent, ScheduledEvent};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use payment_simulator_core_rs::{Agent, SimulationError};
+
+fn create_config(events: Vec<ScheduledEvent>) -> OrchestratorConfig {
+    let make_agent = |id: &str| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![make_agent("BANK_A"), make_agent("BANK_B")],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: Some(events),
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+fn suspend_event(tick: usize, resume_tick: Option<usize>) -> ScheduledEvent {
+    ScheduledEvent {
+        event: ScenarioEvent::AgentSuspend {
+            agent: "BANK_A".to_string(),
+            resume_tick,
+        },
+        schedule: EventSchedule::OneTime { tick },
+    }
+}
+
+fn is_suspended(orch: &Orchestrator, agent_id: &str) -> bool {
+    orch.state().get_agent(agent_id).unwrap().is_suspended()
+}
+
+#[test]
+fn test_suspended_queue_does_not_drain_until_resume_tick() {
+    let mut orch = Orchestrator::new(create_config(vec![suspend_event(0, Some(5))])).unwrap();
+
+    for _ in 0..3 {
+        orch.submit_transaction("BANK_A", "BANK_B", 100_000, 50, 5, false).unwrap();
+    }
+
+    for _ in 0..5 {
+        orch.tick().unwrap();
+        assert!(is_suspended(&orch, "BANK_A"));
+        assert_eq!(orch.get_queue1_size("BANK_A"), Some(3));
+    }
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(1_000_000));
+
+    // Tick 5: scheduled resume, queue drains in the same tick
+    orch.tick().unwrap();
+    assert!(!is_suspended(&orch, "BANK_A"));
+    assert_eq!(orch.get_queue1_size("BANK_A"), Some(0));
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(700_000));
+    assert_eq!(orch.get_agent_balance("BANK_B"), Some(1_300_000));
+
+    let suspended = orch.event_log().events_of_type("AgentSuspended");
+    let resumed = orch.event_log().events_of_type("AgentResumed");
+    assert_eq!(suspended.len(), 1);
+    assert_eq!(suspended[0].tick(), 0);
+    assert_eq!(suspended[0].agent_id(), Some("BANK_A"));
+    assert_eq!(resumed.len(), 1);
+    assert_eq!(resumed[0].tick(), 5);
+}
+
+#[test]
+fn test_suspended_agent_still_receives_payments() {
+    let mut orch = Orchestrator::new(create_config(vec![suspend_event(0, None)])).unwrap();
+
+    orch.submit_transaction("BANK_A", "BANK_B", 100_000, 50, 5, false).unwrap();
+    orch.tick().unwrap();
+
+    orch.submit_transaction("BANK_B", "BANK_A", 250_000, 50, 5, false).unwrap();
+    orch.tick().unwrap();
+
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(1_250_000));
+    assert_eq!(orch.get_agent_balance("BANK_B"), Some(750_000));
+    assert_eq!(orch.get_queue1_size("BANK_A"), Some(1));
+}
+
+#[test]
+fn test_explicit_resume_event() {
+    let events = vec![
+        suspend_event(1, None),
+        ScheduledEvent {
+            event: ScenarioEvent::AgentResume {
+                agent: "BANK_A".to_string(),
+            },
+            schedule: EventSchedule::OneTime { tick: 4 },
+        },
+    ];
+    let mut orch = Orchestrator::new(create_config(events)).unwrap();
+
+    // Tick 0: not yet suspended, first payment releases
+    orch.submit_transaction("BANK_A", "BANK_B", 100_000, 50, 5, false).unwrap();
+    orch.tick().unwrap();
+    assert_eq!(orch.get_queue1_size("BANK_A"), Some(0));
+
+    orch.submit_transaction("BANK_A", "BANK_B", 100_000, 50, 5, false).unwrap();
+    for _ in 1..4 {
+        orch.tick().unwrap();
+        assert_eq!(orch.get_queue1_size("BANK_A"), Some(1));
+    }
+
+    orch.tick().unwrap();
+    assert!(!is_suspended(&orch, "BANK_A"));
+    assert_eq!(orch.get_queue1_size("BANK_A"), Some(0));
+    assert_eq!(orch.event_log().events_of_type("AgentResumed").len(), 1);
+}
+
+#[test]
+fn test_resume_tick_must_follow_suspension() {
+    let result = Orchestrator::new(create_config(vec![suspend_event(2, Some(2))]));
+    assert!(matches!(result, Err(SimulationError::InvalidConfig(_))));
+
+    // Repeating schedules are checked when the event fires
+    let events = vec![ScheduledEvent {
+        event: ScenarioEvent::AgentSuspend {
+            agent: "BANK_A".to_string(),
+            resume_tick: Some(3),
+        },
+        schedule: EventSchedule::Repeating {
+            start_tick: 0,
+            interval: 5,
+        },
+    }];
+    let mut orch = Orchestrator::new(create_config(events)).unwrap();
+    for _ in 0..5 {
+        orch.tick().unwrap();
+    }
+    assert!(matches!(orch.tick(), Err(SimulationError::InvalidConfig(_))));
+}
+
+#[test]
+fn test_suspension_survives_checkpoint() {
+    let config = create_config(vec![suspend_event(0, Some(10))]);
+    let mut orch = Orchestrator::new(config.clone()).unwrap();
+
+    orch.submit_transaction("BANK_A", "BANK_B", 100_000, 50, 5, false).unwrap();
+    orch.tick().unwrap();
+
+    let state_json = orch.save_state().unwrap();
+    let restored = Orchestrator::load_state(config, &state_json).unwrap();
+
+    let agent = restored.state().get_agent("BANK_A").unwrap();
+    assert!(agent.is_suspended());
+    assert_eq!(agent.suspension_resume_tick(), Some(10));
+}
+
+#[test]
+fn test_agent_without_suspension_fields_deserializes() {
+    // Agent state serialized before suspension existed has neither field
+    let mut json = serde_json::to_value(Agent::new("BANK_A".to_string(), 1_000)).unwrap();
+    let fields = json.as_object_mut().unwrap();
+    assert!(fields.remove("suspended").is_some());
+    assert!(fields.remove("suspension_resume_tick").is_some());
+
+    let agent: Agent = serde_json::from_value(json).unwrap();
+    assert!(!agent.is_suspended());
+    assert_eq!(agent.suspension_resume_tick(), None);
+    assert_eq!(agent.balance(), 1_000);
+}
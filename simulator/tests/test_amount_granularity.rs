@@ -0,0 +1,181 @@
+//! Tests for arrival amount granularity
+//!
+//! `ArrivalConfig::amount_granularity` rounds every sampled amount to the
+//! nearest multiple of the unit (ties to even), never below one unit.
+
+use payment_simulator_core_rs::arrivals::{
+    AmountDistribution, ArrivalConfig, ArrivalGenerator, PriorityDistribution,
+};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use payment_simulator_core_rs::{RngManager, SimulationError};
+use std::collections::HashMap;
+
+fn arrival_config(
+    amount_distribution: AmountDistribution,
+    granularity: Option<i64>,
+) -> ArrivalConfig {
+    ArrivalConfig {
+        rate_per_tick: 3.0,
+        amount_distribution,
+        counterparty_weights: HashMap::new(),
+        deadline_range: (5, 10),
+        priority_distribution: PriorityDistribution::Fixed { value: 5 },
+        divisible: false,
+        amount_granularity: granularity,
+        ..Default::default()
+    }
+}
+
+fn sample_amounts(config: ArrivalConfig, ticks: usize) -> Vec<i64> {
+    let mut generator = ArrivalGenerator::new(
+        vec![("BANK_A".to_string(), config)].into_iter().collect(),
+        vec!["BANK_A".to_string(), "BANK_B".to_string()],
+        ticks + 100,
+        ticks + 100,
+        false,
+    );
+    let mut rng = RngManager::new(12345);
+
+    (0..ticks)
+        .flat_map(|tick| generator.generate_for_agent("BANK_A", tick, &mut rng))
+        .map(|tx| tx.amount())
+        .collect()
+}
+
+fn create_orchestrator_config(granularity: Option<i64>) -> OrchestratorConfig {
+    let make_agent = |id: &str, arrival_config: Option<ArrivalConfig>| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 10_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![
+            make_agent(
+                "BANK_A",
+                Some(arrival_config(
+                    AmountDistribution::Uniform {
+                        min: 1_000,
+                        max: 10_000,
+                    },
+                    granularity,
+                )),
+            ),
+            make_agent("BANK_B", None),
+        ],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_amounts_are_multiples_of_granularity() {
+    let distributions = [
+        AmountDistribution::Uniform {
+            min: 1_000,
+            max: 10_000,
+        },
+        AmountDistribution::LogNormal {
+            mean: 5_000.0,
+            std_dev: 3_000.0,
+        },
+        AmountDistribution::Exponential { rate: 0.001 },
+    ];
+
+    for distribution in distributions {
+        let amounts = sample_amounts(arrival_config(distribution, Some(500)), 200);
+        assert!(!amounts.is_empty());
+        for amount in amounts {
+            assert_eq!(
+                amount % 500,
+                0,
+                "amount {} is not a multiple of 500",
+                amount
+            );
+            assert!(amount >= 500, "amount {} below granularity floor", amount);
+        }
+    }
+}
+
+#[test]
+fn test_small_amounts_round_up_to_one_unit() {
+    let config = arrival_config(AmountDistribution::Uniform { min: 1, max: 100 }, Some(500));
+
+    let amounts = sample_amounts(config, 50);
+
+    assert!(!amounts.is_empty());
+    assert!(amounts.iter().all(|&amount| amount == 500));
+}
+
+#[test]
+fn test_no_granularity_leaves_amounts_unrounded() {
+    let config = arrival_config(
+        AmountDistribution::Uniform {
+            min: 1_000,
+            max: 10_000,
+        },
+        None,
+    );
+
+    let amounts = sample_amounts(config, 200);
+
+    assert!(amounts.iter().any(|amount| amount % 500 != 0));
+}
+
+#[test]
+fn test_granularity_applied_in_orchestrator() {
+    let mut orchestrator = Orchestrator::new(create_orchestrator_config(Some(500))).unwrap();
+
+    for _ in 0..20 {
+        orchestrator.tick().unwrap();
+    }
+
+    let arrivals = orchestrator.event_log().events_of_type("Arrival");
+    assert!(!arrivals.is_empty());
+    for tx in orchestrator.get_transactions_for_day(0) {
+        assert_eq!(tx.amount() % 500, 0);
+    }
+}
+
+#[test]
+fn test_non_positive_granularity_rejected() {
+    for granularity in [0, -500] {
+        match Orchestrator::new(create_orchestrator_config(Some(granularity))) {
+            Err(SimulationError::InvalidConfig(msg)) => {
+                assert!(
+                    msg.contains("amount_granularity"),
+                    "unexpected message: {}",
+                    msg
+                );
+            }
+            _ => panic!("expected InvalidConfig for granularity {}", granularity),
+        }
+    }
+}
@@ -0,0 +1,180 @@
+//! Tests for `ScenarioEvent::ArrivalBurst`
+//!
+//! A burst drops `count` payments from one agent at a tick, sampling amounts
+//! from the configured distribution and receivers from the other agents via
+//! the simulation RNG, then submits each through the normal arrival path.
+
+use payment_simulator_core_rs::arrivals::AmountDistribution;
+use payment_simulator_core_rs::events::{EventSchedule, ScenarioEvent, ScheduledEvent};
+use payment_simulator_core_rs::models::event::Event;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::SimulationError;
+
+const BURST_TICK: usize = 3;
+const MIN_AMOUNT: i64 = 1_000;
+const MAX_AMOUNT: i64 = 5_000;
+
+fn burst(count: usize, amount_distribution: AmountDistribution) -> ScheduledEvent {
+    ScheduledEvent {
+        event: ScenarioEvent::ArrivalBurst {
+            agent: "BANK_A".to_string(),
+            count,
+            amount_distribution,
+            deadline_offset: 10,
+            priority: 7,
+        },
+        schedule: EventSchedule::OneTime { tick: BURST_TICK },
+    }
+}
+
+fn uniform() -> AmountDistribution {
+    AmountDistribution::Uniform {
+        min: MIN_AMOUNT,
+        max: MAX_AMOUNT,
+    }
+}
+
+/// BANK_A has no liquidity, so every burst payment stays queued
+fn build_config(
+    seed: u64,
+    events: Vec<ScheduledEvent>,
+) -> Result<OrchestratorConfig, SimulationError> {
+    let make_agent = |id: &str, opening_balance: i64| AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig::builder()
+        .ticks_per_day(50)
+        .num_days(1)
+        .rng_seed(seed)
+        .agent(make_agent("BANK_A", 0))
+        .agent(make_agent("BANK_B", 1_000_000))
+        .agent(make_agent("BANK_C", 1_000_000))
+        .scenario_events(events)
+        .build()
+}
+
+fn run_burst(seed: u64, count: usize) -> Orchestrator {
+    let config = build_config(seed, vec![burst(count, uniform())]).unwrap();
+    let mut orch = Orchestrator::new(config).unwrap();
+    for _ in 0..=BURST_TICK {
+        orch.tick().unwrap();
+    }
+    orch
+}
+
+/// (receiver, amount) of each burst payment, in submission order
+fn burst_payments(orch: &Orchestrator) -> Vec<(String, i64)> {
+    let details = orch
+        .event_log()
+        .events_of_type("ScenarioEventExecuted")
+        .into_iter()
+        .find_map(|e| match e {
+            Event::ScenarioEventExecuted {
+                event_type,
+                details,
+                ..
+            } if event_type == "arrival_burst" => Some(details.clone()),
+            _ => None,
+        })
+        .expect("burst should be logged");
+
+    details["tx_ids"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|id| {
+            let tx = orch.get_transaction(id.as_str().unwrap()).unwrap();
+            (tx.receiver_id().to_string(), tx.amount())
+        })
+        .collect()
+}
+
+#[test]
+fn test_burst_of_50_queues_50_transactions_within_support() {
+    let orch = run_burst(42, 50);
+
+    let queued = orch.get_queue1_size("BANK_A").unwrap() + orch.get_queue2_size();
+    assert_eq!(queued, 50);
+
+    let payments = burst_payments(&orch);
+    assert_eq!(payments.len(), 50);
+    for (receiver, amount) in &payments {
+        assert!(receiver == "BANK_B" || receiver == "BANK_C");
+        assert!(
+            (MIN_AMOUNT..=MAX_AMOUNT).contains(amount),
+            "amount {} outside [{}, {}]",
+            amount,
+            MIN_AMOUNT,
+            MAX_AMOUNT
+        );
+    }
+}
+
+#[test]
+fn test_burst_payments_use_normal_arrival_path() {
+    let orch = run_burst(42, 5);
+
+    let arrivals = orch
+        .get_tick_events(BURST_TICK)
+        .iter()
+        .filter(|e| matches!(e, Event::Arrival { .. }))
+        .count();
+    assert_eq!(arrivals, 5);
+
+    for (_, amount) in burst_payments(&orch) {
+        assert!(amount > 0);
+    }
+    let tx = orch
+        .get_transactions_for_day(0)
+        .into_iter()
+        .find(|tx| tx.sender_id() == "BANK_A")
+        .unwrap();
+    assert_eq!(tx.arrival_tick(), BURST_TICK);
+    assert_eq!(tx.deadline_tick(), BURST_TICK + 10);
+    assert_eq!(tx.priority(), 7);
+}
+
+#[test]
+fn test_burst_is_deterministic_given_seed() {
+    assert_eq!(
+        burst_payments(&run_burst(42, 20)),
+        burst_payments(&run_burst(42, 20))
+    );
+    assert_ne!(
+        burst_payments(&run_burst(42, 20)),
+        burst_payments(&run_burst(43, 20))
+    );
+}
+
+#[test]
+fn test_invalid_burst_distribution_rejected() {
+    let invalid = AmountDistribution::Pareto {
+        scale: 1_000,
+        shape: 0.0,
+    };
+    match build_config(42, vec![burst(10, invalid)]) {
+        Err(SimulationError::InvalidConfig(msg)) => {
+            assert!(
+                msg.contains("ArrivalBurst for BANK_A"),
+                "unexpected message: {}",
+                msg
+            );
+        }
+        other => panic!("expected InvalidConfig, got {:?}", other.map(|_| ())),
+    }
+}
@@ -0,0 +1,174 @@
+//! Tests for `max_arrivals_per_agent_per_tick`
+//!
+//! A pathological `rate_per_tick` samples far more arrivals than the cap;
+//! only the cap is generated and `ArrivalsClamped` records the truncation.
+
+use payment_simulator_core_rs::arrivals::{
+    AmountDistribution, ArrivalBandConfig, ArrivalBandsConfig, ArrivalConfig, ArrivalGenerator,
+    PriorityDistribution,
+};
+use payment_simulator_core_rs::models::event::Event;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::{RngManager, SimulationError};
+use std::collections::HashMap;
+
+const PATHOLOGICAL_RATE: f64 = 1.0e9;
+const CAP: usize = 25;
+
+fn arrival_config(rate_per_tick: f64) -> ArrivalConfig {
+    ArrivalConfig {
+        rate_per_tick,
+        amount_distribution: AmountDistribution::Uniform {
+            min: 1_000,
+            max: 10_000,
+        },
+        counterparty_weights: HashMap::new(),
+        deadline_range: (5, 10),
+        priority_distribution: PriorityDistribution::Fixed { value: 5 },
+        divisible: false,
+        ..Default::default()
+    }
+}
+
+fn band(rate_per_tick: f64) -> ArrivalBandConfig {
+    ArrivalBandConfig {
+        rate_per_tick,
+        amount_distribution: AmountDistribution::Uniform {
+            min: 1_000,
+            max: 10_000,
+        },
+        deadline_offset_min: 5,
+        deadline_offset_max: 15,
+        counterparty_weights: HashMap::new(),
+        divisible: false,
+        deadline_jitter: None,
+    }
+}
+
+fn make_agent(id: &str, arrival_config: Option<ArrivalConfig>) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance: 10_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_pathological_rate_clamped_and_logged() {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent(
+            "BANK_A",
+            Some(arrival_config(PATHOLOGICAL_RATE)),
+        ))
+        .agent(make_agent("BANK_B", None))
+        .max_arrivals_per_agent_per_tick(CAP)
+        .build()
+        .unwrap();
+    let mut orch = Orchestrator::new(config).unwrap();
+
+    for tick in 0..3 {
+        let result = orch.tick().unwrap();
+        assert_eq!(result.num_arrivals, CAP);
+
+        let clamps: Vec<_> = orch
+            .get_tick_events(tick)
+            .into_iter()
+            .filter_map(|e| match e {
+                Event::ArrivalsClamped {
+                    agent_id,
+                    sampled_count,
+                    max_count,
+                    ..
+                } => Some((agent_id.clone(), *sampled_count, *max_count)),
+                _ => None,
+            })
+            .collect();
+        assert_eq!(clamps.len(), 1, "one clamp event per capped agent per tick");
+        let (agent_id, sampled_count, max_count) = &clamps[0];
+        assert_eq!(agent_id, "BANK_A");
+        assert!(*sampled_count > CAP);
+        assert_eq!(*max_count, CAP);
+    }
+}
+
+#[test]
+fn test_cap_is_shared_across_bands() {
+    let bands = ArrivalBandsConfig {
+        urgent: Some(band(PATHOLOGICAL_RATE)),
+        normal: Some(band(PATHOLOGICAL_RATE)),
+        low: Some(band(PATHOLOGICAL_RATE)),
+    };
+    let mut generator = ArrivalGenerator::new_with_bands(
+        HashMap::from([("BANK_A".to_string(), bands)]),
+        vec!["BANK_A".to_string(), "BANK_B".to_string()],
+        100,
+        100,
+        false,
+    )
+    .with_max_arrivals_per_tick(Some(CAP));
+    let mut rng = RngManager::new(7);
+
+    let arrivals = generator.generate_for_agent("BANK_A", 0, &mut rng);
+    assert_eq!(arrivals.len(), CAP);
+    // The first band fills the cap, later bands are fully truncated
+    assert!(arrivals.iter().all(|tx| tx.priority() >= 8));
+
+    let clamps = generator.drain_clamps();
+    assert_eq!(clamps.len(), 1);
+    assert_eq!(clamps[0].max_count, CAP);
+    assert!(clamps[0].sampled_count > 3 * CAP);
+    assert!(generator.drain_clamps().is_empty());
+}
+
+#[test]
+fn test_cap_not_reached_leaves_generation_unchanged() {
+    let build = |cap: Option<usize>| {
+        ArrivalGenerator::new(
+            HashMap::from([("BANK_A".to_string(), arrival_config(3.0))]),
+            vec!["BANK_A".to_string(), "BANK_B".to_string()],
+            100,
+            100,
+            false,
+        )
+        .with_max_arrivals_per_tick(cap)
+    };
+    let mut uncapped = build(None);
+    let mut generous_cap = build(Some(1_000));
+    let mut rng_a = RngManager::new(11);
+    let mut rng_b = RngManager::new(11);
+
+    for tick in 0..20 {
+        let a = uncapped.generate_for_agent("BANK_A", tick, &mut rng_a);
+        let b = generous_cap.generate_for_agent("BANK_A", tick, &mut rng_b);
+        assert_eq!(a.len(), b.len());
+    }
+    assert!(uncapped.drain_clamps().is_empty());
+    assert!(generous_cap.drain_clamps().is_empty());
+}
+
+#[test]
+fn test_zero_cap_rejected() {
+    let result = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent("BANK_A", None))
+        .max_arrivals_per_agent_per_tick(0)
+        .build();
+    assert!(matches!(result, Err(SimulationError::InvalidConfig(_))));
+}
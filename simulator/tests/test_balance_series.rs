@@ -0,0 +1,90 @@
+//! Tests for the opt-in per-tick balance series
+//!
+//! With `record_balance_series` enabled, every agent's balance is appended
+//! to its series at the end of each tick. Off by default.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+
+fn create_orchestrator(record_balance_series: bool) -> Orchestrator {
+    let make_agent = |id: &str, opening_balance: i64| AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(10)
+        .num_days(2)
+        .rng_seed(42)
+        .agent(make_agent("BANK_A", 500_000))
+        .agent(make_agent("BANK_B", 200_000))
+        .record_balance_series(record_balance_series)
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+#[test]
+fn test_series_has_one_entry_per_tick_ending_at_current_balance() {
+    let mut orch = create_orchestrator(true);
+
+    // Payments at ticks 0, 2 and 4 move the balances
+    for tick in 0..13 {
+        if tick % 2 == 0 && tick <= 4 {
+            orch.submit_transaction("BANK_A", "BANK_B", 100_000, tick + 5, 5, false)
+                .unwrap();
+        }
+        orch.tick().unwrap();
+    }
+
+    for agent_id in ["BANK_A", "BANK_B"] {
+        let series = orch.balance_series(agent_id).unwrap();
+        assert_eq!(series.len(), 13);
+        assert_eq!(
+            *series.last().unwrap(),
+            orch.get_agent_balance(agent_id).unwrap()
+        );
+    }
+
+    let series_a = orch.balance_series("BANK_A").unwrap();
+    assert_eq!(
+        &series_a[..6],
+        &[400_000, 400_000, 300_000, 300_000, 200_000, 200_000]
+    );
+    let series_b = orch.balance_series("BANK_B").unwrap();
+    assert_eq!(series_b[0], 300_000);
+    assert_eq!(series_b[12], 500_000);
+}
+
+#[test]
+fn test_series_off_by_default() {
+    let mut orch = create_orchestrator(false);
+    for _ in 0..5 {
+        orch.tick().unwrap();
+    }
+
+    assert!(orch.balance_series("BANK_A").is_none());
+    assert!(!orch.config().record_balance_series);
+}
+
+#[test]
+fn test_series_absent_before_first_tick_and_for_unknown_agents() {
+    let mut orch = create_orchestrator(true);
+    assert!(orch.balance_series("BANK_A").is_none());
+
+    orch.tick().unwrap();
+    assert_eq!(orch.balance_series("BANK_A"), Some(&[500_000][..]));
+    assert!(orch.balance_series("BANK_Z").is_none());
+}
@@ -0,0 +1,102 @@
+//! Tests for `ArrivalBandConfig::deadline_jitter`
+//!
+//! Jitter perturbs each band deadline by a uniform `[-jitter, +jitter]`
+//! before capping, while keeping deadlines strictly after arrival.
+
+use payment_simulator_core_rs::arrivals::{
+    AmountDistribution, ArrivalBandConfig, ArrivalBandsConfig, ArrivalGenerator,
+};
+use payment_simulator_core_rs::rng::RngManager;
+use std::collections::{BTreeSet, HashMap};
+
+const TICKS_PER_DAY: usize = 100;
+const EPISODE_END: usize = 200;
+
+/// Normal band whose deadlines all sit exactly `offset` ticks after arrival
+fn generator(offset: usize, deadline_jitter: Option<usize>) -> ArrivalGenerator {
+    let bands = ArrivalBandsConfig {
+        urgent: None,
+        normal: Some(ArrivalBandConfig {
+            rate_per_tick: 10.0,
+            amount_distribution: AmountDistribution::Uniform {
+                min: 1_000,
+                max: 10_000,
+            },
+            deadline_offset_min: offset,
+            deadline_offset_max: offset,
+            counterparty_weights: HashMap::new(),
+            divisible: false,
+            deadline_jitter,
+        }),
+        low: None,
+    };
+    let mut band_configs = HashMap::new();
+    band_configs.insert("BANK_A".to_string(), bands);
+
+    ArrivalGenerator::new_with_bands(
+        band_configs,
+        vec!["BANK_A".to_string(), "BANK_B".to_string()],
+        EPISODE_END,
+        TICKS_PER_DAY,
+        false,
+    )
+}
+
+/// (arrival, deadline) pairs for BANK_A over `ticks`
+fn deadlines(
+    generator: &mut ArrivalGenerator,
+    ticks: std::ops::Range<usize>,
+) -> Vec<(usize, usize)> {
+    let mut rng = RngManager::new(7);
+    ticks
+        .flat_map(|tick| generator.generate_for_agent("BANK_A", tick, &mut rng))
+        .map(|tx| (tx.arrival_tick(), tx.deadline_tick()))
+        .collect()
+}
+
+#[test]
+fn test_without_jitter_deadlines_cluster() {
+    let pairs = deadlines(&mut generator(10, None), 20..21);
+    assert!(pairs.len() > 1);
+    assert!(pairs.iter().all(|(_, deadline)| *deadline == 30));
+}
+
+#[test]
+fn test_jitter_spreads_clustered_deadlines() {
+    let pairs = deadlines(&mut generator(10, Some(5)), 20..30);
+    assert!(pairs.len() > 50);
+
+    for (arrival, deadline) in &pairs {
+        assert!(
+            (arrival + 5..=arrival + 15).contains(deadline),
+            "deadline {} outside jitter window of arrival {}",
+            deadline,
+            arrival
+        );
+    }
+    let offsets: BTreeSet<usize> = pairs.iter().map(|(a, d)| d - a).collect();
+    assert_eq!(offsets, (5..=15).collect());
+}
+
+#[test]
+fn test_jitter_never_violates_arrival_ordering() {
+    // Jitter far exceeds the offset, and late arrivals hit the episode cap
+    let pairs = deadlines(&mut generator(1, Some(20)), 0..EPISODE_END + 5);
+    assert!(!pairs.is_empty());
+    for (arrival, deadline) in &pairs {
+        assert!(
+            deadline > arrival,
+            "deadline {} not after arrival {}",
+            deadline,
+            arrival
+        );
+        assert!(*deadline <= EPISODE_END.max(arrival + 1));
+    }
+}
+
+#[test]
+fn test_zero_jitter_matches_no_jitter() {
+    let without = deadlines(&mut generator(10, None), 0..20);
+    let zero = deadlines(&mut generator(10, Some(0)), 0..20);
+    assert_eq!(without, zero);
+}
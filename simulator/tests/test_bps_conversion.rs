@@ -35,6 +35,7 @@ fn create_test_config() -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -49,6 +50,7 @@ fn create_test_config() -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -61,6 +63,7 @@ fn create_test_config() -> OrchestratorConfig {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     }
 }
 
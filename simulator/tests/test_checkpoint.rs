@@ -27,7 +27,13 @@ fn create_test_orchestrator() -> Orchestrator {
 
 /// Create test orchestrator with specific seed
 fn create_test_orchestrator_with_seed(seed: u64) -> Orchestrator {
-    let config = OrchestratorConfig {
+    Orchestrator::new(create_test_config_with_seed(seed))
+        .expect("Failed to create test orchestrator")
+}
+
+/// Config used by `create_test_orchestrator_with_seed`
+fn create_test_config_with_seed(seed: u64) -> OrchestratorConfig {
+    OrchestratorConfig {
         ticks_per_day: 100,
         eod_rush_threshold: 0.8,        num_days: 1,
         rng_seed: seed,
@@ -45,6 +51,7 @@ fn create_test_orchestrator_with_seed(seed: u64) -> Orchestrator {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -59,6 +66,7 @@ fn create_test_orchestrator_with_seed(seed: u64) -> Orchestrator {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -71,9 +79,8 @@ fn create_test_orchestrator_with_seed(seed: u64) -> Orchestrator {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
-    };
-
-    Orchestrator::new(config).expect("Failed to create test orchestrator")
+        ..Default::default()
+    }
 }
 
 /// Create orchestrator with automatic arrivals for determinism testing
@@ -102,6 +109,7 @@ fn create_test_orchestrator_with_arrivals() -> Orchestrator {
                     deadline_range: (10, 50),
                     priority_distribution: PriorityDistribution::Fixed { value: 5 },
                     divisible: false,
+                    ..Default::default()
                 }),
                 arrival_bands: None,
                 posted_collateral: None,
@@ -110,6 +118,7 @@ fn create_test_orchestrator_with_arrivals() -> Orchestrator {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -130,6 +139,7 @@ fn create_test_orchestrator_with_arrivals() -> Orchestrator {
                     deadline_range: (10, 50),
                     priority_distribution: PriorityDistribution::Fixed { value: 5 },
                     divisible: false,
+                    ..Default::default()
                 }),
                 arrival_bands: None,
                 posted_collateral: None,
@@ -138,6 +148,7 @@ fn create_test_orchestrator_with_arrivals() -> Orchestrator {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -150,6 +161,7 @@ fn create_test_orchestrator_with_arrivals() -> Orchestrator {
         entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     Orchestrator::new(config).expect("Failed to create orchestrator with arrivals")
@@ -259,6 +271,7 @@ fn test_load_state_restores_exact_state() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -273,6 +286,7 @@ fn test_load_state_restores_exact_state() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -285,6 +299,7 @@ fn test_load_state_restores_exact_state() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
     let state_json = original.save_state().unwrap();
 
@@ -297,6 +312,27 @@ fn test_load_state_restores_exact_state() {
     assert_eq!(restored.current_day(), original.current_day());
 }
 
+#[test]
+fn test_divisible_flag_survives_checkpoint() {
+    let mut original = create_test_orchestrator();
+
+    // Both exceed BANK_A's liquidity, so they stay queued across the save
+    let divisible_id = original
+        .submit_transaction("BANK_A", "BANK_B", 2_000_000, 50, 5, true)
+        .unwrap();
+    let whole_id = original
+        .submit_transaction("BANK_A", "BANK_B", 3_000_000, 50, 5, false)
+        .unwrap();
+    original.tick().unwrap();
+
+    let state_json = original.save_state().unwrap();
+    let restored = Orchestrator::load_state(create_test_config_with_seed(42), &state_json)
+        .expect("load_state() should succeed");
+
+    assert!(restored.state().get_transaction(&divisible_id).unwrap().is_divisible());
+    assert!(!restored.state().get_transaction(&whole_id).unwrap().is_divisible());
+}
+
 // ============================================================================
 // Critical Test: Determinism After Restore
 // ============================================================================
@@ -335,6 +371,7 @@ fn test_determinism_after_restore() {
                     deadline_range: (10, 50),
                     priority_distribution: PriorityDistribution::Fixed { value: 5 },
                     divisible: false,
+                    ..Default::default()
                 }),
                 arrival_bands: None,
                 posted_collateral: None,
@@ -343,6 +380,7 @@ fn test_determinism_after_restore() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -363,6 +401,7 @@ fn test_determinism_after_restore() {
                     deadline_range: (10, 50),
                     priority_distribution: PriorityDistribution::Fixed { value: 5 },
                     divisible: false,
+                    ..Default::default()
                 }),
                 arrival_bands: None,
                 posted_collateral: None,
@@ -371,6 +410,7 @@ fn test_determinism_after_restore() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -383,6 +423,7 @@ fn test_determinism_after_restore() {
         entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
     let state_json = sim1.save_state().unwrap();
 
@@ -411,6 +452,118 @@ fn test_determinism_after_restore() {
     );
 }
 
+/// Config with normally distributed amounts so the RNG's Gaussian cache is exercised
+fn create_gaussian_arrivals_config() -> OrchestratorConfig {
+    let make_agent = |id: &str, counterparty: &str| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 10_000_000,
+        unsecured_cap: 1_000_000,
+        policy: PolicyConfig::Fifo,
+        arrival_config: Some(ArrivalConfig {
+            rate_per_tick: 0.7,
+            amount_distribution: AmountDistribution::Normal {
+                mean: 50_000,
+                std_dev: 15_000,
+            },
+            counterparty_weights: {
+                let mut weights = HashMap::new();
+                weights.insert(counterparty.to_string(), 1.0);
+                weights
+            },
+            deadline_range: (10, 50),
+            priority_distribution: PriorityDistribution::Fixed { value: 5 },
+            divisible: false,
+            ..Default::default()
+        }),
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 2718,
+        agent_configs: vec![make_agent("BANK_A", "BANK_B"), make_agent("BANK_B", "BANK_A")],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_rng_stream_identical_after_restore() {
+    let config = create_gaussian_arrivals_config();
+    let mut uninterrupted = Orchestrator::new(config.clone()).unwrap();
+
+    for _ in 0..37 {
+        uninterrupted.tick().unwrap();
+    }
+
+    let state_json = uninterrupted.save_state().unwrap();
+    let restored = Orchestrator::load_state(config, &state_json).unwrap();
+
+    // Compare the next 100 draws (uniform and Gaussian) bit-for-bit
+    let mut rng1 = uninterrupted.rng_manager().clone();
+    let mut rng2 = restored.rng_manager().clone();
+    for i in 0..100 {
+        let (a, b) = if i % 2 == 0 {
+            (rng1.next(), rng2.next())
+        } else {
+            (rng1.next_gaussian().to_bits(), rng2.next_gaussian().to_bits())
+        };
+        assert_eq!(a, b, "RNG draw {} diverged after restore", i);
+    }
+}
+
+#[test]
+fn test_gaussian_cache_preserved_across_checkpoint() {
+    let config = create_gaussian_arrivals_config();
+    let mut sim1 = Orchestrator::new(config.clone()).unwrap();
+
+    // Run until BANK_A's arrival stream is holding a cached Box-Muller value
+    let mut ticks = 0;
+    while sim1.arrival_rng("BANK_A").unwrap().cached_gaussian().is_none() {
+        sim1.tick().unwrap();
+        ticks += 1;
+        assert!(ticks < 100, "expected a cached Gaussian within one day");
+    }
+
+    let state_json = sim1.save_state().unwrap();
+    let mut sim2 = Orchestrator::load_state(config, &state_json).unwrap();
+    assert_eq!(
+        sim1.arrival_rng("BANK_A").unwrap().cached_gaussian(),
+        sim2.arrival_rng("BANK_A").unwrap().cached_gaussian()
+    );
+
+    for _ in 0..20 {
+        sim1.tick().unwrap();
+        sim2.tick().unwrap();
+    }
+    for agent_id in ["BANK_A", "BANK_B"] {
+        assert_eq!(
+            sim1.arrival_rng(agent_id).unwrap().get_state(),
+            sim2.arrival_rng(agent_id).unwrap().get_state()
+        );
+    }
+    assert_eq!(sim1.get_all_agent_balances(), sim2.get_all_agent_balances());
+}
+
 // ============================================================================
 // Critical Test: Balance Conservation
 // ============================================================================
@@ -452,6 +605,7 @@ fn test_balance_conservation_preserved() {
                     deadline_range: (10, 50),
                     priority_distribution: PriorityDistribution::Fixed { value: 5 },
                     divisible: false,
+                    ..Default::default()
                 }),
                 arrival_bands: None,
                 posted_collateral: None,
@@ -460,6 +614,7 @@ fn test_balance_conservation_preserved() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -480,6 +635,7 @@ fn test_balance_conservation_preserved() {
                     deadline_range: (10, 50),
                     priority_distribution: PriorityDistribution::Fixed { value: 5 },
                     divisible: false,
+                    ..Default::default()
                 }),
                 arrival_bands: None,
                 posted_collateral: None,
@@ -488,6 +644,7 @@ fn test_balance_conservation_preserved() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -500,6 +657,7 @@ fn test_balance_conservation_preserved() {
         entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
     let state_json = orchestrator.save_state().unwrap();
     let restored = Orchestrator::load_state(config, &state_json).unwrap();
@@ -546,6 +704,7 @@ fn test_config_mismatch_rejected() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -560,6 +719,7 @@ fn test_config_mismatch_rejected() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -572,6 +732,7 @@ fn test_config_mismatch_rejected() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     // Should fail to load with config mismatch error
@@ -609,6 +770,7 @@ fn test_corrupted_state_json_rejected() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+            ..Default::default()
         }],
         cost_rates: CostRates::default(),
         lsm_config: LsmConfig::default(),
@@ -620,6 +782,7 @@ fn test_corrupted_state_json_rejected() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     // Invalid JSON
@@ -664,6 +827,7 @@ fn test_save_load_roundtrip_preserves_state_multiple_seeds() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                    ..Default::default()
                 },
                 AgentConfig {
                     id: "BANK_B".to_string(),
@@ -678,6 +842,7 @@ fn test_save_load_roundtrip_preserves_state_multiple_seeds() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                    ..Default::default()
                 },
             ],
             cost_rates: CostRates::default(),
@@ -690,6 +855,7 @@ fn test_save_load_roundtrip_preserves_state_multiple_seeds() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+            ..Default::default()
         };
         let state_json = original.save_state().unwrap();
         let mut restored = Orchestrator::load_state(config, &state_json).unwrap();
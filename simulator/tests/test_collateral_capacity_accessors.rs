@@ -0,0 +1,123 @@
+//! Tests for the collateral capacity accessors
+//!
+//! `get_agent_max_collateral_capacity` and
+//! `get_agent_remaining_collateral_capacity` expose how much more
+//! collateral an agent can post.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+
+/// Posts 10k on ticks 0 and 1, otherwise holds
+const POST_TWICE_POLICY: &str = r#"{
+    "version": "1.0",
+    "policy_id": "post_twice",
+    "payment_tree": {"node_id": "P1", "type": "action", "action": "Release"},
+    "strategic_collateral_tree": {
+        "node_id": "S1",
+        "type": "condition",
+        "condition": {"op": "<", "left": {"field": "current_tick"}, "right": {"value": 2}},
+        "on_true": {
+            "node_id": "S2",
+            "type": "action",
+            "action": "PostCollateral",
+            "parameters": {
+                "amount": {"value": 10000},
+                "reason": {"value": "UrgentLiquidityNeed"}
+            }
+        },
+        "on_false": {"node_id": "S3", "type": "action", "action": "HoldCollateral"}
+    },
+    "end_of_tick_collateral_tree": null
+}"#;
+
+fn make_agent(id: &str, policy: PolicyConfig, max_collateral_capacity: Option<i64>) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 20_000,
+        policy,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+fn create_orchestrator() -> Orchestrator {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent(
+            "BANK_A",
+            PolicyConfig::FromJson {
+                json: POST_TWICE_POLICY.to_string(),
+            },
+            Some(50_000),
+        ))
+        .agent(make_agent("BANK_B", PolicyConfig::Fifo, None))
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+#[test]
+fn test_remaining_capacity_decreases_by_posted_amount() {
+    let mut orch = create_orchestrator();
+    assert_eq!(
+        orch.get_agent_max_collateral_capacity("BANK_A"),
+        Some(50_000)
+    );
+    assert_eq!(
+        orch.get_agent_remaining_collateral_capacity("BANK_A"),
+        Some(50_000)
+    );
+
+    orch.tick().unwrap();
+    assert_eq!(orch.get_agent_collateral_posted("BANK_A"), Some(10_000));
+    assert_eq!(
+        orch.get_agent_remaining_collateral_capacity("BANK_A"),
+        Some(40_000)
+    );
+
+    orch.tick().unwrap();
+    orch.tick().unwrap();
+    assert_eq!(orch.get_agent_collateral_posted("BANK_A"), Some(20_000));
+    assert_eq!(
+        orch.get_agent_remaining_collateral_capacity("BANK_A"),
+        Some(30_000)
+    );
+    // Posting never changes the maximum
+    assert_eq!(
+        orch.get_agent_max_collateral_capacity("BANK_A"),
+        Some(50_000)
+    );
+}
+
+#[test]
+fn test_max_capacity_defaults_to_ten_times_unsecured_cap() {
+    let orch = create_orchestrator();
+
+    assert_eq!(
+        orch.get_agent_max_collateral_capacity("BANK_B"),
+        Some(200_000)
+    );
+    assert_eq!(
+        orch.get_agent_remaining_collateral_capacity("BANK_B"),
+        Some(200_000)
+    );
+}
+
+#[test]
+fn test_unknown_agent_returns_none() {
+    let orch = create_orchestrator();
+
+    assert_eq!(orch.get_agent_max_collateral_capacity("BANK_Z"), None);
+    assert_eq!(orch.get_agent_remaining_collateral_capacity("BANK_Z"), None);
+}
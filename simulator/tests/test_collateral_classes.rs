@@ -0,0 +1,122 @@
+//! Tests for per-class collateral haircuts
+//!
+//! `AgentConfig.collateral_classes` pledges collateral by asset class, each
+//! discounted by its own haircut, on top of the scalar `posted_collateral`.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use payment_simulator_core_rs::SimulationError;
+
+fn create_config(collateral_classes: Option<Vec<(String, i64, f64)>>) -> OrchestratorConfig {
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![
+            AgentConfig {
+                id: "BANK_A".to_string(),
+                opening_balance: 0,
+                unsecured_cap: 0,
+                policy: PolicyConfig::Fifo,
+                arrival_config: None,
+                arrival_bands: None,
+                posted_collateral: None,
+                collateral_haircut: None,
+                max_collateral_capacity: None,
+                limits: None,
+                liquidity_pool: None,
+                liquidity_allocation_fraction: None,
+                collateral_classes,
+                ..Default::default()
+            },
+            AgentConfig {
+                id: "BANK_B".to_string(),
+                opening_balance: 1_000_000,
+                unsecured_cap: 0,
+                policy: PolicyConfig::Fifo,
+                arrival_config: None,
+                arrival_bands: None,
+                posted_collateral: None,
+                collateral_haircut: None,
+                max_collateral_capacity: None,
+                limits: None,
+                liquidity_pool: None,
+                liquidity_allocation_fraction: None,
+                ..Default::default()
+            },
+        ],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+fn mixed_classes() -> Option<Vec<(String, i64, f64)>> {
+    Some(vec![
+        ("govt_bonds".to_string(), 400_000, 0.0),
+        ("corporate_bonds".to_string(), 200_000, 0.10),
+    ])
+}
+
+#[test]
+fn test_blended_capacity_from_config() {
+    let orch = Orchestrator::new(create_config(mixed_classes())).unwrap();
+    let agent = orch.state().get_agent("BANK_A").unwrap();
+
+    // 400k × 1.0 + 200k × 0.9 = 580k
+    assert_eq!(agent.posted_collateral(), 600_000);
+    assert_eq!(agent.allowed_overdraft_limit(), 580_000);
+}
+
+#[test]
+fn test_payments_settle_up_to_blended_capacity() {
+    let mut orch = Orchestrator::new(create_config(mixed_classes())).unwrap();
+
+    orch.submit_transaction("BANK_A", "BANK_B", 580_000, 50, 5, false).unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 1, 50, 5, false).unwrap();
+    orch.tick().unwrap();
+
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(-580_000));
+    assert_eq!(orch.get_queue2_size(), 1, "the extra cent exceeds capacity");
+}
+
+#[test]
+fn test_invalid_collateral_class_rejected() {
+    for classes in [
+        vec![("bad_haircut".to_string(), 100_000, 1.5)],
+        vec![("negative".to_string(), -1, 0.0)],
+    ] {
+        let result = Orchestrator::new(create_config(Some(classes)));
+        assert!(matches!(result, Err(SimulationError::InvalidConfig(_))));
+    }
+}
+
+#[test]
+fn test_collateral_classes_survive_checkpoint() {
+    let config = create_config(mixed_classes());
+    let mut orch = Orchestrator::new(config.clone()).unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 300_000, 50, 5, false).unwrap();
+    orch.tick().unwrap();
+
+    let state_json = orch.save_state().unwrap();
+    let restored = Orchestrator::load_state(config, &state_json).unwrap();
+
+    let before = orch.state().get_agent("BANK_A").unwrap();
+    let after = restored.state().get_agent("BANK_A").unwrap();
+    assert_eq!(after.collateral_classes(), before.collateral_classes());
+    assert_eq!(after.posted_collateral(), 600_000);
+    assert_eq!(after.pool_collateral(), 0);
+    assert_eq!(after.allowed_overdraft_limit(), 580_000);
+}
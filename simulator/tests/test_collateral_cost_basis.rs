@@ -0,0 +1,121 @@
+//! Tests for `CostRates::collateral_cost_basis`
+//!
+//! The collateral opportunity cost accrues either on the raw posted value
+//! (`Posted`, the default) or on the credit capacity the collateral provides
+//! after haircuts (`PostHaircut`).
+
+use payment_simulator_core_rs::models::event::Event;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CollateralCostBasis, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig,
+    Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+
+fn create_config(basis: CollateralCostBasis, haircut: f64) -> OrchestratorConfig {
+    let make_agent = |id: &str, posted_collateral: Option<i64>| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral,
+        collateral_haircut: Some(haircut),
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![
+            make_agent("BANK_A", Some(10_000_000)),
+            make_agent("BANK_B", None),
+        ],
+        cost_rates: CostRates {
+            collateral_cost_per_tick_bps: 1.0,
+            collateral_cost_basis: basis,
+            ..CostRates::default()
+        },
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+/// Collateral cost accrued by BANK_A on the first tick
+fn first_tick_collateral_cost(basis: CollateralCostBasis, haircut: f64) -> i64 {
+    let mut orch = Orchestrator::new(create_config(basis, haircut)).unwrap();
+    orch.tick().unwrap();
+
+    orch.get_tick_events(0)
+        .into_iter()
+        .find_map(|event| match event {
+            Event::CostAccrual {
+                agent_id, costs, ..
+            } if agent_id == "BANK_A" => Some(costs.collateral_cost),
+            _ => None,
+        })
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_posted_basis_charges_raw_collateral() {
+    // $100,000 posted at 1 bp/tick = 1,000 cents regardless of haircut
+    assert_eq!(
+        first_tick_collateral_cost(CollateralCostBasis::Posted, 0.1),
+        1_000
+    );
+}
+
+#[test]
+fn test_post_haircut_basis_charges_usable_capacity() {
+    // 10% haircut leaves $90,000 of capacity = 900 cents
+    assert_eq!(
+        first_tick_collateral_cost(CollateralCostBasis::PostHaircut, 0.1),
+        900
+    );
+}
+
+#[test]
+fn test_bases_differ_by_haircut_factor() {
+    for haircut in [0.0, 0.02, 0.25, 0.5] {
+        let posted = first_tick_collateral_cost(CollateralCostBasis::Posted, haircut);
+        let post_haircut = first_tick_collateral_cost(CollateralCostBasis::PostHaircut, haircut);
+        assert_eq!(
+            post_haircut,
+            (posted as f64 * (1.0 - haircut)).round() as i64
+        );
+    }
+}
+
+#[test]
+fn test_default_basis_is_posted() {
+    assert_eq!(
+        CostRates::default().collateral_cost_basis,
+        CollateralCostBasis::Posted
+    );
+
+    // Configs written before the field existed still deserialize
+    let mut value = serde_json::to_value(CostRates::default()).unwrap();
+    assert!(value
+        .as_object_mut()
+        .unwrap()
+        .remove("collateral_cost_basis")
+        .is_some());
+    let rates: CostRates = serde_json::from_value(value).unwrap();
+    assert_eq!(rates.collateral_cost_basis, CollateralCostBasis::Posted);
+}
@@ -51,6 +51,7 @@ fn create_test_config_with_haircut(
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -65,6 +66,7 @@ fn create_test_config_with_haircut(
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         ticks_per_day: 100,
@@ -76,10 +78,17 @@ fn create_test_config_with_haircut(
             collateral_cost_per_tick_bps: 2.0, // 2 bps (was 0.0002, which was wrong interpretation)
             eod_penalty: PenaltyMode::Fixed { amount: 100_000 },
             deadline_penalty: PenaltyMode::Fixed { amount: 50_000 },
+            deadline_penalty_per_tick_late: 0,
             split_friction_cost: 100,
             overdue_delay_multiplier: 5.0, // Phase 3: Escalating delay cost for overdue
             priority_delay_multipliers: None, // Enhancement 11.1
             liquidity_cost_per_tick_bps: 0.0, // Enhancement 11.2
+            liquidity_cost_tiers: None,
+            overnight_deposit_rate: 0.0,
+            overnight_overdraft_rate: 0.0,
+            collateral_cost_basis: Default::default(),
+            collateral_post_fee: 0,
+            split_friction_exponent: 1.0,
         },
         lsm_config: LsmConfig::default(),
             scenario_events: None,
@@ -90,6 +99,7 @@ fn create_test_config_with_haircut(
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     }
 }
 
@@ -638,6 +648,7 @@ fn test_cross_agent_collateral_isolation() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -652,6 +663,7 @@ fn test_cross_agent_collateral_isolation() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         ticks_per_day: 100,
@@ -667,6 +679,7 @@ fn test_cross_agent_collateral_isolation() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orch = Orchestrator::new(config).unwrap();
@@ -785,6 +798,7 @@ fn test_hold_collateral_action_is_noop() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -799,6 +813,7 @@ fn test_hold_collateral_action_is_noop() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         ticks_per_day: 100,
@@ -814,6 +829,7 @@ fn test_hold_collateral_action_is_noop() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orch = Orchestrator::new(config).unwrap();
@@ -28,6 +28,7 @@ fn create_test_config() -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+            ..Default::default()
         }],
         cost_rates: CostRates::default(),
         lsm_config: LsmConfig::default(),
@@ -39,6 +40,7 @@ fn create_test_config() -> OrchestratorConfig {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     }
 }
 
@@ -84,6 +86,7 @@ fn test_collateral_events_filter_by_day() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+            ..Default::default()
         }],
         cost_rates: CostRates::default(),
         lsm_config: LsmConfig::default(),
@@ -95,6 +98,7 @@ fn test_collateral_events_filter_by_day() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orch = Orchestrator::new(config).unwrap();
@@ -0,0 +1,149 @@
+//! Tests for `CostRates::collateral_post_fee`
+//!
+//! The fee is a one-time charge per executed collateral post, recorded as
+//! `collateral_fee_cost` and separate from the per-tick opportunity cost.
+
+use payment_simulator_core_rs::models::event::Event;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::SimulationError;
+
+const FEE: i64 = 500;
+
+/// Posts 10k on ticks 0 and 1, withdraws 5k on tick 3, otherwise holds
+const POST_TWICE_POLICY: &str = r#"{
+    "version": "1.0",
+    "policy_id": "post_twice",
+    "payment_tree": {"node_id": "P1", "type": "action", "action": "Release"},
+    "strategic_collateral_tree": {
+        "node_id": "S1",
+        "type": "condition",
+        "condition": {"op": "<", "left": {"field": "current_tick"}, "right": {"value": 2}},
+        "on_true": {
+            "node_id": "S2",
+            "type": "action",
+            "action": "PostCollateral",
+            "parameters": {
+                "amount": {"value": 10000},
+                "reason": {"value": "UrgentLiquidityNeed"}
+            }
+        },
+        "on_false": {
+            "node_id": "S3",
+            "type": "condition",
+            "condition": {"op": "==", "left": {"field": "current_tick"}, "right": {"value": 3}},
+            "on_true": {
+                "node_id": "S4",
+                "type": "action",
+                "action": "WithdrawCollateral",
+                "parameters": {
+                    "amount": {"value": 5000},
+                    "reason": {"value": "CostOptimization"}
+                }
+            },
+            "on_false": {"node_id": "S5", "type": "action", "action": "HoldCollateral"}
+        }
+    },
+    "end_of_tick_collateral_tree": null
+}"#;
+
+fn make_agent(id: &str, policy: PolicyConfig) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 10_000,
+        policy,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+fn config(collateral_post_fee: i64) -> Result<OrchestratorConfig, SimulationError> {
+    OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent(
+            "BANK_A",
+            PolicyConfig::FromJson {
+                json: POST_TWICE_POLICY.to_string(),
+            },
+        ))
+        .agent(make_agent("BANK_B", PolicyConfig::Fifo))
+        .cost_rates(CostRates {
+            collateral_cost_per_tick_bps: 10.0,
+            collateral_post_fee,
+            ..CostRates::default()
+        })
+        .build()
+}
+
+fn run(collateral_post_fee: i64) -> Orchestrator {
+    let mut orch = Orchestrator::new(config(collateral_post_fee).unwrap()).unwrap();
+    for _ in 0..6 {
+        orch.tick().unwrap();
+    }
+    orch
+}
+
+#[test]
+fn test_fee_charged_once_per_post() {
+    let orch = run(FEE);
+    let costs = orch.get_costs("BANK_A").unwrap();
+    assert_eq!(costs.total_collateral_fee_cost, 2 * FEE);
+
+    // One fee accrual per post, on the posting ticks; the withdrawal is free
+    let fee_ticks: Vec<usize> = orch
+        .event_log()
+        .events()
+        .iter()
+        .filter_map(|event| match event {
+            Event::CostAccrual {
+                tick,
+                agent_id,
+                costs,
+            } if agent_id == "BANK_A" && costs.collateral_fee_cost > 0 => {
+                assert_eq!(costs.collateral_fee_cost, FEE);
+                assert_eq!(costs.total(), FEE);
+                Some(*tick)
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(fee_ticks, vec![0, 1]);
+    assert_eq!(
+        orch.event_log().events_of_type("CollateralWithdraw").len(),
+        1
+    );
+}
+
+#[test]
+fn test_opportunity_cost_accrues_independently_of_fee() {
+    let with_fee = run(FEE);
+    let without_fee = run(0);
+    let charged = with_fee.get_costs("BANK_A").unwrap();
+    let free = without_fee.get_costs("BANK_A").unwrap();
+
+    assert!(free.total_collateral_cost > 0);
+    assert_eq!(charged.total_collateral_cost, free.total_collateral_cost);
+    assert_eq!(free.total_collateral_fee_cost, 0);
+    assert_eq!(charged.total() - free.total(), 2 * FEE);
+}
+
+#[test]
+fn test_negative_fee_rejected() {
+    match config(-1) {
+        Err(SimulationError::InvalidConfig(msg)) => {
+            assert!(msg.contains("collateral_post_fee"), "{}", msg)
+        }
+        other => panic!("expected InvalidConfig, got {:?}", other.err()),
+    }
+}
@@ -0,0 +1,78 @@
+//! Tests for `Orchestrator::config`
+//!
+//! The accessor borrows the configuration the orchestrator was built with,
+//! including after a checkpoint round-trip through `load_state`.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+
+fn agent(id: &str) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+fn config() -> OrchestratorConfig {
+    OrchestratorConfig::builder()
+        .ticks_per_day(50)
+        .num_days(3)
+        .rng_seed(4242)
+        .agent(agent("BANK_A"))
+        .agent(agent("BANK_B"))
+        .agent(agent("BANK_C"))
+        .flow_window_ticks(7)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_config_reflects_constructed_config() {
+    let orch = Orchestrator::new(config()).unwrap();
+    let config = orch.config();
+
+    assert_eq!(config.ticks_per_day, 50);
+    assert_eq!(config.num_days, 3);
+    assert_eq!(config.rng_seed, 4242);
+    assert_eq!(config.flow_window_ticks, 7);
+    let ids: Vec<&str> = config.agent_configs.iter().map(|a| a.id.as_str()).collect();
+    assert_eq!(ids, vec!["BANK_A", "BANK_B", "BANK_C"]);
+}
+
+#[test]
+fn test_config_unchanged_by_ticking() {
+    let mut orch = Orchestrator::new(config()).unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 10_000, 20, 5, false)
+        .unwrap();
+    for _ in 0..5 {
+        orch.tick().unwrap();
+    }
+
+    assert_eq!(orch.config().ticks_per_day, 50);
+    assert_eq!(orch.config().rng_seed, 4242);
+    assert_eq!(orch.config().agent_configs.len(), 3);
+}
+
+#[test]
+fn test_config_available_after_load_state() {
+    let mut orch = Orchestrator::new(config()).unwrap();
+    orch.tick().unwrap();
+    let state_json = orch.save_state().unwrap();
+
+    let restored = Orchestrator::load_state(config(), &state_json).unwrap();
+    assert_eq!(restored.config().num_days, 3);
+    assert_eq!(restored.config().rng_seed, 4242);
+    assert_eq!(restored.config().flow_window_ticks, 7);
+}
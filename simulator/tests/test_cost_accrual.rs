@@ -8,7 +8,7 @@
 //! - Cost accumulation over multiple ticks
 
 use payment_simulator_core_rs::{
-    orchestrator::{AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PenaltyMode, PolicyConfig, Queue1Ordering},
+    orchestrator::{AgentConfig, CostBreakdown, CostRates, Orchestrator, OrchestratorConfig, PenaltyMode, PolicyConfig, Queue1Ordering},
     settlement::lsm::LsmConfig,
     Transaction,
 };
@@ -34,6 +34,7 @@ fn create_test_config() -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -48,6 +49,7 @@ fn create_test_config() -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -60,6 +62,7 @@ fn create_test_config() -> OrchestratorConfig {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     }
 }
 
@@ -659,3 +662,146 @@ fn test_eod_penalty_rate_mode_uses_remaining_amount() {
         "EOD rate-mode penalty should be at least 2% of remaining amount: got {}",
         costs.total_penalty_cost);
 }
+
+/// Settle `amount` from BANK_A (1M balance, 500k credit) and return the
+/// liquidity cost accrued over the single tick, using a 2-tier schedule:
+/// 1 bp/tick on the first 300k of overdraft, 10 bp/tick beyond that.
+fn tiered_liquidity_cost_for_payment(amount: i64) -> i64 {
+    let mut config = create_test_config();
+    config.cost_rates.overdraft_bps_per_tick = 5.0; // Ignored when tiers are set
+    config.cost_rates.liquidity_cost_tiers = Some(vec![(0, 1.0), (300_000, 10.0)]);
+
+    let mut orchestrator = Orchestrator::new(config).unwrap();
+    let tx = Transaction::new("BANK_A".to_string(), "BANK_B".to_string(), amount, 0, 10);
+    orchestrator.state_mut().add_transaction(tx.clone());
+    orchestrator
+        .state_mut()
+        .get_agent_mut("BANK_A")
+        .unwrap()
+        .queue_outgoing(tx.id().to_string());
+
+    let result = orchestrator.tick().unwrap();
+    assert_eq!(result.num_settlements, 1);
+
+    orchestrator.get_costs("BANK_A").unwrap().total_liquidity_cost
+}
+
+#[test]
+fn test_tiered_liquidity_cost_shallow_overdraft_pays_first_tier() {
+    // Overdraft 200k sits entirely in tier 1: 200,000 × 1 / 10,000 = 20
+    assert_eq!(tiered_liquidity_cost_for_payment(1_200_000), 20);
+}
+
+#[test]
+fn test_tiered_liquidity_cost_deep_overdraft_pays_blended_rate() {
+    // Overdraft 500k: 300,000 × 1 / 10,000 + 200,000 × 10 / 10,000 = 30 + 200
+    assert_eq!(tiered_liquidity_cost_for_payment(1_500_000), 230);
+}
+
+#[test]
+fn test_tiered_liquidity_cost_rejects_unsorted_thresholds() {
+    let mut config = create_test_config();
+    config.cost_rates.liquidity_cost_tiers = Some(vec![(500_000, 1.0), (100_000, 2.0)]);
+
+    assert!(Orchestrator::new(config).is_err());
+}
+
+/// Two-day run where BANK_A pays 1.5M at tick 0 and stays overdrafted at
+/// -500k while BANK_B holds 3.5M. Only overnight interest is priced.
+fn run_two_days_with_overnight_interest() -> Orchestrator {
+    let mut config = create_test_config();
+    config.ticks_per_day = 10;
+    config.num_days = 2;
+    config.cost_rates = CostRates {
+        overdraft_bps_per_tick: 0.0,
+        delay_cost_per_tick_per_cent: 0.0,
+        collateral_cost_per_tick_bps: 0.0,
+        overnight_deposit_rate: 10.0,
+        overnight_overdraft_rate: 20.0,
+        ..CostRates::default()
+    };
+
+    let mut orchestrator = Orchestrator::new(config).unwrap();
+    let tx = Transaction::new("BANK_A".to_string(), "BANK_B".to_string(), 1_500_000, 0, 5);
+    orchestrator.state_mut().add_transaction(tx.clone());
+    orchestrator
+        .state_mut()
+        .get_agent_mut("BANK_A")
+        .unwrap()
+        .queue_outgoing(tx.id().to_string());
+
+    for _ in 0..20 {
+        orchestrator.tick().unwrap();
+    }
+    orchestrator
+}
+
+#[test]
+fn test_overnight_interest_charged_on_overdraft_each_day() {
+    let orchestrator = run_two_days_with_overnight_interest();
+
+    // 500,000 × 20 / 10,000 = 1,000 cents per night
+    for day in 0..2 {
+        let metrics = orchestrator.get_daily_agent_metrics(day);
+        let bank_a = metrics.iter().find(|m| m.agent_id == "BANK_A").unwrap();
+        assert_eq!(bank_a.closing_balance, -500_000);
+        assert_eq!(bank_a.overnight_interest, 1_000, "day {}", day);
+        assert_eq!(bank_a.total_cost, 1_000, "day {}", day);
+    }
+
+    // Balances are not moved by interest accrual
+    assert_eq!(orchestrator.state().get_agent("BANK_A").unwrap().balance(), -500_000);
+}
+
+#[test]
+fn test_overnight_interest_earned_on_positive_balance_each_day() {
+    let orchestrator = run_two_days_with_overnight_interest();
+
+    // 3,500,000 × 10 / 10,000 = 3,500 cents earned per night (negative cost)
+    for day in 0..2 {
+        let metrics = orchestrator.get_daily_agent_metrics(day);
+        let bank_b = metrics.iter().find(|m| m.agent_id == "BANK_B").unwrap();
+        assert_eq!(bank_b.closing_balance, 3_500_000);
+        assert_eq!(bank_b.overnight_interest, -3_500, "day {}", day);
+    }
+
+    // Exactly one accrual per agent per day boundary
+    let accruals: Vec<_> = orchestrator
+        .event_log()
+        .events_of_type("CostAccrual")
+        .into_iter()
+        .filter_map(|e| match e {
+            payment_simulator_core_rs::Event::CostAccrual { tick, agent_id, costs } => {
+                Some((*tick, agent_id.clone(), costs.overnight_interest))
+            }
+            _ => None,
+        })
+        .filter(|(_, _, interest)| *interest != 0)
+        .collect();
+    assert_eq!(
+        accruals,
+        vec![
+            (9, "BANK_A".to_string(), 1_000),
+            (9, "BANK_B".to_string(), -3_500),
+            (19, "BANK_A".to_string(), 1_000),
+            (19, "BANK_B".to_string(), -3_500),
+        ]
+    );
+}
+
+#[test]
+fn test_cost_breakdown_without_overnight_interest_deserializes() {
+    // Breakdowns serialized before overnight interest existed lack the field
+    let json = serde_json::json!({
+        "liquidity_cost": 100,
+        "delay_cost": 20,
+        "collateral_cost": 0,
+        "penalty_cost": 0,
+        "split_friction_cost": 0,
+        "liquidity_opportunity_cost": 0,
+    });
+
+    let costs: CostBreakdown = serde_json::from_value(json).unwrap();
+    assert_eq!(costs.overnight_interest, 0);
+    assert_eq!(costs.total(), 120);
+}
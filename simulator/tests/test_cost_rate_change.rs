@@ -0,0 +1,161 @@
+//! Tests for time-varying cost rates via `ScenarioEvent::CostRateChange`
+//!
+//! A `CostRateChange` event updates one of the live cost rates mid-run
+//! (e.g. a central bank raising the overdraft rate at midday). Costs
+//! accrued from the event tick onwards use the new value.
+
+use payment_simulator_core_rs::events::{EventSchedule, ScenarioEvent, ScheduledEvent};
+use payment_simulator_core_rs::models::event::Event;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use payment_simulator_core_rs::SimulationError;
+
+fn create_config(events: Vec<ScheduledEvent>) -> OrchestratorConfig {
+    let make_agent = |id: &str, opening_balance: i64| AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 1_000_000,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![make_agent("BANK_A", 0), make_agent("BANK_B", 1_000_000)],
+        cost_rates: CostRates {
+            overdraft_bps_per_tick: 1.0,
+            ..CostRates::default()
+        },
+        lsm_config: LsmConfig::default(),
+        scenario_events: Some(events),
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+fn rate_change(tick: usize, field: &str, new_value: f64) -> ScheduledEvent {
+    ScheduledEvent {
+        event: ScenarioEvent::CostRateChange {
+            field: field.to_string(),
+            new_value,
+        },
+        schedule: EventSchedule::OneTime { tick },
+    }
+}
+
+/// Overdraft cost accrued by BANK_A at the given tick
+fn liquidity_cost_at(orch: &Orchestrator, tick: usize) -> i64 {
+    orch.get_tick_events(tick)
+        .into_iter()
+        .find_map(|event| match event {
+            Event::CostAccrual {
+                agent_id, costs, ..
+            } if agent_id == "BANK_A" => Some(costs.liquidity_cost),
+            _ => None,
+        })
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_overdraft_cost_doubles_after_rate_change() {
+    let base_rate = 1.0;
+    let mut orch = Orchestrator::new(create_config(vec![rate_change(
+        5,
+        "overdraft_bps_per_tick",
+        base_rate * 2.0,
+    )]))
+    .unwrap();
+
+    // BANK_A starts at zero, so paying out puts it into a constant overdraft
+    orch.submit_transaction("BANK_A", "BANK_B", 500_000, 50, 5, false)
+        .unwrap();
+    for _ in 0..10 {
+        orch.tick().unwrap();
+    }
+    assert_eq!(
+        orch.state().get_agent("BANK_A").unwrap().balance(),
+        -500_000
+    );
+
+    let before = liquidity_cost_at(&orch, 3);
+    let after = liquidity_cost_at(&orch, 7);
+    assert!(before > 0);
+    assert_eq!(after, before * 2);
+    assert_eq!(orch.cost_rates().overdraft_bps_per_tick, base_rate * 2.0);
+}
+
+#[test]
+fn test_rate_change_emits_event() {
+    let mut orch = Orchestrator::new(create_config(vec![rate_change(
+        2,
+        "delay_cost_per_tick_per_cent",
+        0.5,
+    )]))
+    .unwrap();
+    let old = orch.cost_rates().delay_cost_per_tick_per_cent;
+
+    for _ in 0..3 {
+        orch.tick().unwrap();
+    }
+
+    let events = orch.get_events_of_type("CostRateChanged");
+    assert_eq!(events.len(), 1);
+    match events[0] {
+        Event::CostRateChanged {
+            tick,
+            field,
+            old_value,
+            new_value,
+        } => {
+            assert_eq!(*tick, 2);
+            assert_eq!(field, "delay_cost_per_tick_per_cent");
+            assert_eq!(*old_value, old);
+            assert_eq!(*new_value, 0.5);
+        }
+        other => panic!("unexpected event {:?}", other),
+    }
+}
+
+#[test]
+fn test_invalid_rate_changes_rejected() {
+    let unknown = Orchestrator::new(create_config(vec![rate_change(1, "no_such_rate", 1.0)]));
+    assert!(matches!(unknown, Err(SimulationError::InvalidConfig(_))));
+
+    let negative = Orchestrator::new(create_config(vec![rate_change(
+        1,
+        "collateral_cost_per_tick_bps",
+        -0.1,
+    )]));
+    assert!(matches!(negative, Err(SimulationError::InvalidConfig(_))));
+}
+
+#[test]
+fn test_changed_rate_survives_checkpoint() {
+    let config = create_config(vec![rate_change(0, "liquidity_cost_per_tick_bps", 2.5)]);
+    let mut orch = Orchestrator::new(config.clone()).unwrap();
+    orch.tick().unwrap();
+
+    let state_json = orch.save_state().unwrap();
+    let restored = Orchestrator::load_state(config, &state_json).unwrap();
+    assert_eq!(restored.cost_rates().liquidity_cost_per_tick_bps, 2.5);
+}
@@ -0,0 +1,215 @@
+//! Tests for `AgentConfig.cost_rate_overrides`
+//!
+//! Agents with overrides are charged at their own rates for the fields they
+//! set and at the global `cost_rates` for everything else; agents without
+//! overrides use the global rates unchanged.
+
+use payment_simulator_core_rs::events::{EventSchedule, ScenarioEvent, ScheduledEvent};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRateOverrides, CostRates, Orchestrator, OrchestratorConfig, PenaltyMode,
+    PolicyConfig,
+};
+use payment_simulator_core_rs::SimulationError;
+
+const GLOBAL_OVERDRAFT_BPS: f64 = 10.0;
+const TIER_OVERDRAFT_BPS: f64 = 25.0;
+
+fn rates(overdraft_bps_per_tick: f64) -> CostRates {
+    CostRates {
+        overdraft_bps_per_tick,
+        delay_cost_per_tick_per_cent: 0.0,
+        ..CostRates::default()
+    }
+}
+
+fn overdraft_override(overdraft_bps_per_tick: f64) -> CostRateOverrides {
+    CostRateOverrides {
+        overdraft_bps_per_tick: Some(overdraft_bps_per_tick),
+        ..CostRateOverrides::default()
+    }
+}
+
+fn make_agent(
+    id: &str,
+    opening_balance: i64,
+    cost_rate_overrides: Option<CostRateOverrides>,
+) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 1_000_000,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        cost_rate_overrides,
+        ..Default::default()
+    }
+}
+
+/// BANK_A uses the global rates; BANK_B is priced at a higher tier
+fn create_orchestrator(
+    bank_b_overrides: Option<CostRateOverrides>,
+) -> Result<Orchestrator, SimulationError> {
+    create_orchestrator_with_events(bank_b_overrides, Vec::new())
+}
+
+fn create_orchestrator_with_events(
+    bank_b_overrides: Option<CostRateOverrides>,
+    events: Vec<ScheduledEvent>,
+) -> Result<Orchestrator, SimulationError> {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .cost_rates(rates(GLOBAL_OVERDRAFT_BPS))
+        .agent(make_agent("BANK_A", 0, None))
+        .agent(make_agent("BANK_B", 0, bank_b_overrides))
+        .agent(make_agent("BANK_C", 0, None))
+        .scenario_events(events)
+        .build()?;
+    Orchestrator::new(config)
+}
+
+#[test]
+fn test_identical_overdrafts_accrue_costs_at_each_agents_rate() {
+    let mut orch = create_orchestrator(Some(overdraft_override(TIER_OVERDRAFT_BPS))).unwrap();
+
+    // Both draw the same 500k overdraft paying BANK_C
+    orch.submit_transaction("BANK_A", "BANK_C", 500_000, 50, 5, false)
+        .unwrap();
+    orch.submit_transaction("BANK_B", "BANK_C", 500_000, 50, 5, false)
+        .unwrap();
+    for _ in 0..4 {
+        orch.tick().unwrap();
+    }
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(-500_000));
+    assert_eq!(orch.get_agent_balance("BANK_B"), Some(-500_000));
+
+    // 500k at 10 bps = 500 per tick; at 25 bps = 1,250 per tick
+    let cost_a = orch.get_costs("BANK_A").unwrap().total_liquidity_cost;
+    let cost_b = orch.get_costs("BANK_B").unwrap().total_liquidity_cost;
+    assert_eq!(cost_a, 4 * 500);
+    assert_eq!(cost_b, 4 * 1_250);
+}
+
+#[test]
+fn test_agents_without_overrides_resolve_to_global_rates() {
+    let orch = create_orchestrator(Some(overdraft_override(TIER_OVERDRAFT_BPS))).unwrap();
+
+    assert_eq!(
+        orch.cost_rates_for_agent("BANK_A").overdraft_bps_per_tick,
+        GLOBAL_OVERDRAFT_BPS
+    );
+    assert_eq!(
+        orch.cost_rates_for_agent("BANK_B").overdraft_bps_per_tick,
+        TIER_OVERDRAFT_BPS
+    );
+}
+
+#[test]
+fn test_invalid_overrides_are_rejected() {
+    let invalid = CostRateOverrides {
+        deadline_penalty_per_tick_late: Some(-1),
+        ..overdraft_override(TIER_OVERDRAFT_BPS)
+    };
+    assert!(matches!(
+        create_orchestrator(Some(invalid)),
+        Err(SimulationError::InvalidConfig(msg)) if msg.contains("BANK_B")
+    ));
+}
+
+#[test]
+fn test_fields_left_out_follow_global_rates() {
+    let orch = create_orchestrator(Some(overdraft_override(TIER_OVERDRAFT_BPS))).unwrap();
+
+    let global = orch.cost_rates();
+    let bank_b = orch.cost_rates_for_agent("BANK_B");
+    assert_eq!(bank_b.delay_cost_per_tick_per_cent, 0.0);
+    assert_eq!(bank_b.eod_penalty, global.eod_penalty);
+    assert_eq!(bank_b.split_friction_cost, global.split_friction_cost);
+}
+
+#[test]
+fn test_rate_change_reaches_fields_not_overridden() {
+    let change = |field: &str, new_value: f64| ScheduledEvent {
+        event: ScenarioEvent::CostRateChange {
+            field: field.to_string(),
+            new_value,
+        },
+        schedule: EventSchedule::OneTime { tick: 0 },
+    };
+    let mut orch = create_orchestrator_with_events(
+        Some(overdraft_override(TIER_OVERDRAFT_BPS)),
+        vec![
+            change("overdraft_bps_per_tick", 50.0),
+            change("delay_cost_per_tick_per_cent", 0.0005),
+        ],
+    )
+    .unwrap();
+    orch.tick().unwrap();
+
+    let bank_b = orch.cost_rates_for_agent("BANK_B");
+    assert_eq!(bank_b.overdraft_bps_per_tick, TIER_OVERDRAFT_BPS);
+    assert_eq!(bank_b.delay_cost_per_tick_per_cent, 0.0005);
+    assert_eq!(
+        orch.cost_rates_for_agent("BANK_A").overdraft_bps_per_tick,
+        50.0
+    );
+}
+
+#[test]
+fn test_eod_penalty_uses_agent_rate() {
+    let overrides = CostRateOverrides {
+        eod_penalty: Some(PenaltyMode::Fixed { amount: 30_000 }),
+        ..CostRateOverrides::default()
+    };
+    let mut orch = create_orchestrator(Some(overrides)).unwrap();
+
+    // Beyond both senders' 1M overdraft capacity, so still queued at EOD
+    orch.submit_transaction("BANK_A", "BANK_C", 2_000_000, 10, 5, false)
+        .unwrap();
+    orch.submit_transaction("BANK_B", "BANK_C", 2_000_000, 10, 5, false)
+        .unwrap();
+    for _ in 0..100 {
+        orch.tick().unwrap();
+    }
+
+    // Same deadline penalty for both; EOD penalty 10,000 vs 30,000
+    let penalty_a = orch.get_costs("BANK_A").unwrap().total_penalty_cost;
+    let penalty_b = orch.get_costs("BANK_B").unwrap().total_penalty_cost;
+    assert!(penalty_a > 0);
+    assert_eq!(penalty_b - penalty_a, 20_000);
+}
+
+#[test]
+fn test_overnight_interest_uses_agent_rate() {
+    let overrides = CostRateOverrides {
+        overnight_overdraft_rate: Some(10.0),
+        ..CostRateOverrides::default()
+    };
+    let mut orch = create_orchestrator(Some(overrides)).unwrap();
+
+    orch.submit_transaction("BANK_A", "BANK_C", 500_000, 50, 5, false)
+        .unwrap();
+    orch.submit_transaction("BANK_B", "BANK_C", 500_000, 50, 5, false)
+        .unwrap();
+    for _ in 0..100 {
+        orch.tick().unwrap();
+    }
+
+    // Global overnight rate is 0; BANK_B pays 500k × 10 / 10,000
+    assert_eq!(
+        orch.get_costs("BANK_A").unwrap().total_overnight_interest,
+        0
+    );
+    assert_eq!(
+        orch.get_costs("BANK_B").unwrap().total_overnight_interest,
+        500
+    );
+}
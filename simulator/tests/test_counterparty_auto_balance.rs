@@ -0,0 +1,190 @@
+//! Tests for CounterpartyWeightChange with auto_balance_others
+//!
+//! When auto-balancing, the other counterparty weights are rescaled
+//! proportionally so the agent's total weight is preserved.
+
+use payment_simulator_core_rs::arrivals::{
+    AmountDistribution, ArrivalConfig, PriorityDistribution,
+};
+use payment_simulator_core_rs::events::{EventSchedule, ScenarioEvent, ScheduledEvent};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use std::collections::HashMap;
+
+fn agent(id: &str, arrival_config: Option<ArrivalConfig>) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+fn create_orchestrator(
+    weights: &[(&str, f64)],
+    new_weight: f64,
+    auto_balance_others: bool,
+) -> Orchestrator {
+    create_orchestrator_for("BANK_B", weights, new_weight, auto_balance_others)
+}
+
+fn create_orchestrator_for(
+    counterparty: &str,
+    weights: &[(&str, f64)],
+    new_weight: f64,
+    auto_balance_others: bool,
+) -> Orchestrator {
+    let arrivals = ArrivalConfig {
+        rate_per_tick: 0.5,
+        amount_distribution: AmountDistribution::Uniform {
+            min: 10_000,
+            max: 50_000,
+        },
+        counterparty_weights: weights
+            .iter()
+            .map(|(id, w)| (id.to_string(), *w))
+            .collect::<HashMap<_, _>>(),
+        deadline_range: (10, 50),
+        priority_distribution: PriorityDistribution::Fixed { value: 0 },
+        divisible: false,
+        ..Default::default()
+    };
+
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(10)
+        .num_days(1)
+        .rng_seed(7)
+        .agent(agent("BANK_A", Some(arrivals)))
+        .agent(agent("BANK_B", None))
+        .agent(agent("BANK_C", None))
+        .agent(agent("BANK_D", None))
+        .scenario_events(vec![ScheduledEvent {
+            event: ScenarioEvent::CounterpartyWeightChange {
+                agent: "BANK_A".to_string(),
+                counterparty: counterparty.to_string(),
+                new_weight,
+                auto_balance_others,
+            },
+            schedule: EventSchedule::OneTime { tick: 2 },
+        }])
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+fn weights_after_event(orch: &mut Orchestrator) -> Vec<(String, f64)> {
+    for _ in 0..3 {
+        orch.tick().unwrap();
+    }
+    orch.get_counterparty_weights("BANK_A")
+        .unwrap()
+        .into_iter()
+        .collect()
+}
+
+fn assert_close(actual: f64, expected: f64) {
+    assert!(
+        (actual - expected).abs() < 1e-9,
+        "expected {expected}, got {actual}"
+    );
+}
+
+#[test]
+fn test_auto_balance_preserves_total_and_ratios() {
+    let mut orch = create_orchestrator(
+        &[("BANK_B", 0.5), ("BANK_C", 0.3), ("BANK_D", 0.2)],
+        0.8,
+        true,
+    );
+    let weights = weights_after_event(&mut orch);
+
+    let total: f64 = weights.iter().map(|(_, w)| w).sum();
+    assert_close(total, 1.0);
+    assert_close(weights[0].1, 0.8);
+    // C:D stays 3:2 over the remaining 0.2
+    assert_close(weights[1].1, 0.12);
+    assert_close(weights[2].1, 0.08);
+    assert_close(weights[1].1 / weights[2].1, 1.5);
+}
+
+#[test]
+fn test_auto_balance_counts_implicit_unit_weights() {
+    // No explicit weights: every counterparty weighs 1.0, total 3.0
+    let mut orch = create_orchestrator(&[], 0.0, true);
+    let weights = weights_after_event(&mut orch);
+
+    assert_close(weights[0].1, 0.0);
+    assert_close(weights[1].1, 1.5);
+    assert_close(weights[2].1, 1.5);
+}
+
+#[test]
+fn test_auto_balance_distributes_uniformly_when_others_are_zero() {
+    let mut orch = create_orchestrator(
+        &[("BANK_B", 1.0), ("BANK_C", 0.0), ("BANK_D", 0.0)],
+        0.4,
+        true,
+    );
+    let weights = weights_after_event(&mut orch);
+
+    assert_close(weights[0].1, 0.4);
+    assert_close(weights[1].1, 0.3);
+    assert_close(weights[2].1, 0.3);
+}
+
+#[test]
+fn test_auto_balance_clamps_others_to_zero_when_weight_exceeds_total() {
+    let mut orch = create_orchestrator(
+        &[("BANK_B", 0.5), ("BANK_C", 0.3), ("BANK_D", 0.2)],
+        2.0,
+        true,
+    );
+    let weights = weights_after_event(&mut orch);
+
+    assert_close(weights[0].1, 2.0);
+    assert_close(weights[1].1, 0.0);
+    assert_close(weights[2].1, 0.0);
+}
+
+#[test]
+fn test_auto_balance_ignores_counterparty_that_is_not_a_receiver() {
+    // The sender itself and an unknown ID are not in the weight map
+    for counterparty in ["BANK_A", "BANK_Z"] {
+        let mut orch = create_orchestrator_for(
+            counterparty,
+            &[("BANK_B", 0.5), ("BANK_C", 0.3), ("BANK_D", 0.2)],
+            0.8,
+            true,
+        );
+        let weights = weights_after_event(&mut orch);
+
+        assert_eq!(weights.len(), 3);
+        assert_close(weights[0].1, 0.5);
+        assert_close(weights[1].1, 0.3);
+        assert_close(weights[2].1, 0.2);
+    }
+}
+
+#[test]
+fn test_without_auto_balance_only_target_changes() {
+    let mut orch = create_orchestrator(
+        &[("BANK_B", 0.5), ("BANK_C", 0.3), ("BANK_D", 0.2)],
+        0.8,
+        false,
+    );
+    let weights = weights_after_event(&mut orch);
+
+    assert_close(weights[0].1, 0.8);
+    assert_close(weights[1].1, 0.3);
+    assert_close(weights[2].1, 0.2);
+}
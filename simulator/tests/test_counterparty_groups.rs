@@ -0,0 +1,187 @@
+//! Tests for group-weighted counterparty selection
+//!
+//! Agents declare an `agent_group`; arrival configs with
+//! `counterparty_group_weights` pick a target group by weight, then a
+//! uniform member of it.
+
+use payment_simulator_core_rs::arrivals::{
+    AmountDistribution, ArrivalConfig, PriorityDistribution,
+};
+use payment_simulator_core_rs::models::Event;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::SimulationError;
+use std::collections::HashMap;
+
+fn weights(entries: &[(&str, f64)]) -> HashMap<String, f64> {
+    entries.iter().map(|(k, w)| (k.to_string(), *w)).collect()
+}
+
+fn arrivals(
+    counterparty_weights: HashMap<String, f64>,
+    counterparty_group_weights: HashMap<String, f64>,
+) -> ArrivalConfig {
+    ArrivalConfig {
+        rate_per_tick: 5.0,
+        amount_distribution: AmountDistribution::Uniform {
+            min: 1_000,
+            max: 2_000,
+        },
+        counterparty_weights,
+        deadline_range: (5, 10),
+        priority_distribution: PriorityDistribution::Fixed { value: 5 },
+        divisible: false,
+        counterparty_group_weights,
+        ..Default::default()
+    }
+}
+
+fn agent(id: &str, group: &str, arrival_config: Option<ArrivalConfig>) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance: 100_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        agent_group: Some(group.to_string()),
+        ..Default::default()
+    }
+}
+
+/// Core banks CORE_A..CORE_C and periphery banks PERI_A..PERI_C; only CORE_A sends
+fn build(sender_arrivals: ArrivalConfig) -> Result<OrchestratorConfig, SimulationError> {
+    OrchestratorConfig::builder()
+        .ticks_per_day(200)
+        .num_days(1)
+        .rng_seed(2024)
+        .agent(agent("CORE_A", "core", Some(sender_arrivals)))
+        .agent(agent("CORE_B", "core", None))
+        .agent(agent("CORE_C", "core", None))
+        .agent(agent("PERI_A", "periphery", None))
+        .agent(agent("PERI_B", "periphery", None))
+        .agent(agent("PERI_C", "periphery", None))
+        .build()
+}
+
+/// Receiver counts over 200 ticks of arrivals from CORE_A
+fn receiver_counts(sender_arrivals: ArrivalConfig) -> HashMap<String, usize> {
+    let mut orch = Orchestrator::new(build(sender_arrivals).unwrap()).unwrap();
+    for _ in 0..200 {
+        orch.tick().unwrap();
+    }
+
+    let mut counts = HashMap::new();
+    for event in orch.event_log().events_of_type("Arrival") {
+        if let Event::Arrival { receiver_id, .. } = event {
+            *counts.entry(receiver_id.clone()).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+fn count(counts: &HashMap<String, usize>, id: &str) -> usize {
+    counts.get(id).copied().unwrap_or(0)
+}
+
+#[test]
+fn test_high_own_group_weight_dominates_selection() {
+    let counts = receiver_counts(arrivals(
+        HashMap::new(),
+        weights(&[("core", 0.9), ("periphery", 0.1)]),
+    ));
+
+    let core = count(&counts, "CORE_B") + count(&counts, "CORE_C");
+    let periphery = count(&counts, "PERI_A") + count(&counts, "PERI_B") + count(&counts, "PERI_C");
+    assert_eq!(count(&counts, "CORE_A"), 0, "sender never pays itself");
+    assert!(core + periphery > 500);
+
+    let core_share = core as f64 / (core + periphery) as f64;
+    assert!(core_share > 0.85, "core share {core_share}");
+    assert!(periphery > 0, "periphery still reachable");
+
+    // Members of the chosen group are picked uniformly
+    let ratio = count(&counts, "CORE_B") as f64 / count(&counts, "CORE_C") as f64;
+    assert!((0.8..1.25).contains(&ratio), "CORE_B/CORE_C ratio {ratio}");
+}
+
+#[test]
+fn test_zero_weight_group_is_never_selected() {
+    let counts = receiver_counts(arrivals(
+        HashMap::new(),
+        weights(&[("core", 0.0), ("periphery", 1.0)]),
+    ));
+
+    assert_eq!(count(&counts, "CORE_B") + count(&counts, "CORE_C"), 0);
+    assert!(count(&counts, "PERI_A") > 0);
+    assert!(count(&counts, "PERI_B") > 0);
+    assert!(count(&counts, "PERI_C") > 0);
+}
+
+#[test]
+fn test_falls_back_to_agent_weights_without_group_weights() {
+    let only_peri_c = weights(&[
+        ("CORE_B", 0.0),
+        ("CORE_C", 0.0),
+        ("PERI_A", 0.0),
+        ("PERI_B", 0.0),
+        ("PERI_C", 1.0),
+    ]);
+
+    // No group weights at all
+    let counts = receiver_counts(arrivals(only_peri_c.clone(), HashMap::new()));
+    assert_eq!(counts.len(), 1);
+    assert!(count(&counts, "PERI_C") > 0);
+
+    // Group weights present but none positive
+    let counts = receiver_counts(arrivals(
+        only_peri_c,
+        weights(&[("core", 0.0), ("periphery", 0.0)]),
+    ));
+    assert_eq!(counts.len(), 1);
+    assert!(count(&counts, "PERI_C") > 0);
+}
+
+#[test]
+fn test_negative_group_weight_rejected() {
+    let result = build(arrivals(HashMap::new(), weights(&[("core", -0.5)])));
+
+    match result {
+        Err(SimulationError::InvalidConfig(msg)) => {
+            assert!(msg.contains("counterparty_group_weights"), "{msg}")
+        }
+        other => panic!("expected InvalidConfig, got {other:?}"),
+    }
+}
+
+#[test]
+fn test_group_weights_reject_ungrouped_agents() {
+    let mut ungrouped = agent("LONE", "unused", None);
+    ungrouped.agent_group = None;
+    let result = OrchestratorConfig::builder()
+        .ticks_per_day(200)
+        .num_days(1)
+        .rng_seed(2024)
+        .agent(agent(
+            "CORE_A",
+            "core",
+            Some(arrivals(HashMap::new(), weights(&[("core", 1.0)]))),
+        ))
+        .agent(agent("CORE_B", "core", None))
+        .agent(ungrouped)
+        .build();
+
+    match result {
+        Err(SimulationError::InvalidConfig(msg)) => {
+            assert!(msg.contains("agent_group") && msg.contains("LONE"), "{msg}")
+        }
+        other => panic!("expected InvalidConfig, got {other:?}"),
+    }
+}
@@ -61,6 +61,7 @@ fn create_pool_config(reallocation_enabled: bool) -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: Some(1_000_000),  // $10,000 pool
                 liquidity_allocation_fraction: Some(0.5), // 50% → $5,000 allocated
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -75,6 +76,7 @@ fn create_pool_config(reallocation_enabled: bool) -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,  // No pool — should be unaffected
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -88,6 +90,7 @@ fn create_pool_config(reallocation_enabled: bool) -> OrchestratorConfig {
         deferred_crediting: false,
         deadline_cap_at_eod: false,
         daily_liquidity_reallocation: reallocation_enabled,
+        ..Default::default()
     }
 }
 
@@ -0,0 +1,137 @@
+//! Tests for daily liquidity efficiency metrics
+//!
+//! `DailyMetrics::liquidity_saved` is the gross value an agent settled minus
+//! its peak net liquidity usage (opening balance minus lowest balance).
+//! `turnover_ratio` is settled value over opening balance.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, DailyMetrics, Orchestrator, OrchestratorConfig, PolicyConfig,
+    Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+
+fn create_config(opening_balance: i64) -> OrchestratorConfig {
+    let make_agent = |id: &str| AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 10,
+        eod_rush_threshold: 0.8,
+        num_days: 2,
+        rng_seed: 42,
+        agent_configs: vec![make_agent("BANK_A"), make_agent("BANK_B")],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+fn day0_metrics<'a>(orchestrator: &'a Orchestrator, agent_id: &str) -> &'a DailyMetrics {
+    orchestrator
+        .get_daily_agent_metrics(0)
+        .into_iter()
+        .find(|m| m.agent_id == agent_id)
+        .expect("day 0 metrics should exist")
+}
+
+fn run_day(orchestrator: &mut Orchestrator) {
+    for _ in 0..10 {
+        orchestrator.tick().unwrap();
+    }
+}
+
+#[test]
+fn test_bilateral_offset_day_saves_liquidity() {
+    // Each bank can fund only a fifth of its payment; the LSM offsets the pair
+    let mut orchestrator = Orchestrator::new(create_config(100_000)).unwrap();
+    orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 500_000, 8, 5, false)
+        .unwrap();
+    orchestrator
+        .submit_transaction("BANK_B", "BANK_A", 500_000, 8, 5, false)
+        .unwrap();
+    run_day(&mut orchestrator);
+
+    for agent_id in ["BANK_A", "BANK_B"] {
+        let metrics = day0_metrics(&orchestrator, agent_id);
+        assert_eq!(metrics.settled_value, 500_000);
+        assert_eq!(metrics.liquidity_saved, 500_000);
+        assert!((metrics.turnover_ratio - 5.0).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_rtgs_only_day_saves_no_liquidity() {
+    // One-way gross payment: every cent sent is drawn from the balance
+    let mut orchestrator = Orchestrator::new(create_config(1_000_000)).unwrap();
+    orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 300_000, 8, 5, false)
+        .unwrap();
+    run_day(&mut orchestrator);
+
+    let sender = day0_metrics(&orchestrator, "BANK_A");
+    assert_eq!(sender.settled_value, 300_000);
+    assert_eq!(sender.liquidity_saved, 0);
+    assert!((sender.turnover_ratio - 0.3).abs() < 1e-9);
+
+    let receiver = day0_metrics(&orchestrator, "BANK_B");
+    assert_eq!(receiver.settled_value, 0);
+    assert_eq!(receiver.liquidity_saved, 0);
+    assert_eq!(receiver.turnover_ratio, 0.0);
+}
+
+#[test]
+fn test_zero_opening_balance_has_zero_turnover() {
+    let mut orchestrator = Orchestrator::new(create_config(0)).unwrap();
+    orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 500_000, 8, 5, false)
+        .unwrap();
+    orchestrator
+        .submit_transaction("BANK_B", "BANK_A", 500_000, 8, 5, false)
+        .unwrap();
+    run_day(&mut orchestrator);
+
+    let metrics = day0_metrics(&orchestrator, "BANK_A");
+    assert_eq!(metrics.liquidity_saved, 500_000);
+    assert_eq!(metrics.turnover_ratio, 0.0);
+}
+
+#[test]
+fn test_settled_value_resets_each_day() {
+    let mut orchestrator = Orchestrator::new(create_config(1_000_000)).unwrap();
+    orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 300_000, 8, 5, false)
+        .unwrap();
+    run_day(&mut orchestrator);
+    run_day(&mut orchestrator);
+
+    let day1 = orchestrator
+        .get_daily_agent_metrics(1)
+        .into_iter()
+        .find(|m| m.agent_id == "BANK_A")
+        .unwrap();
+    assert_eq!(day1.settled_value, 0);
+    assert_eq!(day1.liquidity_saved, 0);
+}
@@ -0,0 +1,216 @@
+//! Tests for `DeadlineEnforcement::PenalizeAndForceSettle`
+//!
+//! BANK_A's payment to BANK_B is blocked by a bilateral limit, so it sits in
+//! Queue 2 past its deadline. Under the hard cutoff it is penalized once and
+//! then force-settled into overdraft; under the default soft delay it lingers.
+
+use payment_simulator_core_rs::models::event::Event;
+use payment_simulator_core_rs::models::transaction::SettlementMechanism;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, AgentLimitsConfig, CostRates, DeadlineEnforcement, Orchestrator,
+    OrchestratorConfig, PenaltyMode, PolicyConfig,
+};
+use std::collections::HashMap;
+
+const DEADLINE_PENALTY: i64 = 50_000;
+
+fn make_agent(id: &str, opening_balance: i64, unsecured_cap: i64) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+fn create_orchestrator(mode: DeadlineEnforcement, unsecured_cap: i64) -> Orchestrator {
+    let mut bank_a = make_agent("BANK_A", 100_000, unsecured_cap);
+    bank_a.limits = Some(AgentLimitsConfig {
+        bilateral_limits: HashMap::from([("BANK_B".to_string(), 10_000)]),
+        multilateral_limit: None,
+    });
+
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(bank_a)
+        .agent(make_agent("BANK_B", 0, 0))
+        .cost_rates(CostRates {
+            deadline_penalty: PenaltyMode::Fixed {
+                amount: DEADLINE_PENALTY,
+            },
+            ..CostRates::default()
+        })
+        .deadline_enforcement(mode)
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+fn forced_settlements(orch: &Orchestrator) -> Vec<Event> {
+    (0..=orch.current_tick())
+        .flat_map(|tick| orch.get_tick_events(tick))
+        .filter(|e| matches!(e, Event::ForcedSettlement { .. }))
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn test_overdue_transaction_force_settled_into_overdraft() {
+    let mut orch = create_orchestrator(DeadlineEnforcement::PenalizeAndForceSettle, 500_000);
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 300_000, 5, 5, false)
+        .unwrap();
+
+    // Blocked by the bilateral limit until the deadline passes
+    for _ in 0..5 {
+        orch.tick().unwrap();
+    }
+    assert!(!orch.get_transaction(&tx_id).unwrap().is_fully_settled());
+    assert_eq!(orch.get_queue2_size(), 1);
+
+    for _ in 0..5 {
+        orch.tick().unwrap();
+    }
+
+    let tx = orch.get_transaction(&tx_id).unwrap();
+    assert!(tx.is_fully_settled());
+    assert_eq!(tx.settlement_mechanism(), Some(SettlementMechanism::Forced));
+    assert_eq!(orch.get_queue2_size(), 0);
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(-200_000));
+    assert_eq!(orch.get_agent_balance("BANK_B"), Some(300_000));
+
+    let forced = forced_settlements(&orch);
+    assert_eq!(forced.len(), 1);
+    match &forced[0] {
+        Event::ForcedSettlement {
+            tx_id: event_tx,
+            amount,
+            deadline_tick,
+            sender_balance_before,
+            sender_balance_after,
+            queue,
+            ..
+        } => {
+            assert_eq!(event_tx, &tx_id);
+            assert_eq!(*amount, 300_000);
+            assert_eq!(*deadline_tick, 5);
+            assert_eq!(*sender_balance_before, 100_000);
+            assert_eq!(*sender_balance_after, -200_000);
+            assert_eq!(queue, "queue2");
+        }
+        _ => unreachable!(),
+    }
+
+    // Penalty charged exactly once
+    let costs = orch.get_costs("BANK_A").unwrap();
+    assert_eq!(costs.total_penalty_cost, DEADLINE_PENALTY);
+}
+
+#[test]
+fn test_soft_delay_leaves_overdue_transaction_queued() {
+    let mut orch = create_orchestrator(DeadlineEnforcement::SoftDelay, 500_000);
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 300_000, 5, 5, false)
+        .unwrap();
+
+    for _ in 0..10 {
+        orch.tick().unwrap();
+    }
+
+    let tx = orch.get_transaction(&tx_id).unwrap();
+    assert!(tx.is_overdue());
+    assert!(!tx.is_fully_settled());
+    assert_eq!(orch.get_queue2_size(), 1);
+    assert!(forced_settlements(&orch).is_empty());
+    assert_eq!(
+        orch.get_costs("BANK_A").unwrap().total_penalty_cost,
+        DEADLINE_PENALTY
+    );
+}
+
+#[test]
+fn test_force_settlement_respects_overdraft_limit() {
+    // 100k balance + 100k unsecured cap cannot cover 300k
+    let mut orch = create_orchestrator(DeadlineEnforcement::PenalizeAndForceSettle, 100_000);
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 300_000, 5, 5, false)
+        .unwrap();
+
+    for _ in 0..10 {
+        orch.tick().unwrap();
+    }
+
+    let tx = orch.get_transaction(&tx_id).unwrap();
+    assert!(tx.is_overdue());
+    assert!(!tx.is_fully_settled());
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(100_000));
+    assert!(forced_settlements(&orch).is_empty());
+
+    // Still penalized only once while it keeps waiting
+    assert_eq!(
+        orch.get_costs("BANK_A").unwrap().total_penalty_cost,
+        DEADLINE_PENALTY
+    );
+}
+
+#[test]
+fn test_forced_credit_to_deferred_receiver_applied_at_end_of_tick() {
+    let mut bank_a = make_agent("BANK_A", 100_000, 500_000);
+    bank_a.limits = Some(AgentLimitsConfig {
+        bilateral_limits: HashMap::from([("BANK_B".to_string(), 10_000)]),
+        multilateral_limit: None,
+    });
+    let mut bank_b = make_agent("BANK_B", 0, 0);
+    bank_b.deferred_crediting = Some(true);
+
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(bank_a)
+        .agent(bank_b)
+        .deadline_enforcement(DeadlineEnforcement::PenalizeAndForceSettle)
+        .build()
+        .unwrap();
+    let mut orch = Orchestrator::new(config).unwrap();
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 300_000, 5, 5, false)
+        .unwrap();
+    for _ in 0..10 {
+        orch.tick().unwrap();
+    }
+    assert!(orch.get_transaction(&tx_id).unwrap().is_fully_settled());
+    assert_eq!(orch.get_agent_balance("BANK_B"), Some(300_000));
+
+    let forced_tick = forced_settlements(&orch)[0].tick();
+    let applied: Vec<&Event> = orch
+        .get_tick_events(forced_tick)
+        .into_iter()
+        .filter(|e| matches!(e, Event::DeferredCreditApplied { .. }))
+        .collect();
+    assert_eq!(applied.len(), 1);
+    match applied[0] {
+        Event::DeferredCreditApplied {
+            agent_id,
+            amount,
+            source_transactions,
+            ..
+        } => {
+            assert_eq!(agent_id, "BANK_B");
+            assert_eq!(*amount, 300_000);
+            assert_eq!(source_transactions, &vec![tx_id]);
+        }
+        _ => unreachable!(),
+    }
+}
@@ -26,6 +26,7 @@ fn create_test_arrival_config() -> ArrivalConfig {
         deadline_range: (5, 15), // Range that may extend past day boundary
         priority_distribution: PriorityDistribution::Fixed { value: 5 },
         divisible: false,
+        ..Default::default()
     }
 }
 
@@ -41,6 +42,7 @@ fn create_arrival_config_with_range(min_offset: usize, max_offset: usize) -> Arr
         deadline_range: (min_offset, max_offset),
         priority_distribution: PriorityDistribution::Fixed { value: 5 },
         divisible: false,
+        ..Default::default()
     }
 }
 
@@ -357,6 +359,7 @@ fn test_band_arrivals_respect_eod_cap() {
             deadline_offset_max: 5, // Tight deadlines
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
         normal: Some(ArrivalBandConfig {
             rate_per_tick: 5.0,
@@ -368,6 +371,7 @@ fn test_band_arrivals_respect_eod_cap() {
             deadline_offset_max: 20, // Would extend past day with late arrival
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
         low: None,
     };
@@ -426,6 +430,7 @@ fn test_mixed_mode_respects_eod_cap() {
             deadline_offset_max: 15,
             counterparty_weights: HashMap::new(),
             divisible: false,
+            deadline_jitter: None,
         }),
         low: None,
     };
@@ -0,0 +1,205 @@
+//! Tests for `CostRates::deadline_penalty_per_tick_late`
+//!
+//! A transaction settling after its deadline is charged the one-time
+//! `deadline_penalty` plus the per-tick rate for every tick of lateness.
+
+use payment_simulator_core_rs::models::transaction::TransactionStatus;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, DeadlineEnforcement, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::{PenaltyMode, SimulationError};
+
+const FLAT_PENALTY: i64 = 50_000;
+const PER_TICK_LATE: i64 = 1_000;
+
+fn make_agent(id: &str, opening_balance: i64) -> AgentConfig {
+    make_agent_with_policy(id, opening_balance, PolicyConfig::Fifo)
+}
+
+fn make_agent_with_policy(id: &str, opening_balance: i64, policy: PolicyConfig) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+fn cost_rates(per_tick_late: i64) -> CostRates {
+    CostRates {
+        delay_cost_per_tick_per_cent: 0.0,
+        deadline_penalty: PenaltyMode::Fixed {
+            amount: FLAT_PENALTY,
+        },
+        deadline_penalty_per_tick_late: per_tick_late,
+        ..CostRates::default()
+    }
+}
+
+fn config(per_tick_late: i64) -> Result<OrchestratorConfig, SimulationError> {
+    OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent("BANK_A", 0))
+        .agent(make_agent("BANK_B", 0))
+        .agent(make_agent("BANK_C", 1_000_000))
+        .cost_rates(cost_rates(per_tick_late))
+        .build()
+}
+
+/// BANK_A's payment (deadline tick 2) waits in Queue 2 until BANK_C funds
+/// BANK_A at tick 6, so it settles 4 ticks late. Returns BANK_A's penalty cost.
+fn run_late_settlement(per_tick_late: i64) -> i64 {
+    let mut orch = Orchestrator::new(config(per_tick_late).unwrap()).unwrap();
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 100_000, 2, 5, false)
+        .unwrap();
+    for _ in 0..6 {
+        orch.tick().unwrap();
+    }
+    assert_eq!(orch.get_queue2_size(), 1);
+
+    orch.submit_transaction("BANK_C", "BANK_A", 100_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(
+        *orch.get_transaction(&tx_id).unwrap().status(),
+        TransactionStatus::Settled { tick: 6 }
+    );
+
+    // Further ticks must not charge the lateness again
+    orch.tick().unwrap();
+    orch.get_costs("BANK_A").unwrap().total_penalty_cost
+}
+
+#[test]
+fn test_settling_four_ticks_late_adds_per_tick_penalty() {
+    assert_eq!(
+        run_late_settlement(PER_TICK_LATE),
+        FLAT_PENALTY + 4 * PER_TICK_LATE
+    );
+}
+
+#[test]
+fn test_zero_per_tick_rate_keeps_flat_penalty() {
+    assert_eq!(run_late_settlement(0), FLAT_PENALTY);
+}
+
+#[test]
+fn test_on_time_settlement_pays_no_lateness() {
+    let mut orch = Orchestrator::new(config(PER_TICK_LATE).unwrap()).unwrap();
+    orch.submit_transaction("BANK_C", "BANK_B", 100_000, 2, 5, false)
+        .unwrap();
+    for _ in 0..5 {
+        orch.tick().unwrap();
+    }
+    assert_eq!(orch.get_costs("BANK_C").unwrap().total_penalty_cost, 0);
+}
+
+#[test]
+fn test_negative_per_tick_rate_rejected() {
+    match config(-1) {
+        Err(SimulationError::InvalidConfig(msg)) => {
+            assert!(msg.contains("deadline_penalty_per_tick_late"), "{}", msg)
+        }
+        other => panic!("expected InvalidConfig, got {:?}", other.err()),
+    }
+}
+
+#[test]
+fn test_split_payment_charged_per_child_not_parent() {
+    // BANK_A splits its payment in two; both halves wait for funding
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent_with_policy(
+            "BANK_A",
+            0,
+            PolicyConfig::MockSplitting { num_splits: 2 },
+        ))
+        .agent(make_agent("BANK_B", 0))
+        .agent(make_agent("BANK_C", 1_000_000))
+        .cost_rates(cost_rates(PER_TICK_LATE))
+        .build()
+        .unwrap();
+    let mut orch = Orchestrator::new(config).unwrap();
+    let parent_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 100_000, 2, 5, true)
+        .unwrap();
+    for _ in 0..6 {
+        orch.tick().unwrap();
+    }
+
+    orch.submit_transaction("BANK_C", "BANK_A", 100_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    orch.tick().unwrap();
+    assert_eq!(
+        *orch.get_transaction(&parent_id).unwrap().status(),
+        TransactionStatus::Settled { tick: 6 }
+    );
+
+    // Each child: flat penalty plus 4 ticks late; the parent adds nothing
+    assert_eq!(
+        orch.get_costs("BANK_A").unwrap().total_penalty_cost,
+        2 * (FLAT_PENALTY + 4 * PER_TICK_LATE)
+    );
+}
+
+#[test]
+fn test_force_settled_payment_pays_lateness() {
+    let hold_all = r#"{
+        "version": "1.0",
+        "policy_id": "hold_all",
+        "parameters": {},
+        "payment_tree": {"type": "action", "node_id": "A1", "action": "Hold"},
+        "strategic_collateral_tree": null,
+        "end_of_tick_collateral_tree": null
+    }"#;
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent_with_policy(
+            "BANK_A",
+            1_000_000,
+            PolicyConfig::FromJson {
+                json: hold_all.to_string(),
+            },
+        ))
+        .agent(make_agent("BANK_B", 0))
+        .cost_rates(cost_rates(PER_TICK_LATE))
+        .deadline_enforcement(DeadlineEnforcement::PenalizeAndForceSettle)
+        .build()
+        .unwrap();
+    let mut orch = Orchestrator::new(config).unwrap();
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 100_000, 2, 5, false)
+        .unwrap();
+
+    // Goes overdue at tick 3 and is force-settled in the same tick
+    let mut tick_costs = Vec::new();
+    for _ in 0..5 {
+        tick_costs.push(orch.tick().unwrap().total_cost);
+    }
+    assert_eq!(
+        *orch.get_transaction(&tx_id).unwrap().status(),
+        TransactionStatus::Settled { tick: 3 }
+    );
+    assert_eq!(
+        orch.get_costs("BANK_A").unwrap().total_penalty_cost,
+        FLAT_PENALTY + PER_TICK_LATE
+    );
+    assert_eq!(tick_costs[3], FLAT_PENALTY + PER_TICK_LATE);
+}
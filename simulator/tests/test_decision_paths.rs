@@ -0,0 +1,146 @@
+//! Tests for opt-in decision path recording
+//!
+//! With `record_decision_paths` enabled, every payment tree evaluation
+//! stores the nodes traversed from root to action, keyed by tick and
+//! transaction ID. Recording is off by default.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+
+/// Holds payments above 100,000 and releases everything else.
+const TWO_BRANCH_POLICY: &str = r#"{
+    "version": "1.0",
+    "policy_id": "two_branch",
+    "payment_tree": {
+        "type": "condition",
+        "node_id": "N1_LargePayment",
+        "condition": {
+            "op": ">",
+            "left": {"field": "amount"},
+            "right": {"value": 100000}
+        },
+        "on_true": {
+            "type": "action",
+            "node_id": "A1_Hold",
+            "action": "Hold"
+        },
+        "on_false": {
+            "type": "action",
+            "node_id": "A2_Release",
+            "action": "Release"
+        }
+    },
+    "parameters": {}
+}"#;
+
+fn create_config(record_decision_paths: bool) -> OrchestratorConfig {
+    let make_agent = |id: &str, policy: PolicyConfig| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![
+            make_agent(
+                "BANK_A",
+                PolicyConfig::FromJson {
+                    json: TWO_BRANCH_POLICY.to_string(),
+                },
+            ),
+            make_agent("BANK_B", PolicyConfig::Fifo),
+        ],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        record_decision_paths,
+        ..Default::default()
+    }
+}
+
+fn node_ids(orchestrator: &Orchestrator, tick: usize, tx_id: &str) -> Vec<String> {
+    orchestrator
+        .get_decision_path(tick, tx_id)
+        .expect("decision path should be recorded")
+        .nodes
+        .iter()
+        .map(|node| node.node_id.clone())
+        .collect()
+}
+
+#[test]
+fn test_decision_path_follows_false_branch() {
+    let mut orchestrator = Orchestrator::new(create_config(true)).unwrap();
+    let tx_id = orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 50_000, 50, 5, false)
+        .unwrap();
+
+    orchestrator.tick().unwrap();
+
+    assert_eq!(
+        node_ids(&orchestrator, 0, &tx_id),
+        vec!["N1_LargePayment", "A2_Release"]
+    );
+    let path = orchestrator.get_decision_path(0, &tx_id).unwrap();
+    assert_eq!(path.nodes[0].node_type, "condition");
+    assert_eq!(path.nodes[0].result, Some(false));
+    assert_eq!(path.nodes[1].node_type, "action");
+    assert_eq!(path.nodes[1].result, None);
+}
+
+#[test]
+fn test_decision_path_recorded_each_tick_for_held_payment() {
+    let mut orchestrator = Orchestrator::new(create_config(true)).unwrap();
+    let tx_id = orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 200_000, 50, 5, false)
+        .unwrap();
+
+    orchestrator.tick().unwrap();
+    orchestrator.tick().unwrap();
+
+    for tick in 0..2 {
+        assert_eq!(
+            node_ids(&orchestrator, tick, &tx_id),
+            vec!["N1_LargePayment", "A1_Hold"]
+        );
+        let path = orchestrator.get_decision_path(tick, &tx_id).unwrap();
+        assert_eq!(path.nodes[0].result, Some(true));
+    }
+    assert!(orchestrator.get_decision_path(2, &tx_id).is_none());
+}
+
+#[test]
+fn test_decision_paths_not_recorded_by_default() {
+    let mut orchestrator = Orchestrator::new(create_config(false)).unwrap();
+    let tx_id = orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 50_000, 50, 5, false)
+        .unwrap();
+
+    orchestrator.tick().unwrap();
+
+    assert!(orchestrator.get_decision_path(0, &tx_id).is_none());
+}
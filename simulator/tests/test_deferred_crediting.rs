@@ -151,6 +151,7 @@ fn test_deferred_crediting_causes_gridlock_zero_balances() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -165,6 +166,7 @@ fn test_deferred_crediting_causes_gridlock_zero_balances() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -172,8 +174,11 @@ fn test_deferred_crediting_causes_gridlock_zero_balances() {
         lsm_config: LsmConfig {
             enable_bilateral: false,
             enable_cycles: false,
-            max_cycle_length: 0,
+            max_cycle_length: Some(0),
             max_cycles_per_tick: 0,
+            partial_settlement: false,
+            lsm_interval_ticks: 1,
+            enable_multilateral_netting: false,
         },
         scenario_events: None,
         queue1_ordering: Default::default(),
@@ -182,6 +187,7 @@ fn test_deferred_crediting_causes_gridlock_zero_balances() {
         algorithm_sequencing: false,
         entry_disposition_offsetting: false,
         eod_rush_threshold: 0.8,
+        ..Default::default()
     };
 
     let mut orch = Orchestrator::new(config).expect("Failed to create orchestrator");
@@ -241,6 +247,7 @@ fn test_immediate_crediting_allows_recycling() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -255,6 +262,7 @@ fn test_immediate_crediting_allows_recycling() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -266,6 +274,7 @@ fn test_immediate_crediting_allows_recycling() {
         algorithm_sequencing: false,
         entry_disposition_offsetting: false,
         eod_rush_threshold: 0.8,
+        ..Default::default()
     };
 
     let mut orch = Orchestrator::new(config).expect("Failed to create orchestrator");
@@ -316,6 +325,7 @@ fn test_deferred_credit_event_emitted() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -330,6 +340,7 @@ fn test_deferred_credit_event_emitted() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -341,6 +352,7 @@ fn test_deferred_credit_event_emitted() {
         algorithm_sequencing: false,
         entry_disposition_offsetting: false,
         eod_rush_threshold: 0.8,
+        ..Default::default()
     };
 
     let mut orch = Orchestrator::new(config).expect("Failed to create orchestrator");
@@ -399,6 +411,7 @@ fn test_deferred_crediting_determinism() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -413,6 +426,7 @@ fn test_deferred_crediting_determinism() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_C".to_string(),
@@ -427,6 +441,7 @@ fn test_deferred_crediting_determinism() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -438,6 +453,7 @@ fn test_deferred_crediting_determinism() {
         algorithm_sequencing: false,
         entry_disposition_offsetting: false,
         eod_rush_threshold: 0.8,
+        ..Default::default()
     };
 
     // Run simulation twice with same config and transactions
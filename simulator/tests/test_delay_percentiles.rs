@@ -0,0 +1,119 @@
+//! Tests for settlement delay percentiles
+//!
+//! Percentiles use the nearest-rank method over the same delays as the
+//! settlement delay histogram: rank = ceil(p * n), at least 1.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+
+fn create_config() -> OrchestratorConfig {
+    let make_agent = |id: &str, opening_balance: i64| AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent("BANK_A", 0))
+        .agent(make_agent("BANK_B", 100_000))
+        .agent(make_agent("BANK_C", 100_000))
+        .build()
+        .unwrap()
+}
+
+/// Produces settled delays [0, 0, 0, 2, 5]
+///
+/// BANK_A has no liquidity, so its two payments wait in Queue 2 until
+/// BANK_B funds it at tick 2 (10,000) and tick 5 (20,000). The funding
+/// payments and BANK_C's payment settle on arrival.
+fn run_known_delays() -> Orchestrator {
+    let mut orchestrator = Orchestrator::new(create_config()).unwrap();
+
+    orchestrator
+        .submit_transaction("BANK_A", "BANK_C", 10_000, 50, 5, false)
+        .unwrap();
+    orchestrator
+        .submit_transaction("BANK_A", "BANK_C", 20_000, 50, 5, false)
+        .unwrap();
+    orchestrator
+        .submit_transaction("BANK_C", "BANK_B", 5_000, 50, 5, false)
+        .unwrap();
+
+    for tick in 0..6 {
+        if tick == 2 {
+            orchestrator
+                .submit_transaction("BANK_B", "BANK_A", 10_000, 50, 5, false)
+                .unwrap();
+        }
+        if tick == 5 {
+            orchestrator
+                .submit_transaction("BANK_B", "BANK_A", 20_000, 50, 5, false)
+                .unwrap();
+        }
+        orchestrator.tick().unwrap();
+    }
+
+    assert_eq!(
+        orchestrator.get_queue2_size(),
+        0,
+        "all payments should settle"
+    );
+    orchestrator
+}
+
+#[test]
+fn test_p50_and_p99_match_nearest_rank() {
+    let orchestrator = run_known_delays();
+
+    // n = 5: p50 -> rank ceil(2.5) = 3 -> 0; p99 -> rank ceil(4.95) = 5 -> 5
+    assert_eq!(
+        orchestrator.delay_percentiles(&[0.5, 0.99]),
+        vec![(0.5, 0), (0.99, 5)]
+    );
+}
+
+#[test]
+fn test_intermediate_ranks_and_request_order() {
+    let orchestrator = run_known_delays();
+
+    // p80 -> rank 4 -> 2; p95 -> rank 5 -> 5; p60 -> rank 3 -> 0
+    assert_eq!(
+        orchestrator.delay_percentiles(&[0.8, 0.95, 0.6]),
+        vec![(0.8, 2), (0.95, 5), (0.6, 0)]
+    );
+}
+
+#[test]
+fn test_out_of_range_percentiles_are_clamped() {
+    let orchestrator = run_known_delays();
+
+    assert_eq!(
+        orchestrator.delay_percentiles(&[-0.5, 0.0, 1.0, 7.0]),
+        vec![(0.0, 0), (0.0, 0), (1.0, 5), (1.0, 5)]
+    );
+}
+
+#[test]
+fn test_no_settlements_returns_zeros() {
+    let orchestrator = Orchestrator::new(create_config()).unwrap();
+
+    assert_eq!(
+        orchestrator.delay_percentiles(&[0.5, 0.95, 0.99]),
+        vec![(0.5, 0), (0.95, 0), (0.99, 0)]
+    );
+    assert!(orchestrator.delay_percentiles(&[]).is_empty());
+}
@@ -0,0 +1,107 @@
+//! Tests for the entry disposition offsetting minimum leg size
+//!
+//! With `entry_offset_min_amount` set, entry-time bilateral offsetting only
+//! triggers when both directions of the pair exceed the threshold. Opposing
+//! payments at or below it stay queued.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+
+fn create_config(entry_offset_min_amount: i64) -> OrchestratorConfig {
+    let make_agent = |id: &str| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 0,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![make_agent("BANK_A"), make_agent("BANK_B")],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: true,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        entry_offset_min_amount,
+        ..Default::default()
+    }
+}
+
+/// Neither bank has liquidity, so opposing payments of `amount` can only
+/// settle by offsetting. Returns the entry offset event count and orchestrator.
+fn run_opposing_payments(entry_offset_min_amount: i64, amount: i64) -> (usize, Orchestrator) {
+    let mut orchestrator = Orchestrator::new(create_config(entry_offset_min_amount)).unwrap();
+
+    orchestrator
+        .submit_transaction("BANK_B", "BANK_A", amount, 50, 5, false)
+        .unwrap();
+    orchestrator.tick().unwrap();
+    orchestrator
+        .submit_transaction("BANK_A", "BANK_B", amount, 50, 5, false)
+        .unwrap();
+    orchestrator.tick().unwrap();
+
+    let offsets = orchestrator
+        .event_log()
+        .events_of_type("EntryDispositionOffset")
+        .len();
+    (offsets, orchestrator)
+}
+
+#[test]
+fn test_small_payment_does_not_trigger_entry_offset_below_threshold() {
+    let (offsets, orchestrator) = run_opposing_payments(10_000, 100);
+
+    assert_eq!(offsets, 0);
+    assert_eq!(orchestrator.get_queue2_size(), 2, "both payments should stay queued");
+}
+
+#[test]
+fn test_payment_equal_to_threshold_does_not_trigger_entry_offset() {
+    let (offsets, orchestrator) = run_opposing_payments(10_000, 10_000);
+
+    assert_eq!(offsets, 0);
+    assert_eq!(orchestrator.get_queue2_size(), 2, "legs must exceed the threshold");
+}
+
+#[test]
+fn test_payment_above_threshold_triggers_entry_offset() {
+    let (offsets, orchestrator) = run_opposing_payments(10_000, 50_000);
+
+    assert_eq!(offsets, 1);
+    assert_eq!(orchestrator.get_queue2_size(), 0);
+}
+
+#[test]
+fn test_zero_threshold_offsets_any_size() {
+    let (offsets, orchestrator) = run_opposing_payments(0, 100);
+
+    assert_eq!(offsets, 1);
+    assert_eq!(orchestrator.get_queue2_size(), 0);
+}
+
+#[test]
+fn test_negative_entry_offset_min_amount_rejected() {
+    assert!(Orchestrator::new(create_config(-1)).is_err());
+}
@@ -0,0 +1,127 @@
+//! Tests for `force_release_at_eod`
+//!
+//! A liquidity-aware policy with an unreachable buffer holds everything in
+//! Queue 1; the EOD force release submits it all on the day's last tick.
+
+use payment_simulator_core_rs::models::event::Event;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+
+const TICKS_PER_DAY: usize = 10;
+
+fn make_agent(id: &str, policy: PolicyConfig) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+fn create_orchestrator(force_release_at_eod: bool) -> Orchestrator {
+    let conservative = PolicyConfig::LiquidityAware {
+        target_buffer: 10_000_000,
+        urgency_threshold: 1,
+    };
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(TICKS_PER_DAY)
+        .num_days(2)
+        .rng_seed(42)
+        .agent(make_agent("BANK_A", conservative))
+        .agent(make_agent("BANK_B", PolicyConfig::Fifo))
+        .force_release_at_eod(force_release_at_eod)
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+fn submit_held(orch: &mut Orchestrator) -> Vec<String> {
+    [100_000, 200_000, 300_000]
+        .into_iter()
+        .map(|amount| {
+            orch.submit_transaction("BANK_A", "BANK_B", amount, 19, 5, false)
+                .unwrap()
+        })
+        .collect()
+}
+
+#[test]
+fn test_held_transactions_released_on_last_tick_of_day() {
+    let mut orch = create_orchestrator(true);
+    let tx_ids = submit_held(&mut orch);
+
+    for _ in 0..TICKS_PER_DAY - 1 {
+        orch.tick().unwrap();
+    }
+    assert_eq!(orch.get_queue1_size("BANK_A"), Some(3));
+
+    let last_tick = orch.current_tick();
+    orch.tick().unwrap();
+
+    assert_eq!(orch.get_queue1_size("BANK_A"), Some(0));
+    for tx_id in &tx_ids {
+        assert!(orch.get_transaction(tx_id).unwrap().is_fully_settled());
+    }
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(400_000));
+
+    let released: Vec<String> = orch
+        .get_tick_events(last_tick)
+        .iter()
+        .filter_map(|event| match event {
+            Event::EodForceRelease {
+                agent_id, tx_id, ..
+            } => {
+                assert_eq!(agent_id, "BANK_A");
+                Some(tx_id.clone())
+            }
+            _ => None,
+        })
+        .collect();
+    assert_eq!(released, tx_ids);
+}
+
+#[test]
+fn test_force_release_runs_after_policy_pass() {
+    let mut orch = create_orchestrator(true);
+    let tx_ids = submit_held(&mut orch);
+    for _ in 0..TICKS_PER_DAY {
+        orch.tick().unwrap();
+    }
+
+    // The policy still evaluated (and held) the transaction on the final tick
+    let events = orch.get_tick_events(TICKS_PER_DAY - 1);
+    let hold = events
+        .iter()
+        .position(|e| matches!(e, Event::PolicyHold { tx_id, .. } if *tx_id == tx_ids[0]))
+        .unwrap();
+    let release = events
+        .iter()
+        .position(|e| matches!(e, Event::EodForceRelease { tx_id, .. } if *tx_id == tx_ids[0]))
+        .unwrap();
+    assert!(hold < release);
+}
+
+#[test]
+fn test_transactions_stay_queued_without_force_release() {
+    let mut orch = create_orchestrator(false);
+    submit_held(&mut orch);
+    for _ in 0..TICKS_PER_DAY {
+        orch.tick().unwrap();
+    }
+
+    assert_eq!(orch.get_queue1_size("BANK_A"), Some(3));
+    assert!(!orch
+        .get_tick_events(TICKS_PER_DAY - 1)
+        .iter()
+        .any(|event| matches!(event, Event::EodForceRelease { .. })));
+}
@@ -29,7 +29,7 @@ use payment_simulator_core_rs::{
     arrivals::{AmountDistribution, ArrivalConfig, PriorityDistribution},
     orchestrator::{AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering},
     settlement::lsm::LsmConfig,
-    Transaction,
+    SettlementMechanism, Transaction,
 };
 use std::collections::HashMap;
 
@@ -54,6 +54,7 @@ fn create_test_config() -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -71,6 +72,7 @@ fn create_test_config() -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -83,6 +85,7 @@ fn create_test_config() -> OrchestratorConfig {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     }
 }
 
@@ -164,6 +167,7 @@ fn test_arrival_events_for_automatic_transactions() {
         deadline_range: (10, 50),
         priority_distribution: PriorityDistribution::Fixed { value: 5 },
         divisible: true,
+        ..Default::default()
     });
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -385,6 +389,10 @@ fn test_settlement_event() {
         !settlement_events.is_empty(),
         "Expected RtgsImmediateSettlement event"
     );
+    assert_eq!(
+        orchestrator.get_settlement_mechanism(&tx_id),
+        Some(SettlementMechanism::RtgsImmediate)
+    );
 
     // Verify event details
     let settlement = settlement_events
@@ -592,7 +600,7 @@ fn test_lsm_bilateral_offset_event() {
 
     config.lsm_config.enable_bilateral = true;
     config.lsm_config.enable_cycles = true; // Enable cycles (2-agent cycles are bilateral offsets)
-    config.lsm_config.max_cycle_length = 4;
+    config.lsm_config.max_cycle_length = Some(4);
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
 
@@ -666,9 +674,11 @@ fn test_lsm_bilateral_offset_event() {
         tx_ids,
         amount_a,
         amount_b,
+        settlement_mechanism,
     }) = lsm_events.first()
     {
         assert_eq!(*tick, 0);
+        assert_eq!(*settlement_mechanism, SettlementMechanism::BilateralOffset);
         // Verify the amounts match the original transaction amounts
         // The bilateral offset settled both transactions:
         // - tx1: BANK_A → BANK_B for 100_000
@@ -684,6 +694,16 @@ fn test_lsm_bilateral_offset_event() {
                 && *amount_a == 50_000 && *amount_b == 100_000)
         );
     }
+
+    // Both legs of the offset report the LSM mechanism
+    assert_eq!(
+        orchestrator.get_settlement_mechanism(&tx1_id),
+        Some(SettlementMechanism::BilateralOffset)
+    );
+    assert_eq!(
+        orchestrator.get_settlement_mechanism(&tx2_id),
+        Some(SettlementMechanism::BilateralOffset)
+    );
 }
 
 // ============================================================================
@@ -713,6 +733,7 @@ fn test_lsm_cycle_settlement_event() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -727,6 +748,7 @@ fn test_lsm_cycle_settlement_event() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_C".to_string(),
@@ -741,14 +763,18 @@ fn test_lsm_cycle_settlement_event() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
         lsm_config: LsmConfig {
             enable_bilateral: true,
             enable_cycles: true,
-            max_cycle_length: 4,
+            max_cycle_length: Some(4),
             max_cycles_per_tick: 10,
+            partial_settlement: false,
+            lsm_interval_ticks: 1,
+            enable_multilateral_netting: false,
         },
         scenario_events: None,
         queue1_ordering: Queue1Ordering::default(),
@@ -758,6 +784,7 @@ fn test_lsm_cycle_settlement_event() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -818,6 +845,11 @@ fn test_lsm_cycle_settlement_event() {
         assert!(tx_ids.contains(&tx2_id));
         assert!(tx_ids.contains(&tx3_id));
     }
+
+    assert_eq!(
+        orchestrator.get_settlement_mechanism(&tx1_id),
+        Some(SettlementMechanism::MultilateralCycle)
+    );
 }
 
 // ============================================================================
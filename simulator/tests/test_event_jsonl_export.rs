@@ -0,0 +1,98 @@
+//! Tests for JSON Lines export of the event log
+//!
+//! `Orchestrator::export_events_jsonl` writes one JSON object per event,
+//! in log order, each tagged with its `type` and carrying its `tick`.
+
+use payment_simulator_core_rs::models::event::Event;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+
+fn create_orchestrator() -> Orchestrator {
+    let make_agent = |id: &str, opening_balance: i64| AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    let config = OrchestratorConfig {
+        ticks_per_day: 10,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![make_agent("BANK_A", 1_000_000), make_agent("BANK_B", 0)],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    };
+
+    Orchestrator::new(config).unwrap()
+}
+
+fn run_sample() -> Orchestrator {
+    let mut orch = create_orchestrator();
+    orch.submit_transaction("BANK_A", "BANK_B", 100_000, 5, 5, false)
+        .unwrap();
+    // BANK_B has no liquidity, so this one queues
+    orch.submit_transaction("BANK_B", "BANK_A", 500_000, 8, 5, false)
+        .unwrap();
+    for _ in 0..3 {
+        orch.tick().unwrap();
+    }
+    orch
+}
+
+#[test]
+fn test_export_has_one_line_per_event() {
+    let orch = run_sample();
+    let jsonl = orch.export_events_jsonl();
+
+    assert!(!orch.event_log().is_empty());
+    assert_eq!(jsonl.lines().count(), orch.event_log().len());
+    assert!(jsonl.ends_with('\n'));
+}
+
+#[test]
+fn test_export_round_trips_in_order() {
+    let orch = run_sample();
+    let jsonl = orch.export_events_jsonl();
+
+    let parsed: Vec<Event> = jsonl
+        .lines()
+        .map(|line| serde_json::from_str(line).unwrap())
+        .collect();
+
+    assert_eq!(parsed.as_slice(), orch.event_log().events());
+}
+
+#[test]
+fn test_every_line_has_type_and_tick() {
+    let orch = run_sample();
+    let jsonl = orch.export_events_jsonl();
+
+    for (line, event) in jsonl.lines().zip(orch.event_log().events()) {
+        let value: serde_json::Value = serde_json::from_str(line).unwrap();
+        assert_eq!(value["type"], event.event_type());
+        assert_eq!(value["tick"], event.tick());
+    }
+}
@@ -0,0 +1,143 @@
+//! Tests for event log filtering and pagination
+//!
+//! Range queries are inclusive of the start tick and exclusive of the end
+//! tick. `query_events` applies range and type filters before paging.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+
+fn create_config() -> OrchestratorConfig {
+    let make_agent = |id: &str| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 10_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![make_agent("BANK_A"), make_agent("BANK_B")],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+/// Runs 5 ticks, submitting one payment at the start of each
+fn run_with_one_payment_per_tick() -> Orchestrator {
+    let mut orchestrator = Orchestrator::new(create_config()).unwrap();
+    for _ in 0..5 {
+        orchestrator
+            .submit_transaction("BANK_A", "BANK_B", 10_000, 50, 5, false)
+            .unwrap();
+        orchestrator.tick().unwrap();
+    }
+    orchestrator
+}
+
+#[test]
+fn test_events_of_type_returns_only_that_type() {
+    let orchestrator = run_with_one_payment_per_tick();
+
+    let settlements = orchestrator.get_events_of_type("RtgsImmediateSettlement");
+
+    assert_eq!(settlements.len(), 5);
+    assert!(settlements
+        .iter()
+        .all(|e| e.event_type() == "RtgsImmediateSettlement"));
+    assert!(orchestrator.get_events_of_type("NoSuchEvent").is_empty());
+}
+
+#[test]
+fn test_events_in_range_includes_start_and_excludes_end() {
+    let orchestrator = run_with_one_payment_per_tick();
+
+    let events = orchestrator.get_events_in_range(1, 3);
+
+    assert!(!events.is_empty());
+    assert!(events.iter().any(|e| e.tick() == 1));
+    assert!(events.iter().any(|e| e.tick() == 2));
+    assert!(events.iter().all(|e| e.tick() == 1 || e.tick() == 2));
+
+    let expected = orchestrator.get_tick_events(1).len() + orchestrator.get_tick_events(2).len();
+    assert_eq!(events.len(), expected);
+}
+
+#[test]
+fn test_empty_and_open_ended_ranges() {
+    let orchestrator = run_with_one_payment_per_tick();
+
+    assert!(orchestrator.get_events_in_range(2, 2).is_empty());
+    assert!(orchestrator.get_events_in_range(3, 1).is_empty());
+    assert_eq!(
+        orchestrator.get_events_in_range(0, usize::MAX).len(),
+        orchestrator.event_log().len()
+    );
+    assert_eq!(
+        orchestrator.get_events_in_range(4, 100).len(),
+        orchestrator.get_tick_events(4).len()
+    );
+}
+
+#[test]
+fn test_query_events_paginates_filtered_results() {
+    let orchestrator = run_with_one_payment_per_tick();
+    let all = orchestrator.get_events_of_type("RtgsImmediateSettlement");
+
+    let first = orchestrator.query_events(0, Some(2), None, None, Some("RtgsImmediateSettlement"));
+    let second = orchestrator.query_events(2, Some(2), None, None, Some("RtgsImmediateSettlement"));
+    let rest = orchestrator.query_events(4, None, None, None, Some("RtgsImmediateSettlement"));
+
+    assert_eq!(first.len(), 2);
+    assert_eq!(second.len(), 2);
+    assert_eq!(rest.len(), 1);
+    let paged: Vec<_> = first.into_iter().chain(second).chain(rest).collect();
+    assert_eq!(paged, all);
+
+    assert!(orchestrator
+        .query_events(10, Some(2), None, None, Some("RtgsImmediateSettlement"))
+        .is_empty());
+}
+
+#[test]
+fn test_query_events_combines_range_and_type() {
+    let orchestrator = run_with_one_payment_per_tick();
+
+    let events =
+        orchestrator.query_events(0, None, Some(1), Some(4), Some("RtgsImmediateSettlement"));
+
+    let ticks: Vec<usize> = events.iter().map(|e| e.tick()).collect();
+    assert_eq!(ticks, vec![1, 2, 3]);
+}
+
+#[test]
+fn test_query_events_without_filters_returns_whole_log() {
+    let orchestrator = run_with_one_payment_per_tick();
+
+    let events = orchestrator.query_events(0, None, None, None, None);
+
+    assert_eq!(events.len(), orchestrator.event_log().len());
+}
@@ -28,6 +28,7 @@ fn create_exp3_config(seed: u64) -> OrchestratorConfig {
         deadline_range: (1, 3),
         priority_distribution: PriorityDistribution::Fixed { value: 5 },
         divisible: false,
+        ..Default::default()
     };
 
     let arrival_b = ArrivalConfig {
@@ -40,6 +41,7 @@ fn create_exp3_config(seed: u64) -> OrchestratorConfig {
         deadline_range: (1, 3),
         priority_distribution: PriorityDistribution::Fixed { value: 5 },
         divisible: false,
+        ..Default::default()
     };
 
     OrchestratorConfig {
@@ -60,6 +62,7 @@ fn create_exp3_config(seed: u64) -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -74,6 +77,7 @@ fn create_exp3_config(seed: u64) -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -87,6 +91,7 @@ fn create_exp3_config(seed: u64) -> OrchestratorConfig {
         deferred_crediting: true,
         deadline_cap_at_eod: true, daily_liquidity_reallocation: false,
         priority_escalation: Default::default(),
+        ..Default::default()
     }
 }
 
@@ -123,6 +128,7 @@ fn test_exp3_high_rate_last_tick() {
         deadline_range: (1, 3),
         priority_distribution: PriorityDistribution::Fixed { value: 5 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -143,6 +149,7 @@ fn test_exp3_high_rate_last_tick() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -157,6 +164,7 @@ fn test_exp3_high_rate_last_tick() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -170,6 +178,7 @@ fn test_exp3_high_rate_last_tick() {
         deferred_crediting: true,
         deadline_cap_at_eod: true, daily_liquidity_reallocation: false,
         priority_escalation: Default::default(),
+        ..Default::default()
     };
 
     let mut orch = Orchestrator::new(config).expect("Failed to create orchestrator");
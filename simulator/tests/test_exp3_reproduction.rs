@@ -31,6 +31,7 @@ fn test_exp3_scenario_many_seeds() {
         deadline_range,
         priority_distribution: PriorityDistribution::Fixed { value: 5 },
         divisible: false,
+        ..Default::default()
     };
 
     let mut counterparty_weights_b = HashMap::new();
@@ -46,6 +47,7 @@ fn test_exp3_scenario_many_seeds() {
         deadline_range,
         priority_distribution: PriorityDistribution::Fixed { value: 5 },
         divisible: false,
+        ..Default::default()
     };
 
     let mut configs = HashMap::new();
@@ -111,6 +113,7 @@ fn test_exp3_last_tick_edge_case() {
         deadline_range: (1, 3),
         priority_distribution: PriorityDistribution::Fixed { value: 5 },
         divisible: false,
+        ..Default::default()
     };
 
     let mut configs = HashMap::new();
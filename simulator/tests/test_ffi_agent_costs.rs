@@ -0,0 +1,176 @@
+//! FFI Integration Tests for Mid-Day Cost Accumulator Access
+//!
+//! `PyOrchestrator.agent_costs(agent_id)` must report, at any tick, exactly
+//! the per-category totals held in the native `CostAccumulator`.
+
+#[cfg(feature = "pyo3")]
+mod ffi_tests {
+    use payment_simulator_core_rs::ffi::orchestrator::PyOrchestrator;
+    use payment_simulator_core_rs::orchestrator::{
+        AgentConfig, CostAccumulator, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig,
+    };
+    use pyo3::prelude::*;
+    use pyo3::types::{PyDict, PyList};
+
+    const TICKS_PER_DAY: usize = 10;
+    const OVERDRAFT_BPS: f64 = 10.0;
+
+    /// (id, opening_balance, unsecured_cap, LiquidityAware target_buffer)
+    const AGENTS: [(&str, i64, i64, Option<i64>); 2] = [
+        ("BANK_A", 300_000, 100_000, None),
+        ("BANK_B", 100_000, 0, Some(500_000)),
+    ];
+
+    /// (sender, receiver, amount, deadline) submitted at tick 0: BANK_A
+    /// overdraws and misses a deadline, BANK_B holds its payment in Queue 1
+    const PAYMENTS: [(&str, &str, i64, usize); 3] = [
+        ("BANK_A", "BANK_B", 350_000, 8),
+        ("BANK_B", "BANK_A", 50_000, 3),
+        ("BANK_A", "BANK_B", 200_000, 3),
+    ];
+
+    fn py_config(py: Python<'_>) -> Bound<'_, PyDict> {
+        let config = PyDict::new(py);
+        config.set_item("ticks_per_day", TICKS_PER_DAY).unwrap();
+        config.set_item("num_days", 1).unwrap();
+        config.set_item("rng_seed", 7u64).unwrap();
+
+        let agents = PyList::empty(py);
+        for (id, balance, cap, buffer) in AGENTS {
+            let agent = PyDict::new(py);
+            agent.set_item("id", id).unwrap();
+            agent.set_item("opening_balance", balance).unwrap();
+            agent.set_item("unsecured_cap", cap).unwrap();
+            let policy = PyDict::new(py);
+            match buffer {
+                Some(target_buffer) => {
+                    policy.set_item("type", "LiquidityAware").unwrap();
+                    policy.set_item("target_buffer", target_buffer).unwrap();
+                    policy.set_item("urgency_threshold", 1usize).unwrap();
+                }
+                None => policy.set_item("type", "Fifo").unwrap(),
+            }
+            agent.set_item("policy", policy).unwrap();
+            agents.append(agent).unwrap();
+        }
+        config.set_item("agent_configs", agents).unwrap();
+
+        let costs = PyDict::new(py);
+        costs
+            .set_item("overdraft_bps_per_tick", OVERDRAFT_BPS)
+            .unwrap();
+        config.set_item("cost_rates", costs).unwrap();
+        config
+    }
+
+    fn native_config() -> OrchestratorConfig {
+        let mut builder = OrchestratorConfig::builder()
+            .ticks_per_day(TICKS_PER_DAY)
+            .num_days(1)
+            .rng_seed(7)
+            .cost_rates(CostRates {
+                overdraft_bps_per_tick: OVERDRAFT_BPS,
+                ..CostRates::default()
+            });
+        for (id, opening_balance, unsecured_cap, buffer) in AGENTS {
+            builder = builder.agent(AgentConfig {
+                id: id.to_string(),
+                opening_balance,
+                unsecured_cap,
+                policy: match buffer {
+                    Some(target_buffer) => PolicyConfig::LiquidityAware {
+                        target_buffer,
+                        urgency_threshold: 1,
+                    },
+                    None => PolicyConfig::Fifo,
+                },
+                arrival_config: None,
+                arrival_bands: None,
+                posted_collateral: None,
+                collateral_haircut: None,
+                max_collateral_capacity: None,
+                limits: None,
+                liquidity_pool: None,
+                liquidity_allocation_fraction: None,
+                ..Default::default()
+            });
+        }
+        builder.build().unwrap()
+    }
+
+    fn assert_matches(dict: &Bound<'_, PyDict>, costs: &CostAccumulator) {
+        let get = |key: &str| {
+            dict.get_item(key)
+                .unwrap()
+                .unwrap()
+                .extract::<i64>()
+                .unwrap()
+        };
+        assert_eq!(dict.len(), 8);
+        assert_eq!(get("liquidity"), costs.total_liquidity_cost);
+        assert_eq!(get("delay"), costs.total_delay_cost);
+        assert_eq!(get("collateral"), costs.total_collateral_cost);
+        assert_eq!(get("penalty"), costs.total_penalty_cost);
+        assert_eq!(get("split_friction"), costs.total_split_friction_cost);
+        assert_eq!(
+            get("liquidity_opportunity"),
+            costs.total_liquidity_opportunity_cost
+        );
+        assert_eq!(get("overnight_interest"), costs.total_overnight_interest);
+        assert_eq!(get("collateral_fee"), costs.total_collateral_fee_cost);
+    }
+
+    #[test]
+    fn test_agent_costs_match_native_accumulator_mid_day() {
+        Python::with_gil(|py| {
+            let orch_class = py.get_type::<PyOrchestrator>();
+            let py_orch = orch_class.call_method1("new", (py_config(py),)).unwrap();
+            let mut native = Orchestrator::new(native_config()).unwrap();
+
+            for (sender, receiver, amount, deadline) in PAYMENTS {
+                py_orch
+                    .call_method1(
+                        "submit_transaction",
+                        (sender, receiver, amount, deadline, 5u8, false),
+                    )
+                    .unwrap();
+                native
+                    .submit_transaction(sender, receiver, amount, deadline, 5, false)
+                    .unwrap();
+            }
+
+            // Compare after every tick, well before the first end of day
+            for _ in 0..6 {
+                py_orch.call_method0("tick").unwrap();
+                native.tick().unwrap();
+
+                for (id, _, _, _) in AGENTS {
+                    let dict: Bound<'_, PyDict> = py_orch
+                        .call_method1("agent_costs", (id,))
+                        .unwrap()
+                        .downcast_into()
+                        .unwrap();
+                    assert_matches(&dict, native.get_costs(id).unwrap());
+                }
+            }
+
+            // The scenario exercises several categories, not just zeros
+            let a = native.get_costs("BANK_A").unwrap();
+            let b = native.get_costs("BANK_B").unwrap();
+            assert!(a.total_liquidity_cost > 0);
+            assert!(a.total_penalty_cost > 0);
+            assert!(a.total_delay_cost + b.total_delay_cost > 0);
+        });
+    }
+
+    #[test]
+    fn test_agent_costs_unknown_agent_raises_key_error() {
+        Python::with_gil(|py| {
+            let orch_class = py.get_type::<PyOrchestrator>();
+            let py_orch = orch_class.call_method1("new", (py_config(py),)).unwrap();
+
+            let err = py_orch.call_method1("agent_costs", ("NOPE",)).unwrap_err();
+            assert!(err.is_instance_of::<pyo3::exceptions::PyKeyError>(py));
+        });
+    }
+}
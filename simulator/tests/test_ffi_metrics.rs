@@ -0,0 +1,167 @@
+//! FFI Integration Tests for Metrics Accessors
+//!
+//! `PyOrchestrator.get_system_metrics()` and `get_daily_agent_metrics(day)`
+//! must report exactly what the native `calculate_system_metrics()` and
+//! `get_daily_agent_metrics()` return for the same run, and
+//! `system_metrics()` / `daily_agent_metrics(day)` must match them.
+
+#[cfg(feature = "pyo3")]
+mod ffi_tests {
+    use payment_simulator_core_rs::ffi::orchestrator::PyOrchestrator;
+    use payment_simulator_core_rs::orchestrator::{
+        AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+    };
+    use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+    use pyo3::prelude::*;
+    use pyo3::types::{PyDict, PyList};
+
+    const TICKS_PER_DAY: usize = 10;
+
+    fn py_config(py: Python<'_>) -> Bound<'_, PyDict> {
+        let config = PyDict::new(py);
+        config.set_item("ticks_per_day", TICKS_PER_DAY).unwrap();
+        config.set_item("eod_rush_threshold", 0.8).unwrap();
+        config.set_item("num_days", 2).unwrap();
+        config.set_item("rng_seed", 12345u64).unwrap();
+
+        let agents = PyList::empty(py);
+        for (id, balance) in [("BANK_A", 300_000i64), ("BANK_B", 1_000_000i64)] {
+            let agent = PyDict::new(py);
+            agent.set_item("id", id).unwrap();
+            agent.set_item("opening_balance", balance).unwrap();
+            let policy = PyDict::new(py);
+            policy.set_item("type", "Fifo").unwrap();
+            agent.set_item("policy", policy).unwrap();
+            agents.append(agent).unwrap();
+        }
+        config.set_item("agent_configs", agents).unwrap();
+        config
+    }
+
+    fn native_config() -> OrchestratorConfig {
+        let make_agent = |id: &str, opening_balance: i64| AgentConfig {
+            id: id.to_string(),
+            opening_balance,
+            unsecured_cap: 0,
+            policy: PolicyConfig::Fifo,
+            arrival_config: None,
+            arrival_bands: None,
+            posted_collateral: None,
+            collateral_haircut: None,
+            max_collateral_capacity: None,
+            limits: None,
+            liquidity_pool: None,
+            liquidity_allocation_fraction: None,
+            ..Default::default()
+        };
+
+        OrchestratorConfig {
+            ticks_per_day: TICKS_PER_DAY,
+            eod_rush_threshold: 0.8,
+            num_days: 2,
+            rng_seed: 12345,
+            agent_configs: vec![make_agent("BANK_A", 300_000), make_agent("BANK_B", 1_000_000)],
+            cost_rates: CostRates::default(),
+            lsm_config: LsmConfig::default(),
+            scenario_events: None,
+            queue1_ordering: Queue1Ordering::default(),
+            priority_mode: false,
+            priority_escalation: Default::default(),
+            algorithm_sequencing: false,
+            entry_disposition_offsetting: false,
+            deferred_crediting: false,
+            deadline_cap_at_eod: false,
+            daily_liquidity_reallocation: false,
+            ..Default::default()
+        }
+    }
+
+    /// (sender, receiver, amount, deadline) submitted at tick 0; the last one
+    /// exceeds BANK_A's remaining liquidity and stays queued
+    const PAYMENTS: [(&str, &str, i64, usize); 3] = [
+        ("BANK_A", "BANK_B", 200_000, 5),
+        ("BANK_B", "BANK_A", 50_000, 5),
+        ("BANK_A", "BANK_B", 400_000, 15),
+    ];
+
+    #[test]
+    fn test_ffi_metrics_match_native_accessors() {
+        Python::with_gil(|py| {
+            let orch_class = py.get_type::<PyOrchestrator>();
+            let py_orch = orch_class.call_method1("new", (py_config(py),)).unwrap();
+            let mut native = Orchestrator::new(native_config()).unwrap();
+
+            for (sender, receiver, amount, deadline) in PAYMENTS {
+                py_orch
+                    .call_method1("submit_transaction", (sender, receiver, amount, deadline, 5u8, false))
+                    .unwrap();
+                native
+                    .submit_transaction(sender, receiver, amount, deadline, 5, false)
+                    .unwrap();
+            }
+            for _ in 0..(TICKS_PER_DAY + 3) {
+                py_orch.call_method0("tick").unwrap();
+                native.tick().unwrap();
+            }
+
+            // System metrics: integers as-is, rates as f64
+            let expected = native.calculate_system_metrics();
+            let dict: Bound<'_, PyDict> =
+                py_orch.call_method0("get_system_metrics").unwrap().downcast_into().unwrap();
+            let get = |key: &str| dict.get_item(key).unwrap().unwrap();
+            assert_eq!(get("total_arrivals").extract::<usize>().unwrap(), expected.total_arrivals);
+            assert_eq!(get("total_settlements").extract::<usize>().unwrap(), expected.total_settlements);
+            assert_eq!(get("settlement_rate").extract::<f64>().unwrap(), expected.settlement_rate);
+            assert_eq!(get("avg_delay_ticks").extract::<f64>().unwrap(), expected.avg_delay_ticks);
+            assert_eq!(get("max_delay_ticks").extract::<usize>().unwrap(), expected.max_delay_ticks);
+            assert_eq!(get("queue1_total_size").extract::<usize>().unwrap(), expected.queue1_total_size);
+            assert_eq!(get("queue2_total_size").extract::<usize>().unwrap(), expected.queue2_total_size);
+            assert_eq!(get("max_queue1_age").extract::<usize>().unwrap(), expected.max_queue1_age);
+            assert_eq!(get("peak_overdraft").extract::<i64>().unwrap(), expected.peak_overdraft);
+            assert_eq!(
+                get("agents_in_overdraft").extract::<usize>().unwrap(),
+                expected.agents_in_overdraft
+            );
+            assert_eq!(
+                get("liquidity_usage_gini").extract::<f64>().unwrap(),
+                expected.liquidity_usage_gini
+            );
+            assert_eq!(expected.queue2_total_size, 1, "large payment should still be queued");
+
+            // Daily metrics for the completed day
+            let expected_daily = native.get_daily_agent_metrics(0);
+            let list: Bound<'_, PyList> = py_orch
+                .call_method1("get_daily_agent_metrics", (0usize,))
+                .unwrap()
+                .downcast_into()
+                .unwrap();
+            assert_eq!(list.len(), expected_daily.len());
+            assert_eq!(list.len(), 2);
+
+            for (item, m) in list.iter().zip(expected_daily) {
+                let d: Bound<'_, PyDict> = item.downcast_into().unwrap();
+                let get = |key: &str| d.get_item(key).unwrap().unwrap();
+                assert_eq!(get("agent_id").extract::<String>().unwrap(), m.agent_id);
+                assert_eq!(get("day").extract::<usize>().unwrap(), m.day);
+                assert_eq!(get("opening_balance").extract::<i64>().unwrap(), m.opening_balance);
+                assert_eq!(get("closing_balance").extract::<i64>().unwrap(), m.closing_balance);
+                assert_eq!(get("min_balance").extract::<i64>().unwrap(), m.min_balance);
+                assert_eq!(get("max_balance").extract::<i64>().unwrap(), m.max_balance);
+                assert_eq!(get("num_sent").extract::<usize>().unwrap(), m.num_sent);
+                assert_eq!(get("num_received").extract::<usize>().unwrap(), m.num_received);
+                assert_eq!(get("num_settled").extract::<usize>().unwrap(), m.num_settled);
+                assert_eq!(get("queue1_eod_size").extract::<usize>().unwrap(), m.queue1_eod_size);
+                assert_eq!(get("delay_cost").extract::<i64>().unwrap(), m.delay_cost);
+                assert_eq!(get("total_cost").extract::<i64>().unwrap(), m.total_cost);
+            }
+
+            // system_metrics() / daily_agent_metrics(day) report the same dicts
+            let by_new_name = py_orch.call_method0("system_metrics").unwrap();
+            let by_old_name = py_orch.call_method0("get_system_metrics").unwrap();
+            assert!(by_new_name.eq(by_old_name).unwrap());
+            let daily_new = py_orch.call_method1("daily_agent_metrics", (0usize,)).unwrap();
+            let daily_old = py_orch.call_method1("get_daily_agent_metrics", (0usize,)).unwrap();
+            assert!(daily_new.eq(daily_old).unwrap());
+        });
+    }
+}
@@ -0,0 +1,157 @@
+//! Tests for the `recent_inflow_rate` / `recent_outflow_rate` policy context fields
+//!
+//! Both fields average the value settled per tick over the trailing
+//! `flow_window_ticks` completed ticks.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::policy::tree::EvalContext;
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use payment_simulator_core_rs::{SimulationError, Transaction};
+
+fn create_config(flow_window_ticks: usize) -> OrchestratorConfig {
+    let make_agent = |id: &str, opening_balance: i64| AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![make_agent("BANK_A", 1_000_000), make_agent("BANK_B", 0)],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig {
+            enable_bilateral: false,
+            enable_cycles: false,
+            ..LsmConfig::default()
+        },
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        flow_window_ticks,
+        ..Default::default()
+    }
+}
+
+/// Value of a context field as seen by a policy of `agent_id`
+fn context_field(orch: &Orchestrator, agent_id: &str, field: &str) -> f64 {
+    let state = orch.state();
+    let agent = state.get_agent(agent_id).unwrap();
+    let tx = Transaction::new(agent_id.to_string(), "BANK_A".to_string(), 1, 0, 50);
+    let context = EvalContext::build(
+        &tx,
+        agent,
+        state,
+        orch.current_tick(),
+        orch.cost_rates(),
+        100,
+        0.8,
+    );
+    context.get_field(field).unwrap()
+}
+
+#[test]
+fn test_inflow_burst_raises_rate_then_decays() {
+    let mut orch = Orchestrator::new(create_config(4)).unwrap();
+    assert_eq!(context_field(&orch, "BANK_B", "recent_inflow_rate"), 0.0);
+
+    // Burst: 400k settles to BANK_B in a single tick
+    orch.submit_transaction("BANK_A", "BANK_B", 400_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+
+    assert_eq!(
+        context_field(&orch, "BANK_B", "recent_inflow_rate"),
+        100_000.0
+    );
+    assert_eq!(context_field(&orch, "BANK_B", "recent_outflow_rate"), 0.0);
+    assert_eq!(
+        context_field(&orch, "BANK_A", "recent_outflow_rate"),
+        100_000.0
+    );
+    assert_eq!(context_field(&orch, "BANK_A", "recent_inflow_rate"), 0.0);
+
+    // The burst stays in the window for 4 ticks, then drops out
+    for _ in 0..3 {
+        orch.tick().unwrap();
+        assert_eq!(
+            context_field(&orch, "BANK_B", "recent_inflow_rate"),
+            100_000.0
+        );
+    }
+    orch.tick().unwrap();
+    assert_eq!(context_field(&orch, "BANK_B", "recent_inflow_rate"), 0.0);
+    assert_eq!(context_field(&orch, "BANK_A", "recent_outflow_rate"), 0.0);
+}
+
+#[test]
+fn test_rates_accumulate_across_ticks_in_window() {
+    let mut orch = Orchestrator::new(create_config(4)).unwrap();
+
+    orch.submit_transaction("BANK_A", "BANK_B", 100_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 300_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(
+        context_field(&orch, "BANK_B", "recent_inflow_rate"),
+        100_000.0
+    );
+
+    // BANK_B pays some of it back
+    orch.submit_transaction("BANK_B", "BANK_A", 200_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(
+        context_field(&orch, "BANK_B", "recent_outflow_rate"),
+        50_000.0
+    );
+    assert_eq!(
+        context_field(&orch, "BANK_A", "recent_inflow_rate"),
+        50_000.0
+    );
+}
+
+#[test]
+fn test_flow_window_survives_checkpoint() {
+    let config = create_config(4);
+    let mut orch = Orchestrator::new(config.clone()).unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 400_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+
+    let state_json = orch.save_state().unwrap();
+    let restored = Orchestrator::load_state(config, &state_json).unwrap();
+
+    assert_eq!(
+        context_field(&restored, "BANK_B", "recent_inflow_rate"),
+        100_000.0
+    );
+}
+
+#[test]
+fn test_zero_window_rejected() {
+    let result = Orchestrator::new(create_config(0));
+    assert!(matches!(result, Err(SimulationError::InvalidConfig(_))));
+}
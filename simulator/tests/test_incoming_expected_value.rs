@@ -0,0 +1,114 @@
+//! Tests for the `incoming_expected_value` policy context field
+//!
+//! The field totals the Queue 2 payments where the agent is the receiver,
+//! i.e. inflows already submitted by counterparties but not yet settled.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::policy::tree::EvalContext;
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use payment_simulator_core_rs::Transaction;
+
+fn create_config() -> OrchestratorConfig {
+    let make_agent = |id: &str, opening_balance: i64| AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![
+            make_agent("BANK_A", 1_000_000),
+            make_agent("BANK_B", 0),
+            make_agent("BANK_C", 0),
+        ],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig {
+            enable_bilateral: false,
+            enable_cycles: false,
+            ..LsmConfig::default()
+        },
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+/// `incoming_expected_value` as seen by a policy of `agent_id`
+fn incoming_expected_value(orch: &Orchestrator, agent_id: &str) -> f64 {
+    let state = orch.state();
+    let agent = state.get_agent(agent_id).unwrap();
+    let tx = Transaction::new(agent_id.to_string(), "BANK_C".to_string(), 1, 0, 50);
+    let context = EvalContext::build(
+        &tx,
+        agent,
+        state,
+        orch.current_tick(),
+        orch.cost_rates(),
+        100,
+        0.8,
+    );
+    context.get_field("incoming_expected_value").unwrap()
+}
+
+#[test]
+fn test_reflects_queued_inbound_value() {
+    let mut orch = Orchestrator::new(create_config()).unwrap();
+
+    // BANK_B and BANK_C have no liquidity, so both payments queue in Queue 2
+    orch.submit_transaction("BANK_B", "BANK_A", 300_000, 50, 5, false)
+        .unwrap();
+    orch.submit_transaction("BANK_C", "BANK_A", 200_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+
+    assert_eq!(orch.state().incoming_queued_for("BANK_A").len(), 2);
+    assert_eq!(incoming_expected_value(&orch, "BANK_A"), 500_000.0);
+    assert_eq!(incoming_expected_value(&orch, "BANK_B"), 0.0);
+}
+
+#[test]
+fn test_updates_as_inbound_payments_settle() {
+    let mut orch = Orchestrator::new(create_config()).unwrap();
+
+    orch.submit_transaction("BANK_B", "BANK_A", 300_000, 50, 5, false)
+        .unwrap();
+    orch.submit_transaction("BANK_C", "BANK_A", 200_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(incoming_expected_value(&orch, "BANK_A"), 500_000.0);
+
+    // Funding BANK_B releases its queued payment; BANK_C's stays queued
+    orch.submit_transaction("BANK_A", "BANK_B", 300_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(incoming_expected_value(&orch, "BANK_A"), 200_000.0);
+
+    orch.submit_transaction("BANK_A", "BANK_C", 200_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(incoming_expected_value(&orch, "BANK_A"), 0.0);
+    assert!(orch.state().incoming_queued_for("BANK_A").is_empty());
+}
@@ -0,0 +1,113 @@
+//! Tests for intraday (diurnal) arrival rate profiles
+//!
+//! `ArrivalConfig.intraday_profile` scales `rate_per_tick` by a per-tick
+//! multiplier, repeating every day.
+
+use payment_simulator_core_rs::arrivals::{AmountDistribution, ArrivalConfig, PriorityDistribution};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use std::collections::HashMap;
+
+fn create_config(ticks_per_day: usize, profile: Option<Vec<f64>>) -> OrchestratorConfig {
+    let make_agent = |id: &str, counterparty: &str| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 10_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: Some(ArrivalConfig {
+            rate_per_tick: 1.0,
+            amount_distribution: AmountDistribution::Uniform {
+                min: 1_000,
+                max: 10_000,
+            },
+            counterparty_weights: {
+                let mut weights = HashMap::new();
+                weights.insert(counterparty.to_string(), 1.0);
+                weights
+            },
+            deadline_range: (5, 10),
+            priority_distribution: PriorityDistribution::Fixed { value: 5 },
+            divisible: false,
+            intraday_profile: profile.clone(),
+            ..Default::default()
+        }),
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day,
+        eod_rush_threshold: 0.8,
+        num_days: 5,
+        rng_seed: 4242,
+        agent_configs: vec![make_agent("BANK_A", "BANK_B"), make_agent("BANK_B", "BANK_A")],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_profile_length_must_match_ticks_per_day() {
+    let result = Orchestrator::new(create_config(10, Some(vec![1.0; 9])));
+    let err_msg = result.unwrap_err().to_string();
+    assert!(err_msg.contains("intraday_profile"), "unexpected error: {}", err_msg);
+
+    assert!(Orchestrator::new(create_config(10, Some(vec![1.0; 10]))).is_ok());
+}
+
+#[test]
+fn test_profile_rejects_negative_multiplier() {
+    let mut profile = vec![1.0; 10];
+    profile[3] = -0.5;
+    assert!(Orchestrator::new(create_config(10, Some(profile))).is_err());
+}
+
+#[test]
+fn test_flat_profile_reproduces_constant_rate() {
+    let mut flat = Orchestrator::new(create_config(10, None)).unwrap();
+    let mut profiled = Orchestrator::new(create_config(10, Some(vec![1.0; 10]))).unwrap();
+
+    for _ in 0..50 {
+        let a = flat.tick().unwrap();
+        let b = profiled.tick().unwrap();
+        assert_eq!(a.num_arrivals, b.num_arrivals);
+        assert_eq!(a.num_settlements, b.num_settlements);
+    }
+}
+
+#[test]
+fn test_peaked_profile_concentrates_arrivals() {
+    // Morning peak (ticks 0-2) and nothing else
+    let profile = vec![3.0, 3.0, 3.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0, 0.0];
+    let mut orch = Orchestrator::new(create_config(10, Some(profile))).unwrap();
+
+    let mut peak_arrivals = 0;
+    for _ in 0..50 {
+        let result = orch.tick().unwrap();
+        if result.tick % 10 < 3 {
+            peak_arrivals += result.num_arrivals;
+        } else {
+            assert_eq!(result.num_arrivals, 0, "tick {} should be quiet", result.tick);
+        }
+    }
+
+    assert!(peak_arrivals > 0);
+}
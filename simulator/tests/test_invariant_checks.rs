@@ -0,0 +1,161 @@
+//! Tests for `SimulationState::validate_invariants` and `debug_invariant_checks`
+//!
+//! A normally running simulation always satisfies the invariants; state
+//! corrupted through `state_mut()` is reported, and with
+//! `debug_invariant_checks` enabled the next tick fails.
+
+use payment_simulator_core_rs::models::Transaction;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::SimulationError;
+
+fn make_agent(id: &str, opening_balance: i64, policy: PolicyConfig) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        policy,
+        ..Default::default()
+    }
+}
+
+fn create_orchestrator(debug_invariant_checks: bool) -> Orchestrator {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent(
+            "BANK_A",
+            150_000,
+            PolicyConfig::MockSplitting { num_splits: 3 },
+        ))
+        .agent(make_agent("BANK_B", 0, PolicyConfig::Fifo))
+        .debug_invariant_checks(debug_invariant_checks)
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+#[test]
+fn test_running_simulation_satisfies_invariants() {
+    let mut orch = create_orchestrator(true);
+
+    // Splits, settlements and Queue 2 backlog (BANK_A cannot cover all three)
+    for amount in [100_003, 80_000, 60_000] {
+        orch.submit_transaction("BANK_A", "BANK_B", amount, 50, 5, true)
+            .unwrap();
+    }
+    for _ in 0..5 {
+        orch.tick().unwrap();
+    }
+
+    assert!(orch.state().queue_size() > 0, "expected a Queue 2 backlog");
+    assert_eq!(orch.state().validate_invariants(), Ok(()));
+}
+
+#[test]
+fn test_orphaned_queue_entry_detected() {
+    let mut orch = create_orchestrator(false);
+    orch.state_mut()
+        .rtgs_queue_mut()
+        .push("no_such_tx".to_string());
+
+    let err = orch.state().validate_invariants().unwrap_err();
+    assert!(err.contains("Orphaned"), "unexpected error: {}", err);
+    assert!(err.contains("no_such_tx"));
+}
+
+#[test]
+fn test_duplicate_queue_entry_detected() {
+    let mut orch = create_orchestrator(false);
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 10_000, 50, 5, false)
+        .unwrap();
+    assert_eq!(orch.state().validate_invariants(), Ok(()));
+
+    // Same transaction in Queue 1 and Queue 2
+    orch.state_mut().rtgs_queue_mut().push(tx_id.clone());
+
+    let err = orch.state().validate_invariants().unwrap_err();
+    assert!(err.contains("Duplicate"), "unexpected error: {}", err);
+    assert!(err.contains(&tx_id));
+}
+
+#[test]
+fn test_split_children_mismatch_fails_tick_when_checks_enabled() {
+    let mut orch = create_orchestrator(true);
+    let parent = Transaction::new("BANK_B".to_string(), "BANK_A".to_string(), 50_000, 0, 50);
+    let child = Transaction::new_split(
+        "BANK_B".to_string(),
+        "BANK_A".to_string(),
+        20_000,
+        0,
+        50,
+        parent.id().to_string(),
+    );
+    orch.state_mut().add_transaction(parent);
+    orch.state_mut().add_transaction(child);
+
+    let err = orch.state().validate_invariants().unwrap_err();
+    assert!(err.contains("have 20000 remaining"), "unexpected error: {}", err);
+
+    match orch.tick() {
+        Err(SimulationError::StateValidationError(msg)) => {
+            assert!(msg.contains("after tick 0"), "unexpected error: {}", msg)
+        }
+        other => panic!("expected StateValidationError, got {:?}", other),
+    }
+}
+
+#[test]
+fn test_corruption_ignored_by_tick_when_checks_disabled() {
+    let mut orch = create_orchestrator(false);
+    let parent = Transaction::new("BANK_B".to_string(), "BANK_A".to_string(), 50_000, 0, 50);
+    let child = Transaction::new_split(
+        "BANK_B".to_string(),
+        "BANK_A".to_string(),
+        20_000,
+        0,
+        50,
+        parent.id().to_string(),
+    );
+    orch.state_mut().add_transaction(parent);
+    orch.state_mut().add_transaction(child);
+
+    assert!(orch.tick().is_ok());
+}
+
+#[test]
+fn test_lsm_settled_split_children_satisfy_invariants() {
+    // Neither bank can pay on its own; only bilateral offsetting settles
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent(
+            "BANK_A",
+            0,
+            PolicyConfig::MockSplitting { num_splits: 3 },
+        ))
+        .agent(make_agent("BANK_B", 0, PolicyConfig::Fifo))
+        .debug_invariant_checks(true)
+        .build()
+        .unwrap();
+    let mut orch = Orchestrator::new(config).unwrap();
+
+    let parent_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 90_000, 50, 5, true)
+        .unwrap();
+    orch.submit_transaction("BANK_B", "BANK_A", 90_000, 50, 5, false)
+        .unwrap();
+    for _ in 0..3 {
+        orch.tick().unwrap();
+    }
+
+    // Children settled by LSM reduce the parent in step
+    let parent = orch.get_transaction(&parent_id).unwrap();
+    assert_eq!(parent.remaining_amount(), 0);
+    assert!(parent.is_fully_settled());
+    assert_eq!(orch.state().queue_size(), 0);
+    assert_eq!(orch.state().validate_invariants(), Ok(()));
+}
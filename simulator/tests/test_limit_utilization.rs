@@ -0,0 +1,96 @@
+//! Tests for bilateral/multilateral limit utilization reporting
+//!
+//! Utilization is today's outflow divided by the configured limit and
+//! resets with the daily outflow tracking.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, AgentLimitsConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use std::collections::HashMap;
+
+fn make_agent(id: &str, limits: Option<AgentLimitsConfig>) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+fn create_orchestrator() -> Orchestrator {
+    let limits = AgentLimitsConfig {
+        bilateral_limits: HashMap::from([("BANK_B".to_string(), 100_000)]),
+        multilateral_limit: Some(150_000),
+    };
+
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(10)
+        .num_days(2)
+        .rng_seed(42)
+        .agent(make_agent("BANK_A", Some(limits)))
+        .agent(make_agent("BANK_B", None))
+        .agent(make_agent("BANK_C", None))
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+#[test]
+fn test_half_bilateral_limit_reports_half_utilization() {
+    let mut orch = create_orchestrator();
+    orch.submit_transaction("BANK_A", "BANK_B", 50_000, 5, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+
+    let utilization = orch.get_agent_limit_utilization("BANK_A").unwrap();
+    assert_eq!(utilization.bilateral.len(), 1);
+    assert_eq!(utilization.bilateral["BANK_B"], 0.5);
+    assert_eq!(utilization.multilateral, Some(50_000.0 / 150_000.0));
+}
+
+#[test]
+fn test_fully_consumed_multilateral_limit_reports_one() {
+    let mut orch = create_orchestrator();
+    orch.submit_transaction("BANK_A", "BANK_B", 50_000, 5, 5, false)
+        .unwrap();
+    orch.submit_transaction("BANK_A", "BANK_C", 100_000, 5, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+
+    let utilization = orch.get_agent_limit_utilization("BANK_A").unwrap();
+    assert_eq!(utilization.multilateral, Some(1.0));
+    assert_eq!(utilization.bilateral["BANK_B"], 0.5);
+}
+
+#[test]
+fn test_utilization_resets_at_end_of_day() {
+    let mut orch = create_orchestrator();
+    orch.submit_transaction("BANK_A", "BANK_B", 50_000, 5, 5, false)
+        .unwrap();
+    for _ in 0..10 {
+        orch.tick().unwrap();
+    }
+
+    let utilization = orch.get_agent_limit_utilization("BANK_A").unwrap();
+    assert_eq!(utilization.bilateral["BANK_B"], 0.0);
+    assert_eq!(utilization.multilateral, Some(0.0));
+}
+
+#[test]
+fn test_agents_without_limits_report_nothing() {
+    let orch = create_orchestrator();
+
+    let utilization = orch.get_agent_limit_utilization("BANK_B").unwrap();
+    assert!(utilization.bilateral.is_empty());
+    assert_eq!(utilization.multilateral, None);
+    assert!(orch.get_agent_limit_utilization("UNKNOWN").is_none());
+}
@@ -0,0 +1,116 @@
+//! Tests for the `liquidity_pressure` policy context field
+//!
+//! Pressure is the share of the allowed overdraft limit not covered by
+//! available liquidity, clamped to [0, 1].
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+
+/// Holds payments while liquidity pressure exceeds `max_pressure`
+fn pressure_policy(max_pressure: f64) -> String {
+    format!(
+        r#"{{
+        "version": "1.0",
+        "policy_id": "pressure_gate",
+        "parameters": {{"max_pressure": {}}},
+        "payment_tree": {{
+            "type": "condition",
+            "node_id": "N1",
+            "condition": {{
+                "op": ">",
+                "left": {{"field": "liquidity_pressure"}},
+                "right": {{"param": "max_pressure"}}
+            }},
+            "on_true": {{"type": "action", "node_id": "A1", "action": "Hold"}},
+            "on_false": {{"type": "action", "node_id": "A2", "action": "Release"}}
+        }},
+        "strategic_collateral_tree": null,
+        "end_of_tick_collateral_tree": null
+    }}"#,
+        max_pressure
+    )
+}
+
+fn make_agent(
+    id: &str,
+    opening_balance: i64,
+    unsecured_cap: i64,
+    policy: PolicyConfig,
+) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap,
+        policy,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+/// BANK_A starts flat with a 100k overdraft limit and gates on pressure 0.5
+fn create_orchestrator() -> Orchestrator {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent(
+            "BANK_A",
+            0,
+            100_000,
+            PolicyConfig::FromJson {
+                json: pressure_policy(0.5),
+            },
+        ))
+        .agent(make_agent("BANK_B", 1_000_000, 0, PolicyConfig::Fifo))
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+fn is_settled(orch: &Orchestrator, tx_id: &str) -> bool {
+    orch.get_transaction(tx_id).unwrap().is_fully_settled()
+}
+
+#[test]
+fn test_policy_holds_under_pressure_and_releases_when_it_eases() {
+    let mut orch = create_orchestrator();
+
+    // No overdraft in use yet: released, drawing 80% of the limit
+    let first = orch
+        .submit_transaction("BANK_A", "BANK_B", 80_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert!(is_settled(&orch, &first));
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(-80_000));
+
+    // Pressure 0.8 > 0.5: held even though the overdraft could cover it
+    let second = orch
+        .submit_transaction("BANK_A", "BANK_B", 10_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    orch.tick().unwrap();
+    assert!(!is_settled(&orch, &second));
+    assert_eq!(
+        orch.get_agent_queue1_contents("BANK_A"),
+        vec![second.clone()]
+    );
+
+    // An incoming payment brings the drawn overdraft down to 20k
+    orch.submit_transaction("BANK_B", "BANK_A", 60_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(-20_000));
+
+    // Pressure 0.2 <= 0.5: the held payment is released
+    orch.tick().unwrap();
+    assert!(is_settled(&orch, &second));
+    assert!(orch.get_agent_queue1_contents("BANK_A").is_empty());
+}
@@ -0,0 +1,88 @@
+//! Tests for the liquidity usage Gini coefficient in SystemMetrics
+//!
+//! Gini is computed over each agent's current credit usage: 0.0 when usage
+//! is equal (or zero everywhere), (n-1)/n when a single agent uses it all.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+
+/// Agents start with the given balances; negative balances are credit usage
+fn create_orchestrator(opening_balances: &[i64]) -> Orchestrator {
+    let agent_configs = opening_balances
+        .iter()
+        .enumerate()
+        .map(|(i, &opening_balance)| AgentConfig {
+            id: format!("BANK_{}", i),
+            opening_balance,
+            unsecured_cap: 1_000_000,
+            policy: PolicyConfig::Fifo,
+            arrival_config: None,
+            arrival_bands: None,
+            posted_collateral: None,
+            collateral_haircut: None,
+            max_collateral_capacity: None,
+            limits: None,
+            liquidity_pool: None,
+            liquidity_allocation_fraction: None,
+            ..Default::default()
+        })
+        .collect();
+
+    Orchestrator::new(OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs,
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    })
+    .unwrap()
+}
+
+#[test]
+fn test_equal_usage_has_zero_gini() {
+    let orchestrator = create_orchestrator(&[-200_000, -200_000, -200_000, -200_000]);
+
+    let gini = orchestrator.calculate_system_metrics().liquidity_usage_gini;
+    assert!(gini.abs() < 1e-12, "expected ~0.0, got {}", gini);
+}
+
+#[test]
+fn test_one_hot_usage_reaches_theoretical_max() {
+    let orchestrator = create_orchestrator(&[-500_000, 0, 300_000, 0]);
+
+    // Maximum Gini for n agents is (n - 1) / n
+    let gini = orchestrator.calculate_system_metrics().liquidity_usage_gini;
+    assert!((gini - 0.75).abs() < 1e-12, "expected 0.75, got {}", gini);
+}
+
+#[test]
+fn test_partial_concentration_between_bounds() {
+    let orchestrator = create_orchestrator(&[-100_000, -300_000, 0]);
+
+    // Sorted usage [0, 100k, 300k]: 2·(0 + 200k + 900k)/(3·400k) − 4/3 = 0.5
+    let gini = orchestrator.calculate_system_metrics().liquidity_usage_gini;
+    assert!((gini - 0.5).abs() < 1e-12, "expected 0.5, got {}", gini);
+}
+
+#[test]
+fn test_no_usage_and_single_agent_have_zero_gini() {
+    let no_usage = create_orchestrator(&[100_000, 200_000, 0]);
+    assert_eq!(no_usage.calculate_system_metrics().liquidity_usage_gini, 0.0);
+
+    let single = create_orchestrator(&[-400_000]);
+    assert_eq!(single.calculate_system_metrics().liquidity_usage_gini, 0.0);
+}
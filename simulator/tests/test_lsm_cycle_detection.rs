@@ -11,7 +11,7 @@ use std::collections::BTreeMap;
 
 use payment_simulator_core_rs::{
     settlement::{
-        lsm::{detect_cycles, settle_cycle},
+        lsm::{detect_cycles, run_lsm_pass, settle_cycle, LsmConfig},
         submit_transaction,
     },
     Agent, SimulationState, Transaction,
@@ -213,3 +213,74 @@ fn test_no_cycle_detection_with_incomplete_ring() {
 
     assert!(cycles.is_empty(), "Should not find cycle with incomplete ring");
 }
+
+/// Build a 5-agent ring A→B→C→D→E→A where no payment can settle on its own
+fn create_five_agent_ring() -> SimulationState {
+    let ids = ["BANK_A", "BANK_B", "BANK_C", "BANK_D", "BANK_E"];
+    let agents = ids.iter().map(|id| create_agent(id, 10_000, 0)).collect();
+    let mut state = SimulationState::new(agents);
+
+    for (i, sender) in ids.iter().enumerate() {
+        let receiver = ids[(i + 1) % ids.len()];
+        let tx = create_transaction(sender, receiver, 100_000, 0, 10);
+        submit_transaction(&mut state, tx, 0).unwrap();
+    }
+    assert_eq!(state.queue_size(), 5);
+
+    state
+}
+
+fn ring_lsm_config(max_cycle_length: Option<usize>) -> LsmConfig {
+    LsmConfig {
+        enable_bilateral: false,
+        max_cycle_length,
+        ..LsmConfig::default()
+    }
+}
+
+#[test]
+fn test_max_cycle_length_blocks_longer_cycle() {
+    let mut state = create_five_agent_ring();
+
+    assert!(detect_cycles(&state, 3).is_empty(), "5-cycle exceeds limit of 3");
+
+    let result = run_lsm_pass(&mut state, &ring_lsm_config(Some(3)), 1, 100, false);
+
+    assert_eq!(result.cycles_settled, 0);
+    assert_eq!(result.total_settled_value, 0);
+    assert_eq!(state.queue_size(), 5);
+}
+
+#[test]
+fn test_unbounded_max_cycle_length_settles_five_cycle() {
+    let mut state = create_five_agent_ring();
+    let result = run_lsm_pass(&mut state, &ring_lsm_config(None), 1, 100, false);
+
+    assert_eq!(result.cycles_settled, 1);
+    assert_eq!(result.total_settled_value, 500_000);
+    assert_eq!(state.queue_size(), 0);
+    // Every agent nets to zero
+    assert_eq!(state.get_agent("BANK_A").unwrap().balance(), 10_000);
+}
+
+#[test]
+fn test_max_cycle_length_defaults_to_four_unless_explicitly_null() {
+    assert_eq!(LsmConfig::default().max_cycle_length, Some(4));
+
+    let missing: LsmConfig = serde_json::from_str(
+        r#"{"enable_bilateral": true, "enable_cycles": true, "max_cycles_per_tick": 10}"#,
+    )
+    .unwrap();
+    assert_eq!(missing.max_cycle_length, Some(4));
+
+    let null: LsmConfig = serde_json::from_str(
+        r#"{"enable_bilateral": true, "enable_cycles": true, "max_cycle_length": null, "max_cycles_per_tick": 10}"#,
+    )
+    .unwrap();
+    assert_eq!(null.max_cycle_length, None);
+
+    // The default bound keeps the 5-agent ring unsettled
+    let mut state = create_five_agent_ring();
+    let result = run_lsm_pass(&mut state, &ring_lsm_config(Some(4)), 1, 100, false);
+    assert_eq!(result.cycles_settled, 0);
+}
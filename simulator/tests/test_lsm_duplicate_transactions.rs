@@ -78,8 +78,11 @@ fn test_bilateral_offset_no_duplicate_transactions() {
     let config = LsmConfig {
         enable_bilateral: true,
         enable_cycles: false, // Only test bilateral for now
-        max_cycle_length: 4,
+        max_cycle_length: Some(4),
         max_cycles_per_tick: 10,
+        partial_settlement: false,
+        lsm_interval_ticks: 1,
+        enable_multilateral_netting: false,
     };
 
     let result = run_lsm_pass(&mut state, &config, 5, 100, false);
@@ -362,8 +365,11 @@ fn test_no_duplicate_between_bilateral_and_multilateral() {
     let config = LsmConfig {
         enable_bilateral: true,
         enable_cycles: true,  // This is the key - both are enabled
-        max_cycle_length: 5,
+        max_cycle_length: Some(5),
         max_cycles_per_tick: 10,
+        partial_settlement: false,
+        lsm_interval_ticks: 1,
+        enable_multilateral_netting: false,
     };
     let result = run_lsm_pass(&mut state, &config, 5, 100, false);
 
@@ -455,8 +461,11 @@ fn test_complex_scenario_no_duplicates() {
     let config = LsmConfig {
         enable_bilateral: true,
         enable_cycles: true,
-        max_cycle_length: 5,
+        max_cycle_length: Some(5),
         max_cycles_per_tick: 10,
+        partial_settlement: false,
+        lsm_interval_ticks: 1,
+        enable_multilateral_netting: false,
     };
     let result = run_lsm_pass(&mut state, &config, 5, 100, false);
 
@@ -0,0 +1,158 @@
+//! Tests for LSM run frequency (`LsmConfig::lsm_interval_ticks`)
+//!
+//! The LSM pass runs only on ticks divisible by the interval. Between
+//! passes, queued transactions wait in Queue 2 and are picked up by the
+//! next pass.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use payment_simulator_core_rs::SimulationError;
+
+fn create_config(lsm_interval_ticks: usize) -> OrchestratorConfig {
+    // Zero balances and no credit: only the LSM can settle a cycle
+    let make_agent = |id: &str| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 0,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![
+            make_agent("BANK_A"),
+            make_agent("BANK_B"),
+            make_agent("BANK_C"),
+        ],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig {
+            lsm_interval_ticks,
+            ..LsmConfig::default()
+        },
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+/// Submit a three-bank cycle A→B→C→A of equal amounts
+fn submit_cycle(orchestrator: &mut Orchestrator) -> Vec<String> {
+    [
+        ("BANK_A", "BANK_B"),
+        ("BANK_B", "BANK_C"),
+        ("BANK_C", "BANK_A"),
+    ]
+    .iter()
+    .map(|(sender, receiver)| {
+        orchestrator
+            .submit_transaction(sender, receiver, 100_000, 50, 5, false)
+            .unwrap()
+    })
+    .collect()
+}
+
+#[test]
+fn test_lsm_skipped_between_intervals() {
+    let mut orchestrator = Orchestrator::new(create_config(5)).unwrap();
+
+    // Run tick 0 first so the cycle forms at tick 1, off the interval
+    orchestrator.tick().unwrap();
+    let tx_ids = submit_cycle(&mut orchestrator);
+
+    for _ in 1..5 {
+        orchestrator.tick().unwrap();
+    }
+
+    assert!(orchestrator.get_lsm_cycles_for_day(0).is_empty());
+    assert!(orchestrator
+        .event_log()
+        .events_of_type("LsmCycleSettlement")
+        .is_empty());
+    assert_eq!(
+        orchestrator.get_queue2_size(),
+        3,
+        "cycle should wait in Queue 2"
+    );
+    for tx_id in &tx_ids {
+        assert!(!orchestrator
+            .get_transaction(tx_id)
+            .unwrap()
+            .is_fully_settled());
+    }
+}
+
+#[test]
+fn test_cycle_resolved_on_next_interval_multiple() {
+    let mut orchestrator = Orchestrator::new(create_config(5)).unwrap();
+
+    orchestrator.tick().unwrap();
+    let tx_ids = submit_cycle(&mut orchestrator);
+
+    for _ in 1..=5 {
+        orchestrator.tick().unwrap();
+    }
+
+    let cycles = orchestrator.get_lsm_cycles_for_day(0);
+    assert!(!cycles.is_empty());
+    assert!(cycles.iter().all(|cycle| cycle.tick == 5));
+    for tx_id in &tx_ids {
+        assert!(orchestrator
+            .get_transaction(tx_id)
+            .unwrap()
+            .is_fully_settled());
+    }
+}
+
+#[test]
+fn test_default_interval_runs_lsm_every_tick() {
+    let mut orchestrator = Orchestrator::new(create_config(1)).unwrap();
+
+    orchestrator.tick().unwrap();
+    let tx_ids = submit_cycle(&mut orchestrator);
+    orchestrator.tick().unwrap();
+
+    let cycles = orchestrator.get_lsm_cycles_for_day(0);
+    assert!(!cycles.is_empty());
+    assert!(cycles.iter().all(|cycle| cycle.tick == 1));
+    for tx_id in &tx_ids {
+        assert!(orchestrator
+            .get_transaction(tx_id)
+            .unwrap()
+            .is_fully_settled());
+    }
+}
+
+#[test]
+fn test_zero_interval_rejected() {
+    match Orchestrator::new(create_config(0)) {
+        Err(SimulationError::InvalidConfig(msg)) => {
+            assert!(
+                msg.contains("lsm_interval_ticks"),
+                "unexpected message: {}",
+                msg
+            );
+        }
+        _ => panic!("expected InvalidConfig for lsm_interval_ticks = 0"),
+    }
+}
@@ -0,0 +1,267 @@
+//! Tests for LSM multilateral netting (Algorithm 4)
+//!
+//! Netting settles every queued payment among the largest liquidity-feasible
+//! set of participants at once, breaking gridlocks that neither bilateral
+//! offsetting nor single-cycle settlement can resolve.
+
+use payment_simulator_core_rs::models::agent::Agent;
+use payment_simulator_core_rs::models::event::Event;
+use payment_simulator_core_rs::models::state::SimulationState;
+use payment_simulator_core_rs::models::transaction::{SettlementMechanism, Transaction};
+use payment_simulator_core_rs::settlement::deferred::DeferredCredits;
+use payment_simulator_core_rs::settlement::lsm::{
+    run_lsm_pass, run_multilateral_netting, run_multilateral_netting_with_deferred, LsmConfig,
+};
+
+fn queue_payment(state: &mut SimulationState, sender: &str, receiver: &str, amount: i64) -> String {
+    let tx = Transaction::new(sender.to_string(), receiver.to_string(), amount, 0, 100);
+    let tx_id = tx.id().to_string();
+    state.add_transaction(tx);
+    state.rtgs_queue_mut().push(tx_id.clone());
+    tx_id
+}
+
+/// Four banks with zero balances and the queue
+/// A→B 100k, A→C 100k, B→D 100k, C→D 100k, D→A 200k.
+///
+/// There are no bilateral pairs, and each cycle (A→B→D→A, A→C→D→A) leaves
+/// D needing 100k. Across the whole queue every net position is zero.
+fn gridlocked_state() -> (SimulationState, Vec<String>) {
+    gridlocked_state_with(&["A", "B", "C", "D"])
+}
+
+/// Same gridlock, with any extra zero-balance agents in `agent_ids`
+fn gridlocked_state_with(agent_ids: &[&str]) -> (SimulationState, Vec<String>) {
+    let agents = agent_ids
+        .iter()
+        .map(|id| Agent::new(id.to_string(), 0))
+        .collect();
+    let mut state = SimulationState::new(agents);
+
+    let tx_ids = vec![
+        queue_payment(&mut state, "A", "B", 100_000),
+        queue_payment(&mut state, "A", "C", 100_000),
+        queue_payment(&mut state, "B", "D", 100_000),
+        queue_payment(&mut state, "C", "D", 100_000),
+        queue_payment(&mut state, "D", "A", 200_000),
+    ];
+    (state, tx_ids)
+}
+
+fn netting_config() -> LsmConfig {
+    LsmConfig {
+        enable_multilateral_netting: true,
+        ..LsmConfig::default()
+    }
+}
+
+#[test]
+fn test_bilateral_and_cycles_cannot_break_gridlock() {
+    let (mut state, _) = gridlocked_state();
+
+    let result = run_lsm_pass(&mut state, &LsmConfig::default(), 1, 100, false);
+
+    assert_eq!(result.bilateral_offsets, 0);
+    assert_eq!(result.cycles_settled, 0);
+    assert_eq!(result.total_settled_value, 0);
+    assert_eq!(state.queue_size(), 5);
+}
+
+#[test]
+fn test_netting_breaks_four_agent_gridlock() {
+    let (mut state, tx_ids) = gridlocked_state();
+
+    let result = run_multilateral_netting(&mut state, &netting_config(), 1);
+
+    assert_eq!(result.participants, vec!["A", "B", "C", "D"]);
+    assert!(result.removed_participants.is_empty());
+    assert_eq!(result.transactions, tx_ids);
+    assert_eq!(result.settled_value, 600_000);
+    assert!(result.net_positions.values().all(|&net| net == 0));
+    assert_eq!(state.queue_size(), 0);
+
+    for tx_id in &tx_ids {
+        let tx = state.get_transaction(tx_id).unwrap();
+        assert!(tx.is_fully_settled());
+        assert_eq!(
+            tx.settlement_mechanism(),
+            Some(SettlementMechanism::MultilateralNetting)
+        );
+    }
+    for agent_id in ["A", "B", "C", "D"] {
+        assert_eq!(state.get_agent(agent_id).unwrap().balance(), 0);
+    }
+}
+
+#[test]
+fn test_netting_removes_infeasible_participant() {
+    let (mut state, tx_ids) = gridlocked_state_with(&["A", "B", "C", "D", "E"]);
+    let blocked_tx = queue_payment(&mut state, "E", "A", 300_000);
+
+    let result = run_multilateral_netting(&mut state, &netting_config(), 1);
+
+    // E cannot fund its 300k net outflow; everyone else settles without it
+    assert_eq!(result.removed_participants, vec!["E"]);
+    assert_eq!(result.participants, vec!["A", "B", "C", "D"]);
+    assert_eq!(result.transactions, tx_ids);
+    assert_eq!(state.rtgs_queue(), &vec![blocked_tx.clone()]);
+    assert!(!state
+        .get_transaction(&blocked_tx)
+        .unwrap()
+        .is_fully_settled());
+}
+
+#[test]
+fn test_netting_settles_nothing_when_no_set_is_feasible() {
+    let agents = vec![
+        Agent::new("A".to_string(), 0),
+        Agent::new("B".to_string(), 0),
+    ];
+    let mut state = SimulationState::new(agents);
+    queue_payment(&mut state, "A", "B", 100_000);
+
+    let result = run_multilateral_netting(&mut state, &netting_config(), 1);
+
+    assert!(result.is_empty());
+    assert_eq!(result.removed_participants, vec!["A"]);
+    assert_eq!(state.queue_size(), 1);
+}
+
+#[test]
+fn test_netting_disabled_by_default() {
+    let (mut state, _) = gridlocked_state();
+
+    let result = run_multilateral_netting(&mut state, &LsmConfig::default(), 1);
+
+    assert!(result.is_empty());
+    assert_eq!(state.queue_size(), 5);
+}
+
+#[test]
+fn test_lsm_pass_runs_netting_when_enabled() {
+    let (mut state, tx_ids) = gridlocked_state();
+
+    let result = run_lsm_pass(&mut state, &netting_config(), 1, 100, false);
+
+    assert_eq!(result.netting_batches, 1);
+    assert_eq!(result.total_settled_value, 600_000);
+    assert_eq!(result.final_queue_size, 0);
+
+    let netting_events: Vec<_> = result
+        .replay_events
+        .iter()
+        .filter(|e| e.event_type() == "LsmNettingSettlement")
+        .collect();
+    assert_eq!(netting_events.len(), 1);
+    match netting_events[0] {
+        Event::LsmNettingSettlement {
+            tick,
+            agents,
+            total_value,
+            tx_ids: event_tx_ids,
+            ..
+        } => {
+            assert_eq!(*tick, 1);
+            assert_eq!(agents.len(), 4);
+            assert_eq!(*total_value, 600_000);
+            assert_eq!(event_tx_ids, &tx_ids);
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+fn test_pending_deferred_credits_do_not_fund_netting() {
+    let mut state = SimulationState::new(vec![
+        Agent::new("A".to_string(), 0),
+        Agent::new("B".to_string(), 0),
+    ]);
+    let tx_id = queue_payment(&mut state, "A", "B", 100_000);
+
+    // A was credited earlier this tick, but the credit only lands at t+1
+    let mut deferred = DeferredCredits::new();
+    deferred.defer(&mut state, "A", 150_000, "earlier_tx");
+    assert_eq!(state.get_agent("A").unwrap().provisional_balance(), 150_000);
+
+    let result = run_multilateral_netting_with_deferred(
+        &mut state,
+        &netting_config(),
+        1,
+        Some(&mut deferred),
+    );
+
+    assert!(result.transactions.is_empty());
+    assert_eq!(result.removed_participants, vec!["A".to_string()]);
+    assert_eq!(state.rtgs_queue(), &vec![tx_id.clone()]);
+    assert_eq!(state.get_agent("A").unwrap().balance(), 0);
+
+    // Once the credit is applied, the same payment nets
+    deferred.apply_all(&mut state, 1);
+    let result = run_multilateral_netting(&mut state, &netting_config(), 2);
+
+    assert_eq!(result.transactions, vec![tx_id]);
+    assert_eq!(state.get_agent("A").unwrap().balance(), 50_000);
+}
+
+#[test]
+fn test_netting_settles_split_children_once_and_parent_follows() {
+    let mut state = SimulationState::new(vec![
+        Agent::new("A".to_string(), 0),
+        Agent::new("B".to_string(), 0),
+    ]);
+
+    // Split parent is not queued; only its children reach Queue 2
+    let parent = Transaction::new("A".to_string(), "B".to_string(), 200_000, 0, 100);
+    let parent_id = parent.id().to_string();
+    state.add_transaction(parent);
+    for _ in 0..2 {
+        let child = Transaction::new_split(
+            "A".to_string(),
+            "B".to_string(),
+            100_000,
+            0,
+            100,
+            parent_id.clone(),
+        );
+        let child_id = child.id().to_string();
+        state.add_transaction(child);
+        state.rtgs_queue_mut().push(child_id);
+    }
+    queue_payment(&mut state, "B", "A", 200_000);
+
+    let result = run_multilateral_netting(&mut state, &netting_config(), 1);
+
+    assert_eq!(result.transactions.len(), 3);
+    assert_eq!(result.settled_value, 400_000);
+    assert_eq!(result.net_positions.get("A"), Some(&0));
+
+    let parent = state.get_transaction(&parent_id).unwrap();
+    assert_eq!(parent.remaining_amount(), 0);
+    assert!(parent.is_fully_settled());
+}
+
+#[test]
+fn test_netting_holds_back_reserve_requirement() {
+    // A holds 100k and owes a net 50k across the batch
+    let reserved_state = |reserve: i64| {
+        let mut bank_a = Agent::new("A".to_string(), 100_000);
+        bank_a.set_reserve_requirement(reserve);
+        let mut state = SimulationState::new(vec![bank_a, Agent::new("B".to_string(), 0)]);
+        queue_payment(&mut state, "A", "B", 150_000);
+        queue_payment(&mut state, "B", "A", 100_000);
+        state
+    };
+
+    let mut state = reserved_state(0);
+    let result = run_multilateral_netting(&mut state, &netting_config(), 1);
+    assert_eq!(result.transactions.len(), 2);
+    assert_eq!(state.get_agent("A").unwrap().balance(), 50_000);
+
+    // A 60k reserve leaves only 40k usable, so A is removed from the batch
+    let mut state = reserved_state(60_000);
+    let result = run_multilateral_netting(&mut state, &netting_config(), 1);
+    assert!(result.transactions.is_empty());
+    assert_eq!(result.removed_participants, vec!["A".to_string()]);
+    assert_eq!(state.get_agent("A").unwrap().balance(), 100_000);
+    assert_eq!(state.rtgs_queue().len(), 2);
+}
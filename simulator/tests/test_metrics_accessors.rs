@@ -0,0 +1,101 @@
+//! Tests for the owned metrics accessors used by the FFI layer
+//!
+//! `system_metrics()` and `daily_agent_metrics(day)` must report exactly
+//! what `calculate_system_metrics()` and `get_daily_agent_metrics(day)`
+//! return for the same run.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+
+const TICKS_PER_DAY: usize = 10;
+
+fn make_agent(id: &str, opening_balance: i64) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+/// Runs one day plus three ticks; BANK_A's large payment stays queued
+fn run_short_simulation() -> Orchestrator {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(TICKS_PER_DAY)
+        .num_days(2)
+        .rng_seed(12345)
+        .agent(make_agent("BANK_B", 1_000_000))
+        .agent(make_agent("BANK_A", 300_000))
+        .build()
+        .unwrap();
+    let mut orch = Orchestrator::new(config).unwrap();
+
+    for (sender, receiver, amount, deadline) in [
+        ("BANK_A", "BANK_B", 200_000, 5),
+        ("BANK_B", "BANK_A", 50_000, 5),
+        ("BANK_A", "BANK_B", 400_000, 15),
+    ] {
+        orch.submit_transaction(sender, receiver, amount, deadline, 5, false)
+            .unwrap();
+    }
+    for _ in 0..(TICKS_PER_DAY + 3) {
+        orch.tick().unwrap();
+    }
+    orch
+}
+
+#[test]
+fn test_system_metrics_matches_calculate_system_metrics() {
+    let orch = run_short_simulation();
+
+    let metrics = orch.system_metrics();
+    assert_eq!(metrics, orch.calculate_system_metrics());
+    assert_eq!(metrics.total_arrivals, 3);
+    assert_eq!(metrics.queue2_total_size, 1);
+}
+
+#[test]
+fn test_daily_agent_metrics_matches_borrowed_records() {
+    let orch = run_short_simulation();
+
+    let owned = orch.daily_agent_metrics(0);
+    let borrowed = orch.get_daily_agent_metrics(0);
+    assert_eq!(owned.len(), 2);
+    assert_eq!(owned.len(), borrowed.len());
+
+    for (m, expected) in owned.iter().zip(borrowed) {
+        assert_eq!(m.agent_id, expected.agent_id);
+        assert_eq!(m.day, 0);
+        assert_eq!(m.opening_balance, expected.opening_balance);
+        assert_eq!(m.closing_balance, expected.closing_balance);
+        assert_eq!(m.min_balance, expected.min_balance);
+        assert_eq!(m.max_balance, expected.max_balance);
+        assert_eq!(m.num_sent, expected.num_sent);
+        assert_eq!(m.num_received, expected.num_received);
+        assert_eq!(m.num_settled, expected.num_settled);
+        assert_eq!(m.queue1_eod_size, expected.queue1_eod_size);
+        assert_eq!(m.total_cost, expected.total_cost);
+    }
+
+    // Sorted by agent ID regardless of config order
+    let ids: Vec<&str> = owned.iter().map(|m| m.agent_id.as_str()).collect();
+    assert_eq!(ids, vec!["BANK_A", "BANK_B"]);
+    assert_eq!(owned[0].opening_balance, 300_000);
+    assert_eq!(owned[0].closing_balance, 150_000);
+}
+
+#[test]
+fn test_daily_agent_metrics_empty_for_incomplete_day() {
+    let orch = run_short_simulation();
+    assert!(orch.daily_agent_metrics(1).is_empty());
+}
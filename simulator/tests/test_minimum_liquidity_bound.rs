@@ -0,0 +1,103 @@
+//! Tests for the LSM netting lower bound
+//!
+//! `minimum_liquidity_bound` returns each agent's multilateral net debit
+//! over Queue 2: the least liquidity it needs under perfect netting.
+
+use payment_simulator_core_rs::{
+    settlement::minimum_liquidity_bound, Agent, SimulationState, Transaction,
+};
+use std::collections::HashMap;
+
+fn queue(state: &mut SimulationState, sender: &str, receiver: &str, amount: i64) -> String {
+    let tx = Transaction::new(sender.to_string(), receiver.to_string(), amount, 0, 100);
+    let tx_id = tx.id().to_string();
+    state.add_transaction(tx);
+    state.rtgs_queue_mut().push(tx_id.clone());
+    tx_id
+}
+
+fn create_state() -> SimulationState {
+    SimulationState::new(
+        ["A", "B", "C", "D"]
+            .iter()
+            .map(|id| Agent::new(id.to_string(), 0))
+            .collect(),
+    )
+}
+
+fn bound(entries: &[(&str, i64)]) -> HashMap<String, i64> {
+    entries.iter().map(|(id, v)| (id.to_string(), *v)).collect()
+}
+
+#[test]
+fn test_chain_bound_is_below_gross_outflow() {
+    let mut state = create_state();
+    queue(&mut state, "A", "B", 500_000);
+    queue(&mut state, "B", "C", 500_000);
+    queue(&mut state, "C", "D", 500_000);
+
+    let result = minimum_liquidity_bound(&state);
+
+    // Only the head of the chain needs liquidity; B and C pass it along
+    assert_eq!(
+        result,
+        bound(&[("A", 500_000), ("B", 0), ("C", 0), ("D", 0)])
+    );
+    let total: i64 = result.values().sum();
+    assert!(
+        total < 1_500_000,
+        "bound {total} should be below gross outflow"
+    );
+}
+
+#[test]
+fn test_unequal_chain_nets_each_agent() {
+    let mut state = create_state();
+    queue(&mut state, "A", "B", 500_000);
+    queue(&mut state, "B", "C", 800_000);
+    queue(&mut state, "C", "D", 300_000);
+
+    assert_eq!(
+        minimum_liquidity_bound(&state),
+        bound(&[("A", 500_000), ("B", 300_000), ("C", 0), ("D", 0)])
+    );
+}
+
+#[test]
+fn test_cycle_needs_no_liquidity() {
+    let mut state = create_state();
+    queue(&mut state, "A", "B", 500_000);
+    queue(&mut state, "B", "C", 500_000);
+    queue(&mut state, "C", "A", 500_000);
+
+    assert!(minimum_liquidity_bound(&state).values().all(|v| *v == 0));
+}
+
+#[test]
+fn test_uses_remaining_amounts_and_skips_self_payments() {
+    let mut state = create_state();
+    let partial = queue(&mut state, "A", "B", 500_000);
+    queue(&mut state, "B", "A", 100_000);
+    queue(&mut state, "C", "C", 900_000);
+    state
+        .get_transaction_mut(&partial)
+        .unwrap()
+        .settle_partial(150_000, 1)
+        .unwrap();
+
+    // A owes 350k remaining against 100k incoming
+    assert_eq!(
+        minimum_liquidity_bound(&state),
+        bound(&[("A", 250_000), ("B", 0), ("C", 0), ("D", 0)])
+    );
+}
+
+#[test]
+fn test_empty_queue_gives_zero_for_every_agent() {
+    let state = create_state();
+
+    assert_eq!(
+        minimum_liquidity_bound(&state),
+        bound(&[("A", 0), ("B", 0), ("C", 0), ("D", 0)])
+    );
+}
@@ -0,0 +1,203 @@
+//! Tests for the negative binomial arrival count model
+//!
+//! `ArrivalCountModel::NegativeBinomial { r, p }` draws per-tick counts via
+//! `RngManager::negative_binomial`, with mean `r(1-p)/p` and variance `r(1-p)/p²`.
+
+use payment_simulator_core_rs::arrivals::{
+    AmountDistribution, ArrivalConfig, ArrivalCountModel, ArrivalGenerator, PriorityDistribution,
+};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use payment_simulator_core_rs::{RngManager, SimulationError};
+use std::collections::HashMap;
+
+fn arrival_config(rate_per_tick: f64, model: ArrivalCountModel) -> ArrivalConfig {
+    ArrivalConfig {
+        rate_per_tick,
+        amount_distribution: AmountDistribution::Uniform {
+            min: 1_000,
+            max: 10_000,
+        },
+        counterparty_weights: HashMap::new(),
+        deadline_range: (5, 10),
+        priority_distribution: PriorityDistribution::Fixed { value: 5 },
+        divisible: false,
+        arrival_count_model: model,
+        ..Default::default()
+    }
+}
+
+fn sample_counts(config: ArrivalConfig, seed: u64, ticks: usize) -> Vec<f64> {
+    let mut generator = ArrivalGenerator::new(
+        vec![("BANK_A".to_string(), config)].into_iter().collect(),
+        vec!["BANK_A".to_string(), "BANK_B".to_string()],
+        ticks + 100,
+        ticks + 100,
+        false,
+    );
+    let mut rng = RngManager::new(seed);
+
+    (0..ticks)
+        .map(|tick| generator.generate_for_agent("BANK_A", tick, &mut rng).len() as f64)
+        .collect()
+}
+
+fn mean_and_variance(samples: &[f64]) -> (f64, f64) {
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let var = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+    (mean, var)
+}
+
+fn create_orchestrator_config(model: ArrivalCountModel) -> OrchestratorConfig {
+    let make_agent = |id: &str, arrival_config: Option<ArrivalConfig>| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 10_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![
+            make_agent("BANK_A", Some(arrival_config(1.0, model))),
+            make_agent("BANK_B", None),
+        ],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_negative_binomial_counts_are_overdispersed() {
+    // r = 2, p = 0.4: mean 3.0, variance 7.5
+    let model = ArrivalCountModel::NegativeBinomial { r: 2.0, p: 0.4 };
+    let counts = sample_counts(arrival_config(1.0, model), 12345, 5_000);
+    let (mean, var) = mean_and_variance(&counts);
+
+    assert!((mean - 3.0).abs() < 0.3, "mean {} too far from 3.0", mean);
+    assert!(
+        var > 1.5 * mean,
+        "variance {} should exceed mean {}",
+        var,
+        mean
+    );
+    assert!((var - 7.5).abs() < 1.5, "variance {} too far from 7.5", var);
+}
+
+#[test]
+fn test_negative_binomial_counts_match_rng_sampler() {
+    // Each tick's count is the first draw, so it must equal RngManager::negative_binomial
+    let model = ArrivalCountModel::NegativeBinomial { r: 2.0, p: 0.4 };
+    for seed in 0..200 {
+        let counts = sample_counts(arrival_config(1.0, model.clone()), seed, 1);
+        let expected = RngManager::new(seed).negative_binomial(2.0, 0.4);
+        assert_eq!(counts[0], expected as f64, "seed {}", seed);
+    }
+}
+
+#[test]
+fn test_intraday_profile_scales_negative_binomial_mean() {
+    // Doubling the multiplier doubles the mean: 2 * 3.0 = 6.0
+    let model = ArrivalCountModel::NegativeBinomial { r: 2.0, p: 0.4 };
+    let mut config = arrival_config(1.0, model);
+    config.intraday_profile = Some(vec![2.0; 5_100]);
+    let counts = sample_counts(config, 12345, 5_000);
+    let (mean, _) = mean_and_variance(&counts);
+
+    assert!((mean - 6.0).abs() < 0.5, "mean {} too far from 6.0", mean);
+}
+
+#[test]
+fn test_poisson_counts_are_not_overdispersed() {
+    let counts = sample_counts(
+        arrival_config(3.0, ArrivalCountModel::Poisson),
+        12345,
+        5_000,
+    );
+    let (mean, var) = mean_and_variance(&counts);
+
+    assert!((mean - 3.0).abs() < 0.2, "mean {} too far from 3.0", mean);
+    assert!(
+        (var / mean - 1.0).abs() < 0.15,
+        "variance {} should match mean {}",
+        var,
+        mean
+    );
+}
+
+#[test]
+fn test_negative_binomial_is_deterministic() {
+    let model = ArrivalCountModel::NegativeBinomial { r: 0.5, p: 0.2 };
+    let first = sample_counts(arrival_config(1.0, model.clone()), 99, 200);
+    let second = sample_counts(arrival_config(1.0, model), 99, 200);
+
+    assert_eq!(first, second);
+}
+
+#[test]
+fn test_missing_field_defaults_to_poisson() {
+    let mut json = serde_json::to_value(arrival_config(2.0, ArrivalCountModel::Poisson)).unwrap();
+    json.as_object_mut().unwrap().remove("arrival_count_model");
+
+    let config: ArrivalConfig = serde_json::from_value(json).unwrap();
+    assert_eq!(config.arrival_count_model, ArrivalCountModel::Poisson);
+}
+
+#[test]
+fn test_invalid_negative_binomial_parameters_rejected() {
+    for (r, p) in [
+        (0.0, 0.5),
+        (-1.0, 0.5),
+        (2.0, 0.0),
+        (2.0, 1.0),
+        (f64::NAN, 0.5),
+    ] {
+        let config = create_orchestrator_config(ArrivalCountModel::NegativeBinomial { r, p });
+        match Orchestrator::new(config) {
+            Err(SimulationError::InvalidConfig(msg)) => {
+                assert!(
+                    msg.contains("NegativeBinomial"),
+                    "unexpected message: {}",
+                    msg
+                )
+            }
+            _ => panic!("expected InvalidConfig for r={}, p={}", r, p),
+        }
+    }
+}
+
+#[test]
+fn test_negative_binomial_runs_in_orchestrator() {
+    let config = create_orchestrator_config(ArrivalCountModel::NegativeBinomial { r: 2.0, p: 0.4 });
+    let mut orchestrator = Orchestrator::new(config).unwrap();
+
+    let arrivals: usize = (0..50)
+        .map(|_| orchestrator.tick().unwrap().num_arrivals)
+        .sum();
+    assert!(arrivals > 0);
+}
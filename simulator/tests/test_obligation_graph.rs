@@ -0,0 +1,119 @@
+//! Tests for the LSM obligation graph snapshot
+//!
+//! `build_obligation_graph` nets opposite Queue 2 obligations between each
+//! pair of agents and returns sorted `(sender, receiver, amount)` edges.
+
+use payment_simulator_core_rs::{
+    orchestrator::{AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig},
+    settlement::build_obligation_graph,
+    Agent, SimulationState, Transaction,
+};
+
+fn queue(state: &mut SimulationState, sender: &str, receiver: &str, amount: i64) {
+    let tx = Transaction::new(sender.to_string(), receiver.to_string(), amount, 0, 100);
+    let tx_id = tx.id().to_string();
+    state.add_transaction(tx);
+    state.rtgs_queue_mut().push(tx_id);
+}
+
+fn edge(sender: &str, receiver: &str, amount: i64) -> (String, String, i64) {
+    (sender.to_string(), receiver.to_string(), amount)
+}
+
+fn create_state() -> SimulationState {
+    SimulationState::new(
+        ["A", "B", "C", "D"]
+            .iter()
+            .map(|id| Agent::new(id.to_string(), 0))
+            .collect(),
+    )
+}
+
+#[test]
+fn test_edges_are_netted_and_sorted() {
+    let mut state = create_state();
+    queue(&mut state, "C", "A", 70_000);
+    queue(&mut state, "B", "A", 200_000);
+    queue(&mut state, "A", "B", 300_000);
+    queue(&mut state, "A", "B", 200_000);
+    queue(&mut state, "B", "C", 100_000);
+
+    let graph = build_obligation_graph(&state);
+
+    // A→B: 500k gross against B→A 200k nets to 300k
+    assert_eq!(
+        graph,
+        vec![
+            edge("A", "B", 300_000),
+            edge("B", "C", 100_000),
+            edge("C", "A", 70_000),
+        ]
+    );
+}
+
+#[test]
+fn test_net_direction_follows_larger_side() {
+    let mut state = create_state();
+    queue(&mut state, "A", "D", 40_000);
+    queue(&mut state, "D", "A", 90_000);
+
+    assert_eq!(build_obligation_graph(&state), vec![edge("D", "A", 50_000)]);
+}
+
+#[test]
+fn test_exactly_offsetting_pair_omitted() {
+    let mut state = create_state();
+    queue(&mut state, "A", "B", 100_000);
+    queue(&mut state, "B", "A", 100_000);
+    queue(&mut state, "C", "D", 10_000);
+
+    assert_eq!(build_obligation_graph(&state), vec![edge("C", "D", 10_000)]);
+}
+
+#[test]
+fn test_orchestrator_snapshot_is_read_only() {
+    let make_agent = |id: &str| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 0,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+    let mut config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(7)
+        .agent(make_agent("BANK_A"))
+        .agent(make_agent("BANK_B"))
+        .build()
+        .unwrap();
+    // Keep the LSM from resolving the gridlock so it stays visible
+    config.lsm_config.enable_bilateral = false;
+    config.lsm_config.enable_cycles = false;
+    let mut orch = Orchestrator::new(config).unwrap();
+
+    orch.submit_transaction("BANK_A", "BANK_B", 80_000, 50, 5, false)
+        .unwrap();
+    orch.submit_transaction("BANK_B", "BANK_A", 30_000, 50, 5, false)
+        .unwrap();
+    assert!(
+        orch.current_obligation_graph().is_empty(),
+        "Queue 1 is not part of the graph"
+    );
+
+    orch.tick().unwrap();
+    assert_eq!(orch.get_queue2_size(), 2);
+
+    let graph = orch.current_obligation_graph();
+    assert_eq!(graph, vec![edge("BANK_A", "BANK_B", 50_000)]);
+    assert_eq!(orch.current_obligation_graph(), graph);
+    assert_eq!(orch.get_queue2_size(), 2);
+}
@@ -0,0 +1,116 @@
+//! Tests for `AgentConfig.opening_balance_distribution`
+//!
+//! A randomized agent's opening balance is sampled from the shared RNG at
+//! construction, in sorted agent ID order, so it is reproducible per seed.
+
+use payment_simulator_core_rs::arrivals::AmountDistribution;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::SimulationError;
+
+const MIN_BALANCE: i64 = 100_000;
+const MAX_BALANCE: i64 = 10_000_000;
+
+fn uniform() -> AmountDistribution {
+    AmountDistribution::Uniform {
+        min: MIN_BALANCE,
+        max: MAX_BALANCE,
+    }
+}
+
+fn make_agent(id: &str, opening_balance_distribution: Option<AmountDistribution>) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        opening_balance_distribution,
+        ..Default::default()
+    }
+}
+
+fn create_orchestrator(
+    seed: u64,
+    agents: Vec<AgentConfig>,
+) -> Result<Orchestrator, SimulationError> {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(10)
+        .num_days(1)
+        .rng_seed(seed)
+        .agent_configs(agents)
+        .build()?;
+    Orchestrator::new(config)
+}
+
+fn randomized_agents() -> Vec<AgentConfig> {
+    vec![
+        make_agent("BANK_A", Some(uniform())),
+        make_agent("BANK_B", Some(uniform())),
+        make_agent("BANK_C", None),
+    ]
+}
+
+fn balances(orch: &Orchestrator) -> Vec<i64> {
+    ["BANK_A", "BANK_B", "BANK_C"]
+        .iter()
+        .map(|id| orch.get_agent_balance(id).unwrap())
+        .collect()
+}
+
+#[test]
+fn test_same_seed_gives_identical_opening_balances() {
+    let first = create_orchestrator(42, randomized_agents()).unwrap();
+    let second = create_orchestrator(42, randomized_agents()).unwrap();
+    assert_eq!(balances(&first), balances(&second));
+
+    let other = create_orchestrator(43, randomized_agents()).unwrap();
+    assert_ne!(balances(&first), balances(&other));
+}
+
+#[test]
+fn test_distribution_overrides_opening_balance() {
+    let orch = create_orchestrator(42, randomized_agents()).unwrap();
+    let balances = balances(&orch);
+
+    for balance in &balances[..2] {
+        assert!((MIN_BALANCE..=MAX_BALANCE).contains(balance));
+    }
+    assert_eq!(balances[2], 1_000, "fixed agent keeps its opening_balance");
+}
+
+#[test]
+fn test_sampling_order_follows_agent_ids_not_config_order() {
+    let forward = create_orchestrator(42, randomized_agents()).unwrap();
+    let mut reversed_agents = randomized_agents();
+    reversed_agents.reverse();
+    let reversed = create_orchestrator(42, reversed_agents).unwrap();
+
+    assert_eq!(balances(&forward), balances(&reversed));
+}
+
+#[test]
+fn test_invalid_distribution_is_rejected() {
+    let agents = vec![
+        make_agent(
+            "BANK_A",
+            Some(AmountDistribution::Pareto {
+                scale: 100_000,
+                shape: 0.0,
+            }),
+        ),
+        make_agent("BANK_B", None),
+    ];
+    assert!(matches!(
+        create_orchestrator(42, agents),
+        Err(SimulationError::InvalidConfig(_))
+    ));
+}
@@ -49,6 +49,7 @@ fn create_test_config(agent_id: &str, balance: i64, policy_json: &str) -> Orches
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             // Add BANK_B as receiver with simple FIFO policy
             AgentConfig {
@@ -66,6 +67,7 @@ fn create_test_config(agent_id: &str, balance: i64, policy_json: &str) -> Orches
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates {
@@ -74,10 +76,17 @@ fn create_test_config(agent_id: &str, balance: i64, policy_json: &str) -> Orches
             collateral_cost_per_tick_bps: 0.0002,
             eod_penalty: PenaltyMode::Fixed { amount: 10000 },
             deadline_penalty: PenaltyMode::Fixed { amount: 5000 },
+            deadline_penalty_per_tick_late: 0,
             split_friction_cost: 1000,
             overdue_delay_multiplier: 5.0, // Phase 3: Escalating delay cost for overdue
             priority_delay_multipliers: None, // Enhancement 11.1
             liquidity_cost_per_tick_bps: 0.0, // Enhancement 11.2
+            liquidity_cost_tiers: None,
+            overnight_deposit_rate: 0.0,
+            overnight_overdraft_rate: 0.0,
+            collateral_cost_basis: Default::default(),
+            collateral_post_fee: 0,
+            split_friction_exponent: 1.0,
         },
         lsm_config: LsmConfig::default(),
             scenario_events: None,
@@ -88,6 +97,7 @@ fn create_test_config(agent_id: &str, balance: i64, policy_json: &str) -> Orches
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     }
 }
 
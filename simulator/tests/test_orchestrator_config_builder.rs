@@ -0,0 +1,159 @@
+//! Tests for `OrchestratorConfigBuilder`
+//!
+//! The builder fills every optional field with its config-file default and
+//! validates the result, so callers only spell out what they care about.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::SimulationError;
+
+fn agent(id: &str) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_minimal_builder_produces_valid_config() {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(2)
+        .rng_seed(7)
+        .agent(agent("BANK_A"))
+        .agent(agent("BANK_B"))
+        .build()
+        .unwrap();
+
+    assert_eq!(config.ticks_per_day, 100);
+    assert_eq!(config.num_days, 2);
+    assert_eq!(config.rng_seed, 7);
+    assert_eq!(config.agent_configs.len(), 2);
+    assert_eq!(config.eod_rush_threshold, 0.8);
+    assert!(config.scenario_events.is_none());
+    assert!(!config.deferred_crediting);
+    assert!(!config.record_decision_paths);
+
+    let mut orch = Orchestrator::new(config).unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 100_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(
+        orch.state().get_agent("BANK_B").unwrap().balance(),
+        1_100_000
+    );
+}
+
+#[test]
+fn test_defaults_match_config_file_defaults() {
+    let built = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(1)
+        .agent_configs(vec![agent("BANK_A")])
+        .build()
+        .unwrap();
+
+    // Deserializing with every optional key omitted gives the same flags
+    let mut value = serde_json::to_value(&built).unwrap();
+    let object = value.as_object_mut().unwrap();
+    for key in [
+        "eod_rush_threshold",
+        "scenario_events",
+        "queue1_ordering",
+        "priority_mode",
+        "queue2_ordering",
+        "priority_escalation",
+        "algorithm_sequencing",
+        "entry_disposition_offsetting",
+        "entry_offset_min_amount",
+        "deferred_crediting",
+        "deadline_cap_at_eod",
+        "daily_liquidity_reallocation",
+        "record_decision_paths",
+    ] {
+        object.remove(key);
+    }
+    let parsed: OrchestratorConfig = serde_json::from_value(value).unwrap();
+    assert_eq!(
+        serde_json::to_value(&parsed).unwrap(),
+        serde_json::to_value(&built).unwrap()
+    );
+}
+
+#[test]
+fn test_setters_override_defaults() {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(50)
+        .num_days(1)
+        .rng_seed(3)
+        .agent(agent("BANK_A"))
+        .eod_rush_threshold(0.5)
+        .deferred_crediting(true)
+        .daily_liquidity_reallocation(false)
+        .entry_offset_min_amount(10_000)
+        .build()
+        .unwrap();
+
+    assert_eq!(config.eod_rush_threshold, 0.5);
+    assert!(config.deferred_crediting);
+    assert!(!config.daily_liquidity_reallocation);
+    assert_eq!(config.entry_offset_min_amount, 10_000);
+}
+
+#[test]
+fn test_omitting_agents_errors() {
+    let result = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .build();
+    assert!(matches!(result, Err(SimulationError::InvalidConfig(_))));
+}
+
+#[test]
+fn test_omitting_required_fields_errors() {
+    let missing_ticks = OrchestratorConfig::builder()
+        .num_days(1)
+        .rng_seed(42)
+        .agent(agent("BANK_A"))
+        .build();
+    match missing_ticks {
+        Err(SimulationError::InvalidConfig(msg)) => assert!(msg.contains("ticks_per_day")),
+        other => panic!("expected InvalidConfig, got {:?}", other),
+    }
+
+    let missing_seed = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .agent(agent("BANK_A"))
+        .build();
+    assert!(matches!(
+        missing_seed,
+        Err(SimulationError::InvalidConfig(_))
+    ));
+}
+
+#[test]
+fn test_build_runs_orchestrator_validation() {
+    let result = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(agent("BANK_A"))
+        .entry_offset_min_amount(-1)
+        .build();
+    assert!(matches!(result, Err(SimulationError::InvalidConfig(_))));
+}
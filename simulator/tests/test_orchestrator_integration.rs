@@ -31,6 +31,7 @@ fn create_two_agent_config() -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -48,6 +49,7 @@ fn create_two_agent_config() -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -60,6 +62,7 @@ fn create_two_agent_config() -> OrchestratorConfig {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     }
 }
 
@@ -346,6 +349,7 @@ fn test_orchestrator_lsm_bilateral_offset() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -360,6 +364,7 @@ fn test_orchestrator_lsm_bilateral_offset() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -372,6 +377,7 @@ fn test_orchestrator_lsm_bilateral_offset() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -567,6 +573,7 @@ fn test_orchestrator_automatic_arrivals() {
         deadline_range: (5, 15),
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -587,6 +594,7 @@ fn test_orchestrator_automatic_arrivals() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -601,6 +609,7 @@ fn test_orchestrator_automatic_arrivals() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -613,6 +622,7 @@ fn test_orchestrator_automatic_arrivals() {
         entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -643,6 +653,7 @@ fn test_orchestrator_arrival_determinism() {
         deadline_range: (5, 20),
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -663,6 +674,7 @@ fn test_orchestrator_arrival_determinism() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -677,6 +689,7 @@ fn test_orchestrator_arrival_determinism() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -689,6 +702,7 @@ fn test_orchestrator_arrival_determinism() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     // Run two simulations with same seed
@@ -730,6 +744,7 @@ fn test_orchestrator_weighted_counterparty_arrivals() {
         deadline_range: (5, 15),
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -749,6 +764,7 @@ fn test_orchestrator_weighted_counterparty_arrivals() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -763,6 +779,7 @@ fn test_orchestrator_weighted_counterparty_arrivals() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_C".to_string(),
@@ -777,6 +794,7 @@ fn test_orchestrator_weighted_counterparty_arrivals() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -789,6 +807,7 @@ fn test_orchestrator_weighted_counterparty_arrivals() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -847,6 +866,7 @@ fn test_orchestrator_arrivals_respect_amount_distribution() {
         deadline_range: (5, 15),
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -866,6 +886,7 @@ fn test_orchestrator_arrivals_respect_amount_distribution() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -880,6 +901,7 @@ fn test_orchestrator_arrivals_respect_amount_distribution() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -892,6 +914,7 @@ fn test_orchestrator_arrivals_respect_amount_distribution() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
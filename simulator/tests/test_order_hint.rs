@@ -0,0 +1,135 @@
+//! Tests for the `order_hint` tie-breaker under `Queue1Ordering::PriorityDeadline`
+//!
+//! The hint only orders transactions of equal priority (lower hint first);
+//! the 0-10 priority still decides the band. Policies set it with the
+//! `SetOrderHint` action.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::Transaction;
+
+/// Sets order_hint = -amount (larger payments first) and keeps everything in Queue 1
+const LARGEST_FIRST_JSON: &str = r#"{
+    "version": "1.0",
+    "policy_id": "largest_first",
+    "payment_tree": {
+        "type": "action",
+        "node_id": "A1",
+        "action": "SetOrderHint",
+        "parameters": {
+            "order_hint": {
+                "compute": {
+                    "op": "*",
+                    "left": {"field": "amount"},
+                    "right": {"value": -1}
+                }
+            }
+        }
+    },
+    "strategic_collateral_tree": null,
+    "end_of_tick_collateral_tree": null
+}"#;
+
+fn create_orchestrator() -> Orchestrator {
+    let make_agent = |id: &str, policy: PolicyConfig| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .queue1_ordering(Queue1Ordering::PriorityDeadline)
+        .agent(make_agent(
+            "BANK_A",
+            PolicyConfig::FromJson {
+                json: LARGEST_FIRST_JSON.to_string(),
+            },
+        ))
+        .agent(make_agent("BANK_B", PolicyConfig::Fifo))
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+fn queue_amounts(orch: &Orchestrator) -> Vec<i64> {
+    orch.get_agent_queue1_contents("BANK_A")
+        .iter()
+        .map(|id| orch.get_transaction(id).unwrap().amount())
+        .collect()
+}
+
+#[test]
+fn test_order_hint_builder_and_default() {
+    let tx = Transaction::new("A".to_string(), "B".to_string(), 1_000, 0, 10);
+    assert_eq!(tx.order_hint(), 0);
+
+    let tx = tx.with_order_hint(-7);
+    assert_eq!(tx.order_hint(), -7);
+}
+
+#[test]
+fn test_equal_priority_ordered_by_order_hint() {
+    let mut orch = create_orchestrator();
+
+    // Same priority and deadline: without hints these would stay in arrival order
+    orch.submit_transaction("BANK_A", "BANK_B", 1_000, 50, 5, false)
+        .unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 3_000, 50, 5, false)
+        .unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 2_000, 50, 5, false)
+        .unwrap();
+
+    // First tick sets the hints, the next one sorts by them
+    orch.tick().unwrap();
+    orch.tick().unwrap();
+
+    assert_eq!(queue_amounts(&orch), vec![3_000, 2_000, 1_000]);
+}
+
+#[test]
+fn test_order_hint_does_not_override_priority() {
+    let mut orch = create_orchestrator();
+
+    // The small payment has the worst hint but the higher priority
+    orch.submit_transaction("BANK_A", "BANK_B", 1_000, 50, 8, false)
+        .unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 2_000, 50, 5, false)
+        .unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 3_000, 50, 5, false)
+        .unwrap();
+
+    orch.tick().unwrap();
+    orch.tick().unwrap();
+
+    assert_eq!(queue_amounts(&orch), vec![1_000, 3_000, 2_000]);
+}
+
+#[test]
+fn test_order_hint_precedes_deadline_within_priority() {
+    let mut orch = create_orchestrator();
+
+    // Earlier deadline, but a worse (higher) hint
+    orch.submit_transaction("BANK_A", "BANK_B", 1_000, 20, 5, false)
+        .unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 2_000, 50, 5, false)
+        .unwrap();
+
+    orch.tick().unwrap();
+    orch.tick().unwrap();
+
+    assert_eq!(queue_amounts(&orch), vec![2_000, 1_000]);
+}
@@ -0,0 +1,111 @@
+//! Tests for listing overdue queued transactions with their lateness
+//!
+//! BANK_A holds everything in Queue 1; BANK_C has no liquidity, so its
+//! payments wait in Queue 2.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+
+/// Holds everything so transactions stay in Queue 1
+const HOLD_JSON: &str = r#"{
+    "version": "1.0",
+    "policy_id": "hold_policy",
+    "payment_tree": {"type": "action", "node_id": "A1", "action": "Hold"},
+    "strategic_collateral_tree": null,
+    "end_of_tick_collateral_tree": null
+}"#;
+
+fn agent(id: &str, opening_balance: i64, policy: PolicyConfig) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+fn orchestrator() -> Orchestrator {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(7)
+        .agent(agent(
+            "BANK_A",
+            1_000_000,
+            PolicyConfig::FromJson {
+                json: HOLD_JSON.to_string(),
+            },
+        ))
+        .agent(agent("BANK_B", 1_000_000, PolicyConfig::Fifo))
+        .agent(agent("BANK_C", 0, PolicyConfig::Fifo))
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+#[test]
+fn test_overdue_transactions_ordered_by_lateness() {
+    let mut orch = orchestrator();
+
+    let queue2_tx = orch
+        .submit_transaction("BANK_C", "BANK_B", 50_000, 5, 5, false)
+        .unwrap();
+    let queue1_tx = orch
+        .submit_transaction("BANK_A", "BANK_B", 50_000, 3, 5, false)
+        .unwrap();
+    // Not yet past its deadline
+    orch.submit_transaction("BANK_A", "BANK_B", 50_000, 50, 5, false)
+        .unwrap();
+
+    for _ in 0..8 {
+        orch.tick().unwrap();
+    }
+    assert_eq!(orch.current_tick(), 8);
+    assert!(orch
+        .get_agent_queue1_contents("BANK_A")
+        .contains(&queue1_tx));
+    assert!(orch.get_queue2_contents().contains(&queue2_tx));
+
+    assert_eq!(
+        orch.overdue_transactions(),
+        vec![(queue1_tx, 5), (queue2_tx, 3)]
+    );
+}
+
+#[test]
+fn test_deadline_tick_itself_is_not_overdue() {
+    let mut orch = orchestrator();
+
+    orch.submit_transaction("BANK_A", "BANK_B", 50_000, 4, 5, false)
+        .unwrap();
+    for _ in 0..4 {
+        orch.tick().unwrap();
+    }
+
+    assert_eq!(orch.current_tick(), 4);
+    assert!(orch.overdue_transactions().is_empty());
+}
+
+#[test]
+fn test_settled_transactions_are_not_listed() {
+    let mut orch = orchestrator();
+
+    // BANK_B releases immediately and settles
+    orch.submit_transaction("BANK_B", "BANK_A", 50_000, 2, 5, false)
+        .unwrap();
+    for _ in 0..5 {
+        orch.tick().unwrap();
+    }
+
+    assert!(orch.overdue_transactions().is_empty());
+}
@@ -0,0 +1,198 @@
+//! Tests for partial settlement of queued RTGS payments
+//!
+//! With `LsmConfig::partial_settlement` enabled, a Queue 2 payment whose sender
+//! holds some (but not enough) liquidity settles the affordable portion and
+//! leaves the remainder queued.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use payment_simulator_core_rs::settlement::{
+    process_queue, process_queue_with_options, submit_transaction, DeferredCredits,
+};
+use payment_simulator_core_rs::{Agent, Event, SimulationState, Transaction};
+
+/// BANK_A holds 60% of a 1,000,000 payment to BANK_B; the payment is queued
+fn setup_queued_state() -> (SimulationState, String) {
+    let agents = vec![
+        Agent::new("BANK_A".to_string(), 600_000),
+        Agent::new("BANK_B".to_string(), 0),
+    ];
+    let mut state = SimulationState::new(agents);
+
+    let tx = Transaction::new("BANK_A".to_string(), "BANK_B".to_string(), 1_000_000, 0, 100);
+    let tx_id = tx.id().to_string();
+    submit_transaction(&mut state, tx, 1).unwrap();
+    assert_eq!(state.queue_size(), 1);
+
+    (state, tx_id)
+}
+
+fn create_orchestrator_config(partial_settlement: bool) -> OrchestratorConfig {
+    let make_agent = |id: &str, opening_balance: i64| AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![make_agent("BANK_A", 600_000), make_agent("BANK_B", 0)],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig {
+            partial_settlement,
+            ..LsmConfig::default()
+        },
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_partial_settlement_settles_affordable_portion() {
+    let (mut state, tx_id) = setup_queued_state();
+
+    let result = process_queue_with_options(&mut state, 2, None, true);
+
+    assert_eq!(result.settled_count, 0, "Payment is not fully settled");
+    assert_eq!(result.settled_value, 600_000);
+    assert_eq!(result.partial_settlements.len(), 1);
+    assert_eq!(result.partial_settlements[0].amount, 600_000);
+    assert_eq!(result.partial_settlements[0].remaining_amount, 400_000);
+
+    let tx = state.get_transaction(&tx_id).unwrap();
+    assert_eq!(tx.settled_amount(), 600_000);
+    assert_eq!(tx.remaining_amount(), 400_000);
+    assert!(!tx.is_fully_settled());
+
+    // Remainder stays queued
+    assert_eq!(state.queue_size(), 1);
+    assert_eq!(state.rtgs_queue()[0], tx_id);
+
+    // Debit and credit applied together for the chunk
+    assert_eq!(state.get_agent("BANK_A").unwrap().balance(), 0);
+    assert_eq!(state.get_agent("BANK_B").unwrap().balance(), 600_000);
+    assert_eq!(state.total_balance(), 600_000);
+}
+
+#[test]
+fn test_partial_remainder_settles_when_liquidity_arrives() {
+    let (mut state, tx_id) = setup_queued_state();
+    process_queue_with_options(&mut state, 2, None, true);
+
+    state.get_agent_mut("BANK_A").unwrap().credit(400_000);
+    let result = process_queue_with_options(&mut state, 3, None, true);
+
+    assert_eq!(result.settled_count, 1);
+    assert_eq!(result.settled_value, 400_000);
+    assert!(result.partial_settlements.is_empty());
+    assert!(state.get_transaction(&tx_id).unwrap().is_fully_settled());
+    assert_eq!(state.queue_size(), 0);
+    assert_eq!(state.get_agent("BANK_B").unwrap().balance(), 1_000_000);
+}
+
+#[test]
+fn test_partial_settlement_disabled_leaves_payment_untouched() {
+    let (mut state, tx_id) = setup_queued_state();
+
+    let result = process_queue(&mut state, 2);
+
+    assert_eq!(result.settled_value, 0);
+    assert!(result.partial_settlements.is_empty());
+    assert_eq!(state.get_transaction(&tx_id).unwrap().remaining_amount(), 1_000_000);
+    assert_eq!(state.get_agent("BANK_A").unwrap().balance(), 600_000);
+}
+
+#[test]
+fn test_partial_settlement_skipped_without_liquidity() {
+    let agents = vec![
+        Agent::new("BANK_A".to_string(), 0),
+        Agent::new("BANK_B".to_string(), 0),
+    ];
+    let mut state = SimulationState::new(agents);
+    let tx = Transaction::new("BANK_A".to_string(), "BANK_B".to_string(), 1_000_000, 0, 100);
+    submit_transaction(&mut state, tx, 1).unwrap();
+
+    let result = process_queue_with_options(&mut state, 2, None, true);
+
+    assert!(result.partial_settlements.is_empty());
+    assert_eq!(state.queue_size(), 1);
+}
+
+#[test]
+fn test_partial_settlement_with_deferred_credits() {
+    let (mut state, _tx_id) = setup_queued_state();
+    let mut deferred = DeferredCredits::new();
+
+    process_queue_with_options(&mut state, 2, Some(&mut deferred), true);
+
+    assert_eq!(state.get_agent("BANK_A").unwrap().balance(), 0);
+    assert_eq!(state.get_agent("BANK_B").unwrap().balance(), 0, "Credit is deferred");
+    assert_eq!(deferred.total_for_agent("BANK_B"), 600_000);
+}
+
+#[test]
+fn test_orchestrator_emits_partial_settlement_event() {
+    let mut orchestrator = Orchestrator::new(create_orchestrator_config(true)).unwrap();
+    let tx_id = orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 1_000_000, 50, 5, false)
+        .unwrap();
+
+    orchestrator.tick().unwrap();
+
+    let events = orchestrator.event_log().events_of_type("PartialSettlement");
+    assert_eq!(events.len(), 1);
+    match events[0] {
+        Event::PartialSettlement {
+            tx_id: event_tx_id,
+            settled_amount,
+            remaining_amount,
+            ..
+        } => {
+            assert_eq!(event_tx_id, &tx_id);
+            assert_eq!(*settled_amount, 600_000);
+            assert_eq!(*remaining_amount, 400_000);
+        }
+        other => panic!("Unexpected event: {:?}", other),
+    }
+
+    let tx = orchestrator.state().get_transaction(&tx_id).unwrap();
+    assert_eq!(tx.remaining_amount(), 400_000);
+    assert_eq!(orchestrator.state().queue_size(), 1);
+}
+
+#[test]
+fn test_orchestrator_without_partial_settlement_emits_no_event() {
+    let mut orchestrator = Orchestrator::new(create_orchestrator_config(false)).unwrap();
+    let tx_id = orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 1_000_000, 50, 5, false)
+        .unwrap();
+
+    orchestrator.tick().unwrap();
+
+    assert!(orchestrator.event_log().events_of_type("PartialSettlement").is_empty());
+    let tx = orchestrator.state().get_transaction(&tx_id).unwrap();
+    assert_eq!(tx.remaining_amount(), 1_000_000);
+}
@@ -0,0 +1,96 @@
+//! Tests for `DailyMetrics::peak_liquidity_requirement`
+//!
+//! The peak liquidity requirement is the largest shortfall of an agent's
+//! balance against its queued outbound payments (Queue 1 + Queue 2) at any
+//! tick. It exceeds `peak_overdraft` whenever payments wait for liquidity.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, DailyMetrics, Orchestrator, OrchestratorConfig, PolicyConfig,
+    Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+
+fn create_config(unsecured_cap: i64) -> OrchestratorConfig {
+    let make_agent = |id: &str| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 100_000,
+        unsecured_cap,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 10,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![make_agent("BANK_A"), make_agent("BANK_B")],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+fn day0_metrics<'a>(orchestrator: &'a Orchestrator, agent_id: &str) -> &'a DailyMetrics {
+    orchestrator
+        .get_daily_agent_metrics(0)
+        .into_iter()
+        .find(|m| m.agent_id == agent_id)
+        .expect("day 0 metrics should exist")
+}
+
+fn run_day(orchestrator: &mut Orchestrator) {
+    for _ in 0..10 {
+        orchestrator.tick().unwrap();
+    }
+}
+
+#[test]
+fn test_queued_payments_raise_requirement_above_peak_overdraft() {
+    let mut orchestrator = Orchestrator::new(create_config(50_000)).unwrap();
+
+    // Settles into a 20,000 overdraft
+    orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 120_000, 8, 5, false)
+        .unwrap();
+    // Exceeds the remaining 30,000 headroom, so it waits in Queue 2
+    orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 500_000, 8, 5, false)
+        .unwrap();
+    run_day(&mut orchestrator);
+
+    let metrics = day0_metrics(&orchestrator, "BANK_A");
+    assert_eq!(metrics.peak_overdraft, 20_000);
+    assert_eq!(metrics.peak_liquidity_requirement, 520_000);
+    assert!(metrics.peak_liquidity_requirement > metrics.peak_overdraft);
+}
+
+#[test]
+fn test_covered_payments_need_no_liquidity() {
+    let mut orchestrator = Orchestrator::new(create_config(0)).unwrap();
+    orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 80_000, 8, 5, false)
+        .unwrap();
+    run_day(&mut orchestrator);
+
+    let sender = day0_metrics(&orchestrator, "BANK_A");
+    assert_eq!(sender.peak_overdraft, 0);
+    assert_eq!(sender.peak_liquidity_requirement, 0);
+}
@@ -0,0 +1,111 @@
+//! Tests for inspecting scheduled scenario events before they run
+
+use payment_simulator_core_rs::events::{EventSchedule, ScenarioEvent, ScheduledEvent};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+
+fn agent(id: &str) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+/// Two days of 10 ticks: a one-time transfer at tick 7 and an injection
+/// every 6 ticks from tick 3
+fn orchestrator() -> Orchestrator {
+    let events = vec![
+        ScheduledEvent {
+            event: ScenarioEvent::DirectTransfer {
+                from_agent: "BANK_A".to_string(),
+                to_agent: "BANK_B".to_string(),
+                amount: 10_000,
+            },
+            schedule: EventSchedule::OneTime { tick: 7 },
+        },
+        ScheduledEvent {
+            event: ScenarioEvent::LiquidityInjection {
+                agent: "BANK_B".to_string(),
+                amount: 5_000,
+            },
+            schedule: EventSchedule::Repeating {
+                start_tick: 3,
+                interval: 6,
+            },
+        },
+    ];
+
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(10)
+        .num_days(2)
+        .rng_seed(1)
+        .agent(agent("BANK_A"))
+        .agent(agent("BANK_B"))
+        .scenario_events(events)
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+fn pending(list: &[(usize, &str)]) -> Vec<(usize, String)> {
+    list.iter()
+        .map(|&(tick, name)| (tick, name.to_string()))
+        .collect()
+}
+
+#[test]
+fn test_recurring_schedule_expanded_to_end_of_simulation() {
+    let orch = orchestrator();
+
+    assert_eq!(
+        orch.pending_scenario_events(0),
+        pending(&[
+            (3, "liquidity_injection"),
+            (7, "direct_transfer"),
+            (9, "liquidity_injection"),
+            (15, "liquidity_injection"),
+        ])
+    );
+}
+
+#[test]
+fn test_from_tick_filters_past_occurrences() {
+    let orch = orchestrator();
+
+    assert_eq!(
+        orch.pending_scenario_events(8),
+        pending(&[(9, "liquidity_injection"), (15, "liquidity_injection")])
+    );
+    assert_eq!(
+        orch.pending_scenario_events(9),
+        pending(&[(9, "liquidity_injection"), (15, "liquidity_injection")])
+    );
+    assert!(orch.pending_scenario_events(16).is_empty());
+}
+
+#[test]
+fn test_no_scenario_events_returns_empty() {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(10)
+        .num_days(1)
+        .rng_seed(1)
+        .agent(agent("BANK_A"))
+        .agent(agent("BANK_B"))
+        .build()
+        .unwrap();
+    let orch = Orchestrator::new(config).unwrap();
+
+    assert!(orch.pending_scenario_events(0).is_empty());
+}
@@ -0,0 +1,119 @@
+//! Tests for per-agent deferred crediting overrides
+//!
+//! Two payment chains run in the same tick: a funded bank pays an unfunded
+//! middle bank, which immediately forwards the amount. Only a middle bank
+//! with immediate crediting can recycle the incoming funds within the tick.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+
+fn agent(id: &str, opening_balance: i64, deferred_crediting: Option<bool>) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        deferred_crediting,
+        ..Default::default()
+    }
+}
+
+/// Chains A -> B -> C and X -> Y -> Z, with B and Y overriding the global flag
+fn orchestrator(global: bool, b_deferred: Option<bool>, y_deferred: Option<bool>) -> Orchestrator {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(3)
+        .deferred_crediting(global)
+        .agent(agent("BANK_A", 100_000, None))
+        .agent(agent("BANK_B", 0, b_deferred))
+        .agent(agent("BANK_C", 0, None))
+        .agent(agent("BANK_X", 100_000, None))
+        .agent(agent("BANK_Y", 0, y_deferred))
+        .agent(agent("BANK_Z", 0, None))
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+/// Submits both chains and runs one tick, returning (B -> C, Y -> Z) tx IDs
+fn run_chains(orch: &mut Orchestrator) -> (String, String) {
+    orch.submit_transaction("BANK_A", "BANK_B", 100_000, 50, 5, false)
+        .unwrap();
+    let b_to_c = orch
+        .submit_transaction("BANK_B", "BANK_C", 100_000, 50, 5, false)
+        .unwrap();
+    orch.submit_transaction("BANK_X", "BANK_Y", 100_000, 50, 5, false)
+        .unwrap();
+    let y_to_z = orch
+        .submit_transaction("BANK_Y", "BANK_Z", 100_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    (b_to_c, y_to_z)
+}
+
+fn is_settled(orch: &Orchestrator, tx_id: &str) -> bool {
+    orch.get_transaction(tx_id).unwrap().is_fully_settled()
+}
+
+#[test]
+fn test_only_immediate_receiver_recycles_within_tick() {
+    // Global immediate crediting; BANK_Y opts into deferred crediting
+    let mut orch = orchestrator(false, None, Some(true));
+    let (b_to_c, y_to_z) = run_chains(&mut orch);
+
+    assert!(
+        is_settled(&orch, &b_to_c),
+        "BANK_B recycles within the tick"
+    );
+    assert!(
+        !is_settled(&orch, &y_to_z),
+        "BANK_Y's credit arrives end of tick"
+    );
+    assert_eq!(orch.get_queue2_contents(), vec![y_to_z.clone()]);
+    assert_eq!(orch.get_agent_balance("BANK_Y"), Some(100_000));
+
+    let credited: Vec<_> = orch
+        .get_tick_events(0)
+        .iter()
+        .filter(|e| e.event_type() == "DeferredCreditApplied")
+        .filter_map(|e| e.agent_id().map(str::to_string))
+        .collect();
+    assert_eq!(credited, vec!["BANK_Y".to_string()]);
+
+    // The deferred credit funds the payment on the next tick
+    orch.tick().unwrap();
+    assert!(is_settled(&orch, &y_to_z));
+}
+
+#[test]
+fn test_immediate_override_under_global_deferred_crediting() {
+    // Global deferred crediting; BANK_B opts out
+    let mut orch = orchestrator(true, Some(false), None);
+    let (b_to_c, y_to_z) = run_chains(&mut orch);
+
+    assert!(is_settled(&orch, &b_to_c));
+    assert!(!is_settled(&orch, &y_to_z));
+}
+
+#[test]
+fn test_unset_overrides_follow_global_flag() {
+    let mut orch = orchestrator(false, None, None);
+    let (b_to_c, y_to_z) = run_chains(&mut orch);
+    assert!(is_settled(&orch, &b_to_c));
+    assert!(is_settled(&orch, &y_to_z));
+
+    let mut orch = orchestrator(true, None, None);
+    let (b_to_c, y_to_z) = run_chains(&mut orch);
+    assert!(!is_settled(&orch, &b_to_c));
+    assert!(!is_settled(&orch, &y_to_z));
+}
@@ -79,6 +79,7 @@ fn test_deadline_policy_submits_urgent_arrivals() {
         deadline_range: (2, 20), // Mix of urgent and non-urgent
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -100,6 +101,7 @@ fn test_deadline_policy_submits_urgent_arrivals() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -114,6 +116,7 @@ fn test_deadline_policy_submits_urgent_arrivals() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -126,6 +129,7 @@ fn test_deadline_policy_submits_urgent_arrivals() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -175,6 +179,7 @@ fn test_deadline_policy_holds_non_urgent_under_liquidity_pressure() {
         deadline_range: (3, 30), // Mix of very urgent and non-urgent
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -196,6 +201,7 @@ fn test_deadline_policy_holds_non_urgent_under_liquidity_pressure() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -210,6 +216,7 @@ fn test_deadline_policy_holds_non_urgent_under_liquidity_pressure() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -222,6 +229,7 @@ fn test_deadline_policy_holds_non_urgent_under_liquidity_pressure() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -271,6 +279,7 @@ fn test_deadline_policy_vs_fifo_comparison() {
         deadline_range: (5, 15), // Short deadlines to create pressure
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -294,6 +303,7 @@ fn test_deadline_policy_vs_fifo_comparison() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             // Agent B: FIFO Policy
             AgentConfig {
@@ -309,6 +319,7 @@ fn test_deadline_policy_vs_fifo_comparison() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             // Receiver bank
             AgentConfig {
@@ -324,6 +335,7 @@ fn test_deadline_policy_vs_fifo_comparison() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -336,6 +348,7 @@ fn test_deadline_policy_vs_fifo_comparison() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -390,6 +403,7 @@ fn test_deadline_policy_all_urgent_scenario() {
         deadline_range: (1, 3), // All very urgent (1-3 ticks)
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -411,6 +425,7 @@ fn test_deadline_policy_all_urgent_scenario() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -425,6 +440,7 @@ fn test_deadline_policy_all_urgent_scenario() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -437,6 +453,7 @@ fn test_deadline_policy_all_urgent_scenario() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -479,6 +496,7 @@ fn test_deadline_policy_deadline_cascade() {
         deadline_range: (10, 15), // Moderate deadlines
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -500,6 +518,7 @@ fn test_deadline_policy_deadline_cascade() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -514,6 +533,7 @@ fn test_deadline_policy_deadline_cascade() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -526,6 +546,7 @@ fn test_deadline_policy_deadline_cascade() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
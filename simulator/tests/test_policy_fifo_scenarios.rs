@@ -24,6 +24,7 @@ fn test_fifo_preserves_arrival_order() {
         deadline_range: (10, 50),
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -44,6 +45,7 @@ fn test_fifo_preserves_arrival_order() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -58,6 +60,7 @@ fn test_fifo_preserves_arrival_order() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -70,6 +73,7 @@ fn test_fifo_preserves_arrival_order() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -112,6 +116,7 @@ fn test_fifo_partial_submission() {
         deadline_range: (10, 30),
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -132,6 +137,7 @@ fn test_fifo_partial_submission() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -146,6 +152,7 @@ fn test_fifo_partial_submission() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -158,6 +165,7 @@ fn test_fifo_partial_submission() {
         entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -212,6 +220,7 @@ fn test_fifo_vs_deadline_under_pressure() {
         deadline_range: (5, 12), // Tight deadlines
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -233,6 +242,7 @@ fn test_fifo_vs_deadline_under_pressure() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             // Deadline agent (for comparison)
             AgentConfig {
@@ -250,6 +260,7 @@ fn test_fifo_vs_deadline_under_pressure() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             // Receiver
             AgentConfig {
@@ -265,6 +276,7 @@ fn test_fifo_vs_deadline_under_pressure() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -277,6 +289,7 @@ fn test_fifo_vs_deadline_under_pressure() {
         entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -28,6 +28,7 @@ fn test_orchestrator_loads_fifo_policy_from_json() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+            ..Default::default()
         }],
         cost_rates: CostRates::default(),
         lsm_config: LsmConfig::default(),
@@ -39,6 +40,7 @@ fn test_orchestrator_loads_fifo_policy_from_json() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     // Should successfully create orchestrator with JSON-loaded policy
@@ -78,6 +80,7 @@ fn test_orchestrator_loads_deadline_policy_from_json_with_default_params() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+            ..Default::default()
         }],
         cost_rates: CostRates::default(),
         lsm_config: LsmConfig::default(),
@@ -89,6 +92,7 @@ fn test_orchestrator_loads_deadline_policy_from_json_with_default_params() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).expect("Failed to create orchestrator");
@@ -131,6 +135,7 @@ fn test_orchestrator_loads_deadline_policy_with_custom_threshold() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+            ..Default::default()
         }],
         cost_rates: CostRates::default(),
         lsm_config: LsmConfig::default(),
@@ -142,6 +147,7 @@ fn test_orchestrator_loads_deadline_policy_with_custom_threshold() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).expect("Failed to create orchestrator");
@@ -185,6 +191,7 @@ fn test_orchestrator_loads_liquidity_aware_policy_from_json() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+            ..Default::default()
         }],
         cost_rates: CostRates::default(),
         lsm_config: LsmConfig::default(),
@@ -196,6 +203,7 @@ fn test_orchestrator_loads_liquidity_aware_policy_from_json() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).expect("Failed to create orchestrator");
@@ -253,6 +261,7 @@ fn test_multi_agent_different_json_policies() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -269,6 +278,7 @@ fn test_multi_agent_different_json_policies() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_C".to_string(),
@@ -286,6 +296,7 @@ fn test_multi_agent_different_json_policies() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -298,6 +309,7 @@ fn test_multi_agent_different_json_policies() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     // Should successfully create orchestrator with all three JSON policies
@@ -334,6 +346,7 @@ fn test_determinism_with_json_policies() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+            ..Default::default()
         }],
         cost_rates: CostRates::default(),
         lsm_config: LsmConfig::default(),
@@ -345,6 +358,7 @@ fn test_determinism_with_json_policies() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     // Run simulation 1
@@ -28,6 +28,7 @@ fn test_liquidity_aware_with_high_arrival_rate() {
         deadline_range: (10, 40), // Mix of urgent and non-urgent
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -51,6 +52,7 @@ fn test_liquidity_aware_with_high_arrival_rate() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -65,6 +67,7 @@ fn test_liquidity_aware_with_high_arrival_rate() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -77,6 +80,7 @@ fn test_liquidity_aware_with_high_arrival_rate() {
         entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -130,6 +134,7 @@ fn test_liquidity_aware_buffer_recovery() {
         deadline_range: (20, 50), // Non-urgent
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -153,6 +158,7 @@ fn test_liquidity_aware_buffer_recovery() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -167,6 +173,7 @@ fn test_liquidity_aware_buffer_recovery() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -179,6 +186,7 @@ fn test_liquidity_aware_buffer_recovery() {
         entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -255,6 +263,7 @@ fn test_liquidity_aware_credit_limit_interaction() {
         deadline_range: (10, 30),
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -278,6 +287,7 @@ fn test_liquidity_aware_credit_limit_interaction() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -292,6 +302,7 @@ fn test_liquidity_aware_credit_limit_interaction() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -304,6 +315,7 @@ fn test_liquidity_aware_credit_limit_interaction() {
         entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -373,6 +385,7 @@ fn test_liquidity_aware_urgency_threshold_tuning() {
         deadline_range: (3, 15), // Mix of deadlines
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     // Conservative (low urgency threshold = fewer overrides)
@@ -397,6 +410,7 @@ fn test_liquidity_aware_urgency_threshold_tuning() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -411,6 +425,7 @@ fn test_liquidity_aware_urgency_threshold_tuning() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -423,6 +438,7 @@ fn test_liquidity_aware_urgency_threshold_tuning() {
         entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     // Aggressive (high urgency threshold = more overrides)
@@ -447,6 +463,7 @@ fn test_liquidity_aware_urgency_threshold_tuning() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -461,6 +478,7 @@ fn test_liquidity_aware_urgency_threshold_tuning() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -473,6 +491,7 @@ fn test_liquidity_aware_urgency_threshold_tuning() {
         entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orch_conservative = Orchestrator::new(config_conservative).unwrap();
@@ -30,6 +30,7 @@ fn test_high_frequency_arrivals_single_agent() {
         deadline_range: (5, 20),
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -52,6 +53,7 @@ fn test_high_frequency_arrivals_single_agent() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -66,6 +68,7 @@ fn test_high_frequency_arrivals_single_agent() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -78,6 +81,7 @@ fn test_high_frequency_arrivals_single_agent() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -149,6 +153,7 @@ fn test_sustained_high_load_100_ticks() {
         deadline_range: (10, 40),
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -170,6 +175,7 @@ fn test_sustained_high_load_100_ticks() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -184,6 +190,7 @@ fn test_sustained_high_load_100_ticks() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -196,6 +203,7 @@ fn test_sustained_high_load_100_ticks() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -272,6 +280,7 @@ fn test_extreme_high_frequency_arrivals() {
         deadline_range: (5, 15),
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     let config = OrchestratorConfig {
@@ -291,6 +300,7 @@ fn test_extreme_high_frequency_arrivals() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "BANK_B".to_string(),
@@ -305,6 +315,7 @@ fn test_extreme_high_frequency_arrivals() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates::default(),
@@ -317,6 +328,7 @@ fn test_extreme_high_frequency_arrivals() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -382,6 +394,7 @@ fn test_50_agent_high_frequency_simulation() {
         deadline_range: (10, 30),
         priority_distribution: PriorityDistribution::Fixed { value: 0 },
         divisible: false,
+        ..Default::default()
     };
 
     // Create 50 agents
@@ -410,6 +423,7 @@ fn test_50_agent_high_frequency_simulation() {
             limits: None,
             liquidity_pool: None,
             liquidity_allocation_fraction: None,
+            ..Default::default()
         });
     }
 
@@ -428,6 +442,7 @@ fn test_50_agent_high_frequency_simulation() {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     };
 
     println!("Initializing 50-agent simulation...");
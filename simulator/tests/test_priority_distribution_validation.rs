@@ -0,0 +1,117 @@
+//! Tests for `ArrivalConfig::validate`
+//!
+//! Malformed priority distributions (mismatched categorical lengths,
+//! negative or all-zero weights, priorities outside 0..=10) are rejected
+//! with a descriptive error instead of falling back silently at sampling
+//! time. `Orchestrator::new` runs the same check.
+
+use payment_simulator_core_rs::arrivals::{
+    AmountDistribution, ArrivalConfig, PriorityDistribution,
+};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::SimulationError;
+use std::collections::HashMap;
+
+fn arrival_config(priority_distribution: PriorityDistribution) -> ArrivalConfig {
+    ArrivalConfig {
+        rate_per_tick: 1.0,
+        amount_distribution: AmountDistribution::Uniform {
+            min: 1_000,
+            max: 10_000,
+        },
+        counterparty_weights: HashMap::new(),
+        deadline_range: (5, 10),
+        priority_distribution,
+        divisible: false,
+        ..Default::default()
+    }
+}
+
+fn validation_error(priority_distribution: PriorityDistribution) -> String {
+    arrival_config(priority_distribution)
+        .validate()
+        .expect_err("distribution should be rejected")
+}
+
+fn categorical(values: Vec<u8>, weights: Vec<f64>) -> PriorityDistribution {
+    PriorityDistribution::Categorical { values, weights }
+}
+
+#[test]
+fn test_valid_distributions_pass() {
+    for distribution in [
+        PriorityDistribution::Fixed { value: 10 },
+        PriorityDistribution::Uniform { min: 0, max: 10 },
+        categorical(vec![1, 5, 9], vec![0.2, 0.0, 0.8]),
+    ] {
+        assert_eq!(arrival_config(distribution).validate(), Ok(()));
+    }
+}
+
+#[test]
+fn test_categorical_length_mismatch_rejected() {
+    let err = validation_error(categorical(vec![1, 5, 9], vec![0.5, 0.5]));
+    assert!(err.contains("equal length"), "{}", err);
+}
+
+#[test]
+fn test_negative_weight_rejected() {
+    let err = validation_error(categorical(vec![1, 5], vec![1.5, -0.5]));
+    assert!(err.contains("non-negative"), "{}", err);
+}
+
+#[test]
+fn test_zero_weight_sum_rejected() {
+    let err = validation_error(categorical(vec![1, 5], vec![0.0, 0.0]));
+    assert!(err.contains("positive sum"), "{}", err);
+}
+
+#[test]
+fn test_fixed_priority_out_of_range_rejected() {
+    let err = validation_error(PriorityDistribution::Fixed { value: 11 });
+    assert!(err.contains("0..=10"), "{}", err);
+}
+
+#[test]
+fn test_uniform_priority_out_of_range_rejected() {
+    let err = validation_error(PriorityDistribution::Uniform { min: 5, max: 12 });
+    assert!(err.contains("0..=10"), "{}", err);
+
+    let err = validation_error(PriorityDistribution::Uniform { min: 8, max: 3 });
+    assert!(err.contains("min (8) must be <= max (3)"), "{}", err);
+}
+
+#[test]
+fn test_orchestrator_rejects_invalid_distribution() {
+    let result = OrchestratorConfig::builder()
+        .ticks_per_day(10)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(AgentConfig {
+            id: "BANK_A".to_string(),
+            opening_balance: 1_000_000,
+            unsecured_cap: 0,
+            policy: PolicyConfig::Fifo,
+            arrival_config: Some(arrival_config(categorical(vec![1, 5], vec![1.0]))),
+            arrival_bands: None,
+            posted_collateral: None,
+            collateral_haircut: None,
+            max_collateral_capacity: None,
+            limits: None,
+            liquidity_pool: None,
+            liquidity_allocation_fraction: None,
+            ..Default::default()
+        })
+        .build()
+        .and_then(Orchestrator::new);
+
+    match result {
+        Err(SimulationError::InvalidConfig(msg)) => {
+            assert!(msg.starts_with("Agent BANK_A:"), "{}", msg);
+            assert!(msg.contains("equal length"), "{}", msg);
+        }
+        other => panic!("expected InvalidConfig, got {:?}", other.map(|_| ())),
+    }
+}
@@ -0,0 +1,116 @@
+//! Tests for priority escalation curve shapes
+//!
+//! `PriorityEscalationConfig::curve` selects how the boost grows across the
+//! escalation window: "linear", "quadratic" or "exponential". Every curve
+//! reaches `max_boost` at the deadline; the non-linear ones rise later.
+
+use payment_simulator_core_rs::models::event::Event;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig, PriorityEscalationConfig,
+};
+use payment_simulator_core_rs::SimulationError;
+
+fn escalation(curve: &str) -> PriorityEscalationConfig {
+    PriorityEscalationConfig {
+        enabled: true,
+        curve: curve.to_string(),
+        start_escalating_at_ticks: 20,
+        max_boost: 8,
+    }
+}
+
+fn build_config(curve: &str) -> Result<OrchestratorConfig, SimulationError> {
+    let agent = |id: &str, opening_balance: i64| AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(agent("BANK_A", 0))
+        .agent(agent("BANK_B", 0))
+        .priority_escalation(escalation(curve))
+        .build()
+}
+
+#[test]
+fn test_exponential_below_linear_at_midpoint() {
+    let linear = escalation("linear");
+    let exponential = escalation("exponential");
+
+    assert_eq!(linear.boost(10), 4);
+    assert!(exponential.boost(10) < linear.boost(10));
+}
+
+#[test]
+fn test_quadratic_between_exponential_and_linear_at_midpoint() {
+    let quadratic = escalation("quadratic");
+
+    assert_eq!(quadratic.boost(10), 2);
+    assert!(escalation("exponential").boost(10) <= quadratic.boost(10));
+}
+
+#[test]
+fn test_all_curves_reach_max_boost_at_deadline() {
+    for curve in PriorityEscalationConfig::CURVES {
+        let config = escalation(curve);
+        assert_eq!(config.boost(0), 8, "curve {}", curve);
+        assert_eq!(config.boost(20), 0, "curve {}", curve);
+        assert_eq!(config.boost(25), 0, "curve {}", curve);
+    }
+}
+
+#[test]
+fn test_unknown_curve_rejected() {
+    let result = build_config("logistic");
+    assert!(matches!(result, Err(SimulationError::InvalidConfig(msg)) if msg.contains("logistic")));
+}
+
+#[test]
+fn test_exponential_curve_in_simulation() {
+    let mut orch = Orchestrator::new(build_config("exponential").unwrap()).unwrap();
+
+    // BANK_A has no liquidity, so the payment waits in Queue 2 until its deadline
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 100_000, 20, 2, false)
+        .unwrap();
+    for _ in 0..=20 {
+        orch.tick().unwrap();
+    }
+
+    let boosts: Vec<(usize, u8)> = orch
+        .event_log()
+        .events()
+        .iter()
+        .filter_map(|event| match event {
+            Event::PriorityEscalated {
+                tx_id: id,
+                ticks_until_deadline,
+                boost_applied,
+                ..
+            } if *id == tx_id => Some((*ticks_until_deadline, *boost_applied)),
+            _ => None,
+        })
+        .collect();
+
+    // Up to the midpoint the boost stays below the linear curve's 4,
+    // and the full boost is applied at the deadline
+    assert!(boosts
+        .iter()
+        .all(|(ticks, boost)| *ticks < 10 || *boost < 4));
+    assert_eq!(boosts.last(), Some(&(0, 8)));
+    assert_eq!(orch.get_transaction(&tx_id).unwrap().priority(), 10);
+}
@@ -0,0 +1,109 @@
+//! Tests for provisional balances under deferred crediting
+//!
+//! Deferred credits raise the receiver's `provisional_balance` as soon as a
+//! payment settles, but only reach `balance` at end of tick. BANK_B's
+//! end-of-tick collateral tree runs before the credits are promoted and posts
+//! `provisional_balance - balance` as collateral, exposing the mid-tick gap.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+
+/// Posts the provisional surplus as collateral at end of tick, if any
+const POST_PROVISIONAL_SURPLUS_POLICY: &str = r#"{
+    "version": "1.0",
+    "policy_id": "post_provisional_surplus",
+    "payment_tree": {"node_id": "P1", "type": "action", "action": "Release"},
+    "strategic_collateral_tree": null,
+    "end_of_tick_collateral_tree": {
+        "node_id": "E1",
+        "type": "condition",
+        "condition": {
+            "op": ">",
+            "left": {"field": "provisional_balance"},
+            "right": {"field": "balance"}
+        },
+        "on_true": {
+            "node_id": "E2",
+            "type": "action",
+            "action": "PostCollateral",
+            "parameters": {
+                "amount": {
+                    "compute": {
+                        "op": "-",
+                        "left": {"field": "provisional_balance"},
+                        "right": {"field": "balance"}
+                    }
+                },
+                "reason": {"value": "EndOfDayPreparation"}
+            }
+        },
+        "on_false": {"node_id": "E3", "type": "action", "action": "HoldCollateral"}
+    }
+}"#;
+
+fn make_agent(id: &str, opening_balance: i64, policy: PolicyConfig) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: Some(1_000_000),
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+fn create_orchestrator(deferred_crediting: bool) -> Orchestrator {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .deferred_crediting(deferred_crediting)
+        .agent(make_agent("BANK_A", 500_000, PolicyConfig::Fifo))
+        .agent(make_agent(
+            "BANK_B",
+            200_000,
+            PolicyConfig::FromJson {
+                json: POST_PROVISIONAL_SURPLUS_POLICY.to_string(),
+            },
+        ))
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+#[test]
+fn test_provisional_balance_rises_mid_tick_and_promotes_at_end_of_tick() {
+    let mut orch = create_orchestrator(true);
+    orch.submit_transaction("BANK_A", "BANK_B", 75_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+
+    // The end-of-tick tree saw provisional_balance 75k above balance
+    assert_eq!(orch.get_agent_collateral_posted("BANK_B"), Some(75_000));
+
+    // After the tick the credit has been promoted into the final balance
+    let agent = orch.state().get_agent("BANK_B").unwrap();
+    assert_eq!(agent.balance(), 275_000);
+    assert_eq!(agent.provisional_balance(), 275_000);
+}
+
+#[test]
+fn test_provisional_balance_matches_balance_with_immediate_crediting() {
+    let mut orch = create_orchestrator(false);
+    orch.submit_transaction("BANK_A", "BANK_B", 75_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+
+    assert_eq!(orch.get_agent_collateral_posted("BANK_B"), Some(0));
+    let agent = orch.state().get_agent("BANK_B").unwrap();
+    assert_eq!(agent.balance(), 275_000);
+    assert_eq!(agent.provisional_balance(), 275_000);
+}
@@ -0,0 +1,100 @@
+//! Tests for deterministic tie-breaking under `Queue1Ordering::PriorityDeadline`
+//!
+//! Queue 1 is ordered by priority (descending), deadline (ascending), then
+//! arrival tick (ascending); remaining ties keep their queue order.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+
+/// Holds everything so transactions stay in Queue 1
+const HOLD_JSON: &str = r#"{
+    "version": "1.0",
+    "policy_id": "hold_policy",
+    "payment_tree": {"type": "action", "node_id": "A1", "action": "Hold"},
+    "strategic_collateral_tree": null,
+    "end_of_tick_collateral_tree": null
+}"#;
+
+fn create_orchestrator() -> Orchestrator {
+    let make_agent = |id: &str, policy: PolicyConfig| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .queue1_ordering(Queue1Ordering::PriorityDeadline)
+        .agent(make_agent(
+            "BANK_A",
+            PolicyConfig::FromJson {
+                json: HOLD_JSON.to_string(),
+            },
+        ))
+        .agent(make_agent("BANK_B", PolicyConfig::Fifo))
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+/// Submits (amount, deadline, priority) batches over three ticks and
+/// returns BANK_A's Queue 1 as amounts (IDs are random per run)
+fn queue_order_by_amount() -> Vec<i64> {
+    let batches: [&[(i64, usize, u8)]; 3] = [
+        &[(1_001, 50, 5), (1_002, 50, 5), (1_003, 40, 5)],
+        &[(1_004, 50, 5), (1_005, 50, 8), (1_006, 50, 5)],
+        &[(1_007, 50, 5), (1_008, 40, 5), (1_009, 50, 8)],
+    ];
+
+    let mut orch = create_orchestrator();
+    for batch in batches {
+        for &(amount, deadline, priority) in batch {
+            orch.submit_transaction("BANK_A", "BANK_B", amount, deadline, priority, false)
+                .unwrap();
+        }
+        orch.tick().unwrap();
+    }
+    // One more tick so the final batch is sorted too
+    orch.tick().unwrap();
+
+    orch.get_agent_queue1_contents("BANK_A")
+        .iter()
+        .map(|id| orch.get_transaction(id).unwrap().amount())
+        .collect()
+}
+
+#[test]
+fn test_equal_priority_and_deadline_ordered_by_arrival() {
+    // Priority 8, deadline 50: arrivals at ticks 1, 2
+    let high_priority = [1_005, 1_009];
+    // Priority 5, deadline 40: arrivals at ticks 0, 2
+    let early_deadline = [1_003, 1_008];
+    // Priority 5, deadline 50: arrivals at ticks 0, 0, 1, 1, 2
+    let rest = [1_001, 1_002, 1_004, 1_006, 1_007];
+
+    assert_eq!(
+        queue_order_by_amount(),
+        [&high_priority[..], &early_deadline, &rest].concat()
+    );
+}
+
+#[test]
+fn test_tie_order_reproducible_across_runs() {
+    let first = queue_order_by_amount();
+    for _ in 0..5 {
+        assert_eq!(queue_order_by_amount(), first);
+    }
+}
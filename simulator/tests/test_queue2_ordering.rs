@@ -0,0 +1,155 @@
+//! Tests for configurable Queue 2 processing order
+//!
+//! `Queue2Ordering` sorts the RTGS queue before each retry pass. `Fifo` keeps
+//! insertion order; the other orderings use a stable sort.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+    Queue2Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use payment_simulator_core_rs::settlement::{process_queue_ordered, sort_queue, submit_transaction};
+use payment_simulator_core_rs::{Agent, SimulationState, Transaction};
+
+/// BANK_A has no liquidity, so every submitted payment is queued
+fn setup_state(payments: &[(i64, usize)]) -> (SimulationState, Vec<String>) {
+    let agents = vec![
+        Agent::new("BANK_A".to_string(), 0),
+        Agent::new("BANK_B".to_string(), 0),
+    ];
+    let mut state = SimulationState::new(agents);
+
+    let mut tx_ids = Vec::new();
+    for &(amount, deadline) in payments {
+        let tx = Transaction::new("BANK_A".to_string(), "BANK_B".to_string(), amount, 0, deadline);
+        tx_ids.push(tx.id().to_string());
+        submit_transaction(&mut state, tx, 1).unwrap();
+    }
+    assert_eq!(state.queue_size(), payments.len());
+
+    (state, tx_ids)
+}
+
+fn create_orchestrator_config(queue2_ordering: Queue2Ordering) -> OrchestratorConfig {
+    let make_agent = |id: &str, opening_balance: i64| AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![
+            make_agent("BANK_A", 0),
+            make_agent("BANK_B", 100_000),
+            make_agent("BANK_C", 0),
+        ],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        queue2_ordering,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_fifo_preserves_insertion_order() {
+    let (mut state, tx_ids) = setup_state(&[(30_000, 50), (90_000, 10), (60_000, 30)]);
+
+    sort_queue(&mut state, Queue2Ordering::Fifo);
+
+    assert_eq!(state.rtgs_queue(), &tx_ids);
+}
+
+#[test]
+fn test_largest_first_settles_large_payment_before_earlier_small_one() {
+    let (mut state, tx_ids) = setup_state(&[(40_000, 50), (100_000, 50)]);
+    state.get_agent_mut("BANK_A").unwrap().credit(100_000);
+
+    let result = process_queue_ordered(&mut state, 2, None, false, Queue2Ordering::LargestFirst);
+
+    assert_eq!(result.settled_count, 1);
+    assert!(state.get_transaction(&tx_ids[1]).unwrap().is_fully_settled());
+    assert!(!state.get_transaction(&tx_ids[0]).unwrap().is_fully_settled());
+    assert_eq!(state.rtgs_queue(), &vec![tx_ids[0].clone()]);
+}
+
+#[test]
+fn test_smallest_first_orders_by_amount_ascending() {
+    let (mut state, tx_ids) = setup_state(&[(30_000, 50), (90_000, 10), (10_000, 30)]);
+
+    sort_queue(&mut state, Queue2Ordering::SmallestFirst);
+
+    assert_eq!(
+        state.rtgs_queue(),
+        &vec![tx_ids[2].clone(), tx_ids[0].clone(), tx_ids[1].clone()]
+    );
+}
+
+#[test]
+fn test_soonest_deadline_orders_by_deadline_tick() {
+    let (mut state, tx_ids) =
+        setup_state(&[(30_000, 50), (90_000, 10), (60_000, 30), (20_000, 10)]);
+
+    sort_queue(&mut state, Queue2Ordering::SoonestDeadline);
+
+    // Ties on deadline keep insertion order
+    assert_eq!(
+        state.rtgs_queue(),
+        &vec![
+            tx_ids[1].clone(),
+            tx_ids[3].clone(),
+            tx_ids[2].clone(),
+            tx_ids[0].clone()
+        ]
+    );
+}
+
+#[test]
+fn test_orchestrator_applies_configured_queue2_ordering() {
+    let run = |ordering: Queue2Ordering| {
+        let mut orchestrator = Orchestrator::new(create_orchestrator_config(ordering)).unwrap();
+
+        // BANK_A has no liquidity: both payments queue in Queue 2
+        let small = orchestrator
+            .submit_transaction("BANK_A", "BANK_C", 40_000, 50, 5, false)
+            .unwrap();
+        let large = orchestrator
+            .submit_transaction("BANK_A", "BANK_C", 100_000, 50, 5, false)
+            .unwrap();
+        orchestrator.tick().unwrap();
+
+        // BANK_B funds BANK_A, then Queue 2 is retried in the same tick
+        orchestrator
+            .submit_transaction("BANK_B", "BANK_A", 100_000, 50, 5, false)
+            .unwrap();
+        orchestrator.tick().unwrap();
+
+        let settled = |id: &str| orchestrator.get_transaction(id).unwrap().is_fully_settled();
+        (settled(&small), settled(&large))
+    };
+
+    assert_eq!(run(Queue2Ordering::Fifo), (true, false));
+    assert_eq!(run(Queue2Ordering::LargestFirst), (false, true));
+}
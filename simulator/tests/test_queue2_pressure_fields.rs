@@ -0,0 +1,191 @@
+//! Tests for the `queue2_total_size` / `system_agents_in_overdraft` policy
+//! context fields
+//!
+//! Both are system-wide fields: every agent sees the same value for a given
+//! simulation state.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::policy::tree::EvalContext;
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use payment_simulator_core_rs::Transaction;
+
+/// Holds payments while Queue 2 holds more than `max_queue2` transactions
+fn congestion_policy(max_queue2: usize) -> String {
+    format!(
+        r#"{{
+        "version": "1.0",
+        "policy_id": "queue2_congestion",
+        "parameters": {{"max_queue2": {}}},
+        "payment_tree": {{
+            "type": "condition",
+            "node_id": "N1",
+            "condition": {{
+                "op": ">",
+                "left": {{"field": "queue2_total_size"}},
+                "right": {{"param": "max_queue2"}}
+            }},
+            "on_true": {{"type": "action", "node_id": "A1", "action": "Hold"}},
+            "on_false": {{"type": "action", "node_id": "A2", "action": "Release"}}
+        }},
+        "strategic_collateral_tree": null,
+        "end_of_tick_collateral_tree": null
+    }}"#,
+        max_queue2
+    )
+}
+
+fn make_agent(
+    id: &str,
+    opening_balance: i64,
+    unsecured_cap: i64,
+    policy: PolicyConfig,
+) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap,
+        policy,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+/// BANK_A runs the congestion policy; BANK_B has no liquidity, so its
+/// payments to BANK_C wait in Queue 2
+fn create_orchestrator(max_queue2: usize) -> Orchestrator {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .lsm_config(LsmConfig {
+            enable_bilateral: false,
+            enable_cycles: false,
+            ..LsmConfig::default()
+        })
+        .agent(make_agent(
+            "BANK_A",
+            1_000_000,
+            0,
+            PolicyConfig::FromJson {
+                json: congestion_policy(max_queue2),
+            },
+        ))
+        .agent(make_agent("BANK_B", 0, 0, PolicyConfig::Fifo))
+        .agent(make_agent("BANK_C", 0, 0, PolicyConfig::Fifo))
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+/// Fills Queue 2 with three unfunded BANK_B payments, then has BANK_A
+/// submit one payment of its own
+fn run_congested_scenario(orch: &mut Orchestrator) {
+    for _ in 0..3 {
+        orch.submit_transaction("BANK_B", "BANK_C", 10_000, 50, 5, false)
+            .unwrap();
+    }
+    orch.tick().unwrap();
+    assert_eq!(orch.get_queue2_size(), 3);
+
+    orch.submit_transaction("BANK_A", "BANK_C", 10_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+}
+
+/// Value of a context field as seen by a policy of `agent_id`
+fn context_field(orch: &Orchestrator, agent_id: &str, field: &str) -> f64 {
+    let state = orch.state();
+    let agent = state.get_agent(agent_id).unwrap();
+    let tx = Transaction::new(agent_id.to_string(), "BANK_C".to_string(), 1, 0, 50);
+    let context = EvalContext::build(
+        &tx,
+        agent,
+        state,
+        orch.current_tick(),
+        orch.cost_rates(),
+        100,
+        0.8,
+    );
+    context.get_field(field).unwrap()
+}
+
+#[test]
+fn test_policy_holds_when_queue2_exceeds_parameter() {
+    let mut orch = create_orchestrator(2);
+    run_congested_scenario(&mut orch);
+
+    assert_eq!(orch.get_agent_queue1_contents("BANK_A").len(), 1);
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(1_000_000));
+}
+
+#[test]
+fn test_policy_releases_when_queue2_within_parameter() {
+    let mut orch = create_orchestrator(3);
+    run_congested_scenario(&mut orch);
+
+    assert!(orch.get_agent_queue1_contents("BANK_A").is_empty());
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(990_000));
+}
+
+#[test]
+fn test_queue2_total_size_matches_rtgs_queue() {
+    let mut orch = create_orchestrator(10);
+    assert_eq!(context_field(&orch, "BANK_A", "queue2_total_size"), 0.0);
+
+    run_congested_scenario(&mut orch);
+    for agent_id in ["BANK_A", "BANK_B", "BANK_C"] {
+        assert_eq!(context_field(&orch, agent_id, "queue2_total_size"), 3.0);
+    }
+}
+
+#[test]
+fn test_system_agents_in_overdraft_counts_negative_balances() {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent("BANK_A", 0, 500_000, PolicyConfig::Fifo))
+        .agent(make_agent("BANK_B", 0, 500_000, PolicyConfig::Fifo))
+        .agent(make_agent("BANK_C", 0, 0, PolicyConfig::Fifo))
+        .build()
+        .unwrap();
+    let mut orch = Orchestrator::new(config).unwrap();
+    assert_eq!(
+        context_field(&orch, "BANK_C", "system_agents_in_overdraft"),
+        0.0
+    );
+
+    orch.submit_transaction("BANK_A", "BANK_C", 100_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(
+        context_field(&orch, "BANK_C", "system_agents_in_overdraft"),
+        1.0
+    );
+
+    orch.submit_transaction("BANK_B", "BANK_C", 100_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(
+        context_field(&orch, "BANK_A", "system_agents_in_overdraft"),
+        2.0
+    );
+
+    // Repaying brings BANK_A back to zero, which is not an overdraft
+    orch.submit_transaction("BANK_C", "BANK_A", 100_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(
+        context_field(&orch, "BANK_C", "system_agents_in_overdraft"),
+        1.0
+    );
+}
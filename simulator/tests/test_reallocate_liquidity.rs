@@ -0,0 +1,170 @@
+//! Tests for mid-simulation pool reallocation via `ScenarioEvent::ReallocateLiquidity`
+//!
+//! The event re-draws an agent's liquidity pool at a new fraction: allocated
+//! liquidity becomes `floor(pool × new_fraction)` and the balance moves by
+//! the change, so the liquidity opportunity cost follows the new allocation.
+
+use payment_simulator_core_rs::events::{EventSchedule, ScenarioEvent, ScheduledEvent};
+use payment_simulator_core_rs::models::event::Event;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use payment_simulator_core_rs::SimulationError;
+
+const POOL: i64 = 1_000_000;
+const OPENING_BALANCE: i64 = 100_000;
+
+fn create_config(events: Vec<ScheduledEvent>) -> OrchestratorConfig {
+    let make_agent = |id: &str, liquidity_pool: Option<i64>| AgentConfig {
+        id: id.to_string(),
+        opening_balance: OPENING_BALANCE,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool,
+        liquidity_allocation_fraction: liquidity_pool.map(|_| 0.5),
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 20,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![make_agent("BANK_A", Some(POOL)), make_agent("BANK_B", None)],
+        cost_rates: CostRates {
+            liquidity_cost_per_tick_bps: 10.0,
+            ..CostRates::default()
+        },
+        lsm_config: LsmConfig::default(),
+        scenario_events: Some(events),
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+fn reallocate(tick: usize, agent: &str, new_fraction: f64) -> ScheduledEvent {
+    ScheduledEvent {
+        event: ScenarioEvent::ReallocateLiquidity {
+            agent: agent.to_string(),
+            new_fraction,
+        },
+        schedule: EventSchedule::OneTime { tick },
+    }
+}
+
+/// Liquidity opportunity cost accrued by BANK_A at the given tick
+fn opportunity_cost_at(orch: &Orchestrator, tick: usize) -> i64 {
+    orch.get_tick_events(tick)
+        .into_iter()
+        .find_map(|event| match event {
+            Event::CostAccrual {
+                agent_id, costs, ..
+            } if agent_id == "BANK_A" => Some(costs.liquidity_opportunity_cost),
+            _ => None,
+        })
+        .unwrap_or(0)
+}
+
+#[test]
+fn test_raising_fraction_credits_delta_and_updates_cost_base() {
+    let mut orch = Orchestrator::new(create_config(vec![reallocate(5, "BANK_A", 1.0)])).unwrap();
+    assert_eq!(
+        orch.get_agent_balance("BANK_A"),
+        Some(OPENING_BALANCE + POOL / 2)
+    );
+
+    for _ in 0..7 {
+        orch.tick().unwrap();
+    }
+
+    // Balance rose by the extra half of the pool
+    assert_eq!(
+        orch.get_agent_balance("BANK_A"),
+        Some(OPENING_BALANCE + POOL)
+    );
+    assert_eq!(
+        orch.state()
+            .get_agent("BANK_A")
+            .unwrap()
+            .allocated_liquidity(),
+        POOL
+    );
+
+    // 10 bps/tick on 500,000 before, on 1,000,000 after
+    assert_eq!(opportunity_cost_at(&orch, 4), 500);
+    assert_eq!(opportunity_cost_at(&orch, 6), 1_000);
+
+    let event = orch
+        .get_tick_events(5)
+        .into_iter()
+        .find(|event| matches!(event, Event::LiquidityReallocated { .. }))
+        .expect("LiquidityReallocated event at tick 5");
+    assert_eq!(
+        *event,
+        Event::LiquidityReallocated {
+            tick: 5,
+            agent_id: "BANK_A".to_string(),
+            new_fraction: 1.0,
+            old_allocation: POOL / 2,
+            new_allocation: POOL,
+            balance_after: OPENING_BALANCE + POOL,
+        }
+    );
+}
+
+#[test]
+fn test_lowering_fraction_debits_delta() {
+    let mut orch = Orchestrator::new(create_config(vec![reallocate(2, "BANK_A", 0.25)])).unwrap();
+    for _ in 0..3 {
+        orch.tick().unwrap();
+    }
+
+    assert_eq!(
+        orch.get_agent_balance("BANK_A"),
+        Some(OPENING_BALANCE + POOL / 4)
+    );
+    assert_eq!(
+        orch.state()
+            .get_agent("BANK_A")
+            .unwrap()
+            .allocated_liquidity(),
+        POOL / 4
+    );
+}
+
+#[test]
+fn test_fraction_out_of_range_rejected() {
+    for fraction in [-0.1, 1.5] {
+        let result = Orchestrator::new(create_config(vec![reallocate(5, "BANK_A", fraction)]));
+        assert!(
+            matches!(result, Err(SimulationError::InvalidConfig(_))),
+            "fraction {} should be rejected",
+            fraction
+        );
+    }
+}
+
+#[test]
+fn test_agent_without_pool_rejected() {
+    let result = Orchestrator::new(create_config(vec![reallocate(5, "BANK_B", 0.5)]));
+    match result {
+        Err(SimulationError::InvalidConfig(msg)) => {
+            assert!(msg.contains("no liquidity_pool"), "{}", msg)
+        }
+        other => panic!("expected InvalidConfig, got {:?}", other.map(|_| ())),
+    }
+}
@@ -0,0 +1,125 @@
+//! Tests for `EventSchedule::Recurring`
+//!
+//! A recurring schedule fires every `interval` ticks from `start_tick` and,
+//! when bounded, stops after `end_tick` (inclusive).
+
+use payment_simulator_core_rs::events::{EventSchedule, ScenarioEvent, ScheduledEvent};
+use payment_simulator_core_rs::models::event::Event;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::SimulationError;
+
+fn rate_change(schedule: EventSchedule) -> ScheduledEvent {
+    ScheduledEvent {
+        event: ScenarioEvent::CostRateChange {
+            field: "overdraft_bps_per_tick".to_string(),
+            new_value: 2.0,
+        },
+        schedule,
+    }
+}
+
+fn build_config(events: Vec<ScheduledEvent>) -> Result<OrchestratorConfig, SimulationError> {
+    let make_agent = |id: &str| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(5)
+        .agent(make_agent("BANK_A"))
+        .agent(make_agent("BANK_B"))
+        .scenario_events(events)
+        .build()
+}
+
+/// Ticks at which a rate change was executed during the first `ticks` ticks
+fn rate_change_ticks(schedule: EventSchedule, ticks: usize) -> Vec<usize> {
+    let config = build_config(vec![rate_change(schedule)]).unwrap();
+    let mut orch = Orchestrator::new(config).unwrap();
+    for _ in 0..ticks {
+        orch.tick().unwrap();
+    }
+
+    orch.event_log()
+        .events_of_type("ScenarioEventExecuted")
+        .into_iter()
+        .filter_map(|e| match e {
+            Event::ScenarioEventExecuted {
+                tick, event_type, ..
+            } if event_type == "cost_rate_change" => Some(*tick),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_recurring_rate_change_stops_at_end_tick() {
+    let schedule = EventSchedule::Recurring {
+        start_tick: 5,
+        interval: 10,
+        end_tick: Some(35),
+    };
+
+    assert_eq!(rate_change_ticks(schedule, 60), vec![5, 15, 25, 35]);
+}
+
+#[test]
+fn test_unbounded_recurring_rate_change_fires_until_simulation_end() {
+    let schedule = EventSchedule::Recurring {
+        start_tick: 0,
+        interval: 10,
+        end_tick: None,
+    };
+
+    assert_eq!(
+        rate_change_ticks(schedule, 100),
+        vec![0, 10, 20, 30, 40, 50, 60, 70, 80, 90]
+    );
+}
+
+#[test]
+fn test_recurring_schedule_listed_by_pending_scenario_events() {
+    let config = build_config(vec![rate_change(EventSchedule::Recurring {
+        start_tick: 5,
+        interval: 10,
+        end_tick: Some(35),
+    })])
+    .unwrap();
+    let orch = Orchestrator::new(config).unwrap();
+
+    let ticks: Vec<usize> = orch
+        .pending_scenario_events(10)
+        .into_iter()
+        .map(|(tick, _)| tick)
+        .collect();
+    assert_eq!(ticks, vec![15, 25, 35]);
+}
+
+#[test]
+fn test_zero_interval_rejected() {
+    let result = build_config(vec![rate_change(EventSchedule::Recurring {
+        start_tick: 0,
+        interval: 0,
+        end_tick: None,
+    })]);
+
+    match result {
+        Err(SimulationError::InvalidConfig(msg)) => assert!(msg.contains("interval")),
+        other => panic!("Expected InvalidConfig, got {:?}", other.map(|_| ())),
+    }
+}
@@ -0,0 +1,177 @@
+//! Tests for `AgentConfig::reserve_requirement`
+//!
+//! The reserve is held back from the freely usable balance, so RTGS
+//! settlement and LSM offsets leave payments queued rather than dip into
+//! the reserve, unless overdraft capacity covers the shortfall.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::settlement::lsm::{bilateral_offset, detect_cycles, settle_cycle};
+use payment_simulator_core_rs::{Agent, SimulationError, SimulationState, Transaction};
+use std::collections::BTreeMap;
+
+fn make_agent(id: &str, opening_balance: i64, unsecured_cap: i64, reserve: i64) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        reserve_requirement: reserve,
+        ..Default::default()
+    }
+}
+
+/// State where BANK_A (100k, given reserve) owes a net 50k over `payments`
+fn lsm_state(reserve: i64, payments: &[(&str, &str, i64)]) -> SimulationState {
+    let mut bank_a = Agent::new("BANK_A".to_string(), 100_000);
+    bank_a.set_reserve_requirement(reserve);
+    let mut state = SimulationState::new(vec![
+        bank_a,
+        Agent::new("BANK_B".to_string(), 0),
+        Agent::new("BANK_C".to_string(), 0),
+    ]);
+    for (sender, receiver, amount) in payments {
+        let tx = Transaction::new(sender.to_string(), receiver.to_string(), *amount, 0, 100);
+        let tx_id = tx.id().to_string();
+        state.add_transaction(tx);
+        state.rtgs_queue_mut().push(tx_id);
+    }
+    state
+}
+
+/// BANK_A holds 100k with a 60k reserve; BANK_C has ample funds
+fn config(unsecured_cap: i64, reserve: i64) -> Result<OrchestratorConfig, SimulationError> {
+    OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent("BANK_A", 100_000, unsecured_cap, reserve))
+        .agent(make_agent("BANK_B", 0, 0, 0))
+        .agent(make_agent("BANK_C", 1_000_000, 0, 0))
+        .build()
+}
+
+#[test]
+fn test_reserve_holds_payment_until_inflow() {
+    let mut orch = Orchestrator::new(config(0, 60_000).unwrap()).unwrap();
+
+    // Affordable from the balance alone, but would breach the reserve
+    orch.submit_transaction("BANK_A", "BANK_B", 50_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(orch.get_queue2_size(), 1);
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(100_000));
+
+    // A large inflow lifts the balance well above the reserve
+    orch.submit_transaction("BANK_C", "BANK_A", 200_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(orch.get_queue2_size(), 0);
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(250_000));
+    assert_eq!(orch.get_agent_balance("BANK_B"), Some(50_000));
+}
+
+#[test]
+fn test_without_reserve_payment_settles_immediately() {
+    let mut orch = Orchestrator::new(config(0, 0).unwrap()).unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 50_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(orch.get_queue2_size(), 0);
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(50_000));
+}
+
+#[test]
+fn test_overdraft_capacity_can_cover_reserve_shortfall() {
+    // Usable: 40k above the reserve plus 30k of overdraft capacity
+    let mut orch = Orchestrator::new(config(30_000, 60_000).unwrap()).unwrap();
+    assert_eq!(
+        orch.state()
+            .get_agent("BANK_A")
+            .unwrap()
+            .available_liquidity(),
+        70_000
+    );
+
+    orch.submit_transaction("BANK_A", "BANK_B", 71_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(orch.get_queue2_size(), 1);
+
+    let mut orch = Orchestrator::new(config(30_000, 60_000).unwrap()).unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 70_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(orch.get_queue2_size(), 0);
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(30_000));
+}
+
+#[test]
+fn test_reserve_survives_checkpoint() {
+    let config = config(0, 60_000).unwrap();
+    let orch = Orchestrator::new(config.clone()).unwrap();
+    let restored = Orchestrator::load_state(config, &orch.save_state().unwrap()).unwrap();
+    assert_eq!(
+        restored
+            .state()
+            .get_agent("BANK_A")
+            .unwrap()
+            .reserve_requirement(),
+        60_000
+    );
+}
+
+#[test]
+fn test_negative_reserve_rejected() {
+    match config(0, -1) {
+        Err(SimulationError::InvalidConfig(msg)) => {
+            assert!(msg.contains("reserve_requirement"), "{}", msg)
+        }
+        other => panic!("expected InvalidConfig, got {:?}", other.err()),
+    }
+}
+
+#[test]
+fn test_bilateral_offset_respects_reserve() {
+    // A→B 150k against B→A 100k leaves A paying a net 50k
+    let payments = [("BANK_A", "BANK_B", 150_000), ("BANK_B", "BANK_A", 100_000)];
+
+    let mut state = lsm_state(0, &payments);
+    assert_eq!(bilateral_offset(&mut state, 1).settlements_count, 2);
+    assert_eq!(state.get_agent("BANK_A").unwrap().balance(), 50_000);
+
+    // With a 60k reserve only 40k is usable, so the offset must not run
+    let mut state = lsm_state(60_000, &payments);
+    assert_eq!(bilateral_offset(&mut state, 1).settlements_count, 0);
+    assert_eq!(state.rtgs_queue().len(), 2);
+    assert_eq!(state.get_agent("BANK_A").unwrap().balance(), 100_000);
+}
+
+#[test]
+fn test_cycle_settlement_respects_reserve() {
+    // A→B 150k, B→C 100k, C→A 100k: A pays a net 50k, B receives it
+    let payments = [
+        ("BANK_A", "BANK_B", 150_000),
+        ("BANK_B", "BANK_C", 100_000),
+        ("BANK_C", "BANK_A", 100_000),
+    ];
+
+    let mut state = lsm_state(0, &payments);
+    let cycle = detect_cycles(&state, 4).remove(0);
+    assert!(settle_cycle(&mut state, &cycle, 1, &mut BTreeMap::new()).is_ok());
+    assert_eq!(state.get_agent("BANK_A").unwrap().balance(), 50_000);
+
+    let mut state = lsm_state(60_000, &payments);
+    let cycle = detect_cycles(&state, 4).remove(0);
+    assert!(settle_cycle(&mut state, &cycle, 1, &mut BTreeMap::new()).is_err());
+    assert_eq!(state.get_agent("BANK_A").unwrap().balance(), 100_000);
+}
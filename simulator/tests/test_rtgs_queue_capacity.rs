@@ -0,0 +1,124 @@
+//! Tests for a bounded RTGS queue (`rtgs_queue_capacity`)
+//!
+//! When Queue 2 is full, a submission that cannot settle is rejected back
+//! to the sender's Queue 1 with an `RtgsQueueFull` event instead of being
+//! queued. The policy releases it again on a later tick.
+
+use payment_simulator_core_rs::models::event::Event;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use payment_simulator_core_rs::SimulationError;
+
+fn create_config(rtgs_queue_capacity: Option<usize>) -> OrchestratorConfig {
+    let make_agent = |id: &str, opening_balance: i64| AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 10,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![make_agent("BANK_A", 0), make_agent("BANK_B", 1_000_000)],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig {
+            enable_bilateral: false,
+            enable_cycles: false,
+            ..LsmConfig::default()
+        },
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        rtgs_queue_capacity,
+        ..Default::default()
+    }
+}
+
+fn queue_full_events(orch: &Orchestrator, tick: usize) -> Vec<String> {
+    orch.get_tick_events(tick)
+        .into_iter()
+        .filter_map(|event| match event {
+            Event::RtgsQueueFull { tx_id, .. } => Some(tx_id.clone()),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_third_submission_rejected_and_retried_next_tick() {
+    let mut orch = Orchestrator::new(create_config(Some(2))).unwrap();
+
+    // BANK_A has no liquidity, so none of these can settle on submission
+    let tx_ids: Vec<String> = (0..3)
+        .map(|_| {
+            orch.submit_transaction("BANK_A", "BANK_B", 100_000, 9, 5, false)
+                .unwrap()
+        })
+        .collect();
+
+    orch.tick().unwrap();
+
+    // First two fill Queue 2, the third is bounced back to Queue 1
+    assert_eq!(orch.get_queue2_contents(), tx_ids[..2].to_vec());
+    assert_eq!(
+        orch.get_agent_queue1_contents("BANK_A"),
+        vec![tx_ids[2].clone()]
+    );
+    assert_eq!(queue_full_events(&orch, 0), vec![tx_ids[2].clone()]);
+
+    // Tick 1: BANK_B pays BANK_A, which frees Queue 2 but only after
+    // BANK_A's retry has been bounced again
+    orch.submit_transaction("BANK_B", "BANK_A", 300_000, 9, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(queue_full_events(&orch, 1), vec![tx_ids[2].clone()]);
+    assert_eq!(orch.get_queue2_size(), 0);
+
+    // Tick 2: the retry goes through
+    orch.tick().unwrap();
+    assert!(queue_full_events(&orch, 2).is_empty());
+    assert!(orch.get_agent_queue1_contents("BANK_A").is_empty());
+    assert!(orch
+        .get_transaction(&tx_ids[2])
+        .is_some_and(|tx| tx.is_fully_settled()));
+}
+
+#[test]
+fn test_unbounded_by_default() {
+    let mut orch = Orchestrator::new(create_config(None)).unwrap();
+
+    for _ in 0..3 {
+        orch.submit_transaction("BANK_A", "BANK_B", 100_000, 9, 5, false)
+            .unwrap();
+    }
+    orch.tick().unwrap();
+
+    assert_eq!(orch.get_queue2_size(), 3);
+    assert!(queue_full_events(&orch, 0).is_empty());
+}
+
+#[test]
+fn test_zero_capacity_rejected() {
+    let result = Orchestrator::new(create_config(Some(0)));
+    assert!(matches!(result, Err(SimulationError::InvalidConfig(_))));
+}
@@ -901,6 +901,38 @@ fn test_partial_gridlock_resolution() {
     // by netting: A sends net 100k to B (500k - 400k)
 }
 
+#[test]
+fn test_submit_transaction_rejected_when_queue_full() {
+    use payment_simulator_core_rs::settlement::{submit_transaction, SubmissionResult};
+    use payment_simulator_core_rs::SimulationState;
+
+    // Setup: BANK_A has no liquidity, Queue 2 holds at most 2 transactions
+    let agents = vec![
+        create_test_agent("BANK_A", 0, 0),
+        create_test_agent("BANK_B", 0, 0),
+    ];
+    let mut state = SimulationState::new(agents);
+    state.set_rtgs_queue_capacity(Some(2));
+
+    for _ in 0..2 {
+        let tx = create_test_transaction("BANK_A", "BANK_B", 100_000, 0, 100);
+        let result = submit_transaction(&mut state, tx, 5);
+        assert!(matches!(result, Ok(SubmissionResult::Queued { .. })));
+    }
+
+    let tx = create_test_transaction("BANK_A", "BANK_B", 100_000, 0, 100);
+    let tx_id = tx.id().to_string();
+    let result = submit_transaction(&mut state, tx, 5);
+
+    assert_eq!(result, Ok(SubmissionResult::Rejected { capacity: 2 }));
+    assert_eq!(state.queue_size(), 2, "Queue 2 should not grow past capacity");
+    assert_eq!(
+        state.get_agent("BANK_A").unwrap().outgoing_queue(),
+        &[tx_id],
+        "Rejected transaction should be back in the sender's Queue 1"
+    );
+}
+
 // ============================================================================
 // Multiple Queue Processing Tests
 // ============================================================================
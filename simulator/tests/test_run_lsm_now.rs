@@ -0,0 +1,119 @@
+//! Tests for `Orchestrator::run_lsm_now`
+//!
+//! LSM normally runs inside the tick loop every `lsm_interval_ticks` ticks.
+//! `run_lsm_now` triggers a pass on demand against the current state.
+
+use payment_simulator_core_rs::models::event::Event;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+
+fn make_agent(id: &str) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance: 0,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+/// Unfunded banks whose in-loop LSM only runs on ticks 0, 50, ...
+fn create_orchestrator(algorithm_sequencing: bool) -> Orchestrator {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .lsm_config(LsmConfig {
+            lsm_interval_ticks: 50,
+            ..LsmConfig::default()
+        })
+        .algorithm_sequencing(algorithm_sequencing)
+        .agent(make_agent("BANK_A"))
+        .agent(make_agent("BANK_B"))
+        .build()
+        .unwrap();
+    let mut orch = Orchestrator::new(config).unwrap();
+
+    // Skip tick 0 so the queued pair below waits for the next LSM pass
+    orch.tick().unwrap();
+    orch
+}
+
+/// Queues an offsetable A <-> B pair in Queue 2 and returns the tx IDs
+fn queue_offsetable_pair(orch: &mut Orchestrator) -> (String, String) {
+    let a_to_b = orch
+        .submit_transaction("BANK_A", "BANK_B", 300_000, 60, 5, false)
+        .unwrap();
+    let b_to_a = orch
+        .submit_transaction("BANK_B", "BANK_A", 300_000, 60, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert_eq!(orch.get_queue2_contents().len(), 2);
+    (a_to_b, b_to_a)
+}
+
+#[test]
+fn test_run_lsm_now_settles_offsetable_queue() {
+    let mut orch = create_orchestrator(false);
+    let (a_to_b, b_to_a) = queue_offsetable_pair(&mut orch);
+
+    let result = orch.run_lsm_now();
+
+    assert!(result.total_settled_value > 0);
+    assert_eq!(result.bilateral_offsets, 1);
+    assert_eq!(result.final_queue_size, 0);
+    assert!(orch.get_queue2_contents().is_empty());
+    for tx_id in [&a_to_b, &b_to_a] {
+        assert!(orch.get_transaction(tx_id).unwrap().is_fully_settled());
+    }
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(0));
+    assert_eq!(orch.get_agent_balance("BANK_B"), Some(0));
+
+    // Logged at the current tick, like the in-loop pass
+    let offsets = orch
+        .get_tick_events(orch.current_tick())
+        .iter()
+        .filter(|e| matches!(e, Event::LsmBilateralOffset { .. }))
+        .count();
+    assert_eq!(offsets, 1);
+}
+
+#[test]
+fn test_run_lsm_now_emits_algorithm_events_when_sequencing() {
+    let mut orch = create_orchestrator(true);
+    queue_offsetable_pair(&mut orch);
+
+    orch.run_lsm_now();
+
+    let algorithms: Vec<u8> = orch
+        .get_tick_events(orch.current_tick())
+        .iter()
+        .filter_map(|e| match e {
+            Event::AlgorithmExecution { algorithm, .. } => Some(*algorithm),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(algorithms, vec![2, 3]);
+}
+
+#[test]
+fn test_run_lsm_now_with_empty_queue_settles_nothing() {
+    let mut orch = create_orchestrator(false);
+
+    let result = orch.run_lsm_now();
+
+    assert_eq!(result.total_settled_value, 0);
+    assert_eq!(result.bilateral_offsets, 0);
+    assert_eq!(result.cycles_settled, 0);
+    assert_eq!(result.final_queue_size, 0);
+}
@@ -0,0 +1,131 @@
+//! Tests for batch tick execution (run_ticks / run_to_end)
+//!
+//! Batch execution must be indistinguishable from calling tick() in a loop.
+
+use payment_simulator_core_rs::arrivals::{AmountDistribution, ArrivalConfig, PriorityDistribution};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use std::collections::HashMap;
+
+fn create_config(num_days: usize) -> OrchestratorConfig {
+    let make_agent = |id: &str, counterparty: &str| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 500_000,
+        unsecured_cap: 100_000,
+        policy: PolicyConfig::Fifo,
+        arrival_config: Some(ArrivalConfig {
+            rate_per_tick: 0.8,
+            amount_distribution: AmountDistribution::Uniform {
+                min: 10_000,
+                max: 80_000,
+            },
+            counterparty_weights: {
+                let mut weights = HashMap::new();
+                weights.insert(counterparty.to_string(), 1.0);
+                weights
+            },
+            deadline_range: (5, 30),
+            priority_distribution: PriorityDistribution::Fixed { value: 5 },
+            divisible: false,
+            ..Default::default()
+        }),
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 50,
+        eod_rush_threshold: 0.8,
+        num_days,
+        rng_seed: 8675309,
+        agent_configs: vec![make_agent("BANK_A", "BANK_B"), make_agent("BANK_B", "BANK_A")],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_run_ticks_matches_individual_ticks() {
+    let mut batched = Orchestrator::new(create_config(3)).unwrap();
+    let mut stepped = Orchestrator::new(create_config(3)).unwrap();
+
+    let batch_results = batched.run_ticks(100).unwrap();
+    let step_results: Vec<_> = (0..100).map(|_| stepped.tick().unwrap()).collect();
+
+    assert_eq!(batch_results.len(), 100);
+    for (a, b) in batch_results.iter().zip(&step_results) {
+        assert_eq!(a.tick, b.tick);
+        assert_eq!(a.num_arrivals, b.num_arrivals);
+        assert_eq!(a.num_settlements, b.num_settlements);
+        assert_eq!(a.num_lsm_releases, b.num_lsm_releases);
+        assert_eq!(a.total_cost, b.total_cost);
+    }
+
+    assert_eq!(batched.current_tick(), stepped.current_tick());
+    assert_eq!(batched.event_count(), stepped.event_count());
+    assert_eq!(batched.get_all_agent_balances(), stepped.get_all_agent_balances());
+    assert_eq!(batched.get_queue2_size(), stepped.get_queue2_size());
+    assert_eq!(batched.rng_manager().get_state(), stepped.rng_manager().get_state());
+
+    // Transaction IDs are UUIDs, so compare per-agent state rather than raw snapshots
+    for agent_id in batched.get_agent_ids() {
+        assert_eq!(batched.get_queue1_size(&agent_id), stepped.get_queue1_size(&agent_id));
+        assert_eq!(
+            batched.get_costs(&agent_id).unwrap().total(),
+            stepped.get_costs(&agent_id).unwrap().total()
+        );
+    }
+}
+
+#[test]
+fn test_run_ticks_zero_is_noop() {
+    let mut orch = Orchestrator::new(create_config(1)).unwrap();
+    let results = orch.run_ticks(0).unwrap();
+
+    assert!(results.is_empty());
+    assert_eq!(orch.current_tick(), 0);
+}
+
+#[test]
+fn test_run_to_end_stops_at_terminal_tick() {
+    let mut orch = Orchestrator::new(create_config(2)).unwrap();
+    orch.run_ticks(30).unwrap();
+
+    let results = orch.run_to_end().unwrap();
+
+    assert_eq!(results.len(), 70);
+    assert_eq!(results.last().unwrap().tick, 99);
+    assert_eq!(orch.current_tick(), 100);
+    assert!(orch.is_complete());
+}
+
+#[test]
+fn test_run_to_end_noop_when_complete() {
+    let mut orch = Orchestrator::new(create_config(1)).unwrap();
+    orch.run_to_end().unwrap();
+    let events_before = orch.event_count();
+
+    let results = orch.run_to_end().unwrap();
+
+    assert!(results.is_empty());
+    assert_eq!(orch.current_tick(), 50);
+    assert_eq!(orch.event_count(), events_before);
+}
@@ -0,0 +1,176 @@
+//! Tests for scheduled release of transactions with a future `target_tick`
+//!
+//! A policy `Release` with `timed_for_tick` in the future schedules the
+//! transaction. It stays in Queue 1, is not re-evaluated by the policy, and
+//! is moved to settlement automatically when the target tick arrives.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use payment_simulator_core_rs::Event;
+
+/// Always releases, timed for 5 ticks after the current tick. Without
+/// scheduled release tracking the target would keep moving forward.
+const RELEASE_IN_FIVE_TICKS: &str = r#"{
+    "version": "1.0",
+    "policy_id": "release_in_five_ticks",
+    "payment_tree": {
+        "type": "action",
+        "node_id": "A1_ReleaseTimed",
+        "action": "Release",
+        "parameters": {
+            "timed_for_tick": {
+                "compute": {
+                    "op": "+",
+                    "left": {"field": "current_tick"},
+                    "right": {"value": 5.0}
+                }
+            }
+        }
+    },
+    "parameters": {}
+}"#;
+
+fn create_config() -> OrchestratorConfig {
+    let make_agent = |id: &str, policy: PolicyConfig| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![
+            make_agent(
+                "BANK_A",
+                PolicyConfig::FromJson {
+                    json: RELEASE_IN_FIVE_TICKS.to_string(),
+                },
+            ),
+            make_agent("BANK_B", PolicyConfig::Fifo),
+        ],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_transaction_not_released_before_target_tick() {
+    let mut orchestrator = Orchestrator::new(create_config()).unwrap();
+    let tx_id = orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 50_000, 50, 5, false)
+        .unwrap();
+
+    for _ in 0..5 {
+        orchestrator.tick().unwrap();
+
+        assert_eq!(orchestrator.scheduled_release_tick(&tx_id), Some(5));
+        assert_eq!(orchestrator.get_queue1_size("BANK_A"), Some(1));
+        assert!(!orchestrator.get_transaction(&tx_id).unwrap().is_fully_settled());
+    }
+    assert!(orchestrator
+        .event_log()
+        .events_of_type("ScheduledReleaseTriggered")
+        .is_empty());
+}
+
+#[test]
+fn test_transaction_settles_at_exactly_target_tick() {
+    let mut orchestrator = Orchestrator::new(create_config()).unwrap();
+    let tx_id = orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 50_000, 50, 5, false)
+        .unwrap();
+
+    for _ in 0..=5 {
+        orchestrator.tick().unwrap();
+    }
+
+    let tx = orchestrator.get_transaction(&tx_id).unwrap();
+    assert!(tx.is_fully_settled());
+    assert_eq!(orchestrator.get_queue1_size("BANK_A"), Some(0));
+    assert_eq!(orchestrator.scheduled_release_tick(&tx_id), None);
+
+    let events = orchestrator.event_log().events_of_type("ScheduledReleaseTriggered");
+    assert_eq!(events.len(), 1);
+    match events[0] {
+        Event::ScheduledReleaseTriggered {
+            tick,
+            tx_id: event_tx_id,
+            scheduled_tick,
+            amount,
+            ..
+        } => {
+            assert_eq!(*tick, 5);
+            assert_eq!(*scheduled_tick, 5);
+            assert_eq!(*amount, 50_000);
+            assert_eq!(event_tx_id, &tx_id);
+        }
+        _ => panic!("Expected ScheduledReleaseTriggered event"),
+    }
+}
+
+#[test]
+fn test_scheduled_release_survives_checkpoint() {
+    let config = create_config();
+    let mut orchestrator = Orchestrator::new(config.clone()).unwrap();
+    let tx_id = orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 50_000, 50, 5, false)
+        .unwrap();
+    orchestrator.tick().unwrap();
+    orchestrator.tick().unwrap();
+
+    let saved = orchestrator.save_state().unwrap();
+    let mut restored = Orchestrator::load_state(config, &saved).unwrap();
+    assert_eq!(restored.scheduled_release_tick(&tx_id), Some(5));
+
+    for _ in 2..5 {
+        restored.tick().unwrap();
+        assert!(!restored.get_transaction(&tx_id).unwrap().is_fully_settled());
+    }
+    restored.tick().unwrap();
+    assert!(restored.get_transaction(&tx_id).unwrap().is_fully_settled());
+}
+
+#[test]
+fn test_scheduled_transaction_not_evaluated_while_waiting() {
+    let mut config = create_config();
+    config.record_decision_paths = true;
+    let mut orchestrator = Orchestrator::new(config).unwrap();
+    let tx_id = orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 50_000, 50, 5, false)
+        .unwrap();
+
+    for _ in 0..5 {
+        orchestrator.tick().unwrap();
+    }
+
+    // Evaluated once when scheduled, then withheld from the policy
+    assert!(orchestrator.get_decision_path(0, &tx_id).is_some());
+    for tick in 1..5 {
+        assert!(orchestrator.get_decision_path(tick, &tx_id).is_none());
+    }
+}
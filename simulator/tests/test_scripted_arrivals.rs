@@ -0,0 +1,194 @@
+//! Tests for `AgentConfig.scripted_arrivals`
+//!
+//! Scripted arrivals replay exact (tick, receiver, amount) rows for an agent
+//! instead of sampling them, and take precedence over its arrival config.
+
+use payment_simulator_core_rs::arrivals::{
+    AmountDistribution, ArrivalConfig, PriorityDistribution,
+};
+use payment_simulator_core_rs::models::event::Event;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig, ScriptedArrival,
+};
+use payment_simulator_core_rs::SimulationError;
+use std::collections::HashMap;
+
+fn scripted(
+    tick: usize,
+    receiver: &str,
+    amount: i64,
+    deadline_offset: usize,
+    priority: u8,
+) -> ScriptedArrival {
+    ScriptedArrival {
+        tick,
+        receiver: receiver.to_string(),
+        amount,
+        deadline_offset,
+        priority,
+    }
+}
+
+/// A busy Poisson process that would generate arrivals almost every tick
+fn busy_arrival_config() -> ArrivalConfig {
+    ArrivalConfig {
+        rate_per_tick: 5.0,
+        amount_distribution: AmountDistribution::Uniform {
+            min: 1_000,
+            max: 2_000,
+        },
+        counterparty_weights: HashMap::new(),
+        deadline_range: (10, 20),
+        priority_distribution: PriorityDistribution::Fixed { value: 5 },
+        divisible: false,
+        ..Default::default()
+    }
+}
+
+fn make_agent(
+    id: &str,
+    arrival_config: Option<ArrivalConfig>,
+    scripted_arrivals: Option<Vec<ScriptedArrival>>,
+) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        scripted_arrivals,
+        ..Default::default()
+    }
+}
+
+fn create_config(bank_a: AgentConfig) -> Result<OrchestratorConfig, SimulationError> {
+    OrchestratorConfig::builder()
+        .ticks_per_day(20)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(bank_a)
+        .agent(make_agent("BANK_B", None, None))
+        .agent(make_agent("BANK_C", None, None))
+        .build()
+}
+
+fn create_orchestrator(bank_a: AgentConfig) -> Result<Orchestrator, SimulationError> {
+    Orchestrator::new(create_config(bank_a)?)
+}
+
+/// (tick, sender, receiver, amount, deadline, priority) of every arrival
+fn arrivals(orch: &Orchestrator, ticks: usize) -> Vec<(usize, String, String, i64, usize, u8)> {
+    (0..ticks)
+        .flat_map(|tick| orch.get_tick_events(tick))
+        .filter_map(|e| match e {
+            Event::Arrival {
+                tick,
+                sender_id,
+                receiver_id,
+                amount,
+                deadline,
+                priority,
+                ..
+            } => Some((
+                *tick,
+                sender_id.clone(),
+                receiver_id.clone(),
+                *amount,
+                *deadline,
+                *priority,
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_scripted_arrivals_appear_at_exact_ticks_with_exact_amounts() {
+    let script = vec![
+        scripted(7, "BANK_C", 12_345, 4, 9),
+        scripted(2, "BANK_B", 50_000, 5, 5),
+        scripted(7, "BANK_B", 1_001, 10, 0),
+    ];
+    let mut orch = create_orchestrator(make_agent("BANK_A", None, Some(script))).unwrap();
+    for _ in 0..10 {
+        orch.tick().unwrap();
+    }
+
+    let a = "BANK_A".to_string();
+    assert_eq!(
+        arrivals(&orch, 10),
+        vec![
+            (2, a.clone(), "BANK_B".to_string(), 50_000, 7, 5),
+            (7, a.clone(), "BANK_C".to_string(), 12_345, 11, 9),
+            (7, a, "BANK_B".to_string(), 1_001, 17, 0),
+        ]
+    );
+}
+
+#[test]
+fn test_scripted_arrivals_bypass_the_arrival_generator() {
+    let bank_a = make_agent(
+        "BANK_A",
+        Some(busy_arrival_config()),
+        Some(vec![scripted(3, "BANK_B", 40_000, 5, 5)]),
+    );
+    let mut orch = create_orchestrator(bank_a).unwrap();
+    for _ in 0..10 {
+        orch.tick().unwrap();
+    }
+
+    let amounts: Vec<i64> = arrivals(&orch, 10).iter().map(|a| a.3).collect();
+    assert_eq!(amounts, vec![40_000]);
+}
+
+#[test]
+fn test_invalid_scripted_arrivals_are_rejected() {
+    let cases = [
+        scripted(1, "BANK_Z", 1_000, 5, 5),
+        scripted(1, "BANK_A", 1_000, 5, 5),
+        scripted(1, "BANK_B", 0, 5, 5),
+        scripted(1, "BANK_B", 1_000, 0, 5),
+        scripted(20, "BANK_B", 1_000, 5, 5),
+        scripted(1, "BANK_B", 1_000, 5, 11),
+    ];
+    for arrival in cases {
+        let result = create_orchestrator(make_agent("BANK_A", None, Some(vec![arrival.clone()])));
+        assert!(
+            matches!(result, Err(SimulationError::InvalidConfig(_))),
+            "{:?} should be rejected",
+            arrival
+        );
+    }
+}
+
+#[test]
+fn test_scripted_arrivals_resume_after_checkpoint() {
+    let script = vec![
+        scripted(2, "BANK_B", 50_000, 5, 5),
+        scripted(6, "BANK_C", 12_345, 4, 9),
+    ];
+    let config = create_config(make_agent("BANK_A", None, Some(script))).unwrap();
+    let mut orch = Orchestrator::new(config.clone()).unwrap();
+    for _ in 0..4 {
+        orch.tick().unwrap();
+    }
+
+    let saved = orch.save_state().unwrap();
+    let mut restored = Orchestrator::load_state(config, &saved).unwrap();
+    for _ in 4..10 {
+        restored.tick().unwrap();
+    }
+
+    // Only the arrival still ahead of the checkpoint is injected again
+    assert_eq!(
+        arrivals(&restored, 10),
+        vec![(6, "BANK_A".to_string(), "BANK_C".to_string(), 12_345, 10, 9)]
+    );
+}
@@ -0,0 +1,120 @@
+//! Tests for the settlement delay histogram
+//!
+//! Buckets are contiguous from 0 up to the maximum observed delay and count
+//! settled original (non-split) transactions by arrival-to-settlement delay.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+
+fn create_config() -> OrchestratorConfig {
+    let make_agent = |id: &str, opening_balance: i64| AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![
+            make_agent("BANK_A", 0),
+            make_agent("BANK_B", 100_000),
+            make_agent("BANK_C", 100_000),
+        ],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+/// Produces settled delays [0, 0, 0, 2, 5]
+///
+/// BANK_A has no liquidity, so its two payments wait in Queue 2 until
+/// BANK_B funds it at tick 2 (10,000) and tick 5 (20,000). The funding
+/// payments and BANK_C's payment settle on arrival.
+fn run_known_delays() -> Orchestrator {
+    let mut orchestrator = Orchestrator::new(create_config()).unwrap();
+
+    orchestrator.submit_transaction("BANK_A", "BANK_C", 10_000, 50, 5, false).unwrap();
+    orchestrator.submit_transaction("BANK_A", "BANK_C", 20_000, 50, 5, false).unwrap();
+    orchestrator.submit_transaction("BANK_C", "BANK_B", 5_000, 50, 5, false).unwrap();
+
+    for tick in 0..6 {
+        if tick == 2 {
+            orchestrator.submit_transaction("BANK_B", "BANK_A", 10_000, 50, 5, false).unwrap();
+        }
+        if tick == 5 {
+            orchestrator.submit_transaction("BANK_B", "BANK_A", 20_000, 50, 5, false).unwrap();
+        }
+        orchestrator.tick().unwrap();
+    }
+
+    assert_eq!(orchestrator.get_queue2_size(), 0, "all payments should settle");
+    orchestrator
+}
+
+#[test]
+fn test_unit_bucket_width_gives_exact_per_tick_counts() {
+    let orchestrator = run_known_delays();
+
+    assert_eq!(
+        orchestrator.settlement_delay_histogram(1),
+        vec![(0, 3), (1, 0), (2, 1), (3, 0), (4, 0), (5, 1)]
+    );
+}
+
+#[test]
+fn test_wider_buckets_are_contiguous_and_cover_max_delay() {
+    let orchestrator = run_known_delays();
+
+    assert_eq!(
+        orchestrator.settlement_delay_histogram(2),
+        vec![(0, 3), (2, 1), (4, 1)]
+    );
+    assert_eq!(orchestrator.settlement_delay_histogram(3), vec![(0, 4), (3, 1)]);
+    assert_eq!(orchestrator.settlement_delay_histogram(10), vec![(0, 5)]);
+}
+
+#[test]
+fn test_histogram_total_matches_settled_arrivals() {
+    let orchestrator = run_known_delays();
+
+    let total: usize = orchestrator
+        .settlement_delay_histogram(1)
+        .iter()
+        .map(|(_, count)| count)
+        .sum();
+    assert_eq!(total, orchestrator.calculate_system_metrics().total_settlements);
+}
+
+#[test]
+fn test_empty_histogram_without_settlements_or_width() {
+    let orchestrator = Orchestrator::new(create_config()).unwrap();
+    assert!(orchestrator.settlement_delay_histogram(1).is_empty());
+
+    let orchestrator = run_known_delays();
+    assert!(orchestrator.settlement_delay_histogram(0).is_empty());
+}
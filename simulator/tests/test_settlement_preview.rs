@@ -0,0 +1,134 @@
+//! Tests for `Orchestrator::preview_settlement`
+//!
+//! The preview simulates sequential RTGS settlement on copies of the agents,
+//! so it must predict the real outcome of releasing the same transactions in
+//! the same order while leaving state untouched.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+
+fn make_agent(id: &str, opening_balance: i64) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+fn create_orchestrator(deferred_crediting: bool) -> Orchestrator {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent("BANK_A", 500_000))
+        .agent(make_agent("BANK_B", 0))
+        .agent(make_agent("BANK_C", 0))
+        .deferred_crediting(deferred_crediting)
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+/// Settlement outcome of each transaction after one tick
+fn actual_outcomes(orch: &Orchestrator, tx_ids: &[String]) -> Vec<(String, bool)> {
+    tx_ids
+        .iter()
+        .map(|id| {
+            let settled = orch.get_transaction(id).unwrap().is_fully_settled();
+            (id.clone(), settled)
+        })
+        .collect()
+}
+
+#[test]
+fn test_preview_matches_actual_settlement() {
+    let mut orch = create_orchestrator(false);
+
+    // BANK_A releases in FIFO order before BANK_B, whose payment relies on
+    // the incoming 300k from BANK_A
+    let tx_ids = vec![
+        orch.submit_transaction("BANK_A", "BANK_B", 300_000, 50, 5, false)
+            .unwrap(),
+        orch.submit_transaction("BANK_A", "BANK_C", 150_000, 50, 5, false)
+            .unwrap(),
+        orch.submit_transaction("BANK_A", "BANK_C", 100_000, 50, 5, false)
+            .unwrap(),
+        orch.submit_transaction("BANK_B", "BANK_C", 250_000, 50, 5, false)
+            .unwrap(),
+    ];
+
+    let preview = orch.preview_settlement(&tx_ids);
+    let expected: Vec<bool> = vec![true, true, false, true];
+    assert_eq!(
+        preview.iter().map(|(_, ok)| *ok).collect::<Vec<_>>(),
+        expected
+    );
+
+    orch.tick().unwrap();
+    assert_eq!(actual_outcomes(&orch, &tx_ids), preview);
+}
+
+#[test]
+fn test_preview_respects_deferred_crediting() {
+    let mut orch = create_orchestrator(true);
+
+    let tx_ids = vec![
+        orch.submit_transaction("BANK_A", "BANK_B", 300_000, 50, 5, false)
+            .unwrap(),
+        orch.submit_transaction("BANK_B", "BANK_C", 250_000, 50, 5, false)
+            .unwrap(),
+    ];
+
+    // The incoming credit only lands at end of tick, so BANK_B cannot pay yet
+    let preview = orch.preview_settlement(&tx_ids);
+    assert!(preview[0].1);
+    assert!(!preview[1].1);
+
+    orch.tick().unwrap();
+    assert_eq!(actual_outcomes(&orch, &tx_ids), preview);
+}
+
+#[test]
+fn test_preview_does_not_mutate_state() {
+    let orch = {
+        let mut orch = create_orchestrator(false);
+        orch.submit_transaction("BANK_A", "BANK_B", 300_000, 50, 5, false)
+            .unwrap();
+        orch
+    };
+    let tx_ids: Vec<String> = orch.state().transactions().keys().cloned().collect();
+    let balances_before = orch.get_all_agent_balances();
+    let events_before = orch.event_count();
+
+    let preview = orch.preview_settlement(&tx_ids);
+    assert!(preview[0].1);
+
+    assert_eq!(orch.get_all_agent_balances(), balances_before);
+    assert_eq!(orch.event_count(), events_before);
+    assert!(!orch.get_transaction(&tx_ids[0]).unwrap().is_fully_settled());
+    assert_eq!(
+        orch.state()
+            .get_agent("BANK_A")
+            .unwrap()
+            .outgoing_queue_size(),
+        1
+    );
+}
+
+#[test]
+fn test_unknown_transaction_reported_as_not_settling() {
+    let orch = create_orchestrator(false);
+    let preview = orch.preview_settlement(&["missing".to_string()]);
+    assert_eq!(preview, vec![("missing".to_string(), false)]);
+}
@@ -0,0 +1,181 @@
+//! Tests for `max_settlement_retries`
+//!
+//! Every Queue 2 pass that fails to settle a transaction counts one retry.
+//! On reaching the limit the transaction leaves Queue 2, becomes
+//! `TransactionStatus::Returned` and `SettlementAbandoned` is logged. Without
+//! a limit, queued transactions retry forever.
+
+use payment_simulator_core_rs::models::{Event, TransactionStatus};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::SimulationError;
+
+const AMOUNT: i64 = 100_000;
+
+fn build_config(max_retries: Option<usize>) -> Result<OrchestratorConfig, SimulationError> {
+    let make_agent = |id: &str, opening_balance: i64| AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    let mut builder = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        // BANK_A has no liquidity and no credit: it can never pay on its own
+        .agent(make_agent("BANK_A", 0))
+        .agent(make_agent("BANK_B", 1_000_000));
+    if let Some(max_retries) = max_retries {
+        builder = builder.max_settlement_retries(max_retries);
+    }
+    builder.build()
+}
+
+fn abandoned_events(orch: &Orchestrator) -> Vec<Event> {
+    orch.event_log()
+        .events_of_type("SettlementAbandoned")
+        .into_iter()
+        .cloned()
+        .collect()
+}
+
+#[test]
+fn test_unpayable_transaction_abandoned_after_three_failed_ticks() {
+    let mut orch = Orchestrator::new(build_config(Some(3)).unwrap()).unwrap();
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", AMOUNT, 50, 5, false)
+        .unwrap();
+
+    // Ticks 0 and 1: two failed retries, still queued
+    for expected_retries in 1..=2 {
+        orch.tick().unwrap();
+        assert_eq!(orch.get_queue2_size(), 1);
+        assert_eq!(
+            orch.get_transaction(&tx_id).unwrap().retry_count(),
+            expected_retries
+        );
+        assert!(abandoned_events(&orch).is_empty());
+    }
+
+    // Tick 2: third failure reaches the limit
+    orch.tick().unwrap();
+    assert_eq!(orch.get_queue2_size(), 0);
+    let tx = orch.get_transaction(&tx_id).unwrap();
+    assert_eq!(tx.retry_count(), 3);
+    assert_eq!(tx.status(), &TransactionStatus::Returned { tick: 2 });
+    assert_eq!(tx.remaining_amount(), AMOUNT);
+
+    let events = abandoned_events(&orch);
+    assert_eq!(events.len(), 1);
+    match &events[0] {
+        Event::SettlementAbandoned {
+            tick,
+            tx_id: abandoned_id,
+            sender_id,
+            receiver_id,
+            remaining_amount,
+            retry_count,
+        } => {
+            assert_eq!(*tick, 2);
+            assert_eq!(abandoned_id, &tx_id);
+            assert_eq!(sender_id, "BANK_A");
+            assert_eq!(receiver_id, "BANK_B");
+            assert_eq!(*remaining_amount, AMOUNT);
+            assert_eq!(*retry_count, 3);
+        }
+        other => panic!("unexpected event {:?}", other),
+    }
+
+    // Returned transactions are gone for good, even once funds arrive
+    orch.submit_transaction("BANK_B", "BANK_A", 500_000, 50, 5, false)
+        .unwrap();
+    for _ in 0..3 {
+        orch.tick().unwrap();
+    }
+    assert!(orch.get_transaction(&tx_id).unwrap().is_returned());
+    assert_eq!(orch.status_counts()["Returned"], 1);
+    assert_eq!(abandoned_events(&orch).len(), 1);
+}
+
+#[test]
+fn test_transaction_settling_before_limit_is_not_returned() {
+    let mut orch = Orchestrator::new(build_config(Some(3)).unwrap()).unwrap();
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", AMOUNT, 50, 5, false)
+        .unwrap();
+
+    orch.tick().unwrap();
+    orch.submit_transaction("BANK_B", "BANK_A", AMOUNT, 50, 5, false)
+        .unwrap();
+    for _ in 0..4 {
+        orch.tick().unwrap();
+    }
+
+    let tx = orch.get_transaction(&tx_id).unwrap();
+    assert!(tx.is_fully_settled());
+    assert!(tx.retry_count() < 3);
+    assert!(abandoned_events(&orch).is_empty());
+}
+
+#[test]
+fn test_no_limit_retries_forever() {
+    let mut orch = Orchestrator::new(build_config(None).unwrap()).unwrap();
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", AMOUNT, 50, 5, false)
+        .unwrap();
+
+    for _ in 0..20 {
+        orch.tick().unwrap();
+    }
+
+    assert_eq!(orch.get_queue2_size(), 1);
+    assert_eq!(orch.get_transaction(&tx_id).unwrap().retry_count(), 20);
+    assert!(abandoned_events(&orch).is_empty());
+}
+
+#[test]
+fn test_zero_retry_limit_rejected() {
+    match build_config(Some(0)) {
+        Err(SimulationError::InvalidConfig(msg)) => {
+            assert!(
+                msg.contains("max_settlement_retries"),
+                "unexpected message: {}",
+                msg
+            );
+        }
+        other => panic!("expected InvalidConfig, got {:?}", other.map(|_| ())),
+    }
+}
+
+#[test]
+fn test_retry_count_survives_checkpoint() {
+    let mut orch = Orchestrator::new(build_config(Some(3)).unwrap()).unwrap();
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", AMOUNT, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    orch.tick().unwrap();
+
+    let state_json = orch.save_state().unwrap();
+    let mut restored = Orchestrator::load_state(orch.config().clone(), &state_json).unwrap();
+    assert_eq!(restored.get_transaction(&tx_id).unwrap().retry_count(), 2);
+
+    // Abandoned on the same tick as an uninterrupted run
+    restored.tick().unwrap();
+    assert_eq!(
+        restored.get_transaction(&tx_id).unwrap().status(),
+        &TransactionStatus::Returned { tick: 2 }
+    );
+}
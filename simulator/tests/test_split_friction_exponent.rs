@@ -0,0 +1,152 @@
+//! Tests for `CostRates::split_friction_exponent`
+//!
+//! Splitting into N parts costs `split_friction_cost × (N-1)^exponent`.
+//! The default exponent of 1.0 keeps the original linear formula; larger
+//! exponents make many small pieces disproportionately expensive. Both the
+//! `SubmitPartial` and `StaggerSplit` handlers charge the same formula.
+
+use payment_simulator_core_rs::models::{Event, Transaction};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::SimulationError;
+
+const FRICTION: i64 = 1_000;
+
+fn make_agent(id: &str, opening_balance: i64, policy: PolicyConfig) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        policy,
+        ..Default::default()
+    }
+}
+
+fn build_config(
+    policy: PolicyConfig,
+    exponent: f64,
+) -> Result<OrchestratorConfig, SimulationError> {
+    OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent("BANK_A", 0, policy))
+        .agent(make_agent("BANK_B", 1_000_000, PolicyConfig::Fifo))
+        .cost_rates(CostRates {
+            split_friction_cost: FRICTION,
+            split_friction_exponent: exponent,
+            ..CostRates::default()
+        })
+        .build()
+}
+
+fn submit_partial(num_splits: usize) -> PolicyConfig {
+    PolicyConfig::MockSplitting { num_splits }
+}
+
+fn stagger_split(num_splits: usize) -> PolicyConfig {
+    let json = format!(
+        r#"{{
+            "version": "1.0",
+            "policy_id": "stagger_split",
+            "payment_tree": {{
+                "type": "action",
+                "node_id": "A_StaggerSplit",
+                "action": "StaggerSplit",
+                "parameters": {{
+                    "num_splits": {{"value": {}}},
+                    "stagger_first_now": {{"value": 1.0}},
+                    "stagger_gap_ticks": {{"value": 2.0}},
+                    "priority_boost_children": {{"value": 0.0}}
+                }}
+            }},
+            "parameters": {{}}
+        }}"#,
+        num_splits as f64
+    );
+    PolicyConfig::FromJson { json }
+}
+
+/// Split one divisible payment from BANK_A at tick 0 and return the friction
+/// charged, checking the accumulator and the logged accrual agree
+fn friction_charged(policy: PolicyConfig, exponent: f64) -> i64 {
+    let mut orch = Orchestrator::new(build_config(policy, exponent).unwrap()).unwrap();
+
+    let tx = Transaction::new("BANK_A".to_string(), "BANK_B".to_string(), 100_000, 0, 50)
+        .with_divisible(true);
+    let tx_id = tx.id().to_string();
+    orch.state_mut().add_transaction(tx);
+    orch.state_mut()
+        .get_agent_mut("BANK_A")
+        .unwrap()
+        .queue_outgoing(tx_id);
+    orch.tick().unwrap();
+
+    assert!(orch
+        .get_tick_events(0)
+        .iter()
+        .any(|e| matches!(e, Event::PolicySplit { .. })));
+
+    let logged: i64 = orch
+        .get_tick_events(0)
+        .iter()
+        .filter_map(|e| match e {
+            Event::CostAccrual {
+                agent_id, costs, ..
+            } if agent_id == "BANK_A" => Some(costs.split_friction_cost),
+            _ => None,
+        })
+        .sum();
+    let accumulated = orch.get_costs("BANK_A").unwrap().total_split_friction_cost;
+    assert_eq!(logged, accumulated);
+    accumulated
+}
+
+#[test]
+fn test_exponent_one_is_linear_for_both_handlers() {
+    for num_splits in 2..=5 {
+        let expected = FRICTION * (num_splits as i64 - 1);
+        assert_eq!(friction_charged(submit_partial(num_splits), 1.0), expected);
+        assert_eq!(friction_charged(stagger_split(num_splits), 1.0), expected);
+    }
+}
+
+#[test]
+fn test_exponent_two_is_quadratic_for_both_handlers() {
+    for num_splits in 2..=5 {
+        let extra = num_splits as i64 - 1;
+        let expected = FRICTION * extra * extra;
+        assert_eq!(friction_charged(submit_partial(num_splits), 2.0), expected);
+        assert_eq!(friction_charged(stagger_split(num_splits), 2.0), expected);
+    }
+
+    // 4 parts: 1,000 × 3² = 9,000 vs 3,000 linear
+    assert_eq!(friction_charged(submit_partial(4), 2.0), 9_000);
+}
+
+#[test]
+fn test_default_exponent_matches_original_formula() {
+    assert_eq!(CostRates::default().split_friction_exponent, 1.0);
+
+    // Configs written before the field existed deserialize to linear friction
+    let mut json = serde_json::to_value(CostRates::default()).unwrap();
+    json.as_object_mut()
+        .unwrap()
+        .remove("split_friction_exponent");
+    let rates: CostRates = serde_json::from_value(json).unwrap();
+    assert_eq!(rates.split_friction_exponent, 1.0);
+}
+
+#[test]
+fn test_negative_exponent_rejected() {
+    match build_config(submit_partial(4), -1.0) {
+        Err(SimulationError::InvalidConfig(msg)) => {
+            assert!(
+                msg.contains("split_friction_exponent"),
+                "unexpected message: {}",
+                msg
+            );
+        }
+        other => panic!("expected InvalidConfig, got {:?}", other.map(|_| ())),
+    }
+}
@@ -0,0 +1,171 @@
+//! Tests for `split_remainder_policy`
+//!
+//! Splitting an amount not divisible by `num_splits` leaves a remainder.
+//! `LastChild` adds it all to the final child; `Distributed` gives one extra
+//! cent to each of the first `remainder` children. Either way the children
+//! sum exactly to the parent.
+
+use payment_simulator_core_rs::models::{Event, Transaction};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig, SplitRemainderPolicy,
+};
+
+const PARENT_AMOUNT: i64 = 100_003;
+
+fn make_agent(id: &str, opening_balance: i64, policy: PolicyConfig) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        policy,
+        ..Default::default()
+    }
+}
+
+fn create_config(
+    policy: PolicyConfig,
+    remainder_policy: SplitRemainderPolicy,
+) -> OrchestratorConfig {
+    OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent("BANK_A", 0, policy))
+        .agent(make_agent("BANK_B", 1_000_000, PolicyConfig::Fifo))
+        .split_remainder_policy(remainder_policy)
+        .build()
+        .unwrap()
+}
+
+/// Split one divisible payment from BANK_A and return child amounts in child order
+fn split_child_amounts(policy: PolicyConfig, remainder_policy: SplitRemainderPolicy) -> Vec<i64> {
+    let mut orch = Orchestrator::new(create_config(policy, remainder_policy)).unwrap();
+
+    let tx = Transaction::new(
+        "BANK_A".to_string(),
+        "BANK_B".to_string(),
+        PARENT_AMOUNT,
+        0,
+        50,
+    )
+    .with_divisible(true);
+    let tx_id = tx.id().to_string();
+    orch.state_mut().add_transaction(tx);
+    orch.state_mut()
+        .get_agent_mut("BANK_A")
+        .unwrap()
+        .queue_outgoing(tx_id);
+    orch.tick().unwrap();
+
+    let child_ids = orch
+        .get_tick_events(0)
+        .into_iter()
+        .find_map(|event| match event {
+            Event::PolicySplit { child_ids, .. } => Some(child_ids.clone()),
+            _ => None,
+        })
+        .expect("payment should have been split");
+
+    child_ids
+        .iter()
+        .map(|id| orch.get_transaction(id).unwrap().amount())
+        .collect()
+}
+
+fn submit_partial() -> PolicyConfig {
+    PolicyConfig::MockSplitting { num_splits: 4 }
+}
+
+/// Always stagger splits into 4 children, one released now, then every 2 ticks
+const STAGGER_SPLIT_POLICY: &str = r#"{
+    "version": "1.0",
+    "policy_id": "stagger_split",
+    "payment_tree": {
+        "type": "action",
+        "node_id": "A_StaggerSplit",
+        "action": "StaggerSplit",
+        "parameters": {
+            "num_splits": {"value": 4.0},
+            "stagger_first_now": {"value": 1.0},
+            "stagger_gap_ticks": {"value": 2.0},
+            "priority_boost_children": {"value": 0.0}
+        }
+    },
+    "parameters": {}
+}"#;
+
+fn stagger_split() -> PolicyConfig {
+    PolicyConfig::FromJson {
+        json: STAGGER_SPLIT_POLICY.to_string(),
+    }
+}
+
+#[test]
+fn test_child_amount_conserves_parent() {
+    for policy in [
+        SplitRemainderPolicy::LastChild,
+        SplitRemainderPolicy::Distributed,
+    ] {
+        for num_splits in 1..=7 {
+            let total: i64 = (0..num_splits)
+                .map(|i| policy.child_amount(PARENT_AMOUNT, num_splits, i))
+                .sum();
+            assert_eq!(
+                total, PARENT_AMOUNT,
+                "{:?} with {} splits",
+                policy, num_splits
+            );
+        }
+    }
+}
+
+#[test]
+fn test_last_child_policy_submit_partial() {
+    let amounts = split_child_amounts(submit_partial(), SplitRemainderPolicy::LastChild);
+
+    assert_eq!(amounts, vec![25_000, 25_000, 25_000, 25_003]);
+    assert_eq!(amounts.iter().sum::<i64>(), PARENT_AMOUNT);
+}
+
+#[test]
+fn test_distributed_policy_submit_partial() {
+    let amounts = split_child_amounts(submit_partial(), SplitRemainderPolicy::Distributed);
+
+    assert_eq!(amounts, vec![25_001, 25_001, 25_001, 25_000]);
+    assert_eq!(amounts.iter().sum::<i64>(), PARENT_AMOUNT);
+}
+
+#[test]
+fn test_last_child_policy_stagger_split() {
+    let amounts = split_child_amounts(stagger_split(), SplitRemainderPolicy::LastChild);
+
+    assert_eq!(amounts, vec![25_000, 25_000, 25_000, 25_003]);
+    assert_eq!(amounts.iter().sum::<i64>(), PARENT_AMOUNT);
+}
+
+#[test]
+fn test_distributed_policy_stagger_split() {
+    let amounts = split_child_amounts(stagger_split(), SplitRemainderPolicy::Distributed);
+
+    assert_eq!(amounts, vec![25_001, 25_001, 25_001, 25_000]);
+    assert_eq!(amounts.iter().sum::<i64>(), PARENT_AMOUNT);
+}
+
+#[test]
+fn test_default_is_last_child() {
+    assert_eq!(
+        SplitRemainderPolicy::default(),
+        SplitRemainderPolicy::LastChild
+    );
+
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(10)
+        .num_days(1)
+        .rng_seed(1)
+        .agent(make_agent("BANK_A", 0, PolicyConfig::Fifo))
+        .build()
+        .unwrap();
+    assert_eq!(
+        config.split_remainder_policy,
+        SplitRemainderPolicy::LastChild
+    );
+}
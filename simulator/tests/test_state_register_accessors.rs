@@ -0,0 +1,114 @@
+//! Tests for reading policy state registers after a run
+//!
+//! `Orchestrator::get_agent_state_registers` exposes the `bank_state_*`
+//! registers written by `SetState`/`AddState`, and checkpoints carry them
+//! so a restored simulation continues from the same values.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+
+/// Bank tree adds 1 to `bank_state_ticks` every tick
+const COUNTING_POLICY: &str = r#"{
+    "version": "1.0",
+    "policy_id": "tick_counter",
+    "bank_tree": {
+        "type": "action",
+        "node_id": "B1_Count",
+        "action": "AddState",
+        "parameters": {
+            "key": {"value": "bank_state_ticks"},
+            "value": {"value": 1.0}
+        }
+    },
+    "payment_tree": {
+        "type": "action",
+        "node_id": "P1_Release",
+        "action": "Release"
+    }
+}"#;
+
+fn create_config() -> OrchestratorConfig {
+    let make_agent = |id: &str, policy: PolicyConfig| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 20,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![
+            make_agent(
+                "BANK_A",
+                PolicyConfig::FromJson {
+                    json: COUNTING_POLICY.to_string(),
+                },
+            ),
+            make_agent("BANK_B", PolicyConfig::Fifo),
+        ],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+fn ticks_register(orch: &Orchestrator) -> Option<f64> {
+    orch.get_agent_state_registers("BANK_A")
+        .expect("BANK_A exists")
+        .get("bank_state_ticks")
+        .copied()
+}
+
+#[test]
+fn test_register_incremented_each_tick() {
+    let mut orch = Orchestrator::new(create_config()).unwrap();
+    for _ in 0..5 {
+        orch.tick().unwrap();
+    }
+
+    assert_eq!(ticks_register(&orch), Some(5.0));
+    assert!(orch.get_agent_state_registers("BANK_B").unwrap().is_empty());
+    assert!(orch.get_agent_state_registers("UNKNOWN").is_none());
+}
+
+#[test]
+fn test_registers_survive_checkpoint() {
+    let config = create_config();
+    let mut orch = Orchestrator::new(config.clone()).unwrap();
+    for _ in 0..5 {
+        orch.tick().unwrap();
+    }
+
+    let state_json = orch.save_state().unwrap();
+    let mut restored = Orchestrator::load_state(config, &state_json).unwrap();
+    assert_eq!(ticks_register(&restored), Some(5.0));
+
+    // The restored run keeps counting from the checkpointed value
+    restored.tick().unwrap();
+    orch.tick().unwrap();
+    assert_eq!(ticks_register(&restored), Some(6.0));
+    assert_eq!(ticks_register(&restored), ticks_register(&orch));
+}
@@ -0,0 +1,96 @@
+//! Tests for `Orchestrator::status_counts`
+//!
+//! Every transaction in state, split children included, is counted under its
+//! `TransactionStatus` variant name; statuses with no transactions report 0.
+
+use payment_simulator_core_rs::models::{Transaction, TransactionStatus};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+
+fn make_agent(id: &str, opening_balance: i64, policy: PolicyConfig) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        policy,
+        ..Default::default()
+    }
+}
+
+fn create_orchestrator() -> Orchestrator {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent("BANK_A", 50_000, PolicyConfig::Fifo))
+        .agent(make_agent("BANK_B", 0, PolicyConfig::Fifo))
+        .agent(make_agent(
+            "BANK_C",
+            0,
+            PolicyConfig::MockSplitting { num_splits: 4 },
+        ))
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+fn count(counts: &std::collections::HashMap<String, usize>, status: &str) -> usize {
+    counts[status]
+}
+
+#[test]
+fn test_fresh_orchestrator_reports_all_statuses_as_zero() {
+    let orch = create_orchestrator();
+    let counts = orch.status_counts();
+
+    assert_eq!(counts.len(), TransactionStatus::NAMES.len());
+    assert!(counts.values().all(|&c| c == 0));
+}
+
+#[test]
+fn test_counts_match_expected_statuses_and_sum_to_total() {
+    let mut orch = create_orchestrator();
+
+    // BANK_A: 30k and 10k settle, 40k (deadline 2) stays queued and goes overdue
+    orch.submit_transaction("BANK_A", "BANK_B", 30_000, 50, 5, false)
+        .unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 40_000, 2, 5, false)
+        .unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 10_000, 50, 5, false)
+        .unwrap();
+
+    // BANK_B: unfunded payment that is cancelled before it can settle
+    let cancelled = orch
+        .submit_transaction("BANK_B", "BANK_A", 5_000_000, 50, 5, false)
+        .unwrap();
+
+    // BANK_C: divisible payment split into 4 unfunded children
+    let tx = Transaction::new("BANK_C".to_string(), "BANK_A".to_string(), 80_000, 0, 50)
+        .with_divisible(true);
+    let parent_id = tx.id().to_string();
+    orch.state_mut().add_transaction(tx);
+    orch.state_mut()
+        .get_agent_mut("BANK_C")
+        .unwrap()
+        .queue_outgoing(parent_id);
+
+    for _ in 0..4 {
+        orch.tick().unwrap();
+    }
+    orch.cancel_transaction(&cancelled).unwrap();
+
+    let counts = orch.status_counts();
+    let total = orch.state().transactions().len();
+    assert_eq!(total, 9, "4 submitted + split parent + 4 children");
+    assert_eq!(counts.values().sum::<usize>(), total);
+
+    assert_eq!(count(&counts, "Settled"), 2);
+    assert_eq!(count(&counts, "Overdue"), 1);
+    assert_eq!(count(&counts, "Cancelled"), 1);
+    assert_eq!(
+        count(&counts, "Pending"),
+        5,
+        "split parent and its 4 children"
+    );
+    assert_eq!(count(&counts, "PartiallySettled"), 0);
+}
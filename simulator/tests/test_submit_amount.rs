@@ -0,0 +1,171 @@
+//! Tests for explicit partial release (`ReleaseDecision::SubmitAmount`)
+//!
+//! The `ReleaseAmount` action releases exactly `amount` now and keeps the
+//! remainder in Queue 1 as a second child of the original transaction.
+
+use payment_simulator_core_rs::models::Event;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+
+/// Releases 300,000 of any unsplit payment and holds split children.
+const RELEASE_AMOUNT_POLICY: &str = r#"{
+    "version": "1.0",
+    "policy_id": "release_amount",
+    "payment_tree": {
+        "type": "condition",
+        "node_id": "N1_IsSplit",
+        "condition": {
+            "op": "==",
+            "left": {"field": "is_split"},
+            "right": {"value": 1}
+        },
+        "on_true": {
+            "type": "action",
+            "node_id": "A1_Hold",
+            "action": "Hold"
+        },
+        "on_false": {
+            "type": "action",
+            "node_id": "A2_ReleaseAmount",
+            "action": "ReleaseAmount",
+            "parameters": {"amount": {"value": 300000}}
+        }
+    },
+    "parameters": {}
+}"#;
+
+const SPLIT_FRICTION: i64 = 1_000;
+
+fn create_config() -> OrchestratorConfig {
+    let make_agent = |id: &str, policy: PolicyConfig| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![
+            make_agent(
+                "BANK_A",
+                PolicyConfig::FromJson {
+                    json: RELEASE_AMOUNT_POLICY.to_string(),
+                },
+            ),
+            make_agent("BANK_B", PolicyConfig::Fifo),
+        ],
+        cost_rates: CostRates {
+            split_friction_cost: SPLIT_FRICTION,
+            ..CostRates::default()
+        },
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+/// Child IDs from the single PolicySplit event
+fn split_children(orch: &Orchestrator) -> Vec<String> {
+    let splits = orch.get_events_of_type("PolicySplit");
+    assert_eq!(splits.len(), 1);
+    match splits[0] {
+        Event::PolicySplit {
+            num_splits,
+            child_ids,
+            ..
+        } => {
+            assert_eq!(*num_splits, 2);
+            child_ids.clone()
+        }
+        other => panic!("unexpected event {:?}", other),
+    }
+}
+
+#[test]
+fn test_released_child_has_exact_amount() {
+    let mut orch = Orchestrator::new(create_config()).unwrap();
+    let parent_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 1_000_000, 50, 5, true)
+        .unwrap();
+    orch.tick().unwrap();
+
+    let children = split_children(&orch);
+    let released = orch.get_transaction(&children[0]).unwrap();
+    assert_eq!(released.amount(), 300_000);
+    assert!(released.is_fully_settled());
+    assert_eq!(released.parent_id(), Some(parent_id.as_str()));
+
+    let balance = orch.state().get_agent("BANK_A").unwrap().balance();
+    assert_eq!(balance, 1_000_000 - 300_000);
+}
+
+#[test]
+fn test_remainder_stays_in_queue1() {
+    let mut orch = Orchestrator::new(create_config()).unwrap();
+    let parent_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 1_000_000, 50, 5, true)
+        .unwrap();
+    orch.tick().unwrap();
+
+    let children = split_children(&orch);
+    let retained = orch.get_transaction(&children[1]).unwrap();
+    assert_eq!(retained.amount(), 700_000);
+    assert_eq!(retained.parent_id(), Some(parent_id.as_str()));
+
+    let agent = orch.state().get_agent("BANK_A").unwrap();
+    assert_eq!(agent.outgoing_queue(), &[children[1].clone()]);
+
+    // Held on later ticks rather than re-split
+    orch.tick().unwrap();
+    assert_eq!(orch.get_queue1_size("BANK_A"), Some(1));
+    assert_eq!(orch.get_events_of_type("PolicySplit").len(), 1);
+}
+
+#[test]
+fn test_split_friction_charged_once() {
+    let mut orch = Orchestrator::new(create_config()).unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 1_000_000, 50, 5, true)
+        .unwrap();
+    orch.tick().unwrap();
+
+    let costs = orch.get_costs("BANK_A").unwrap();
+    assert_eq!(costs.total_split_friction_cost, SPLIT_FRICTION);
+}
+
+#[test]
+fn test_non_divisible_transaction_released_whole() {
+    let mut orch = Orchestrator::new(create_config()).unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 1_000_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+
+    assert!(orch.get_events_of_type("PolicySplit").is_empty());
+    assert_eq!(
+        orch.get_costs("BANK_A").unwrap().total_split_friction_cost,
+        0
+    );
+    assert_eq!(orch.state().get_agent("BANK_A").unwrap().balance(), 0);
+}
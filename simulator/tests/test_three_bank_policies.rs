@@ -44,6 +44,7 @@ fn create_config(num_days: usize) -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "ARB".to_string(),
@@ -60,6 +61,7 @@ fn create_config(num_days: usize) -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
             AgentConfig {
                 id: "MIB".to_string(),
@@ -76,6 +78,7 @@ fn create_config(num_days: usize) -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates {
@@ -84,16 +87,26 @@ fn create_config(num_days: usize) -> OrchestratorConfig {
             collateral_cost_per_tick_bps: 1.0,
             split_friction_cost: 100_00,
             deadline_penalty: PenaltyMode::Fixed { amount: 1000_00 },
+            deadline_penalty_per_tick_late: 0,
             eod_penalty: PenaltyMode::Fixed { amount: 5000_00 },
             overdue_delay_multiplier: 5.0, // Phase 3: Escalating delay cost for overdue
             priority_delay_multipliers: None, // Enhancement 11.1
             liquidity_cost_per_tick_bps: 0.0, // Enhancement 11.2
+            liquidity_cost_tiers: None,
+            overnight_deposit_rate: 0.0,
+            overnight_overdraft_rate: 0.0,
+            collateral_cost_basis: Default::default(),
+            collateral_post_fee: 0,
+            split_friction_exponent: 1.0,
         },
         lsm_config: LsmConfig {
             enable_bilateral: true,
             enable_cycles: true,
-            max_cycle_length: 5,
+            max_cycle_length: Some(5),
             max_cycles_per_tick: 10,
+            partial_settlement: false,
+            lsm_interval_ticks: 1,
+            enable_multilateral_netting: false,
         },
         scenario_events: None,
         queue1_ordering: Queue1Ordering::default(),
@@ -103,6 +116,7 @@ fn create_config(num_days: usize) -> OrchestratorConfig {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     }
 }
 
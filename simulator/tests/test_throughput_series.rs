@@ -0,0 +1,177 @@
+//! Tests for the opt-in network-wide throughput series
+//!
+//! With `record_throughput_series` enabled, the value and number of
+//! settlement postings across all agents are appended at the end of each
+//! tick. Value counts what actually moved, so partial settlements and split
+//! children contribute their settled amounts.
+
+use payment_simulator_core_rs::arrivals::{
+    AmountDistribution, ArrivalConfig, PriorityDistribution,
+};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use std::collections::{HashMap, HashSet};
+
+fn make_agent(
+    id: &str,
+    opening_balance: i64,
+    policy: PolicyConfig,
+    arrival_config: Option<ArrivalConfig>,
+) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy,
+        arrival_config,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+fn arrivals(rate_per_tick: f64) -> ArrivalConfig {
+    ArrivalConfig {
+        rate_per_tick,
+        amount_distribution: AmountDistribution::Uniform {
+            min: 50_000,
+            max: 400_000,
+        },
+        counterparty_weights: HashMap::new(),
+        deadline_range: (5, 20),
+        priority_distribution: PriorityDistribution::Fixed { value: 5 },
+        divisible: true,
+        ..Default::default()
+    }
+}
+
+fn partial_settlement_lsm() -> LsmConfig {
+    LsmConfig {
+        partial_settlement: true,
+        ..LsmConfig::default()
+    }
+}
+
+/// Total value settled over the run, from the transactions themselves
+///
+/// Parents already count their children's settlements, so only leaves
+/// (transactions that were never split) are summed.
+fn total_settled_value(orch: &Orchestrator) -> i64 {
+    let transactions = orch.state().transactions();
+    let parents: HashSet<&str> = transactions
+        .values()
+        .filter_map(|tx| tx.parent_id())
+        .collect();
+    transactions
+        .values()
+        .filter(|tx| !parents.contains(tx.id()))
+        .map(|tx| tx.settled_amount())
+        .sum()
+}
+
+#[test]
+fn test_partial_and_split_settlements_are_value_based() {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(10)
+        .num_days(1)
+        .rng_seed(42)
+        .lsm_config(partial_settlement_lsm())
+        .agent(make_agent("BANK_A", 600_000, PolicyConfig::Fifo, None))
+        .agent(make_agent("BANK_B", 0, PolicyConfig::Fifo, None))
+        .agent(make_agent(
+            "BANK_C",
+            500_000,
+            PolicyConfig::MockSplitting { num_splits: 2 },
+            None,
+        ))
+        .record_throughput_series(true)
+        .build()
+        .unwrap();
+    let mut orch = Orchestrator::new(config).unwrap();
+
+    // Nothing settles on tick 0
+    orch.tick().unwrap();
+
+    // BANK_A can only cover 600k of a 1,000,000 divisible payment: it is
+    // queued at tick 1 and the affordable part settles when Queue 2 is processed
+    orch.submit_transaction("BANK_A", "BANK_B", 1_000_000, 9, 5, true)
+        .unwrap();
+    orch.tick().unwrap();
+
+    // BANK_C's 200k payment settles as two 100k children
+    orch.submit_transaction("BANK_C", "BANK_B", 200_000, 9, 5, true)
+        .unwrap();
+    orch.tick().unwrap();
+
+    let series = orch.throughput_series();
+    assert_eq!(series[0], (0, 0));
+    assert_eq!(series[1], (600_000, 1));
+    assert_eq!(series[2], (200_000, 2));
+}
+
+#[test]
+fn test_summed_series_value_equals_total_settled_value() {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(20)
+        .num_days(2)
+        .rng_seed(7)
+        .lsm_config(partial_settlement_lsm())
+        .agent(make_agent(
+            "BANK_A",
+            300_000,
+            PolicyConfig::Fifo,
+            Some(arrivals(0.8)),
+        ))
+        .agent(make_agent(
+            "BANK_B",
+            300_000,
+            PolicyConfig::MockSplitting { num_splits: 3 },
+            Some(arrivals(0.8)),
+        ))
+        .agent(make_agent(
+            "BANK_C",
+            300_000,
+            PolicyConfig::Fifo,
+            Some(arrivals(0.8)),
+        ))
+        .record_throughput_series(true)
+        .build()
+        .unwrap();
+    let mut orch = Orchestrator::new(config).unwrap();
+    for _ in 0..40 {
+        orch.tick().unwrap();
+    }
+
+    let series = orch.throughput_series();
+    assert_eq!(series.len(), 40);
+
+    let summed_value: i64 = series.iter().map(|(value, _)| value).sum();
+    assert!(summed_value > 0);
+    assert_eq!(summed_value, total_settled_value(&orch));
+}
+
+#[test]
+fn test_series_is_empty_when_recording_is_off() {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(10)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent("BANK_A", 500_000, PolicyConfig::Fifo, None))
+        .agent(make_agent("BANK_B", 0, PolicyConfig::Fifo, None))
+        .build()
+        .unwrap();
+    let mut orch = Orchestrator::new(config).unwrap();
+    orch.submit_transaction("BANK_A", "BANK_B", 100_000, 5, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+
+    assert!(!orch.config().record_throughput_series);
+    assert!(orch.throughput_series().is_empty());
+}
@@ -0,0 +1,151 @@
+//! Tests for the per-tick observer and recent tick results buffer
+//!
+//! The observer sees every tick's result plus a read-only state view and
+//! must not change simulation outcomes.
+
+use payment_simulator_core_rs::arrivals::{AmountDistribution, ArrivalConfig, PriorityDistribution};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+    RECENT_TICK_RESULTS_CAPACITY,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+fn create_config() -> OrchestratorConfig {
+    let make_agent = |id: &str, counterparty: &str| AgentConfig {
+        id: id.to_string(),
+        opening_balance: 500_000,
+        unsecured_cap: 100_000,
+        policy: PolicyConfig::Fifo,
+        arrival_config: Some(ArrivalConfig {
+            rate_per_tick: 0.8,
+            amount_distribution: AmountDistribution::Uniform {
+                min: 10_000,
+                max: 80_000,
+            },
+            counterparty_weights: {
+                let mut weights = HashMap::new();
+                weights.insert(counterparty.to_string(), 1.0);
+                weights
+            },
+            deadline_range: (5, 30),
+            priority_distribution: PriorityDistribution::Fixed { value: 5 },
+            divisible: false,
+            ..Default::default()
+        }),
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 50,
+        eod_rush_threshold: 0.8,
+        num_days: 10,
+        rng_seed: 31337,
+        agent_configs: vec![make_agent("BANK_A", "BANK_B"), make_agent("BANK_B", "BANK_A")],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_observer_invoked_once_per_tick_in_order() {
+    let mut orchestrator = Orchestrator::new(create_config()).unwrap();
+    let seen_ticks = Arc::new(Mutex::new(Vec::new()));
+
+    let sink = Arc::clone(&seen_ticks);
+    orchestrator.set_tick_observer(Box::new(move |result, _state| {
+        sink.lock().unwrap().push(result.tick);
+    }));
+
+    for _ in 0..10 {
+        orchestrator.tick().unwrap();
+    }
+
+    let seen = seen_ticks.lock().unwrap();
+    assert_eq!(seen.len(), 10);
+    assert!(seen.windows(2).all(|w| w[1] > w[0]), "ticks must increase: {:?}", seen);
+    assert_eq!(*seen, (0..10).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_observer_sees_post_tick_state() {
+    let mut orchestrator = Orchestrator::new(create_config()).unwrap();
+    let queue_sizes = Arc::new(Mutex::new(Vec::new()));
+
+    let sink = Arc::clone(&queue_sizes);
+    orchestrator.set_tick_observer(Box::new(move |_result, state| {
+        sink.lock().unwrap().push(state.queue_size());
+    }));
+    orchestrator.tick().unwrap();
+
+    assert_eq!(*queue_sizes.lock().unwrap(), vec![orchestrator.get_queue2_size()]);
+}
+
+#[test]
+fn test_observer_does_not_affect_determinism() {
+    let mut observed = Orchestrator::new(create_config()).unwrap();
+    let mut plain = Orchestrator::new(create_config()).unwrap();
+    observed.set_tick_observer(Box::new(|_result, state| {
+        let _ = state.num_transactions();
+    }));
+
+    for _ in 0..100 {
+        let a = observed.tick().unwrap();
+        let b = plain.tick().unwrap();
+        assert_eq!(a.num_arrivals, b.num_arrivals);
+        assert_eq!(a.num_settlements, b.num_settlements);
+        assert_eq!(a.total_cost, b.total_cost);
+    }
+    assert_eq!(
+        observed.get_all_agent_balances(),
+        plain.get_all_agent_balances()
+    );
+}
+
+#[test]
+fn test_recent_tick_results_returns_latest_oldest_first() {
+    let mut orchestrator = Orchestrator::new(create_config()).unwrap();
+    assert!(orchestrator.recent_tick_results(5).is_empty());
+
+    orchestrator.run_ticks(10).unwrap();
+
+    let recent: Vec<usize> = orchestrator
+        .recent_tick_results(3)
+        .iter()
+        .map(|r| r.tick)
+        .collect();
+    assert_eq!(recent, vec![7, 8, 9]);
+    assert_eq!(orchestrator.recent_tick_results(100).len(), 10);
+}
+
+#[test]
+fn test_recent_tick_results_bounded_by_capacity() {
+    let mut orchestrator = Orchestrator::new(create_config()).unwrap();
+    orchestrator.run_ticks(RECENT_TICK_RESULTS_CAPACITY + 20).unwrap();
+
+    let recent = orchestrator.recent_tick_results(usize::MAX);
+    assert_eq!(recent.len(), RECENT_TICK_RESULTS_CAPACITY);
+    assert_eq!(recent.first().unwrap().tick, 20);
+    assert_eq!(
+        recent.last().unwrap().tick,
+        RECENT_TICK_RESULTS_CAPACITY + 19
+    );
+}
@@ -0,0 +1,153 @@
+//! Tests for `OrchestratorConfig::time_scale`
+//!
+//! The time scale multiplies every agent's arrival rate, so a config can be
+//! reused at a different temporal resolution.
+
+use payment_simulator_core_rs::arrivals::{
+    AmountDistribution, ArrivalBandConfig, ArrivalBandsConfig, ArrivalConfig, PriorityDistribution,
+};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+use payment_simulator_core_rs::SimulationError;
+use std::collections::HashMap;
+
+const TICKS: usize = 1_000;
+
+fn make_agent(
+    id: &str,
+    arrival_config: Option<ArrivalConfig>,
+    arrival_bands: Option<ArrivalBandsConfig>,
+) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config,
+        arrival_bands,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+fn amounts() -> AmountDistribution {
+    AmountDistribution::Uniform {
+        min: 100,
+        max: 1_000,
+    }
+}
+
+fn legacy_arrivals() -> ArrivalConfig {
+    ArrivalConfig {
+        rate_per_tick: 1.5,
+        amount_distribution: amounts(),
+        counterparty_weights: HashMap::new(),
+        deadline_range: (5, 10),
+        priority_distribution: PriorityDistribution::Fixed { value: 5 },
+        divisible: false,
+        ..Default::default()
+    }
+}
+
+fn band_arrivals() -> ArrivalBandsConfig {
+    ArrivalBandsConfig {
+        urgent: None,
+        normal: Some(ArrivalBandConfig {
+            rate_per_tick: 1.5,
+            amount_distribution: amounts(),
+            deadline_offset_min: 5,
+            deadline_offset_max: 10,
+            counterparty_weights: HashMap::new(),
+            divisible: false,
+            deadline_jitter: None,
+        }),
+        low: None,
+    }
+}
+
+fn config(time_scale: f64) -> Result<OrchestratorConfig, SimulationError> {
+    OrchestratorConfig::builder()
+        .ticks_per_day(TICKS)
+        .num_days(1)
+        .rng_seed(99)
+        .agent(make_agent("BANK_A", Some(legacy_arrivals()), None))
+        .agent(make_agent("BANK_B", None, Some(band_arrivals())))
+        .time_scale(time_scale)
+        .build()
+}
+
+/// Arrival counts per sender over the whole run
+fn arrival_counts(time_scale: f64) -> (usize, usize) {
+    let mut orch = Orchestrator::new(config(time_scale).unwrap()).unwrap();
+    for _ in 0..TICKS {
+        orch.tick().unwrap();
+    }
+
+    let arrivals = orch.event_log().events_of_type("Arrival");
+    let from = |sender: &str| {
+        arrivals
+            .iter()
+            .filter(|e| e.agent_id() == Some(sender))
+            .count()
+    };
+    (from("BANK_A"), from("BANK_B"))
+}
+
+fn assert_ratio(scaled: usize, base: usize, expected: f64) {
+    let ratio = scaled as f64 / base as f64;
+    assert!(
+        (ratio - expected).abs() < 0.15 * expected,
+        "expected ratio ≈ {expected}, got {ratio} ({scaled} / {base})"
+    );
+}
+
+#[test]
+fn test_doubling_time_scale_doubles_arrivals() {
+    let (legacy_base, band_base) = arrival_counts(1.0);
+    let (legacy_doubled, band_doubled) = arrival_counts(2.0);
+
+    // λ = 1.5 over 1,000 ticks
+    assert_ratio(legacy_base, TICKS * 3 / 2, 1.0);
+    assert_ratio(legacy_doubled, legacy_base, 2.0);
+    assert_ratio(band_doubled, band_base, 2.0);
+}
+
+#[test]
+fn test_fractional_time_scale_reduces_arrivals() {
+    let (legacy_base, band_base) = arrival_counts(1.0);
+    let (legacy_scaled, band_scaled) = arrival_counts(0.25);
+
+    assert_ratio(legacy_scaled, legacy_base, 0.25);
+    assert_ratio(band_scaled, band_base, 0.25);
+}
+
+#[test]
+fn test_default_time_scale_is_one() {
+    let built = OrchestratorConfig::builder()
+        .ticks_per_day(10)
+        .num_days(1)
+        .rng_seed(1)
+        .agent(make_agent("BANK_A", None, None))
+        .agent(make_agent("BANK_B", None, None))
+        .build()
+        .unwrap();
+    assert_eq!(built.time_scale, 1.0);
+}
+
+#[test]
+fn test_non_positive_time_scale_rejected() {
+    for time_scale in [0.0, -1.0, f64::NAN, f64::INFINITY] {
+        match config(time_scale) {
+            Err(SimulationError::InvalidConfig(msg)) => {
+                assert!(msg.contains("time_scale"), "{msg}")
+            }
+            other => panic!("time_scale {time_scale} should be rejected, got {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,168 @@
+//! Tests for the `ticks_since_arrival` and `ticks_until_deadline` policy
+//! context fields
+//!
+//! Both are computed per transaction when the payment tree is evaluated;
+//! `ticks_until_deadline` saturates at 0 once the deadline has passed.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+
+/// Releases a payment once `field` compares `op` against the `threshold` parameter
+fn age_policy(field: &str, op: &str, threshold: i64) -> String {
+    format!(
+        r#"{{
+        "version": "1.0",
+        "policy_id": "age_gate",
+        "parameters": {{"threshold": {}}},
+        "payment_tree": {{
+            "type": "condition",
+            "node_id": "N1",
+            "condition": {{
+                "op": "{}",
+                "left": {{"field": "{}"}},
+                "right": {{"param": "threshold"}}
+            }},
+            "on_true": {{"type": "action", "node_id": "A1", "action": "Release"}},
+            "on_false": {{"type": "action", "node_id": "A2", "action": "Hold"}}
+        }},
+        "strategic_collateral_tree": null,
+        "end_of_tick_collateral_tree": null
+    }}"#,
+        threshold, op, field
+    )
+}
+
+fn make_agent(id: &str, policy: PolicyConfig) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance: 1_000_000,
+        unsecured_cap: 0,
+        policy,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    }
+}
+
+fn create_orchestrator(policy_json: String) -> Orchestrator {
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(100)
+        .num_days(1)
+        .rng_seed(42)
+        .agent(make_agent(
+            "BANK_A",
+            PolicyConfig::FromJson { json: policy_json },
+        ))
+        .agent(make_agent("BANK_B", PolicyConfig::Fifo))
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+fn is_settled(orch: &Orchestrator, tx_id: &str) -> bool {
+    orch.get_transaction(tx_id).unwrap().is_fully_settled()
+}
+
+#[test]
+fn test_policy_submits_once_age_exceeds_parameter() {
+    let mut orch = create_orchestrator(age_policy("ticks_since_arrival", ">", 3));
+
+    // Arrives at tick 0 and is held while its age is 0..=3
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 10_000, 50, 5, false)
+        .unwrap();
+    for _ in 0..4 {
+        orch.tick().unwrap();
+        assert!(!is_settled(&orch, &tx_id));
+    }
+
+    // Age 4 > 3 at tick 4
+    orch.tick().unwrap();
+    assert!(is_settled(&orch, &tx_id));
+}
+
+#[test]
+fn test_age_is_measured_from_each_transactions_arrival() {
+    let mut orch = create_orchestrator(age_policy("ticks_since_arrival", ">=", 2));
+
+    let early = orch
+        .submit_transaction("BANK_A", "BANK_B", 10_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    let late = orch
+        .submit_transaction("BANK_A", "BANK_B", 10_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert!(!is_settled(&orch, &early));
+
+    // Tick 2: early is 2 ticks old, late only 1
+    orch.tick().unwrap();
+    assert!(is_settled(&orch, &early));
+    assert!(!is_settled(&orch, &late));
+
+    orch.tick().unwrap();
+    assert!(is_settled(&orch, &late));
+}
+
+#[test]
+fn test_ticks_until_deadline_counts_down_to_deadline() {
+    let mut orch = create_orchestrator(age_policy("ticks_until_deadline", "<=", 0));
+
+    // Deadline tick 3: held while 3, 2, 1 ticks remain
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 10_000, 3, 5, false)
+        .unwrap();
+    for _ in 0..3 {
+        orch.tick().unwrap();
+        assert!(!is_settled(&orch, &tx_id));
+    }
+
+    orch.tick().unwrap();
+    assert!(is_settled(&orch, &tx_id));
+}
+
+#[test]
+fn test_ticks_until_deadline_is_zero_when_overdue() {
+    // Release only once the payment is overdue (ticks_to_deadline < 0) and
+    // ticks_until_deadline has saturated at 0 rather than going negative
+    let policy = r#"{
+        "version": "1.0",
+        "policy_id": "overdue_gate",
+        "parameters": {},
+        "payment_tree": {
+            "type": "condition",
+            "node_id": "N1",
+            "condition": {
+                "op": "and",
+                "conditions": [
+                    {"op": "<", "left": {"field": "ticks_to_deadline"}, "right": {"value": 0}},
+                    {"op": "==", "left": {"field": "ticks_until_deadline"}, "right": {"value": 0}}
+                ]
+            },
+            "on_true": {"type": "action", "node_id": "A1", "action": "Release"},
+            "on_false": {"type": "action", "node_id": "A2", "action": "Hold"}
+        },
+        "strategic_collateral_tree": null,
+        "end_of_tick_collateral_tree": null
+    }"#;
+    let mut orch = create_orchestrator(policy.to_string());
+
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 10_000, 3, 5, false)
+        .unwrap();
+    for _ in 0..4 {
+        orch.tick().unwrap();
+        assert!(!is_settled(&orch, &tx_id));
+    }
+
+    // Tick 4 is past the deadline
+    orch.tick().unwrap();
+    assert!(is_settled(&orch, &tx_id));
+}
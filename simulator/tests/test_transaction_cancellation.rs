@@ -0,0 +1,163 @@
+//! Tests for `Orchestrator::cancel_transaction`
+//!
+//! A cancelled transaction leaves whichever queue holds it, is marked
+//! `TransactionStatus::Cancelled`, and never settles afterwards. Settled
+//! transactions cannot be cancelled.
+
+use payment_simulator_core_rs::models::event::Event;
+use payment_simulator_core_rs::models::TransactionStatus;
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use payment_simulator_core_rs::SimulationError;
+
+fn create_config() -> OrchestratorConfig {
+    let make_agent = |id: &str, opening_balance: i64| AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![make_agent("BANK_A", 1_000_000), make_agent("BANK_B", 0)],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig {
+            enable_bilateral: false,
+            enable_cycles: false,
+            ..LsmConfig::default()
+        },
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+fn cancelled_events(orch: &Orchestrator, tick: usize) -> Vec<(String, String)> {
+    orch.get_tick_events(tick)
+        .into_iter()
+        .filter_map(|event| match event {
+            Event::TransactionCancelled { tx_id, queue, .. } => {
+                Some((tx_id.clone(), queue.clone()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn test_cancel_from_queue1() {
+    let mut orch = Orchestrator::new(create_config()).unwrap();
+
+    // Submitted transactions wait in the sender's Queue 1 until the next tick
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 300_000, 50, 5, false)
+        .unwrap();
+    orch.cancel_transaction(&tx_id).unwrap();
+
+    let agent = orch.state().get_agent("BANK_A").unwrap();
+    assert!(!agent.outgoing_queue().contains(&tx_id));
+    assert_eq!(
+        cancelled_events(&orch, 0),
+        vec![(tx_id.clone(), "queue1".to_string())]
+    );
+
+    for _ in 0..3 {
+        orch.tick().unwrap();
+    }
+    let tx = orch.get_transaction(&tx_id).unwrap();
+    assert_eq!(tx.status(), &TransactionStatus::Cancelled { tick: 0 });
+    assert_eq!(tx.remaining_amount(), 300_000);
+    assert_eq!(orch.get_agent_balance("BANK_A"), Some(1_000_000));
+    assert_eq!(orch.get_agent_balance("BANK_B"), Some(0));
+}
+
+#[test]
+fn test_cancel_from_queue2_never_settles() {
+    let mut orch = Orchestrator::new(create_config()).unwrap();
+
+    // BANK_B has no liquidity, so its payment waits in Queue 2
+    let tx_id = orch
+        .submit_transaction("BANK_B", "BANK_A", 200_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert!(orch.state().rtgs_queue().contains(&tx_id));
+
+    orch.cancel_transaction(&tx_id).unwrap();
+    assert!(!orch.state().rtgs_queue().contains(&tx_id));
+    assert_eq!(
+        cancelled_events(&orch, 1),
+        vec![(tx_id.clone(), "queue2".to_string())]
+    );
+
+    // Funding BANK_B would have released the queued payment
+    orch.submit_transaction("BANK_A", "BANK_B", 500_000, 50, 5, false)
+        .unwrap();
+    for _ in 0..3 {
+        orch.tick().unwrap();
+    }
+
+    let tx = orch.get_transaction(&tx_id).unwrap();
+    assert!(tx.is_cancelled());
+    assert_eq!(tx.settled_amount(), 0);
+    assert_eq!(orch.get_agent_balance("BANK_B"), Some(500_000));
+}
+
+#[test]
+fn test_cannot_cancel_after_settlement() {
+    let mut orch = Orchestrator::new(create_config()).unwrap();
+
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 100_000, 50, 5, false)
+        .unwrap();
+    orch.tick().unwrap();
+    assert!(orch.get_transaction(&tx_id).unwrap().is_fully_settled());
+
+    let result = orch.cancel_transaction(&tx_id);
+    assert!(matches!(result, Err(SimulationError::SettlementError(_))));
+    assert!(matches!(
+        orch.get_transaction(&tx_id).unwrap().status(),
+        TransactionStatus::Settled { .. }
+    ));
+    assert!(cancelled_events(&orch, 1).is_empty());
+}
+
+#[test]
+fn test_cannot_cancel_twice_or_unknown() {
+    let mut orch = Orchestrator::new(create_config()).unwrap();
+
+    let tx_id = orch
+        .submit_transaction("BANK_A", "BANK_B", 100_000, 50, 5, false)
+        .unwrap();
+    orch.cancel_transaction(&tx_id).unwrap();
+
+    assert!(matches!(
+        orch.cancel_transaction(&tx_id),
+        Err(SimulationError::SettlementError(_))
+    ));
+    assert!(matches!(
+        orch.cancel_transaction("missing"),
+        Err(SimulationError::TransactionNotFound(_))
+    ));
+}
@@ -107,12 +107,14 @@ fn test_orchestrator_handles_submit_partial() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+        ..Default::default()
     });
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
 
     // Inject large transaction that will be split
-    let tx = Transaction::new("BANK_A".to_string(), "BANK_B".to_string(), 100_000, 0, 10);
+    let tx = Transaction::new("BANK_A".to_string(), "BANK_B".to_string(), 100_000, 0, 10)
+        .with_divisible(true);
     let parent_id = tx.id().to_string();
     let tx_id = tx.id().to_string();
 
@@ -184,12 +186,14 @@ fn test_split_friction_cost_formula() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+        ..Default::default()
     });
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
 
     // Inject transaction that will be split into 3 parts
-    let tx = Transaction::new("BANK_A".to_string(), "BANK_B".to_string(), 150_000, 0, 10);
+    let tx = Transaction::new("BANK_A".to_string(), "BANK_B".to_string(), 150_000, 0, 10)
+        .with_divisible(true);
     let tx_id = tx.id().to_string();
 
     orchestrator.state_mut().add_transaction(tx);
@@ -231,6 +235,7 @@ fn test_no_split_friction_for_whole_transaction() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+        ..Default::default()
     });
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -280,12 +285,14 @@ fn test_liquidity_splitting_policy_splits_when_insufficient_balance() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+        ..Default::default()
     });
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
 
     // Large transaction that can't be settled whole
-    let tx = Transaction::new("BANK_A".to_string(), "BANK_B".to_string(), 100_000, 0, 20);
+    let tx = Transaction::new("BANK_A".to_string(), "BANK_B".to_string(), 100_000, 0, 20)
+        .with_divisible(true);
     let tx_id = tx.id().to_string();
 
     orchestrator.state_mut().add_transaction(tx);
@@ -310,6 +317,60 @@ fn test_liquidity_splitting_policy_splits_when_insufficient_balance() {
     );
 }
 
+#[test]
+fn test_liquidity_splitting_policy_never_splits_non_divisible_transaction() {
+    // Same setup as above, but the payment is submitted as non-divisible
+
+    let mut config = create_basic_config();
+    config.cost_rates.split_friction_cost = 100;
+
+    config.agent_configs.push(AgentConfig {
+        id: "BANK_A".to_string(),
+        opening_balance: 50_000,
+        unsecured_cap: 0,
+        policy: PolicyConfig::LiquiditySplitting {
+            max_splits: 4,
+            min_split_amount: 10_000,
+        },
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    });
+
+    let mut orchestrator = Orchestrator::new(config).unwrap();
+    let tx_id = orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 100_000, 20, 5, false)
+        .unwrap();
+
+    for _ in 0..5 {
+        orchestrator.tick().unwrap();
+    }
+
+    let split_events: Vec<_> = orchestrator
+        .event_log()
+        .events()
+        .iter()
+        .filter(|e| matches!(e, Event::PolicySplit { .. }))
+        .collect();
+    assert!(split_events.is_empty(), "Non-divisible payment must never be split");
+
+    // Released whole to Queue 2, waiting for liquidity; no friction charged
+    let tx = orchestrator.state().get_transaction(&tx_id).unwrap();
+    assert!(!tx.is_divisible());
+    assert_eq!(tx.remaining_amount(), 100_000);
+    assert_eq!(orchestrator.state().queue_size(), 1);
+    assert_eq!(
+        orchestrator.get_costs("BANK_A").unwrap().total_split_friction_cost,
+        0
+    );
+}
+
 #[test]
 fn test_liquidity_splitting_policy_does_not_split_when_affordable() {
     // Test that policy does NOT split when agent has sufficient balance
@@ -333,6 +394,7 @@ fn test_liquidity_splitting_policy_does_not_split_when_affordable() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+        ..Default::default()
     });
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -397,6 +459,7 @@ fn test_liquidity_splitting_respects_min_split_amount() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+        ..Default::default()
     });
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -458,6 +521,7 @@ fn test_liquidity_splitting_respects_max_splits() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+        ..Default::default()
     });
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -514,6 +578,7 @@ fn test_liquidity_splitting_urgency_factor() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+        ..Default::default()
     });
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -581,12 +646,14 @@ fn test_full_splitting_workflow() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+        ..Default::default()
     });
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
 
     // Inject 200k transaction (will need to split)
-    let tx = Transaction::new("BANK_A".to_string(), "BANK_B".to_string(), 200_000, 0, 10);
+    let tx = Transaction::new("BANK_A".to_string(), "BANK_B".to_string(), 200_000, 0, 10)
+        .with_divisible(true);
     let _parent_id = tx.id().to_string();
     let tx_id = tx.id().to_string();
 
@@ -660,6 +727,7 @@ fn test_multiple_transactions_with_selective_splitting() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+        ..Default::default()
     });
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -732,6 +800,7 @@ fn create_basic_config() -> OrchestratorConfig {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+                ..Default::default()
             },
         ],
         cost_rates: CostRates {
@@ -740,16 +809,26 @@ fn create_basic_config() -> OrchestratorConfig {
             collateral_cost_per_tick_bps: 0.0002,
             eod_penalty: PenaltyMode::Fixed { amount: 10000 }, // $100
             deadline_penalty: PenaltyMode::Fixed { amount: 5000 },             // $50 per missed deadline
+            deadline_penalty_per_tick_late: 0,
             split_friction_cost: 0,             // Default to 0, tests override
             overdue_delay_multiplier: 5.0,      // Phase 3: Escalating delay cost for overdue
             priority_delay_multipliers: None,   // Enhancement 11.1
             liquidity_cost_per_tick_bps: 0.0,   // Enhancement 11.2
+            liquidity_cost_tiers: None,
+            overnight_deposit_rate: 0.0,
+            overnight_overdraft_rate: 0.0,
+            collateral_cost_basis: Default::default(),
+            collateral_post_fee: 0,
+            split_friction_exponent: 1.0,
         },
         lsm_config: LsmConfig {
             enable_bilateral: false, // Disable LSM for simpler tests
             enable_cycles: false,
-            max_cycle_length: 4,
+            max_cycle_length: Some(4),
             max_cycles_per_tick: 10,
+            partial_settlement: false,
+            lsm_interval_ticks: 1,
+            enable_multilateral_netting: false,
         },
         scenario_events: None,
         queue1_ordering: Queue1Ordering::default(),
@@ -759,6 +838,7 @@ fn create_basic_config() -> OrchestratorConfig {
             entry_disposition_offsetting: false,
             deferred_crediting: false,
             deadline_cap_at_eod: false, daily_liquidity_reallocation: false,
+        ..Default::default()
     }
 }
 
@@ -800,6 +880,7 @@ fn test_tree_policy_split_decision_with_positive_liquidity() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+        ..Default::default()
     });
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -912,6 +993,7 @@ fn test_tree_policy_split_with_negative_liquidity_reveals_bug() {
                 limits: None,
                 liquidity_pool: None,
                 liquidity_allocation_fraction: None,
+        ..Default::default()
     });
 
     let mut orchestrator = Orchestrator::new(config).unwrap();
@@ -964,7 +1046,8 @@ fn test_tree_policy_split_with_negative_liquidity_reveals_bug() {
         400_000, // $4k - exceeds split_threshold ($3k)
         1,       // arrival_tick
         50,      // deadline (not too urgent)
-    );
+    )
+    .with_divisible(true);
     let tx2_id = tx2.id().to_string();
 
     eprintln!("\n=== Before TX2 ===");
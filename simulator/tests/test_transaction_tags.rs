@@ -0,0 +1,144 @@
+//! Tests for transaction tags
+//!
+//! Tags are free-form labels attached at arrival or submission time. They
+//! are inherited by split children and survive checkpoint save/load.
+
+use payment_simulator_core_rs::arrivals::{AmountDistribution, ArrivalConfig, PriorityDistribution};
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+use std::collections::HashMap;
+
+fn make_agent(id: &str, opening_balance: i64, policy: PolicyConfig) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        policy,
+        ..Default::default()
+    }
+}
+
+fn create_config(agent_configs: Vec<AgentConfig>) -> OrchestratorConfig {
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs,
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+/// BANK_A can only afford half of a 100,000 payment, so its splitting
+/// policy breaks the payment into children
+fn create_splitting_config() -> OrchestratorConfig {
+    create_config(vec![
+        make_agent(
+            "BANK_A",
+            50_000,
+            PolicyConfig::LiquiditySplitting {
+                max_splits: 4,
+                min_split_amount: 10_000,
+            },
+        ),
+        make_agent("BANK_B", 0, PolicyConfig::Fifo),
+    ])
+}
+
+#[test]
+fn test_split_children_inherit_parent_tags() {
+    let mut orchestrator = Orchestrator::new(create_splitting_config()).unwrap();
+    let parent_id = orchestrator
+        .submit_transaction_with_tags(
+            "BANK_A",
+            "BANK_B",
+            100_000,
+            20,
+            5,
+            true,
+            vec!["CLS".to_string(), "fx".to_string()],
+        )
+        .unwrap();
+    orchestrator.submit_transaction("BANK_A", "BANK_B", 1_000, 20, 5, false).unwrap();
+
+    orchestrator.tick().unwrap();
+
+    let tagged = orchestrator.get_transactions_with_tag("CLS");
+    let children: Vec<_> = tagged
+        .iter()
+        .filter(|tx| tx.parent_id() == Some(parent_id.as_str()))
+        .collect();
+    assert!(children.len() >= 2, "payment should have been split");
+    assert_eq!(tagged.len(), children.len() + 1, "parent plus children only");
+    for child in children {
+        assert_eq!(child.tags(), ["CLS".to_string(), "fx".to_string()]);
+    }
+    assert_eq!(orchestrator.get_transactions_with_tag("fx").len(), tagged.len());
+    assert!(orchestrator.get_transactions_with_tag("customer").is_empty());
+}
+
+#[test]
+fn test_tags_survive_checkpoint_round_trip() {
+    let config = create_splitting_config();
+    let mut original = Orchestrator::new(config.clone()).unwrap();
+    original
+        .submit_transaction_with_tags("BANK_A", "BANK_B", 100_000, 20, 5, true, vec!["CLS".to_string()])
+        .unwrap();
+    original.tick().unwrap();
+
+    let state_json = original.save_state().unwrap();
+    let restored = Orchestrator::load_state(config, &state_json).unwrap();
+
+    let ids = |o: &Orchestrator| -> Vec<String> {
+        o.get_transactions_with_tag("CLS")
+            .iter()
+            .map(|tx| tx.id().to_string())
+            .collect()
+    };
+    assert!(!ids(&original).is_empty());
+    assert_eq!(ids(&original), ids(&restored));
+}
+
+#[test]
+fn test_arrival_config_tags_applied_to_generated_transactions() {
+    let mut bank_a = make_agent("BANK_A", 10_000_000, PolicyConfig::Fifo);
+    bank_a.arrival_config = Some(ArrivalConfig {
+        rate_per_tick: 2.0,
+        amount_distribution: AmountDistribution::Uniform {
+            min: 1_000,
+            max: 10_000,
+        },
+        counterparty_weights: HashMap::new(),
+        deadline_range: (5, 10),
+        priority_distribution: PriorityDistribution::Fixed { value: 5 },
+        divisible: false,
+        tags: Some(vec!["customer".to_string()]),
+        ..Default::default()
+    });
+    let mut orchestrator = Orchestrator::new(create_config(vec![
+        bank_a,
+        make_agent("BANK_B", 10_000_000, PolicyConfig::Fifo),
+    ]))
+    .unwrap();
+
+    for _ in 0..5 {
+        orchestrator.tick().unwrap();
+    }
+
+    let tagged = orchestrator.get_transactions_with_tag("customer");
+    assert!(!tagged.is_empty());
+    assert_eq!(tagged.len(), orchestrator.state().transactions().len());
+    assert!(tagged.iter().all(|tx| tx.sender_id() == "BANK_A"));
+}
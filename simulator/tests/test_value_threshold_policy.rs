@@ -0,0 +1,104 @@
+//! Tests for the ValueThreshold batching policy
+//!
+//! Transactions wait in Queue 1 until the agent's total queued value reaches
+//! the threshold, then the whole batch is released in the same tick. A
+//! transaction held for `max_hold_ticks` is released regardless.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, CostRates, Orchestrator, OrchestratorConfig, PolicyConfig, Queue1Ordering,
+};
+use payment_simulator_core_rs::settlement::lsm::LsmConfig;
+
+fn make_agent(id: &str, opening_balance: i64, policy: PolicyConfig) -> AgentConfig {
+    AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        policy,
+        ..Default::default()
+    }
+}
+
+fn create_config(threshold: i64, max_hold_ticks: usize) -> OrchestratorConfig {
+    OrchestratorConfig {
+        ticks_per_day: 100,
+        eod_rush_threshold: 0.8,
+        num_days: 1,
+        rng_seed: 42,
+        agent_configs: vec![
+            make_agent(
+                "BANK_A",
+                1_000_000,
+                PolicyConfig::ValueThreshold {
+                    threshold,
+                    max_hold_ticks,
+                },
+            ),
+            make_agent("BANK_B", 0, PolicyConfig::Fifo),
+        ],
+        cost_rates: CostRates::default(),
+        lsm_config: LsmConfig::default(),
+        scenario_events: None,
+        queue1_ordering: Queue1Ordering::default(),
+        priority_mode: false,
+        priority_escalation: Default::default(),
+        algorithm_sequencing: false,
+        entry_disposition_offsetting: false,
+        deferred_crediting: false,
+        deadline_cap_at_eod: false,
+        daily_liquidity_reallocation: false,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_small_transactions_batched_until_threshold_crossed() {
+    let mut orchestrator = Orchestrator::new(create_config(100_000, 50)).unwrap();
+    let mut tx_ids = Vec::new();
+
+    // Four payments of 20,000 stay below the 100,000 threshold
+    for expected_queued in 1..=4 {
+        tx_ids.push(
+            orchestrator
+                .submit_transaction("BANK_A", "BANK_B", 20_000, 90, 5, false)
+                .unwrap(),
+        );
+        orchestrator.tick().unwrap();
+
+        assert_eq!(orchestrator.get_queue1_size("BANK_A"), Some(expected_queued));
+        assert_eq!(orchestrator.get_agent_balance("BANK_B"), Some(0));
+    }
+
+    // The fifth payment crosses the threshold and the whole batch goes out
+    tx_ids.push(
+        orchestrator
+            .submit_transaction("BANK_A", "BANK_B", 20_000, 90, 5, false)
+            .unwrap(),
+    );
+    orchestrator.tick().unwrap();
+
+    assert_eq!(orchestrator.get_queue1_size("BANK_A"), Some(0));
+    assert_eq!(orchestrator.get_agent_balance("BANK_B"), Some(100_000));
+    for tx_id in &tx_ids {
+        let tx = orchestrator.get_transaction(tx_id).unwrap();
+        assert!(tx.is_fully_settled(), "{} should settle with the batch", tx_id);
+    }
+}
+
+#[test]
+fn test_transaction_force_released_after_max_hold_ticks() {
+    let mut orchestrator = Orchestrator::new(create_config(100_000, 3)).unwrap();
+    let tx_id = orchestrator
+        .submit_transaction("BANK_A", "BANK_B", 10_000, 90, 5, false)
+        .unwrap();
+
+    // Held while queue_age < max_hold_ticks
+    for _ in 0..3 {
+        orchestrator.tick().unwrap();
+        assert_eq!(orchestrator.get_queue1_size("BANK_A"), Some(1));
+    }
+
+    // Released once it has waited max_hold_ticks, despite the low queue value
+    orchestrator.tick().unwrap();
+    assert_eq!(orchestrator.get_queue1_size("BANK_A"), Some(0));
+    assert!(orchestrator.get_transaction(&tx_id).unwrap().is_fully_settled());
+}
@@ -0,0 +1,139 @@
+//! Tests for `OrchestratorConfig::warmup_ticks`
+//!
+//! Transactions arriving before `warmup_ticks` are excluded from delay
+//! metrics, and daily metrics for days overlapping the warm-up are flagged.
+
+use payment_simulator_core_rs::orchestrator::{
+    AgentConfig, Orchestrator, OrchestratorConfig, PolicyConfig,
+};
+
+fn create_orchestrator(warmup_ticks: usize) -> Orchestrator {
+    let make_agent = |id: &str, opening_balance: i64| AgentConfig {
+        id: id.to_string(),
+        opening_balance,
+        unsecured_cap: 0,
+        policy: PolicyConfig::Fifo,
+        arrival_config: None,
+        arrival_bands: None,
+        posted_collateral: None,
+        collateral_haircut: None,
+        max_collateral_capacity: None,
+        limits: None,
+        liquidity_pool: None,
+        liquidity_allocation_fraction: None,
+        ..Default::default()
+    };
+
+    let config = OrchestratorConfig::builder()
+        .ticks_per_day(4)
+        .num_days(3)
+        .rng_seed(42)
+        .agent(make_agent("BANK_A", 0))
+        .agent(make_agent("BANK_B", 100_000))
+        .agent(make_agent("BANK_C", 100_000))
+        .warmup_ticks(warmup_ticks)
+        .build()
+        .unwrap();
+    Orchestrator::new(config).unwrap()
+}
+
+/// Settled delays [0, 0, 0, 2, 5] by arrival: three at tick 0, one each at ticks 2 and 5
+///
+/// BANK_A has no liquidity, so its two tick-0 payments wait in Queue 2
+/// until BANK_B funds it at tick 2 (10,000) and tick 5 (20,000). The
+/// funding payments and BANK_C's payment settle on arrival.
+fn run_known_delays(warmup_ticks: usize) -> Orchestrator {
+    let mut orchestrator = create_orchestrator(warmup_ticks);
+
+    orchestrator
+        .submit_transaction("BANK_A", "BANK_C", 10_000, 11, 5, false)
+        .unwrap();
+    orchestrator
+        .submit_transaction("BANK_A", "BANK_C", 20_000, 11, 5, false)
+        .unwrap();
+    orchestrator
+        .submit_transaction("BANK_C", "BANK_B", 5_000, 11, 5, false)
+        .unwrap();
+
+    for tick in 0..12 {
+        if tick == 2 {
+            orchestrator
+                .submit_transaction("BANK_B", "BANK_A", 10_000, 11, 5, false)
+                .unwrap();
+        }
+        if tick == 5 {
+            orchestrator
+                .submit_transaction("BANK_B", "BANK_A", 20_000, 11, 5, false)
+                .unwrap();
+        }
+        orchestrator.tick().unwrap();
+    }
+
+    assert_eq!(orchestrator.get_queue2_size(), 0);
+    orchestrator
+}
+
+#[test]
+fn test_no_warmup_includes_all_delays() {
+    let orchestrator = run_known_delays(0);
+
+    assert_eq!(
+        orchestrator.delay_percentiles(&[1.0]),
+        vec![(1.0, 5)],
+        "the tick-0 payment waiting 5 ticks counts"
+    );
+    assert_eq!(orchestrator.calculate_system_metrics().total_arrivals, 5);
+}
+
+#[test]
+fn test_warmup_excludes_early_transactions_from_delays() {
+    let orchestrator = run_known_delays(1);
+
+    // Only the two funding payments (ticks 2 and 5, delay 0) remain
+    assert_eq!(
+        orchestrator.delay_percentiles(&[0.5, 1.0]),
+        vec![(0.5, 0), (1.0, 0)]
+    );
+    assert_eq!(orchestrator.settlement_delay_histogram(1), vec![(0, 2)]);
+
+    let metrics = orchestrator.calculate_system_metrics();
+    assert_eq!(metrics.total_arrivals, 2);
+    assert_eq!(metrics.total_settlements, 2);
+    assert_eq!(metrics.settlement_rate, 1.0);
+}
+
+#[test]
+fn test_warmup_covering_all_arrivals_reports_empty_metrics() {
+    let orchestrator = run_known_delays(6);
+
+    assert_eq!(orchestrator.delay_percentiles(&[1.0]), vec![(1.0, 0)]);
+    assert!(orchestrator.settlement_delay_histogram(1).is_empty());
+    assert_eq!(orchestrator.calculate_system_metrics().total_arrivals, 0);
+}
+
+#[test]
+fn test_daily_metrics_flag_warmup_days() {
+    // Days start at ticks 0, 4 and 8; a 6-tick warm-up overlaps days 0 and 1
+    let orchestrator = run_known_delays(6);
+
+    for (day, expected) in [(0, true), (1, true), (2, false)] {
+        let metrics = orchestrator.get_daily_agent_metrics(day);
+        assert!(!metrics.is_empty(), "day {day} metrics should exist");
+        assert!(
+            metrics.iter().all(|m| m.in_warmup == expected),
+            "day {day} in_warmup should be {expected}"
+        );
+    }
+}
+
+#[test]
+fn test_no_day_flagged_without_warmup() {
+    let orchestrator = run_known_delays(0);
+
+    for day in 0..3 {
+        assert!(orchestrator
+            .get_daily_agent_metrics(day)
+            .iter()
+            .all(|m| !m.in_warmup));
+    }
+}
@@ -75,6 +75,7 @@ FIELD_GROUPS: dict[str, list[str]] = {
     "cost": [
         "cost_overdraft_bps_per_tick", "cost_delay_per_tick_per_cent",
         "cost_collateral_bps_per_tick", "cost_split_friction",
+        "cost_split_friction_exponent",
         "cost_deadline_penalty", "cost_eod_penalty",
         # Per-transaction cost calculations (payment_tree only)
         "cost_delay_this_tx_one_tick", "cost_overdraft_this_amount_one_tick",